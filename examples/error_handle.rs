@@ -14,9 +14,14 @@ fn main() {
 fn app() -> Element {
     rsx! {
         ErrorBoundary {
-            handle_error: |error: CapturedError| rsx! {
-                h1 { "An error occurred" }
-                pre { "{error:#?}" }
+            handle_error: |error: CapturedError| {
+                // The handler is rendered inside of the boundary, so it can reset the boundary to try again
+                let boundary = consume_context::<ErrorBoundary>();
+                rsx! {
+                    h1 { "An error occurred" }
+                    pre { "{error:#?}" }
+                    button { onclick: move |_| boundary.reset(), "Try again" }
+                }
             },
             DemoC { x: 1 }
         }
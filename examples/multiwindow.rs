@@ -3,7 +3,10 @@
 //! This exmaple shows how to implement a simple multiwindow application using dioxus.
 //! This works by spawning a new window when the user clicks a button. We have to build a new virtualdom which has its
 //! own context, root elements, etc.
+//!
+//! The handle returned from `new_window` lets the parent window focus or close the popup, and run a callback when the popup closes.
 
+use dioxus::desktop::WindowHandle;
 use dioxus::prelude::*;
 
 fn main() {
@@ -11,13 +14,26 @@ fn main() {
 }
 
 fn app() -> Element {
+    let mut popup_window = use_signal(|| None as Option<WindowHandle>);
+
     let onclick = move |_| {
         let dom = VirtualDom::new(popup);
-        dioxus::desktop::window().new_window(dom, Default::default());
+        let handle = dioxus::desktop::window().new_window(dom, Default::default());
+        handle.on_close(move || popup_window.set(None));
+        popup_window.set(Some(handle));
     };
 
-    rsx! {
-        button { onclick, "New Window" }
+    match popup_window() {
+        Some(handle) => {
+            let focus = handle.clone();
+            rsx! {
+                button { onclick: move |_| focus.focus(), "Focus Window" }
+                button { onclick: move |_| handle.close(), "Close Window" }
+            }
+        }
+        None => rsx! {
+            button { onclick, "New Window" }
+        },
     }
 }
 
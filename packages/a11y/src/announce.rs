@@ -0,0 +1,72 @@
+//! Screen reader announcements with ARIA live regions
+
+use crate::script::{js_string, run_script};
+
+/// How urgently a screen reader should read an announcement
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum Politeness {
+    /// Read the announcement once the user is idle
+    #[default]
+    Polite,
+    /// Interrupt the user to read the announcement. Only use this for time sensitive messages, like errors.
+    Assertive,
+}
+
+impl Politeness {
+    fn as_str(self) -> &'static str {
+        match self {
+            Politeness::Polite => "polite",
+            Politeness::Assertive => "assertive",
+        }
+    }
+
+    fn role(self) -> &'static str {
+        match self {
+            Politeness::Polite => "status",
+            Politeness::Assertive => "alert",
+        }
+    }
+}
+
+/// Read a message to screen reader users without showing it.
+///
+/// The message is put in a visually hidden [ARIA live region](https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/ARIA_Live_Regions)
+/// that is added to the page the first time it is needed. Announcing the same message twice reads it twice.
+///
+/// ```rust, ignore
+/// rsx! {
+///     button {
+///         onclick: move |_| {
+///             save();
+///             announce("Your changes were saved", Politeness::Polite);
+///         },
+///         "Save"
+///     }
+/// }
+/// ```
+///
+/// Call this from a component or an event handler. Renderers without JavaScript ignore announcements.
+pub fn announce(message: impl AsRef<str>, politeness: Politeness) {
+    run_script(&format!(
+        r#"(() => {{
+            const id = {id};
+            let region = document.getElementById(id);
+            if (!region) {{
+                region = document.createElement("div");
+                region.id = id;
+                region.setAttribute("role", {role});
+                region.setAttribute("aria-live", {politeness});
+                region.setAttribute("aria-atomic", "true");
+                region.style.cssText = "position:absolute;width:1px;height:1px;margin:-1px;padding:0;overflow:hidden;clip:rect(0,0,0,0);white-space:nowrap;border:0;";
+                document.body.appendChild(region);
+            }}
+            // Clear the region first, so screen readers notice the change if the message is the same
+            region.textContent = "";
+            setTimeout(() => {{ region.textContent = {message}; }}, 50);
+        }})();"#,
+        id = js_string(&format!("dioxus-live-region-{}", politeness.as_str())),
+        role = js_string(politeness.role()),
+        politeness = js_string(politeness.as_str()),
+        message = js_string(message.as_ref()),
+    ));
+}
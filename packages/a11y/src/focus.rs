@@ -0,0 +1,165 @@
+//! Keep focus inside of dialogs and give it back when they close
+
+use std::fmt::Display;
+
+use dioxus_lib::prelude::*;
+
+use crate::script::{hook_key, js_string, run_script};
+
+/// The elements that can be reached with Tab
+const FOCUSABLE: &str = r#"a[href], area[href], button:not([disabled]), input:not([disabled]):not([type="hidden"]), select:not([disabled]), textarea:not([disabled]), iframe, [contenteditable]:not([contenteditable="false"]), [tabindex]:not([tabindex="-1"])"#;
+
+/// A focus trap created with [`use_focus_trap`]. Set the id of the element focus should stay inside of to [`FocusTrap::id`].
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct FocusTrap {
+    id: String,
+}
+
+impl FocusTrap {
+    /// The id of the element that traps focus
+    pub fn id(&self) -> String {
+        self.id.clone()
+    }
+}
+
+impl Display for FocusTrap {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.id)
+    }
+}
+
+/// Keep keyboard focus inside of an element while the component is mounted.
+///
+/// When the component is mounted, the first focusable element inside of the trap is focused. Tab on the last focusable
+/// element moves focus back to the first one, and Shift+Tab on the first one moves it to the last one. If traps are
+/// nested, only the one that was mounted last is active.
+///
+/// ```rust, ignore
+/// #[component]
+/// fn Modal(children: Element) -> Element {
+///     let trap = use_focus_trap();
+///     rsx! {
+///         div { id: trap.id(), role: "dialog", aria_modal: "true", {children} }
+///     }
+/// }
+/// ```
+///
+/// The id of the trap is derived from the position of the hook, so it is the same on the server and the client.
+pub fn use_focus_trap() -> FocusTrap {
+    let trap = use_hook(|| FocusTrap {
+        id: hook_key("dioxus-focus-trap"),
+    });
+
+    // The element of the trap only exists after the first render is applied
+    let id = trap.id.clone();
+    use_effect(move || run_script(&trap_focus(&id)));
+
+    let id = trap.id.clone();
+    use_drop(move || run_script(&release_focus(&id)));
+
+    trap
+}
+
+fn trap_focus(id: &str) -> String {
+    format!(
+        r#"(() => {{
+            const id = {id};
+            const selector = {selector};
+            const traps = (window.__dioxusFocusTraps ??= []);
+            const focusable = (container) => Array.from(container.querySelectorAll(selector))
+                .filter((element) => element.getClientRects().length > 0);
+            const focusContainer = (container) => {{
+                if (!container.hasAttribute("tabindex")) container.setAttribute("tabindex", "-1");
+                container.focus();
+            }};
+            const onKeyDown = (event) => {{
+                // Only the innermost trap moves focus
+                if (event.key !== "Tab" || traps[traps.length - 1]?.id !== id) return;
+                const container = document.getElementById(id);
+                if (!container) return;
+                const elements = focusable(container);
+                if (elements.length === 0) {{
+                    event.preventDefault();
+                    focusContainer(container);
+                    return;
+                }}
+                const first = elements[0];
+                const last = elements[elements.length - 1];
+                const active = document.activeElement;
+                const outside = !container.contains(active);
+                if (event.shiftKey && (active === first || active === container || outside)) {{
+                    event.preventDefault();
+                    last.focus();
+                }} else if (!event.shiftKey && (active === last || outside)) {{
+                    event.preventDefault();
+                    first.focus();
+                }}
+            }};
+            document.addEventListener("keydown", onKeyDown, true);
+            traps.push({{ id, onKeyDown }});
+
+            const container = document.getElementById(id);
+            if (container && !container.contains(document.activeElement)) {{
+                const first = focusable(container)[0];
+                if (first) first.focus(); else focusContainer(container);
+            }}
+        }})();"#,
+        id = js_string(id),
+        selector = js_string(FOCUSABLE),
+    )
+}
+
+fn release_focus(id: &str) -> String {
+    format!(
+        r#"(() => {{
+            const traps = window.__dioxusFocusTraps ?? [];
+            const index = traps.findIndex((trap) => trap.id === {id});
+            if (index === -1) return;
+            document.removeEventListener("keydown", traps[index].onKeyDown, true);
+            traps.splice(index, 1);
+        }})();"#,
+        id = js_string(id),
+    )
+}
+
+/// Focus the element that had focus before the component was mounted again when the component is unmounted.
+///
+/// Call this before any hook that moves focus, like [`use_focus_trap`], so the focus from before the component was
+/// mounted is saved.
+///
+/// ```rust, ignore
+/// #[component]
+/// fn Menu() -> Element {
+///     // The button that opened the menu is focused again when the menu closes
+///     use_restore_focus();
+///     let trap = use_focus_trap();
+///     rsx! {
+///         ul { id: trap.id(), role: "menu",
+///             li { role: "menuitem", tabindex: 0, "Copy" }
+///             li { role: "menuitem", tabindex: 0, "Paste" }
+///         }
+///     }
+/// }
+/// ```
+pub fn use_restore_focus() {
+    let key = use_hook(|| {
+        let key = hook_key("dioxus-restore-focus");
+        run_script(&format!(
+            "(window.__dioxusRestoreFocus ??= {{}})[{}] = document.activeElement;",
+            js_string(&key)
+        ));
+        key
+    });
+
+    use_drop(move || {
+        run_script(&format!(
+            r#"(() => {{
+                const saved = window.__dioxusRestoreFocus ?? {{}};
+                const element = saved[{key}];
+                delete saved[{key}];
+                if (element && element.isConnected) element.focus();
+            }})();"#,
+            key = js_string(&key)
+        ))
+    });
+}
@@ -0,0 +1,17 @@
+#![doc = include_str!("../README.md")]
+#![doc(html_logo_url = "https://avatars.githubusercontent.com/u/79236386")]
+#![doc(html_favicon_url = "https://avatars.githubusercontent.com/u/79236386")]
+#![warn(missing_docs)]
+
+mod announce;
+mod focus;
+mod script;
+
+pub use announce::*;
+pub use focus::*;
+
+/// A collection of the most common exports from the crate
+pub mod prelude {
+    pub use crate::announce::{announce, Politeness};
+    pub use crate::focus::{use_focus_trap, use_restore_focus, FocusTrap};
+}
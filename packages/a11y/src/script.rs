@@ -0,0 +1,38 @@
+use std::fmt::Write;
+
+use dioxus_lib::prelude::*;
+
+/// Run a script in the root scope, so it still runs while the component that started it is unmounted.
+///
+/// Renderers without JavaScript ignore the script, and it is skipped outside of a Dioxus runtime.
+pub(crate) fn run_script(script: &str) {
+    if Runtime::current().is_none() {
+        return;
+    }
+    ScopeId::ROOT.in_runtime(|| {
+        let _ = eval(script);
+    });
+}
+
+/// A key for the current hook that is the same on the server and the client
+pub(crate) fn hook_key(prefix: &str) -> String {
+    let scope = current_scope_id().expect("hooks must be called from a component");
+    format!("{prefix}-{}-{}", scope.0, current_hook_index())
+}
+
+/// Quote a string for JavaScript, escaping everything that could end the string or script.
+pub(crate) fn js_string(value: &str) -> String {
+    let mut quoted = String::from('"');
+    for unit in value.encode_utf16() {
+        match char::from_u32(unit as u32) {
+            Some(c) if c.is_ascii_alphanumeric() || matches!(c, ' ' | '-' | '_' | '.' | ':') => {
+                quoted.push(c)
+            }
+            _ => {
+                let _ = write!(quoted, "\\u{unit:04x}");
+            }
+        }
+    }
+    quoted.push('"');
+    quoted
+}
@@ -0,0 +1,129 @@
+//! The accessibility utilities run scripts with the eval provider of the renderer
+
+use std::{cell::RefCell, rc::Rc, time::Duration};
+
+use dioxus::prelude::*;
+use dioxus_a11y::prelude::*;
+use generational_box::{AnyStorage, GenerationalBox, UnsyncStorage};
+
+/// An eval provider that records the scripts instead of running them
+#[derive(Clone, Default)]
+struct Recorder(Rc<RefCell<Vec<String>>>);
+
+impl EvalProvider for Recorder {
+    fn new_evaluator(&self, js: String) -> GenerationalBox<Box<dyn Evaluator>> {
+        self.0.borrow_mut().push(js);
+        UnsyncStorage::owner().insert(Box::new(Finished) as Box<dyn Evaluator>)
+    }
+}
+
+struct Finished;
+
+impl Evaluator for Finished {
+    fn send(&self, _: serde_json::Value) -> Result<(), EvalError> {
+        Err(EvalError::Finished)
+    }
+    fn poll_recv(
+        &mut self,
+        _: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<Result<serde_json::Value, EvalError>> {
+        std::task::Poll::Ready(Err(EvalError::Finished))
+    }
+    fn poll_join(
+        &mut self,
+        _: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<Result<serde_json::Value, EvalError>> {
+        std::task::Poll::Ready(Ok(serde_json::Value::Null))
+    }
+}
+
+fn app(recorder: Recorder) -> Element {
+    use_hook(|| provide_context(Rc::new(recorder) as Rc<dyn EvalProvider>));
+    let open = use_signal(|| true);
+    use_hook(|| provide_context(open));
+
+    rsx! {
+        if open() {
+            Dialog {}
+        }
+    }
+}
+
+#[component]
+fn Dialog() -> Element {
+    use_restore_focus();
+    let trap = use_focus_trap();
+    rsx! {
+        div { id: trap.id(), button { "Close" } }
+    }
+}
+
+async fn run_effects(dom: &mut VirtualDom) {
+    let _ = tokio::time::timeout(Duration::from_millis(50), dom.wait_for_work()).await;
+    dom.render_immediate(&mut dioxus_core::NoOpMutations);
+}
+
+#[tokio::test]
+async fn focus_is_trapped_while_mounted_and_restored_after() {
+    let recorder = Recorder::default();
+    let mut dom = VirtualDom::new_with_props(app, recorder.clone());
+    dom.rebuild_in_place();
+    run_effects(&mut dom).await;
+
+    {
+        let scripts = recorder.0.borrow();
+        assert_eq!(scripts.len(), 2);
+        // Focus is saved before the trap moves it
+        assert!(scripts[0].contains("__dioxusRestoreFocus"));
+        assert!(scripts[1].contains("addEventListener(\"keydown\""));
+    }
+    let html = dioxus_ssr::render(&dom);
+    let id = html
+        .split("id=\"")
+        .nth(1)
+        .unwrap()
+        .split('"')
+        .next()
+        .unwrap();
+    assert!(id.starts_with("dioxus-focus-trap-"));
+    assert!(recorder.0.borrow()[1].contains(&format!("\"{id}\"")));
+
+    // Closing the dialog releases the trap and restores focus
+    dom.in_runtime(|| {
+        let mut open = ScopeId::ROOT.in_runtime(consume_context::<Signal<bool>>);
+        open.set(false);
+    });
+    run_effects(&mut dom).await;
+    let scripts = recorder.0.borrow();
+    assert_eq!(scripts.len(), 4);
+    assert!(scripts[2..]
+        .iter()
+        .any(|script| script.contains("removeEventListener")));
+    assert!(scripts[2..]
+        .iter()
+        .any(|script| script.contains("element.focus()")));
+}
+
+#[test]
+fn announcements_escape_the_message() {
+    let recorder = Recorder::default();
+    let mut dom = VirtualDom::new_with_props(
+        |recorder: Recorder| {
+            use_hook(|| provide_context(Rc::new(recorder) as Rc<dyn EvalProvider>));
+            use_hook(|| announce("Saved \"draft\" </script>", Politeness::Assertive));
+            rsx! {}
+        },
+        recorder.clone(),
+    );
+    dom.rebuild_in_place();
+
+    let scripts = recorder.0.borrow();
+    assert_eq!(scripts.len(), 1);
+    assert!(scripts[0].contains("dioxus-live-region-assertive"));
+    assert!(scripts[0].contains("Saved \\u0022draft\\u0022 \\u003c\\u002fscript\\u003e"));
+}
+
+#[test]
+fn announcements_outside_of_a_runtime_are_ignored() {
+    announce("Nothing to see", Politeness::Polite);
+}
@@ -223,7 +223,7 @@ impl Writer<'_> {
         Ok(())
     }
 
-    fn write_attribute_value(&mut self, value: &ElementAttrValue) -> Result {
+    pub(crate) fn write_attribute_value(&mut self, value: &ElementAttrValue) -> Result {
         match value {
             ElementAttrValue::AttrOptionalExpr { condition, value } => {
                 write!(
@@ -234,9 +234,28 @@ impl Writer<'_> {
                 self.write_attribute_value(value)?;
                 write!(self.out, " }}")?;
             }
+            ElementAttrValue::AttrFlag(condition) => {
+                write!(
+                    self.out,
+                    "if {condition}",
+                    condition = prettyplease::unparse_expr(condition),
+                )?;
+            }
             ElementAttrValue::AttrLiteral(value) => {
                 write!(self.out, "{value}", value = ifmt_to_string(value))?;
             }
+            ElementAttrValue::Styles(properties) => {
+                write!(self.out, "{{ ")?;
+                let mut properties = properties.iter().peekable();
+                while let Some(property) = properties.next() {
+                    write!(self.out, "{}: ", property.name)?;
+                    self.write_attribute_value(&property.value)?;
+                    if properties.peek().is_some() {
+                        write!(self.out, ", ")?;
+                    }
+                }
+                write!(self.out, " }}")?;
+            }
             ElementAttrValue::Shorthand(value) => {
                 write!(self.out, "{value}",)?;
             }
@@ -263,7 +282,10 @@ impl Writer<'_> {
                     }
                 }
             }
-            ElementAttrValue::EventTokens(tokens) => {
+            ElementAttrValue::EventTokens(tokens)
+            | ElementAttrValue::EventWithModifiers {
+                handler: tokens, ..
+            } => {
                 let out = self.retrieve_formatted_expr(tokens).to_string();
                 let mut lines = out.split('\n').peekable();
                 let first = lines.next().unwrap();
@@ -300,6 +322,10 @@ impl Writer<'_> {
 
     fn write_named_attribute(&mut self, attr: &ElementAttrNamed) -> Result {
         self.write_attribute_name(&attr.attr.name)?;
+        if let ElementAttrValue::EventWithModifiers { modifiers, .. } = &attr.attr.value {
+            let modifiers = modifiers.iter().map(|m| m.as_str()).collect::<Vec<_>>();
+            write!(self.out, " |{}|", modifiers.join(", "))?;
+        }
         write!(self.out, ": ")?;
         self.write_attribute_value(&attr.attr.value)?;
 
@@ -376,6 +402,7 @@ impl Writer<'_> {
             }
             // TODO: let rawexprs to be inlined
             [BodyNode::RawExpr(ref expr)] => get_expr_length(expr),
+            [BodyNode::RawHtml(ref raw)] => Some(self.attr_value_len(&raw.value) + "raw!()".len()),
             [BodyNode::Element(ref el)] => {
                 let attr_len = self.is_short_attrs(&el.attributes);
 
@@ -411,6 +438,8 @@ impl Writer<'_> {
                         },
                         BodyNode::ForLoop(_forloop) => return None,
                         BodyNode::IfChain(_chain) => return None,
+                        BodyNode::Match(_chain) => return None,
+                        BodyNode::RawHtml(_raw) => return None,
                     }
                 }
 
@@ -50,17 +50,27 @@ pub struct FormattedBlock {
 /// back to the file precisely.
 ///
 /// Nested blocks of RSX will be handled automatically
+///
+/// If the file or any of its rsx! blocks fail to parse, no edits are returned. Use [`try_fmt_file`] to get the parse error.
 pub fn fmt_file(contents: &str, indent: IndentOptions) -> Vec<FormattedBlock> {
+    try_fmt_file(contents, indent).unwrap_or_default()
+}
+
+/// Format a file into a list of `FormattedBlock`s, returning an error if the file or any rsx! block fails to parse.
+///
+/// This is the fallible version of [`fmt_file`], useful for tools that format on save and need to report syntax errors
+/// instead of silently skipping the file.
+pub fn try_fmt_file(contents: &str, indent: IndentOptions) -> syn::Result<Vec<FormattedBlock>> {
     let mut formatted_blocks = Vec::new();
 
-    let parsed = syn::parse_file(contents).unwrap();
+    let parsed = syn::parse_file(contents)?;
 
     let mut macros = vec![];
     collect_macros::collect_from_file(&parsed, &mut macros);
 
     // No macros, no work to do
     if macros.is_empty() {
-        return formatted_blocks;
+        return Ok(formatted_blocks);
     }
 
     let mut writer = Writer::new(contents);
@@ -76,7 +86,7 @@ pub fn fmt_file(contents: &str, indent: IndentOptions) -> Vec<FormattedBlock> {
             continue;
         }
 
-        let body = item.parse_body::<CallBody>().unwrap();
+        let body = item.parse_body::<CallBody>()?;
 
         let rsx_start = macro_path.span().start();
 
@@ -128,7 +138,7 @@ pub fn fmt_file(contents: &str, indent: IndentOptions) -> Vec<FormattedBlock> {
         });
     }
 
-    formatted_blocks
+    Ok(formatted_blocks)
 }
 
 pub fn write_block_out(body: CallBody) -> Option<String> {
@@ -159,7 +169,7 @@ fn write_body(buf: &mut Writer, body: &CallBody) {
 }
 
 pub fn fmt_block_from_expr(raw: &str, expr: ExprMacro) -> Option<String> {
-    let body = syn::parse2::<CallBody>(expr.mac.tokens).unwrap();
+    let body = syn::parse2::<CallBody>(expr.mac.tokens).ok()?;
 
     let mut buf = Writer::new(raw);
 
@@ -169,7 +179,7 @@ pub fn fmt_block_from_expr(raw: &str, expr: ExprMacro) -> Option<String> {
 }
 
 pub fn fmt_block(block: &str, indent_level: usize, indent: IndentOptions) -> Option<String> {
-    let body = syn::parse_str::<dioxus_rsx::CallBody>(block).unwrap();
+    let body = syn::parse_str::<dioxus_rsx::CallBody>(block).ok()?;
 
     let mut buf = Writer::new(block);
 
@@ -1,4 +1,6 @@
-use dioxus_rsx::{AttributeType, BodyNode, ElementAttrValue, ForLoop, IfChain};
+use dioxus_rsx::{
+    AttributeType, BodyNode, ElementAttrValue, ForLoop, IfChain, MatchArm, MatchChain, RawHtml,
+};
 use proc_macro2::{LineColumn, Span};
 use quote::ToTokens;
 use std::{
@@ -54,6 +56,8 @@ impl<'a> Writer<'a> {
             BodyNode::RawExpr(exp) => self.write_raw_expr(exp.span()),
             BodyNode::ForLoop(forloop) => self.write_for_loop(forloop),
             BodyNode::IfChain(ifchain) => self.write_if_chain(ifchain),
+            BodyNode::Match(chain) => self.write_match(chain),
+            BodyNode::RawHtml(raw) => self.write_raw_html(raw),
         }
     }
 
@@ -140,9 +144,24 @@ impl<'a> Writer<'a> {
 
                 condition_len + value_len + 6
             }
+            ElementAttrValue::AttrFlag(condition) => {
+                self.retrieve_formatted_expr(condition).len() + 3
+            }
             ElementAttrValue::AttrLiteral(lit) => ifmt_to_string(lit).len(),
+            ElementAttrValue::Styles(properties) => properties
+                .iter()
+                .map(|property| {
+                    property.name.to_string().len() + self.attr_value_len(&property.value) + 4
+                })
+                .sum(),
             ElementAttrValue::AttrExpr(expr) => expr.span().line_length(),
             ElementAttrValue::Shorthand(expr) => expr.span().line_length(),
+            ElementAttrValue::EventWithModifiers { modifiers, handler } => {
+                let modifiers_len: usize = modifiers.iter().map(|m| m.as_str().len() + 2).sum();
+                modifiers_len
+                    + 2
+                    + self.attr_value_len(&ElementAttrValue::EventTokens(handler.clone()))
+            }
             ElementAttrValue::EventTokens(tokens) => {
                 let location = Location::new(tokens.span().start());
 
@@ -214,11 +233,17 @@ impl<'a> Writer<'a> {
     fn write_for_loop(&mut self, forloop: &ForLoop) -> std::fmt::Result {
         write!(
             self.out,
-            "for {} in {} {{",
+            "for {} in {}",
             forloop.pat.clone().into_token_stream(),
             prettyplease::unparse_expr(&forloop.expr)
         )?;
 
+        if let Some(key) = &forloop.key {
+            write!(self.out, ", key: {}", prettyplease::unparse_expr(key))?;
+        }
+
+        write!(self.out, " {{")?;
+
         if forloop.body.is_empty() {
             write!(self.out, "}}")?;
             return Ok(());
@@ -232,6 +257,12 @@ impl<'a> Writer<'a> {
         Ok(())
     }
 
+    fn write_raw_html(&mut self, raw: &RawHtml) -> std::fmt::Result {
+        write!(self.out, "raw!(")?;
+        self.write_attribute_value(&raw.value)?;
+        write!(self.out, ")")
+    }
+
     fn write_if_chain(&mut self, ifchain: &IfChain) -> std::fmt::Result {
         // Recurse in place by setting the next chain
         let mut branch = Some(ifchain);
@@ -276,6 +307,49 @@ impl<'a> Writer<'a> {
 
         Ok(())
     }
+
+    fn write_match(&mut self, chain: &MatchChain) -> std::fmt::Result {
+        write!(
+            self.out,
+            "{} {} {{",
+            chain.match_token.to_token_stream(),
+            prettyplease::unparse_expr(&chain.expr)
+        )?;
+
+        self.out.indent_level += 1;
+        for arm in &chain.arms {
+            self.out.tabbed_line()?;
+            write!(self.out, "{} => {{", unparse_match_arm_head(arm))?;
+            if !arm.body.is_empty() {
+                self.write_body_indented(&arm.body)?;
+                self.out.tabbed_line()?;
+            }
+            write!(self.out, "}}")?;
+        }
+        self.out.indent_level -= 1;
+
+        self.out.tabbed_line()?;
+        write!(self.out, "}}")?;
+
+        Ok(())
+    }
+}
+
+/// Format the pattern and guard of a match arm by letting prettyplease format a dummy match expression
+fn unparse_match_arm_head(arm: &MatchArm) -> String {
+    let pat = &arm.pat;
+    let guard = arm
+        .guard
+        .as_ref()
+        .map(|(if_token, cond)| quote::quote! { #if_token #cond });
+    let expr: Expr = syn::parse_quote! { match () { #pat #guard => {} } };
+    let formatted = prettyplease::unparse_expr(&expr);
+
+    let mut lines = formatted.lines().skip(1);
+    match (lines.next(), lines.next()) {
+        (Some(head), Some("}")) => head.trim().trim_end_matches(" => {}").to_string(),
+        _ => quote::quote! { #pat #guard }.to_string(),
+    }
 }
 
 pub(crate) trait SpanLength {
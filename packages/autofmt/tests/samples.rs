@@ -36,6 +36,7 @@ twoway![
     long_exprs,
     long,
     manual_props,
+    match_expr,
     messy_indent,
     multirsx,
     raw_strings,
@@ -46,3 +47,10 @@ twoway![
     tinynoopt,
     trailing_expr,
 ];
+
+#[test]
+fn invalid_rsx_is_an_error() {
+    let src = "fn app() -> Element { rsx! { div { class: } } }";
+    assert!(dioxus_autofmt::try_fmt_file(src, Default::default()).is_err());
+    assert!(dioxus_autofmt::fmt_file(src, Default::default()).is_empty());
+}
@@ -16,6 +16,11 @@ pub struct BundleConfig {
     pub deb: Option<DebianSettings>,
     pub macos: Option<MacOsSettings>,
     pub windows: Option<WindowsSettings>,
+    /// The version of the installers. Defaults to the version of the package in Cargo.toml
+    pub version: Option<String>,
+    /// A command that is run for every installer once bundling is done, for example to sign it.
+    /// `{path}` in the arguments is replaced with the path of the installer, or the path is appended if no argument contains it
+    pub sign_command: Option<Vec<String>>,
 }
 
 #[cfg(feature = "cli")]
@@ -24,7 +24,8 @@ pub struct DioxusConfig {
 
     pub web: WebConfig,
 
-    #[serde(default)]
+    // The config template of `dx create` calls the section `bundler`
+    #[serde(default, alias = "bundler")]
     pub bundle: BundleConfig,
 
     #[cfg(feature = "cli")]
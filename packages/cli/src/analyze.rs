@@ -0,0 +1,321 @@
+//! Size analysis of the wasm binary of a web build, run with `dx build --analyze`
+//!
+//! The function sizes are read from the code section and attributed to crates with the names in the name section, which wasm-bindgen keeps.
+//! The report of every build is saved in the target directory, so the next build can show how the size changed.
+
+use std::{collections::HashMap, path::PathBuf};
+
+use dioxus_cli_config::CrateConfig;
+use serde::{Deserialize, Serialize};
+use wasmparser::{Name, NameSectionReader, Parser, Payload};
+
+use crate::{Error, Result};
+
+/// The number of crates and functions shown in the report
+const SHOWN_ENTRIES: usize = 15;
+
+/// The sizes of the parts of a wasm binary in bytes
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct SizeReport {
+    /// The size of the whole binary
+    pub total: u64,
+    /// The size of the function bodies
+    pub code: u64,
+    /// The size of the static data
+    pub data: u64,
+    /// The size of the functions of each crate, from largest to smallest
+    pub crates: Vec<(String, u64)>,
+    /// The largest functions, from largest to smallest
+    pub functions: Vec<(String, u64)>,
+}
+
+impl SizeReport {
+    /// Measure the functions and data of a wasm binary
+    pub fn from_wasm(wasm: &[u8]) -> Result<Self> {
+        let mut report = SizeReport {
+            total: wasm.len() as u64,
+            ..Default::default()
+        };
+        let mut imported_functions = 0;
+        let mut function_sizes = Vec::new();
+        let mut names = HashMap::new();
+
+        for payload in Parser::new(0).parse_all(wasm) {
+            match payload.map_err(invalid_wasm)? {
+                Payload::ImportSection(imports) => {
+                    for import in imports {
+                        if let wasmparser::ImportSectionEntryType::Function(_) =
+                            import.map_err(invalid_wasm)?.ty
+                        {
+                            imported_functions += 1;
+                        }
+                    }
+                }
+                Payload::CodeSectionEntry(body) => {
+                    let range = body.range();
+                    function_sizes.push((range.end - range.start) as u64);
+                }
+                Payload::DataSection(data) => {
+                    for segment in data {
+                        report.data += segment.map_err(invalid_wasm)?.data.len() as u64;
+                    }
+                }
+                Payload::CustomSection {
+                    name: "name",
+                    data,
+                    data_offset,
+                    ..
+                } => {
+                    let mut reader =
+                        NameSectionReader::new(data, data_offset).map_err(invalid_wasm)?;
+                    while !reader.eof() {
+                        let Name::Function(map) = reader.read().map_err(invalid_wasm)? else {
+                            continue;
+                        };
+                        let mut map = map.get_map().map_err(invalid_wasm)?;
+                        for _ in 0..map.get_count() {
+                            let naming = map.read().map_err(invalid_wasm)?;
+                            names.insert(naming.index, naming.name.to_string());
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        // Imported functions come first in the function index space, but they have no body
+        let mut crates = HashMap::<String, u64>::new();
+        let mut functions = Vec::with_capacity(function_sizes.len());
+        for (index, size) in function_sizes.into_iter().enumerate() {
+            let name = names
+                .remove(&(imported_functions + index as u32))
+                .unwrap_or_else(|| format!("function[{index}]"));
+            report.code += size;
+            *crates.entry(crate_name(&name).to_string()).or_default() += size;
+            functions.push((name, size));
+        }
+
+        report.crates = sorted(crates.into_iter().collect());
+        functions = sorted(functions);
+        functions.truncate(SHOWN_ENTRIES);
+        report.functions = functions;
+
+        Ok(report)
+    }
+
+    /// Print the report, with the changes since the previous build if there is one
+    pub fn print(&self, previous: Option<&SizeReport>) {
+        let change = |current: u64, previous: Option<u64>| match previous {
+            Some(previous) if previous != current => {
+                format!(" ({})", format_change(current as i64 - previous as i64))
+            }
+            _ => String::new(),
+        };
+
+        println!("\n📊 Wasm size");
+        println!(
+            "  total {}{}",
+            format_size(self.total),
+            change(self.total, previous.map(|p| p.total))
+        );
+        println!(
+            "  code  {}{}",
+            format_size(self.code),
+            change(self.code, previous.map(|p| p.code))
+        );
+        println!(
+            "  data  {}{}",
+            format_size(self.data),
+            change(self.data, previous.map(|p| p.data))
+        );
+
+        println!("\n📦 Largest crates");
+        for (name, size) in self.crates.iter().take(SHOWN_ENTRIES) {
+            let before = previous.map(|p| find(&p.crates, name).unwrap_or_default());
+            println!(
+                "  {:>10} {:>5.1}%  {name}{}",
+                format_size(*size),
+                percent(*size, self.code),
+                change(*size, before)
+            );
+        }
+
+        // Crates that are no longer in the binary don't show up in the list above
+        if let Some(previous) = previous {
+            for (name, size) in &previous.crates {
+                if find(&self.crates, name).is_none() {
+                    println!("  removed {name} ({})", format_change(-(*size as i64)));
+                }
+            }
+        }
+
+        println!("\n🔍 Largest functions");
+        for (name, size) in &self.functions {
+            println!(
+                "  {:>10} {:>5.1}%  {name}",
+                format_size(*size),
+                percent(*size, self.code)
+            );
+        }
+
+        println!();
+    }
+}
+
+/// Analyze the wasm binary of a web build, print the report and save it for the next build
+pub fn analyze(config: &CrateConfig) -> Result<()> {
+    let wasm_path = config
+        .out_dir()
+        .join("assets")
+        .join("dioxus")
+        .join(format!("{}_bg.wasm", config.dioxus_config.application.name));
+    let wasm = std::fs::read(wasm_path)?;
+    let report = SizeReport::from_wasm(&wasm)?;
+
+    let report_path = report_path(config);
+    let previous = std::fs::read_to_string(&report_path)
+        .ok()
+        .and_then(|previous| serde_json::from_str::<SizeReport>(&previous).ok());
+    report.print(previous.as_ref());
+
+    for warning in size_warnings(config) {
+        log::warn!("{warning}");
+    }
+
+    if let Some(parent) = report_path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::write(&report_path, serde_json::to_string(&report).unwrap())?;
+
+    Ok(())
+}
+
+/// The reports of different profiles are saved separately, so the sizes are compared with the same profile
+fn report_path(config: &CrateConfig) -> PathBuf {
+    let profile = config
+        .custom_profile
+        .as_deref()
+        .unwrap_or(if config.release { "release" } else { "debug" });
+    config.target_dir.join("dx-analyze").join(format!(
+        "{}-{profile}.json",
+        config.dioxus_config.application.name
+    ))
+}
+
+/// Settings that make the binary larger than it needs to be
+fn size_warnings(config: &CrateConfig) -> Vec<String> {
+    let mut warnings = Vec::new();
+
+    if !config.release && config.custom_profile.is_none() {
+        warnings.push(
+            "This is a debug build. Build with --release to see the size of the binary that is shipped."
+                .to_string(),
+        );
+    }
+
+    let wasm_opt = config
+        .dioxus_config
+        .application
+        .tools
+        .get("binaryen")
+        .and_then(|binaryen| binaryen.get("wasm_opt"))
+        .and_then(|wasm_opt| wasm_opt.as_bool())
+        .unwrap_or(false);
+    if !wasm_opt {
+        warnings.push(
+            "wasm-opt is not enabled. Add `binaryen = { wasm_opt = true }` to `[application.tools]` in Dioxus.toml to optimize the binary."
+                .to_string(),
+        );
+    } else if !crate::tools::Tool::Binaryen.is_installed() {
+        warnings.push(
+            "wasm-opt is enabled, but Binaryen tool not found, you can use `dx tool add binaryen` to install it."
+                .to_string(),
+        );
+    }
+
+    warnings
+}
+
+/// The crate a function belongs to, from its demangled name like `<dioxus_core::Scope as Drop>::drop`
+fn crate_name(function: &str) -> &str {
+    let name = function.trim_start_matches(['<', '&', '*', '(', '[']);
+    let name = ["mut ", "const ", "dyn "]
+        .iter()
+        .find_map(|prefix| name.strip_prefix(prefix))
+        .unwrap_or(name);
+    match name.split_once("::") {
+        Some((krate, _))
+            if !krate.is_empty() && krate.chars().all(|c| c.is_alphanumeric() || c == '_') =>
+        {
+            krate
+        }
+        _ => "[other]",
+    }
+}
+
+fn sorted(mut entries: Vec<(String, u64)>) -> Vec<(String, u64)> {
+    entries.sort_by(|(a_name, a_size), (b_name, b_size)| {
+        b_size.cmp(a_size).then_with(|| a_name.cmp(b_name))
+    });
+    entries
+}
+
+fn find(entries: &[(String, u64)], name: &str) -> Option<u64> {
+    entries
+        .iter()
+        .find(|(entry, _)| entry == name)
+        .map(|(_, size)| *size)
+}
+
+fn percent(size: u64, total: u64) -> f64 {
+    if total == 0 {
+        return 0.0;
+    }
+    size as f64 / total as f64 * 100.0
+}
+
+fn format_size(bytes: u64) -> String {
+    match bytes {
+        0..=1023 => format!("{bytes} B"),
+        1024..=1048575 => format!("{:.1} KB", bytes as f64 / 1024.0),
+        _ => format!("{:.2} MB", bytes as f64 / 1048576.0),
+    }
+}
+
+fn format_change(bytes: i64) -> String {
+    let sign = if bytes < 0 { "-" } else { "+" };
+    format!("{sign}{}", format_size(bytes.unsigned_abs()))
+}
+
+fn invalid_wasm(err: wasmparser::BinaryReaderError) -> Error {
+    Error::BuildFailed(format!("Failed to analyze the wasm binary: {err}"))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_crate_name() {
+        assert_eq!(
+            crate_name("dioxus_core::virtual_dom::VirtualDom::rebuild"),
+            "dioxus_core"
+        );
+        assert_eq!(
+            crate_name("<dioxus_core::scopes::ScopeId as core::fmt::Debug>::fmt"),
+            "dioxus_core"
+        );
+        assert_eq!(
+            crate_name("<&mut serde_json::de::Deserializer<R> as serde::de::Deserializer>::deserialize_any"),
+            "serde_json"
+        );
+        assert_eq!(crate_name("memcpy"), "[other]");
+    }
+
+    #[test]
+    fn test_format_size() {
+        assert_eq!(format_size(512), "512 B");
+        assert_eq!(format_size(2048), "2.0 KB");
+        assert_eq!(format_change(-3 * 1048576), "-3.00 MB");
+    }
+}
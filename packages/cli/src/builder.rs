@@ -62,6 +62,21 @@ impl ExecWithRustFlagsSetter for subprocess::Exec {
     }
 }
 
+/// Install the wasm32-unknown-unknown target with rustup if it is missing
+pub fn install_wasm_target() -> Result<()> {
+    let wasm_check_command = std::process::Command::new("rustup")
+        .args(["show"])
+        .output()?;
+    let wasm_check_output = String::from_utf8(wasm_check_command.stdout).unwrap();
+    if !wasm_check_output.contains("wasm32-unknown-unknown") {
+        log::info!("wasm32-unknown-unknown target not detected, installing..");
+        let _ = std::process::Command::new("rustup")
+            .args(["target", "add", "wasm32-unknown-unknown"])
+            .output()?;
+    }
+    Ok(())
+}
+
 /// Build client (WASM).
 /// Note: `rust_flags` argument is only used for the fullstack platform.
 pub fn build(
@@ -99,16 +114,7 @@ pub fn build(
     // [1] Build the .wasm module
     log::info!("🚅 Running build command...");
 
-    let wasm_check_command = std::process::Command::new("rustup")
-        .args(["show"])
-        .output()?;
-    let wasm_check_output = String::from_utf8(wasm_check_command.stdout).unwrap();
-    if !wasm_check_output.contains("wasm32-unknown-unknown") {
-        log::info!("wasm32-unknown-unknown target not detected, installing..");
-        let _ = std::process::Command::new("rustup")
-            .args(["target", "add", "wasm32-unknown-unknown"])
-            .output()?;
-    }
+    install_wasm_target()?;
 
     let cmd = subprocess::Exec::cmd("cargo")
         .set_rust_flags(rust_flags)
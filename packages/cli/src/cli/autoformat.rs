@@ -93,7 +93,8 @@ fn refactor_file(
         s = format_rust(&s)?;
     }
 
-    let edits = dioxus_autofmt::fmt_file(&s, indent);
+    let edits = dioxus_autofmt::try_fmt_file(&s, indent)
+        .map_err(|err| Error::ParseError(format!("Syntax Error:\n{}", err)))?;
     let out = dioxus_autofmt::apply_formats(&s, edits);
 
     if file == "-" {
@@ -137,7 +138,8 @@ fn format_file(
         }
     }
 
-    let edits = dioxus_autofmt::fmt_file(&contents, indent);
+    let edits = dioxus_autofmt::try_fmt_file(&contents, indent)
+        .map_err(|err| Error::ParseError(format!("Syntax Error:\n{}", err)))?;
     let len = edits.len();
 
     if !edits.is_empty() {
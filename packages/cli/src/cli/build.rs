@@ -108,6 +108,15 @@ impl Build {
             }
         };
 
+        if self.build.analyze {
+            match platform {
+                Platform::Web | Platform::Fullstack => crate::analyze::analyze(&crate_config)?,
+                Platform::Desktop => {
+                    log::warn!("Only the wasm binary of web builds can be analyzed")
+                }
+            }
+        }
+
         let temp = gen_page(&crate_config, build_result.assets.as_ref(), false);
 
         let mut file = std::fs::File::create(crate_config.out_dir().join("index.html"))?;
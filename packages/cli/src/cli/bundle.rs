@@ -11,6 +11,7 @@ use crate::{build_desktop, cfg::ConfigOptsBundle};
 #[derive(Clone, Debug, Parser)]
 #[clap(name = "bundle")]
 pub struct Bundle {
+    /// The installers to create: macos, app, ios, msi, nsis, deb, rpm, appimage or dmg [default: all installers of the current platform]
     #[clap(long)]
     pub package: Option<Vec<String>>,
     #[clap(flatten)]
@@ -22,6 +23,7 @@ pub enum PackageType {
     MacOsBundle,
     IosBundle,
     WindowsMsi,
+    Nsis,
     Deb,
     Rpm,
     AppImage,
@@ -34,9 +36,10 @@ impl FromStr for PackageType {
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
-            "macos" => Ok(PackageType::MacOsBundle),
+            "macos" | "app" => Ok(PackageType::MacOsBundle),
             "ios" => Ok(PackageType::IosBundle),
             "msi" => Ok(PackageType::WindowsMsi),
+            "nsis" => Ok(PackageType::Nsis),
             "deb" => Ok(PackageType::Deb),
             "rpm" => Ok(PackageType::Rpm),
             "appimage" => Ok(PackageType::AppImage),
@@ -52,6 +55,7 @@ impl From<PackageType> for tauri_bundler::PackageType {
             PackageType::MacOsBundle => tauri_bundler::PackageType::MacOsBundle,
             PackageType::IosBundle => tauri_bundler::PackageType::IosBundle,
             PackageType::WindowsMsi => tauri_bundler::PackageType::WindowsMsi,
+            PackageType::Nsis => tauri_bundler::PackageType::Nsis,
             PackageType::Deb => tauri_bundler::PackageType::Deb,
             PackageType::Rpm => tauri_bundler::PackageType::Rpm,
             PackageType::AppImage => tauri_bundler::PackageType::AppImage,
@@ -107,6 +111,14 @@ impl Bundle {
                 .set_src_path(Some(crate_config.crate_dir.display().to_string())),
         ];
 
+        let version = crate_config
+            .dioxus_config
+            .bundle
+            .version
+            .clone()
+            .unwrap_or_else(|| package.version().to_string());
+        let sign_command = crate_config.dioxus_config.bundle.sign_command.clone();
+
         let mut bundle_settings: BundleSettings = crate_config.dioxus_config.bundle.clone().into();
         if cfg!(windows) {
             let windows_icon_override = crate_config
@@ -154,7 +166,7 @@ impl Bundle {
             .project_out_directory(crate_config.out_dir())
             .package_settings(PackageSettings {
                 product_name: crate_config.dioxus_config.application.name.clone(),
-                version: package.version().to_string(),
+                version,
                 description: package.description().unwrap_or_default().to_string(),
                 homepage: Some(package.homepage().unwrap_or_default().to_string()),
                 authors: Some(Vec::from(package.authors())),
@@ -163,12 +175,11 @@ impl Bundle {
             .binaries(binaries)
             .bundle_settings(bundle_settings);
         if let Some(packages) = self.package {
-            settings = settings.package_types(
-                packages
-                    .into_iter()
-                    .map(|p| p.parse::<PackageType>().unwrap().into())
-                    .collect(),
-            );
+            let packages = packages
+                .into_iter()
+                .map(|p| p.parse::<PackageType>().map(Into::into))
+                .collect::<Result<Vec<_>, _>>()?;
+            settings = settings.package_types(packages);
         }
 
         if let Some(target) = &self.build.target {
@@ -181,13 +192,42 @@ impl Bundle {
         #[cfg(target_os = "macos")]
         std::env::set_var("CI", "true");
 
-        tauri_bundler::bundle::bundle_project(settings.unwrap()).unwrap_or_else(|err|{
+        let bundles = tauri_bundler::bundle::bundle_project(settings.unwrap()).unwrap_or_else(|err|{
             #[cfg(target_os = "macos")]
             panic!("Failed to bundle project: {:#?}\nMake sure you have automation enabled in your terminal (https://github.com/tauri-apps/tauri/issues/3055#issuecomment-1624389208) and full disk access enabled for your terminal (https://github.com/tauri-apps/tauri/issues/3055#issuecomment-1624389208)", err);
             #[cfg(not(target_os = "macos"))]
             panic!("Failed to bundle project: {:#?}", err);
         });
 
+        for path in bundles.iter().flat_map(|bundle| &bundle.bundle_paths) {
+            if let Some(sign_command) = &sign_command {
+                sign(sign_command, path)?;
+            }
+            log::info!("📦 Bundled {}", path.display());
+        }
+
         Ok(())
     }
 }
+
+/// Run the sign command of the bundle config for an installer
+fn sign(sign_command: &[String], path: &std::path::Path) -> Result<()> {
+    let Some((program, args)) = sign_command.split_first() else {
+        return custom_error!("The sign command of the bundle config is empty.");
+    };
+    let path = path.display().to_string();
+
+    let mut cmd = Command::new(program);
+    if args.iter().any(|arg| arg.contains("{path}")) {
+        cmd.args(args.iter().map(|arg| arg.replace("{path}", &path)));
+    } else {
+        cmd.args(args).arg(&path);
+    }
+
+    log::info!("🔏 Signing {path}...");
+    let status = cmd.status()?;
+    if !status.success() {
+        return custom_error!("Signing {path} failed with {status}.");
+    }
+    Ok(())
+}
@@ -53,6 +53,11 @@ pub struct ConfigOptsBuild {
     #[clap(long)]
     pub target: Option<String>,
 
+    /// Print a report of the size of the wasm binary and how it changed since the last build [default: false]
+    #[clap(long)]
+    #[serde(default)]
+    pub analyze: bool,
+
     /// Extra arguments passed to cargo build
     #[clap(last = true)]
     pub cargo_args: Vec<String>,
@@ -72,6 +77,7 @@ impl From<ConfigOptsServe> for ConfigOptsBuild {
             server_feature: serve.server_feature,
             skip_assets: serve.skip_assets,
             force_debug: serve.force_debug,
+            analyze: false,
             cargo_args: serve.cargo_args,
         }
     }
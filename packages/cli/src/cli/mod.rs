@@ -9,6 +9,7 @@ pub mod create;
 pub mod init;
 pub mod plugin;
 pub mod serve;
+pub mod test;
 pub mod translate;
 pub mod version;
 
@@ -20,7 +21,6 @@ use crate::{
 };
 use clap::{Parser, Subcommand};
 use dioxus_cli_config::CrateConfig;
-use html_parser::Dom;
 use serde::Deserialize;
 use std::{
     fmt::Display,
@@ -69,6 +69,9 @@ pub enum Commands {
     /// Bundle the Rust desktop app and all of its assets.
     Bundle(bundle::Bundle),
 
+    /// Run the component tests of the project in a headless browser.
+    Test(test::Test),
+
     /// Print the version of this extension
     #[clap(name = "version")]
     Version(version::Version),
@@ -105,6 +108,7 @@ impl Display for Commands {
             Commands::Autoformat(_) => write!(f, "fmt"),
             Commands::Check(_) => write!(f, "check"),
             Commands::Bundle(_) => write!(f, "bundle"),
+            Commands::Test(_) => write!(f, "test"),
 
             #[cfg(feature = "plugin")]
             Commands::Plugin(_) => write!(f, "plugin"),
@@ -0,0 +1,163 @@
+use clap::ValueEnum;
+
+use super::*;
+
+/// Run the component tests of the project in a headless browser.
+///
+/// The tests are compiled for wasm and run with `wasm-bindgen-test-runner`, so they are written with `#[wasm_bindgen_test]`.
+/// Tests only run in the browser if the test crate calls `wasm_bindgen_test_configure!(run_in_browser);`
+#[derive(Clone, Debug, Parser)]
+#[clap(name = "test")]
+pub struct Test {
+    /// Only run the tests whose names contain the filter
+    pub filter: Option<String>,
+
+    /// The browser to run the tests in
+    #[clap(long, value_enum, default_value_t = Browser::Chrome)]
+    pub browser: Browser,
+
+    /// The path of the webdriver for the browser. The driver is looked up in PATH if this is not set
+    #[clap(long)]
+    pub driver: Option<PathBuf>,
+
+    /// Open the browser window instead of running the browser headless [default: false]
+    #[clap(long)]
+    pub no_headless: bool,
+
+    /// Test in release mode [default: false]
+    #[clap(long, short)]
+    pub release: bool,
+
+    /// Space separated list of features to activate
+    #[clap(long)]
+    pub features: Option<Vec<String>>,
+
+    /// Extra arguments passed to cargo test
+    #[clap(last = true)]
+    pub cargo_args: Vec<String>,
+}
+
+/// A browser that can be controlled over webdriver
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum Browser {
+    Chrome,
+    Firefox,
+    Safari,
+}
+
+impl Browser {
+    /// The environment variable `wasm-bindgen-test-runner` reads the path of the driver from
+    fn driver_env(self) -> &'static str {
+        match self {
+            Browser::Chrome => "CHROMEDRIVER",
+            Browser::Firefox => "GECKODRIVER",
+            Browser::Safari => "SAFARIDRIVER",
+        }
+    }
+
+    /// The name of the driver executable
+    fn driver_name(self) -> &'static str {
+        match self {
+            Browser::Chrome => "chromedriver",
+            Browser::Firefox => "geckodriver",
+            Browser::Safari => "safaridriver",
+        }
+    }
+}
+
+impl Test {
+    /// Run the tests. The process exits with the exit code of the tests if they fail, so CI fails with them
+    pub fn test(self, bin: Option<PathBuf>) -> Result<()> {
+        let crate_config = dioxus_cli_config::CrateConfig::new(bin)?;
+
+        crate::builder::install_wasm_target()?;
+        let runner = test_runner(&crate_config.crate_dir)?;
+
+        let mut cmd = Command::new("cargo");
+        cmd.current_dir(&crate_config.crate_dir)
+            .env("CARGO_TARGET_DIR", &crate_config.target_dir)
+            .env("CARGO_TARGET_WASM32_UNKNOWN_UNKNOWN_RUNNER", runner)
+            .env(
+                self.browser.driver_env(),
+                self.driver
+                    .unwrap_or_else(|| PathBuf::from(self.browser.driver_name())),
+            )
+            .args(["test", "--target", "wasm32-unknown-unknown"]);
+
+        // Other drivers in the environment would take precedence over the selected browser
+        for browser in Browser::value_variants() {
+            if *browser != self.browser {
+                cmd.env_remove(browser.driver_env());
+            }
+        }
+        if self.no_headless {
+            cmd.env("NO_HEADLESS", "1");
+        }
+        if self.release {
+            cmd.arg("--release");
+        }
+        if let Some(features) = self.features {
+            cmd.arg("--features").arg(features.join(" "));
+        }
+        cmd.args(self.cargo_args);
+        if let Some(filter) = self.filter {
+            cmd.arg("--").arg(filter);
+        }
+
+        log::info!("🧪 Running tests in {}...", self.browser.driver_name());
+        let status = cmd.status()?;
+        if !status.success() {
+            std::process::exit(status.code().unwrap_or(1));
+        }
+
+        Ok(())
+    }
+}
+
+/// Find a `wasm-bindgen-test-runner` with the version of wasm-bindgen the project uses, or install it into the tools directory
+///
+/// The runner can only run tests compiled with the same version of wasm-bindgen.
+fn test_runner(crate_dir: &std::path::Path) -> Result<PathBuf> {
+    let metadata = cargo_metadata::MetadataCommand::new()
+        .current_dir(crate_dir)
+        .exec()
+        .map_err(Error::CargoMetadata)?;
+    let Some(wasm_bindgen) = metadata
+        .packages
+        .iter()
+        .find(|package| package.name == "wasm-bindgen")
+    else {
+        return custom_error!(
+            "The project doesn't depend on wasm-bindgen, so it has no wasm tests."
+        );
+    };
+    let version = wasm_bindgen.version.to_string();
+
+    let installed = Command::new("wasm-bindgen-test-runner")
+        .arg("--version")
+        .output()
+        .is_ok_and(|output| String::from_utf8_lossy(&output.stdout).contains(&version));
+    if installed {
+        return Ok(PathBuf::from("wasm-bindgen-test-runner"));
+    }
+
+    let root = crate::tools::tools_path().join(format!("wasm-bindgen-{version}"));
+    let runner = root
+        .join("bin")
+        .join("wasm-bindgen-test-runner")
+        .with_extension(std::env::consts::EXE_EXTENSION);
+    if !runner.is_file() {
+        log::info!("📦 Installing wasm-bindgen-test-runner {version}...");
+        let status = Command::new("cargo")
+            .args(["install", "wasm-bindgen-cli", "--locked", "--version"])
+            .arg(format!("={version}"))
+            .arg("--root")
+            .arg(&root)
+            .status()?;
+        if !status.success() {
+            return custom_error!("Installing wasm-bindgen-test-runner {version} failed.");
+        }
+    }
+
+    Ok(runner)
+}
@@ -1,6 +1,6 @@
 use std::process::exit;
 
-use dioxus_rsx::{BodyNode, CallBody};
+use dioxus_rsx::{BodyNode, CallBody, Component};
 
 use super::*;
 
@@ -13,6 +13,14 @@ pub struct Translate {
     #[clap(short, long)]
     pub component: bool,
 
+    /// Write a component for each top-level element, and an `app` component that renders them
+    #[clap(long)]
+    pub split: bool,
+
+    /// Keep html comments as raw! nodes instead of dropping them
+    #[clap(long)]
+    pub comments: bool,
+
     /// Input file
     #[clap(short, long)]
     pub file: Option<String>,
@@ -21,21 +29,29 @@ pub struct Translate {
     #[clap(short, long)]
     pub raw: Option<String>,
 
+    /// Download the html to translate from a URL
+    #[clap(short, long)]
+    pub url: Option<String>,
+
     /// Output file, stdout if not present
     #[arg(short, long)]
     pub output: Option<PathBuf>,
 }
 
 impl Translate {
-    pub fn translate(self) -> Result<()> {
+    pub async fn translate(self) -> Result<()> {
         // Get the right input for the translation
-        let contents = determine_input(self.file, self.raw)?;
-
-        // Ensure we're loading valid HTML
-        let dom = html_parser::Dom::parse(&contents)?;
+        let contents = determine_input(self.file, self.raw, self.url).await?;
 
         // Convert the HTML to RSX
-        let out = convert_html_to_formatted_rsx(&dom, self.component);
+        let component = self.component || self.split;
+        let options = rsx_rosetta::ConvertOptions::new()
+            .with_extracted_svgs(component)
+            .with_comments(self.comments);
+        let out = match self.split {
+            true => convert_html_to_split_components(&contents, &options),
+            false => convert_html_to_formatted_rsx(&contents, &options, component),
+        };
 
         // Write the output
         match self.output {
@@ -47,33 +63,130 @@ impl Translate {
     }
 }
 
-pub fn convert_html_to_formatted_rsx(dom: &Dom, component: bool) -> String {
-    let callbody = rsx_rosetta::rsx_from_html(dom);
+pub fn convert_html_to_formatted_rsx(
+    html: &str,
+    options: &rsx_rosetta::ConvertOptions,
+    component: bool,
+) -> String {
+    let conversion = rsx_rosetta::convert_html(html, options);
 
     match component {
-        true => write_callbody_with_icon_section(callbody),
-        false => dioxus_autofmt::write_block_out(callbody).unwrap(),
+        true => write_callbody_with_icon_section(conversion),
+        false => dioxus_autofmt::write_block_out(body_with_head(&conversion)).unwrap(),
     }
 }
 
-fn write_callbody_with_icon_section(mut callbody: CallBody) -> String {
-    let mut svgs = vec![];
+/// Convert the html into a component for each top-level element, named after its id or tag
+pub fn convert_html_to_split_components(
+    html: &str,
+    options: &rsx_rosetta::ConvertOptions,
+) -> String {
+    let conversion = rsx_rosetta::convert_html(html, options);
 
-    rsx_rosetta::collect_svgs(&mut callbody.roots, &mut svgs);
+    let mut app = conversion.head.clone();
+    let mut components = Vec::new();
+    for root in conversion.body.roots.iter().cloned() {
+        let BodyNode::Element(element) = &root else {
+            app.push(root);
+            continue;
+        };
+        let name = unique_component_name(&component_name(element), &components);
+        app.push(BodyNode::Component(Component {
+            name: syn::parse_str(&name).unwrap(),
+            prop_gen_args: None,
+            fields: Vec::new(),
+            children: Vec::new(),
+            manual_props: None,
+            brace: Default::default(),
+        }));
+        components.push((name, root));
+    }
 
-    let mut out = write_component_body(dioxus_autofmt::write_block_out(callbody).unwrap());
+    let mut out = write_component("app", CallBody { roots: app });
+    for (name, root) in components {
+        out.push_str("\n\n");
+        out.push_str(&write_component(&name, CallBody { roots: vec![root] }));
+    }
+    if !conversion.svgs.is_empty() {
+        write_svg_section(&mut out, conversion.svgs);
+    }
+
+    out
+}
 
-    if !svgs.is_empty() {
-        write_svg_section(&mut out, svgs);
+/// The name of the component for a top-level element: `<nav id="main-nav">` is `MainNav` and `<footer>` is `Footer`
+fn component_name(element: &dioxus_rsx::Element) -> String {
+    let id = element
+        .attributes
+        .iter()
+        .find_map(|attribute| match attribute {
+            dioxus_rsx::AttributeType::Named(dioxus_rsx::ElementAttrNamed {
+                attr:
+                    dioxus_rsx::ElementAttr {
+                        name: dioxus_rsx::ElementAttrName::BuiltIn(name),
+                        value: dioxus_rsx::ElementAttrValue::AttrLiteral(value),
+                    },
+                ..
+            }) if name == "id" => value.source.as_ref().map(|id| id.value()),
+            _ => None,
+        });
+
+    let name = pascal_case(&id.unwrap_or_else(|| element.name.to_string()));
+    match name.starts_with(|c: char| c.is_ascii_alphabetic()) {
+        true => name,
+        false => format!("Section{name}"),
+    }
+}
+
+fn unique_component_name(name: &str, components: &[(String, BodyNode)]) -> String {
+    let taken = |name: &str| components.iter().any(|(component, _)| component == name);
+    if !taken(name) {
+        return name.to_string();
+    }
+    (2..)
+        .map(|idx| format!("{name}{idx}"))
+        .find(|name| !taken(name))
+        .unwrap()
+}
+
+fn pascal_case(name: &str) -> String {
+    name.split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(|word| {
+            let mut chars = word.chars();
+            let first = chars.next().unwrap().to_ascii_uppercase();
+            std::iter::once(first).chain(chars).collect::<String>()
+        })
+        .collect()
+}
+
+/// The head of a document goes before the body, so its styles and scripts are still loaded
+fn body_with_head(conversion: &rsx_rosetta::Conversion) -> CallBody {
+    CallBody {
+        roots: conversion
+            .head
+            .iter()
+            .chain(&conversion.body.roots)
+            .cloned()
+            .collect(),
+    }
+}
+
+fn write_callbody_with_icon_section(conversion: rsx_rosetta::Conversion) -> String {
+    let mut out = write_component("component", body_with_head(&conversion));
+
+    if !conversion.svgs.is_empty() {
+        write_svg_section(&mut out, conversion.svgs);
     }
 
     out
 }
 
-fn write_component_body(raw: String) -> String {
-    let mut out = String::from("fn component() -> Element {\n    rsx! {");
-    indent_and_write(&raw, 1, &mut out);
-    out.push_str("    })\n}");
+fn write_component(name: &str, body: CallBody) -> String {
+    let raw = dioxus_autofmt::write_block_out(body).unwrap();
+    let mut out = format!("fn {name}() -> Element {{\n    rsx! {{\n");
+    indent_and_write(&raw, 2, &mut out);
+    out.push_str("    }\n}");
     out
 }
 
@@ -84,28 +197,44 @@ fn write_svg_section(out: &mut String, svgs: Vec<BodyNode>) {
         let raw = dioxus_autofmt::write_block_out(CallBody { roots: vec![icon] }).unwrap();
         out.push_str("\n\n    pub fn icon_");
         out.push_str(&idx.to_string());
-        out.push_str("() -> Element {\n        rsx! {");
-        indent_and_write(&raw, 2, out);
-        out.push_str("        })\n    }");
+        out.push_str("() -> Element {\n        rsx! {\n");
+        indent_and_write(&raw, 3, out);
+        out.push_str("        }\n    }");
     }
 
     out.push_str("\n}");
 }
 
+/// Write the lines of a block at the indent level, whatever the indent of the block was
 fn indent_and_write(raw: &str, idx: usize, out: &mut String) {
-    for line in raw.lines() {
+    let lines = raw.lines().filter(|line| !line.trim().is_empty());
+    let common_indent = lines
+        .clone()
+        .map(|line| line.len() - line.trim_start().len())
+        .min()
+        .unwrap_or_default();
+    for line in lines {
         for _ in 0..idx {
             out.push_str("    ");
         }
-        out.push_str(line);
+        out.push_str(&line[common_indent..]);
         out.push('\n');
     }
 }
 
-fn determine_input(file: Option<String>, raw: Option<String>) -> Result<String> {
-    // Make sure not both are specified
-    if file.is_some() && raw.is_some() {
-        log::error!("Only one of --file or --raw should be specified.");
+async fn determine_input(
+    file: Option<String>,
+    raw: Option<String>,
+    url: Option<String>,
+) -> Result<String> {
+    // Make sure only one input is specified
+    if [file.is_some(), raw.is_some(), url.is_some()]
+        .into_iter()
+        .filter(|input| *input)
+        .count()
+        > 1
+    {
+        log::error!("Only one of --file, --raw or --url should be specified.");
         exit(0);
     }
 
@@ -117,9 +246,16 @@ fn determine_input(file: Option<String>, raw: Option<String>) -> Result<String>
         return Ok(std::fs::read_to_string(file)?);
     }
 
+    if let Some(url) = url {
+        let download = async { reqwest::get(&url).await?.error_for_status()?.text().await };
+        return download
+            .await
+            .map_err(|err| Error::CustomError(format!("Failed to download {url}: {err}")));
+    }
+
     // If neither exist, we try to read from stdin
     if atty::is(atty::Stream::Stdin) {
-        return custom_error!("No input file, source, or stdin to translate from.");
+        return custom_error!("No input file, source, url or stdin to translate from.");
     }
 
     let mut buffer = String::new();
@@ -132,7 +268,44 @@ fn determine_input(file: Option<String>, raw: Option<String>) -> Result<String>
 fn generates_svgs() {
     let st = include_str!("../../tests/svg.html");
 
-    let out = convert_html_to_formatted_rsx(&html_parser::Dom::parse(st).unwrap(), true);
+    let options = rsx_rosetta::ConvertOptions::new().with_extracted_svgs(true);
+    let out = convert_html_to_formatted_rsx(st, &options, true);
 
     println!("{}", out);
 }
+
+#[test]
+fn splits_top_level_elements() {
+    let html = r#"<nav id="main-nav"><a href="/">Home</a></nav><section>one</section><section>two</section>"#;
+
+    let out = convert_html_to_split_components(html, &rsx_rosetta::ConvertOptions::new());
+
+    assert_eq!(
+        out,
+        r#"fn app() -> Element {
+    rsx! {
+        MainNav {}
+        Section {}
+        Section2 {}
+    }
+}
+
+fn MainNav() -> Element {
+    rsx! {
+        nav { id: "main-nav", a { href: "/", "Home" } }
+    }
+}
+
+fn Section() -> Element {
+    rsx! {
+        section { "one" }
+    }
+}
+
+fn Section2() -> Element {
+    rsx! {
+        section { "two" }
+    }
+}"#
+    );
+}
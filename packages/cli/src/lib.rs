@@ -4,6 +4,7 @@
 
 pub const DIOXUS_CLI_VERSION: &str = "0.4.1";
 
+pub mod analyze;
 mod assets;
 pub mod builder;
 pub mod server;
@@ -45,6 +45,7 @@ async fn main() -> anyhow::Result<()> {
     match args.action {
         Translate(opts) => opts
             .translate()
+            .await
             .context(error_wrapper("Translation of HTML into RSX failed")),
 
         Create(opts) => opts
@@ -114,6 +115,10 @@ async fn main() -> anyhow::Result<()> {
                     .bundle(Some(bin.clone()))
                     .context(error_wrapper("Bundling project failed")),
 
+                Test(opts) => opts
+                    .test(Some(bin.clone()))
+                    .context(error_wrapper("Testing project failed")),
+
                 _ => unreachable!(),
             }
         }
@@ -2,7 +2,7 @@ use crate::{cfg::ConfigOptsServe, BuildResult, Result};
 use dioxus_cli_config::CrateConfig;
 
 use cargo_metadata::diagnostic::Diagnostic;
-use dioxus_core::Template;
+use dioxus_hot_reload::HotReloadMsg;
 use dioxus_html::HtmlCtx;
 use dioxus_rsx::hot_reload::*;
 use notify::{RecommendedWatcher, Watcher};
@@ -27,6 +27,7 @@ async fn setup_file_watcher<F: Fn() -> Result<BuildResult> + Send + 'static>(
     // file watcher: check file change
     let allow_watch_path = config.dioxus_config.web.watcher.watch_path.clone();
 
+    let watch_assets = hot_reload.is_some();
     let watcher_config = config.clone();
     let mut watcher = notify::recommended_watcher(move |info: notify::Result<notify::Event>| {
         let config = watcher_config.clone();
@@ -40,12 +41,26 @@ async fn setup_file_watcher<F: Fn() -> Result<BuildResult> + Send + 'static>(
                         if let Some(hot_reload) = &hot_reload {
                             // find changes to the rsx in the file
                             let mut rsx_file_map = hot_reload.file_map.lock().unwrap();
-                            let mut messages: Vec<Template> = Vec::new();
+                            let mut messages: Vec<HotReloadMsg> = Vec::new();
 
                             // In hot reload mode, we only need to rebuild if non-rsx code is changed
                             needs_full_rebuild = false;
 
                             for path in &e.paths {
+                                // assets are copied to the output directory and reloaded by the client without rebuilding
+                                if let Some(asset) =
+                                    dioxus_hot_reload::asset_path(&config.asset_dir(), path)
+                                {
+                                    let out_path = config.out_dir().join(&asset);
+                                    if let Err(err) = fs::copy(path, &out_path) {
+                                        log::error!("Failed to copy asset {:?}: {}", path, err);
+                                        needs_full_rebuild = true;
+                                        break;
+                                    }
+                                    messages.push(HotReloadMsg::UpdateAsset(asset));
+                                    continue;
+                                }
+
                                 // if this is not a rust file, rebuild the whole project
                                 if path.extension().and_then(|p| p.to_str()) != Some("rs") {
                                     needs_full_rebuild = true;
@@ -64,7 +79,9 @@ async fn setup_file_watcher<F: Fn() -> Result<BuildResult> + Send + 'static>(
 
                                 match rsx_file_map.update_rsx(path, &config.crate_dir) {
                                     Ok(UpdateResult::UpdatedRsx(msgs)) => {
-                                        messages.extend(msgs);
+                                        messages.extend(
+                                            msgs.into_iter().map(HotReloadMsg::UpdateTemplate),
+                                        );
                                         needs_full_rebuild = false;
                                     }
                                     Ok(UpdateResult::NeedsRebuild) => {
@@ -127,7 +144,7 @@ async fn setup_file_watcher<F: Fn() -> Result<BuildResult> + Send + 'static>(
     })
     .unwrap();
 
-    for sub_path in allow_watch_path {
+    for sub_path in &allow_watch_path {
         if let Err(err) = watcher.watch(
             &config.crate_dir.join(sub_path),
             notify::RecursiveMode::Recursive,
@@ -135,6 +152,17 @@ async fn setup_file_watcher<F: Fn() -> Result<BuildResult> + Send + 'static>(
             log::error!("Failed to watch path: {}", err);
         }
     }
+
+    // Watch the asset directory so changed assets can be hot reloaded
+    let asset_dir = config.asset_dir();
+    let asset_dir_watched = allow_watch_path
+        .iter()
+        .any(|sub_path| asset_dir.starts_with(config.crate_dir.join(sub_path)));
+    if watch_assets && asset_dir.is_dir() && !asset_dir_watched {
+        if let Err(err) = watcher.watch(&asset_dir, notify::RecursiveMode::Recursive) {
+            log::error!("Failed to watch the asset directory: {}", err);
+        }
+    }
     Ok(watcher)
 }
 
@@ -147,6 +175,6 @@ pub(crate) trait Platform {
 
 #[derive(Clone)]
 pub struct HotReloadState {
-    pub messages: broadcast::Sender<Template>,
+    pub messages: broadcast::Sender<HotReloadMsg>,
     pub file_map: Arc<Mutex<FileMap<HtmlCtx>>>,
 }
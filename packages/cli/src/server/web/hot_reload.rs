@@ -4,6 +4,7 @@ use axum::{
     response::IntoResponse,
     Extension,
 };
+use dioxus_hot_reload::HotReloadMsg;
 
 pub async fn hot_reload_handler(
     ws: WebSocketUpgrade,
@@ -26,8 +27,9 @@ pub async fn hot_reload_handler(
                         .collect()
                 };
                 for template in templates {
+                    let msg = HotReloadMsg::UpdateTemplate(template);
                     if socket
-                        .send(Message::Text(serde_json::to_string(&template).unwrap()))
+                        .send(Message::Text(serde_json::to_string(&msg).unwrap()))
                         .await
                         .is_err()
                     {
@@ -40,9 +42,9 @@ pub async fn hot_reload_handler(
 
         let mut rx = state.messages.subscribe();
         loop {
-            if let Ok(rsx) = rx.recv().await {
+            if let Ok(msg) = rx.recv().await {
                 if socket
-                    .send(Message::Text(serde_json::to_string(&rsx).unwrap()))
+                    .send(Message::Text(serde_json::to_string(&msg).unwrap()))
                     .await
                     .is_err()
                 {
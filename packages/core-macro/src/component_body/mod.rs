@@ -194,6 +194,25 @@ impl Parse for ComponentBody {
             ));
         }
 
+        // Every argument becomes a field on the props struct, so it needs a name
+        for input in &item_fn.sig.inputs {
+            match input {
+                FnArg::Receiver(receiver) => {
+                    return Err(Error::new(
+                        receiver.span(),
+                        "Components cannot take `self` as an argument",
+                    ));
+                }
+                FnArg::Typed(pt) if !matches!(pt.pat.as_ref(), Pat::Ident(_)) => {
+                    return Err(Error::new(
+                        pt.pat.span(),
+                        "Component arguments must be plain identifiers because they become fields of the props struct.\nDestructure the argument inside of the component body instead.",
+                    ));
+                }
+                _ => {}
+            }
+        }
+
         let has_extra_args = !item_fn.sig.inputs.is_empty();
 
         Ok(Self {
@@ -121,7 +121,7 @@ fn get_props_struct(component_body: &ComponentBody) -> ItemStruct {
 }
 
 fn get_props_docs(fn_ident: &Ident, inputs: Vec<&FnArg>) -> Vec<Attribute> {
-    if inputs.len() <= 1 {
+    if inputs.is_empty() {
         return Vec::new();
     }
 
@@ -163,6 +163,7 @@ fn get_props_docs(fn_ident: &Ident, inputs: Vec<&FnArg>) -> Vec<Attribute> {
                 Some((
                     &pt.pat,
                     &pt.ty,
+                    default_doc(pt),
                     pt.attrs.iter().find_map(|attr| {
                         if attr.path() != &parse_quote!(deprecated) {
                             return None;
@@ -190,7 +191,7 @@ fn get_props_docs(fn_ident: &Ident, inputs: Vec<&FnArg>) -> Vec<Attribute> {
         #[doc = #header]
     });
 
-    for (arg_name, arg_type, deprecation, input_arg_doc) in arg_docs {
+    for (arg_name, arg_type, default, deprecation, input_arg_doc) in arg_docs {
         let arg_name = arg_name.into_token_stream().to_string();
         let arg_type = crate::utils::format_type_string(arg_type);
 
@@ -199,6 +200,10 @@ fn get_props_docs(fn_ident: &Ident, inputs: Vec<&FnArg>) -> Vec<Attribute> {
         let prop_def_link = format!("{props_def_link}::{arg_name}");
         let mut arg_doc = format!("- [`{arg_name}`]({prop_def_link}) : `{arg_type}`");
 
+        if let Some(default) = default {
+            arg_doc.push_str(&format!(" ({default})"));
+        }
+
         if let Some(deprecation) = deprecation {
             arg_doc.push_str("<p>👎 Deprecated");
 
@@ -307,7 +312,7 @@ fn get_function(component_body: &ComponentBody) -> ItemFn {
         generics
     };
 
-    let props_docs = get_props_docs(fn_ident, inputs.iter().skip(1).collect());
+    let props_docs = get_props_docs(fn_ident, inputs.iter().collect());
 
     parse_quote! {
         #(#fn_attrs)*
@@ -321,6 +326,69 @@ fn get_function(component_body: &ComponentBody) -> ItemFn {
     }
 }
 
+/// Describe what an argument is set to when it isn't passed to the component, like `optional` or ``default: `3` ``
+///
+/// `Option` arguments and arguments with `#[props(default)]` don't need to be passed, just like the fields of a props struct.
+fn default_doc(pt: &PatType) -> Option<String> {
+    let mut optional = is_option(&pt.ty);
+    let mut default = None;
+
+    let props_args = pt
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("props"))
+        .filter_map(|attr| {
+            attr.parse_args_with(Punctuated::<Expr, Token![,]>::parse_terminated)
+                .ok()
+        })
+        .flatten();
+    for arg in props_args {
+        match arg {
+            Expr::Assign(assign) => match assign.left.to_token_stream().to_string().as_str() {
+                "default" => default = Some(assign.right.to_token_stream().to_string()),
+                "default_code" => {
+                    if let Expr::Lit(ExprLit {
+                        lit: Lit::Str(code),
+                        ..
+                    }) = assign.right.as_ref()
+                    {
+                        default = Some(code.value());
+                    }
+                }
+                _ => {}
+            },
+            Expr::Path(path) if path.path.is_ident("default") => {
+                default = Some("Default::default()".to_string())
+            }
+            Expr::Path(path) if path.path.is_ident("optional") => optional = true,
+            Expr::Unary(ExprUnary {
+                op: UnOp::Not(_),
+                expr,
+                ..
+            }) if matches!(expr.as_ref(), Expr::Path(path) if path.path.is_ident("optional")) => {
+                optional = false
+            }
+            _ => {}
+        }
+    }
+
+    match default {
+        Some(default) => Some(format!("default: `{default}`")),
+        None => optional.then(|| "optional".to_string()),
+    }
+}
+
+fn is_option(ty: &Type) -> bool {
+    match ty {
+        Type::Path(ty) if ty.qself.is_none() => ty
+            .path
+            .segments
+            .last()
+            .is_some_and(|segment| segment.ident == "Option"),
+        _ => false,
+    }
+}
+
 /// Checks if the attribute is a `#[doc]` attribute.
 fn is_attr_doc(attr: &Attribute) -> bool {
     attr.path() == &parse_quote!(doc)
@@ -46,6 +46,29 @@ pub fn rsx(tokens: TokenStream) -> TokenStream {
     }
 }
 
+/// The html! macro accepts HTML-like markup and expands to the same output as the rsx! macro.
+///
+/// Markup can be pasted as it is, and dynamic values are wrapped in braces:
+///
+/// ```rust, ignore
+/// html! {
+///     <div class="card" onclick={move |_| count += 1}>
+///         <h1>Hello {name}</h1>
+///         <MyComponent title="hi" />
+///         {for item in items {
+///             <p key="{item.id}">{item.text}</p>
+///         }}
+///     </div>
+/// }
+/// ```
+#[proc_macro]
+pub fn html(tokens: TokenStream) -> TokenStream {
+    match syn::parse::<rsx::HtmlCallBody>(tokens) {
+        Err(err) => err.to_compile_error().into(),
+        Ok(body) => RenderCallBody(body.into()).into_token_stream().into(),
+    }
+}
+
 /// The rsx! macro makes it easy for developers to write jsx-style markup in their components.
 ///
 /// The render macro automatically renders rsx - making it unhygienic.
@@ -176,6 +199,16 @@ pub(crate) const COMPONENT_ARG_CASE_CHECK_OFF: &str = "no_case_check";
 ///     __dx_inner_comp(cx)
 /// }
 /// ```
+/// * With optional props:
+/// ```rust,ignore
+/// // `Option` arguments and arguments with a default don't need to be passed in rsx
+/// #[component]
+/// fn Badge(label: String, color: Option<String>, #[props(default = 3)] size: u32) -> Element {
+///     rsx! { "{label} {color:?} {size}" }
+/// }
+///
+/// rsx! { Badge { label: "new" } }
+/// ```
 // TODO: Maybe add an option to input a custom component name through the args.
 //  I think that's unnecessary, but there might be some scenario where it could be useful.
 #[proc_macro_attribute]
@@ -248,6 +248,22 @@ mod field_info {
             }
         }
 
+        /// A slot is a named `Element` field other than `children` that accepts a block of rsx
+        pub fn is_slot(&self) -> bool {
+            if self.name == "children" {
+                return false;
+            }
+            match self.ty {
+                syn::Type::Path(ty) if ty.qself.is_none() => ty
+                    .path
+                    .segments
+                    .last()
+                    .map(|seg| seg.ident == "Element" && seg.arguments.is_empty())
+                    .unwrap_or(false),
+                _ => false,
+            }
+        }
+
         pub fn generic_ty_param(&self) -> syn::GenericParam {
             syn::GenericParam::Type(self.generic_ident.clone().into())
         }
@@ -278,6 +294,18 @@ mod field_info {
         pub strip_option: bool,
         pub ignore_option: bool,
         pub extends: Vec<Path>,
+        pub validations: Vec<PropValidation>,
+    }
+
+    /// A check that runs on a prop when the props are built in debug builds
+    #[derive(Debug, Clone)]
+    pub enum PropValidation {
+        /// `#[props(validate = path::to_fn)]`: a function that takes a reference to the prop and returns a `Result<(), impl Display>`
+        Function(syn::Expr),
+        /// `#[props(range = 1..=10)]`: the prop must be contained in the range
+        Range(syn::Expr),
+        /// `#[props(len = 1..)]`: the length of the prop must be contained in the range
+        Len(syn::Expr),
     }
 
     impl FieldBuilderAttr {
@@ -349,6 +377,19 @@ mod field_info {
                             self.doc = Some(*assign.right);
                             Ok(())
                         }
+                        "validate" => {
+                            self.validations
+                                .push(PropValidation::Function(*assign.right));
+                            Ok(())
+                        }
+                        "range" => {
+                            self.validations.push(PropValidation::Range(*assign.right));
+                            Ok(())
+                        }
+                        "len" => {
+                            self.validations.push(PropValidation::Len(*assign.right));
+                            Ok(())
+                        }
                         "default_code" => {
                             if let syn::Expr::Lit(syn::ExprLit {
                                 lit: syn::Lit::Str(code),
@@ -452,7 +493,7 @@ mod field_info {
                                 self.skip = false;
                                 Ok(())
                             }
-                            "auto_into" => {
+                            "into" | "auto_into" => {
                                 self.auto_into = false;
                                 Ok(())
                             }
@@ -516,12 +557,12 @@ mod struct_info {
     use syn::spanned::Spanned;
     use syn::{Expr, Ident};
 
-    use super::field_info::{FieldBuilderAttr, FieldInfo};
-    use super::looks_like_signal_type;
+    use super::field_info::{FieldBuilderAttr, FieldInfo, PropValidation};
     use super::util::{
         empty_type, empty_type_tuple, expr_to_single_string, make_punctuated_single,
         modify_types_generics_hack, path_to_single_string, strip_raw_ident_prefix, type_tuple,
     };
+    use super::{event_handler_type, looks_like_signal_type};
 
     #[derive(Debug)]
     pub struct StructInfo<'a> {
@@ -662,6 +703,25 @@ mod struct_info {
             }
         }
 
+        fn debug_props_impl(&self) -> TokenStream {
+            let props = self.fields.iter().map(|f| {
+                let name = f.name;
+                let label = name.to_string().trim_start_matches("r#").to_string();
+                quote! {
+                    dioxus_core::PropInfo {
+                        name: #label.to_string(),
+                        value: (&dioxus_core::DebugProp(&self.#name)).debug_prop(),
+                    }
+                }
+            });
+
+            quote! {
+                #[allow(unused_imports)]
+                use dioxus_core::{DebugPropValue, NoDebugPropValue};
+                vec![#(#props),*]
+            }
+        }
+
         pub fn builder_creation_impl(&self) -> Result<TokenStream, Error> {
             let StructInfo {
                 ref vis,
@@ -750,6 +810,7 @@ Finally, call `.build()` to create the instance of `{name}`.
             }
 
             let memoize = self.memoize_impl()?;
+            let debug_props = self.debug_props_impl();
 
             let global_fields = self
                 .extend_fields()
@@ -802,6 +863,9 @@ Finally, call `.build()` to create the instance of `{name}`.
                     fn memoize(&mut self, new: &Self) -> bool {
                         #memoize
                     }
+                    fn debug_props(&self) -> Vec<dioxus_core::PropInfo> {
+                        #debug_props
+                    }
                 }
             })
         }
@@ -1059,6 +1123,16 @@ Finally, call `.build()` to create the instance of `{name}`.
                     // If this looks like a signal type, we automatically convert it with SuperInto and use the props struct as the owner
                     quote!(with_owner(self.owner.clone(), move || dioxus_core::prelude::SuperInto::super_into(#field_name))),
                 )
+            } else if let Some((event, optional)) = event_handler_type(arg_type) {
+                // Event handlers take the closure itself, so the type of the event is inferred in rsx
+                let handler = quote!(dioxus_core::prelude::EventHandler::new(#field_name));
+                (
+                    quote!(impl FnMut(#event) + 'static),
+                    match optional {
+                        true => quote!(Some(#handler)),
+                        false => handler,
+                    },
+                )
             } else if field.builder_attr.auto_into || field.builder_attr.strip_option {
                 let marker_ident = syn::Ident::new("__Marker", proc_macro2::Span::call_site());
                 marker = Some(marker_ident.clone());
@@ -1134,6 +1208,7 @@ Finally, call `.build()` to create the instance of `{name}`.
 
             let FieldInfo {
                 name: ref field_name,
+                ty: field_type,
                 ..
             } = field;
             // Add a bump lifetime to the generics
@@ -1206,33 +1281,105 @@ Finally, call `.build()` to create the instance of `{name}`.
             let (impl_generics, _, where_clause) = generics.split_for_impl();
             let (_, ty_generics, _) = self.generics.split_for_impl();
 
+            let kind = if field.is_slot() { "slot" } else { "field" };
             let early_build_error_type_name = syn::Ident::new(
                 &format!(
-                    "{}_Error_Missing_required_field_{}",
+                    "{}_Error_Missing_required_{}_{}",
                     builder_name,
+                    kind,
                     strip_raw_ident_prefix(field_name.to_string())
                 ),
                 builder_name.span(),
             );
-            let early_build_error_message = format!("Missing required field {field_name}");
+            let field_name_str = strip_raw_ident_prefix(field_name.to_string());
+            let early_build_error_message = if field.is_slot() {
+                format!("Missing required slot `{field_name_str}`. Pass it with `{field_name_str}: rsx! {{ ... }}` or make it optional with `Option<Element>`")
+            } else {
+                format!(
+                    "Missing required prop `{field_name_str}` of type `{}`",
+                    crate::utils::format_type_string(field_type)
+                )
+            };
+            let early_build_error_label = format!("`{field_name_str}` is not set");
 
+            // The bound can never hold, so calling `build` fails with the message of the trait. The bound names a lifetime
+            // of the method so it is only checked where `build` is called
             Ok(quote! {
                 #[doc(hidden)]
                 #[allow(dead_code, non_camel_case_types, non_snake_case)]
-                pub enum #early_build_error_type_name {}
+                #[diagnostic::on_unimplemented(message = #early_build_error_message, label = #early_build_error_label)]
+                pub trait #early_build_error_type_name {}
                 #[doc(hidden)]
                 #[allow(dead_code, non_camel_case_types, missing_docs, clippy::panic)]
                 impl #impl_generics #builder_name < #( #builder_generics ),* > #where_clause {
-                    #[deprecated(
-                        note = #early_build_error_message
-                    )]
-                    pub fn build(self, _: #early_build_error_type_name) -> #name #ty_generics {
+                    pub fn build<'__missing>(self) -> #name #ty_generics
+                    where
+                        &'__missing (): #early_build_error_type_name,
+                    {
                         panic!()
                     }
                 }
             })
         }
 
+        /// Run the `#[props(validate = ..)]`, `#[props(range = ..)]` and `#[props(len = ..)]` checks in debug builds.
+        /// Failures are reported to the nearest error boundary.
+        fn validations_impl(&self) -> TokenStream {
+            let props = self.name.to_string();
+            let checks: Vec<_> = self.included_fields().flat_map(|field| {
+                let name = field.name;
+                let prop = strip_raw_ident_prefix(name.to_string());
+                let props = &props;
+                field.builder_attr.validations.iter().map(move |validation| {
+                    let check = match validation {
+                        PropValidation::Function(validate) => quote! {
+                            if let Err(err) = (#validate)(__value) {
+                                dioxus_core::report_invalid_prop(#props, #prop, err);
+                            }
+                        },
+                        PropValidation::Range(range) => quote! {
+                            let __range = #range;
+                            if !__range.contains(__value) {
+                                dioxus_core::report_invalid_prop(#props, #prop, format!("expected a value in {:?}, found {:?}", __range, __value));
+                            }
+                        },
+                        PropValidation::Len(range) => quote! {
+                            let __range = #range;
+                            if !__range.contains(&__value.len()) {
+                                dioxus_core::report_invalid_prop(#props, #prop, format!("expected a length in {:?}, found {}", __range, __value.len()));
+                            }
+                        },
+                    };
+
+                    // Range checks on optional props only apply when the prop is set
+                    match (validation, field.builder_attr.strip_option) {
+                        (PropValidation::Function(_), _) | (_, false) => quote! {
+                            {
+                                let __value = &#name;
+                                #check
+                            }
+                        },
+                        (_, true) => quote! {
+                            if let Some(__value) = &#name {
+                                #check
+                            }
+                        },
+                    }
+                })
+            }).collect();
+
+            if checks.is_empty() {
+                return quote! {};
+            }
+
+            quote! {
+                #[cfg(debug_assertions)]
+                {
+                    #( #checks )*
+                }
+            }
+        }
+
         pub fn build_method_impl(&self) -> TokenStream {
             let StructInfo {
                 ref name,
@@ -1317,6 +1464,7 @@ Finally, call `.build()` to create the instance of `{name}`.
                 }
             });
             let field_names = self.fields.iter().map(|field| field.name);
+            let validations = self.validations_impl();
             let doc = if self.builder_attr.doc {
                 match self.builder_attr.build_method_doc {
                     Some(ref doc) => quote!(#[doc = #doc]),
@@ -1370,6 +1518,9 @@ Finally, call `.build()` to create the instance of `{name}`.
                         fn memoize(&mut self, new: &Self) -> bool {
                             self.inner.memoize(&new.inner)
                         }
+                        fn debug_props(&self) -> Vec<dioxus_core::PropInfo> {
+                            self.inner.debug_props()
+                        }
                     }
 
                     #[allow(dead_code, non_camel_case_types, missing_docs)]
@@ -1378,6 +1529,7 @@ Finally, call `.build()` to create the instance of `{name}`.
                         pub fn build(self) -> #name #ty_generics {
                             let ( #(#descructuring,)* ) = self.fields;
                             #( #assignments )*
+                            #validations
                             #name {
                                 inner: #original_name {
                                     #( #field_names ),*
@@ -1395,6 +1547,7 @@ Finally, call `.build()` to create the instance of `{name}`.
                         pub fn build(self) -> #name #ty_generics {
                             let ( #(#descructuring,)* ) = self.fields;
                             #( #assignments )*
+                            #validations
                             #name {
                                 #( #field_names ),*
                             }
@@ -1428,6 +1581,17 @@ Finally, call `.build()` to create the instance of `{name}`.
         pub fn new(attrs: &[syn::Attribute]) -> Result<TypeBuilderAttr, Error> {
             let mut result = TypeBuilderAttr::default();
             for attr in attrs {
+                // `#[props(...)]` on the struct sets the defaults of every field, like `#[props(into)]`
+                if path_to_single_string(attr.path()).as_deref() == Some("props") {
+                    let as_expr = attr.parse_args_with(
+                        Punctuated::<Expr, syn::Token![,]>::parse_separated_nonempty,
+                    )?;
+                    for expr in as_expr.into_iter() {
+                        result.field_defaults.apply_meta(expr)?;
+                    }
+                    continue;
+                }
+
                 if path_to_single_string(attr.path()).as_deref() != Some("builder") {
                     continue;
                 }
@@ -1571,3 +1735,30 @@ fn looks_like_signal_type(ty: &Type) -> bool {
         _ => false,
     }
 }
+
+/// The type of the event of an `EventHandler<T>` or `Option<EventHandler<T>>` field, and whether the field is optional
+fn event_handler_type(ty: &Type) -> Option<(Type, bool)> {
+    let (ty, optional) = match type_from_inside_option(ty, true) {
+        Some(inner) => (inner, true),
+        None => (ty, false),
+    };
+    let Type::Path(ty) = ty else {
+        return None;
+    };
+    if ty.qself.is_some() {
+        return None;
+    }
+    let segment = ty.path.segments.last()?;
+    if segment.ident != "EventHandler" {
+        return None;
+    }
+    let event = match &segment.arguments {
+        PathArguments::None => parse_quote!(()),
+        PathArguments::AngleBracketed(args) if args.args.len() == 1 => match &args.args[0] {
+            syn::GenericArgument::Type(event) => event.clone(),
+            _ => return None,
+        },
+        _ => return None,
+    };
+    Some((event, optional))
+}
@@ -2,4 +2,16 @@
 fn rsx() {
     let t = trybuild::TestCases::new();
     t.compile_fail("tests/rsx/trailing-comma-0.rs");
+    t.compile_fail("tests/rsx/missing-slot.rs");
+    t.compile_fail("tests/rsx/invalid-attribute-name.rs");
+    t.compile_fail("tests/rsx/misspelled-attribute.rs");
+    t.compile_fail("tests/rsx/misspelled-element.rs");
+    t.compile_fail("tests/rsx/component-pattern-arg.rs");
+    t.compile_fail("tests/rsx/invalid-style-property.rs");
+    t.compile_fail("tests/rsx/missing-prop.rs");
+    t.compile_fail("tests/rsx/duplicate-prop.rs");
+    t.compile_fail("tests/rsx/unknown-prop.rs");
+    t.compile_fail("tests/rsx/index-key.rs");
+    t.compile_fail("tests/rsx/mixed-keys.rs");
+    t.compile_fail("tests/rsx/match-arm-error.rs");
 }
@@ -0,0 +1,11 @@
+// Given a component with a destructured argument,
+// ensure the error explains that arguments need to be named.
+
+use dioxus::prelude::*;
+
+#[component]
+fn Point((x, y): (i32, i32)) -> Element {
+    rsx! { "{x}, {y}" }
+}
+
+fn main() {}
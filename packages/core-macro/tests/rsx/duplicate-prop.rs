@@ -0,0 +1,14 @@
+// Passing a prop twice is an error at the second prop
+
+use dioxus::prelude::*;
+
+#[component]
+fn Greeting(name: String) -> Element {
+    rsx! { "Hello {name}" }
+}
+
+fn main() {
+    let _ = rsx! {
+        Greeting { name: "a", name: "b" }
+    };
+}
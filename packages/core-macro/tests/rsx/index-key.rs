@@ -0,0 +1,13 @@
+// Keys derived from the loop index are flagged
+#![deny(deprecated)]
+
+use dioxus::prelude::*;
+
+fn main() {
+    let items = ["a", "b"];
+    let _ = rsx! {
+        for (i, item) in items.iter().enumerate(), key: i {
+            "{item}"
+        }
+    };
+}
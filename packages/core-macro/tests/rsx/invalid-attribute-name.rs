@@ -0,0 +1,13 @@
+// Given an `rsx!` invocation with a JSX-style event name,
+// ensure the error suggests the dioxus spelling.
+
+use dioxus::prelude::*;
+
+fn main() {
+    rsx! {
+        button {
+            on_click: move |_| {},
+            "Click me"
+        }
+    };
+}
@@ -0,0 +1,9 @@
+// Style maps only accept CSS properties
+
+use dioxus::prelude::*;
+
+fn main() {
+    let _ = rsx! {
+        div { style: { id: "not-a-style" } }
+    };
+}
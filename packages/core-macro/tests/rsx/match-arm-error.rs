@@ -0,0 +1,17 @@
+// Given a match with rsx arms that contain an error,
+// ensure the error from the rsx is reported instead of treating the match as a rust expression.
+
+use dioxus::prelude::*;
+
+fn main() {
+    let value = Some(1);
+    rsx! {
+        match value {
+            Some(value) => p {
+                class: "foo bar"
+                "{value}"
+            }
+            None => {}
+        }
+    };
+}
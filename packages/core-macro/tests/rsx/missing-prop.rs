@@ -0,0 +1,14 @@
+// Missing props should point at the component and say which prop is missing
+
+use dioxus::prelude::*;
+
+#[component]
+fn Greeting(name: String, excited: bool) -> Element {
+    rsx! { "Hello {name}" }
+}
+
+fn main() {
+    let _ = rsx! {
+        Greeting { excited: true }
+    };
+}
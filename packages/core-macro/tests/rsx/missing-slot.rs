@@ -0,0 +1,18 @@
+// Given a component with a required `Element` slot that isn't passed,
+// ensure the error mentions the missing slot by name.
+
+use dioxus::prelude::*;
+
+#[component]
+fn Layout(header: Element, children: Element) -> Element {
+    rsx! {
+        {header}
+        {children}
+    }
+}
+
+fn main() {
+    rsx! {
+        Layout { "body" }
+    };
+}
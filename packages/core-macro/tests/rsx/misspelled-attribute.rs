@@ -0,0 +1,13 @@
+// Given an `rsx!` invocation with a misspelled attribute name,
+// ensure the error suggests the closest attribute of the element.
+
+use dioxus::prelude::*;
+
+fn main() {
+    rsx! {
+        div {
+            calss: "container",
+            "Hello"
+        }
+    };
+}
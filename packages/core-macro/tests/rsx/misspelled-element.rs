@@ -0,0 +1,10 @@
+// Given an `rsx!` invocation with a misspelled element name,
+// ensure the error suggests the closest element.
+
+use dioxus::prelude::*;
+
+fn main() {
+    rsx! {
+        dvi { class: "container" }
+    };
+}
@@ -0,0 +1,16 @@
+// Lists where only some items are keyed are an error
+
+use dioxus::prelude::*;
+
+fn main() {
+    let items = [1, 2, 3];
+    let _ = rsx! {
+        for item in items {
+            if item % 2 == 0 {
+                div { key: "{item}" }
+            } else {
+                div {}
+            }
+        }
+    };
+}
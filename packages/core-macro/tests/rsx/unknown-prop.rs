@@ -0,0 +1,14 @@
+// Props that don't exist should say which prop is unknown
+
+use dioxus::prelude::*;
+
+#[component]
+fn Greeting(name: String) -> Element {
+    rsx! { "Hello {name}" }
+}
+
+fn main() {
+    let _ = rsx! {
+        Greeting { name: "world", nme: "typo" }
+    };
+}
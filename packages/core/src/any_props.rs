@@ -1,4 +1,4 @@
-use crate::{nodes::RenderReturn, ComponentFunction};
+use crate::{inspect::PropInfo, nodes::RenderReturn, ComponentFunction};
 use std::{any::Any, panic::AssertUnwindSafe};
 
 pub(crate) type BoxedAnyProps = Box<dyn AnyProps>;
@@ -11,6 +11,8 @@ pub(crate) trait AnyProps: 'static {
     fn memoize(&mut self, other: &dyn Any) -> bool;
     /// Get the props as a type erased `dyn Any`.
     fn props(&self) -> &dyn Any;
+    /// Describe the props for developer tools.
+    fn debug(&self) -> Vec<PropInfo>;
     /// Duplicate this component into a new boxed component.
     fn duplicate(&self) -> BoxedAnyProps;
 }
@@ -19,6 +21,7 @@ pub(crate) trait AnyProps: 'static {
 pub(crate) struct VProps<F: ComponentFunction<P, M>, P, M> {
     render_fn: F,
     memo: fn(&mut P, &P) -> bool,
+    debug: fn(&P) -> Vec<PropInfo>,
     props: P,
     name: &'static str,
     phantom: std::marker::PhantomData<M>,
@@ -29,6 +32,7 @@ impl<F: ComponentFunction<P, M>, P: Clone, M> Clone for VProps<F, P, M> {
         Self {
             render_fn: self.render_fn.clone(),
             memo: self.memo,
+            debug: self.debug,
             props: self.props.clone(),
             name: self.name,
             phantom: std::marker::PhantomData,
@@ -41,12 +45,14 @@ impl<F: ComponentFunction<P, M> + Clone, P: Clone + 'static, M: 'static> VProps<
     pub fn new(
         render_fn: F,
         memo: fn(&mut P, &P) -> bool,
+        debug: fn(&P) -> Vec<PropInfo>,
         props: P,
         name: &'static str,
     ) -> VProps<F, P, M> {
         VProps {
             render_fn,
             memo,
+            debug,
             props,
             name,
             phantom: std::marker::PhantomData,
@@ -68,6 +74,10 @@ impl<F: ComponentFunction<P, M> + Clone, P: Clone + 'static, M: 'static> AnyProp
         &self.props
     }
 
+    fn debug(&self) -> Vec<PropInfo> {
+        (self.debug)(&self.props)
+    }
+
     fn render(&self) -> RenderReturn {
         let res = std::panic::catch_unwind(AssertUnwindSafe(move || {
             self.render_fn.rebuild(self.props.clone())
@@ -88,6 +98,7 @@ impl<F: ComponentFunction<P, M> + Clone, P: Clone + 'static, M: 'static> AnyProp
         Box::new(Self {
             render_fn: self.render_fn.clone(),
             memo: self.memo,
+            debug: self.debug,
             props: self.props.clone(),
             name: self.name,
             phantom: std::marker::PhantomData,
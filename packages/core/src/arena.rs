@@ -68,6 +68,10 @@ impl VirtualDom {
     //
     // Note: This will not remove any ids from the arena
     pub(crate) fn drop_scope(&mut self, id: ScopeId) {
+        self.resolve_suspended_scope(id);
+        self.drop_suspense_content(id);
+        self.cached_scopes.remove(&id);
+
         let height = {
             let scope = self.scopes.remove(id.0);
             let context = scope.state();
@@ -75,6 +79,7 @@ impl VirtualDom {
         };
 
         self.dirty_scopes.remove(&DirtyScope { height, id });
+        self.dirty_priorities.remove(&id);
     }
 }
 
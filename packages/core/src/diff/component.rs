@@ -2,7 +2,9 @@ use std::ops::{Deref, DerefMut};
 
 use crate::{
     any_props::AnyProps,
-    innerlude::{DirtyScope, ElementRef, MountId, VComponent, WriteMutations},
+    innerlude::{
+        DirtyScope, ElementRef, KeepAliveProps, MountId, PortalMount, VComponent, WriteMutations,
+    },
     nodes::RenderReturn,
     nodes::VNode,
     scopes::ScopeId,
@@ -16,6 +18,12 @@ impl VirtualDom {
         scope: ScopeId,
         new_nodes: RenderReturn,
     ) {
+        let _span = tracing::trace_span!(
+            "diff",
+            component = self.runtime.get_state(scope).map(|state| state.name),
+            scope = scope.0
+        )
+        .entered();
         self.runtime.scope_stack.borrow_mut().push(scope);
         let scope_state = &mut self.scopes[scope.0];
         // Load the old and new bump arenas
@@ -27,6 +35,8 @@ impl VirtualDom {
         let scope_state = &mut self.scopes[scope.0];
         scope_state.last_rendered_node = Some(new_nodes);
 
+        self.move_portal(to, scope);
+
         self.runtime.scope_stack.borrow_mut().pop();
     }
 
@@ -40,6 +50,12 @@ impl VirtualDom {
         new_node: RenderReturn,
         parent: Option<ElementRef>,
     ) -> usize {
+        let _span = tracing::trace_span!(
+            "create",
+            component = self.runtime.get_state(scope).map(|state| state.name),
+            scope = scope.0
+        )
+        .entered();
         self.runtime.scope_stack.borrow_mut().push(scope);
 
         // Create the node
@@ -48,9 +64,62 @@ impl VirtualDom {
         // Then set the new node as the last rendered node
         self.scopes[scope.0].last_rendered_node = Some(new_node);
 
+        let nodes = self.mount_portal(to, scope, nodes);
+
         self.runtime.scope_stack.borrow_mut().pop();
         nodes
     }
+
+    /// If the scope is a portal, move the m nodes it created to its subtree and leave a placeholder in their place
+    ///
+    /// Returns the number of nodes left on the stack
+    fn mount_portal(&mut self, to: &mut impl WriteMutations, scope: ScopeId, m: usize) -> usize {
+        let Some(target) = self.portal_target(scope) else {
+            return m;
+        };
+
+        // Scope ids are unique while the portal is mounted, so they double as the index of the subtree
+        to.create_subtree_root(scope.0, &target);
+        to.append_to_subtree(scope.0, m);
+
+        let placeholder = self.next_element();
+        to.create_placeholder(placeholder);
+        self.portals.insert(
+            scope,
+            PortalMount {
+                target,
+                placeholder,
+            },
+        );
+
+        1
+    }
+
+    /// Move the nodes of a portal to a new subtree if its target changed
+    fn move_portal(&mut self, to: &mut impl WriteMutations, scope: ScopeId) {
+        let Some(target) = self.portal_target(scope) else {
+            return;
+        };
+        let Some(portal) = self.portals.get_mut(&scope) else {
+            return;
+        };
+        if portal.target == target {
+            return;
+        }
+        to.create_subtree_root(scope.0, &target);
+        portal.target = target;
+
+        let m = self.scopes[scope.0]
+            .root_node()
+            .push_all_real_nodes(self, to);
+        to.append_to_subtree(scope.0, m);
+    }
+
+    fn portal_target(&self, scope: ScopeId) -> Option<String> {
+        self.runtime
+            .get_state(scope)
+            .and_then(|state| state.portal_target.borrow().clone())
+    }
 }
 
 impl VNode {
@@ -95,6 +164,7 @@ impl VNode {
             height,
             id: scope_id,
         });
+        dom.dirty_priorities.remove(&scope_id);
     }
 
     fn replace_vcomponent(
@@ -123,6 +193,18 @@ impl VNode {
         dom: &mut VirtualDom,
         to: &mut impl WriteMutations,
     ) -> usize {
+        // Keyed keep alive components restore the subtree cached under their key
+        let keep_alive = self
+            .key
+            .clone()
+            .filter(|_| component.props.props().is::<KeepAliveProps>());
+        if let Some(key) = &keep_alive {
+            if let Some((scope, m)) = dom.restore_keep_alive(to, key, component, parent) {
+                dom.mounts[mount.0].mounted_dynamic_nodes[idx] = scope.0;
+                return m;
+            }
+        }
+
         // Load up a ScopeId for this vcomponent. If it's already mounted, then we can just use that
         let scope = dom
             .new_scope(component.props.duplicate(), component.name)
@@ -132,6 +214,10 @@ impl VNode {
         // Store the scope id for the next render
         dom.mounts[mount.0].mounted_dynamic_nodes[idx] = scope.0;
 
+        if let Some(key) = keep_alive {
+            dom.keep_alive.insert(scope, key);
+        }
+
         let new = dom.run_scope(scope);
 
         dom.create_scope(to, scope, new, parent)
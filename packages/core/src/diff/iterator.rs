@@ -1,7 +1,7 @@
 use crate::{
     innerlude::{ElementRef, WriteMutations},
     nodes::VNode,
-    DynamicNode, ScopeId, TemplateNode, VirtualDom,
+    DynamicNode, ElementId, ScopeId, TemplateNode, VirtualDom,
 };
 
 use rustc_hash::{FxHashMap, FxHashSet};
@@ -432,38 +432,45 @@ impl VNode {
         dom: &VirtualDom,
         to: &mut impl WriteMutations,
     ) -> usize {
+        let mut nodes = Vec::new();
+        self.collect_real_nodes(dom, &mut nodes);
+        for id in &nodes {
+            to.push_root(*id);
+        }
+        nodes.len()
+    }
+
+    /// Collect the ids of the nodes this node has mounted in the dom, in order
+    pub(crate) fn collect_real_nodes(&self, dom: &VirtualDom, nodes: &mut Vec<ElementId>) {
         let template = self.template.get();
 
         let mount = dom.mounts.get(self.mount.get().0).unwrap();
 
-        template
-            .roots
-            .iter()
-            .enumerate()
-            .map(|(root_idx, _)| match &self.template.get().roots[root_idx] {
-                TemplateNode::Dynamic { id: idx } => match &self.dynamic_nodes[*idx] {
-                    DynamicNode::Placeholder(_) | DynamicNode::Text(_) => {
-                        to.push_root(mount.root_ids[root_idx]);
-                        1
-                    }
-                    DynamicNode::Fragment(nodes) => {
-                        let mut accumulated = 0;
-                        for node in nodes {
-                            accumulated += node.push_all_real_nodes(dom, to);
+        for (root_idx, root) in template.roots.iter().enumerate() {
+            match root {
+                TemplateNode::Dynamic { id: idx } | TemplateNode::DynamicText { id: idx } => {
+                    match &self.dynamic_nodes[*idx] {
+                        DynamicNode::Placeholder(_) | DynamicNode::Text(_) => {
+                            nodes.push(ElementId(mount.mounted_dynamic_nodes[*idx]));
+                        }
+                        DynamicNode::Fragment(children) => {
+                            for node in children {
+                                node.collect_real_nodes(dom, nodes);
+                            }
+                        }
+                        DynamicNode::Component(_) => {
+                            let scope = ScopeId(mount.mounted_dynamic_nodes[*idx]);
+                            if let Some(portal) = dom.portals.get(&scope) {
+                                nodes.push(portal.placeholder);
+                                continue;
+                            }
+                            let node = dom.get_scope(scope).unwrap().root_node();
+                            node.collect_real_nodes(dom, nodes);
                         }
-                        accumulated
-                    }
-                    DynamicNode::Component(_) => {
-                        let scope = ScopeId(mount.mounted_dynamic_nodes[*idx]);
-                        let node = dom.get_scope(scope).unwrap().root_node();
-                        node.push_all_real_nodes(dom, to)
                     }
-                },
-                _ => {
-                    to.push_root(mount.root_ids[root_idx]);
-                    1
                 }
-            })
-            .sum()
+                _ => nodes.push(mount.root_ids[root_idx]),
+            }
+        }
     }
 }
@@ -0,0 +1,309 @@
+use crate::{
+    arena::ElementId,
+    innerlude::{ElementRef, KeepAliveProps, NoOpMutations, VComponent, WriteMutations},
+    nodes::{DynamicNode, VNode},
+    scopes::ScopeId,
+    virtual_dom::VirtualDom,
+    AttributeValue, TemplateNode,
+};
+
+impl VirtualDom {
+    /// Cache a keep alive scope and the scopes below it instead of dropping them
+    pub(crate) fn cache_keep_alive(
+        &mut self,
+        to: &mut impl WriteMutations,
+        scope: ScopeId,
+        key: String,
+        replace_with: Option<usize>,
+        gen_muts: bool,
+    ) {
+        // Take the nodes out of the renderer. They are created again from the cached nodes when the subtree is restored
+        if gen_muts {
+            let mut nodes = Vec::new();
+            self.scopes[scope.0]
+                .root_node()
+                .collect_real_nodes(self, &mut nodes);
+            let last = nodes.pop().unwrap();
+            for id in nodes {
+                to.remove_node(id);
+            }
+            match replace_with {
+                Some(m) => to.replace_node_with(last, m),
+                None => to.remove_node(last),
+            }
+        }
+
+        for cached in self.scopes_in(scope) {
+            // The children of portals are in a different subtree, so removing the parent doesn't remove them
+            if self.portals.contains_key(&cached) {
+                let mut nodes = Vec::new();
+                self.scopes[cached.0]
+                    .root_node()
+                    .collect_real_nodes(self, &mut nodes);
+                for id in nodes {
+                    to.remove_node(id);
+                }
+            }
+            self.cached_scopes.insert(cached, false);
+        }
+
+        // The parent of the scope may be dropped while it is cached. A suspense boundary drops the children it caches when it is dropped
+        let suspense = self.is_suspense_content(scope);
+        if !suspense {
+            self.runtime.get_state(scope).unwrap().parent_id.set(None);
+        }
+
+        if let Some(idx) = self.cached_keep_alive(&key, suspense) {
+            let (_, scope) = self.keep_alive_cache.remove(idx).unwrap();
+            self.drop_cached_keep_alive(scope);
+        }
+        self.keep_alive_cache.push_back((key, scope));
+        self.evict_keep_alive_over_limit();
+    }
+
+    /// Drop the subtree cached under the key
+    pub(crate) fn evict_keep_alive(&mut self, key: &str) {
+        let Some(idx) = self.cached_keep_alive(key, false) else {
+            return;
+        };
+        let (_, scope) = self.keep_alive_cache.remove(idx).unwrap();
+        self.drop_cached_keep_alive(scope);
+    }
+
+    /// Drop the subtrees that were cached the longest time ago until the cache fits in the limit
+    ///
+    /// The children of suspense boundaries are not counted. They are only dropped with their boundary
+    pub(crate) fn evict_keep_alive_over_limit(&mut self) {
+        loop {
+            let mut limited = self
+                .keep_alive_cache
+                .iter()
+                .enumerate()
+                .filter(|(_, (_, scope))| !self.is_suspense_content(*scope))
+                .map(|(idx, _)| idx);
+            let Some(oldest) = limited.next() else {
+                return;
+            };
+            if limited.count() < self.keep_alive_limit {
+                return;
+            }
+            let (_, scope) = self.keep_alive_cache.remove(oldest).unwrap();
+            self.drop_cached_keep_alive(scope);
+        }
+    }
+
+    /// Drop the children a suspense boundary cached while it shows its fallback
+    pub(crate) fn drop_suspense_content(&mut self, boundary: ScopeId) {
+        if let Some(idx) = self.cached_keep_alive(&boundary.0.to_string(), true) {
+            let (_, scope) = self.keep_alive_cache.remove(idx).unwrap();
+            self.drop_cached_keep_alive(scope);
+        }
+    }
+
+    fn drop_cached_keep_alive(&mut self, scope: ScopeId) {
+        for cached in self.scopes_in(scope) {
+            self.cached_scopes.remove(&cached);
+        }
+        // The nodes of the cached subtree are already removed from the renderer
+        self.remove_component_node(&mut NoOpMutations, scope, None, false);
+    }
+
+    /// The position of the subtree cached under the key. Keep alives and suspense boundaries use separate keys
+    fn cached_keep_alive(&self, key: &str, suspense: bool) -> Option<usize> {
+        self.keep_alive_cache.iter().position(|(cached, scope)| {
+            cached == key && self.is_suspense_content(*scope) == suspense
+        })
+    }
+
+    /// Returns true if the keep alive scope holds the children of a suspense boundary
+    fn is_suspense_content(&self, scope: ScopeId) -> bool {
+        self.scopes[scope.0]
+            .props
+            .props()
+            .downcast_ref::<KeepAliveProps>()
+            .is_some_and(|props| props.suspense)
+    }
+
+    /// Mount the subtree cached under the key in place of a new keep alive component
+    ///
+    /// Returns the restored scope and the number of nodes it created, or None if nothing is cached under the key
+    pub(crate) fn restore_keep_alive(
+        &mut self,
+        to: &mut impl WriteMutations,
+        key: &str,
+        component: &VComponent,
+        parent: Option<ElementRef>,
+    ) -> Option<(ScopeId, usize)> {
+        let suspense = component
+            .props
+            .props()
+            .downcast_ref::<KeepAliveProps>()
+            .is_some_and(|props| props.suspense);
+        let idx = self.cached_keep_alive(key, suspense)?;
+        let (_, scope) = self.keep_alive_cache.remove(idx)?;
+        self.keep_alive.insert(scope, key.to_string());
+
+        // Move the subtree below the component that restores it
+        let parent_scope = self.runtime.current_scope_id();
+        self.runtime
+            .get_state(scope)
+            .unwrap()
+            .parent_id
+            .set(parent_scope);
+        let mount = self.scopes[scope.0].root_node().mount.get();
+        self.mounts[mount.0].parent = parent;
+
+        for cached in self.scopes_in(scope) {
+            if self.cached_scopes.remove(&cached) == Some(true) {
+                self.mark_dirty(cached);
+            }
+        }
+
+        // The children of the keep alive may have changed while it was cached
+        self.scopes[scope.0].props = component.props.duplicate();
+        self.mark_dirty(scope);
+
+        Some((scope, self.recreate_scope(to, scope)))
+    }
+
+    /// The scope and every scope below it
+    pub(crate) fn scopes_in(&self, scope: ScopeId) -> Vec<ScopeId> {
+        let mut scopes = vec![scope];
+        let mut idx = 0;
+        while let Some(scope) = scopes.get(idx).copied() {
+            if let Some(node) = self.scopes[scope.0].try_root_node() {
+                node.collect_child_scopes(self, &mut scopes);
+            }
+            idx += 1;
+        }
+        scopes
+    }
+
+    /// Write the mutations to create the nodes of a mounted scope again with the ids they are mounted with
+    fn recreate_scope(&mut self, to: &mut impl WriteMutations, scope: ScopeId) -> usize {
+        let node = self.scopes[scope.0].root_node().clone_mounted();
+        let m = node.recreate(self, to);
+
+        match self.portals.get(&scope) {
+            Some(portal) => {
+                let placeholder = portal.placeholder;
+                to.create_subtree_root(scope.0, &portal.target);
+                to.append_to_subtree(scope.0, m);
+                to.create_placeholder(placeholder);
+                1
+            }
+            None => m,
+        }
+    }
+}
+
+impl VNode {
+    fn collect_child_scopes(&self, dom: &VirtualDom, scopes: &mut Vec<ScopeId>) {
+        let mount = &dom.mounts[self.mount.get().0];
+        for (idx, node) in self.dynamic_nodes.iter().enumerate() {
+            match node {
+                DynamicNode::Component(_) => {
+                    scopes.push(ScopeId(mount.mounted_dynamic_nodes[idx]));
+                }
+                DynamicNode::Fragment(nodes) => {
+                    for node in nodes {
+                        node.collect_child_scopes(dom, scopes);
+                    }
+                }
+                DynamicNode::Text(_) | DynamicNode::Placeholder(_) => {}
+            }
+        }
+    }
+
+    /// Write the mutations to create this mounted node again with the ids it is mounted with
+    ///
+    /// This follows the same order as creating the node
+    fn recreate(&self, dom: &mut VirtualDom, to: &mut impl WriteMutations) -> usize {
+        let template = self.template.get();
+        let mount = self.mount.get();
+
+        template
+            .roots
+            .iter()
+            .enumerate()
+            .map(|(root_idx, root)| match root {
+                TemplateNode::Dynamic { id } | TemplateNode::DynamicText { id } => {
+                    self.recreate_dynamic_node(*id, dom, to)
+                }
+                TemplateNode::Text { .. } => {
+                    to.load_template(
+                        template.name,
+                        root_idx,
+                        dom.mounts[mount.0].root_ids[root_idx],
+                    );
+                    1
+                }
+                TemplateNode::Element { .. } => {
+                    to.load_template(
+                        template.name,
+                        root_idx,
+                        dom.mounts[mount.0].root_ids[root_idx],
+                    );
+
+                    let below_root = |path: &&[u8]| path.first() == Some(&(root_idx as u8));
+                    for (attr_idx, path) in template.attr_paths.iter().enumerate() {
+                        if !below_root(path) {
+                            continue;
+                        }
+                        let id = dom.mounts[mount.0].mounted_attributes[attr_idx];
+                        if path.len() > 1 {
+                            to.assign_node_id(&path[1..], id);
+                        }
+                        for attr in &*self.dynamic_attrs[attr_idx] {
+                            if !matches!(attr.value, AttributeValue::None) {
+                                self.write_attribute(path, attr, id, mount, dom, to);
+                            }
+                        }
+                    }
+
+                    let mut dynamic_nodes = (0..template.node_paths.len())
+                        .filter(|idx| below_root(&template.node_paths[*idx]))
+                        .collect::<Vec<_>>();
+                    dynamic_nodes.sort_by_key(|idx| template.node_paths[*idx]);
+                    for idx in dynamic_nodes.into_iter().rev() {
+                        let path = &template.node_paths[idx][1..];
+                        let id = ElementId(dom.mounts[mount.0].mounted_dynamic_nodes[idx]);
+                        match &self.dynamic_nodes[idx] {
+                            DynamicNode::Text(text) => to.hydrate_text_node(path, &text.value, id),
+                            DynamicNode::Placeholder(_) => to.assign_node_id(path, id),
+                            DynamicNode::Component(_) | DynamicNode::Fragment(_) => {
+                                let m = self.recreate_dynamic_node(idx, dom, to);
+                                if m > 0 {
+                                    to.replace_placeholder_with_nodes(path, m);
+                                }
+                            }
+                        }
+                    }
+
+                    1
+                }
+            })
+            .sum()
+    }
+
+    fn recreate_dynamic_node(
+        &self,
+        idx: usize,
+        dom: &mut VirtualDom,
+        to: &mut impl WriteMutations,
+    ) -> usize {
+        let mounted = dom.mounts[self.mount.get().0].mounted_dynamic_nodes[idx];
+        match &self.dynamic_nodes[idx] {
+            DynamicNode::Component(_) => dom.recreate_scope(to, ScopeId(mounted)),
+            DynamicNode::Fragment(nodes) => nodes.iter().map(|node| node.recreate(dom, to)).sum(),
+            DynamicNode::Placeholder(_) => {
+                to.create_placeholder(ElementId(mounted));
+                1
+            }
+            DynamicNode::Text(text) => {
+                to.create_text_node(&text.value, ElementId(mounted));
+                1
+            }
+        }
+    }
+}
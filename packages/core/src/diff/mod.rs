@@ -2,7 +2,7 @@
 
 use crate::{
     arena::ElementId,
-    innerlude::{ElementRef, MountId, WriteMutations},
+    innerlude::{ElementRef, MountId, PortalMount, WriteMutations},
     nodes::VNode,
     scopes::ScopeId,
     virtual_dom::VirtualDom,
@@ -11,7 +11,9 @@ use crate::{
 
 mod component;
 mod iterator;
+mod keep_alive;
 mod node;
+mod presence;
 
 impl VirtualDom {
     pub(crate) fn create_children<'a>(
@@ -90,8 +92,24 @@ impl VirtualDom {
         replace_with: Option<usize>,
         gen_muts: bool,
     ) {
+        // Exiting presences below the component are removed before the nodes around them
+        self.remove_exiting_children(to, scope, gen_muts);
+
+        // Animated presences keep their nodes until their exit animation ends. If the nodes are removed with a parent element, there is nothing to animate
+        if gen_muts && self.start_exit(to, scope, replace_with) {
+            return;
+        }
+
+        // Keep alive components are cached instead of dropped
+        if let Some(key) = self.keep_alive.remove(&scope) {
+            self.cache_keep_alive(to, scope, key, replace_with, gen_muts);
+            return;
+        }
+
         // Remove the component from the dom
-        if let Some(node) = self.scopes[scope.0].last_rendered_node.take() {
+        if let Some(portal) = self.portals.remove(&scope) {
+            self.remove_portal(to, scope, portal, replace_with, gen_muts);
+        } else if let Some(node) = self.scopes[scope.0].last_rendered_node.take() {
             node.remove_node(self, to, replace_with, gen_muts)
         };
 
@@ -99,6 +117,29 @@ impl VirtualDom {
         self.drop_scope(scope);
     }
 
+    fn remove_portal(
+        &mut self,
+        to: &mut impl WriteMutations,
+        scope: ScopeId,
+        portal: PortalMount,
+        replace_with: Option<usize>,
+        gen_muts: bool,
+    ) {
+        // The placeholder is the only node of the portal in the parent
+        if gen_muts {
+            match replace_with {
+                Some(m) => to.replace_node_with(portal.placeholder, m),
+                None => to.remove_node(portal.placeholder),
+            }
+        }
+        self.reclaim(portal.placeholder);
+
+        // The children are mounted in a different subtree, so removing the parent doesn't remove them
+        if let Some(node) = self.scopes[scope.0].last_rendered_node.take() {
+            node.remove_node(self, to, None, true)
+        };
+    }
+
     /// Insert a new template into the VirtualDom's template registry
     // used in conditional compilation
     #[allow(unused_mut)]
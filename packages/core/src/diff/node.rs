@@ -5,7 +5,7 @@ use core::iter::Peekable;
 
 use crate::{
     arena::ElementId,
-    innerlude::{ElementPath, ElementRef, VComponent, VNodeMount, VText},
+    innerlude::{ElementPath, ElementRef, KeepAliveProps, VComponent, VNodeMount, VText},
     nodes::DynamicNode,
     scopes::ScopeId,
     TemplateNode,
@@ -39,6 +39,12 @@ impl VNode {
             }
         }
 
+        // A keep alive with a different key is a different subtree even if the template is the same, so the old subtree is cached and the new one is restored
+        if self.key != new.key && (self.is_keep_alive() || new.is_keep_alive()) {
+            let parent = dom.mounts[self.mount.get().0].parent;
+            return self.replace([new], parent, dom, to);
+        }
+
         // If the templates are different by name, we need to replace the entire template
         if self.templates_are_different(new) {
             return self.light_diff_templates(new, dom, to);
@@ -127,6 +133,9 @@ impl VNode {
                     }
                     Component(_comp) => {
                         let scope = ScopeId(mount.mounted_dynamic_nodes[*id]);
+                        if let Some(portal) = dom.portals.get(&scope) {
+                            return portal.placeholder;
+                        }
                         dom.get_scope(scope)
                             .unwrap()
                             .root_node()
@@ -149,6 +158,9 @@ impl VNode {
                     Fragment(t) => t.last().unwrap().find_last_element(dom),
                     Component(_comp) => {
                         let scope = ScopeId(mount.mounted_dynamic_nodes[*id]);
+                        if let Some(portal) = dom.portals.get(&scope) {
+                            return portal.placeholder;
+                        }
                         dom.get_scope(scope)
                             .unwrap()
                             .root_node()
@@ -307,6 +319,17 @@ impl VNode {
         };
     }
 
+    /// Check if the only root of this node is a [`crate::KeepAlive`]
+    fn is_keep_alive(&self) -> bool {
+        match self.template.get().roots {
+            [Dynamic { id }] => matches!(
+                &self.dynamic_nodes[*id],
+                Component(component) if component.props.props().is::<KeepAliveProps>()
+            ),
+            _ => false,
+        }
+    }
+
     fn templates_are_different(&self, other: &VNode) -> bool {
         let self_node_name = self.template.get().name;
         let other_node_name = other.template.get().name;
@@ -353,11 +376,32 @@ impl VNode {
             .zip(new.dynamic_attrs.iter())
             .enumerate()
         {
-            let mut old_attributes_iter = old_attrs.iter().peekable();
-            let mut new_attributes_iter = new_attrs.iter().peekable();
             let attribute_id = dom.mounts[mount_id.0].mounted_attributes[idx];
             let path = self.template.get().attr_paths[idx];
 
+            // Most slots hold the same attributes in the same order every render, so they are diffed in place
+            let same_names = old_attrs.len() == new_attrs.len()
+                && old_attrs
+                    .iter()
+                    .zip(new_attrs.iter())
+                    .all(|(old, new)| old.name == new.name && old.namespace == new.namespace);
+            if same_names {
+                for (old, new) in old_attrs.iter().zip(new_attrs.iter()) {
+                    if old.value != new.value {
+                        self.write_attribute(path, new, attribute_id, mount_id, dom, to);
+                    }
+                }
+                continue;
+            }
+
+            // A spread added, removed or moved attributes. Attributes are kept in the order they were written, so they are walked in the order of their names to find the ones that were added or removed
+            let mut old_attrs = old_attrs.iter().collect::<Vec<_>>();
+            let mut new_attrs = new_attrs.iter().collect::<Vec<_>>();
+            old_attrs.sort_by_key(|attribute| attribute.name);
+            new_attrs.sort_by_key(|attribute| attribute.name);
+            let mut old_attributes_iter = old_attrs.into_iter().peekable();
+            let mut new_attributes_iter = new_attrs.into_iter().peekable();
+
             loop {
                 match (old_attributes_iter.peek(), new_attributes_iter.peek()) {
                     (Some(old_attribute), Some(new_attribute)) => {
@@ -420,7 +464,7 @@ impl VNode {
         }
     }
 
-    fn write_attribute(
+    pub(super) fn write_attribute(
         &self,
         path: &'static [u8],
         attribute: &Attribute,
@@ -767,7 +811,10 @@ impl VNode {
 
             loop {
                 for attr in &*self.dynamic_attrs[attr_id] {
-                    self.write_attribute(path, attr, id, mount, dom, to);
+                    // A freshly created element has no value for this attribute yet, so there is nothing to clear
+                    if !matches!(attr.value, AttributeValue::None) {
+                        self.write_attribute(path, attr, id, mount, dom, to);
+                    }
                     dom.mounts[mount.0].mounted_attributes[attr_id] = id;
                 }
 
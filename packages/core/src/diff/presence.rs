@@ -0,0 +1,134 @@
+use crate::{
+    innerlude::WriteMutations,
+    nodes::{DynamicNode, VNode},
+    scopes::ScopeId,
+    virtual_dom::VirtualDom,
+};
+
+impl VirtualDom {
+    /// If the scope is an animated presence, keep its nodes in the renderer until the exit animation ends instead of removing them
+    ///
+    /// Returns true if the exit animation started
+    pub(crate) fn start_exit(
+        &mut self,
+        to: &mut impl WriteMutations,
+        scope: ScopeId,
+        replace_with: Option<usize>,
+    ) -> bool {
+        let Some(duration) = self.runtime.get_state(scope).and_then(|state| {
+            let animation = state.exit_animation.borrow();
+            animation.as_ref().map(|animation| animation.duration)
+        }) else {
+            return false;
+        };
+
+        let mut nodes = Vec::new();
+        self.scopes[scope.0]
+            .root_node()
+            .collect_real_nodes(self, &mut nodes);
+        let (Some(first), Some(last)) = (nodes.first().copied(), nodes.last().copied()) else {
+            return false;
+        };
+
+        // The new nodes go after the exiting nodes instead of replacing them
+        if let Some(m) = replace_with {
+            to.insert_nodes_after(last, m);
+        }
+
+        // The parent of the presence may be removed while it exits, so events from the exiting nodes stop bubbling at the presence
+        self.scopes[scope.0]
+            .root_node()
+            .clone_mounted()
+            .detach_from_parent(self);
+
+        // Render the subtree again so it can switch to its exit state
+        for exiting in self.scopes_in(scope) {
+            if let Some(state) = self.runtime.get_state(exiting) {
+                if let Some(animation) = &*state.exit_animation.borrow() {
+                    animation.presence.set_exiting();
+                }
+            }
+            self.mark_dirty(exiting);
+        }
+
+        self.exiting.insert(scope);
+        to.wait_for_exit(scope, first, duration);
+        true
+    }
+
+    /// Queue the nodes of an exiting presence to be removed in the next render
+    pub(crate) fn queue_finished_exit(&mut self, scope: ScopeId) {
+        if self.exiting.contains(&scope) && !self.finished_exits.contains(&scope) {
+            self.finished_exits.push(scope);
+        }
+    }
+
+    /// Remove the nodes of the presences that finished their exit animation
+    pub(crate) fn remove_finished_exits(&mut self, to: &mut impl WriteMutations) {
+        for scope in std::mem::take(&mut self.finished_exits) {
+            if self.exiting.remove(&scope) {
+                self.remove_exited(to, scope, true);
+            }
+        }
+    }
+
+    /// Remove the exiting presences below a scope that is being removed. They are not in the nodes of the scope anymore, so removing the scope doesn't remove them
+    pub(crate) fn remove_exiting_children(
+        &mut self,
+        to: &mut impl WriteMutations,
+        parent: ScopeId,
+        gen_muts: bool,
+    ) {
+        let children = self
+            .exiting
+            .iter()
+            .copied()
+            .filter(|scope| {
+                self.runtime
+                    .get_state(*scope)
+                    .and_then(|state| state.parent_id())
+                    == Some(parent)
+            })
+            .collect::<Vec<_>>();
+        for child in children {
+            self.exiting.remove(&child);
+            self.remove_exited(to, child, gen_muts);
+        }
+    }
+
+    fn remove_exited(&mut self, to: &mut impl WriteMutations, scope: ScopeId, gen_muts: bool) {
+        // The exit animation already played, so the scope is removed like any other component
+        if let Some(state) = self.runtime.get_state(scope) {
+            state.exit_animation.take();
+        }
+        self.remove_component_node(to, scope, None, gen_muts);
+    }
+}
+
+impl VNode {
+    /// Remove the parent of the mounts at the root of this node
+    fn detach_from_parent(&self, dom: &mut VirtualDom) {
+        let mount = self.mount.get();
+        dom.mounts[mount.0].parent = None;
+        for root in self.template.get().roots {
+            let Some(idx) = root.dynamic_id() else {
+                continue;
+            };
+            match &self.dynamic_nodes[idx] {
+                DynamicNode::Component(_) => {
+                    let scope = ScopeId(dom.mounts[mount.0].mounted_dynamic_nodes[idx]);
+                    dom.scopes[scope.0]
+                        .root_node()
+                        .clone_mounted()
+                        .detach_from_parent(dom);
+                }
+                DynamicNode::Fragment(nodes) => {
+                    for node in nodes {
+                        node.detach_from_parent(dom);
+                    }
+                }
+                DynamicNode::Text(_) | DynamicNode::Placeholder(_) => {}
+            }
+        }
+    }
+}
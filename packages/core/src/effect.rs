@@ -0,0 +1,33 @@
+use crate::{runtime::Runtime, ScopeId};
+
+/// A callback that runs after the renderer applies the mutations of a render
+pub(crate) struct Effect {
+    scope: ScopeId,
+    effect: Box<dyn FnOnce()>,
+}
+
+impl Runtime {
+    /// Queue an effect to run in a scope after the next render is committed
+    pub(crate) fn queue_effect(&self, scope: ScopeId, effect: impl FnOnce() + 'static) {
+        self.pending_effects.borrow_mut().push_back(Effect {
+            scope,
+            effect: Box::new(effect),
+        });
+    }
+
+    /// Run the effects that are currently queued. Effects queued while this runs wait for the next call.
+    ///
+    /// Returns true if any effects ran
+    pub(crate) fn run_effects(&self) -> bool {
+        let effects = std::mem::take(&mut *self.pending_effects.borrow_mut());
+        let ran = !effects.is_empty();
+        for Effect { scope, effect } in effects {
+            // The component may have been removed in the render the effect was waiting for
+            if self.get_state(scope).is_none() {
+                continue;
+            }
+            self.on_scope(scope, effect);
+        }
+        ran
+    }
+}
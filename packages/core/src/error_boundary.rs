@@ -5,7 +5,7 @@ use crate::{
     VNode,
 };
 use std::{
-    any::{Any, TypeId},
+    any::Any,
     backtrace::Backtrace,
     cell::RefCell,
     error::Error,
@@ -27,7 +27,7 @@ pub struct ErrorBoundary {
 /// A boundary that will capture any errors from child components
 pub struct ErrorBoundaryInner {
     error: RefCell<Option<CapturedError>>,
-    _id: ScopeId,
+    id: ScopeId,
 }
 
 impl Debug for ErrorBoundaryInner {
@@ -49,14 +49,14 @@ impl<T: Any + Debug> AnyDebug for T {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 /// An instance of an error captured by a descendant component.
 pub struct CapturedError {
     /// The error captured by the error boundary
-    pub error: Box<dyn AnyDebug + 'static>,
+    pub error: Rc<dyn AnyDebug + 'static>,
 
     /// The backtrace of the error
-    pub backtrace: Backtrace,
+    pub backtrace: Rc<Backtrace>,
 
     /// The scope that threw the error
     pub scope: ScopeId,
@@ -76,11 +76,8 @@ impl Error for CapturedError {}
 impl CapturedError {
     /// Downcast the error type into a concrete error type
     pub fn downcast<T: 'static>(&self) -> Option<&T> {
-        if TypeId::of::<T>() == self.error.type_id() {
-            self.error.as_any().downcast_ref::<T>()
-        } else {
-            None
-        }
+        // Deref the box first so we check the type of the error, not the type of the box
+        (*self.error).as_any().downcast_ref::<T>()
     }
 }
 
@@ -88,7 +85,7 @@ impl Default for ErrorBoundaryInner {
     fn default() -> Self {
         Self {
             error: RefCell::new(None),
-            _id: current_scope_id()
+            id: current_scope_id()
                 .expect("Cannot create an error boundary outside of a component's scope."),
         }
     }
@@ -105,7 +102,7 @@ impl ErrorBoundary {
         Self {
             inner: Rc::new(ErrorBoundaryInner {
                 error: RefCell::new(None),
-                _id: scope,
+                id: scope,
             }),
         }
     }
@@ -113,19 +110,31 @@ impl ErrorBoundary {
     /// Push an error into this Error Boundary
     pub fn insert_error(&self, scope: ScopeId, error: impl Debug + 'static, backtrace: Backtrace) {
         self.inner.error.replace(Some(CapturedError {
-            error: Box::new(error),
+            error: Rc::new(error),
             scope,
-            backtrace,
+            backtrace: Rc::new(backtrace),
         }));
-        if self.inner._id != ScopeId::ROOT {
-            self.inner._id.needs_update();
+        if self.inner.id != ScopeId::ROOT {
+            self.inner.id.needs_update();
         }
     }
 
+    /// Get the error that has been captured by this error boundary without clearing it
+    pub fn error(&self) -> Option<CapturedError> {
+        self.inner.error.borrow().clone()
+    }
+
     /// Take any error that has been captured by this error boundary
     pub fn take_error(&self) -> Option<CapturedError> {
         self.inner.error.take()
     }
+
+    /// Clear the captured error and render the boundary again. The [`ErrorBoundary`] component will create its children from scratch.
+    pub fn reset(&self) {
+        if self.inner.error.take().is_some() && self.inner.id != ScopeId::ROOT {
+            self.inner.id.needs_update();
+        }
+    }
 }
 
 /// A trait to allow results to be thrown upwards to the nearest Error Boundary
@@ -204,7 +213,7 @@ pub trait Throw<S = ()>: Sized {
 fn throw_error<T>(e: impl Debug + 'static) -> Option<T> {
     if let Some(cx) = try_consume_context::<ErrorBoundary>() {
         match current_scope_id() {
-            Some(id) => cx.insert_error(id, Box::new(e), Backtrace::capture()),
+            Some(id) => cx.insert_error(id, e, Backtrace::capture()),
             None => {
                 tracing::error!("Cannot throw error outside of a component's scope.")
             }
@@ -214,6 +223,52 @@ fn throw_error<T>(e: impl Debug + 'static) -> Option<T> {
     None
 }
 
+/// An error thrown when a prop fails one of the `#[props(validate = ..)]`, `#[props(range = ..)]` or
+/// `#[props(len = ..)]` checks of its component. Validation only runs in debug builds.
+pub struct InvalidProp {
+    /// The name of the props struct the prop belongs to
+    pub props: &'static str,
+    /// The name of the prop that failed validation
+    pub prop: &'static str,
+    /// Why the prop is invalid
+    pub message: String,
+}
+
+impl Display for InvalidProp {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Invalid value for prop `{}` of `{}`: {}",
+            self.prop, self.props, self.message
+        )
+    }
+}
+
+// The error boundary shows the debug representation of errors, so this prints the same message as Display
+impl Debug for InvalidProp {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl Error for InvalidProp {}
+
+/// Report a prop that failed validation to the nearest error boundary. Called from the builder generated by `#[derive(Props)]`
+#[doc(hidden)]
+pub fn report_invalid_prop(props: &'static str, prop: &'static str, message: impl Display) {
+    let error = InvalidProp {
+        props,
+        prop,
+        message: message.to_string(),
+    };
+    tracing::error!("{error}");
+
+    match (try_consume_context::<ErrorBoundary>(), current_scope_id()) {
+        (Some(boundary), Some(id)) => boundary.insert_error(id, error, Backtrace::capture()),
+        _ => tracing::error!("Cannot throw error outside of a component's scope."),
+    }
+}
+
 /// We call clone on any errors that can be owned out of a reference
 impl<'a, T, O: Debug + 'static, E: ToOwned<Owned = O>> Throw for &'a Result<T, E> {
     type Out = &'a T;
@@ -429,12 +484,20 @@ impl<
 ///
 /// Error boundaries handle errors within a specific part of your application. Any errors passed in a child with [`Throw`] will be caught by the nearest error boundary.
 ///
+/// The error handler is rendered instead of the children until the error is cleared with [`ErrorBoundary::reset`]. The handler runs inside of the boundary, so it can get the boundary with `consume_context::<ErrorBoundary>()`.
+///
 /// ## Example
 ///
 /// ```rust, ignore
 /// rsx!{
 ///     ErrorBoundary {
-///         handle_error: |error| rsx! { "Oops, we encountered an error. Please report {error} to the developer of this application" }
+///         handle_error: |error| {
+///             let boundary = consume_context::<ErrorBoundary>();
+///             rsx! {
+///                 "Oops, we encountered an error. Please report {error} to the developer of this application"
+///                 button { onclick: move |_| boundary.reset(), "Try again" }
+///             }
+///         }
 ///         ThrowsError {}
 ///     }
 /// }
@@ -448,7 +511,7 @@ impl<
 #[allow(non_upper_case_globals, non_snake_case)]
 pub fn ErrorBoundary(props: ErrorBoundaryProps) -> Element {
     let error_boundary = use_error_boundary();
-    match error_boundary.take_error() {
+    match error_boundary.error() {
         Some(error) => (props.handle_error.0)(error),
         None => Some({
             static TEMPLATE: Template = Template {
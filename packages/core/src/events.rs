@@ -137,9 +137,10 @@ impl<T: std::fmt::Debug> std::fmt::Debug for Event<T> {
     }
 }
 
-/// The callback type generated by the `rsx!` macro when an `on` field is specified for components.
+/// A callback that can be passed to components as a prop.
 ///
-/// This makes it possible to pass `move |evt| {}` style closures into components as property fields.
+/// The `Props` derive makes the setter of an `EventHandler<T>` field take any `FnMut(T)`, so `move |evt| {}` style closures can be passed to components without wrapping them.
+/// Another `EventHandler` can be forwarded with a closure that calls it, like `move |evt| props.onclick.call(evt)`.
 ///
 ///
 /// # Example
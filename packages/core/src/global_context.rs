@@ -68,6 +68,21 @@ pub fn spawn_forever(fut: impl Future<Output = ()> + 'static) -> Option<Task> {
     Runtime::with_current_scope(|cx| cx.spawn_forever(fut))
 }
 
+/// Queue an effect to run after the next render is committed.
+///
+/// Renderers apply the mutations of a render before they wait for more work with [`crate::VirtualDom::wait_for_work`], so the effect can read the layout of elements or use element references from that render. If no components need to render, the effect runs the next time the VirtualDom waits for work.
+///
+/// The effect runs in the current scope, and is skipped if the scope is removed before it runs.
+pub fn queue_effect(effect: impl FnOnce() + 'static) {
+    Runtime::with(|rt| {
+        let scope = rt
+            .current_scope_id()
+            .expect("effects must be queued inside of a component's scope");
+        rt.queue_effect(scope, effect)
+    })
+    .expect("to be in a dioxus runtime")
+}
+
 /// Informs the scheduler that this task is no longer needed and should be removed.
 ///
 /// This drops the task immediately.
@@ -102,6 +117,35 @@ pub fn generation() -> usize {
     Runtime::with_current_scope(|cx| cx.generation()).expect("to be in a dioxus runtime")
 }
 
+/// Get the index of the hook that runs next in the current component
+///
+/// Hooks run in the same order every render, so together with the [`ScopeId`] this identifies a hook. Inside the
+/// initializer of [`use_hook`] this is the index of that hook.
+pub fn current_hook_index() -> usize {
+    Runtime::with_current_scope(|cx| cx.hook_index.get()).expect("to be in a dioxus runtime")
+}
+
+/// Describe the value of the hook at `index` in the current component for developer tools
+///
+/// The closure runs every time [`VirtualDom::scope_tree`](crate::VirtualDom::scope_tree) is called, so it should be cheap.
+///
+/// ```rust
+/// use dioxus::prelude::*;
+/// use std::{cell::Cell, rc::Rc};
+///
+/// fn use_counter() -> Rc<Cell<u32>> {
+///     use_hook(|| {
+///         let counter = Rc::new(Cell::new(0));
+///         let described = counter.clone();
+///         describe_hook(current_hook_index(), move || described.get().to_string());
+///         counter
+///     })
+/// }
+/// ```
+pub fn describe_hook(index: usize, describe: impl Fn() -> String + 'static) {
+    Runtime::with_current_scope(|cx| cx.describe_hook(index, describe));
+}
+
 /// Get the parent of the current scope if it exists
 pub fn parent_scope() -> Option<ScopeId> {
     Runtime::with_current_scope(|cx| cx.parent_id()).flatten()
@@ -0,0 +1,168 @@
+//! Snapshots of the scope tree of a [`VirtualDom`] for developer tools
+
+use std::{any::type_name, fmt::Debug, time::Duration};
+
+use crate::{arena::ElementId, scopes::ScopeId, VirtualDom};
+
+/// A snapshot of one scope in the tree returned by [`VirtualDom::scope_tree`]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScopeInfo {
+    /// The id of the scope
+    pub id: ScopeId,
+    /// The name of the component that renders the scope
+    pub name: String,
+    /// The scope that rendered this scope
+    pub parent: Option<ScopeId>,
+    /// The scopes this scope rendered
+    pub children: Vec<ScopeId>,
+    /// The depth of the scope in the tree. The root scope has a height of 0
+    pub height: u32,
+    /// How many times the component has rendered
+    pub render_count: usize,
+    /// How long the last render of the component took. This is only measured on targets with `std::time::Instant`, so it is `None` on `wasm32-unknown-unknown`
+    pub render_time: Option<Duration>,
+    /// The props of the component. This is empty if the props do not implement [`Properties::debug_props`](crate::Properties::debug_props)
+    pub props: Vec<PropInfo>,
+    /// The hooks of the component in the order they run
+    pub hooks: Vec<HookInfo>,
+    /// If the component is waiting for suspended work
+    pub suspended: bool,
+    /// The elements the component has mounted at the top of its subtree, which renderers can highlight
+    pub elements: Vec<ElementId>,
+}
+
+/// A prop of a component in a [`ScopeInfo`]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PropInfo {
+    /// The name of the prop
+    pub name: String,
+    /// The debug output of the prop, or the type of the prop if it does not implement [`Debug`]
+    pub value: String,
+}
+
+/// A hook of a component in a [`ScopeInfo`]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct HookInfo {
+    /// The type of the value the hook stores
+    pub type_name: String,
+    /// The value of the hook if the hook described it with [`describe_hook`](crate::prelude::describe_hook)
+    pub value: Option<String>,
+}
+
+/// Wraps a prop so the props derive can format it with [`Debug`] when the prop implements it and fall back to the name of its type otherwise
+#[doc(hidden)]
+pub struct DebugProp<'a, T>(pub &'a T);
+
+#[doc(hidden)]
+pub trait DebugPropValue {
+    fn debug_prop(&self) -> String;
+}
+
+impl<T: Debug> DebugPropValue for DebugProp<'_, T> {
+    fn debug_prop(&self) -> String {
+        format!("{:?}", self.0)
+    }
+}
+
+#[doc(hidden)]
+pub trait NoDebugPropValue {
+    fn debug_prop(&self) -> String;
+}
+
+impl<T> NoDebugPropValue for &DebugProp<'_, T> {
+    fn debug_prop(&self) -> String {
+        type_name::<T>().to_string()
+    }
+}
+
+impl VirtualDom {
+    /// Take a snapshot of every scope in the tree, with the root first and every scope before its children
+    ///
+    /// ```rust
+    /// use dioxus::prelude::*;
+    ///
+    /// fn app() -> Element {
+    ///     rsx! { Child {} }
+    /// }
+    ///
+    /// #[component]
+    /// fn Child() -> Element {
+    ///     rsx! { "hello" }
+    /// }
+    ///
+    /// let mut dom = VirtualDom::new(app);
+    /// dom.rebuild(&mut dioxus_core::NoOpMutations);
+    ///
+    /// let tree = dom.scope_tree();
+    /// assert_eq!(tree[1].name, "Child");
+    /// assert_eq!(tree[1].parent, Some(ScopeId::ROOT));
+    /// ```
+    pub fn scope_tree(&self) -> Vec<ScopeInfo> {
+        let mut infos: Vec<ScopeInfo> = self
+            .scopes
+            .iter()
+            .map(|(_, scope)| {
+                let state = scope.state();
+                let descriptions = state.hook_descriptions.borrow();
+                let hooks = state
+                    .hook_types
+                    .borrow()
+                    .iter()
+                    .enumerate()
+                    .map(|(index, type_name)| HookInfo {
+                        type_name: type_name.to_string(),
+                        value: descriptions.get(&index).map(|describe| describe()),
+                    })
+                    .collect();
+                let mut elements = Vec::new();
+                if let Some(node) = scope.try_root_node() {
+                    // Cached scopes are not mounted and have no elements
+                    if let Some(mount) = node.mount.get().as_usize() {
+                        if self.mounts.contains(mount) {
+                            node.collect_real_nodes(self, &mut elements);
+                        }
+                    }
+                }
+                ScopeInfo {
+                    id: state.id,
+                    name: state.name.to_string(),
+                    parent: state.parent_id(),
+                    children: Vec::new(),
+                    height: state.height,
+                    render_count: state.render_count.get(),
+                    render_time: state.render_time.get(),
+                    props: scope.props.debug(),
+                    hooks,
+                    suspended: state.suspended.get(),
+                    elements,
+                }
+            })
+            .collect();
+
+        let children: Vec<(ScopeId, ScopeId)> = infos
+            .iter()
+            .filter_map(|info| Some((info.parent?, info.id)))
+            .collect();
+        for (parent, child) in children {
+            if let Some(parent) = infos.iter_mut().find(|info| info.id == parent) {
+                parent.children.push(child);
+            }
+        }
+
+        // Order the scopes depth first from the root
+        let mut tree = Vec::with_capacity(infos.len());
+        let mut stack = vec![ScopeId::ROOT];
+        while let Some(id) = stack.pop() {
+            let Some(index) = infos.iter().position(|info| info.id == id) else {
+                continue;
+            };
+            let info = infos.swap_remove(index);
+            stack.extend(info.children.iter().rev().copied());
+            tree.push(info);
+        }
+        tree
+    }
+}
@@ -0,0 +1,89 @@
+use crate::innerlude::*;
+
+/// The number of [`KeepAlive`] subtrees that are cached at the same time if the VirtualDom doesn't set a limit
+pub(crate) const DEFAULT_KEEP_ALIVE_LIMIT: usize = 10;
+
+/// Keep the state of a subtree alive while it is removed from the tree.
+///
+/// ## Details
+///
+/// When a keyed `KeepAlive` is removed, its scope and every component below it are cached instead of dropped. Hooks keep their values and tasks keep running, but the components are not rendered until the subtree is mounted again. When a `KeepAlive` with the same key is created later, the cached subtree is mounted in its place and rendered with the new children.
+///
+/// The key is the key of the `KeepAlive`, so it must be the only root of its `rsx!` call or an item in a keyed list. A `KeepAlive` without a key is not cached.
+///
+/// A cached subtree is dropped when another subtree is cached with the same key, when it is evicted with [`evict_keep_alive`], or when more subtrees are cached than the limit set with [`VirtualDom::with_keep_alive_limit`]. The subtree that was cached the longest time ago is dropped first.
+///
+/// ## Example
+///
+/// ```rust, ignore
+/// rsx!{
+///     match tab() {
+///         Tab::Profile => rsx! { KeepAlive { key: "profile", ProfileForm {} } },
+///         Tab::Settings => rsx! { KeepAlive { key: "settings", SettingsForm {} } },
+///     }
+/// }
+/// ```
+#[allow(non_upper_case_globals, non_snake_case)]
+pub fn KeepAlive(props: KeepAliveProps) -> Element {
+    props.children
+}
+
+/// The props of a [`KeepAlive`]
+#[derive(Clone, PartialEq)]
+pub struct KeepAliveProps {
+    children: Element,
+    // Set for the children of a suspense boundary. They are not counted in the limit and keep their parent while cached
+    pub(crate) suspense: bool,
+}
+
+impl KeepAliveProps {
+    /// The props that keep the children of a suspense boundary alive while its fallback is shown
+    pub(crate) fn suspense(children: Element) -> Self {
+        Self {
+            children,
+            suspense: true,
+        }
+    }
+}
+
+#[doc(hidden)]
+pub struct KeepAliveBuilder(Element);
+
+#[allow(missing_docs)]
+impl KeepAliveBuilder {
+    pub fn children(self, children: Element) -> Self {
+        KeepAliveBuilder(children)
+    }
+
+    pub fn build(self) -> KeepAliveProps {
+        KeepAliveProps {
+            children: self.0,
+            suspense: false,
+        }
+    }
+}
+
+impl Properties for KeepAliveProps {
+    type Builder = KeepAliveBuilder;
+    fn builder() -> Self::Builder {
+        KeepAliveBuilder(None)
+    }
+    fn memoize(&mut self, _other: &Self) -> bool {
+        false
+    }
+}
+
+/// Drop the [`KeepAlive`] subtree that is cached under the key, so its hooks and tasks are dropped. The subtree is dropped before the next render.
+///
+/// Does nothing if nothing is cached under the key. A `KeepAlive` that is mounted is not affected.
+///
+/// ```rust, ignore
+/// // The form starts over the next time the profile tab is opened
+/// evict_keep_alive("profile");
+/// ```
+pub fn evict_keep_alive(key: &str) {
+    Runtime::with(|rt| {
+        rt.sender
+            .unbounded_send(SchedulerMsg::EvictKeepAlive(key.to_string()))
+    });
+}
@@ -7,17 +7,27 @@ mod any_props;
 mod arena;
 mod diff;
 mod dirty_scope;
+mod effect;
 mod error_boundary;
 mod events;
 mod fragment;
 mod global_context;
+mod inspect;
+mod keep_alive;
+mod memo;
+mod mutation_codec;
 mod mutations;
 mod nodes;
+mod portal;
+mod presence;
 mod properties;
+mod recorder;
 mod runtime;
+mod scheduler;
 mod scope_arena;
 mod scope_context;
 mod scopes;
+mod suspense;
 mod tasks;
 mod virtual_dom;
 
@@ -29,11 +39,20 @@ pub(crate) mod innerlude {
     pub use crate::events::*;
     pub use crate::fragment::*;
     pub use crate::global_context::*;
+    pub use crate::inspect::*;
+    pub use crate::keep_alive::*;
+    pub use crate::memo::*;
+    pub use crate::mutation_codec::*;
     pub use crate::mutations::*;
     pub use crate::nodes::*;
+    pub use crate::portal::*;
+    pub use crate::presence::*;
     pub use crate::properties::*;
+    pub use crate::recorder::*;
     pub use crate::runtime::{Runtime, RuntimeGuard};
+    pub use crate::scheduler::*;
     pub use crate::scopes::*;
+    pub use crate::suspense::*;
     pub use crate::tasks::*;
     pub use crate::virtual_dom::*;
 
@@ -74,11 +93,15 @@ pub(crate) mod innerlude {
 }
 
 pub use crate::innerlude::{
-    fc_to_builder, generation, schedule_update, schedule_update_any, use_hook, vdom_is_rendering,
-    AnyValue, Attribute, AttributeValue, CapturedError, Component, ComponentFunction, DynamicNode,
-    Element, ElementId, Event, Fragment, HasAttributes, IntoDynNode, Mutation, Mutations,
-    NoOpMutations, Properties, RenderReturn, Runtime, ScopeId, ScopeState, Task, Template,
-    TemplateAttribute, TemplateNode, VComponent, VNode, VNodeInner, VPlaceholder, VText,
+    fc_to_builder, generation, merge_attributes, report_invalid_prop, schedule_update,
+    schedule_update_any, use_hook, use_presence, vdom_is_rendering, AnimatedPresence, AnyValue,
+    Attribute, AttributeValue, CapturedError, Component, ComponentFunction, DebugProp,
+    DebugPropValue, DynamicNode, Element, ElementId, Event, EventPriority, Fragment, HasAttributes,
+    HookInfo, IntoDynNode, InvalidProp, KeepAlive, Memo, Mutation, MutationDecodeError,
+    MutationDecodeErrorKind, MutationDecoder, MutationEncoder, Mutations, NoDebugPropValue,
+    NoOpMutations, Portal, Presence, PropInfo, Properties, RecordedAction, RecordedEntry, Recorder,
+    Recording, RenderReturn, Runtime, ScopeId, ScopeInfo, ScopeState, SuspenseContext, Task,
+    Template, TemplateAttribute, TemplateNode, VComponent, VNode, VNodeInner, VPlaceholder, VText,
     VirtualDom, WriteMutations,
 };
 
@@ -87,14 +110,17 @@ pub use crate::innerlude::{
 /// This includes types like [`Element`], and [`Component`].
 pub mod prelude {
     pub use crate::innerlude::{
-        consume_context, consume_context_from_scope, current_scope_id, fc_to_builder, flush_sync,
-        generation, has_context, needs_update, needs_update_any, parent_scope, provide_context,
-        provide_root_context, remove_future, schedule_update, schedule_update_any, spawn,
-        spawn_forever, suspend, try_consume_context, use_after_render, use_before_render, use_drop,
-        use_error_boundary, use_hook, use_hook_with_cleanup, AnyValue, Attribute, Component,
-        ComponentFunction, Element, ErrorBoundary, Event, EventHandler, Fragment, HasAttributes,
-        IntoAttributeValue, IntoDynNode, OptionStringFromMarker, Properties, Runtime, RuntimeGuard,
-        ScopeId, ScopeState, SuperFrom, SuperInto, Task, Template, TemplateAttribute, TemplateNode,
-        Throw, VNode, VNodeInner, VirtualDom,
+        consume_context, consume_context_from_scope, current_hook_index, current_scope_id,
+        describe_hook, evict_keep_alive, fc_to_builder, flush_sync, generation, has_context,
+        needs_update, needs_update_any, parent_scope, provide_context, provide_root_context,
+        queue_effect, remove_future, schedule_update, schedule_update_any, spawn, spawn_forever,
+        suspend, try_consume_context, use_after_render, use_before_render, use_drop,
+        use_error_boundary, use_hook, use_hook_with_cleanup, use_presence, use_suspense_boundary,
+        AnimatedPresence, AnyValue, Attribute, Component, ComponentFunction, Element,
+        ErrorBoundary, Event, EventHandler, EventPriority, Fragment, HasAttributes,
+        IntoAttributeValue, IntoDynNode, KeepAlive, Memo, OptionStringFromMarker, Portal, Presence,
+        Properties, Runtime, RuntimeGuard, ScopeId, ScopeState, SuperFrom, SuperInto,
+        SuspenseBoundary, SuspenseContext, Task, Template, TemplateAttribute, TemplateNode, Throw,
+        VNode, VNodeInner, VirtualDom,
     };
 }
@@ -0,0 +1,79 @@
+use crate::innerlude::*;
+
+/// Skip rendering a subtree again while its dependencies stay the same.
+///
+/// ## Details
+///
+/// Components are already rendered again only when their props change, but `children` are a new [`Element`] every time the parent renders, so components with children are always rendered again. A `Memo` compares only its `deps` and keeps the children of the last render that had different `deps`, so nothing below it is rendered again until `deps` change.
+///
+/// The children are not updated while `deps` are the same, so every value the children read from the parent should be in `deps`. Components below the `Memo` still render when their own state changes.
+///
+/// ## Example
+///
+/// ```rust, ignore
+/// rsx!{
+///     Memo { deps: (user.id, theme),
+///         ProfileCard { user: user.clone(), theme }
+///     }
+/// }
+/// ```
+#[allow(non_upper_case_globals, non_snake_case)]
+pub fn Memo<D: PartialEq + Clone + 'static>(props: MemoProps<D>) -> Element {
+    props.children
+}
+
+/// The props of a [`Memo`]
+#[derive(Clone)]
+pub struct MemoProps<D> {
+    deps: D,
+    children: Element,
+}
+
+/// The children are left out, so the props are equal while the deps are equal
+impl<D: PartialEq> PartialEq for MemoProps<D> {
+    fn eq(&self, other: &Self) -> bool {
+        self.deps == other.deps
+    }
+}
+
+#[doc(hidden)]
+pub struct MemoBuilder<D> {
+    deps: Option<D>,
+    children: Element,
+}
+
+#[allow(missing_docs)]
+impl<D> MemoBuilder<D> {
+    pub fn deps(mut self, deps: D) -> Self {
+        self.deps = Some(deps);
+        self
+    }
+
+    pub fn children(mut self, children: Element) -> Self {
+        self.children = children;
+        self
+    }
+
+    #[track_caller]
+    pub fn build(self) -> MemoProps<D> {
+        MemoProps {
+            deps: self
+                .deps
+                .expect("Memo needs the deps that its children are rendered from"),
+            children: self.children,
+        }
+    }
+}
+
+impl<D: PartialEq + Clone + 'static> Properties for MemoProps<D> {
+    type Builder = MemoBuilder<D>;
+    fn builder() -> Self::Builder {
+        MemoBuilder {
+            deps: None,
+            children: None,
+        }
+    }
+    fn memoize(&mut self, other: &Self) -> bool {
+        self == other
+    }
+}
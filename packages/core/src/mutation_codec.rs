@@ -0,0 +1,770 @@
+//! A compact binary format for mutations that renderers in another process or on another machine can apply.
+//!
+//! The [`MutationEncoder`] implements [`WriteMutations`] and writes every mutation into a buffer. The [`MutationDecoder`] reads the buffer and writes the same mutations into any other [`WriteMutations`] implementation on the other side.
+//!
+//! Every batch returned by [`MutationEncoder::finish`] starts with the magic bytes `DXM` and the [version](MutationEncoder::VERSION) of the format. Integers are encoded as LEB128 varints. Static strings like tags, attribute names and template names and the paths of templates are only sent the first time they are used, later uses refer to them by index. An encoder must be paired with a single decoder that decodes every batch in order.
+
+use std::{fmt::Display, time::Duration};
+
+use rustc_hash::FxHashMap;
+
+use crate::{
+    arena::ElementId, AttributeValue, ScopeId, Template, TemplateAttribute, TemplateNode,
+    WriteMutations,
+};
+
+const MAGIC: &[u8; 3] = b"DXM";
+
+mod op {
+    pub const REGISTER_TEMPLATE: u8 = 0;
+    pub const APPEND_CHILDREN: u8 = 1;
+    pub const ASSIGN_ID: u8 = 2;
+    pub const CREATE_PLACEHOLDER: u8 = 3;
+    pub const CREATE_TEXT_NODE: u8 = 4;
+    pub const HYDRATE_TEXT: u8 = 5;
+    pub const LOAD_TEMPLATE: u8 = 6;
+    pub const REPLACE_WITH: u8 = 7;
+    pub const REPLACE_PLACEHOLDER: u8 = 8;
+    pub const INSERT_AFTER: u8 = 9;
+    pub const INSERT_BEFORE: u8 = 10;
+    pub const SET_ATTRIBUTE: u8 = 11;
+    pub const SET_TEXT: u8 = 12;
+    pub const NEW_EVENT_LISTENER: u8 = 13;
+    pub const REMOVE_EVENT_LISTENER: u8 = 14;
+    pub const REMOVE: u8 = 15;
+    pub const PUSH_ROOT: u8 = 16;
+    pub const SWAP_SUBTREE: u8 = 17;
+    pub const MARK_SCOPE_DIRTY: u8 = 18;
+    pub const CREATE_SUBTREE_ROOT: u8 = 19;
+    pub const APPEND_TO_SUBTREE: u8 = 20;
+    pub const WAIT_FOR_EXIT: u8 = 21;
+}
+
+mod value {
+    pub const NONE: u8 = 0;
+    pub const TEXT: u8 = 1;
+    pub const FLOAT: u8 = 2;
+    pub const INT: u8 = 3;
+    pub const FALSE: u8 = 4;
+    pub const TRUE: u8 = 5;
+}
+
+mod node {
+    pub const ELEMENT: u8 = 0;
+    pub const TEXT: u8 = 1;
+    pub const DYNAMIC: u8 = 2;
+    pub const DYNAMIC_TEXT: u8 = 3;
+    pub const STATIC_ATTRIBUTE: u8 = 0;
+    pub const DYNAMIC_ATTRIBUTE: u8 = 1;
+}
+
+/// A [`WriteMutations`] implementation that encodes mutations and templates into a compact binary format. Decode the batches with a [`MutationDecoder`].
+///
+/// ```rust
+/// use dioxus::prelude::*;
+/// use dioxus_core::{MutationDecoder, MutationEncoder, Mutations};
+///
+/// let mut dom = VirtualDom::new(|| rsx! { div { "hello" } });
+/// let mut encoder = MutationEncoder::new();
+/// dom.rebuild(&mut encoder);
+/// let bytes = encoder.finish();
+///
+/// // on the other side of the connection
+/// let mut decoder = MutationDecoder::new();
+/// let mut mutations = Mutations::default();
+/// decoder.decode(&bytes, &mut mutations).unwrap();
+/// assert_eq!(mutations.templates.len(), 1);
+/// ```
+pub struct MutationEncoder {
+    buffer: Vec<u8>,
+    strings: FxHashMap<&'static str, usize>,
+    paths: FxHashMap<&'static [u8], usize>,
+}
+
+impl Default for MutationEncoder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MutationEncoder {
+    /// The version of the binary format. Decoders reject batches with a different version.
+    pub const VERSION: u8 = 3;
+
+    /// Create an encoder with an empty batch
+    pub fn new() -> Self {
+        Self {
+            buffer: Self::header(),
+            strings: FxHashMap::default(),
+            paths: FxHashMap::default(),
+        }
+    }
+
+    fn header() -> Vec<u8> {
+        let mut header = MAGIC.to_vec();
+        header.push(Self::VERSION);
+        header
+    }
+
+    /// Check if any mutations were written since the last batch was finished
+    pub fn is_empty(&self) -> bool {
+        self.buffer.len() == MAGIC.len() + 1
+    }
+
+    /// Take the mutations written since the last batch was finished as a batch that can be sent to the decoder
+    pub fn finish(&mut self) -> Vec<u8> {
+        std::mem::replace(&mut self.buffer, Self::header())
+    }
+
+    fn write_usize(&mut self, value: usize) {
+        write_varint(&mut self.buffer, value as u64);
+    }
+
+    fn write_id(&mut self, id: ElementId) {
+        self.write_usize(id.0);
+    }
+
+    fn write_str(&mut self, value: &str) {
+        self.write_usize(value.len());
+        self.buffer.extend_from_slice(value.as_bytes());
+    }
+
+    /// Write a string the decoder has seen as its index plus one, or a zero followed by the string the first time it is used
+    fn write_static_str(&mut self, value: &'static str) {
+        let next = self.strings.len();
+        match self.strings.get(value) {
+            Some(index) => self.write_usize(index + 1),
+            None => {
+                self.strings.insert(value, next);
+                self.write_usize(0);
+                self.write_str(value);
+            }
+        }
+    }
+
+    fn write_optional_static_str(&mut self, value: Option<&'static str>) {
+        match value {
+            Some(value) => {
+                self.buffer.push(1);
+                self.write_static_str(value);
+            }
+            None => self.buffer.push(0),
+        }
+    }
+
+    fn write_path(&mut self, path: &'static [u8]) {
+        let next = self.paths.len();
+        match self.paths.get(path) {
+            Some(index) => self.write_usize(index + 1),
+            None => {
+                self.paths.insert(path, next);
+                self.write_usize(0);
+                self.write_usize(path.len());
+                self.buffer.extend_from_slice(path);
+            }
+        }
+    }
+
+    fn write_template_node(&mut self, template_node: &'static TemplateNode) {
+        match template_node {
+            TemplateNode::Element {
+                tag,
+                namespace,
+                attrs,
+                children,
+            } => {
+                self.buffer.push(node::ELEMENT);
+                self.write_static_str(tag);
+                self.write_optional_static_str(*namespace);
+                self.write_usize(attrs.len());
+                for attr in attrs.iter() {
+                    match attr {
+                        TemplateAttribute::Static {
+                            name,
+                            value,
+                            namespace,
+                        } => {
+                            self.buffer.push(node::STATIC_ATTRIBUTE);
+                            self.write_static_str(name);
+                            self.write_static_str(value);
+                            self.write_optional_static_str(*namespace);
+                        }
+                        TemplateAttribute::Dynamic { id } => {
+                            self.buffer.push(node::DYNAMIC_ATTRIBUTE);
+                            self.write_usize(*id);
+                        }
+                    }
+                }
+                self.write_usize(children.len());
+                for child in children.iter() {
+                    self.write_template_node(child);
+                }
+            }
+            TemplateNode::Text { text } => {
+                self.buffer.push(node::TEXT);
+                self.write_static_str(text);
+            }
+            TemplateNode::Dynamic { id } => {
+                self.buffer.push(node::DYNAMIC);
+                self.write_usize(*id);
+            }
+            TemplateNode::DynamicText { id } => {
+                self.buffer.push(node::DYNAMIC_TEXT);
+                self.write_usize(*id);
+            }
+        }
+    }
+
+    fn write_op_with_id(&mut self, op: u8, id: ElementId) {
+        self.buffer.push(op);
+        self.write_id(id);
+    }
+
+    fn write_op_with_id_and_count(&mut self, op: u8, id: ElementId, m: usize) {
+        self.write_op_with_id(op, id);
+        self.write_usize(m);
+    }
+}
+
+impl WriteMutations for MutationEncoder {
+    fn register_template(&mut self, template: Template) {
+        self.buffer.push(op::REGISTER_TEMPLATE);
+        self.write_static_str(template.name);
+        self.write_usize(template.roots.len());
+        for root in template.roots {
+            self.write_template_node(root);
+        }
+        for paths in [template.node_paths, template.attr_paths] {
+            self.write_usize(paths.len());
+            for path in paths {
+                self.write_path(path);
+            }
+        }
+    }
+
+    fn append_children(&mut self, id: ElementId, m: usize) {
+        self.write_op_with_id_and_count(op::APPEND_CHILDREN, id, m);
+    }
+
+    fn assign_node_id(&mut self, path: &'static [u8], id: ElementId) {
+        self.write_op_with_id(op::ASSIGN_ID, id);
+        self.write_path(path);
+    }
+
+    fn create_placeholder(&mut self, id: ElementId) {
+        self.write_op_with_id(op::CREATE_PLACEHOLDER, id);
+    }
+
+    fn create_text_node(&mut self, value: &str, id: ElementId) {
+        self.write_op_with_id(op::CREATE_TEXT_NODE, id);
+        self.write_str(value);
+    }
+
+    fn hydrate_text_node(&mut self, path: &'static [u8], value: &str, id: ElementId) {
+        self.write_op_with_id(op::HYDRATE_TEXT, id);
+        self.write_path(path);
+        self.write_str(value);
+    }
+
+    fn load_template(&mut self, name: &'static str, index: usize, id: ElementId) {
+        self.write_op_with_id(op::LOAD_TEMPLATE, id);
+        self.write_static_str(name);
+        self.write_usize(index);
+    }
+
+    fn replace_node_with(&mut self, id: ElementId, m: usize) {
+        self.write_op_with_id_and_count(op::REPLACE_WITH, id, m);
+    }
+
+    fn replace_placeholder_with_nodes(&mut self, path: &'static [u8], m: usize) {
+        self.buffer.push(op::REPLACE_PLACEHOLDER);
+        self.write_path(path);
+        self.write_usize(m);
+    }
+
+    fn insert_nodes_after(&mut self, id: ElementId, m: usize) {
+        self.write_op_with_id_and_count(op::INSERT_AFTER, id, m);
+    }
+
+    fn insert_nodes_before(&mut self, id: ElementId, m: usize) {
+        self.write_op_with_id_and_count(op::INSERT_BEFORE, id, m);
+    }
+
+    fn set_attribute(
+        &mut self,
+        name: &'static str,
+        ns: Option<&'static str>,
+        value: &AttributeValue,
+        id: ElementId,
+    ) {
+        self.write_op_with_id(op::SET_ATTRIBUTE, id);
+        self.write_static_str(name);
+        self.write_optional_static_str(ns);
+        match value {
+            AttributeValue::Text(text) => {
+                self.buffer.push(value::TEXT);
+                self.write_str(text);
+            }
+            AttributeValue::Float(float) => {
+                self.buffer.push(value::FLOAT);
+                self.buffer.extend_from_slice(&float.to_le_bytes());
+            }
+            AttributeValue::Int(int) => {
+                self.buffer.push(value::INT);
+                // zigzag encoding keeps small negative numbers small
+                write_varint(&mut self.buffer, ((int << 1) ^ (int >> 63)) as u64);
+            }
+            AttributeValue::Bool(false) => self.buffer.push(value::FALSE),
+            AttributeValue::Bool(true) => self.buffer.push(value::TRUE),
+            AttributeValue::None => self.buffer.push(value::NONE),
+            _ => panic!("Cannot serialize attribute value"),
+        }
+    }
+
+    fn set_node_text(&mut self, value: &str, id: ElementId) {
+        self.write_op_with_id(op::SET_TEXT, id);
+        self.write_str(value);
+    }
+
+    fn create_event_listener(&mut self, name: &'static str, id: ElementId) {
+        self.write_op_with_id(op::NEW_EVENT_LISTENER, id);
+        self.write_static_str(name);
+    }
+
+    fn remove_event_listener(&mut self, name: &'static str, id: ElementId) {
+        self.write_op_with_id(op::REMOVE_EVENT_LISTENER, id);
+        self.write_static_str(name);
+    }
+
+    fn remove_node(&mut self, id: ElementId) {
+        self.write_op_with_id(op::REMOVE, id);
+    }
+
+    fn push_root(&mut self, id: ElementId) {
+        self.write_op_with_id(op::PUSH_ROOT, id);
+    }
+
+    fn create_subtree_root(&mut self, subtree: usize, target: &str) {
+        self.buffer.push(op::CREATE_SUBTREE_ROOT);
+        self.write_usize(subtree);
+        self.write_str(target);
+    }
+
+    fn append_to_subtree(&mut self, subtree: usize, m: usize) {
+        self.buffer.push(op::APPEND_TO_SUBTREE);
+        self.write_usize(subtree);
+        self.write_usize(m);
+    }
+
+    /// The duration is sent in whole milliseconds
+    fn wait_for_exit(&mut self, scope: ScopeId, id: ElementId, duration: Duration) {
+        self.buffer.push(op::WAIT_FOR_EXIT);
+        self.write_usize(scope.0);
+        self.write_id(id);
+        write_varint(&mut self.buffer, duration.as_millis() as u64);
+    }
+
+    fn swap_subtree(&mut self, subtree_index: usize) {
+        self.buffer.push(op::SWAP_SUBTREE);
+        self.write_usize(subtree_index);
+    }
+
+    fn mark_scope_dirty(&mut self, scope_id: ScopeId) {
+        self.buffer.push(op::MARK_SCOPE_DIRTY);
+        self.write_usize(scope_id.0);
+    }
+}
+
+fn write_varint(buffer: &mut Vec<u8>, mut value: u64) {
+    loop {
+        let byte = (value & 0x7f) as u8;
+        value >>= 7;
+        if value == 0 {
+            buffer.push(byte);
+            return;
+        }
+        buffer.push(byte | 0x80);
+    }
+}
+
+/// Decodes batches written by a [`MutationEncoder`] and writes the mutations into any [`WriteMutations`] implementation.
+///
+/// [`WriteMutations`] borrows static strings and templates for the lifetime of the program, so every string and path the decoder sees for the first time and every template is leaked. Templates are only sent once and static strings come from a fixed set in the program that created the mutations, so the leaked memory is bounded.
+#[derive(Default)]
+pub struct MutationDecoder {
+    strings: Vec<&'static str>,
+    paths: Vec<&'static [u8]>,
+}
+
+impl MutationDecoder {
+    /// Create a decoder for a new encoder
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Decode a batch and write its mutations into `to`. If the batch is invalid, the mutations before the error were already written.
+    pub fn decode(
+        &mut self,
+        batch: &[u8],
+        to: &mut impl WriteMutations,
+    ) -> Result<(), MutationDecodeError> {
+        let mut reader = Reader {
+            bytes: batch,
+            position: 0,
+            decoder: self,
+        };
+        reader.read_header()?;
+        while !reader.is_empty() {
+            reader.read_mutation(to)?;
+        }
+        Ok(())
+    }
+}
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+    position: usize,
+    decoder: &'a mut MutationDecoder,
+}
+
+impl Reader<'_> {
+    fn is_empty(&self) -> bool {
+        self.position == self.bytes.len()
+    }
+
+    fn error(&self, kind: MutationDecodeErrorKind) -> MutationDecodeError {
+        MutationDecodeError {
+            position: self.position,
+            kind,
+        }
+    }
+
+    fn read_bytes(&mut self, len: usize) -> Result<&[u8], MutationDecodeError> {
+        let end = self
+            .position
+            .checked_add(len)
+            .filter(|end| *end <= self.bytes.len())
+            .ok_or_else(|| self.error(MutationDecodeErrorKind::UnexpectedEnd))?;
+        let bytes = &self.bytes[self.position..end];
+        self.position = end;
+        Ok(bytes)
+    }
+
+    fn read_u8(&mut self) -> Result<u8, MutationDecodeError> {
+        Ok(self.read_bytes(1)?[0])
+    }
+
+    fn read_header(&mut self) -> Result<(), MutationDecodeError> {
+        if self.read_bytes(MAGIC.len()).ok() != Some(MAGIC) {
+            self.position = 0;
+            return Err(self.error(MutationDecodeErrorKind::InvalidHeader));
+        }
+        match self.read_u8()? {
+            MutationEncoder::VERSION => Ok(()),
+            version => Err(self.error(MutationDecodeErrorKind::UnsupportedVersion(version))),
+        }
+    }
+
+    fn read_varint(&mut self) -> Result<u64, MutationDecodeError> {
+        let mut value = 0u64;
+        for shift in (0..64).step_by(7) {
+            let byte = self.read_u8()?;
+            value |= ((byte & 0x7f) as u64) << shift;
+            if byte & 0x80 == 0 {
+                return Ok(value);
+            }
+        }
+        Err(self.error(MutationDecodeErrorKind::InvalidInteger))
+    }
+
+    fn read_usize(&mut self) -> Result<usize, MutationDecodeError> {
+        let value = self.read_varint()?;
+        usize::try_from(value).map_err(|_| self.error(MutationDecodeErrorKind::InvalidInteger))
+    }
+
+    fn read_id(&mut self) -> Result<ElementId, MutationDecodeError> {
+        self.read_usize().map(ElementId)
+    }
+
+    fn read_str(&mut self) -> Result<&str, MutationDecodeError> {
+        let len = self.read_usize()?;
+        let start = self.position;
+        let bytes = self.read_bytes(len)?;
+        std::str::from_utf8(bytes).map_err(|_| MutationDecodeError {
+            position: start,
+            kind: MutationDecodeErrorKind::InvalidString,
+        })
+    }
+
+    fn read_static_str(&mut self) -> Result<&'static str, MutationDecodeError> {
+        let start = self.position;
+        if let Some(index) = self.read_usize()?.checked_sub(1) {
+            return self
+                .decoder
+                .strings
+                .get(index)
+                .copied()
+                .ok_or(MutationDecodeError {
+                    position: start,
+                    kind: MutationDecodeErrorKind::UnknownIndex(index),
+                });
+        }
+        let string: &'static str = Box::leak(self.read_str()?.to_string().into_boxed_str());
+        self.decoder.strings.push(string);
+        Ok(string)
+    }
+
+    fn read_optional_static_str(&mut self) -> Result<Option<&'static str>, MutationDecodeError> {
+        match self.read_u8()? {
+            0 => Ok(None),
+            _ => self.read_static_str().map(Some),
+        }
+    }
+
+    fn read_path(&mut self) -> Result<&'static [u8], MutationDecodeError> {
+        let start = self.position;
+        if let Some(index) = self.read_usize()?.checked_sub(1) {
+            return self
+                .decoder
+                .paths
+                .get(index)
+                .copied()
+                .ok_or(MutationDecodeError {
+                    position: start,
+                    kind: MutationDecodeErrorKind::UnknownIndex(index),
+                });
+        }
+        let len = self.read_usize()?;
+        let path: &'static [u8] = Box::leak(self.read_bytes(len)?.to_vec().into_boxed_slice());
+        self.decoder.paths.push(path);
+        Ok(path)
+    }
+
+    fn read_list<T>(
+        &mut self,
+        mut read: impl FnMut(&mut Self) -> Result<T, MutationDecodeError>,
+    ) -> Result<&'static [T], MutationDecodeError> {
+        let len = self.read_usize()?;
+        // Every item takes at least one byte, so don't trust lengths longer than the rest of the batch
+        let mut items = Vec::with_capacity(len.min(self.bytes.len() - self.position));
+        for _ in 0..len {
+            items.push(read(self)?);
+        }
+        Ok(Box::leak(items.into_boxed_slice()))
+    }
+
+    fn read_template_node(&mut self) -> Result<TemplateNode, MutationDecodeError> {
+        let start = self.position;
+        Ok(match self.read_u8()? {
+            node::ELEMENT => TemplateNode::Element {
+                tag: self.read_static_str()?,
+                namespace: self.read_optional_static_str()?,
+                attrs: self.read_list(|reader| {
+                    let start = reader.position;
+                    Ok(match reader.read_u8()? {
+                        node::STATIC_ATTRIBUTE => TemplateAttribute::Static {
+                            name: reader.read_static_str()?,
+                            value: reader.read_static_str()?,
+                            namespace: reader.read_optional_static_str()?,
+                        },
+                        node::DYNAMIC_ATTRIBUTE => TemplateAttribute::Dynamic {
+                            id: reader.read_usize()?,
+                        },
+                        tag => {
+                            return Err(MutationDecodeError {
+                                position: start,
+                                kind: MutationDecodeErrorKind::InvalidTag(tag),
+                            })
+                        }
+                    })
+                })?,
+                children: self.read_list(Self::read_template_node)?,
+            },
+            node::TEXT => TemplateNode::Text {
+                text: self.read_static_str()?,
+            },
+            node::DYNAMIC => TemplateNode::Dynamic {
+                id: self.read_usize()?,
+            },
+            node::DYNAMIC_TEXT => TemplateNode::DynamicText {
+                id: self.read_usize()?,
+            },
+            tag => {
+                return Err(MutationDecodeError {
+                    position: start,
+                    kind: MutationDecodeErrorKind::InvalidTag(tag),
+                })
+            }
+        })
+    }
+
+    fn read_attribute_value(&mut self) -> Result<AttributeValue, MutationDecodeError> {
+        let start = self.position;
+        Ok(match self.read_u8()? {
+            value::NONE => AttributeValue::None,
+            value::TEXT => AttributeValue::Text(self.read_str()?.to_string()),
+            value::FLOAT => {
+                let bytes = self.read_bytes(8)?;
+                AttributeValue::Float(f64::from_le_bytes(bytes.try_into().unwrap()))
+            }
+            value::INT => {
+                let zigzag = self.read_varint()?;
+                AttributeValue::Int((zigzag >> 1) as i64 ^ -((zigzag & 1) as i64))
+            }
+            value::FALSE => AttributeValue::Bool(false),
+            value::TRUE => AttributeValue::Bool(true),
+            tag => {
+                return Err(MutationDecodeError {
+                    position: start,
+                    kind: MutationDecodeErrorKind::InvalidTag(tag),
+                })
+            }
+        })
+    }
+
+    fn read_mutation(&mut self, to: &mut impl WriteMutations) -> Result<(), MutationDecodeError> {
+        let start = self.position;
+        match self.read_u8()? {
+            op::REGISTER_TEMPLATE => {
+                let template = Template {
+                    name: self.read_static_str()?,
+                    roots: self.read_list(Self::read_template_node)?,
+                    node_paths: self.read_list(Self::read_path)?,
+                    attr_paths: self.read_list(Self::read_path)?,
+                };
+                to.register_template(template);
+            }
+            op::APPEND_CHILDREN => {
+                let id = self.read_id()?;
+                to.append_children(id, self.read_usize()?);
+            }
+            op::ASSIGN_ID => {
+                let id = self.read_id()?;
+                to.assign_node_id(self.read_path()?, id);
+            }
+            op::CREATE_PLACEHOLDER => to.create_placeholder(self.read_id()?),
+            op::CREATE_TEXT_NODE => {
+                let id = self.read_id()?;
+                to.create_text_node(self.read_str()?, id);
+            }
+            op::HYDRATE_TEXT => {
+                let id = self.read_id()?;
+                let path = self.read_path()?;
+                to.hydrate_text_node(path, self.read_str()?, id);
+            }
+            op::LOAD_TEMPLATE => {
+                let id = self.read_id()?;
+                let name = self.read_static_str()?;
+                to.load_template(name, self.read_usize()?, id);
+            }
+            op::REPLACE_WITH => {
+                let id = self.read_id()?;
+                to.replace_node_with(id, self.read_usize()?);
+            }
+            op::REPLACE_PLACEHOLDER => {
+                let path = self.read_path()?;
+                to.replace_placeholder_with_nodes(path, self.read_usize()?);
+            }
+            op::INSERT_AFTER => {
+                let id = self.read_id()?;
+                to.insert_nodes_after(id, self.read_usize()?);
+            }
+            op::INSERT_BEFORE => {
+                let id = self.read_id()?;
+                to.insert_nodes_before(id, self.read_usize()?);
+            }
+            op::SET_ATTRIBUTE => {
+                let id = self.read_id()?;
+                let name = self.read_static_str()?;
+                let ns = self.read_optional_static_str()?;
+                to.set_attribute(name, ns, &self.read_attribute_value()?, id);
+            }
+            op::SET_TEXT => {
+                let id = self.read_id()?;
+                to.set_node_text(self.read_str()?, id);
+            }
+            op::NEW_EVENT_LISTENER => {
+                let id = self.read_id()?;
+                to.create_event_listener(self.read_static_str()?, id);
+            }
+            op::REMOVE_EVENT_LISTENER => {
+                let id = self.read_id()?;
+                to.remove_event_listener(self.read_static_str()?, id);
+            }
+            op::REMOVE => to.remove_node(self.read_id()?),
+            op::PUSH_ROOT => to.push_root(self.read_id()?),
+            op::CREATE_SUBTREE_ROOT => {
+                let subtree = self.read_usize()?;
+                to.create_subtree_root(subtree, self.read_str()?);
+            }
+            op::APPEND_TO_SUBTREE => {
+                let subtree = self.read_usize()?;
+                to.append_to_subtree(subtree, self.read_usize()?);
+            }
+            op::WAIT_FOR_EXIT => {
+                let scope = ScopeId(self.read_usize()?);
+                let id = self.read_id()?;
+                to.wait_for_exit(scope, id, Duration::from_millis(self.read_varint()?));
+            }
+            op::SWAP_SUBTREE => to.swap_subtree(self.read_usize()?),
+            op::MARK_SCOPE_DIRTY => to.mark_scope_dirty(ScopeId(self.read_usize()?)),
+            tag => {
+                return Err(MutationDecodeError {
+                    position: start,
+                    kind: MutationDecodeErrorKind::InvalidTag(tag),
+                })
+            }
+        }
+        Ok(())
+    }
+}
+
+/// An error that occurred while decoding a batch of mutations
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MutationDecodeError {
+    /// The byte offset in the batch the error occurred at
+    pub position: usize,
+    /// What went wrong
+    pub kind: MutationDecodeErrorKind,
+}
+
+/// The kind of a [`MutationDecodeError`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum MutationDecodeErrorKind {
+    /// The batch doesn't start with the magic bytes of the format
+    InvalidHeader,
+    /// The batch was encoded with a different version of the format
+    UnsupportedVersion(u8),
+    /// The batch ended in the middle of a mutation
+    UnexpectedEnd,
+    /// An unknown mutation, attribute value or template node
+    InvalidTag(u8),
+    /// An integer is too large
+    InvalidInteger,
+    /// A string is not valid UTF-8
+    InvalidString,
+    /// A string or path refers to an index the decoder has not seen. The batches were decoded out of order or by a different decoder
+    UnknownIndex(usize),
+}
+
+impl Display for MutationDecodeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.kind {
+            MutationDecodeErrorKind::InvalidHeader => write!(f, "missing the mutation header")?,
+            MutationDecodeErrorKind::UnsupportedVersion(version) => write!(
+                f,
+                "unsupported mutation format version {version}, expected {}",
+                MutationEncoder::VERSION
+            )?,
+            MutationDecodeErrorKind::UnexpectedEnd => write!(f, "unexpected end of mutations")?,
+            MutationDecodeErrorKind::InvalidTag(tag) => write!(f, "invalid tag {tag}")?,
+            MutationDecodeErrorKind::InvalidInteger => write!(f, "invalid integer")?,
+            MutationDecodeErrorKind::InvalidString => write!(f, "invalid UTF-8 string")?,
+            MutationDecodeErrorKind::UnknownIndex(index) => write!(f, "unknown index {index}")?,
+        }
+        write!(f, " at position {}", self.position)
+    }
+}
+
+impl std::error::Error for MutationDecodeError {}
@@ -1,3 +1,5 @@
+use std::time::Duration;
+
 use rustc_hash::FxHashSet;
 
 use crate::{arena::ElementId, AttributeValue, ScopeId, Template};
@@ -130,6 +132,37 @@ pub trait WriteMutations {
     /// Id: The ID of the root node to push.
     fn push_root(&mut self, id: ElementId);
 
+    /// Create the root of a subtree that portals render their children into.
+    ///
+    /// The target is chosen by the portal and renderers decide what it means. The web and desktop renderers look up the element with that id and create it if it does not exist.
+    ///
+    /// Subtree: The index of the subtree. If a subtree with this index already exists, it is replaced.
+    /// Target: The mount point the portal chose for the subtree.
+    ///
+    /// Renderers that don't support portals can keep the default, which does nothing. The children of portals are then not shown.
+    fn create_subtree_root(&mut self, _subtree: usize, _target: &str) {}
+
+    /// Append the m nodes on the stack to the root of a subtree
+    ///
+    /// Subtree: The index of the subtree to append to.
+    /// M: The number of nodes on the stack to append to the subtree.
+    ///
+    /// The default does nothing, so renderers that keep a stack of nodes and don't support portals should override it to pop the m nodes.
+    fn append_to_subtree(&mut self, _subtree: usize, _m: usize) {}
+
+    /// Wait for the exit animation of an animated presence that was removed, then call [`ScopeId::finish_exit`] with its scope.
+    ///
+    /// The nodes of the presence stay in the renderer until the exit animation ends. Renderers that can detect the end of an animation wait for it on the node, others wait for the duration.
+    ///
+    /// Scope: The scope of the animated presence.
+    /// Id: The ID of the first node of the presence.
+    /// Duration: How long the exit animation takes.
+    ///
+    /// Renderers without animations can keep the default, which finishes the exit right away so the nodes are removed in the next render.
+    fn wait_for_exit(&mut self, scope: ScopeId, _id: ElementId, _duration: Duration) {
+        scope.finish_exit();
+    }
+
     /// Swap to a new subtree
     fn swap_subtree(&mut self, _subtree_index: usize) {}
 
@@ -323,6 +356,36 @@ pub enum Mutation {
         /// The ID of the root node to push.
         id: ElementId,
     },
+
+    /// Create the root of a subtree that portals render their children into.
+    CreateSubtreeRoot {
+        /// The index of the subtree. If a subtree with this index already exists, it is replaced.
+        subtree: usize,
+
+        /// The mount point the portal chose for the subtree.
+        target: String,
+    },
+
+    /// Append the m nodes on the stack to the root of a subtree
+    AppendToSubtree {
+        /// The index of the subtree to append to.
+        subtree: usize,
+
+        /// The number of nodes on the stack to append to the subtree.
+        m: usize,
+    },
+
+    /// Wait for the exit animation of an animated presence that was removed, then call [`ScopeId::finish_exit`] with its scope.
+    WaitForExit {
+        /// The scope of the animated presence.
+        scope: ScopeId,
+
+        /// The ID of the first node of the presence.
+        id: ElementId,
+
+        /// How long the exit animation takes.
+        duration: Duration,
+    },
 }
 
 /// A static list of mutations that can be applied to the DOM. Note: this list does not contain any `Any` attribute values
@@ -458,6 +521,25 @@ impl WriteMutations for Mutations {
         self.edits.push(Mutation::PushRoot { id })
     }
 
+    fn create_subtree_root(&mut self, subtree: usize, target: &str) {
+        self.edits.push(Mutation::CreateSubtreeRoot {
+            subtree,
+            target: target.into(),
+        })
+    }
+
+    fn append_to_subtree(&mut self, subtree: usize, m: usize) {
+        self.edits.push(Mutation::AppendToSubtree { subtree, m })
+    }
+
+    fn wait_for_exit(&mut self, scope: ScopeId, id: ElementId, duration: Duration) {
+        self.edits.push(Mutation::WaitForExit {
+            scope,
+            id,
+            duration,
+        })
+    }
+
     fn swap_subtree(&mut self, _subtree_index: usize) {}
 
     fn mark_scope_dirty(&mut self, scope_id: ScopeId) {
@@ -509,4 +591,10 @@ impl WriteMutations for NoOpMutations {
     fn remove_node(&mut self, _: ElementId) {}
 
     fn push_root(&mut self, _: ElementId) {}
+
+    fn create_subtree_root(&mut self, _: usize, _: &str) {}
+
+    fn append_to_subtree(&mut self, _: usize, _: usize) {}
+
+    fn wait_for_exit(&mut self, _: ScopeId, _: ElementId, _: Duration) {}
 }
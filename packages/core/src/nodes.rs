@@ -118,7 +118,7 @@ pub struct VNodeInner {
     /// Would be represented as:
     /// ```rust, ignore
     /// [
-    ///     [class, every attribute in attrs sorted by name], // Slot 0 in the template
+    ///     [class, every attribute in attrs], // Slot 0 in the template
     ///     [color], // Slot 1 in the template
     /// ]
     /// ```
@@ -530,6 +530,7 @@ impl VComponent {
         let props = Box::new(VProps::new(
             component,
             <P as Properties>::memoize,
+            <P as Properties>::debug_props,
             props,
             fn_name,
         ));
@@ -662,6 +663,59 @@ impl Attribute {
             value: value.into_value(),
         }
     }
+
+    /// The separator used to join two values of this attribute if it is set more than once, like the classes of an element
+    fn separator(&self) -> Option<&'static str> {
+        match (self.name, self.namespace) {
+            ("class" | "dioxus-prevent-default", None) => Some(" "),
+            ("style", None) => Some(";"),
+            _ => None,
+        }
+    }
+
+    fn merge(&mut self, other: Attribute) {
+        let separator = self.separator();
+        self.volatile |= other.volatile;
+        match (&mut self.value, other.value, separator) {
+            // An attribute that is not set doesn't change the value that was set before it
+            (_, AttributeValue::None, _) => {}
+            (AttributeValue::Text(text), AttributeValue::Text(other), Some(separator)) => {
+                if text.is_empty() {
+                    *text = other;
+                } else if !other.is_empty() {
+                    text.push_str(separator);
+                    text.push_str(&other);
+                }
+            }
+            (value, other, _) => *value = other,
+        }
+    }
+}
+
+/// Merge the attributes of an element that are set more than once, like an attribute that is set on the element and again in an attribute spread.
+///
+/// The attributes keep the order they were written in. Classes, styles and prevented events are joined, listeners are all kept and any other attribute takes the last value that was written.
+#[doc(hidden)]
+pub fn merge_attributes(attributes: Vec<Attribute>) -> Box<[Attribute]> {
+    let mut merged: Vec<Attribute> = Vec::with_capacity(attributes.len());
+    for attribute in attributes {
+        let is_listener =
+            |attribute: &Attribute| matches!(attribute.value, AttributeValue::Listener(_));
+        if is_listener(&attribute) {
+            merged.push(attribute);
+            continue;
+        }
+        let existing = merged.iter_mut().find(|existing| {
+            existing.name == attribute.name
+                && existing.namespace == attribute.namespace
+                && !is_listener(existing)
+        });
+        match existing {
+            Some(existing) => existing.merge(attribute),
+            None => merged.push(attribute),
+        }
+    }
+    merged.into_boxed_slice()
 }
 
 /// Any of the built-in values that the Dioxus VirtualDom supports as dynamic attributes on elements
@@ -0,0 +1,98 @@
+use crate::{arena::ElementId, innerlude::Properties, runtime::Runtime, Element};
+
+/// A portal that is mounted in the tree
+pub(crate) struct PortalMount {
+    /// The target the children of the portal are mounted at
+    pub(crate) target: String,
+    /// The placeholder that marks the position of the portal in its parent
+    pub(crate) placeholder: ElementId,
+}
+
+/// The props of a [`Portal`]
+#[derive(Clone, PartialEq)]
+pub struct PortalProps {
+    target: String,
+    children: Element,
+}
+
+impl PortalProps {
+    /**
+    Create a builder for building `PortalProps`.
+    On the builder, call `.target(...)`, `.children(...)`(optional) to set the values of the fields.
+    Finally, call `.build()` to create the instance of `PortalProps`.
+                        */
+    pub fn builder() -> PortalPropsBuilder<()> {
+        PortalPropsBuilder {
+            target: (),
+            children: None,
+        }
+    }
+}
+
+impl Properties for PortalProps {
+    type Builder = PortalPropsBuilder<()>;
+    fn builder() -> Self::Builder {
+        PortalProps::builder()
+    }
+    fn memoize(&mut self, _: &Self) -> bool {
+        false
+    }
+}
+
+#[must_use]
+#[doc(hidden)]
+pub struct PortalPropsBuilder<Target> {
+    target: Target,
+    children: Element,
+}
+
+#[allow(missing_docs)]
+impl<Target> PortalPropsBuilder<Target> {
+    pub fn target(self, target: impl ToString) -> PortalPropsBuilder<String> {
+        PortalPropsBuilder {
+            target: target.to_string(),
+            children: self.children,
+        }
+    }
+
+    pub fn children(self, children: Element) -> Self {
+        Self { children, ..self }
+    }
+}
+
+#[allow(missing_docs)]
+impl PortalPropsBuilder<String> {
+    pub fn build(self) -> PortalProps {
+        PortalProps {
+            target: self.target,
+            children: self.children,
+        }
+    }
+}
+
+/// Render children into a different mount point than the one the component is in.
+///
+/// ## Details
+///
+/// The children of a portal are created as usual and then moved to the root of a separate subtree with [`crate::WriteMutations::append_to_subtree`]. A placeholder marks the position of the portal in its parent.
+///
+/// The renderer decides what the target means. The web and desktop renderers mount the children in the element with the target as its id, and create that element at the end of the body if it does not exist. The TUI renders the children in an absolutely positioned overlay layer.
+///
+/// Events from the children bubble up through the components above the portal, not through the elements around the mount point. Changing the target moves the children to the new mount point.
+///
+/// ## Example
+///
+/// ```rust, ignore
+/// rsx!{
+///     Portal {
+///         target: "modals",
+///         div { class: "modal", "Are you sure?" }
+///     }
+/// }
+/// ```
+#[allow(non_upper_case_globals, non_snake_case)]
+pub fn Portal(props: PortalProps) -> Element {
+    Runtime::with_current_scope(|scope| *scope.portal_target.borrow_mut() = Some(props.target))
+        .expect("Portals must be rendered inside of a component");
+    props.children
+}
@@ -0,0 +1,165 @@
+use std::{cell::Cell, rc::Rc, time::Duration};
+
+use crate::{
+    innerlude::{
+        current_scope_id, provide_context, try_consume_context, use_hook, Properties, SchedulerMsg,
+    },
+    runtime::Runtime,
+    Element, ScopeId,
+};
+
+/// The exit animation an [`AnimatedPresence`] plays when it is removed
+pub(crate) struct ExitAnimation {
+    pub(crate) presence: Presence,
+    pub(crate) duration: Duration,
+}
+
+/// Whether the closest [`AnimatedPresence`] is being removed. Get it with [`use_presence`].
+#[derive(Clone)]
+pub struct Presence {
+    scope: Option<ScopeId>,
+    exiting: Rc<Cell<bool>>,
+}
+
+impl Presence {
+    /// Check if the [`AnimatedPresence`] was removed and is playing its exit animation.
+    ///
+    /// Every component below the presence renders again when the exit animation starts, so the nodes can change to their exit state.
+    pub fn is_exiting(&self) -> bool {
+        self.exiting.get()
+    }
+
+    /// Remove the nodes of the exiting [`AnimatedPresence`] without waiting for the renderer. Does nothing if the presence is not exiting.
+    pub fn finish_exit(&self) {
+        if let Some(scope) = self.scope.filter(|_| self.is_exiting()) {
+            scope.finish_exit();
+        }
+    }
+
+    pub(crate) fn set_exiting(&self) {
+        self.exiting.set(true);
+    }
+}
+
+/// Get the [`Presence`] of the closest [`AnimatedPresence`] above the current component. Outside of an animated presence, the presence never exits.
+pub fn use_presence() -> Presence {
+    use_hook(|| {
+        try_consume_context::<Presence>().unwrap_or_else(|| Presence {
+            scope: None,
+            exiting: Default::default(),
+        })
+    })
+}
+
+impl ScopeId {
+    /// Finish the exit animation of the [`AnimatedPresence`] with this scope id. Its nodes are removed the next time the VirtualDom renders.
+    ///
+    /// Renderers call this when the exit animation they waited for with [`crate::WriteMutations::wait_for_exit`] ends. Does nothing if the scope is not exiting.
+    pub fn finish_exit(self) {
+        Runtime::with(|rt| rt.sender.unbounded_send(SchedulerMsg::ExitFinished(self)));
+    }
+}
+
+/// The props of an [`AnimatedPresence`]
+#[derive(Clone, PartialEq)]
+pub struct AnimatedPresenceProps {
+    exit_duration: Duration,
+    children: Element,
+}
+
+impl AnimatedPresenceProps {
+    /**
+    Create a builder for building `AnimatedPresenceProps`.
+    On the builder, call `.exit_duration(...)`(optional), `.children(...)`(optional) to set the values of the fields.
+    Finally, call `.build()` to create the instance of `AnimatedPresenceProps`.
+                        */
+    pub fn builder() -> AnimatedPresencePropsBuilder {
+        AnimatedPresencePropsBuilder {
+            exit_duration: Duration::from_millis(300),
+            children: None,
+        }
+    }
+}
+
+impl Properties for AnimatedPresenceProps {
+    type Builder = AnimatedPresencePropsBuilder;
+    fn builder() -> Self::Builder {
+        AnimatedPresenceProps::builder()
+    }
+    fn memoize(&mut self, _: &Self) -> bool {
+        false
+    }
+}
+
+#[must_use]
+#[doc(hidden)]
+pub struct AnimatedPresencePropsBuilder {
+    exit_duration: Duration,
+    children: Element,
+}
+
+#[allow(missing_docs)]
+impl AnimatedPresencePropsBuilder {
+    pub fn exit_duration(self, exit_duration: Duration) -> Self {
+        Self {
+            exit_duration,
+            ..self
+        }
+    }
+
+    pub fn children(self, children: Element) -> Self {
+        Self { children, ..self }
+    }
+
+    pub fn build(self) -> AnimatedPresenceProps {
+        AnimatedPresenceProps {
+            exit_duration: self.exit_duration,
+            children: self.children,
+        }
+    }
+}
+
+/// Keep the nodes of the children in the renderer until their exit animation ends.
+///
+/// ## Details
+///
+/// When an `AnimatedPresence` is removed, its nodes are not removed from the renderer right away. The components below it render again with [`Presence::is_exiting`] set, so they can switch to an exit class or style, and the renderer is asked to wait for the exit animation with [`crate::WriteMutations::wait_for_exit`]. The nodes are removed when the animation ends.
+///
+/// The web renderer waits for a `transitionend` event on the first node of the presence, or for the exit duration if no transition ends before then. Other renderers wait for the exit duration. Components below the presence can also end the animation early with [`Presence::finish_exit`].
+///
+/// Nodes that replace the presence are inserted after the exiting nodes. Enter animations don't need support from the VirtualDom: CSS animations play when the nodes are created.
+///
+/// The exit animation only plays if the presence itself is removed. If an element around the presence is removed, the nodes of the presence are removed with it.
+///
+/// ## Example
+///
+/// ```rust, ignore
+/// fn Toast() -> Element {
+///     let presence = use_presence();
+///     let class = if presence.is_exiting() { "toast fade-out" } else { "toast" };
+///     rsx! { div { class, "Saved" } }
+/// }
+///
+/// rsx!{
+///     if show_toast() {
+///         AnimatedPresence { exit_duration: Duration::from_millis(500), Toast {} }
+///     }
+/// }
+/// ```
+#[allow(non_upper_case_globals, non_snake_case)]
+pub fn AnimatedPresence(props: AnimatedPresenceProps) -> Element {
+    let presence = use_hook(|| {
+        provide_context(Presence {
+            scope: current_scope_id(),
+            exiting: Default::default(),
+        })
+    });
+    Runtime::with_current_scope(|scope| {
+        *scope.exit_animation.borrow_mut() = Some(ExitAnimation {
+            presence,
+            duration: props.exit_duration,
+        })
+    })
+    .expect("Animated presences must be rendered inside of a component");
+    props.children
+}
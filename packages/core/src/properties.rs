@@ -34,6 +34,11 @@ pub trait Properties: Clone + Sized + 'static {
     /// Compare two props to see if they are memoizable.
     fn memoize(&mut self, other: &Self) -> bool;
 
+    /// Describe the props for developer tools. The derive macro lists every field with its [`Debug`](std::fmt::Debug) output if the field implements it.
+    fn debug_props(&self) -> Vec<PropInfo> {
+        Vec::new()
+    }
+
     /// Create a component from the props.
     fn into_vcomponent<M: 'static>(
         self,
@@ -161,6 +166,15 @@ impl<'a> SuperFrom<&'a str, OptionStringFromMarker> for Option<String> {
     }
 }
 
+#[doc(hidden)]
+pub struct StringArgumentsFromMarker;
+
+impl<'a> SuperFrom<Arguments<'a>, StringArgumentsFromMarker> for String {
+    fn super_from(input: Arguments<'a>) -> Self {
+        input.to_string()
+    }
+}
+
 #[doc(hidden)]
 pub struct OptionArgumentsFromMarker;
 
@@ -182,6 +196,7 @@ fn from_props_compiles() {
     // // T -> From<T> works
     let option: i64 = 0i32.super_into();
     let option: String = "hello world".super_into();
+    let option: String = format_args!("hello {}", "world").super_into();
 
     // T -> Option works
     let option: Option<i32> = 0i32.super_into();
@@ -0,0 +1,161 @@
+//! Recording everything that drives a [`VirtualDom`] so it can be replayed into a fresh one, for time-travel debugging and reproducing bugs.
+//!
+//! A [`Recorder`] passed to [`VirtualDom::record`] logs every event delivered with [`VirtualDom::handle_event`], every template replaced with [`VirtualDom::replace_template`] and every render, with the time since the recording started. [`Recording::replay`] runs the same steps on another VirtualDom.
+//!
+//! Event data is only known to the renderer, so the recorder encodes it with a function from the renderer and the replay decodes it with another one. With the `serialize` feature the [`Recording`] itself implements `Serialize` and `Deserialize`. Like other types that hold a [`Template`](crate::Template), it is deserialized from `'static` data, for example with `serde_json::from_reader`.
+//!
+//! Replaying is deterministic as long as the app only changes in response to the recorded events. Work that finishes in async tasks, like timers or requests, is not recorded.
+
+use std::{any::Any, rc::Rc, time::Duration};
+
+use crate::{arena::ElementId, innerlude::WriteMutations, nodes::Template, VirtualDom};
+
+type EncodeEvent = Box<dyn Fn(&str, &dyn Any) -> Option<String>>;
+
+/// Logs the events, template changes and renders of a [`VirtualDom`]
+pub struct Recorder {
+    encode: EncodeEvent,
+    clock: Box<dyn Fn() -> Duration>,
+    recording: Recording,
+}
+
+impl Recorder {
+    /// Create a recorder that encodes the data of events with `encode`. It receives the name and the data of the event, and returns `None` for data it can't encode.
+    ///
+    /// Times are measured from the creation of the recorder. `std::time::Instant` is not available on `wasm32-unknown-unknown`, so the times are zero there unless a clock is set with [`Recorder::with_clock`].
+    pub fn new(encode: impl Fn(&str, &dyn Any) -> Option<String> + 'static) -> Self {
+        #[cfg(not(target_arch = "wasm32"))]
+        let clock = {
+            let start = std::time::Instant::now();
+            move || start.elapsed()
+        };
+        #[cfg(target_arch = "wasm32")]
+        let clock = || Duration::ZERO;
+
+        Self {
+            encode: Box::new(encode),
+            clock: Box::new(clock),
+            recording: Recording::default(),
+        }
+    }
+
+    /// Measure the time of every entry with `clock`, like `performance.now()` in the browser
+    pub fn with_clock(mut self, clock: impl Fn() -> Duration + 'static) -> Self {
+        self.clock = Box::new(clock);
+        self
+    }
+
+    pub(crate) fn push(&mut self, action: RecordedAction) {
+        let time = (self.clock)();
+        self.recording.entries.push(RecordedEntry { time, action });
+    }
+
+    pub(crate) fn push_event(
+        &mut self,
+        name: &str,
+        data: &dyn Any,
+        element: ElementId,
+        bubbles: bool,
+    ) {
+        let data = (self.encode)(name, data);
+        self.push(RecordedAction::Event {
+            name: name.to_string(),
+            element,
+            bubbles,
+            data,
+        });
+    }
+
+    /// The entries recorded so far
+    pub fn recording(&self) -> &Recording {
+        &self.recording
+    }
+
+    /// Stop recording and return the log
+    pub fn finish(self) -> Recording {
+        self.recording
+    }
+}
+
+/// The log of a [`Recorder`]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serialize", serde(bound(deserialize = "'de: 'static")))]
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Recording {
+    /// The recorded entries in the order they happened
+    pub entries: Vec<RecordedEntry>,
+}
+
+/// One step of a [`Recording`]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serialize", serde(bound(deserialize = "'de: 'static")))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct RecordedEntry {
+    /// The time since the recording started
+    pub time: Duration,
+    /// What happened
+    pub action: RecordedAction,
+}
+
+/// Something that drove a [`VirtualDom`]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serialize", serde(bound(deserialize = "'de: 'static")))]
+#[derive(Debug, Clone, PartialEq)]
+pub enum RecordedAction {
+    /// The first render with [`VirtualDom::rebuild`]
+    Rebuild,
+    /// An event delivered with [`VirtualDom::handle_event`]
+    Event {
+        /// The name of the event, like `click`
+        name: String,
+        /// The element the event was delivered to
+        element: ElementId,
+        /// If the event bubbles
+        bubbles: bool,
+        /// The data of the event encoded by the recorder, or `None` if the recorder couldn't encode it
+        data: Option<String>,
+    },
+    /// A template replaced with [`VirtualDom::replace_template`]
+    ReplaceTemplate(Template),
+    /// A render with [`VirtualDom::render_immediate`]
+    Render,
+}
+
+impl Recording {
+    /// Run the recorded steps on `dom`, which should be a fresh VirtualDom of the same app if the recording started before the first render
+    ///
+    /// `decode` turns the name and encoded data of an event back into the data its listeners expect. Events whose data was not encoded or can't be decoded are skipped.
+    /// Effects that run between renders in the app run after every replayed render.
+    pub fn replay(
+        &self,
+        dom: &mut VirtualDom,
+        decode: impl Fn(&str, &str) -> Option<Rc<dyn Any>>,
+        to: &mut impl WriteMutations,
+    ) {
+        for entry in &self.entries {
+            match &entry.action {
+                RecordedAction::Rebuild => {
+                    dom.rebuild(to);
+                    dom.run_effects();
+                }
+                RecordedAction::Event {
+                    name,
+                    element,
+                    bubbles,
+                    data,
+                } => {
+                    let Some(data) = data.as_deref().and_then(|data| decode(name, data)) else {
+                        tracing::warn!("Skipping the {name} event without data in the replay");
+                        continue;
+                    };
+                    dom.handle_event(name, data, *element, *bubbles);
+                }
+                RecordedAction::ReplaceTemplate(template) => dom.replace_template(*template),
+                RecordedAction::Render => {
+                    dom.render_immediate(to);
+                    dom.run_effects();
+                }
+            }
+        }
+    }
+}
@@ -1,4 +1,5 @@
 use crate::{
+    effect::Effect,
     innerlude::{LocalTask, SchedulerMsg},
     scope_context::Scope,
     scopes::ScopeId,
@@ -6,6 +7,7 @@ use crate::{
 };
 use std::{
     cell::{Cell, Ref, RefCell},
+    collections::VecDeque,
     rc::Rc,
     sync::Arc,
 };
@@ -29,6 +31,9 @@ pub struct Runtime {
     /// Tasks created with cx.spawn
     pub(crate) tasks: RefCell<slab::Slab<Rc<LocalTask>>>,
 
+    /// Effects that run after the next render is committed
+    pub(crate) pending_effects: RefCell<VecDeque<Effect>>,
+
     pub(crate) sender: futures_channel::mpsc::UnboundedSender<SchedulerMsg>,
 
     // the virtualdom will hold this lock while it's doing syncronous work
@@ -48,6 +53,7 @@ impl Runtime {
             scope_stack: Default::default(),
             current_task: Default::default(),
             tasks: Default::default(),
+            pending_effects: Default::default(),
         })
     }
 
@@ -79,6 +85,9 @@ impl Runtime {
                         self.remove_task(id);
                     }
 
+                    // Descriptions may capture the values of hooks, so drop them first
+                    scope.hook_descriptions.take();
+
                     // Drop all hooks in reverse order in case a hook depends on another hook.
                     for hook in scope.hooks.take().drain(..).rev() {
                         drop(hook);
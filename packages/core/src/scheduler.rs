@@ -0,0 +1,59 @@
+use crate::innerlude::*;
+
+/// How urgent the work caused by an event is.
+///
+/// ## Details
+///
+/// Every dirty scope is in the lane of the most urgent work that marked it dirty. Scopes that are marked dirty by the signals a listener writes are in the lane of the event. Scopes that are marked dirty by anything else, like tasks (even the tasks a listener wakes), effects or work that was queued before the event, are in the [`EventPriority::Low`] lane.
+///
+/// Renderers can call [`VirtualDom::render_with_priority`] to render the urgent lanes first and the rest of the work later. [`VirtualDom::render_immediate`] renders every lane.
+///
+/// The lanes are ordered from the least urgent to the most urgent.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum EventPriority {
+    /// Work that is not caused by an event, like tasks and effects
+    #[default]
+    Low,
+    /// Events that are not direct user input, like `load` or `animationend`
+    Medium,
+    /// Continuous user input that fires many times in a row, like `mousemove` or `scroll`
+    High,
+    /// Discrete user input like clicks and key presses. The user expects to see the result right away
+    Immediate,
+}
+
+impl EventPriority {
+    /// The lane of an event with the name the renderer passes to [`VirtualDom::handle_event`], like `click`
+    pub fn of_event(name: &str) -> Self {
+        match name {
+            "click" | "dblclick" | "contextmenu" | "auxclick" | "mousedown" | "mouseup"
+            | "pointerdown" | "pointerup" | "pointercancel" | "touchstart" | "touchend"
+            | "touchcancel" | "keydown" | "keyup" | "keypress" | "input" | "change"
+            | "beforeinput" | "submit" | "reset" | "invalid" | "focus" | "blur" | "focusin"
+            | "focusout" | "compositionstart" | "compositionupdate" | "compositionend" | "copy"
+            | "cut" | "paste" | "select" | "drop" | "dragstart" | "dragend" => {
+                EventPriority::Immediate
+            }
+            "mousemove" | "mouseover" | "mouseout" | "mouseenter" | "mouseleave"
+            | "pointermove" | "pointerover" | "pointerout" | "pointerenter" | "pointerleave"
+            | "touchmove" | "scroll" | "wheel" | "drag" | "dragover" | "dragenter"
+            | "dragleave" => EventPriority::High,
+            _ => EventPriority::Medium,
+        }
+    }
+}
+
+impl VirtualDom {
+    /// The lane of a dirty scope
+    pub(crate) fn dirty_priority(&self, id: ScopeId) -> EventPriority {
+        self.dirty_priorities.get(&id).copied().unwrap_or_default()
+    }
+
+    /// Move a dirty scope into the lane of `priority` if it is more urgent
+    pub(crate) fn raise_dirty_priority(&mut self, id: ScopeId, priority: EventPriority) {
+        self.dirty_priorities
+            .entry(id)
+            .and_modify(|lane| *lane = (*lane).max(priority))
+            .or_insert(priority);
+    }
+}
@@ -1,6 +1,6 @@
 use crate::{
     any_props::{AnyProps, BoxedAnyProps},
-    innerlude::{DirtyScope, ScopeState},
+    innerlude::{DirtyScope, ScopeState, SuspenseContext},
     nodes::RenderReturn,
     scope_context::Scope,
     scopes::ScopeId,
@@ -37,6 +37,12 @@ impl VirtualDom {
 
         self.runtime.scope_stack.borrow_mut().push(scope_id);
         let scope = &self.scopes[scope_id.0];
+        let _span =
+            tracing::trace_span!("render", component = scope.state().name, scope = scope_id.0)
+                .entered();
+        // Instant is not available on wasm32-unknown-unknown, so the render time is only measured on other targets
+        #[cfg(not(target_arch = "wasm32"))]
+        let started = std::time::Instant::now();
         let new_nodes = {
             let context = scope.state();
 
@@ -51,8 +57,7 @@ impl VirtualDom {
             // safety: due to how we traverse the tree, we know that the scope is not currently aliased
             let props: &dyn AnyProps = &*scope.props;
 
-            let span = tracing::trace_span!("render", scope = %scope.state().name);
-            span.in_scope(|| props.render())
+            props.render()
         };
 
         let context = scope.state();
@@ -64,23 +69,56 @@ impl VirtualDom {
 
         // And move the render generation forward by one
         context.render_count.set(context.render_count.get() + 1);
+        #[cfg(not(target_arch = "wasm32"))]
+        context.render_time.set(Some(started.elapsed()));
 
         // remove this scope from dirty scopes
         self.dirty_scopes.remove(&DirtyScope {
             height: context.height,
             id: context.id,
         });
+        self.dirty_priorities.remove(&context.id);
 
-        if context.suspended.get() {
+        let suspended = context.suspended.get();
+        let id = context.id;
+        drop(context);
+
+        if suspended {
             if matches!(new_nodes, RenderReturn::Aborted(_)) {
-                self.suspended_scopes.insert(context.id);
+                self.suspend_scope(id);
             }
-        } else if !self.suspended_scopes.is_empty() {
-            _ = self.suspended_scopes.remove(&context.id);
+        } else {
+            self.resolve_suspended_scope(id);
         }
 
         self.runtime.scope_stack.borrow_mut().pop();
 
         new_nodes
     }
+
+    /// Track a suspended scope and show the fallback of the nearest suspense boundary
+    fn suspend_scope(&mut self, id: ScopeId) {
+        if self.suspended_scopes.contains_key(&id) {
+            return;
+        }
+        let boundary = self
+            .runtime
+            .get_state(id)
+            .and_then(|scope| scope.consume_context::<SuspenseContext>());
+        if let Some(boundary) = &boundary {
+            if boundary.add(id) {
+                self.mark_dirty(boundary.id());
+            }
+        }
+        self.suspended_scopes.insert(id, boundary);
+    }
+
+    /// Stop tracking a scope that is no longer suspended. If it was the last suspended scope in a suspense boundary, the boundary shows its children again
+    pub(crate) fn resolve_suspended_scope(&mut self, id: ScopeId) {
+        if let Some(Some(boundary)) = self.suspended_scopes.remove(&id) {
+            if boundary.remove(id) {
+                self.mark_dirty(boundary.id());
+            }
+        }
+    }
 }
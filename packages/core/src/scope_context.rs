@@ -1,10 +1,14 @@
-use crate::{innerlude::SchedulerMsg, Element, Runtime, ScopeId, Task};
-use rustc_hash::FxHashSet;
+use crate::{
+    innerlude::{ExitAnimation, SchedulerMsg},
+    Element, Runtime, ScopeId, Task,
+};
+use rustc_hash::{FxHashMap, FxHashSet};
 use std::{
     any::Any,
     cell::{Cell, RefCell},
     future::Future,
     sync::Arc,
+    time::Duration,
 };
 
 /// A component's state separate from its props.
@@ -13,14 +17,19 @@ use std::{
 pub(crate) struct Scope {
     pub(crate) name: &'static str,
     pub(crate) id: ScopeId,
-    pub(crate) parent_id: Option<ScopeId>,
+    pub(crate) parent_id: Cell<Option<ScopeId>>,
     pub(crate) height: u32,
     pub(crate) render_count: Cell<usize>,
+    pub(crate) render_time: Cell<Option<Duration>>,
     pub(crate) suspended: Cell<bool>,
+    pub(crate) portal_target: RefCell<Option<String>>,
+    pub(crate) exit_animation: RefCell<Option<ExitAnimation>>,
 
     // Note: the order of the hook and context fields is important. The hooks field must be dropped before the contexts field in case a hook drop implementation tries to access a context.
     pub(crate) hooks: RefCell<Vec<Box<dyn Any>>>,
     pub(crate) hook_index: Cell<usize>,
+    pub(crate) hook_types: RefCell<Vec<&'static str>>,
+    pub(crate) hook_descriptions: RefCell<FxHashMap<usize, Box<dyn Fn() -> String>>>,
     pub(crate) shared_contexts: RefCell<Vec<Box<dyn Any>>>,
     pub(crate) spawned_tasks: RefCell<FxHashSet<Task>>,
     pub(crate) before_render: RefCell<Vec<Box<dyn FnMut()>>>,
@@ -37,21 +46,26 @@ impl Scope {
         Self {
             name,
             id,
-            parent_id,
+            parent_id: Cell::new(parent_id),
             height,
             render_count: Cell::new(0),
+            render_time: Cell::new(None),
             suspended: Cell::new(false),
+            portal_target: RefCell::new(None),
+            exit_animation: RefCell::new(None),
             shared_contexts: RefCell::new(vec![]),
             spawned_tasks: RefCell::new(FxHashSet::default()),
             hooks: RefCell::new(vec![]),
             hook_index: Cell::new(0),
+            hook_types: RefCell::new(vec![]),
+            hook_descriptions: RefCell::new(FxHashMap::default()),
             before_render: RefCell::new(vec![]),
             after_render: RefCell::new(vec![]),
         }
     }
 
     pub fn parent_id(&self) -> Option<ScopeId> {
-        self.parent_id
+        self.parent_id.get()
     }
 
     fn sender(&self) -> futures_channel::mpsc::UnboundedSender<SchedulerMsg> {
@@ -113,7 +127,7 @@ impl Scope {
             return Some(this_ctx);
         }
 
-        let mut search_parent = self.parent_id;
+        let mut search_parent = self.parent_id.get();
         let cur_runtime = Runtime::with(|runtime| {
             while let Some(parent_id) = search_parent {
                 let parent = runtime.get_state(parent_id).unwrap();
@@ -129,7 +143,7 @@ impl Scope {
                 }) {
                     return Some(shared.clone());
                 }
-                search_parent = parent.parent_id;
+                search_parent = parent.parent_id.get();
             }
             None
         });
@@ -268,6 +282,9 @@ impl Scope {
 
         if cur_hook >= hooks.len() {
             hooks.push(Box::new(initializer()));
+            self.hook_types
+                .borrow_mut()
+                .push(std::any::type_name::<State>());
         }
 
         hooks
@@ -288,6 +305,13 @@ impl Scope {
             )
     }
 
+    /// Describe the value of the hook at `index` for developer tools. The closure runs every time the scope tree is inspected.
+    pub fn describe_hook(&self, index: usize, describe: impl Fn() -> String + 'static) {
+        self.hook_descriptions
+            .borrow_mut()
+            .insert(index, Box::new(describe));
+    }
+
     pub fn push_before_render(&self, f: impl FnMut() + 'static) {
         self.before_render.borrow_mut().push(Box::new(f));
     }
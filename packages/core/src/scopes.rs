@@ -1,5 +1,6 @@
 use crate::{
     any_props::BoxedAnyProps, nodes::RenderReturn, runtime::Runtime, scope_context::Scope,
+    SuspenseContext,
 };
 use std::{cell::Ref, rc::Rc};
 
@@ -80,6 +81,21 @@ impl ScopeState {
         self.last_rendered_node.as_ref()
     }
 
+    /// The id of the scope
+    pub fn id(&self) -> ScopeId {
+        self.context_id
+    }
+
+    /// The name of the component that renders the scope
+    pub fn name(&self) -> &'static str {
+        self.state().name
+    }
+
+    /// The suspense boundary the scope provides, if the scope is a suspense boundary like [`crate::prelude::SuspenseBoundary`]
+    pub fn suspense_boundary(&self) -> Option<SuspenseContext> {
+        self.state().has_context()
+    }
+
     pub(crate) fn state(&self) -> Ref<'_, Scope> {
         self.runtime.get_state(self.context_id).unwrap()
     }
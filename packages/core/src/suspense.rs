@@ -0,0 +1,261 @@
+use crate::{
+    global_context::current_scope_id,
+    innerlude::{provide_context, KeepAlive, KeepAliveProps, VComponent},
+    use_hook, DynamicNode, Element, IntoDynNode, Properties, ScopeId, Template, TemplateNode,
+    VNode,
+};
+use rustc_hash::FxHashSet;
+use std::{cell::RefCell, rc::Rc};
+
+/// Provide a suspense boundary that tracks the suspended components below it
+pub fn use_suspense_boundary() -> SuspenseContext {
+    use_hook(|| provide_context(SuspenseContext::new()))
+}
+
+/// A boundary that tracks the components below it that are suspended
+#[derive(Debug, Clone)]
+pub struct SuspenseContext {
+    inner: Rc<SuspenseContextInner>,
+}
+
+#[derive(Debug)]
+struct SuspenseContextInner {
+    suspended: RefCell<FxHashSet<ScopeId>>,
+    id: ScopeId,
+}
+
+impl Default for SuspenseContext {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SuspenseContext {
+    /// Create a new suspense boundary in the current scope
+    pub fn new() -> Self {
+        Self {
+            inner: Rc::new(SuspenseContextInner {
+                suspended: RefCell::new(FxHashSet::default()),
+                id: current_scope_id()
+                    .expect("Cannot create a suspense boundary outside of a component's scope."),
+            }),
+        }
+    }
+
+    /// The scope the boundary was created in
+    pub fn id(&self) -> ScopeId {
+        self.inner.id
+    }
+
+    /// Returns true if any component below the boundary is suspended
+    pub fn has_suspended(&self) -> bool {
+        !self.inner.suspended.borrow().is_empty()
+    }
+
+    /// The components below the boundary that are suspended
+    pub fn suspended_scopes(&self) -> Vec<ScopeId> {
+        self.inner.suspended.borrow().iter().copied().collect()
+    }
+
+    /// Track a suspended scope. Returns true if the boundary was not suspended before
+    pub(crate) fn add(&self, scope: ScopeId) -> bool {
+        let mut suspended = self.inner.suspended.borrow_mut();
+        suspended.insert(scope);
+        suspended.len() == 1
+    }
+
+    /// Stop tracking a scope that resolved or was dropped. Returns true if the boundary is no longer suspended
+    pub(crate) fn remove(&self, scope: ScopeId) -> bool {
+        let mut suspended = self.inner.suspended.borrow_mut();
+        suspended.remove(&scope) && suspended.is_empty()
+    }
+}
+
+#[derive(Clone)]
+pub struct SuspenseBoundaryProps {
+    children: Element,
+    fallback: Element,
+}
+impl SuspenseBoundaryProps {
+    /**
+    Create a builder for building `SuspenseBoundaryProps`.
+    On the builder, call `.children(...)`(optional), `.fallback(...)`(optional) to set the values of the fields.
+    Finally, call `.build()` to create the instance of `SuspenseBoundaryProps`.
+                        */
+    #[allow(dead_code)]
+    pub fn builder() -> SuspenseBoundaryPropsBuilder<((), ())> {
+        SuspenseBoundaryPropsBuilder { fields: ((), ()) }
+    }
+}
+#[must_use]
+#[doc(hidden)]
+#[allow(dead_code, non_camel_case_types, non_snake_case)]
+pub struct SuspenseBoundaryPropsBuilder<TypedBuilderFields> {
+    fields: TypedBuilderFields,
+}
+impl<TypedBuilderFields> Clone for SuspenseBoundaryPropsBuilder<TypedBuilderFields>
+where
+    TypedBuilderFields: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            fields: self.fields.clone(),
+        }
+    }
+}
+impl Properties for SuspenseBoundaryProps {
+    type Builder = SuspenseBoundaryPropsBuilder<((), ())>;
+    fn builder() -> Self::Builder {
+        SuspenseBoundaryProps::builder()
+    }
+    fn memoize(&mut self, _: &Self) -> bool {
+        false
+    }
+}
+#[doc(hidden)]
+#[allow(dead_code, non_camel_case_types, non_snake_case)]
+pub trait SuspenseBoundaryPropsBuilder_Optional<T> {
+    fn into_value<F: FnOnce() -> T>(self, default: F) -> T;
+}
+impl<T> SuspenseBoundaryPropsBuilder_Optional<T> for () {
+    fn into_value<F: FnOnce() -> T>(self, default: F) -> T {
+        default()
+    }
+}
+impl<T> SuspenseBoundaryPropsBuilder_Optional<T> for (T,) {
+    fn into_value<F: FnOnce() -> T>(self, _: F) -> T {
+        self.0
+    }
+}
+#[allow(dead_code, non_camel_case_types, missing_docs)]
+impl<__fallback> SuspenseBoundaryPropsBuilder<((), __fallback)> {
+    pub fn children(
+        self,
+        children: Element,
+    ) -> SuspenseBoundaryPropsBuilder<((Element,), __fallback)> {
+        let children = (children,);
+        let (_, fallback) = self.fields;
+        SuspenseBoundaryPropsBuilder {
+            fields: (children, fallback),
+        }
+    }
+}
+#[doc(hidden)]
+#[allow(dead_code, non_camel_case_types, non_snake_case)]
+pub enum SuspenseBoundaryPropsBuilder_Error_Repeated_field_children {}
+#[doc(hidden)]
+#[allow(dead_code, non_camel_case_types, missing_docs)]
+impl<__fallback> SuspenseBoundaryPropsBuilder<((Element,), __fallback)> {
+    #[deprecated(note = "Repeated field children")]
+    pub fn children(
+        self,
+        _: SuspenseBoundaryPropsBuilder_Error_Repeated_field_children,
+    ) -> SuspenseBoundaryPropsBuilder<((Element,), __fallback)> {
+        self
+    }
+}
+#[allow(dead_code, non_camel_case_types, missing_docs)]
+impl<__children> SuspenseBoundaryPropsBuilder<(__children, ())> {
+    pub fn fallback(
+        self,
+        fallback: Element,
+    ) -> SuspenseBoundaryPropsBuilder<(__children, (Element,))> {
+        let fallback = (fallback,);
+        let (children, _) = self.fields;
+        SuspenseBoundaryPropsBuilder {
+            fields: (children, fallback),
+        }
+    }
+}
+#[doc(hidden)]
+#[allow(dead_code, non_camel_case_types, non_snake_case)]
+pub enum SuspenseBoundaryPropsBuilder_Error_Repeated_field_fallback {}
+#[doc(hidden)]
+#[allow(dead_code, non_camel_case_types, missing_docs)]
+impl<__children> SuspenseBoundaryPropsBuilder<(__children, (Element,))> {
+    #[deprecated(note = "Repeated field fallback")]
+    pub fn fallback(
+        self,
+        _: SuspenseBoundaryPropsBuilder_Error_Repeated_field_fallback,
+    ) -> SuspenseBoundaryPropsBuilder<(__children, (Element,))> {
+        self
+    }
+}
+#[allow(dead_code, non_camel_case_types, missing_docs)]
+impl<
+        __fallback: SuspenseBoundaryPropsBuilder_Optional<Element>,
+        __children: SuspenseBoundaryPropsBuilder_Optional<Element>,
+    > SuspenseBoundaryPropsBuilder<(__children, __fallback)>
+{
+    pub fn build(self) -> SuspenseBoundaryProps {
+        let (children, fallback) = self.fields;
+        let children = SuspenseBoundaryPropsBuilder_Optional::into_value(children, || {
+            ::core::default::Default::default()
+        });
+        let fallback = SuspenseBoundaryPropsBuilder_Optional::into_value(fallback, || {
+            ::core::default::Default::default()
+        });
+        SuspenseBoundaryProps { children, fallback }
+    }
+}
+/// Create a new suspense boundary component.
+///
+/// ## Details
+///
+/// Suspense boundaries show a fallback while any component below them is suspended with [`crate::prelude::suspend`]. Once every suspended component resolves, the children are shown again.
+///
+/// The nodes of the children are removed while the fallback is shown, but their components stay alive like a [`crate::prelude::KeepAlive`] so their tasks keep running. Suspended components below the boundary keep rendering until they resolve.
+///
+/// ## Example
+///
+/// ```rust, ignore
+/// rsx!{
+///     SuspenseBoundary {
+///         fallback: rsx! { "Loading..." },
+///         LoadsData {}
+///     }
+/// }
+/// ```
+#[allow(non_upper_case_globals, non_snake_case)]
+pub fn SuspenseBoundary(props: SuspenseBoundaryProps) -> Element {
+    static TEMPLATE: Template = Template {
+        name: concat!(file!(), ":", line!(), ":", column!(), ":0"),
+        roots: &[
+            TemplateNode::Dynamic { id: 0usize },
+            TemplateNode::Dynamic { id: 1usize },
+        ],
+        node_paths: &[&[0u8], &[1u8]],
+        attr_paths: &[],
+    };
+    static CHILDREN_TEMPLATE: Template = Template {
+        name: concat!(file!(), ":", line!(), ":", column!(), ":1"),
+        roots: &[TemplateNode::Dynamic { id: 0usize }],
+        node_paths: &[&[0u8]],
+        attr_paths: &[],
+    };
+
+    let boundary = use_suspense_boundary();
+    let suspended = boundary.has_suspended();
+
+    // The children are cached under the id of the boundary while they are removed
+    let children = (!suspended).then(|| {
+        VNode::new(
+            Some(boundary.id().0.to_string()),
+            CHILDREN_TEMPLATE,
+            Box::new([DynamicNode::Component(VComponent::new(
+                KeepAlive,
+                KeepAliveProps::suspense(props.children),
+                "KeepAlive",
+            ))]),
+            Box::new([]),
+        )
+    });
+    let fallback = if suspended { props.fallback } else { None };
+
+    Some(VNode::new(
+        None,
+        TEMPLATE,
+        Box::new([fallback.into_dyn_node(), children.into_dyn_node()]),
+        Box::new([]),
+    ))
+}
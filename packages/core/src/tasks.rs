@@ -206,6 +206,12 @@ pub(crate) enum SchedulerMsg {
 
     /// A task has woken and needs to be progressed
     TaskNotified(Task),
+
+    /// The exit animation of an animated presence ended and its nodes can be removed
+    ExitFinished(ScopeId),
+
+    /// The keep alive subtree cached under the key should be dropped
+    EvictKeepAlive(String),
 }
 
 struct LocalTaskHandle {
@@ -6,11 +6,13 @@ use crate::{
     any_props::AnyProps,
     arena::ElementId,
     innerlude::{
-        DirtyScope, ElementRef, ErrorBoundary, NoOpMutations, SchedulerMsg, ScopeState, VNodeMount,
-        VProps, WriteMutations,
+        DirtyScope, ElementRef, ErrorBoundary, EventPriority, NoOpMutations, PortalMount,
+        SchedulerMsg, ScopeState, SuspenseContext, VNodeMount, VProps, WriteMutations,
+        DEFAULT_KEEP_ALIVE_LIMIT,
     },
     nodes::RenderReturn,
     nodes::{Template, TemplateId},
+    recorder::{RecordedAction, Recorder, Recording},
     runtime::{Runtime, RuntimeGuard},
     scopes::ScopeId,
     AttributeValue, ComponentFunction, Element, Event, Mutations,
@@ -18,7 +20,11 @@ use crate::{
 use futures_util::StreamExt;
 use rustc_hash::{FxHashMap, FxHashSet};
 use slab::Slab;
-use std::{any::Any, collections::BTreeSet, rc::Rc};
+use std::{
+    any::Any,
+    collections::{BTreeSet, VecDeque},
+    rc::Rc,
+};
 use tracing::instrument;
 
 /// A virtual node system that progresses user events and diffs UI trees.
@@ -185,6 +191,9 @@ pub struct VirtualDom {
 
     pub(crate) dirty_scopes: BTreeSet<DirtyScope>,
 
+    // The lane of each dirty scope. Scopes without a lane are in the lowest lane
+    pub(crate) dirty_priorities: FxHashMap<ScopeId, EventPriority>,
+
     // Maps a template path to a map of byte indexes to templates
     pub(crate) templates: FxHashMap<TemplateId, FxHashMap<usize, Template>>,
 
@@ -199,8 +208,32 @@ pub struct VirtualDom {
 
     pub(crate) runtime: Rc<Runtime>,
 
-    // Currently suspended scopes
-    pub(crate) suspended_scopes: FxHashSet<ScopeId>,
+    // Currently suspended scopes and the suspense boundary they are in
+    pub(crate) suspended_scopes: FxHashMap<ScopeId, Option<SuspenseContext>>,
+
+    // The portals that are mounted and the placeholder that marks their position in the tree
+    pub(crate) portals: FxHashMap<ScopeId, PortalMount>,
+
+    // The keys of the keep alive scopes that are mounted
+    pub(crate) keep_alive: FxHashMap<ScopeId, String>,
+
+    // The keep alive scopes that were removed from the tree by their key, from the least recently cached to the most recently cached
+    pub(crate) keep_alive_cache: VecDeque<(String, ScopeId)>,
+
+    // The maximum number of keep alive scopes that are cached at the same time
+    pub(crate) keep_alive_limit: usize,
+
+    // The scopes in cached subtrees and whether they were marked dirty while they were cached
+    pub(crate) cached_scopes: FxHashMap<ScopeId, bool>,
+
+    // The animated presences that were removed and are waiting for their exit animation to end
+    pub(crate) exiting: FxHashSet<ScopeId>,
+
+    // The exiting presences whose exit animation ended. Their nodes are removed in the next render
+    pub(crate) finished_exits: Vec<ScopeId>,
+
+    // Logs the events and renders while a recording is running
+    recorder: Option<Recorder>,
 
     rx: futures_channel::mpsc::UnboundedReceiver<SchedulerMsg>,
 }
@@ -264,7 +297,13 @@ impl VirtualDom {
         root: impl ComponentFunction<P, M>,
         root_props: P,
     ) -> Self {
-        Self::new_with_component(VProps::new(root, |_, _| true, root_props, "root"))
+        Self::new_with_component(VProps::new(
+            root,
+            |_, _| true,
+            |_| Vec::new(),
+            root_props,
+            "root",
+        ))
     }
 
     /// Create a new virtualdom and build it immediately
@@ -313,11 +352,20 @@ impl VirtualDom {
             runtime: Runtime::new(tx),
             scopes: Default::default(),
             dirty_scopes: Default::default(),
+            dirty_priorities: Default::default(),
             templates: Default::default(),
             queued_templates: Default::default(),
             elements: Default::default(),
             mounts: Default::default(),
             suspended_scopes: Default::default(),
+            portals: Default::default(),
+            keep_alive: Default::default(),
+            keep_alive_cache: Default::default(),
+            keep_alive_limit: DEFAULT_KEEP_ALIVE_LIMIT,
+            cached_scopes: Default::default(),
+            exiting: Default::default(),
+            finished_exits: Default::default(),
+            recorder: None,
         };
 
         let root = dom.new_scope(Box::new(root), "app");
@@ -361,6 +409,15 @@ impl VirtualDom {
         self
     }
 
+    /// Build the virtualdom with a limit on the number of [`KeepAlive`](crate::KeepAlive) subtrees that are cached at the same time. The default limit is 10.
+    ///
+    /// When a subtree is cached while the cache is full, the subtree that was cached the longest time ago is dropped.
+    pub fn with_keep_alive_limit(mut self, limit: usize) -> Self {
+        self.keep_alive_limit = limit;
+        self.evict_keep_alive_over_limit();
+        self
+    }
+
     /// Build the virtualdom with a global context inserted into the base scope
     ///
     /// This method is useful for when you want to provide a context in your app without knowing its type
@@ -372,6 +429,11 @@ impl VirtualDom {
     ///
     /// Whenever the Runtime "works", it will re-render this scope
     pub fn mark_dirty(&mut self, id: ScopeId) {
+        self.mark_dirty_with_priority(id, EventPriority::default());
+    }
+
+    /// Mark a scope as requiring a re-render and move it into the lane of `priority` if that lane is more urgent
+    pub(crate) fn mark_dirty_with_priority(&mut self, id: ScopeId, priority: EventPriority) {
         let Some(scope) = self.runtime.get_state(id) else {
             return;
         };
@@ -387,6 +449,8 @@ impl VirtualDom {
             height: scope.height(),
             id,
         });
+        drop(scope);
+        self.raise_dirty_priority(id, priority);
     }
 
     /// Call a listener inside the VirtualDom with data from outside the VirtualDom. **The ElementId passed in must be the id of an element with a listener, not a static node or a text node.**
@@ -398,7 +462,12 @@ impl VirtualDom {
     /// It is up to the listeners themselves to mark nodes as dirty.
     ///
     /// If you have multiple events, you can call this method multiple times before calling "render_with_deadline"
-    #[instrument(skip(self), level = "trace", name = "VirtualDom::handle_event")]
+    #[instrument(
+        skip(self, data),
+        level = "trace",
+        name = "VirtualDom::handle_event",
+        fields(element = element.0)
+    )]
     pub fn handle_event(
         &mut self,
         name: &str,
@@ -406,8 +475,15 @@ impl VirtualDom {
         element: ElementId,
         bubbles: bool,
     ) {
+        if let Some(recorder) = &mut self.recorder {
+            recorder.push_event(name, &*data, element, bubbles);
+        }
+
         let _runtime = RuntimeGuard::new(self.runtime.clone());
 
+        // Work that was queued before the event stays in its own lane
+        self.process_events();
+
         if let Some(Some(parent_path)) = self.elements.get(element.0).copied() {
             if bubbles {
                 self.handle_bubbling_event(Some(parent_path), name, Event::new(data, bubbles));
@@ -415,6 +491,21 @@ impl VirtualDom {
                 self.handle_non_bubbling_event(parent_path, name, Event::new(data, bubbles));
             }
         }
+
+        // Signals that were written in the listeners mark their scopes dirty through the queue. Only those scopes are
+        // in the lane of the event. The tasks the listeners wake run in the default lane, like any other task
+        let mut queued = Vec::new();
+        while let Ok(Some(msg)) = self.rx.try_next() {
+            queued.push(msg);
+        }
+        for msg in queued {
+            if let SchedulerMsg::Immediate(id) = msg {
+                self.mark_dirty_with_priority(id, EventPriority::of_event(name));
+            } else {
+                self.handle_scheduler_msg(msg);
+            }
+        }
+        self.process_events();
     }
 
     /// Wait for the scheduler to have any work.
@@ -450,14 +541,20 @@ impl VirtualDom {
             // Sometimes when wakers fire we get a slew of updates at once, so its important that we drain this completely
             self.process_events();
 
-            // Now that we have collected all queued work, we should check if we have any dirty scopes. If there are not, then we can poll any queued futures
-            if !self.dirty_scopes.is_empty() {
+            // Now that we have collected all queued work, we should check if we have any dirty scopes or exits to finish. If there are not, then we can poll any queued futures
+            if !self.dirty_scopes.is_empty() || !self.finished_exits.is_empty() {
                 return;
             }
 
             // Make sure we set the runtime since we're running user code
             let _runtime = RuntimeGuard::new(self.runtime.clone());
 
+            // The renderer applies the mutations from the last render before waiting for more work, so the effects queued
+            // during that render can see them now. Effects may mark scopes dirty or wake tasks, so we check for work again
+            if self.runtime.run_effects() {
+                continue;
+            }
+
             // Hold a lock to the flush sync to prevent tasks from running in the event we get an immediate
             // When we're doing awaiting the rx, the lock will be dropped and tasks waiting on the lock will get waked
             // We have to own the lock since poll_tasks is cancel safe - the future that this is running in might get dropped
@@ -465,10 +562,8 @@ impl VirtualDom {
             self.runtime.release_flush_lock();
             self.runtime.acquire_flush_lock();
 
-            match self.rx.next().await.expect("channel should never close") {
-                SchedulerMsg::Immediate(id) => self.mark_dirty(id),
-                SchedulerMsg::TaskNotified(id) => _ = self.runtime.handle_task_wakeup(id),
-            };
+            let msg = self.rx.next().await.expect("channel should never close");
+            self.handle_scheduler_msg(msg);
         }
     }
 
@@ -479,10 +574,16 @@ impl VirtualDom {
 
         // Prevent a task from deadlocking the runtime by repeatedly queueing itself
         while let Ok(Some(msg)) = self.rx.try_next() {
-            match msg {
-                SchedulerMsg::Immediate(id) => self.mark_dirty(id),
-                SchedulerMsg::TaskNotified(task) => _ = self.runtime.handle_task_wakeup(task),
-            }
+            self.handle_scheduler_msg(msg);
+        }
+    }
+
+    fn handle_scheduler_msg(&mut self, msg: SchedulerMsg) {
+        match msg {
+            SchedulerMsg::Immediate(id) => self.mark_dirty(id),
+            SchedulerMsg::TaskNotified(task) => _ = self.runtime.handle_task_wakeup(task),
+            SchedulerMsg::ExitFinished(id) => self.queue_finished_exit(id),
+            SchedulerMsg::EvictKeepAlive(key) => self.evict_keep_alive(&key),
         }
     }
 
@@ -494,6 +595,9 @@ impl VirtualDom {
     /// This will only replace the parent template, not any nested templates.
     #[instrument(skip(self), level = "trace", name = "VirtualDom::replace_template")]
     pub fn replace_template(&mut self, template: Template) {
+        if let Some(recorder) = &mut self.recorder {
+            recorder.push(RecordedAction::ReplaceTemplate(template));
+        }
         self.register_template_first_byte_index(template);
         // iterating a slab is very inefficient, but this is a rare operation that will only happen during development so it's fine
         for (_, scope) in self.scopes.iter() {
@@ -549,6 +653,9 @@ impl VirtualDom {
     /// ```
     #[instrument(skip(self, to), level = "trace", name = "VirtualDom::rebuild")]
     pub fn rebuild(&mut self, to: &mut impl WriteMutations) {
+        if let Some(recorder) = &mut self.recorder {
+            recorder.push(RecordedAction::Rebuild);
+        }
         self.flush_templates(to);
         let _runtime = RuntimeGuard::new(self.runtime.clone());
         let new_nodes = self.run_scope(ScopeId::ROOT);
@@ -563,6 +670,37 @@ impl VirtualDom {
     /// suspended subtrees.
     #[instrument(skip(self, to), level = "trace", name = "VirtualDom::render_immediate")]
     pub fn render_immediate(&mut self, to: &mut impl WriteMutations) {
+        self.render_with_priority(to, EventPriority::Low, || false);
+    }
+
+    /// Render the dirty scopes in the lanes that are at least as urgent as `priority`. The scopes in less urgent lanes stay dirty until a later render.
+    ///
+    /// `interrupt` is called before each scope is rendered. If it returns true, the render stops and the scopes that are left are rendered by a later call, so more urgent work like a new event can be handled first. Each scope is diffed completely before the render stops, so the mutations that were written are always complete.
+    ///
+    /// Returns false if the render was interrupted.
+    ///
+    /// ```rust, ignore
+    /// // Show the result of the input right away
+    /// dom.render_with_priority(&mut renderer, EventPriority::Immediate, || false);
+    /// renderer.flush();
+    ///
+    /// // Render the rest of the work until another event comes in
+    /// dom.render_with_priority(&mut renderer, EventPriority::Low, || events.has_pending());
+    /// ```
+    #[instrument(
+        skip(self, to, interrupt),
+        level = "trace",
+        name = "VirtualDom::render_with_priority"
+    )]
+    pub fn render_with_priority(
+        &mut self,
+        to: &mut impl WriteMutations,
+        priority: EventPriority,
+        mut interrupt: impl FnMut() -> bool,
+    ) -> bool {
+        if let Some(recorder) = &mut self.recorder {
+            recorder.push(RecordedAction::Render);
+        }
         self.flush_templates(to);
 
         // Process any events that might be pending in the queue
@@ -570,11 +708,49 @@ impl VirtualDom {
         // This also processes futures which might progress into immediates
         self.process_events();
 
-        // Next, diff any dirty scopes
-        // We choose not to poll the deadline since we complete pretty quickly anyways
+        // Remove the nodes of animated presences that finished their exit animation
+        self.remove_finished_exits(to);
+
+        // Next, diff any dirty scopes in the lanes we render
+        let mut deferred = Vec::new();
+        let mut finished = true;
         while let Some(dirty) = self.dirty_scopes.pop_first() {
             // If the scope doesn't exist for whatever reason, then we should skip it
             if !self.scopes.contains(dirty.id.0) {
+                self.dirty_priorities.remove(&dirty.id);
+                continue;
+            }
+
+            if self.dirty_priority(dirty.id) < priority {
+                deferred.push(dirty);
+                continue;
+            }
+
+            if interrupt() {
+                self.dirty_scopes.insert(dirty);
+                finished = false;
+                break;
+            }
+
+            // Cached scopes are not in the tree, so they are rendered once they are restored
+            if let Some(marked_dirty) = self.cached_scopes.get_mut(&dirty.id) {
+                if !self.suspended_scopes.contains_key(&dirty.id) {
+                    *marked_dirty = true;
+                    continue;
+                }
+
+                // Suspended scopes keep rendering while a suspense boundary hides them, so they can resolve.
+                // Their nodes are not in the renderer, so only the templates are written. The nodes are created when the subtree is restored
+                let _runtime = RuntimeGuard::new(self.runtime.clone());
+                let new_nodes = self.run_scope(dirty.id);
+                let mut hidden = Mutations::default();
+                self.diff_scope(&mut hidden, dirty.id, new_nodes);
+                for template in hidden.templates {
+                    to.register_template(template);
+                }
+                for scope in self.scopes_in(dirty.id) {
+                    self.cached_scopes.entry(scope).or_insert(false);
+                }
                 continue;
             }
 
@@ -586,6 +762,9 @@ impl VirtualDom {
                 self.diff_scope(to, dirty.id, new_nodes);
             }
         }
+
+        self.dirty_scopes.extend(deferred);
+        finished
     }
 
     /// [`Self::render_immediate`] to a vector of mutations for testing purposes
@@ -595,6 +774,34 @@ impl VirtualDom {
         mutations
     }
 
+    /// Remove the nodes of a scope and create them again from a new render of the scope
+    ///
+    /// The mutations replace the old nodes of the scope with the new ones. Renderers use this to recover a subtree that was mounted wrong, like a hydrated subtree that does not match the HTML from the server, without rebuilding the whole app.
+    /// The scope keeps its state, but the components below it are created again.
+    #[instrument(skip(self, to), level = "trace", name = "VirtualDom::remount_scope")]
+    pub fn remount_scope(&mut self, scope: ScopeId, to: &mut impl WriteMutations) {
+        let Some(old) = self
+            .scopes
+            .get_mut(scope.0)
+            .and_then(|scope| scope.last_rendered_node.take())
+        else {
+            return;
+        };
+        let parent = self
+            .mounts
+            .get(old.mount.get().0)
+            .and_then(|mount| mount.parent);
+
+        let _runtime = RuntimeGuard::new(self.runtime.clone());
+        let new = self.run_scope(scope);
+
+        self.runtime.scope_stack.borrow_mut().push(scope);
+        old.replace([&*new], parent, self, to);
+        self.runtime.scope_stack.borrow_mut().pop();
+
+        self.scopes[scope.0].last_rendered_node = Some(new);
+    }
+
     /// Render the virtual dom, waiting for all suspense to be finished
     ///
     /// The mutations will be thrown out, so it's best to use this method for things like SSR that have async content
@@ -616,6 +823,42 @@ impl VirtualDom {
         }
     }
 
+    /// Start recording the events, template changes and renders of the VirtualDom. A recording that is already running is replaced.
+    ///
+    /// Start the recording before [`VirtualDom::rebuild`] to replay it into a fresh VirtualDom with [`Recording::replay`].
+    ///
+    /// ```rust
+    /// use dioxus::prelude::*;
+    /// use dioxus_core::{NoOpMutations, Recorder};
+    ///
+    /// let mut dom = VirtualDom::new(|| rsx! { "hello" });
+    /// dom.record(Recorder::new(|_name, _data| None));
+    /// dom.rebuild(&mut NoOpMutations);
+    ///
+    /// let recording = dom.stop_recording().unwrap();
+    /// recording.replay(&mut VirtualDom::new(|| rsx! { "hello" }), |_, _| None, &mut NoOpMutations);
+    /// ```
+    pub fn record(&mut self, recorder: Recorder) {
+        self.recorder = Some(recorder);
+    }
+
+    /// The running recording, if there is one
+    pub fn recorder(&self) -> Option<&Recorder> {
+        self.recorder.as_ref()
+    }
+
+    /// Stop the running recording and return its log
+    pub fn stop_recording(&mut self) -> Option<Recording> {
+        self.recorder.take().map(Recorder::finish)
+    }
+
+    /// Run the effects queued in the last render, like [`VirtualDom::wait_for_work`] does before waiting
+    pub(crate) fn run_effects(&mut self) {
+        let _runtime = RuntimeGuard::new(self.runtime.clone());
+        self.runtime.run_effects();
+        self.process_events();
+    }
+
     /// Get the current runtime
     pub fn runtime(&self) -> Rc<Runtime> {
         self.runtime.clone()
@@ -19,3 +19,27 @@ fn bool_test() {
         ]
     );
 }
+
+#[test]
+fn conditional_flag_test() {
+    let mut app = VirtualDom::new(|| {
+        let is_hidden = true;
+        let is_focused = false;
+        let title: Option<&str> = None;
+        rsx!(div { hidden: if is_hidden, autofocus: if is_focused, title })
+    });
+
+    assert_eq!(
+        app.rebuild_to_vec().santize().edits,
+        [
+            LoadTemplate { name: "template", index: 0, id: ElementId(1) },
+            SetAttribute {
+                name: "hidden",
+                value: dioxus_core::AttributeValue::Bool(true),
+                id: ElementId(1,),
+                ns: None
+            },
+            AppendChildren { m: 1, id: ElementId(0) },
+        ]
+    );
+}
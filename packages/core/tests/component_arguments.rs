@@ -0,0 +1,37 @@
+#![allow(non_snake_case)]
+
+use dioxus::prelude::*;
+
+fn render(app: fn() -> Element) -> String {
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild(&mut dioxus_core::NoOpMutations);
+    dioxus_ssr::render(&dom)
+}
+
+#[test]
+fn optional_and_default_component_arguments() {
+    #[component]
+    fn Badge(
+        label: String,
+        color: Option<String>,
+        #[props(default = 3)] size: u32,
+        #[props(default)] mut tags: Vec<String>,
+        #[props(into, default = "left".to_string())] align: String,
+    ) -> Element {
+        tags.push("last".to_string());
+        rsx! { "{label} {color:?} {size} {tags:?} {align}" }
+    }
+
+    fn app() -> Element {
+        rsx! {
+            Badge { label: "new" }
+            " | "
+            Badge { label: "old", color: "red", size: 5, tags: vec!["a".to_string()], align: "right" }
+        }
+    }
+
+    assert_eq!(
+        render(app),
+        "new None 3 [&quot;last&quot;] left | old Some(&quot;red&quot;) 5 [&quot;a&quot;, &quot;last&quot;] right"
+    );
+}
@@ -30,3 +30,38 @@ fn multiroot() {
     //     ]
     // )
 }
+
+#[test]
+fn namespaced_attributes() {
+    use dioxus::dioxus_core::{ElementId, Mutation::*};
+
+    let mut dom = VirtualDom::new(|| {
+        let id = "shape";
+        rsx! {
+            svg {
+                r#use { xlink_href: "#{id}", "xlink:title": "{id}" }
+            }
+        }
+    });
+
+    assert_eq!(
+        dom.rebuild_to_vec().santize().edits,
+        [
+            LoadTemplate { name: "template", index: 0, id: ElementId(1) },
+            AssignId { path: &[0], id: ElementId(2) },
+            SetAttribute {
+                name: "xlink:href",
+                value: "#shape".into_value(),
+                id: ElementId(2),
+                ns: Some("http://www.w3.org/1999/xlink")
+            },
+            SetAttribute {
+                name: "xlink:title",
+                value: "shape".into_value(),
+                id: ElementId(2),
+                ns: Some("http://www.w3.org/1999/xlink")
+            },
+            AppendChildren { m: 1, id: ElementId(0) },
+        ]
+    );
+}
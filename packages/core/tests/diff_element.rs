@@ -181,3 +181,58 @@ fn attribute_diff() {
         ]
     );
 }
+
+#[test]
+fn spread_iterator_diff() {
+    fn app() -> Element {
+        let active = generation() % 2 == 1;
+        let text = |name| Attribute::new(name, AttributeValue::Text("hello".into()), None, false);
+
+        // Spreads take anything that iterates over attributes, in any order
+        let attrs = if active {
+            vec![text("a")].into_iter()
+        } else {
+            vec![text("c"), text("a")].into_iter()
+        };
+        let active_attr = active.then(|| text("b"));
+
+        rsx!(
+            div {
+                ..attrs,
+                ..active_attr,
+                "hello"
+            }
+        )
+    }
+
+    let mut vdom = VirtualDom::new(app);
+    vdom.rebuild(&mut NoOpMutations);
+
+    vdom.mark_dirty(ScopeId::ROOT);
+    assert_eq!(
+        vdom.render_immediate_to_vec().santize().edits,
+        [
+            SetAttribute {
+                name: "b",
+                value: AttributeValue::Text("hello".into()),
+                id: ElementId(1,),
+                ns: None,
+            },
+            SetAttribute { name: "c", value: AttributeValue::None, id: ElementId(1,), ns: None },
+        ]
+    );
+
+    vdom.mark_dirty(ScopeId::ROOT);
+    assert_eq!(
+        vdom.render_immediate_to_vec().santize().edits,
+        [
+            SetAttribute { name: "b", value: AttributeValue::None, id: ElementId(1,), ns: None },
+            SetAttribute {
+                name: "c",
+                value: AttributeValue::Text("hello".into()),
+                id: ElementId(1,),
+                ns: None,
+            },
+        ]
+    );
+}
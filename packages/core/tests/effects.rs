@@ -0,0 +1,50 @@
+//! Verify that queued effects run once the mutations of a render are committed
+
+use std::{cell::RefCell, rc::Rc, time::Duration};
+
+use dioxus::prelude::*;
+
+#[tokio::test]
+async fn effects_run_after_render() {
+    let log = Rc::new(RefCell::new(Vec::new()));
+
+    let mut dom = VirtualDom::new_with_props(
+        |log: Rc<RefCell<Vec<&'static str>>>| {
+            log.borrow_mut().push("render");
+            use_hook(|| {
+                let log = log.clone();
+                queue_effect(move || log.borrow_mut().push("effect"));
+            });
+
+            rsx!({})
+        },
+        log.clone(),
+    );
+
+    dom.rebuild(&mut dioxus_core::NoOpMutations);
+    // The renderer hasn't applied the mutations yet
+    assert_eq!(*log.borrow(), ["render"]);
+
+    tokio::select! {
+        _ = dom.wait_for_work() => {}
+        _ = tokio::time::sleep(Duration::from_millis(100)) => {}
+    };
+    assert_eq!(*log.borrow(), ["render", "effect"]);
+}
+
+#[tokio::test]
+async fn effects_can_mark_scopes_dirty() {
+    let mut dom = VirtualDom::new(|| {
+        let update = use_hook(schedule_update);
+        use_hook(|| queue_effect(move || update()));
+
+        rsx!({})
+    });
+
+    dom.rebuild(&mut dioxus_core::NoOpMutations);
+
+    // The effect marks the app dirty, so there is work to do
+    tokio::time::timeout(Duration::from_millis(100), dom.wait_for_work())
+        .await
+        .expect("the effect should mark the scope dirty");
+}
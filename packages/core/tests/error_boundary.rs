@@ -1,6 +1,7 @@
 #![allow(non_snake_case)]
 
 use dioxus::prelude::*;
+use dioxus_core::CapturedError;
 
 #[test]
 fn catches_panic() {
@@ -30,3 +31,47 @@ fn ThrowChild() -> Element {
 
     rsx! { div {} }
 }
+
+#[test]
+fn reset_renders_children_again() {
+    use std::sync::atomic::{AtomicBool, Ordering};
+    static SHOULD_THROW: AtomicBool = AtomicBool::new(true);
+
+    fn app() -> Element {
+        rsx! {
+            ErrorBoundary {
+                handle_error: |error: CapturedError| rsx! { "{error.downcast::<&str>().unwrap()}" },
+                MaybeThrows {}
+            }
+        }
+    }
+
+    fn MaybeThrows() -> Element {
+        if SHOULD_THROW.load(Ordering::SeqCst) {
+            Err("broken").throw()?;
+        }
+        rsx! { "fixed" }
+    }
+
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild(&mut dioxus_core::NoOpMutations);
+    dom.render_immediate(&mut dioxus_core::NoOpMutations);
+    assert_eq!(dioxus_ssr::render(&dom), "broken");
+
+    // The error is kept until the boundary is reset
+    dom.mark_dirty(ScopeId(1));
+    dom.render_immediate(&mut dioxus_core::NoOpMutations);
+    assert_eq!(dioxus_ssr::render(&dom), "broken");
+
+    SHOULD_THROW.store(false, Ordering::SeqCst);
+    // The error boundary component is the first scope below the root
+    dom.in_runtime(|| {
+        ScopeId(1)
+            .consume_context::<ErrorBoundary>()
+            .unwrap()
+            .reset()
+    });
+    dom.process_events();
+    dom.render_immediate(&mut dioxus_core::NoOpMutations);
+    assert_eq!(dioxus_ssr::render(&dom), "fixed");
+}
@@ -80,3 +80,30 @@ fn problematic_child() -> Element {
             } }
     }
 }
+
+static MODIFIER_CLICKS: Mutex<usize> = Mutex::new(0);
+
+#[test]
+fn stop_propagation_modifier() {
+    set_event_converter(Box::new(dioxus::html::SerializedHtmlEventConverter));
+
+    fn app() -> Element {
+        rsx! {
+            div { onclick: move |_| *MODIFIER_CLICKS.lock().unwrap() += 10,
+                button { onclick |stop_propagation|: move |_| *MODIFIER_CLICKS.lock().unwrap() += 1 }
+            }
+        }
+    }
+
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild(&mut dioxus_core::NoOpMutations);
+
+    // The button handler runs, but the click never reaches the div
+    dom.handle_event(
+        "click",
+        Rc::new(PlatformEventData::new(Box::<SerializedMouseData>::default())),
+        ElementId(2),
+        true,
+    );
+    assert_eq!(*MODIFIER_CLICKS.lock().unwrap(), 1);
+}
@@ -361,4 +361,10 @@ impl WriteMutations for InsertEventListenerMutationHandler<'_> {
     fn remove_node(&mut self, _: ElementId) {}
 
     fn push_root(&mut self, _: ElementId) {}
+
+    fn create_subtree_root(&mut self, _: usize, _: &str) {}
+
+    fn append_to_subtree(&mut self, _: usize, _: usize) {}
+
+    fn wait_for_exit(&mut self, _: ScopeId, _: ElementId, _: std::time::Duration) {}
 }
@@ -0,0 +1,175 @@
+//! Keyed KeepAlive components cache their subtree when they are removed and restore it when they are created again
+
+use std::{cell::Cell, rc::Rc};
+
+use dioxus::dioxus_core::Mutation::*;
+use dioxus::prelude::*;
+use dioxus_core::ElementId;
+
+/// Show one of two tabs. The tabs come from the same template, so switching tabs only changes the key
+fn tabs() -> Element {
+    let tab = if generation() % 2 == 0 { "a" } else { "b" };
+    rsx! {
+        KeepAlive { key: "{tab}", Counter { name: tab } }
+    }
+}
+
+/// Counts how many times it rendered in a hook
+#[component]
+fn Counter(name: &'static str) -> Element {
+    let renders = use_hook(|| Rc::new(Cell::new(0)));
+    renders.set(renders.get() + 1);
+    rsx! { p { "{name}: {renders.get()}" } }
+}
+
+/// Cycle through three tabs from the same template
+fn three_tabs() -> Element {
+    let tab = ["a", "b", "c"][generation() % 3];
+    rsx! {
+        KeepAlive { key: "{tab}", Counter { name: tab } }
+    }
+}
+
+fn switch_tab(dom: &mut VirtualDom) {
+    dom.mark_dirty(ScopeId::ROOT);
+    dom.render_immediate(&mut dioxus_core::NoOpMutations);
+}
+
+#[test]
+fn state_is_kept_while_cached() {
+    let mut dom = VirtualDom::new(tabs);
+    dom.rebuild(&mut dioxus_core::NoOpMutations);
+
+    // The counter of the first tab is ScopeId(2)
+    dom.mark_dirty(ScopeId(2));
+    dom.render_immediate(&mut dioxus_core::NoOpMutations);
+    assert_eq!(dioxus_ssr::render(&dom), "<p>a: 2</p>");
+
+    switch_tab(&mut dom);
+    assert_eq!(dioxus_ssr::render(&dom), "<p>b: 1</p>");
+
+    switch_tab(&mut dom);
+    assert_eq!(dioxus_ssr::render(&dom), "<p>a: 2</p>");
+}
+
+#[test]
+fn restored_nodes_keep_their_ids() {
+    let mut dom = VirtualDom::new(tabs);
+
+    assert_eq!(
+        dom.rebuild_to_vec().santize().edits,
+        [
+            LoadTemplate { name: "template", index: 0, id: ElementId(1) },
+            HydrateText { path: &[0], value: "a: 1".to_string(), id: ElementId(2) },
+            AppendChildren { id: ElementId(0), m: 1 },
+        ]
+    );
+
+    dom.mark_dirty(ScopeId::ROOT);
+    assert_eq!(
+        dom.render_immediate_to_vec().santize().edits,
+        [
+            LoadTemplate { name: "template", index: 0, id: ElementId(3) },
+            HydrateText { path: &[0], value: "b: 1".to_string(), id: ElementId(4) },
+            ReplaceWith { id: ElementId(1), m: 1 },
+        ]
+    );
+
+    // The cached nodes are created again with the same ids
+    dom.mark_dirty(ScopeId::ROOT);
+    assert_eq!(
+        dom.render_immediate_to_vec().santize().edits,
+        [
+            LoadTemplate { name: "template", index: 0, id: ElementId(1) },
+            HydrateText { path: &[0], value: "a: 1".to_string(), id: ElementId(2) },
+            ReplaceWith { id: ElementId(3), m: 1 },
+        ]
+    );
+}
+
+#[test]
+fn scopes_marked_dirty_while_cached_render_when_restored() {
+    let mut dom = VirtualDom::new(tabs);
+    dom.rebuild(&mut dioxus_core::NoOpMutations);
+    switch_tab(&mut dom);
+
+    // The cached counter is not rendered while it is out of the tree
+    dom.mark_dirty(ScopeId(2));
+    assert!(dom.render_immediate_to_vec().edits.is_empty());
+    assert_eq!(dioxus_ssr::render(&dom), "<p>b: 1</p>");
+
+    switch_tab(&mut dom);
+    assert_eq!(dioxus_ssr::render(&dom), "<p>a: 2</p>");
+}
+
+#[test]
+fn keep_alive_without_a_key_is_dropped() {
+    fn app() -> Element {
+        let gen = generation();
+        rsx! {
+            div {
+                if gen % 2 == 0 {
+                    KeepAlive { Counter { name: "a" } }
+                }
+            }
+        }
+    }
+
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild(&mut dioxus_core::NoOpMutations);
+    assert!(dom.get_scope(ScopeId(2)).is_some());
+
+    switch_tab(&mut dom);
+    assert!(dom.get_scope(ScopeId(2)).is_none());
+}
+
+#[test]
+fn subtrees_cached_the_longest_time_ago_are_dropped_over_the_limit() {
+    let mut dom = VirtualDom::new(three_tabs).with_keep_alive_limit(1);
+    dom.rebuild(&mut dioxus_core::NoOpMutations);
+    dom.mark_dirty(ScopeId(2));
+    dom.render_immediate(&mut dioxus_core::NoOpMutations);
+
+    // Caching the second tab drops the first one
+    switch_tab(&mut dom);
+    assert!(dom.get_scope(ScopeId(2)).is_some());
+    switch_tab(&mut dom);
+    assert!(dom.get_scope(ScopeId(2)).is_none());
+    assert!(dom.get_scope(ScopeId(4)).is_some());
+
+    switch_tab(&mut dom);
+    assert_eq!(dioxus_ssr::render(&dom), "<p>a: 1</p>");
+}
+
+#[test]
+fn evicted_subtrees_are_dropped() {
+    let mut dom = VirtualDom::new(tabs);
+    dom.rebuild(&mut dioxus_core::NoOpMutations);
+    dom.mark_dirty(ScopeId(2));
+    dom.render_immediate(&mut dioxus_core::NoOpMutations);
+    switch_tab(&mut dom);
+
+    dom.in_runtime(|| evict_keep_alive("a"));
+    switch_tab(&mut dom);
+    assert_eq!(dioxus_ssr::render(&dom), "<p>a: 1</p>");
+}
+
+#[test]
+fn keyed_nodes_that_are_not_keep_alive_are_diffed() {
+    fn app() -> Element {
+        let gen = generation();
+        rsx! {
+            div { key: "{gen}", "{gen}" }
+        }
+    }
+
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild(&mut dioxus_core::NoOpMutations);
+
+    // Only the text changes even though the key of the node changed
+    dom.mark_dirty(ScopeId::ROOT);
+    assert_eq!(
+        dom.render_immediate_to_vec().santize().edits,
+        [SetText { value: "1".to_string(), id: ElementId(2) }]
+    );
+}
@@ -0,0 +1,43 @@
+#![allow(non_snake_case)]
+
+use std::cell::Cell;
+
+use dioxus::prelude::*;
+
+thread_local! {
+    static GROUP: Cell<usize> = const { Cell::new(0) };
+    static CHILD_RENDERS: Cell<usize> = const { Cell::new(0) };
+}
+
+#[component]
+fn Child(label: String, children: Element) -> Element {
+    CHILD_RENDERS.with(|renders| renders.set(renders.get() + 1));
+    rsx! { "{label}" }
+}
+
+/// Memo only renders its children again when its deps change
+#[test]
+fn memo_skips_unchanged_deps() {
+    fn app() -> Element {
+        let group = GROUP.with(Cell::get);
+
+        rsx! {
+            Memo { deps: group,
+                Child { label: "group {group}", "children are never equal" }
+            }
+        }
+    }
+
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild(&mut dioxus_core::NoOpMutations);
+    assert_eq!(dioxus_ssr::render(&dom), "group 0");
+
+    for (group, renders) in [(0, 1), (1, 2), (1, 2), (2, 3)] {
+        GROUP.with(|current| current.set(group));
+        dom.mark_dirty(ScopeId::ROOT);
+        dom.render_immediate(&mut dioxus_core::NoOpMutations);
+
+        assert_eq!(dioxus_ssr::render(&dom), format!("group {group}"));
+        assert_eq!(CHILD_RENDERS.with(Cell::get), renders);
+    }
+}
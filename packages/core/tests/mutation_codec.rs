@@ -0,0 +1,142 @@
+//! Mutations encoded with the binary mutation codec decode to the same mutations
+
+use dioxus::dioxus_core::{
+    AttributeValue, ElementId, MutationDecodeErrorKind, MutationDecoder, MutationEncoder,
+    Mutations, WriteMutations,
+};
+use dioxus::prelude::*;
+
+fn app() -> Element {
+    let gen = generation();
+    let items = (0..gen % 3).map(|i| rsx! { li { key: "{i}", "item {i}" } });
+    rsx! {
+        div { class: "list", "data-gen": gen as i64, hidden: gen % 2 == 0, width: gen as f64 / 2.0,
+            if gen % 2 == 0 {
+                h1 { onclick: |_| {}, "even" }
+            }
+            ul { {items} }
+            "generation {gen}"
+        }
+    }
+}
+
+fn round_trip(decoder: &mut MutationDecoder, encoder: &mut MutationEncoder) -> Mutations {
+    let mut decoded = Mutations::default();
+    decoder.decode(&encoder.finish(), &mut decoded).unwrap();
+    decoded
+}
+
+#[test]
+fn round_trip_mutations() {
+    let mut expected_dom = VirtualDom::new(app);
+    let mut encoded_dom = VirtualDom::new(app);
+    let mut encoder = MutationEncoder::new();
+    let mut decoder = MutationDecoder::new();
+
+    let expected = expected_dom.rebuild_to_vec();
+    encoded_dom.rebuild(&mut encoder);
+    let decoded = round_trip(&mut decoder, &mut encoder);
+    assert!(encoder.is_empty());
+    assert!(!decoded.templates.is_empty());
+    assert_eq!(decoded, expected);
+
+    for _ in 0..6 {
+        expected_dom.mark_dirty(ScopeId::ROOT);
+        encoded_dom.mark_dirty(ScopeId::ROOT);
+        let expected = expected_dom.render_immediate_to_vec();
+        encoded_dom.render_immediate(&mut encoder);
+        assert_eq!(round_trip(&mut decoder, &mut encoder), expected);
+    }
+}
+
+#[test]
+fn static_strings_are_sent_once() {
+    let mut encoder = MutationEncoder::new();
+    encoder.create_event_listener("click", ElementId(1));
+    let first = encoder.finish();
+    encoder.create_event_listener("click", ElementId(2));
+    let second = encoder.finish();
+    assert!(second.len() < first.len());
+
+    // a decoder that missed the first batch doesn't know the string
+    let mut decoded = Mutations::default();
+    let err = MutationDecoder::new()
+        .decode(&second, &mut decoded)
+        .unwrap_err();
+    assert_eq!(err.kind, MutationDecodeErrorKind::UnknownIndex(0));
+
+    let mut decoder = MutationDecoder::new();
+    decoder.decode(&first, &mut decoded).unwrap();
+    decoder.decode(&second, &mut decoded).unwrap();
+    assert_eq!(decoded.edits.len(), 2);
+}
+
+#[test]
+fn invalid_batches() {
+    let mut encoder = MutationEncoder::new();
+    encoder.set_attribute("value", None, &AttributeValue::Int(-42), ElementId(3));
+    let batch = encoder.finish();
+
+    let mut decoded = Mutations::default();
+    let mut decode = |bytes: &[u8]| {
+        MutationDecoder::new()
+            .decode(bytes, &mut decoded)
+            .unwrap_err()
+            .kind
+    };
+    assert_eq!(
+        decode(b"not mutations"),
+        MutationDecodeErrorKind::InvalidHeader
+    );
+    let mut newer = batch.clone();
+    newer[3] = MutationEncoder::VERSION + 1;
+    assert_eq!(
+        decode(&newer),
+        MutationDecodeErrorKind::UnsupportedVersion(MutationEncoder::VERSION + 1)
+    );
+    assert_eq!(
+        decode(&batch[..batch.len() - 1]),
+        MutationDecodeErrorKind::UnexpectedEnd
+    );
+    let mut unknown = batch.clone();
+    unknown[4] = u8::MAX;
+    assert_eq!(
+        decode(&unknown),
+        MutationDecodeErrorKind::InvalidTag(u8::MAX)
+    );
+
+    let mut decoded = Mutations::default();
+    MutationDecoder::new().decode(&batch, &mut decoded).unwrap();
+    assert_eq!(
+        decoded.edits,
+        [dioxus::dioxus_core::Mutation::SetAttribute {
+            name: "value",
+            ns: None,
+            value: AttributeValue::Int(-42),
+            id: ElementId(3)
+        }]
+    );
+}
+
+#[test]
+fn exit_durations_are_sent_in_milliseconds() {
+    let mut encoder = MutationEncoder::new();
+    encoder.wait_for_exit(
+        ScopeId(4),
+        ElementId(7),
+        std::time::Duration::from_micros(250_900),
+    );
+
+    let mut decoded = Mutations::default();
+    MutationDecoder::new()
+        .decode(&encoder.finish(), &mut decoded)
+        .unwrap();
+    assert_eq!(
+        decoded.edits,
+        [dioxus::dioxus_core::Mutation::WaitForExit {
+            scope: ScopeId(4),
+            id: ElementId(7),
+            duration: std::time::Duration::from_millis(250)
+        }]
+    );
+}
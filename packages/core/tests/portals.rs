@@ -0,0 +1,142 @@
+//! Portals render their children into a separate subtree and leave a placeholder in the tree
+
+use dioxus::dioxus_core::Mutation::*;
+use dioxus::prelude::*;
+use dioxus_core::ElementId;
+
+#[test]
+fn portal_mounts_children_in_subtree() {
+    fn app() -> Element {
+        rsx! {
+            div {
+                Portal { target: "modals", p { "in a portal" } }
+            }
+        }
+    }
+
+    let mut dom = VirtualDom::new(app);
+
+    assert_eq!(
+        dom.rebuild_to_vec().santize().edits,
+        [
+            LoadTemplate { name: "template", index: 0, id: ElementId(1) },
+            LoadTemplate { name: "template", index: 0, id: ElementId(2) },
+            CreateSubtreeRoot { subtree: 1, target: "modals".to_string() },
+            AppendToSubtree { subtree: 1, m: 1 },
+            CreatePlaceholder { id: ElementId(3) },
+            ReplacePlaceholder { path: &[0], m: 1 },
+            AppendChildren { id: ElementId(0), m: 1 },
+        ]
+    );
+}
+
+#[test]
+fn portal_children_are_removed_with_the_portal() {
+    fn app() -> Element {
+        let gen = generation();
+        rsx! {
+            div {
+                if gen % 2 == 0 {
+                    Portal { target: "modals", p { "in a portal" } }
+                }
+            }
+        }
+    }
+
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild(&mut dioxus_core::NoOpMutations);
+
+    dom.mark_dirty(ScopeId::ROOT);
+    assert_eq!(
+        dom.render_immediate_to_vec().santize().edits,
+        [
+            CreatePlaceholder { id: ElementId(4) },
+            ReplaceWith { id: ElementId(3), m: 1 },
+            Remove { id: ElementId(2) },
+        ]
+    );
+
+    // the portal is mounted again in a fresh subtree
+    dom.mark_dirty(ScopeId::ROOT);
+    assert_eq!(
+        dom.render_immediate_to_vec().santize().edits,
+        [
+            LoadTemplate { name: "template", index: 0, id: ElementId(2) },
+            CreateSubtreeRoot { subtree: 1, target: "modals".to_string() },
+            AppendToSubtree { subtree: 1, m: 1 },
+            CreatePlaceholder { id: ElementId(3) },
+            ReplaceWith { id: ElementId(4), m: 1 },
+        ]
+    );
+}
+
+#[test]
+fn portal_children_diff_in_place() {
+    fn app() -> Element {
+        let gen = generation();
+        rsx! {
+            Portal { target: "modals", p { "{gen}" } }
+        }
+    }
+
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild(&mut dioxus_core::NoOpMutations);
+
+    dom.mark_dirty(ScopeId::ROOT);
+    assert_eq!(
+        dom.render_immediate_to_vec().santize().edits,
+        [SetText { value: "1".to_string(), id: ElementId(2) }]
+    );
+}
+
+#[test]
+fn changing_the_target_moves_the_children() {
+    fn app() -> Element {
+        let target = if generation() == 0 {
+            "modals"
+        } else {
+            "toasts"
+        };
+        rsx! {
+            Portal { target: "{target}", p { "first" } "second" }
+        }
+    }
+
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild(&mut dioxus_core::NoOpMutations);
+
+    dom.mark_dirty(ScopeId::ROOT);
+    assert_eq!(
+        dom.render_immediate_to_vec().santize().edits,
+        [
+            CreateSubtreeRoot { subtree: 1, target: "toasts".to_string() },
+            PushRoot { id: ElementId(1) },
+            PushRoot { id: ElementId(2) },
+            AppendToSubtree { subtree: 1, m: 2 },
+        ]
+    );
+}
+
+#[test]
+fn keyed_portals_move_their_placeholders() {
+    fn app() -> Element {
+        let order: &[usize] = if generation() == 0 { &[0, 1] } else { &[1, 0] };
+        rsx! {
+            for i in order.iter().copied() {
+                Portal { key: "{i}", target: "portal-{i}", "{i}" }
+            }
+        }
+    }
+
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild(&mut dioxus_core::NoOpMutations);
+
+    dom.mark_dirty(ScopeId::ROOT);
+    let edits = dom.render_immediate_to_vec().santize().edits;
+    // only the placeholders in the tree move, the children stay in their subtrees
+    assert!(edits.iter().all(|edit| matches!(
+        edit,
+        PushRoot { id: ElementId(2) | ElementId(4) } | InsertBefore { .. } | InsertAfter { .. }
+    )));
+    assert!(!edits.is_empty());
+}
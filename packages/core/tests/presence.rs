@@ -0,0 +1,144 @@
+//! Animated presences keep their nodes until the renderer reports that their exit animation ended
+
+use std::time::Duration;
+
+use dioxus::dioxus_core::Mutation::*;
+use dioxus::prelude::*;
+use dioxus_core::{ElementId, NoOpMutations};
+
+/// Show a toast on even generations and a span in its place on odd generations
+fn app() -> Element {
+    let show = generation() % 2 == 0;
+    rsx! {
+        div {
+            if show {
+                AnimatedPresence { exit_duration: Duration::from_millis(100), Toast {} }
+            } else {
+                span { "after" }
+            }
+        }
+    }
+}
+
+#[component]
+fn Toast() -> Element {
+    let presence = use_presence();
+    let class = if presence.is_exiting() { "out" } else { "in" };
+    rsx! { p { class, "toast" } }
+}
+
+#[test]
+fn exits_wait_for_the_renderer() {
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild(&mut NoOpMutations);
+
+    // The toast stays and switches to its exit class
+    dom.mark_dirty(ScopeId::ROOT);
+    assert_eq!(
+        dom.render_immediate_to_vec().santize().edits,
+        [
+            LoadTemplate { name: "template", index: 0, id: ElementId(3) },
+            InsertAfter { id: ElementId(2), m: 1 },
+            WaitForExit {
+                scope: ScopeId(1),
+                id: ElementId(2),
+                duration: Duration::from_millis(100)
+            },
+            SetAttribute { name: "class", ns: None, value: "out".into_value(), id: ElementId(2) },
+        ]
+    );
+    assert!(dom.get_scope(ScopeId(2)).is_some());
+
+    dom.in_runtime(|| ScopeId(1).finish_exit());
+    assert_eq!(
+        dom.render_immediate_to_vec().santize().edits,
+        [Remove { id: ElementId(2) }]
+    );
+    assert!(dom.get_scope(ScopeId(1)).is_none());
+    assert!(dom.get_scope(ScopeId(2)).is_none());
+}
+
+#[tokio::test]
+async fn finishing_an_exit_wakes_the_virtual_dom() {
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild(&mut NoOpMutations);
+    dom.mark_dirty(ScopeId::ROOT);
+    dom.render_immediate(&mut NoOpMutations);
+
+    dom.in_runtime(|| {
+        ScopeId(2).spawn(async {
+            use_presence().finish_exit();
+        })
+    });
+    tokio::time::timeout(Duration::from_millis(500), dom.wait_for_work())
+        .await
+        .expect("the finished exit should wake the VirtualDom");
+    assert_eq!(
+        dom.render_immediate_to_vec().edits,
+        [Remove { id: ElementId(2) }]
+    );
+}
+
+#[test]
+fn exiting_nodes_are_removed_with_their_parent_component() {
+    fn app() -> Element {
+        let gen = generation();
+        rsx! {
+            div {
+                if gen < 2 {
+                    Wrapper { show: gen == 0 }
+                }
+            }
+        }
+    }
+
+    #[component]
+    fn Wrapper(show: bool) -> Element {
+        rsx! {
+            if show {
+                AnimatedPresence { Toast {} }
+            }
+        }
+    }
+
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild(&mut NoOpMutations);
+
+    // The presence starts exiting inside of the wrapper
+    dom.mark_dirty(ScopeId::ROOT);
+    let edits = dom.render_immediate_to_vec().edits;
+    assert!(edits.iter().any(|edit| matches!(edit, WaitForExit { .. })));
+
+    // Removing the wrapper removes the exiting toast before the placeholder of the wrapper
+    dom.mark_dirty(ScopeId::ROOT);
+    assert_eq!(
+        dom.render_immediate_to_vec().edits,
+        [
+            CreatePlaceholder { id: ElementId(4) },
+            Remove { id: ElementId(2) },
+            ReplaceWith { id: ElementId(3), m: 1 },
+        ]
+    );
+    assert!(dom.get_scope(ScopeId(2)).is_none());
+    assert!(dom.get_scope(ScopeId(3)).is_none());
+}
+
+#[test]
+fn presences_removed_with_a_parent_element_do_not_exit() {
+    fn app() -> Element {
+        let show = generation() % 2 == 0;
+        rsx! {
+            if show {
+                div { AnimatedPresence { Toast {} } }
+            }
+        }
+    }
+
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild(&mut NoOpMutations);
+
+    dom.mark_dirty(ScopeId::ROOT);
+    let edits = dom.render_immediate_to_vec().edits;
+    assert!(!edits.iter().any(|edit| matches!(edit, WaitForExit { .. })));
+    assert!(dom.get_scope(ScopeId(1)).is_none());
+}
@@ -0,0 +1,122 @@
+//! Render the work of urgent events before the rest of the work
+
+use dioxus::prelude::*;
+use dioxus_core::{ElementId, EventPriority, Mutation::*};
+use std::rc::Rc;
+
+fn app() -> Element {
+    rsx! {
+        Clicker {}
+        Background {}
+    }
+}
+
+#[component]
+fn Clicker() -> Element {
+    let mut clicks = use_signal(|| 0);
+    rsx! {
+        button { onclick: move |_| clicks += 1, "{clicks}" }
+    }
+}
+
+#[component]
+fn Background() -> Element {
+    let renders = use_hook(|| Rc::new(std::cell::Cell::new(0)));
+    renders.set(renders.get() + 1);
+    let renders = renders.get();
+    rsx! { "{renders}" }
+}
+
+fn click(dom: &mut VirtualDom) {
+    dom.handle_event(
+        "click",
+        Rc::new(PlatformEventData::new(Box::<SerializedMouseData>::default())),
+        ElementId(1),
+        true,
+    );
+}
+
+#[test]
+fn events_are_classified_into_lanes() {
+    assert_eq!(EventPriority::of_event("click"), EventPriority::Immediate);
+    assert_eq!(EventPriority::of_event("keydown"), EventPriority::Immediate);
+    assert_eq!(EventPriority::of_event("input"), EventPriority::Immediate);
+    assert_eq!(EventPriority::of_event("mousemove"), EventPriority::High);
+    assert_eq!(EventPriority::of_event("scroll"), EventPriority::High);
+    assert_eq!(EventPriority::of_event("load"), EventPriority::Medium);
+    assert!(EventPriority::Immediate > EventPriority::High);
+    assert!(EventPriority::Medium > EventPriority::Low);
+}
+
+#[test]
+fn urgent_lanes_render_first() {
+    set_event_converter(Box::new(dioxus::html::SerializedHtmlEventConverter));
+
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild(&mut dioxus_core::NoOpMutations);
+
+    // The background scope is marked dirty by work that isn't an event, so it is in the lowest lane
+    dom.mark_dirty(ScopeId(2));
+    click(&mut dom);
+
+    let mut mutations = dioxus_core::Mutations::default();
+    assert!(dom.render_with_priority(&mut mutations, EventPriority::Immediate, || false));
+    assert_eq!(
+        mutations.edits,
+        [SetText { value: "1".into(), id: ElementId(2) }]
+    );
+
+    // The rest of the work is rendered later
+    assert_eq!(
+        dom.render_immediate_to_vec().edits,
+        [SetText { value: "2".into(), id: ElementId(3) }]
+    );
+}
+
+#[test]
+fn interrupted_renders_resume() {
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild(&mut dioxus_core::NoOpMutations);
+
+    dom.mark_dirty(ScopeId(1));
+    dom.mark_dirty(ScopeId(2));
+
+    // Stop after the first scope is rendered
+    let mut rendered = 0;
+    let mut mutations = dioxus_core::Mutations::default();
+    let finished = dom.render_with_priority(&mut mutations, EventPriority::Low, || {
+        rendered += 1;
+        rendered > 1
+    });
+    assert!(!finished);
+    assert!(mutations.edits.is_empty());
+
+    // The background scope is rendered by the next render
+    assert_eq!(
+        dom.render_immediate_to_vec().edits,
+        [SetText { value: "2".into(), id: ElementId(3) }]
+    );
+}
+
+#[test]
+fn work_queued_before_an_event_keeps_its_lane() {
+    set_event_converter(Box::new(dioxus::html::SerializedHtmlEventConverter));
+
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild(&mut dioxus_core::NoOpMutations);
+
+    // A signal the background scope reads was written before the click
+    dom.in_runtime(|| ScopeId(2).needs_update());
+    click(&mut dom);
+
+    let mut mutations = dioxus_core::Mutations::default();
+    assert!(dom.render_with_priority(&mut mutations, EventPriority::Immediate, || false));
+    assert_eq!(
+        mutations.edits,
+        [SetText { value: "1".into(), id: ElementId(2) }]
+    );
+    assert_eq!(
+        dom.render_immediate_to_vec().edits,
+        [SetText { value: "2".into(), id: ElementId(3) }]
+    );
+}
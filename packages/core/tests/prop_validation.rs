@@ -0,0 +1,110 @@
+#![allow(non_snake_case)]
+
+use dioxus::prelude::*;
+use dioxus_core::{CapturedError, InvalidProp};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+fn check_name(name: &str) -> Result<(), &'static str> {
+    match name.chars().next() {
+        Some(c) if c.is_uppercase() => Ok(()),
+        _ => Err("names must start with an uppercase letter"),
+    }
+}
+
+#[component]
+fn Profile(
+    #[props(validate = check_name)] name: String,
+    #[props(range = 0..=150)] age: u8,
+    #[props(len = 1..=3)] tags: Vec<&'static str>,
+    #[props(range = 1..)] rank: Option<u32>,
+) -> Element {
+    rsx! { "{name} {age} {tags:?} {rank:?}" }
+}
+
+/// The body of the child component. Function pointers can't be compared reliably, so each body is compared by a unique id instead
+#[derive(Clone)]
+struct Body {
+    id: usize,
+    render: fn() -> Element,
+}
+
+impl PartialEq for Body {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+/// Render the body of `child` inside of an error boundary that shows any invalid props
+fn render_with_boundary(child: fn() -> Element) -> String {
+    static NEXT_ID: AtomicUsize = AtomicUsize::new(0);
+
+    #[component]
+    fn Child(body: Body) -> Element {
+        (body.render)()
+    }
+
+    #[derive(Props, Clone, PartialEq)]
+    struct AppProps {
+        child: Body,
+    }
+
+    fn app(props: AppProps) -> Element {
+        rsx! {
+            ErrorBoundary {
+                handle_error: |error: CapturedError| {
+                    let message = error.downcast::<InvalidProp>().unwrap().to_string();
+                    rsx! { "{message}" }
+                },
+                Child { body: props.child }
+            }
+        }
+    }
+
+    let child = Body { id: NEXT_ID.fetch_add(1, Ordering::Relaxed), render: child };
+    let mut dom = VirtualDom::new_with_props(app, AppProps { child });
+    dom.rebuild(&mut dioxus_core::NoOpMutations);
+    dom.render_immediate(&mut dioxus_core::NoOpMutations);
+    dioxus_ssr::render(&dom)
+}
+
+#[test]
+fn valid_props_render() {
+    assert_eq!(
+        render_with_boundary(|| rsx! { Profile { name: "Ann", age: 30, tags: vec!["a"] } }),
+        "Ann 30 [&quot;a&quot;] None"
+    );
+}
+
+#[test]
+fn validate_function() {
+    assert_eq!(
+        render_with_boundary(|| rsx! { Profile { name: "ann", age: 30, tags: vec!["a"] } }),
+        "Invalid value for prop `name` of `ProfileProps`: names must start with an uppercase letter"
+    );
+}
+
+#[test]
+fn range() {
+    assert_eq!(
+        render_with_boundary(|| rsx! { Profile { name: "Ann", age: 200, tags: vec!["a"] } }),
+        "Invalid value for prop `age` of `ProfileProps`: expected a value in 0..=150, found 200"
+    );
+}
+
+#[test]
+fn optional_range() {
+    assert_eq!(
+        render_with_boundary(
+            || rsx! { Profile { name: "Ann", age: 30, tags: vec!["a"], rank: 0 } }
+        ),
+        "Invalid value for prop `rank` of `ProfileProps`: expected a value in 1.., found 0"
+    );
+}
+
+#[test]
+fn len() {
+    assert_eq!(
+        render_with_boundary(|| rsx! { Profile { name: "Ann", age: 30, tags: vec![] } }),
+        "Invalid value for prop `tags` of `ProfileProps`: expected a length in 1..=3, found 0"
+    );
+}
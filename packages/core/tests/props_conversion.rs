@@ -0,0 +1,75 @@
+#![allow(non_snake_case)]
+
+use std::{cell::RefCell, rc::Rc};
+
+use dioxus::prelude::*;
+
+fn render(app: fn() -> Element) -> String {
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild(&mut dioxus_core::NoOpMutations);
+    dioxus_ssr::render(&dom)
+}
+
+#[test]
+fn event_handler_props_take_closures() {
+    thread_local! {
+        static CALLS: RefCell<Vec<String>> = const { RefCell::new(Vec::new()) };
+    }
+
+    #[component]
+    fn Child(
+        onselect: EventHandler<i32>,
+        callback: EventHandler<String>,
+        onclose: Option<EventHandler>,
+        ondone: Option<EventHandler>,
+    ) -> Element {
+        onselect.call(1);
+        callback.call("two".to_string());
+        if let Some(onclose) = onclose {
+            onclose.call(());
+        }
+        assert!(ondone.is_none());
+        rsx! { "child" }
+    }
+
+    fn app() -> Element {
+        let record = |call: String| CALLS.with(|calls| calls.borrow_mut().push(call));
+        rsx! {
+            Child {
+                onselect: move |idx| record(format!("select {}", idx + 1)),
+                callback: move |text: String| record(text.to_uppercase()),
+                onclose: move |_| record("close".to_string())
+            }
+        }
+    }
+
+    assert_eq!(render(app), "child");
+    CALLS.with(|calls| assert_eq!(*calls.borrow(), ["select 2", "TWO", "close"]));
+}
+
+#[test]
+fn struct_level_into() {
+    #[derive(Props, Clone, PartialEq)]
+    #[props(into)]
+    struct CardProps {
+        title: String,
+        subtitle: Option<String>,
+        count: u64,
+        #[props(!into)]
+        shared: Rc<str>,
+    }
+
+    fn Card(props: CardProps) -> Element {
+        let CardProps { title, subtitle, count, shared } = props;
+        rsx! { "{title} {subtitle:?} {count} {shared}" }
+    }
+
+    fn app() -> Element {
+        let name = "world";
+        rsx! {
+            Card { title: "hello {name}", subtitle: "sub", count: 1u8, shared: Rc::from("rc") }
+        }
+    }
+
+    assert_eq!(render(app), "hello world Some(&quot;sub&quot;) 1 rc");
+}
@@ -0,0 +1,86 @@
+//! Recording the events and renders of a VirtualDom and replaying them into a fresh one
+use dioxus::dioxus_core::{ElementId, RecordedAction, Recorder};
+use dioxus::html::SerializedHtmlEventConverter;
+use dioxus::prelude::*;
+use std::{any::Any, rc::Rc};
+
+fn app() -> Element {
+    let mut count = use_signal(|| 0);
+
+    rsx! {
+        button { onclick: move |_| count += 1, "count {count}" }
+    }
+}
+
+fn click() -> Rc<dyn Any> {
+    Rc::new(PlatformEventData::new(Box::<SerializedMouseData>::default()))
+}
+
+#[test]
+fn replay_reaches_the_same_state() {
+    set_event_converter(Box::new(SerializedHtmlEventConverter));
+
+    let mut dom = VirtualDom::new(app);
+    dom.record(Recorder::new(|name, _| (name == "click").then(String::new)));
+    dom.rebuild(&mut dioxus_core::NoOpMutations);
+    for _ in 0..3 {
+        dom.handle_event("click", click(), ElementId(1), true);
+        dom.render_immediate(&mut dioxus_core::NoOpMutations);
+    }
+    assert_eq!(dioxus_ssr::render(&dom), "<button>count 3</button>");
+
+    let recording = dom.stop_recording().unwrap();
+    assert!(dom.recorder().is_none());
+
+    let actions: Vec<_> = recording
+        .entries
+        .iter()
+        .map(|entry| &entry.action)
+        .collect();
+    assert_eq!(actions.len(), 7);
+    assert_eq!(actions[0], &RecordedAction::Rebuild);
+    assert_eq!(
+        actions[1],
+        &RecordedAction::Event {
+            name: "click".to_string(),
+            element: ElementId(1),
+            bubbles: true,
+            data: Some(String::new()),
+        }
+    );
+    assert_eq!(actions[2], &RecordedAction::Render);
+    assert!(recording
+        .entries
+        .windows(2)
+        .all(|pair| pair[0].time <= pair[1].time));
+
+    let mut replayed = VirtualDom::new(app);
+    recording.replay(
+        &mut replayed,
+        |name, _| (name == "click").then(click),
+        &mut dioxus_core::NoOpMutations,
+    );
+    assert_eq!(dioxus_ssr::render(&replayed), "<button>count 3</button>");
+}
+
+/// Events the recorder couldn't encode are skipped in the replay
+#[test]
+fn replay_skips_events_without_data() {
+    set_event_converter(Box::new(SerializedHtmlEventConverter));
+
+    let mut dom = VirtualDom::new(app);
+    dom.record(Recorder::new(|_, _| None));
+    dom.rebuild(&mut dioxus_core::NoOpMutations);
+    dom.handle_event("click", click(), ElementId(1), true);
+    dom.render_immediate(&mut dioxus_core::NoOpMutations);
+    assert_eq!(dioxus_ssr::render(&dom), "<button>count 1</button>");
+
+    let recording = dom.stop_recording().unwrap();
+    let mut replayed = VirtualDom::new(app);
+    recording.replay(
+        &mut replayed,
+        |_, _| Some(click()),
+        &mut dioxus_core::NoOpMutations,
+    );
+    assert_eq!(dioxus_ssr::render(&replayed), "<button>count 0</button>");
+}
@@ -0,0 +1,62 @@
+//! Remounting a scope replaces its nodes with new ones without touching the rest of the tree
+
+use std::{cell::Cell, rc::Rc};
+
+use dioxus::dioxus_core::Mutation::*;
+use dioxus::prelude::*;
+use dioxus_core::ElementId;
+
+fn app() -> Element {
+    rsx! {
+        h1 { "title" }
+        Counter {}
+    }
+}
+
+/// Counts how many times it rendered in a hook
+#[component]
+fn Counter() -> Element {
+    let renders = use_hook(|| Rc::new(Cell::new(0)));
+    renders.set(renders.get() + 1);
+    rsx! {
+        p { "renders: {renders.get()}" }
+        Label {}
+    }
+}
+
+#[component]
+fn Label() -> Element {
+    rsx! { span { "label" } }
+}
+
+#[test]
+fn remount_replaces_the_nodes_of_the_scope() {
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild(&mut dioxus_core::NoOpMutations);
+    let counter = ScopeId(1);
+    let label = ScopeId(2);
+    assert_eq!(dom.get_scope(counter).unwrap().name(), "Counter");
+    assert_eq!(dom.get_scope(label).unwrap().id(), label);
+
+    let mut mutations = dioxus_core::Mutations::default();
+    dom.remount_scope(counter, &mut mutations);
+
+    // The other roots are removed and the new nodes replace the last root. The title is untouched
+    assert_eq!(
+        mutations.santize().edits,
+        [
+            LoadTemplate { name: "template", index: 0, id: ElementId(5) },
+            HydrateText { path: &[0], value: "renders: 2".to_string(), id: ElementId(6) },
+            LoadTemplate { name: "template", index: 0, id: ElementId(7) },
+            Remove { id: ElementId(2) },
+            ReplaceWith { id: ElementId(4), m: 2 },
+        ]
+    );
+
+    // The counter keeps its state, but the label below it is created again
+    assert_eq!(
+        dioxus_ssr::render(&dom),
+        "<h1>title</h1><p>renders: 2</p><span>label</span>"
+    );
+    assert!(dom.get_scope(label).is_none());
+}
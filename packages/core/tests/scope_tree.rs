@@ -0,0 +1,86 @@
+#![allow(non_snake_case)]
+
+//! Snapshots of the scope tree for developer tools
+use dioxus::dioxus_core::ElementId;
+use dioxus::prelude::*;
+
+#[test]
+fn scope_tree_is_depth_first() {
+    fn app() -> Element {
+        rsx! {
+            Parent {}
+            Leaf { name: "second" }
+        }
+    }
+
+    #[component]
+    fn Parent() -> Element {
+        let count = use_signal(|| 0);
+        rsx! {
+            div { "count {count}" }
+            Leaf { name: "first" }
+        }
+    }
+
+    #[component]
+    fn Leaf(name: String) -> Element {
+        rsx! { "{name}" }
+    }
+
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild(&mut dioxus_core::NoOpMutations);
+
+    let tree = dom.scope_tree();
+    let names: Vec<_> = tree
+        .iter()
+        .map(|info| (info.name.as_str(), info.height))
+        .collect();
+    assert_eq!(names, [("app", 0), ("Parent", 1), ("Leaf", 2), ("Leaf", 1)]);
+    assert_eq!(tree[0].children, [tree[1].id, tree[3].id]);
+    assert_eq!(tree[2].parent, Some(tree[1].id));
+
+    let parent = &tree[1];
+    assert_eq!(parent.render_count, 1);
+    assert_eq!(parent.hooks.len(), 1);
+    assert!(parent.hooks[0].type_name.contains("Signal<i32"));
+    assert_eq!(parent.hooks[0].value.as_deref(), Some("0"));
+    assert!(parent.render_time.is_some());
+    // The div and the text node of the child
+    assert_eq!(parent.elements.len(), 2);
+    assert!(!parent.elements.contains(&ElementId(0)));
+
+    let leaf = &tree[2];
+    assert_eq!(leaf.props.len(), 1);
+    assert_eq!(leaf.props[0].name, "name");
+    assert_eq!(leaf.props[0].value, "\"first\"");
+    // The root component has no props to show
+    assert!(tree[0].props.is_empty());
+
+    dom.mark_dirty(parent.id);
+    dom.render_immediate(&mut dioxus_core::NoOpMutations);
+    assert_eq!(dom.scope_tree()[1].render_count, 2);
+}
+
+#[test]
+fn props_without_debug_show_their_type() {
+    #[derive(Clone, PartialEq)]
+    struct Opaque;
+
+    fn app() -> Element {
+        rsx! { Child { opaque: Opaque, count: 3 } }
+    }
+
+    #[component]
+    fn Child(opaque: Opaque, count: u32) -> Element {
+        let _ = opaque;
+        rsx! { "{count}" }
+    }
+
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild(&mut dioxus_core::NoOpMutations);
+
+    let props = &dom.scope_tree()[1].props;
+    assert_eq!(props[0].name, "opaque");
+    assert!(props[0].value.ends_with("Opaque"));
+    assert_eq!(props[1].value, "3");
+}
@@ -40,3 +40,69 @@ fn suspended_child() -> Element {
 
     rsx!("child")
 }
+
+#[test]
+fn suspense_boundary_shows_fallback() {
+    tokio::runtime::Builder::new_current_thread()
+        .build()
+        .unwrap()
+        .block_on(async {
+            let mut dom = VirtualDom::new(boundary_app);
+            dom.rebuild(&mut dioxus_core::NoOpMutations);
+            // the boundary is rendered again once the child suspends
+            dom.render_immediate(&mut dioxus_core::NoOpMutations);
+            let out = dioxus_ssr::render(&dom);
+            assert_eq!(out, "<div>Loading...</div>");
+
+            dom.wait_for_suspense().await;
+            dom.render_immediate(&mut dioxus_core::NoOpMutations);
+            let out = dioxus_ssr::render(&dom);
+            assert_eq!(out, "<div>siblingchild</div>");
+        });
+}
+
+fn boundary_app() -> Element {
+    rsx!(
+        div {
+            SuspenseBoundary {
+                fallback: rsx!("Loading..."),
+                "sibling"
+                suspended_child {}
+            }
+        }
+    )
+}
+
+#[test]
+fn suspense_boundary_keeps_children_alive() {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    static CREATED: AtomicUsize = AtomicUsize::new(0);
+
+    fn app() -> Element {
+        rsx!(
+            SuspenseBoundary {
+                fallback: rsx!("Loading..."),
+                created_once {}
+                suspended_child {}
+            }
+        )
+    }
+
+    fn created_once() -> Element {
+        use_hook(|| CREATED.fetch_add(1, Ordering::SeqCst));
+        rsx!("sibling")
+    }
+
+    tokio::runtime::Builder::new_current_thread()
+        .build()
+        .unwrap()
+        .block_on(async {
+            let mut dom = VirtualDom::new(app);
+            dom.rebuild(&mut dioxus_core::NoOpMutations);
+            dom.wait_for_suspense().await;
+            dom.render_immediate(&mut dioxus_core::NoOpMutations);
+
+            assert_eq!(dioxus_ssr::render(&dom), "siblingchild");
+            assert_eq!(CREATED.load(Ordering::SeqCst), 1);
+        });
+}
@@ -1,16 +1,19 @@
 use crate::{
     config::{Config, WindowCloseBehaviour},
-    element::DesktopElement,
+    element::{DesktopElement, DesktopPointerEvent},
     event_handlers::WindowEventHandlers,
-    file_upload::FileDialogRequest,
+    file_upload::{DesktopFileDragEvent, FileDialogRequest},
     ipc::IpcMessage,
     ipc::{EventData, UserWindowEvent},
     query::QueryResult,
+    safe_area::SafeAreaMessage,
     shortcut::{GlobalHotKeyEvent, ShortcutRegistry},
     webview::WebviewInstance,
 };
 use crossbeam_channel::Receiver;
 use dioxus_core::ElementId;
+#[cfg(not(any(target_os = "ios", target_os = "android")))]
+use dioxus_core::ScopeId;
 use dioxus_core::VirtualDom;
 use dioxus_html::{
     native_bind::NativeFileEngine, FileEngine, HasFileData, HasFormData, HtmlEvent,
@@ -41,6 +44,25 @@ pub(crate) struct App {
     pub(crate) window_behavior: WindowCloseBehaviour,
     pub(crate) webviews: HashMap<WindowId, WebviewInstance>,
 
+    /// The first window that was opened. The system tray shows and hides this window
+    pub(crate) main_window: Option<WindowId>,
+
+    /// The arguments of other instances of the app are sent to the main window
+    #[cfg(not(any(target_os = "ios", target_os = "android")))]
+    pub(crate) single_instance: crate::single_instance::SingleInstance,
+
+    /// The URLs the app is opened with are sent to the main window
+    #[cfg(not(any(target_os = "ios", target_os = "android")))]
+    pub(crate) deep_link_scheme: Option<String>,
+    #[cfg(not(any(target_os = "ios", target_os = "android")))]
+    pub(crate) deep_links: crate::deep_link::DeepLinks,
+    #[cfg(not(any(target_os = "ios", target_os = "android")))]
+    pub(crate) deep_link: Option<dioxus_hooks::DeepLinkContext>,
+
+    /// Lifecycle events and the back button of the platform are sent to the main window
+    pub(crate) lifecycle: Option<dioxus_hooks::AppLifecycleContext>,
+    pub(crate) back_handlers: Option<dioxus_hooks::BackHandlerContext>,
+
     /// This single blob of state is shared between all the windows so they have access to the runtime state
     ///
     /// This includes stuff like the event handlers, shortcuts, etc as well as ways to modify *other* windows
@@ -57,16 +79,47 @@ pub(crate) struct SharedContext {
     pub(crate) global_hotkey_channel: Receiver<GlobalHotKeyEvent>,
     pub(crate) proxy: EventLoopProxy<UserWindowEvent>,
     pub(crate) target: EventLoopWindowTarget<UserWindowEvent>,
+    #[cfg(not(any(target_os = "ios", target_os = "android")))]
+    pub(crate) tray: RefCell<Option<crate::tray::SystemTrayState>>,
+    #[cfg(not(any(target_os = "ios", target_os = "android")))]
+    pub(crate) notifications: crate::notification::NotificationHandlers,
+    #[cfg(not(any(target_os = "ios", target_os = "android")))]
+    pub(crate) menu_handlers: crate::menubar::MenuEventHandlers,
 }
 
 impl App {
     pub fn new(cfg: Config, virtual_dom: VirtualDom) -> (EventLoop<UserWindowEvent>, Self) {
+        // If another instance is running, this sends it the arguments of this process and exits
+        #[cfg(not(any(target_os = "ios", target_os = "android")))]
+        let single_instance = if cfg.single_instance || cfg.deep_link_scheme.is_some() {
+            crate::single_instance::SingleInstance::start()
+        } else {
+            Default::default()
+        };
+        #[cfg(not(any(target_os = "ios", target_os = "android")))]
+        let deep_links = cfg
+            .deep_link_scheme
+            .as_deref()
+            .map(crate::deep_link::DeepLinks::start)
+            .unwrap_or_default();
+
         let event_loop = EventLoopBuilder::<UserWindowEvent>::with_user_event().build();
 
         let app = Self {
             window_behavior: cfg.last_window_close_behaviour,
             is_visible_before_start: true,
             webviews: HashMap::new(),
+            main_window: None,
+            #[cfg(not(any(target_os = "ios", target_os = "android")))]
+            single_instance,
+            #[cfg(not(any(target_os = "ios", target_os = "android")))]
+            deep_link_scheme: cfg.deep_link_scheme.clone(),
+            #[cfg(not(any(target_os = "ios", target_os = "android")))]
+            deep_links,
+            #[cfg(not(any(target_os = "ios", target_os = "android")))]
+            deep_link: None,
+            lifecycle: None,
+            back_handlers: None,
             control_flow: ControlFlow::Wait,
             unmounted_dom: Cell::new(Some(virtual_dom)),
             cfg: Cell::new(Some(cfg)),
@@ -77,6 +130,12 @@ impl App {
                 global_hotkey_channel: GlobalHotKeyEvent::receiver().clone(),
                 proxy: event_loop.create_proxy(),
                 target: event_loop.clone(),
+                #[cfg(not(any(target_os = "ios", target_os = "android")))]
+                tray: Default::default(),
+                #[cfg(not(any(target_os = "ios", target_os = "android")))]
+                notifications: Default::default(),
+                #[cfg(not(any(target_os = "ios", target_os = "android")))]
+                menu_handlers: Default::default(),
             }),
         };
 
@@ -100,6 +159,142 @@ impl App {
         if let Ok(event) = self.shared.global_hotkey_channel.try_recv() {
             self.shared.shortcut_manager.call_handlers(event);
         }
+
+        // The tray and the menu bar share one channel for clicks on their items
+        #[cfg(not(any(target_os = "ios", target_os = "android")))]
+        {
+            let menu_event = muda::MenuEvent::receiver().try_recv().ok();
+            if let Some(event) = self.handle_tray_events(menu_event) {
+                self.handle_menu_event(event);
+            }
+        }
+    }
+
+    /// Call the handlers of the system tray for any clicks on the icon or its menu
+    ///
+    /// Returns the menu event if it was not for an item of the tray menu
+    #[cfg(not(any(target_os = "ios", target_os = "android")))]
+    pub fn handle_tray_events(
+        &mut self,
+        menu_event: Option<muda::MenuEvent>,
+    ) -> Option<muda::MenuEvent> {
+        use tray_icon::TrayIconEvent;
+
+        let icon_event = TrayIconEvent::receiver().try_recv().ok();
+
+        let (handlers, toggle_window, menu_event) = {
+            let tray = self.shared.tray.borrow();
+            let Some(tray) = &*tray else {
+                return menu_event;
+            };
+            let mut handlers = Vec::new();
+            let mut toggle_window = false;
+            let menu_event = match menu_event
+                .as_ref()
+                .and_then(|event| tray.menu_handler(event))
+            {
+                Some(handler) => {
+                    handlers.push(handler);
+                    None
+                }
+                None => menu_event,
+            };
+            if let Some(event) = &icon_event {
+                let (handler, toggle) = tray.icon_handler(event);
+                handlers.extend(handler);
+                toggle_window = toggle;
+            }
+            (handlers, toggle_window, menu_event)
+        };
+
+        let main_window = self.main_window.and_then(|id| self.webviews.get(&id));
+
+        // The tray is not borrowed while the handlers run so they can change it
+        let run_handlers = || {
+            for handler in handlers {
+                (handler.borrow_mut())();
+            }
+        };
+
+        // Run the handlers in the main window so they can use its context
+        match main_window {
+            Some(webview) => webview
+                .dom
+                .in_runtime(|| ScopeId::ROOT.in_runtime(run_handlers)),
+            None => run_handlers(),
+        }
+
+        if let (true, Some(webview)) = (toggle_window, main_window) {
+            let window = &webview.desktop_context.window;
+            let visible = !window.is_visible();
+            window.set_visible(visible);
+            if visible {
+                window.set_focus();
+            }
+        }
+
+        menu_event
+    }
+
+    /// Call the menu event handlers of the focused window for a click on an item of the menu bar
+    #[cfg(not(any(target_os = "ios", target_os = "android")))]
+    pub fn handle_menu_event(&mut self, event: muda::MenuEvent) {
+        let focused = self
+            .webviews
+            .iter()
+            .find(|(_, webview)| webview.desktop_context.window.is_focused())
+            .map(|(id, _)| *id);
+
+        // The handlers are taken out of the registry while they run so they can add and remove handlers
+        let mut handlers = self.shared.menu_handlers.take_for(focused);
+        for (_, handler) in &mut handlers {
+            handler(event.id.as_ref());
+        }
+        self.shared.menu_handlers.restore(handlers);
+    }
+
+    /// Send a URL the app was opened with to the main window and bring it to the front
+    #[cfg(not(any(target_os = "ios", target_os = "android")))]
+    pub fn handle_deep_link(&mut self, url: String) {
+        let main_window = self.main_window.and_then(|id| self.webviews.get(&id));
+        let (Some(context), Some(webview)) = (self.deep_link, main_window) else {
+            // The main window is not open yet, so it gets the URL in its first render
+            self.deep_links.initial = Some(url);
+            return;
+        };
+        webview.dom.in_runtime(|| context.open(url));
+        self.focus_main_window();
+    }
+
+    /// Bring the main window to the front when another instance of the app is started, and open the deep links it was started with
+    #[cfg(not(any(target_os = "ios", target_os = "android")))]
+    pub fn handle_second_instance(&mut self, args: Vec<String>) {
+        if let Some(scheme) = self.deep_link_scheme.clone() {
+            for url in crate::deep_link::links(args, &scheme) {
+                self.handle_deep_link(url);
+            }
+        }
+        self.focus_main_window();
+    }
+
+    #[cfg(not(any(target_os = "ios", target_os = "android")))]
+    fn focus_main_window(&self) {
+        let Some(webview) = self.main_window.and_then(|id| self.webviews.get(&id)) else {
+            return;
+        };
+        let window = &webview.desktop_context.window;
+        window.set_visible(true);
+        window.set_minimized(false);
+        window.set_focus();
+    }
+
+    /// Call the click handler of a notification if it was clicked and the window that sent it is still open
+    #[cfg(not(any(target_os = "ios", target_os = "android")))]
+    pub fn handle_notification_closed(&mut self, id: usize, clicked: bool, window: WindowId) {
+        let handler = self.shared.notifications.closed(id, clicked);
+        if let (Some(handler), Some(webview)) = (handler, self.webviews.get(&window)) {
+            webview.dom.in_runtime(handler);
+        }
     }
 
     #[cfg(all(feature = "hot-reload", debug_assertions))]
@@ -164,14 +359,51 @@ impl App {
 
     pub fn handle_start_cause_init(&mut self) {
         let virtual_dom = self.unmounted_dom.take().unwrap();
-        let cfg = self.cfg.take().unwrap();
+        #[allow(unused_mut)]
+        let mut cfg = self.cfg.take().unwrap();
+        #[cfg(not(any(target_os = "ios", target_os = "android")))]
+        let system_tray = cfg.system_tray.take();
 
         self.is_visible_before_start = cfg.window.window.visible;
 
         let webview = WebviewInstance::new(cfg, virtual_dom, self.shared.clone());
 
         let id = webview.desktop_context.window.id();
+
+        // The URL the app was started with is available in the first render
+        #[cfg(not(any(target_os = "ios", target_os = "android")))]
+        {
+            let initial = self.deep_links.initial.take();
+            self.deep_link = Some(
+                webview
+                    .dom
+                    .in_runtime(|| dioxus_hooks::DeepLinkContext::provide(initial)),
+            );
+            self.single_instance
+                .forward_to(self.shared.proxy.clone(), id);
+        }
+
+        let desktop_context = webview.desktop_context.clone();
+        let (lifecycle, back_handlers) = webview.dom.in_runtime(|| {
+            let lifecycle = dioxus_hooks::AppLifecycleContext::provide();
+            // The page only intercepts the back button while a handler is enabled
+            let back_handlers = dioxus_hooks::BackHandlerContext::provide(move |enabled| {
+                let script = crate::back_button::set_enabled_script(enabled);
+                _ = desktop_context.webview.evaluate_script(&script);
+            });
+            (lifecycle, back_handlers)
+        });
+        self.lifecycle = Some(lifecycle);
+        self.back_handlers = Some(back_handlers);
+
         self.webviews.insert(id, webview);
+        self.main_window = Some(id);
+
+        // The tray icon can only be created once the event loop has started
+        #[cfg(not(any(target_os = "ios", target_os = "android")))]
+        if let Some(tray) = system_tray {
+            *self.shared.tray.borrow_mut() = tray.build();
+        }
 
         _ = self
             .shared
@@ -190,11 +422,32 @@ impl App {
         }
     }
 
+    /// Send a lifecycle event of the app to the main window
+    pub fn handle_lifecycle(&mut self, event: dioxus_hooks::AppLifecycle) {
+        let main_window = self.main_window.and_then(|id| self.webviews.get(&id));
+        let (Some(context), Some(webview)) = (&self.lifecycle, main_window) else {
+            return;
+        };
+        webview.dom.in_runtime(|| context.dispatch(event));
+    }
+
+    /// The back button was pressed while the app had a back handler enabled
+    pub fn handle_back_msg(&mut self, id: WindowId) {
+        if self.main_window != Some(id) {
+            return;
+        }
+        let (Some(context), Some(webview)) = (&self.back_handlers, self.webviews.get(&id)) else {
+            return;
+        };
+        webview.dom.in_runtime(|| context.back());
+    }
+
     pub fn handle_initialize_msg(&mut self, id: WindowId) {
         let view = self.webviews.get_mut(&id).unwrap();
         view.dom
             .rebuild(&mut *view.desktop_context.mutation_state.borrow_mut());
         view.desktop_context.send_edits();
+        view.update_devtools();
         view.desktop_context
             .window
             .set_visible(self.is_visible_before_start);
@@ -242,6 +495,31 @@ impl App {
                 let element = DesktopElement::new(element, view.desktop_context.clone(), query);
                 Rc::new(PlatformEventData::new(Box::new(element)))
             }
+            // attach the paths of files dragged from the filesystem
+            dioxus_html::EventData::Drag(mouse) => {
+                let file_hover = &view.desktop_context.file_hover;
+                let paths = file_hover.current();
+                if name == "drop" {
+                    file_hover.clear();
+                }
+                match paths {
+                    Some(paths) => {
+                        Rc::new(PlatformEventData::new(Box::new(DesktopFileDragEvent {
+                            mouse,
+                            files: Arc::new(NativeFileEngine::new(paths)),
+                        })))
+                    }
+                    None => dioxus_html::EventData::Drag(mouse).into_any(),
+                }
+            }
+            // let pointer events capture the pointer on the element that received them
+            dioxus_html::EventData::Pointer(pointer) => {
+                let element = DesktopElement::new(element, view.desktop_context.clone(), query);
+                Rc::new(PlatformEventData::new(Box::new(DesktopPointerEvent {
+                    pointer,
+                    element,
+                })))
+            }
             _ => data.into_any(),
         };
 
@@ -249,6 +527,8 @@ impl App {
         view.dom
             .render_immediate(&mut *view.desktop_context.mutation_state.borrow_mut());
         view.desktop_context.send_edits();
+        view.wait_for_exits();
+        view.update_devtools();
     }
 
     #[cfg(all(feature = "hot-reload", debug_assertions))]
@@ -260,6 +540,13 @@ impl App {
                     webview.poll_vdom();
                 }
             }
+            dioxus_hot_reload::HotReloadMsg::UpdateAsset(asset) => {
+                // Assets are read from the filesystem for every request, so we only need to bypass the cache of the webview
+                let script = reload_asset_script(&asset);
+                for webview in self.webviews.values() {
+                    _ = webview.desktop_context.webview.evaluate_script(&script);
+                }
+            }
             dioxus_hot_reload::HotReloadMsg::Shutdown => {
                 self.control_flow = ControlFlow::Exit;
             }
@@ -308,6 +595,24 @@ impl App {
         view.dom
             .render_immediate(&mut *view.desktop_context.mutation_state.borrow_mut());
         view.desktop_context.send_edits();
+        view.wait_for_exits();
+        view.update_devtools();
+    }
+
+    /// The page measured new insets or the keyboard opened or closed
+    pub fn handle_safe_area_msg(&mut self, msg: IpcMessage, id: WindowId) {
+        let Ok(message) = serde_json::from_value::<SafeAreaMessage>(msg.params()) else {
+            return;
+        };
+        if let Some(view) = self.webviews.get_mut(&id) {
+            view.update_safe_area(message.into());
+        }
+    }
+
+    pub fn handle_viewport_changed(&mut self, id: WindowId) {
+        if let Some(view) = self.webviews.get_mut(&id) {
+            view.update_viewport();
+        }
     }
 
     /// Poll the virtualdom until it's pending
@@ -324,6 +629,32 @@ impl App {
     }
 }
 
+/// Create a script that reloads every stylesheet and image that uses an asset
+#[cfg(all(feature = "hot-reload", debug_assertions))]
+fn reload_asset_script(asset: &std::path::Path) -> String {
+    let asset = asset
+        .components()
+        .map(|component| component.as_os_str().to_string_lossy())
+        .collect::<Vec<_>>()
+        .join("/");
+    let asset = serde_json::to_string(&asset).unwrap_or_default();
+    format!(
+        r#"
+        (() => {{
+            const asset = {asset};
+            const reloaded = Date.now();
+            for (const element of document.querySelectorAll("link[href], img[src]")) {{
+                const attribute = element.tagName === "LINK" ? "href" : "src";
+                const url = element.getAttribute(attribute).split("?")[0];
+                if (url === asset || url.endsWith("/" + asset)) {{
+                    element.setAttribute(attribute, url + "?dx_hot_reload=" + reloaded);
+                }}
+            }}
+        }})();
+        "#
+    )
+}
+
 /// Different hide implementations per platform
 #[allow(unused)]
 pub fn hide_app_window(window: &wry::WebView) {
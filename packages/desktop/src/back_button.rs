@@ -0,0 +1,47 @@
+//! The back button of the platform, like the hardware back button on Android
+//!
+//! The webview only sees the back button as a navigation in its history: Android goes back in the history of the webview before it closes the app. While a back handler is enabled, the page keeps an extra entry in its history. Going back from that entry is sent to the app as a `back` message and the entry is added again.
+
+/// Keeps the extra history entry while the app handles the back button
+pub(crate) const BACK_BUTTON_JS: &str = r#"
+window.__dioxusBackButton = {
+  enabled: false,
+  // the history entry is being removed, so the next popstate is not the back button
+  removing: false,
+  setEnabled(enabled) {
+    if (enabled === this.enabled) {
+      return;
+    }
+    this.enabled = enabled;
+    if (this.removing) {
+      // the popstate of the removed entry adds it again if the back button is enabled by then
+      return;
+    }
+    if (enabled) {
+      history.pushState({ dioxusBackButton: true }, "");
+    } else {
+      this.removing = true;
+      history.back();
+    }
+  },
+};
+window.addEventListener("popstate", () => {
+  const back = window.__dioxusBackButton;
+  if (back.removing) {
+    back.removing = false;
+    if (back.enabled) {
+      history.pushState({ dioxusBackButton: true }, "");
+    }
+    return;
+  }
+  if (back.enabled) {
+    history.pushState({ dioxusBackButton: true }, "");
+    window.ipc.postMessage(window.interpreter.serializeIpcMessage("back"));
+  }
+});
+"#;
+
+/// The script that tells the page if the app handles the back button
+pub(crate) fn set_enabled_script(enabled: bool) -> String {
+    format!("window.__dioxusBackButton.setEnabled({enabled});")
+}
@@ -0,0 +1,69 @@
+use std::{borrow::Cow, cell::RefCell, rc::Rc};
+
+use dioxus_hooks::{ClipboardError, ClipboardFuture, ClipboardImage, ClipboardProvider};
+
+/// The clipboard of the system, used by `use_clipboard`
+pub(crate) fn provider() -> Rc<dyn ClipboardProvider> {
+    Rc::new(DesktopClipboard::default())
+}
+
+/// The clipboard is opened the first time it is used. On Linux the copied data is only available while it is open, so it is kept open for the lifetime of the window.
+#[derive(Default)]
+struct DesktopClipboard {
+    clipboard: RefCell<Option<arboard::Clipboard>>,
+}
+
+impl DesktopClipboard {
+    fn with<T>(
+        &self,
+        f: impl FnOnce(&mut arboard::Clipboard) -> Result<T, arboard::Error>,
+    ) -> Result<T, ClipboardError> {
+        let mut clipboard = self.clipboard.borrow_mut();
+        if clipboard.is_none() {
+            *clipboard = Some(arboard::Clipboard::new().map_err(convert_error)?);
+        }
+        f(clipboard.as_mut().unwrap()).map_err(convert_error)
+    }
+}
+
+impl ClipboardProvider for DesktopClipboard {
+    fn read_text(&self) -> ClipboardFuture<String> {
+        let result = self.with(|clipboard| clipboard.get_text());
+        Box::pin(async move { result })
+    }
+
+    fn write_text(&self, text: String) -> ClipboardFuture<()> {
+        let result = self.with(|clipboard| clipboard.set_text(text));
+        Box::pin(async move { result })
+    }
+
+    fn read_image(&self) -> ClipboardFuture<ClipboardImage> {
+        let result = self
+            .with(|clipboard| clipboard.get_image())
+            .map(|image| ClipboardImage {
+                width: image.width,
+                height: image.height,
+                rgba: image.bytes.into_owned(),
+            });
+        Box::pin(async move { result })
+    }
+
+    fn write_image(&self, image: ClipboardImage) -> ClipboardFuture<()> {
+        let result = self.with(|clipboard| {
+            clipboard.set_image(arboard::ImageData {
+                width: image.width,
+                height: image.height,
+                bytes: Cow::Owned(image.rgba),
+            })
+        });
+        Box::pin(async move { result })
+    }
+}
+
+fn convert_error(error: arboard::Error) -> ClipboardError {
+    match error {
+        arboard::Error::ContentNotAvailable => ClipboardError::Empty,
+        arboard::Error::ClipboardNotSupported => ClipboardError::Unsupported,
+        error => ClipboardError::Failed(error.to_string()),
+    }
+}
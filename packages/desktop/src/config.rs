@@ -33,6 +33,14 @@ pub struct Config {
     pub(crate) background_color: Option<(u8, u8, u8, u8)>,
     pub(crate) last_window_close_behaviour: WindowCloseBehaviour,
     pub(crate) enable_default_menu_bar: bool,
+    #[cfg(not(any(target_os = "ios", target_os = "android")))]
+    pub(crate) system_tray: Option<crate::SystemTray>,
+    #[cfg(not(any(target_os = "ios", target_os = "android")))]
+    pub(crate) menu_bar: Option<crate::MenuBar>,
+    #[cfg(not(any(target_os = "ios", target_os = "android")))]
+    pub(crate) deep_link_scheme: Option<String>,
+    #[cfg(not(any(target_os = "ios", target_os = "android")))]
+    pub(crate) single_instance: bool,
 }
 
 type DropHandler = Box<dyn Fn(WindowId, FileDropEvent) -> bool>;
@@ -67,9 +75,26 @@ impl Config {
             background_color: None,
             last_window_close_behaviour: WindowCloseBehaviour::LastWindowExitsApp,
             enable_default_menu_bar: true,
+            #[cfg(not(any(target_os = "ios", target_os = "android")))]
+            system_tray: None,
+            #[cfg(not(any(target_os = "ios", target_os = "android")))]
+            menu_bar: None,
+            #[cfg(not(any(target_os = "ios", target_os = "android")))]
+            deep_link_scheme: None,
+            #[cfg(not(any(target_os = "ios", target_os = "android")))]
+            single_instance: false,
         }
     }
 
+    /// Show an icon in the system tray when the app starts
+    ///
+    /// The callbacks of the tray run in the root scope of the main window, so they can use [`crate::window`] to get the main window.
+    #[cfg(not(any(target_os = "ios", target_os = "android")))]
+    pub fn with_system_tray(mut self, tray: crate::SystemTray) -> Self {
+        self.system_tray = Some(tray);
+        self
+    }
+
     /// Set whether the default menu bar should be enabled.
     ///
     /// > Note: `enable` is `true` by default. To disable the default menu bar pass `false`.
@@ -78,6 +103,37 @@ impl Config {
         self
     }
 
+    /// Add menus with items that are handled in Rust to the menu bar
+    ///
+    /// Clicks on the items are sent to the handlers of [`crate::use_menu_event`].
+    #[cfg(not(any(target_os = "ios", target_os = "android")))]
+    pub fn with_menu_bar(mut self, menu_bar: crate::MenuBar) -> Self {
+        self.menu_bar = Some(menu_bar);
+        self
+    }
+
+    /// Open the app with URLs like `myapp://settings` when the scheme is set to `myapp`
+    ///
+    /// The URL is passed to [`dioxus_hooks::use_deep_link`] in the main window. If the app is already running, the URL is sent to the running app instead of starting a new instance.
+    ///
+    /// The scheme is registered for the current user when the app starts on Windows and Linux. On macOS, the scheme must be listed in the `CFBundleURLTypes` of the `Info.plist` of the bundle.
+    #[cfg(not(any(target_os = "ios", target_os = "android")))]
+    pub fn with_deep_link_scheme(mut self, scheme: impl Into<String>) -> Self {
+        self.deep_link_scheme = Some(scheme.into());
+        self
+    }
+
+    /// Keep only one instance of the app running
+    ///
+    /// When the app is started while it is already running, the new process sends its command line arguments to the running app and exits. The running app brings its main window to the front, and passes the arguments that are deep links to [`dioxus_hooks::use_deep_link`].
+    ///
+    /// > Note: Apps with a [deep link scheme](Config::with_deep_link_scheme) always run as a single instance.
+    #[cfg(not(any(target_os = "ios", target_os = "android")))]
+    pub fn with_single_instance(mut self, single_instance: bool) -> Self {
+        self.single_instance = single_instance;
+        self
+    }
+
     /// set the directory from which assets will be searched in release mode
     pub fn with_resource_directory(mut self, path: impl Into<PathBuf>) -> Self {
         self.resource_dir = Some(path.into());
@@ -118,7 +174,9 @@ impl Config {
         self
     }
 
-    /// Set a file drop handler. If this is enabled, html drag events will be disabled.
+    /// Set a file drop handler. Return `true` from the handler to block the default drop behavior, which also disables the html drag events for files.
+    ///
+    /// You don't need a handler to get the paths of dropped files. The `ondragover` and `ondrop` events contain the paths of files dragged from the filesystem in [`dioxus_html::HasFileData::files`].
     pub fn with_file_drop_handler(
         mut self,
         handler: impl Fn(WindowId, FileDropEvent) -> bool + 'static,
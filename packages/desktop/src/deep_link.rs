@@ -0,0 +1,131 @@
+//! Opening the app through a custom URL scheme like `myapp://settings`
+//!
+//! The operating system starts the app with the URL as an argument, or on macOS sends it to the running app as an `Opened` event. If the app is already running, the new instance forwards its arguments to the first instance over a local socket and exits.
+
+/// The URL the app was started with
+#[derive(Default)]
+pub(crate) struct DeepLinks {
+    /// The last URL the app was started with
+    pub(crate) initial: Option<String>,
+}
+
+impl DeepLinks {
+    /// Register the scheme and read the URL the app was started with
+    pub(crate) fn start(scheme: &str) -> Self {
+        let scheme = scheme.to_string();
+        let initial = links(std::env::args().skip(1), &scheme).last();
+
+        // Registering touches the registry or runs xdg-mime, so it doesn't block the first window
+        std::thread::spawn(move || {
+            if let Err(err) = register(&scheme) {
+                tracing::error!("Failed to register the {scheme} URL scheme: {err}");
+            }
+        });
+
+        Self { initial }
+    }
+}
+
+/// The arguments that are URLs with the scheme
+pub(crate) fn links<'a>(
+    args: impl IntoIterator<Item = String> + 'a,
+    scheme: &'a str,
+) -> impl Iterator<Item = String> + 'a {
+    args.into_iter().filter(move |arg| {
+        arg.split_once(':')
+            .is_some_and(|(arg_scheme, _)| arg_scheme.eq_ignore_ascii_case(scheme))
+    })
+}
+
+/// Make the app the handler for the scheme with a desktop entry
+#[cfg(any(
+    target_os = "linux",
+    target_os = "dragonfly",
+    target_os = "freebsd",
+    target_os = "netbsd",
+    target_os = "openbsd"
+))]
+fn register(scheme: &str) -> std::io::Result<()> {
+    let exe = std::env::current_exe()?;
+    let applications = match std::env::var_os("XDG_DATA_HOME") {
+        Some(data) => std::path::PathBuf::from(data),
+        None => std::path::PathBuf::from(std::env::var_os("HOME").unwrap_or_default())
+            .join(".local/share"),
+    }
+    .join("applications");
+    std::fs::create_dir_all(&applications)?;
+
+    let file_name = format!("{scheme}-handler.desktop");
+    let name = exe
+        .file_stem()
+        .map(|name| name.to_string_lossy())
+        .unwrap_or_default();
+    std::fs::write(
+        applications.join(&file_name),
+        format!(
+            "[Desktop Entry]\nType=Application\nName={name}\nExec=\"{}\" %u\nTerminal=false\nNoDisplay=true\nMimeType=x-scheme-handler/{scheme};\n",
+            exe.display()
+        ),
+    )?;
+
+    let status = std::process::Command::new("xdg-mime")
+        .args(["default", &file_name, &format!("x-scheme-handler/{scheme}")])
+        .status()?;
+    if !status.success() {
+        return Err(std::io::Error::other(format!(
+            "xdg-mime exited with {status}"
+        )));
+    }
+    Ok(())
+}
+
+/// Make the app the handler for the scheme in the registry of the current user
+#[cfg(target_os = "windows")]
+fn register(scheme: &str) -> std::io::Result<()> {
+    use windows::{
+        core::{HSTRING, PCWSTR},
+        Win32::System::Registry::{RegSetKeyValueW, HKEY_CURRENT_USER, REG_SZ},
+    };
+
+    let exe = std::env::current_exe()?;
+    let key = format!("Software\\Classes\\{scheme}");
+    let values = [
+        (key.clone(), None, format!("URL:{scheme}")),
+        (key.clone(), Some("URL Protocol"), String::new()),
+        (
+            format!("{key}\\shell\\open\\command"),
+            None,
+            format!("\"{}\" \"%1\"", exe.display()),
+        ),
+    ];
+    for (key, name, value) in values {
+        let data = value.encode_utf16().chain([0]).collect::<Vec<u16>>();
+        let name = name.map(HSTRING::from);
+        unsafe {
+            RegSetKeyValueW(
+                HKEY_CURRENT_USER,
+                &HSTRING::from(key),
+                name.as_ref()
+                    .map_or(PCWSTR::null(), |name| PCWSTR(name.as_ptr())),
+                REG_SZ.0,
+                Some(data.as_ptr().cast()),
+                (data.len() * std::mem::size_of::<u16>()) as u32,
+            )
+        }
+        .map_err(std::io::Error::other)?;
+    }
+    Ok(())
+}
+
+/// Apps are registered as the handler of a scheme in the `CFBundleURLTypes` of their `Info.plist` when they are bundled
+#[cfg(not(any(
+    target_os = "linux",
+    target_os = "dragonfly",
+    target_os = "freebsd",
+    target_os = "netbsd",
+    target_os = "openbsd",
+    target_os = "windows"
+)))]
+fn register(_scheme: &str) -> std::io::Result<()> {
+    Ok(())
+}
@@ -2,6 +2,7 @@ use crate::{
     app::SharedContext,
     assets::AssetHandlerRegistry,
     edits::EditQueue,
+    file_upload::NativeFileHover,
     ipc::{EventData, UserWindowEvent},
     query::QueryEngine,
     shortcut::{HotKey, ShortcutHandle, ShortcutRegistryError},
@@ -9,7 +10,7 @@ use crate::{
     AssetRequest, Config, WryEventHandler,
 };
 use dioxus_core::{
-    prelude::{current_scope_id, ScopeId},
+    prelude::{current_scope_id, Runtime, RuntimeGuard, ScopeId},
     VirtualDom,
 };
 use dioxus_interpreter_js::MutationState;
@@ -62,11 +63,102 @@ pub struct DesktopService {
     pub(crate) edit_queue: EditQueue,
     pub(crate) mutation_state: RefCell<MutationState>,
     pub(crate) asset_handlers: AssetHandlerRegistry,
+    pub(crate) file_hover: NativeFileHover,
+
+    /// Callbacks that run once the window is closed
+    pub(crate) close_handlers: RefCell<Vec<Box<dyn FnOnce()>>>,
+
+    /// The items of the menu bar of this window
+    #[cfg(not(any(target_os = "ios", target_os = "android")))]
+    pub(crate) menu_items: RefCell<crate::menubar::MenuItems>,
 
     #[cfg(target_os = "ios")]
     pub(crate) views: Rc<RefCell<Vec<*mut objc::runtime::Object>>>,
 }
 
+/// A handle to a window created with [`DesktopService::new_window`].
+///
+/// The handle does not keep the window open. Once the window is closed, the methods on the handle do nothing.
+#[derive(Clone)]
+pub struct WindowHandle {
+    id: WindowId,
+    service: Weak<DesktopService>,
+}
+
+impl WindowHandle {
+    /// The id of the window
+    pub fn id(&self) -> WindowId {
+        self.id
+    }
+
+    /// Get the [`DesktopContext`] of the window, or None if the window was closed
+    pub fn context(&self) -> Option<DesktopContext> {
+        self.service.upgrade()
+    }
+
+    /// Returns true if the window is still open
+    pub fn is_open(&self) -> bool {
+        self.service.strong_count() > 0
+    }
+
+    /// Close the window
+    pub fn close(&self) {
+        if let Some(service) = self.context() {
+            service.close();
+        }
+    }
+
+    /// Bring the window to the front and focus it
+    pub fn focus(&self) {
+        if let Some(service) = self.context() {
+            service.window.set_focus();
+        }
+    }
+
+    /// Run a callback once the window is closed. If the window is already closed, the callback runs immediately.
+    ///
+    /// The callback runs in the scope it was registered in, so the parent window can update its own signals and state.
+    pub fn on_close(&self, callback: impl FnOnce() + 'static) {
+        match self.context() {
+            Some(service) => service.on_close(callback),
+            None => callback(),
+        }
+    }
+}
+
+/// Wrap a callback so it runs in the scope and runtime it was created in, even if it is called from another window
+pub(crate) fn in_current_scope(callback: impl FnOnce() + 'static) -> Box<dyn FnOnce()> {
+    let scope = CallbackScope::current();
+    Box::new(move || scope.run(callback))
+}
+
+/// The runtime and scope a callback was registered in
+pub(crate) struct CallbackScope {
+    runtime: Option<Rc<Runtime>>,
+    scope: Option<ScopeId>,
+}
+
+impl CallbackScope {
+    /// Capture the current runtime and scope
+    pub(crate) fn current() -> Self {
+        Self {
+            runtime: Runtime::current(),
+            scope: current_scope_id(),
+        }
+    }
+
+    /// Run a callback in the captured runtime and scope, or as is if there was no scope
+    pub(crate) fn run<O>(&self, callback: impl FnOnce() -> O) -> O {
+        match (&self.runtime, self.scope) {
+            (Some(runtime), Some(scope)) => {
+                let _guard = RuntimeGuard::new(runtime.clone());
+                runtime.on_scope(scope, callback)
+            }
+            _ => callback(),
+        }
+    }
+}
+
 /// A smart pointer to the current window.
 impl std::ops::Deref for DesktopService {
     type Target = Window;
@@ -83,6 +175,7 @@ impl DesktopService {
         shared: Rc<SharedContext>,
         edit_queue: EditQueue,
         asset_handlers: AssetHandlerRegistry,
+        file_hover: NativeFileHover,
     ) -> Self {
         Self {
             window,
@@ -91,13 +184,18 @@ impl DesktopService {
             edit_queue,
             mutation_state: Default::default(),
             asset_handlers,
+            file_hover,
             query: Default::default(),
+            close_handlers: Default::default(),
+            #[cfg(not(any(target_os = "ios", target_os = "android")))]
+            menu_items: Default::default(),
             #[cfg(target_os = "ios")]
             views: Default::default(),
         }
     }
 
     /// Send a list of mutations to the webview
+    #[tracing::instrument(skip(self), level = "trace", name = "DesktopService::send_edits")]
     pub(crate) fn send_edits(&self) {
         let mut mutations = self.mutation_state.borrow_mut();
         let serialized_edits = mutations.export_memory();
@@ -106,12 +204,12 @@ impl DesktopService {
 
     /// Create a new window using the props and window builder
     ///
-    /// Returns the webview handle for the new window.
+    /// The new window has its own webview and VirtualDom, but shares the event loop with the current window.
     ///
-    /// You can use this to control other windows from the current window.
+    /// Returns a [`WindowHandle`] that you can use to close or focus the new window from the current window, or to run a callback when it closes.
     ///
     /// Be careful to not create a cycle of windows, or you might leak memory.
-    pub fn new_window(&self, dom: VirtualDom, cfg: Config) -> Weak<DesktopService> {
+    pub fn new_window(&self, dom: VirtualDom, cfg: Config) -> WindowHandle {
         let window = WebviewInstance::new(cfg, dom, self.shared.clone());
 
         let cx = window.dom.in_runtime(|| {
@@ -132,7 +230,10 @@ impl DesktopService {
 
         self.shared.pending_webviews.borrow_mut().push(window);
 
-        Rc::downgrade(&cx)
+        WindowHandle {
+            id: cx.id(),
+            service: Rc::downgrade(&cx),
+        }
     }
 
     /// trigger the drag-window event
@@ -162,6 +263,15 @@ impl DesktopService {
             .send_event(UserWindowEvent(EventData::CloseWindow, self.id()));
     }
 
+    /// Run a callback once this window is closed
+    ///
+    /// The callback runs in the scope it was registered in, so it can update signals and state in that scope.
+    pub fn on_close(&self, callback: impl FnOnce() + 'static) {
+        self.close_handlers
+            .borrow_mut()
+            .push(in_current_scope(callback));
+    }
+
     /// Close a particular window, given its ID
     pub fn close_window(&self, id: WindowId) {
         let _ = self
@@ -170,6 +280,92 @@ impl DesktopService {
             .send_event(UserWindowEvent(EventData::CloseWindow, id));
     }
 
+    /// Show an icon in the system tray, replacing the current tray icon if there is one
+    ///
+    /// The callbacks of the tray run in the scope this is called from.
+    #[cfg(not(any(target_os = "ios", target_os = "android")))]
+    pub fn set_system_tray(&self, tray: crate::SystemTray) {
+        // Drop the old icon first so the new one doesn't show up next to it
+        self.shared.tray.borrow_mut().take();
+        let tray = tray.in_current_scope().build();
+        *self.shared.tray.borrow_mut() = tray;
+    }
+
+    /// Remove the icon from the system tray
+    #[cfg(not(any(target_os = "ios", target_os = "android")))]
+    pub fn remove_system_tray(&self) {
+        self.shared.tray.borrow_mut().take();
+    }
+
+    /// Change the image of the system tray icon
+    #[cfg(not(any(target_os = "ios", target_os = "android")))]
+    pub fn set_tray_icon(&self, icon: crate::TrayIconImage) {
+        if let Some(tray) = &*self.shared.tray.borrow() {
+            if let Err(err) = tray.icon.set_icon(Some(icon)) {
+                tracing::error!("Failed to set the tray icon: {err}");
+            }
+        }
+    }
+
+    /// Change the text shown when the mouse is over the system tray icon
+    #[cfg(not(any(target_os = "ios", target_os = "android")))]
+    pub fn set_tray_tooltip(&self, tooltip: &str) {
+        if let Some(tray) = &*self.shared.tray.borrow() {
+            if let Err(err) = tray.icon.set_tooltip(Some(tooltip)) {
+                tracing::error!("Failed to set the tray tooltip: {err}");
+            }
+        }
+    }
+
+    /// Enable or disable the items of the menu bar of this window with the id
+    ///
+    /// Returns false if the menu bar has no item with the id.
+    #[cfg(not(any(target_os = "ios", target_os = "android")))]
+    pub fn set_menu_item_enabled(&self, id: &str, enabled: bool) -> bool {
+        self.menu_items.borrow().set_enabled(id, enabled)
+    }
+
+    /// Check or uncheck the check items of the menu bar of this window with the id
+    ///
+    /// Returns false if the menu bar has no check item with the id.
+    #[cfg(not(any(target_os = "ios", target_os = "android")))]
+    pub fn set_menu_item_checked(&self, id: &str, checked: bool) -> bool {
+        self.menu_items.borrow().set_checked(id, checked)
+    }
+
+    /// Whether the check item of the menu bar of this window with the id is checked, or None if there is no check item with the id
+    #[cfg(not(any(target_os = "ios", target_os = "android")))]
+    pub fn is_menu_item_checked(&self, id: &str) -> Option<bool> {
+        self.menu_items.borrow().is_checked(id)
+    }
+
+    /// Create a handler that is called with the id of the items of the menu bar that are clicked while this window is focused
+    ///
+    /// The handler runs in the scope this is called from. The id this function returns can be used to remove the handler with [`DesktopContext::remove_menu_event_handler`]
+    #[cfg(not(any(target_os = "ios", target_os = "android")))]
+    pub fn create_menu_event_handler(
+        &self,
+        handler: impl FnMut(&str) + 'static,
+    ) -> crate::MenuEventHandler {
+        self.shared.menu_handlers.add(self.window.id(), handler)
+    }
+
+    /// Remove a menu event handler created with [`DesktopContext::create_menu_event_handler`]
+    #[cfg(not(any(target_os = "ios", target_os = "android")))]
+    pub fn remove_menu_event_handler(&self, id: crate::MenuEventHandler) {
+        self.shared.menu_handlers.remove(id)
+    }
+
+    /// Show a notification from the operating system
+    ///
+    /// The click callback of the notification runs in the scope this is called from.
+    #[cfg(not(any(target_os = "ios", target_os = "android")))]
+    pub fn send_notification(&self, notification: crate::NotificationBuilder) {
+        self.shared
+            .notifications
+            .send(notification, self.shared.proxy.clone(), self.id());
+    }
+
     /// change window to fullscreen
     pub fn set_fullscreen(&self, fullscreen: bool) {
         if let Some(handle) = &self.window.current_monitor() {
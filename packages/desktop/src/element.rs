@@ -1,5 +1,15 @@
 use dioxus_core::ElementId;
-use dioxus_html::{geometry::euclid::Rect, MountedResult, RenderedElementBacking};
+use dioxus_html::{
+    geometry::{
+        euclid::Rect, ClientPoint, ElementPoint, PagePoint, PixelsSize, PixelsVector2D, ScreenPoint,
+    },
+    input_data::{MouseButton, MouseButtonSet},
+    point_interaction::{
+        InteractionElementOffset, InteractionLocation, ModifiersInteraction, PointerInteraction,
+    },
+    prelude::Modifiers,
+    HasPointerData, MountedResult, RenderedElementBacking, SerializedPointerData,
+};
 
 use crate::{desktop_context::DesktopContext, query::QueryEngine};
 
@@ -15,6 +25,35 @@ impl DesktopElement {
     pub(crate) fn new(id: ElementId, webview: DesktopContext, query: QueryEngine) -> Self {
         Self { id, webview, query }
     }
+
+    /// Capture or release a pointer on the element
+    fn set_pointer_capture(
+        &self,
+        pointer_id: i32,
+        capture: bool,
+    ) -> std::pin::Pin<Box<dyn futures_util::Future<Output = dioxus_html::MountedResult<()>>>> {
+        let script = format!(
+            "return window.interpreter.setPointerCapture({}, {}, {});",
+            self.id.0, pointer_id, capture
+        );
+
+        let fut = self
+            .query
+            .new_query::<bool>(&script, self.webview.clone())
+            .resolve();
+
+        Box::pin(async move {
+            match fut.await {
+                Ok(true) => Ok(()),
+                Ok(false) => MountedResult::Err(dioxus_html::MountedError::OperationFailed(
+                    Box::new(DesktopQueryError::FailedToQuery),
+                )),
+                Err(err) => {
+                    MountedResult::Err(dioxus_html::MountedError::OperationFailed(Box::new(err)))
+                }
+            }
+        })
+    }
 }
 
 impl RenderedElementBacking for DesktopElement {
@@ -77,6 +116,83 @@ impl RenderedElementBacking for DesktopElement {
         })
     }
 
+    fn get_scroll_offset(
+        &self,
+    ) -> std::pin::Pin<
+        Box<dyn futures_util::Future<Output = dioxus_html::MountedResult<PixelsVector2D>>>,
+    > {
+        let script = format!("return window.interpreter.getScrollOffset({});", self.id.0);
+
+        let fut = self
+            .query
+            .new_query::<Option<PixelsVector2D>>(&script, self.webview.clone())
+            .resolve();
+        Box::pin(async move {
+            match fut.await {
+                Ok(Some(offset)) => Ok(offset),
+                Ok(None) => MountedResult::Err(dioxus_html::MountedError::OperationFailed(
+                    Box::new(DesktopQueryError::FailedToQuery),
+                )),
+                Err(err) => {
+                    MountedResult::Err(dioxus_html::MountedError::OperationFailed(Box::new(err)))
+                }
+            }
+        })
+    }
+
+    fn get_scroll_size(
+        &self,
+    ) -> std::pin::Pin<Box<dyn futures_util::Future<Output = dioxus_html::MountedResult<PixelsSize>>>>
+    {
+        let script = format!("return window.interpreter.getScrollSize({});", self.id.0);
+
+        let fut = self
+            .query
+            .new_query::<Option<PixelsSize>>(&script, self.webview.clone())
+            .resolve();
+        Box::pin(async move {
+            match fut.await {
+                Ok(Some(size)) => Ok(size),
+                Ok(None) => MountedResult::Err(dioxus_html::MountedError::OperationFailed(
+                    Box::new(DesktopQueryError::FailedToQuery),
+                )),
+                Err(err) => {
+                    MountedResult::Err(dioxus_html::MountedError::OperationFailed(Box::new(err)))
+                }
+            }
+        })
+    }
+
+    fn scroll(
+        &self,
+        offset: PixelsVector2D,
+        behavior: dioxus_html::ScrollBehavior,
+    ) -> std::pin::Pin<Box<dyn futures_util::Future<Output = dioxus_html::MountedResult<()>>>> {
+        let script = format!(
+            "return window.interpreter.scroll({}, {}, {}, {});",
+            self.id.0,
+            offset.x,
+            offset.y,
+            serde_json::to_string(&behavior).expect("Failed to serialize ScrollBehavior")
+        );
+
+        let fut = self
+            .query
+            .new_query::<bool>(&script, self.webview.clone())
+            .resolve();
+        Box::pin(async move {
+            match fut.await {
+                Ok(true) => Ok(()),
+                Ok(false) => MountedResult::Err(dioxus_html::MountedError::OperationFailed(
+                    Box::new(DesktopQueryError::FailedToQuery),
+                )),
+                Err(err) => {
+                    MountedResult::Err(dioxus_html::MountedError::OperationFailed(Box::new(err)))
+                }
+            }
+        })
+    }
+
     fn set_focus(
         &self,
         focus: bool,
@@ -105,6 +221,109 @@ impl RenderedElementBacking for DesktopElement {
     }
 }
 
+/// A pointer event from the webview that can capture the pointer on the element that received it
+#[derive(Clone)]
+pub(crate) struct DesktopPointerEvent {
+    pub pointer: SerializedPointerData,
+    pub element: DesktopElement,
+}
+
+impl HasPointerData for DesktopPointerEvent {
+    fn pointer_id(&self) -> i32 {
+        self.pointer.pointer_id()
+    }
+
+    fn width(&self) -> i32 {
+        self.pointer.width()
+    }
+
+    fn height(&self) -> i32 {
+        self.pointer.height()
+    }
+
+    fn pressure(&self) -> f32 {
+        self.pointer.pressure()
+    }
+
+    fn tangential_pressure(&self) -> f32 {
+        self.pointer.tangential_pressure()
+    }
+
+    fn tilt_x(&self) -> i32 {
+        self.pointer.tilt_x()
+    }
+
+    fn tilt_y(&self) -> i32 {
+        self.pointer.tilt_y()
+    }
+
+    fn twist(&self) -> i32 {
+        self.pointer.twist()
+    }
+
+    fn pointer_type(&self) -> String {
+        self.pointer.pointer_type()
+    }
+
+    fn is_primary(&self) -> bool {
+        self.pointer.is_primary()
+    }
+
+    fn set_pointer_capture(
+        &self,
+    ) -> std::pin::Pin<Box<dyn futures_util::Future<Output = dioxus_html::MountedResult<()>>>> {
+        self.element
+            .set_pointer_capture(self.pointer.pointer_id(), true)
+    }
+
+    fn release_pointer_capture(
+        &self,
+    ) -> std::pin::Pin<Box<dyn futures_util::Future<Output = dioxus_html::MountedResult<()>>>> {
+        self.element
+            .set_pointer_capture(self.pointer.pointer_id(), false)
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl InteractionLocation for DesktopPointerEvent {
+    fn client_coordinates(&self) -> ClientPoint {
+        self.pointer.client_coordinates()
+    }
+
+    fn page_coordinates(&self) -> PagePoint {
+        self.pointer.page_coordinates()
+    }
+
+    fn screen_coordinates(&self) -> ScreenPoint {
+        self.pointer.screen_coordinates()
+    }
+}
+
+impl InteractionElementOffset for DesktopPointerEvent {
+    fn element_coordinates(&self) -> ElementPoint {
+        self.pointer.element_coordinates()
+    }
+}
+
+impl ModifiersInteraction for DesktopPointerEvent {
+    fn modifiers(&self) -> Modifiers {
+        self.pointer.modifiers()
+    }
+}
+
+impl PointerInteraction for DesktopPointerEvent {
+    fn held_buttons(&self) -> MouseButtonSet {
+        self.pointer.held_buttons()
+    }
+
+    fn trigger_button(&self) -> Option<MouseButton> {
+        self.pointer.trigger_button()
+    }
+}
+
 #[derive(Debug)]
 enum DesktopQueryError {
     FailedToQuery,
@@ -1,6 +1,9 @@
 //! Convert a serialized event to an event trigger
 
-use crate::element::DesktopElement;
+use crate::{
+    element::{DesktopElement, DesktopPointerEvent},
+    file_upload::DesktopFileDragEvent,
+};
 use dioxus_html::*;
 
 pub(crate) struct SerializedHtmlEventConverter;
@@ -31,6 +34,9 @@ impl HtmlEventConverter for SerializedHtmlEventConverter {
     }
 
     fn convert_drag_data(&self, event: &PlatformEventData) -> DragData {
+        if let Some(event) = event.downcast::<DesktopFileDragEvent>() {
+            return event.clone().into();
+        }
         event
             .downcast::<SerializedDragData>()
             .cloned()
@@ -91,6 +97,9 @@ impl HtmlEventConverter for SerializedHtmlEventConverter {
     }
 
     fn convert_pointer_data(&self, event: &PlatformEventData) -> PointerData {
+        if let Some(event) = event.downcast::<DesktopPointerEvent>() {
+            return event.clone().into();
+        }
         event
             .downcast::<SerializedPointerData>()
             .cloned()
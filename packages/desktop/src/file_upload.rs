@@ -1,7 +1,18 @@
 #![allow(unused)]
 
+use dioxus_html::{
+    geometry::{ClientPoint, Coordinates, ElementPoint, PagePoint, ScreenPoint},
+    input_data::{MouseButton, MouseButtonSet},
+    native_bind::NativeFileEngine,
+    point_interaction::{
+        InteractionElementOffset, InteractionLocation, ModifiersInteraction, PointerInteraction,
+    },
+    prelude::Modifiers,
+    FileEngine, HasDragData, HasFileData, HasMouseData, SerializedDragData,
+};
 use serde::Deserialize;
-use std::{path::PathBuf, str::FromStr};
+use std::{cell::RefCell, path::PathBuf, rc::Rc, str::FromStr, sync::Arc};
+use wry::FileDropEvent;
 
 #[derive(Debug, Deserialize)]
 pub(crate) struct FileDialogRequest {
@@ -124,3 +135,100 @@ impl FromStr for Filters {
         }
     }
 }
+
+/// The files from the filesystem that are being dragged over a window
+///
+/// Wry tells us about native file drops before the webview sends the html drag events, but the html events only
+/// contain the file names. We keep the paths around so they can be attached to the drag events.
+#[derive(Clone, Default)]
+pub(crate) struct NativeFileHover {
+    paths: Rc<RefCell<Option<Vec<PathBuf>>>>,
+}
+
+impl NativeFileHover {
+    /// Track the files from a wry file drop event
+    pub fn set(&self, event: &FileDropEvent) {
+        let paths = match event {
+            FileDropEvent::Hovered { paths, .. } | FileDropEvent::Dropped { paths, .. } => {
+                Some(paths.clone())
+            }
+            _ => None,
+        };
+        *self.paths.borrow_mut() = paths;
+    }
+
+    /// The paths of the files that are being dragged, if there are any
+    pub fn current(&self) -> Option<Vec<PathBuf>> {
+        self.paths.borrow().clone()
+    }
+
+    /// Stop tracking the files after they were dropped
+    pub fn clear(&self) {
+        self.paths.borrow_mut().take();
+    }
+}
+
+/// A drag event from the webview with the paths of the files that are being dragged from the filesystem
+#[derive(Clone)]
+pub(crate) struct DesktopFileDragEvent {
+    pub mouse: SerializedDragData,
+    pub files: Arc<NativeFileEngine>,
+}
+
+impl HasFileData for DesktopFileDragEvent {
+    fn files(&self) -> Option<Arc<dyn FileEngine>> {
+        Some(self.files.clone())
+    }
+}
+
+impl HasDragData for DesktopFileDragEvent {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl HasMouseData for DesktopFileDragEvent {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl InteractionLocation for DesktopFileDragEvent {
+    fn client_coordinates(&self) -> ClientPoint {
+        self.mouse.client_coordinates()
+    }
+
+    fn page_coordinates(&self) -> PagePoint {
+        self.mouse.page_coordinates()
+    }
+
+    fn screen_coordinates(&self) -> ScreenPoint {
+        self.mouse.screen_coordinates()
+    }
+}
+
+impl InteractionElementOffset for DesktopFileDragEvent {
+    fn element_coordinates(&self) -> ElementPoint {
+        self.mouse.element_coordinates()
+    }
+
+    fn coordinates(&self) -> Coordinates {
+        self.mouse.coordinates()
+    }
+}
+
+impl ModifiersInteraction for DesktopFileDragEvent {
+    fn modifiers(&self) -> Modifiers {
+        self.mouse.modifiers()
+    }
+}
+
+impl PointerInteraction for DesktopFileDragEvent {
+    fn held_buttons(&self) -> MouseButtonSet {
+        self.mouse.held_buttons()
+    }
+
+    fn trigger_button(&self) -> Option<MouseButton> {
+        self.mouse.trigger_button()
+    }
+}
@@ -28,6 +28,23 @@ pub fn use_wry_event_handler(
     )
 }
 
+/// Call a handler with the id of the [`crate::MenuItem`]s of the menu bar that are clicked while the window of the component is focused
+///
+/// ```rust, ignore
+/// use_menu_event(move |id| match id {
+///     "open" => open_file(),
+///     "autosave" => autosave.set(window().is_menu_item_checked("autosave") == Some(true)),
+///     _ => {}
+/// });
+/// ```
+#[cfg(not(any(target_os = "ios", target_os = "android")))]
+pub fn use_menu_event(handler: impl FnMut(&str) + 'static) -> crate::MenuEventHandler {
+    use_hook_with_cleanup(
+        move || window().create_menu_event_handler(handler),
+        move |handler| handler.remove(),
+    )
+}
+
 /// Provide a callback to handle asset loading yourself.
 ///
 /// The callback takes a path as requested by the web view, and it should return `Some(response)`
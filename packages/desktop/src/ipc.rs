@@ -23,6 +23,19 @@ pub enum EventData {
 
     /// Close a given window (could be any window!)
     CloseWindow,
+
+    /// Another instance of the app was started with these command line arguments
+    #[cfg(not(any(target_os = "ios", target_os = "android")))]
+    SecondInstance(Vec<String>),
+
+    /// A notification sent from the window was closed
+    #[cfg(not(any(target_os = "ios", target_os = "android")))]
+    NotificationClosed {
+        /// The id of the notification
+        id: usize,
+        /// Whether the notification was closed because the user clicked it
+        clicked: bool,
+    },
 }
 
 /// A message struct that manages the communication between the webview and the eventloop code
@@ -42,6 +55,8 @@ pub enum IpcMethod<'a> {
     Query,
     BrowserOpen,
     Initialize,
+    Back,
+    SafeArea,
     Other(&'a str),
 }
 
@@ -54,6 +69,8 @@ impl IpcMessage {
             "query" => IpcMethod::Query,
             "browser_open" => IpcMethod::BrowserOpen,
             "initialize" => IpcMethod::Initialize,
+            "back" => IpcMethod::Back,
+            "safe_area" => IpcMethod::SafeArea,
             _ => IpcMethod::Other(&self.method),
         }
     }
@@ -24,12 +24,31 @@ pub fn launch_virtual_dom_blocking(virtual_dom: VirtualDom, desktop_config: Conf
             } => match event {
                 WindowEvent::CloseRequested => app.handle_close_requested(window_id),
                 WindowEvent::Destroyed { .. } => app.window_destroyed(window_id),
+                WindowEvent::Resized(_)
+                | WindowEvent::ScaleFactorChanged { .. }
+                | WindowEvent::ThemeChanged(_) => app.handle_viewport_changed(window_id),
                 _ => {}
             },
+            // macOS sends the URLs the app is opened with to the running app
+            #[cfg(not(any(target_os = "ios", target_os = "android")))]
+            Event::Opened { urls } => {
+                for url in urls {
+                    app.handle_deep_link(url.to_string());
+                }
+            }
+            Event::Suspended => app.handle_lifecycle(dioxus_hooks::AppLifecycle::Paused),
+            Event::Resumed => app.handle_lifecycle(dioxus_hooks::AppLifecycle::Resumed),
             Event::UserEvent(UserWindowEvent(event, id)) => match event {
                 EventData::Poll => app.poll_vdom(id),
                 EventData::NewWindow => app.handle_new_window(),
                 EventData::CloseWindow => app.handle_close_msg(id),
+                #[cfg(not(any(target_os = "ios", target_os = "android")))]
+                EventData::SecondInstance(args) => app.handle_second_instance(args),
+                #[cfg(not(any(target_os = "ios", target_os = "android")))]
+                EventData::NotificationClosed {
+                    id: notification,
+                    clicked,
+                } => app.handle_notification_closed(notification, clicked, id),
                 #[cfg(all(feature = "hot-reload", debug_assertions))]
                 EventData::HotReloadEvent(msg) => app.handle_hot_reload_msg(msg),
                 EventData::Ipc(msg) => match msg.method() {
@@ -38,6 +57,8 @@ pub fn launch_virtual_dom_blocking(virtual_dom: VirtualDom, desktop_config: Conf
                     IpcMethod::Query => app.handle_query_msg(msg, id),
                     IpcMethod::BrowserOpen => app.handle_browser_open(msg),
                     IpcMethod::Initialize => app.handle_initialize_msg(id),
+                    IpcMethod::Back => app.handle_back_msg(id),
+                    IpcMethod::SafeArea => app.handle_safe_area_msg(msg, id),
                     IpcMethod::Other(_) => {}
                 },
             },
@@ -5,7 +5,12 @@
 
 mod app;
 mod assets;
+mod back_button;
+#[cfg(not(any(target_os = "ios", target_os = "android")))]
+mod clipboard;
 mod config;
+#[cfg(not(any(target_os = "ios", target_os = "android")))]
+mod deep_link;
 mod desktop_context;
 mod edits;
 mod element;
@@ -16,9 +21,16 @@ mod file_upload;
 mod hooks;
 mod ipc;
 mod menubar;
+#[cfg(not(any(target_os = "ios", target_os = "android")))]
+mod notification;
 mod protocol;
 mod query;
+mod safe_area;
 mod shortcut;
+#[cfg(not(any(target_os = "ios", target_os = "android")))]
+mod single_instance;
+#[cfg(not(any(target_os = "ios", target_os = "android")))]
+mod tray;
 mod waker;
 mod webview;
 
@@ -39,8 +51,16 @@ pub use wry;
 // Public exports
 pub use assets::AssetRequest;
 pub use config::{Config, WindowCloseBehaviour};
-pub use desktop_context::{window, DesktopContext, DesktopService};
+pub use desktop_context::{window, DesktopContext, DesktopService, WindowHandle};
 pub use event_handlers::WryEventHandler;
+#[cfg(not(any(target_os = "ios", target_os = "android")))]
+pub use hooks::use_menu_event;
 pub use hooks::{use_asset_handler, use_global_shortcut, use_window, use_wry_event_handler};
+#[cfg(not(any(target_os = "ios", target_os = "android")))]
+pub use menubar::{MenuBar, MenuEventHandler, MenuItem, Submenu};
+#[cfg(not(any(target_os = "ios", target_os = "android")))]
+pub use notification::NotificationBuilder;
 pub use shortcut::{ShortcutHandle, ShortcutRegistryError};
+#[cfg(not(any(target_os = "ios", target_os = "android")))]
+pub use tray::{SystemTray, TrayIconImage};
 pub use wry::RequestAsyncResponder;
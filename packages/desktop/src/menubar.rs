@@ -1,21 +1,334 @@
-use std::any::Any;
-
-use tao::window::Window;
-
-#[allow(unused)]
-pub fn build_menu(window: &Window, default_menu_bar: bool) -> Option<Box<dyn Any>> {
-    #[cfg(not(any(target_os = "ios", target_os = "android")))]
-    {
-        return Some(Box::new(impl_::build_menu_bar(default_menu_bar, window)) as Box<dyn Any>);
-    }
+#[cfg(not(any(target_os = "ios", target_os = "android")))]
+pub use impl_::{MenuBar, MenuEventHandler, MenuItem, Submenu};
+#[cfg(not(any(target_os = "ios", target_os = "android")))]
+pub(crate) use impl_::{MenuEventHandlers, MenuItems};
 
-    None
+/// Build the menu bar of a window. The items of the menu bar are added to `items` so they can be changed later
+#[cfg(not(any(target_os = "ios", target_os = "android")))]
+pub fn build_menu(
+    window: &tao::window::Window,
+    default_menu_bar: bool,
+    menu_bar: Option<&MenuBar>,
+    items: &mut MenuItems,
+) -> Box<dyn std::any::Any> {
+    Box::new(impl_::build_menu_bar(
+        default_menu_bar,
+        menu_bar,
+        items,
+        window,
+    ))
 }
 
 #[cfg(not(any(target_os = "ios", target_os = "android")))]
 mod impl_ {
-    use super::*;
-    use muda::{Menu, MenuItem, PredefinedMenuItem, Submenu};
+    use crate::{desktop_context::CallbackScope, window};
+    use muda::{accelerator::Accelerator, CheckMenuItem, Menu, PredefinedMenuItem};
+    use slab::Slab;
+    use std::{cell::RefCell, collections::HashMap, str::FromStr};
+    use tao::window::{Window, WindowId};
+
+    /// A menu bar with items that are identified by the ids you give them
+    ///
+    /// Pass this to [`crate::Config::with_menu_bar`]. Listen for clicks on the items with [`crate::use_menu_event`], and change the items while the app is running with [`crate::DesktopService::set_menu_item_enabled`] and [`crate::DesktopService::set_menu_item_checked`].
+    ///
+    /// ```rust, ignore
+    /// use dioxus::desktop::{Config, MenuBar, MenuItem, Submenu};
+    ///
+    /// let menu_bar = MenuBar::new().with_menu(
+    ///     Submenu::new("File")
+    ///         .with_item(MenuItem::new("open", "Open").with_accelerator("CmdOrCtrl+O"))
+    ///         .with_separator()
+    ///         .with_item(MenuItem::check("autosave", "Autosave", true)),
+    /// );
+    ///
+    /// let config = Config::new().with_menu_bar(menu_bar);
+    /// ```
+    ///
+    /// If the default menu bar is enabled, these menus are added between the default "Edit" and "Help" menus.
+    #[derive(Clone, Default)]
+    pub struct MenuBar {
+        menus: Vec<Submenu>,
+    }
+
+    impl MenuBar {
+        /// Create a menu bar without any menus
+        pub fn new() -> Self {
+            Self::default()
+        }
+
+        /// Add a menu to the end of the menu bar
+        pub fn with_menu(mut self, menu: Submenu) -> Self {
+            self.menus.push(menu);
+            self
+        }
+    }
+
+    /// A menu in the [`MenuBar`] or in another menu
+    #[derive(Clone)]
+    pub struct Submenu {
+        title: String,
+        enabled: bool,
+        entries: Vec<MenuEntry>,
+    }
+
+    #[derive(Clone)]
+    enum MenuEntry {
+        Item(MenuItem),
+        Submenu(Submenu),
+        Separator,
+    }
+
+    impl Submenu {
+        /// Create an empty menu
+        pub fn new(title: impl Into<String>) -> Self {
+            Self {
+                title: title.into(),
+                enabled: true,
+                entries: Vec::new(),
+            }
+        }
+
+        /// Set whether the menu can be opened
+        pub fn with_enabled(mut self, enabled: bool) -> Self {
+            self.enabled = enabled;
+            self
+        }
+
+        /// Add an item to the end of the menu
+        pub fn with_item(mut self, item: MenuItem) -> Self {
+            self.entries.push(MenuEntry::Item(item));
+            self
+        }
+
+        /// Add a nested menu to the end of the menu
+        pub fn with_submenu(mut self, submenu: Submenu) -> Self {
+            self.entries.push(MenuEntry::Submenu(submenu));
+            self
+        }
+
+        /// Add a separator to the end of the menu
+        pub fn with_separator(mut self) -> Self {
+            self.entries.push(MenuEntry::Separator);
+            self
+        }
+
+        fn build(&self, items: &mut MenuItems) -> muda::Submenu {
+            let submenu = muda::Submenu::new(&self.title, self.enabled);
+            for entry in &self.entries {
+                let result = match entry {
+                    MenuEntry::Item(item) => submenu.append(&*item.build(items)),
+                    MenuEntry::Submenu(nested) => submenu.append(&nested.build(items)),
+                    MenuEntry::Separator => submenu.append(&PredefinedMenuItem::separator()),
+                };
+                if let Err(err) = result {
+                    tracing::error!("Failed to add an item to the {} menu: {err}", self.title);
+                }
+            }
+            submenu
+        }
+    }
+
+    /// An item in a [`Submenu`]
+    ///
+    /// The id of the item is passed to the handlers of [`crate::use_menu_event`] when the item is clicked.
+    #[derive(Clone)]
+    pub struct MenuItem {
+        id: String,
+        text: String,
+        enabled: bool,
+        checked: Option<bool>,
+        accelerator: Option<String>,
+    }
+
+    impl MenuItem {
+        /// Create an item that can be clicked
+        pub fn new(id: impl Into<String>, text: impl Into<String>) -> Self {
+            Self {
+                id: id.into(),
+                text: text.into(),
+                enabled: true,
+                checked: None,
+                accelerator: None,
+            }
+        }
+
+        /// Create an item with a check mark that is toggled when the item is clicked
+        pub fn check(id: impl Into<String>, text: impl Into<String>, checked: bool) -> Self {
+            Self {
+                checked: Some(checked),
+                ..Self::new(id, text)
+            }
+        }
+
+        /// Set whether the item can be clicked
+        pub fn with_enabled(mut self, enabled: bool) -> Self {
+            self.enabled = enabled;
+            self
+        }
+
+        /// Set the keyboard shortcut that clicks the item while the window is focused, like `"CmdOrCtrl+Shift+S"`
+        pub fn with_accelerator(mut self, accelerator: impl Into<String>) -> Self {
+            self.accelerator = Some(accelerator.into());
+            self
+        }
+
+        fn build(&self, items: &mut MenuItems) -> Box<dyn muda::IsMenuItem> {
+            let accelerator = self.accelerator.as_deref().and_then(|accelerator| {
+                Accelerator::from_str(accelerator)
+                    .map_err(|err| {
+                        tracing::error!(
+                            "Invalid accelerator {accelerator:?} for {}: {err}",
+                            self.id
+                        )
+                    })
+                    .ok()
+            });
+            match self.checked {
+                Some(checked) => {
+                    let item = CheckMenuItem::with_id(
+                        &self.id,
+                        &self.text,
+                        self.enabled,
+                        checked,
+                        accelerator,
+                    );
+                    items.insert(&self.id, MenuItemHandle::Check(item.clone()));
+                    Box::new(item)
+                }
+                None => {
+                    let item =
+                        muda::MenuItem::with_id(&self.id, &self.text, self.enabled, accelerator);
+                    items.insert(&self.id, MenuItemHandle::Normal(item.clone()));
+                    Box::new(item)
+                }
+            }
+        }
+    }
+
+    /// The items of the menu bar of a window, by their id
+    #[derive(Default)]
+    pub(crate) struct MenuItems {
+        items: HashMap<String, Vec<MenuItemHandle>>,
+    }
+
+    enum MenuItemHandle {
+        Normal(muda::MenuItem),
+        Check(CheckMenuItem),
+    }
+
+    impl MenuItems {
+        fn insert(&mut self, id: &str, item: MenuItemHandle) {
+            self.items.entry(id.to_string()).or_default().push(item);
+        }
+
+        /// Enable or disable every item with the id. Returns false if there is no item with the id
+        pub(crate) fn set_enabled(&self, id: &str, enabled: bool) -> bool {
+            let items = self.items.get(id).map(Vec::as_slice).unwrap_or_default();
+            for item in items {
+                match item {
+                    MenuItemHandle::Normal(item) => item.set_enabled(enabled),
+                    MenuItemHandle::Check(item) => item.set_enabled(enabled),
+                }
+            }
+            !items.is_empty()
+        }
+
+        /// Check or uncheck every check item with the id. Returns false if there is no check item with the id
+        pub(crate) fn set_checked(&self, id: &str, checked: bool) -> bool {
+            let mut found = false;
+            for item in self.items.get(id).into_iter().flatten() {
+                if let MenuItemHandle::Check(item) = item {
+                    item.set_checked(checked);
+                    found = true;
+                }
+            }
+            found
+        }
+
+        /// Whether the check item with the id is checked, or None if there is no check item with the id
+        pub(crate) fn is_checked(&self, id: &str) -> Option<bool> {
+            self.items
+                .get(id)
+                .into_iter()
+                .flatten()
+                .find_map(|item| match item {
+                    MenuItemHandle::Check(item) => Some(item.is_checked()),
+                    MenuItemHandle::Normal(_) => None,
+                })
+        }
+    }
+
+    /// The unique identifier of a menu event handler. This can be used to later remove the handler.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub struct MenuEventHandler(pub(crate) usize);
+
+    impl MenuEventHandler {
+        /// Unregister this event handler
+        pub fn remove(&self) {
+            window().shared.menu_handlers.remove(*self)
+        }
+    }
+
+    /// The handlers for clicks on the items of the menu bars of every window
+    #[derive(Default)]
+    pub(crate) struct MenuEventHandlers {
+        handlers: RefCell<Slab<MenuEventHandlerInner>>,
+    }
+
+    type MenuCallback = Box<dyn FnMut(&str)>;
+
+    struct MenuEventHandlerInner {
+        window_id: WindowId,
+        handler: MenuCallback,
+    }
+
+    impl MenuEventHandlers {
+        /// Add a handler that runs in the current scope
+        pub(crate) fn add(
+            &self,
+            window_id: WindowId,
+            mut handler: impl FnMut(&str) + 'static,
+        ) -> MenuEventHandler {
+            let scope = CallbackScope::current();
+            MenuEventHandler(self.handlers.borrow_mut().insert(MenuEventHandlerInner {
+                window_id,
+                handler: Box::new(move |id| scope.run(|| handler(id))),
+            }))
+        }
+
+        pub(crate) fn remove(&self, id: MenuEventHandler) {
+            self.handlers.borrow_mut().try_remove(id.0);
+        }
+
+        /// Take the handlers of the focused window out so they can run without borrowing the registry. If no window is focused, every handler is taken
+        pub(crate) fn take_for(&self, focused: Option<WindowId>) -> Vec<(usize, MenuCallback)> {
+            let mut handlers = self.handlers.borrow_mut();
+            let keys = handlers
+                .iter()
+                .filter(|(_, handler)| focused.is_none() || focused == Some(handler.window_id))
+                .map(|(key, _)| key)
+                .collect::<Vec<_>>();
+            keys.into_iter()
+                .map(|key| {
+                    let handler = std::mem::replace(
+                        &mut handlers[key].handler,
+                        Box::new(|_: &str| {}) as MenuCallback,
+                    );
+                    (key, handler)
+                })
+                .collect()
+        }
+
+        /// Put back handlers that were taken with [`MenuEventHandlers::take_for`], unless they were removed while they ran
+        pub(crate) fn restore(&self, taken: Vec<(usize, MenuCallback)>) {
+            let mut handlers = self.handlers.borrow_mut();
+            for (key, handler) in taken {
+                if let Some(inner) = handlers.get_mut(key) {
+                    inner.handler = handler;
+                }
+            }
+        }
+    }
 
     /// Builds a standard menu bar depending on the users platform. It may be used as a starting point
     /// to further customize the menu bar and pass it to a [`WindowBuilder`](tao::window::WindowBuilder).
@@ -23,7 +336,12 @@ mod impl_ {
     /// > The menu bar differs per platform because of constraints introduced
     /// > by [`MenuItem`](tao::menu::MenuItem).
     #[allow(unused)]
-    pub fn build_menu_bar(default: bool, window: &Window) -> Menu {
+    pub fn build_menu_bar(
+        default: bool,
+        menu_bar: Option<&MenuBar>,
+        items: &mut MenuItems,
+        window: &Window,
+    ) -> Menu {
         let menu = Menu::new();
 
         #[cfg(target_os = "windows")]
@@ -45,10 +363,19 @@ mod impl_ {
             menu.init_for_nsapp();
         }
 
+        let custom_menus = menu_bar
+            .map(|menu_bar| menu_bar.menus.as_slice())
+            .unwrap_or_default();
+        let append_custom_menus = |items: &mut MenuItems| {
+            for custom in custom_menus {
+                menu.append(&custom.build(items)).unwrap();
+            }
+        };
+
         if default {
             // since it is uncommon on windows to have an "application menu"
             // we add a "window" menu to be more consistent across platforms with the standard menu
-            let window_menu = Submenu::new("Window", true);
+            let window_menu = muda::Submenu::new("Window", true);
             window_menu
                 .append_items(&[
                     &PredefinedMenuItem::fullscreen(None),
@@ -64,7 +391,7 @@ mod impl_ {
                 ])
                 .unwrap();
 
-            let edit_menu = Submenu::new("Edit", true);
+            let edit_menu = muda::Submenu::new("Edit", true);
             edit_menu
                 .append_items(&[
                     &PredefinedMenuItem::undo(None),
@@ -78,19 +405,22 @@ mod impl_ {
                 ])
                 .unwrap();
 
-            let help_menu = Submenu::new("Help", true);
+            let help_menu = muda::Submenu::new("Help", true);
             help_menu
-                .append_items(&[&MenuItem::new("Toggle Developer Tools", true, None)])
+                .append_items(&[&muda::MenuItem::new("Toggle Developer Tools", true, None)])
                 .unwrap();
 
-            menu.append_items(&[&window_menu, &edit_menu, &help_menu])
-                .unwrap();
+            menu.append_items(&[&window_menu, &edit_menu]).unwrap();
+            append_custom_menus(items);
+            menu.append(&help_menu).unwrap();
 
             #[cfg(target_os = "macos")]
             {
                 window_menu.set_as_windows_menu_for_nsapp();
                 help_menu.set_as_help_menu_for_nsapp();
             }
+        } else {
+            append_custom_menus(items);
         }
 
         menu
@@ -0,0 +1,346 @@
+//! Notifications shown by the operating system
+
+use std::{
+    cell::{Cell, RefCell},
+    collections::HashMap,
+    path::PathBuf,
+};
+
+use tao::{event_loop::EventLoopProxy, window::WindowId};
+
+use crate::{
+    desktop_context::in_current_scope,
+    ipc::{EventData, UserWindowEvent},
+};
+
+/// A builder for a notification shown by the operating system
+///
+/// Show the notification with [`crate::DesktopService::send_notification`].
+///
+/// ```rust, ignore
+/// use dioxus::desktop::{use_window, NotificationBuilder};
+///
+/// let window = use_window();
+/// let mut unread = use_signal(|| 1);
+///
+/// window.send_notification(
+///     NotificationBuilder::new("New message")
+///         .with_body("You have 1 unread message")
+///         .on_click(move || unread.set(0)),
+/// );
+/// ```
+///
+/// ## Platform-specific:
+///
+/// - **macOS**: Click callbacks are not called. Notifications are only shown if the app runs from a bundle.
+/// - **Windows**: Notifications are shown under the name of PowerShell unless the app is installed with its own app id.
+/// - **Linux**: The notification server must support the `actions` capability for click callbacks to be called.
+pub struct NotificationBuilder {
+    title: String,
+    body: String,
+    icon: Option<PathBuf>,
+    on_click: Option<Box<dyn FnOnce()>>,
+}
+
+impl NotificationBuilder {
+    /// Create a notification with a title
+    pub fn new(title: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            body: String::new(),
+            icon: None,
+            on_click: None,
+        }
+    }
+
+    /// Set the text shown below the title
+    pub fn with_body(mut self, body: impl Into<String>) -> Self {
+        self.body = body.into();
+        self
+    }
+
+    /// Set the path of the image shown next to the notification
+    pub fn with_icon(mut self, icon: impl Into<PathBuf>) -> Self {
+        self.icon = Some(icon.into());
+        self
+    }
+
+    /// Call `callback` when the notification is clicked
+    ///
+    /// The callback runs in the scope the notification was sent from, if the window that sent it is still open.
+    pub fn on_click(mut self, callback: impl FnOnce() + 'static) -> Self {
+        self.on_click = Some(in_current_scope(callback));
+        self
+    }
+}
+
+/// The click callbacks of the notifications that are still shown
+#[derive(Default)]
+pub(crate) struct NotificationHandlers {
+    next_id: Cell<usize>,
+    handlers: RefCell<HashMap<usize, Box<dyn FnOnce()>>>,
+}
+
+impl NotificationHandlers {
+    /// Show a notification. Once it is closed, an [`EventData::NotificationClosed`] event is sent to `window`
+    pub(crate) fn send(
+        &self,
+        notification: NotificationBuilder,
+        proxy: EventLoopProxy<UserWindowEvent>,
+        window: WindowId,
+    ) {
+        let NotificationBuilder {
+            title,
+            body,
+            icon,
+            on_click,
+        } = notification;
+
+        let id = self.next_id.get();
+        self.next_id.set(id + 1);
+        if let Some(on_click) = on_click {
+            self.handlers.borrow_mut().insert(id, on_click);
+        }
+
+        let closed = move |clicked| {
+            _ = proxy.send_event(UserWindowEvent(
+                EventData::NotificationClosed { id, clicked },
+                window,
+            ));
+        };
+        let content = NotificationContent { title, body, icon };
+        if let Err(err) = platform::show(content, closed) {
+            tracing::error!("Failed to show the notification: {err}");
+            self.handlers.borrow_mut().remove(&id);
+        }
+    }
+
+    /// Remove the click callback of a notification that was closed. Returns the callback if the notification was clicked
+    pub(crate) fn closed(&self, id: usize, clicked: bool) -> Option<Box<dyn FnOnce()>> {
+        let handler = self.handlers.borrow_mut().remove(&id);
+        handler.filter(|_| clicked)
+    }
+}
+
+/// The parts of a notification that are sent to the operating system
+struct NotificationContent {
+    title: String,
+    body: String,
+    icon: Option<PathBuf>,
+}
+
+/// Notifications through the freedesktop notification service over dbus
+#[cfg(any(
+    target_os = "linux",
+    target_os = "dragonfly",
+    target_os = "freebsd",
+    target_os = "netbsd",
+    target_os = "openbsd"
+))]
+mod platform {
+    use super::NotificationContent;
+    use std::collections::HashMap;
+    use zbus::{blocking::Proxy, zvariant::Value};
+
+    /// Show a notification, then call `closed` with whether it was clicked once it closes
+    pub(super) fn show(
+        content: NotificationContent,
+        closed: impl FnOnce(bool) + Send + 'static,
+    ) -> std::io::Result<()> {
+        // The dbus calls block until the notification closes, so they run on their own thread
+        std::thread::Builder::new()
+            .name("dioxus-notification".into())
+            .spawn(move || match wait_for_close(content) {
+                Ok(clicked) => closed(clicked),
+                Err(err) => {
+                    tracing::error!("Failed to show the notification: {err}");
+                    closed(false)
+                }
+            })
+            .map(|_| ())
+    }
+
+    fn wait_for_close(content: NotificationContent) -> zbus::Result<bool> {
+        let connection = zbus::blocking::Connection::session()?;
+        let proxy = Proxy::new(
+            &connection,
+            "org.freedesktop.Notifications",
+            "/org/freedesktop/Notifications",
+            "org.freedesktop.Notifications",
+        )?;
+        // Listen before the notification is shown so we don't miss the click
+        let signals = proxy.receive_all_signals()?;
+
+        let icon = content
+            .icon
+            .as_ref()
+            .map(|icon| icon.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        let app_name = std::env::current_exe()
+            .ok()
+            .and_then(|exe| Some(exe.file_stem()?.to_string_lossy().into_owned()))
+            .unwrap_or_default();
+        // The "default" action is invoked when the notification itself is clicked
+        let actions = vec!["default", ""];
+        let hints: HashMap<&str, Value> = HashMap::new();
+        let id: u32 = proxy.call(
+            "Notify",
+            &(
+                app_name,
+                0u32,
+                icon,
+                &content.title,
+                &content.body,
+                actions,
+                hints,
+                -1i32,
+            ),
+        )?;
+
+        for message in signals {
+            let Some(member) = message.member() else {
+                continue;
+            };
+            match member.as_str() {
+                "ActionInvoked" => {
+                    let (notification, action): (u32, String) = message.body()?;
+                    if notification == id {
+                        return Ok(action == "default");
+                    }
+                }
+                "NotificationClosed" => {
+                    let (notification, _reason): (u32, u32) = message.body()?;
+                    if notification == id {
+                        return Ok(false);
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        Ok(false)
+    }
+}
+
+/// Notifications through toasts
+#[cfg(target_os = "windows")]
+mod platform {
+    use super::NotificationContent;
+    use std::sync::{Arc, Mutex};
+    use windows::{
+        core::HSTRING,
+        Data::Xml::Dom::XmlDocument,
+        Foundation::TypedEventHandler,
+        UI::Notifications::{ToastNotification, ToastNotificationManager},
+    };
+
+    /// Apps that are not installed don't have an app id of their own, so notifications are sent as PowerShell
+    const POWERSHELL_APP_ID: &str =
+        "{1AC14E77-02E7-4E5D-B744-2EB1AE5198B7}\\WindowsPowerShell\\v1.0\\powershell.exe";
+
+    /// Show a notification, then call `closed` with whether it was clicked once it closes
+    pub(super) fn show(
+        content: NotificationContent,
+        closed: impl FnOnce(bool) + Send + 'static,
+    ) -> windows::core::Result<()> {
+        let image = content
+            .icon
+            .map(|icon| {
+                format!(
+                    r#"<image placement="appLogoOverride" src="file:///{}"/>"#,
+                    escape(&icon.to_string_lossy())
+                )
+            })
+            .unwrap_or_default();
+        let xml = format!(
+            r#"<toast><visual><binding template="ToastGeneric">{image}<text>{}</text><text>{}</text></binding></visual></toast>"#,
+            escape(&content.title),
+            escape(&content.body),
+        );
+        let document = XmlDocument::new()?;
+        document.LoadXml(&HSTRING::from(xml))?;
+        let toast = ToastNotification::CreateToastNotification(&document)?;
+
+        // Either the toast is clicked or it is dismissed, but only the first event calls the callback
+        let closed = Arc::new(Mutex::new(Some(closed)));
+        let on_close = move |clicked| {
+            if let Some(closed) = closed.lock().ok().and_then(|mut closed| closed.take()) {
+                closed(clicked);
+            }
+        };
+        let on_dismiss = on_close.clone();
+        toast.Activated(&TypedEventHandler::new(move |_, _| {
+            on_close(true);
+            Ok(())
+        }))?;
+        toast.Dismissed(&TypedEventHandler::new(move |_, _| {
+            on_dismiss(false);
+            Ok(())
+        }))?;
+
+        ToastNotificationManager::CreateToastNotifierWithId(&HSTRING::from(POWERSHELL_APP_ID))?
+            .Show(&toast)
+    }
+
+    fn escape(text: &str) -> String {
+        text.replace('&', "&amp;")
+            .replace('<', "&lt;")
+            .replace('>', "&gt;")
+            .replace('"', "&quot;")
+    }
+}
+
+/// Notifications through the user notification center
+#[cfg(target_os = "macos")]
+mod platform {
+    use super::NotificationContent;
+    use objc::{class, msg_send, runtime::Object, sel, sel_impl};
+
+    /// Show a notification. The notification center only tells its delegate about clicks, so `closed` is never called
+    pub(super) fn show(
+        content: NotificationContent,
+        _closed: impl FnOnce(bool) + Send + 'static,
+    ) -> Result<(), &'static str> {
+        unsafe {
+            let center: *mut Object = msg_send![
+                class!(NSUserNotificationCenter),
+                defaultUserNotificationCenter
+            ];
+            // The center only exists for apps that run from a bundle
+            if center.is_null() {
+                return Err("notifications are only supported for bundled apps");
+            }
+
+            let notification: *mut Object = msg_send![class!(NSUserNotification), new];
+            let _: () = msg_send![notification, setTitle: ns_string(&content.title)];
+            let _: () = msg_send![notification, setInformativeText: ns_string(&content.body)];
+            if let Some(icon) = &content.icon {
+                let image: *mut Object = msg_send![class!(NSImage), alloc];
+                let image: *mut Object = msg_send![
+                    image,
+                    initWithContentsOfFile: ns_string(&icon.to_string_lossy())
+                ];
+                if !image.is_null() {
+                    let _: () = msg_send![notification, setContentImage: image];
+                    let _: () = msg_send![image, release];
+                }
+            }
+            let _: () = msg_send![center, deliverNotification: notification];
+            let _: () = msg_send![notification, release];
+        }
+        Ok(())
+    }
+
+    /// Create an autoreleased NSString
+    unsafe fn ns_string(text: &str) -> *mut Object {
+        const UTF8_ENCODING: usize = 4;
+        let string: *mut Object = msg_send![class!(NSString), alloc];
+        let string: *mut Object = msg_send![
+            string,
+            initWithBytes: text.as_ptr()
+            length: text.len()
+            encoding: UTF8_ENCODING
+        ];
+        msg_send![string, autorelease]
+    }
+}
@@ -1,8 +1,16 @@
 use crate::{assets::*, edits::EditQueue};
 use dioxus_interpreter_js::binary_protocol::SLEDGEHAMMER_JS;
-use std::path::{Path, PathBuf};
+use std::{
+    fs::File,
+    io::{Read, Seek, SeekFrom},
+    path::{Path, PathBuf},
+};
 use wry::{
-    http::{status::StatusCode, Request, Response},
+    http::{
+        header::{ACCEPT_RANGES, CONTENT_LENGTH, CONTENT_RANGE, CONTENT_TYPE, RANGE},
+        status::StatusCode,
+        Request, Response,
+    },
     RequestAsyncResponder, Result,
 };
 
@@ -204,13 +212,13 @@ pub(super) fn desktop_handler(
     }
 
     // Else, try to serve a file from the filesystem.
-    match serve_from_fs(path) {
+    match serve_from_fs(&request, path) {
         Ok(res) => responder.respond(res),
         Err(e) => tracing::error!("Error serving request from filesystem {}", e),
     }
 }
 
-fn serve_from_fs(path: PathBuf) -> Result<Response<Vec<u8>>> {
+fn serve_from_fs(request: &Request<Vec<u8>>, path: PathBuf) -> Result<Response<Vec<u8>>> {
     // If the path is relative, we'll try to serve it from the assets directory.
     let mut asset = get_asset_root_or_default().join(&path);
 
@@ -225,9 +233,67 @@ fn serve_from_fs(path: PathBuf) -> Result<Response<Vec<u8>>> {
             .body(String::from("Not Found").into_bytes())?);
     }
 
+    let mut file = File::open(&asset)?;
+    let len = file.metadata()?.len();
+    let mime = get_mime_from_path(&asset)?;
+
+    // Media elements request the parts of the file they need with a range header, so we only read those parts
+    let Some(range) = request.headers().get(RANGE) else {
+        return Ok(Response::builder()
+            .header(CONTENT_TYPE, mime)
+            .header(ACCEPT_RANGES, "bytes")
+            .body(std::fs::read(asset)?)?);
+    };
+
+    let Some((start, end)) = range
+        .to_str()
+        .ok()
+        .and_then(|range| parse_range(range, len))
+    else {
+        return Ok(Response::builder()
+            .status(StatusCode::RANGE_NOT_SATISFIABLE)
+            .header(CONTENT_RANGE, format!("bytes */{len}"))
+            .body(Vec::new())?);
+    };
+
+    // Serve large ranges in chunks. The webview requests the rest of the range once it needs it
+    let end = end.min(start + MAX_RANGE_CHUNK - 1);
+    let mut body = vec![0; (end - start + 1) as usize];
+    file.seek(SeekFrom::Start(start))?;
+    file.read_exact(&mut body)?;
+
     Ok(Response::builder()
-        .header("Content-Type", get_mime_from_path(&asset)?)
-        .body(std::fs::read(asset)?)?)
+        .status(StatusCode::PARTIAL_CONTENT)
+        .header(CONTENT_TYPE, mime)
+        .header(ACCEPT_RANGES, "bytes")
+        .header(CONTENT_RANGE, format!("bytes {start}-{end}/{len}"))
+        .header(CONTENT_LENGTH, body.len())
+        .body(body)?)
+}
+
+/// The largest part of a file we read for one range request
+const MAX_RANGE_CHUNK: u64 = 1024 * 1024;
+
+/// Parse the first range of a `Range` header into the inclusive start and end of the bytes to serve
+///
+/// Returns `None` if the range is invalid or not satisfiable for a file of `len` bytes
+fn parse_range(header: &str, len: u64) -> Option<(u64, u64)> {
+    let range = header.strip_prefix("bytes=")?.split(',').next()?.trim();
+    let (start, end) = range.split_once('-')?;
+    let (start, end) = match (start.trim(), end.trim()) {
+        // The last n bytes of the file
+        ("", suffix) => {
+            let suffix = suffix.parse::<u64>().ok()?.min(len);
+            (len.checked_sub(suffix)?, len.checked_sub(1)?)
+        }
+        // Everything after the start
+        (start, "") => (start.parse().ok()?, len.checked_sub(1)?),
+        (start, end) => (
+            start.parse().ok()?,
+            end.parse::<u64>().ok()?.min(len.checked_sub(1)?),
+        ),
+    };
+    (start <= end && start < len).then_some((start, end))
 }
 
 /// Construct the inline script that boots up the page and bridges the webview with rust code.
@@ -238,10 +304,14 @@ fn serve_from_fs(path: PathBuf) -> Result<Response<Vec<u8>>> {
 ///             interpreter can't connect until the window is ready.
 fn module_loader(root_id: &str, headless: bool) -> String {
     let js = handle_edits_code();
+    let back_button = crate::back_button::BACK_BUTTON_JS;
+    let safe_area = crate::safe_area::SAFE_AREA_JS;
     format!(
         r#"
 <script type="module">
     {js}
+    {back_button}
+    {safe_area}
     // Wait for the page to load
     window.onload = function() {{
         let rootname = "{root_id}";
@@ -295,36 +365,61 @@ fn get_asset_root() -> Option<PathBuf> {
 }
 
 /// Get the mime type from a path-like string
+///
+/// Text formats can't be sniffed from their contents, so known extensions win. Other files are sniffed from their first bytes
 fn get_mime_from_path(trimmed: &Path) -> Result<&'static str> {
-    if trimmed.extension().is_some_and(|ext| ext == "svg") {
-        return Ok("image/svg+xml");
+    if let Some(mime) = get_mime_by_ext(trimmed) {
+        return Ok(mime);
     }
 
     match infer::get_from_path(trimmed)?.map(|f| f.mime_type()) {
         Some(f) if f != "text/plain" => Ok(f),
-        _ => Ok(get_mime_by_ext(trimmed)),
-    }
-}
-
-/// Get the mime type from a URI using its extension
-fn get_mime_by_ext(trimmed: &Path) -> &'static str {
-    match trimmed.extension().and_then(|e| e.to_str()) {
-        Some("bin") => "application/octet-stream",
-        Some("css") => "text/css",
-        Some("csv") => "text/csv",
-        Some("html") => "text/html",
-        Some("ico") => "image/vnd.microsoft.icon",
-        Some("js") => "text/javascript",
-        Some("json") => "application/json",
-        Some("jsonld") => "application/ld+json",
-        Some("mjs") => "text/javascript",
-        Some("rtf") => "application/rtf",
-        Some("svg") => "image/svg+xml",
-        Some("mp4") => "video/mp4",
         // Assume HTML when a TLD is found for eg. `dioxus:://dioxuslabs.app` | `dioxus://hello.com`
-        Some(_) => "text/html",
+        _ if trimmed.extension().is_some() => Ok("text/html"),
         // https://developer.mozilla.org/en-US/docs/Web/HTTP/Basics_of_HTTP/MIME_types/Common_types
         // using octet stream according to this:
-        None => "application/octet-stream",
+        _ => Ok("application/octet-stream"),
     }
 }
+
+/// Get the mime type from a URI using its extension
+fn get_mime_by_ext(trimmed: &Path) -> Option<&'static str> {
+    let mime = match trimmed.extension()?.to_str()?.to_ascii_lowercase().as_str() {
+        "bin" => "application/octet-stream",
+        "css" => "text/css",
+        "csv" => "text/csv",
+        "html" | "htm" => "text/html",
+        "ico" => "image/vnd.microsoft.icon",
+        "js" | "mjs" => "text/javascript",
+        "json" => "application/json",
+        "jsonld" => "application/ld+json",
+        "rtf" => "application/rtf",
+        "svg" => "image/svg+xml",
+        "txt" => "text/plain",
+        "xml" => "application/xml",
+        "wasm" => "application/wasm",
+        "pdf" => "application/pdf",
+        "png" => "image/png",
+        "jpg" | "jpeg" => "image/jpeg",
+        "gif" => "image/gif",
+        "webp" => "image/webp",
+        "avif" => "image/avif",
+        "woff" => "font/woff",
+        "woff2" => "font/woff2",
+        "ttf" => "font/ttf",
+        "otf" => "font/otf",
+        "mp4" | "m4v" => "video/mp4",
+        "webm" => "video/webm",
+        "mov" => "video/quicktime",
+        "ogv" => "video/ogg",
+        "mp3" => "audio/mpeg",
+        "m4a" => "audio/mp4",
+        "aac" => "audio/aac",
+        "ogg" | "oga" => "audio/ogg",
+        "opus" => "audio/opus",
+        "wav" => "audio/wav",
+        "flac" => "audio/flac",
+        _ => return None,
+    };
+    Some(mime)
+}
@@ -0,0 +1,111 @@
+//! The safe area of the window and the on-screen keyboard
+//!
+//! The insets are only known to the page: CSS reports them with `env(safe-area-inset-*)` and the keyboard shrinks the visual viewport. The page measures both and sends them to the app as a `safe_area` message whenever they change.
+
+use dioxus_hooks::SafeArea;
+use serde::Deserialize;
+
+/// Measures the insets and keeps the focused input visible while the keyboard is open
+pub(crate) const SAFE_AREA_JS: &str = r#"
+(() => {
+  // iOS only reports the insets of the notch if the page covers the whole screen
+  let meta = document.querySelector('meta[name="viewport"]');
+  if (meta == null) {
+    meta = document.createElement("meta");
+    meta.name = "viewport";
+    meta.content = "width=device-width, initial-scale=1.0";
+    document.head.appendChild(meta);
+  }
+  if (!meta.content.includes("viewport-fit")) {
+    meta.content += ", viewport-fit=cover";
+  }
+
+  let probe = null;
+  let last = null;
+  // The height of the window without the keyboard. Android resizes the window when the keyboard opens
+  let full = { width: window.innerWidth, height: window.innerHeight };
+
+  const editing = () => {
+    const element = document.activeElement;
+    return element != null && (element.isContentEditable || element.matches("input, textarea, select"));
+  };
+
+  const keyboardHeight = () => {
+    if (window.innerWidth !== full.width) {
+      // the device was rotated
+      full = { width: window.innerWidth, height: window.innerHeight };
+    } else {
+      full.height = Math.max(full.height, window.innerHeight);
+    }
+    const viewport = window.visualViewport;
+    if (viewport == null || !editing()) {
+      return 0;
+    }
+    const height = full.height - viewport.height - viewport.offsetTop;
+    // small differences are toolbars of the webview, not a keyboard
+    return height > 50 ? Math.round(height) : 0;
+  };
+
+  const measure = () => {
+    if (probe == null) {
+      probe = document.createElement("div");
+      probe.style.cssText =
+        "position: fixed; visibility: hidden; pointer-events: none; padding: env(safe-area-inset-top) env(safe-area-inset-right) env(safe-area-inset-bottom) env(safe-area-inset-left);";
+      document.body.appendChild(probe);
+    }
+    const style = getComputedStyle(probe);
+    const insets = {
+      top: parseFloat(style.paddingTop) || 0,
+      right: parseFloat(style.paddingRight) || 0,
+      bottom: parseFloat(style.paddingBottom) || 0,
+      left: parseFloat(style.paddingLeft) || 0,
+      keyboard: keyboardHeight(),
+    };
+    const opened = insets.keyboard > 0 && (last == null || last.keyboard === 0);
+    document.documentElement.style.setProperty("--dioxus-keyboard-height", `${insets.keyboard}px`);
+    if (opened) {
+      document.activeElement.scrollIntoView({ block: "nearest" });
+    }
+    if (JSON.stringify(insets) !== JSON.stringify(last)) {
+      last = insets;
+      window.ipc.postMessage(window.interpreter.serializeIpcMessage("safe_area", insets));
+    }
+  };
+
+  window.addEventListener("load", measure);
+  window.addEventListener("resize", measure);
+  window.visualViewport?.addEventListener("resize", measure);
+  window.visualViewport?.addEventListener("scroll", measure);
+  document.addEventListener("focusin", () => {
+    // the keyboard doesn't resize anything if another input was focused while it was open
+    const open = last != null && last.keyboard > 0;
+    measure();
+    if (open) {
+      document.activeElement.scrollIntoView({ block: "nearest" });
+    }
+  });
+  document.addEventListener("focusout", () => setTimeout(measure, 0));
+})();
+"#;
+
+/// The insets sent by the page
+#[derive(Deserialize)]
+pub(crate) struct SafeAreaMessage {
+    top: f64,
+    right: f64,
+    bottom: f64,
+    left: f64,
+    keyboard: f64,
+}
+
+impl From<SafeAreaMessage> for SafeArea {
+    fn from(message: SafeAreaMessage) -> Self {
+        SafeArea {
+            top: message.top,
+            right: message.right,
+            bottom: message.bottom,
+            left: message.left,
+            keyboard: message.keyboard,
+        }
+    }
+}
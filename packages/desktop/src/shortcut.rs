@@ -129,6 +129,46 @@ impl ShortcutRegistry {
     }
 }
 
+/// Provide the shortcuts of `use_shortcut` in the root scope. They are registered as global shortcuts of the window, like `use_global_shortcut`
+#[cfg(not(any(target_os = "ios", target_os = "android")))]
+pub(crate) fn provide_shortcut_context(desktop_context: crate::DesktopContext) {
+    use dioxus_hooks::{ShortcutContext, ShortcutError};
+
+    let mut handles = HashMap::new();
+    ShortcutContext::provide(move |context, shortcut, registered| {
+        if !registered {
+            if let Some(handle) = handles.remove(shortcut) {
+                desktop_context.remove_shortcut(handle);
+            }
+            return Ok(());
+        }
+
+        let key = Code::from_str(&shortcut.code())
+            .map_err(|_| ShortcutError::Invalid(format!("unsupported key {}", shortcut.key())))?;
+        let held = shortcut.modifiers();
+        let mut modifiers = Modifiers::default();
+        modifiers.set(Modifiers::CONTROL, held.ctrl);
+        modifiers.set(Modifiers::ALT, held.alt);
+        modifiers.set(Modifiers::SHIFT, held.shift);
+        modifiers.set(Modifiers::META, held.meta);
+
+        let context = context.clone();
+        let pressed = shortcut.clone();
+        let handle = desktop_context
+            .create_shortcut(HotKey::new(Some(modifiers), key), move || {
+                context.dispatch(&pressed);
+            })
+            .map_err(|err| match err {
+                ShortcutRegistryError::InvalidShortcut(shortcut) => {
+                    ShortcutError::Invalid(shortcut)
+                }
+                ShortcutRegistryError::Other(err) => ShortcutError::Failed(err.to_string()),
+            })?;
+        handles.insert(shortcut.clone(), handle);
+        Ok(())
+    });
+}
+
 pub trait IntoAccelerator {
     fn accelerator(&self) -> HotKey;
 }
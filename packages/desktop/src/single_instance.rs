@@ -0,0 +1,95 @@
+//! Keeping a single instance of the app running
+//!
+//! The first instance listens on a local socket named after the app. Instances started later send their command line arguments to the first instance over the socket and exit.
+
+use std::{
+    ffi::OsString,
+    io::{BufRead, BufReader, Write},
+};
+
+use interprocess_docfix::local_socket::{LocalSocketListener, LocalSocketStream};
+use tao::{event_loop::EventLoopProxy, window::WindowId};
+
+use crate::ipc::{EventData, UserWindowEvent};
+
+/// The socket the first instance of the app receives the arguments of other instances on
+#[derive(Default)]
+pub(crate) struct SingleInstance {
+    listener: Option<LocalSocketListener>,
+}
+
+impl SingleInstance {
+    /// Listen for other instances of the app
+    ///
+    /// If another instance of the app is running, the command line arguments are sent to it and this process exits.
+    pub(crate) fn start() -> Self {
+        let name = socket_name();
+
+        if let Ok(mut stream) = LocalSocketStream::connect(name.clone()) {
+            let args = std::env::args().skip(1).collect::<Vec<_>>();
+            let message = serde_json::to_string(&args).expect("Failed to serialize the arguments");
+            match writeln!(stream, "{message}") {
+                Ok(()) => std::process::exit(0),
+                Err(err) => {
+                    tracing::error!("Failed to send the arguments to the running app: {err}")
+                }
+            }
+        }
+
+        // Nothing answered on the socket, so a socket file is left behind by an instance that crashed
+        #[cfg(unix)]
+        {
+            _ = std::fs::remove_file(&name);
+        }
+
+        let listener = LocalSocketListener::bind(name)
+            .map_err(|err| {
+                tracing::error!("Failed to listen for other instances of the app: {err}")
+            })
+            .ok();
+        Self { listener }
+    }
+
+    /// Send the arguments of other instances to `window`
+    pub(crate) fn forward_to(&mut self, proxy: EventLoopProxy<UserWindowEvent>, window: WindowId) {
+        let Some(listener) = self.listener.take() else {
+            return;
+        };
+        std::thread::spawn(move || {
+            for stream in listener.incoming().flatten() {
+                for line in BufReader::new(stream).lines().map_while(Result::ok) {
+                    let Ok(args) = serde_json::from_str(&line) else {
+                        tracing::error!("Received invalid arguments from another instance");
+                        continue;
+                    };
+                    _ = proxy.send_event(UserWindowEvent(EventData::SecondInstance(args), window));
+                }
+            }
+        });
+    }
+}
+
+/// The name of the executable identifies the app
+fn app_name() -> String {
+    std::env::current_exe()
+        .ok()
+        .and_then(|exe| Some(exe.file_stem()?.to_string_lossy().into_owned()))
+        .unwrap_or_else(|| "dioxus".to_string())
+}
+
+/// The runtime directory is private to the user, so other users can't send arguments to the app
+#[cfg(unix)]
+fn socket_name() -> OsString {
+    std::env::var_os("XDG_RUNTIME_DIR")
+        .map(std::path::PathBuf::from)
+        .unwrap_or_else(std::env::temp_dir)
+        .join(format!("{}-instance.sock", app_name()))
+        .into_os_string()
+}
+
+/// Named pipes are shared between all users, so the name of the pipe includes the user
+#[cfg(windows)]
+fn socket_name() -> OsString {
+    let user = std::env::var("USERNAME").unwrap_or_default();
+    OsString::from(format!("{}-{user}-instance", app_name()))
+}
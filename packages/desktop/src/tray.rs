@@ -0,0 +1,215 @@
+//! A system tray icon with a menu built from Rust callbacks
+
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
+
+use crate::desktop_context::CallbackScope;
+
+use tray_icon::{
+    menu::{Menu, MenuEvent, MenuId, MenuItem, PredefinedMenuItem},
+    ClickType, TrayIcon, TrayIconBuilder, TrayIconEvent,
+};
+
+pub use tray_icon::Icon as TrayIconImage;
+
+type TrayCallback = Rc<RefCell<dyn FnMut()>>;
+
+/// A builder for the icon shown in the system tray
+///
+/// Pass this to [`crate::Config::with_system_tray`] to show the tray when the app starts, or to [`crate::DesktopService::set_system_tray`] to change it while the app is running.
+///
+/// ```rust, ignore
+/// use dioxus::desktop::{Config, SystemTray, WindowCloseBehaviour};
+///
+/// let tray = SystemTray::new()
+///     .with_tooltip("My App")
+///     .with_toggle_window(true)
+///     .with_item("Quit", || std::process::exit(0));
+///
+/// let config = Config::new()
+///     .with_system_tray(tray)
+///     .with_close_behaviour(WindowCloseBehaviour::LastWindowHides);
+/// ```
+///
+/// ## Platform-specific:
+///
+/// - **Linux**: Click events are not emitted. The menu is shown when the icon is clicked.
+#[derive(Default)]
+pub struct SystemTray {
+    icon: Option<TrayIconImage>,
+    tooltip: Option<String>,
+    items: Vec<TrayItem>,
+    on_left_click: Option<TrayCallback>,
+    on_right_click: Option<TrayCallback>,
+    toggle_window: bool,
+}
+
+enum TrayItem {
+    Item {
+        label: String,
+        enabled: bool,
+        callback: TrayCallback,
+    },
+    Separator,
+}
+
+impl SystemTray {
+    /// Create a tray icon without an icon or menu
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the image shown in the tray
+    pub fn with_icon(mut self, icon: TrayIconImage) -> Self {
+        self.icon = Some(icon);
+        self
+    }
+
+    /// Set the text shown when the mouse is over the icon
+    pub fn with_tooltip(mut self, tooltip: impl Into<String>) -> Self {
+        self.tooltip = Some(tooltip.into());
+        self
+    }
+
+    /// Add an item to the tray menu that calls `callback` when it is clicked
+    pub fn with_item(self, label: impl Into<String>, callback: impl FnMut() + 'static) -> Self {
+        self.with_item_enabled(label, true, callback)
+    }
+
+    /// Add an item to the tray menu that may be disabled
+    pub fn with_item_enabled(
+        mut self,
+        label: impl Into<String>,
+        enabled: bool,
+        callback: impl FnMut() + 'static,
+    ) -> Self {
+        self.items.push(TrayItem::Item {
+            label: label.into(),
+            enabled,
+            callback: Rc::new(RefCell::new(callback)),
+        });
+        self
+    }
+
+    /// Add a separator to the tray menu
+    pub fn with_separator(mut self) -> Self {
+        self.items.push(TrayItem::Separator);
+        self
+    }
+
+    /// Call `callback` when the icon is clicked with the left mouse button
+    pub fn on_left_click(mut self, callback: impl FnMut() + 'static) -> Self {
+        self.on_left_click = Some(Rc::new(RefCell::new(callback)));
+        self
+    }
+
+    /// Call `callback` when the icon is clicked with the right mouse button
+    pub fn on_right_click(mut self, callback: impl FnMut() + 'static) -> Self {
+        self.on_right_click = Some(Rc::new(RefCell::new(callback)));
+        self
+    }
+
+    /// Show or hide the main window when the icon is clicked with the left mouse button
+    ///
+    /// Combine this with [`crate::WindowCloseBehaviour::LastWindowHides`] to minimize the app to the tray.
+    pub fn with_toggle_window(mut self, toggle: bool) -> Self {
+        self.toggle_window = toggle;
+        self
+    }
+
+    /// Wrap every callback so it runs in the scope it was registered in
+    pub(crate) fn in_current_scope(mut self) -> Self {
+        let wrap = |callback: TrayCallback| -> TrayCallback {
+            let scope = CallbackScope::current();
+            Rc::new(RefCell::new(move || {
+                scope.run(|| (callback.borrow_mut())())
+            }))
+        };
+        for item in &mut self.items {
+            if let TrayItem::Item { callback, .. } = item {
+                *callback = wrap(callback.clone());
+            }
+        }
+        self.on_left_click = self.on_left_click.map(wrap);
+        self.on_right_click = self.on_right_click.map(wrap);
+        self
+    }
+
+    /// Create the tray icon. This must be called after the event loop has started
+    pub(crate) fn build(self) -> Option<SystemTrayState> {
+        let menu = Menu::new();
+        let mut menu_handlers = HashMap::new();
+        for item in self.items {
+            let result = match item {
+                TrayItem::Item {
+                    label,
+                    enabled,
+                    callback,
+                } => {
+                    let item = MenuItem::new(label, enabled, None);
+                    menu_handlers.insert(item.id().clone(), callback);
+                    menu.append(&item)
+                }
+                TrayItem::Separator => menu.append(&PredefinedMenuItem::separator()),
+            };
+            if let Err(err) = result {
+                tracing::error!("Failed to add an item to the tray menu: {err}");
+            }
+        }
+
+        let mut builder = TrayIconBuilder::new().with_menu(Box::new(menu));
+        if let Some(icon) = self.icon {
+            builder = builder.with_icon(icon);
+        }
+        if let Some(tooltip) = self.tooltip {
+            builder = builder.with_tooltip(tooltip);
+        }
+        // The left click toggles the window instead of opening the menu
+        if self.toggle_window || self.on_left_click.is_some() {
+            builder = builder.with_menu_on_left_click(false);
+        }
+
+        let icon = match builder.build() {
+            Ok(icon) => icon,
+            Err(err) => {
+                tracing::error!("Failed to create the tray icon: {err}");
+                return None;
+            }
+        };
+
+        Some(SystemTrayState {
+            icon,
+            menu_handlers,
+            on_left_click: self.on_left_click,
+            on_right_click: self.on_right_click,
+            toggle_window: self.toggle_window,
+        })
+    }
+}
+
+/// The tray icon that is currently shown
+pub(crate) struct SystemTrayState {
+    pub(crate) icon: TrayIcon,
+    menu_handlers: HashMap<MenuId, TrayCallback>,
+    on_left_click: Option<TrayCallback>,
+    on_right_click: Option<TrayCallback>,
+    toggle_window: bool,
+}
+
+impl SystemTrayState {
+    /// Get the handler for a menu item, or None if the item is not in the tray menu
+    pub(crate) fn menu_handler(&self, event: &MenuEvent) -> Option<TrayCallback> {
+        self.menu_handlers.get(event.id()).cloned()
+    }
+
+    /// Get the handler for a click on the icon, and whether the click should show or hide the main window
+    pub(crate) fn icon_handler(&self, event: &TrayIconEvent) -> (Option<TrayCallback>, bool) {
+        if event.id() != self.icon.id() {
+            return (None, false);
+        }
+        match event.click_type {
+            ClickType::Left => (self.on_left_click.clone(), self.toggle_window),
+            ClickType::Right => (self.on_right_click.clone(), false),
+            ClickType::Double => (None, false),
+        }
+    }
+}
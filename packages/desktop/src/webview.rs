@@ -3,21 +3,29 @@ use crate::{
     assets::AssetHandlerRegistry,
     edits::EditQueue,
     eval::DesktopEvalProvider,
+    file_upload::NativeFileHover,
     ipc::{EventData, UserWindowEvent},
     protocol::{self},
     waker::tao_waker,
     Config, DesktopContext, DesktopService,
 };
 use dioxus_core::{ScopeId, VirtualDom};
+use dioxus_hooks::{SafeArea, SafeAreaContext, Viewport, ViewportContext};
 use dioxus_html::prelude::EvalProvider;
 use futures_util::{pin_mut, FutureExt};
 use std::{any::Any, rc::Rc, task::Waker};
+use tao::window::{Theme, Window};
 use wry::{RequestAsyncResponder, WebContext, WebViewBuilder};
 
 pub(crate) struct WebviewInstance {
     pub dom: VirtualDom,
     pub desktop_context: DesktopContext,
     pub waker: Waker,
+    pub viewport: ViewportContext,
+    pub safe_area: SafeAreaContext,
+
+    #[cfg(all(feature = "devtools", debug_assertions))]
+    devtools: dioxus_devtools::Devtools,
 
     // Wry assumes the webcontext is alive for the lifetime of the webview.
     // We need to keep the webcontext alive, otherwise the webview will crash
@@ -31,6 +39,15 @@ pub(crate) struct WebviewInstance {
     _menu: Option<Box<dyn Any>>,
 }
 
+impl Drop for WebviewInstance {
+    fn drop(&mut self) {
+        let handlers = self.desktop_context.close_handlers.take();
+        for handler in handlers {
+            handler();
+        }
+    }
+}
+
 impl WebviewInstance {
     pub(crate) fn new(
         mut cfg: Config,
@@ -131,9 +148,16 @@ impl WebviewInstance {
             .with_asynchronous_custom_protocol(String::from("dioxus"), request_handler)
             .with_web_context(&mut web_context);
 
-        if let Some(handler) = file_handler {
-            webview = webview.with_file_drop_handler(move |evt| handler(window_id, evt))
-        }
+        // Track the files dragged from the filesystem so the html drag events can include their paths
+        let file_hover = NativeFileHover::default();
+        let file_hover_ = file_hover.clone();
+        webview = webview.with_file_drop_handler(move |evt| {
+            file_hover_.set(&evt);
+            match &file_handler {
+                Some(handler) => handler(window_id, evt),
+                None => false,
+            }
+        });
 
         if let Some(color) = cfg.background_color {
             webview = webview.with_background_color(color);
@@ -165,12 +189,17 @@ impl WebviewInstance {
 
         let webview = webview.build().unwrap();
 
-        // TODO: allow users to specify their own menubars, again :/
-        let menu = if cfg!(not(any(target_os = "android", target_os = "ios"))) {
-            crate::menubar::build_menu(&window, cfg.enable_default_menu_bar)
-        } else {
-            None
-        };
+        #[cfg(not(any(target_os = "ios", target_os = "android")))]
+        let mut menu_items = crate::menubar::MenuItems::default();
+        #[cfg(not(any(target_os = "ios", target_os = "android")))]
+        let menu = Some(crate::menubar::build_menu(
+            &window,
+            cfg.enable_default_menu_bar,
+            cfg.menu_bar.as_ref(),
+            &mut menu_items,
+        ));
+        #[cfg(any(target_os = "ios", target_os = "android"))]
+        let menu = None;
 
         let desktop_context = Rc::from(DesktopService::new(
             webview,
@@ -178,27 +207,66 @@ impl WebviewInstance {
             shared.clone(),
             edit_queue,
             asset_handlers,
+            file_hover,
         ));
+        #[cfg(not(any(target_os = "ios", target_os = "android")))]
+        desktop_context.menu_items.replace(menu_items);
 
         let provider: Rc<dyn EvalProvider> =
             Rc::new(DesktopEvalProvider::new(desktop_context.clone()));
 
-        dom.in_runtime(|| {
+        // The webview can't tell us when media queries change, so use_media_query is driven by window events
+        // The insets are measured by the page once it is loaded
+        let (viewport, safe_area) = dom.in_runtime(|| {
             ScopeId::ROOT.provide_context(desktop_context.clone());
             ScopeId::ROOT.provide_context(provider);
+            #[cfg(not(any(target_os = "ios", target_os = "android")))]
+            ScopeId::ROOT.provide_context(crate::clipboard::provider());
+            #[cfg(not(any(target_os = "ios", target_os = "android")))]
+            crate::shortcut::provide_shortcut_context(desktop_context.clone());
+            (
+                ViewportContext::provide(window_viewport(&desktop_context.window)),
+                SafeAreaContext::provide(SafeArea::default()),
+            )
         });
 
+        // Connecting to the devtools server needs the tokio runtime
+        #[cfg(all(feature = "devtools", debug_assertions))]
+        let devtools = {
+            let devtools = dioxus_devtools::Devtools::install(&dom);
+            #[cfg(feature = "tokio")]
+            dioxus_devtools::native::connect(&devtools, &dioxus_devtools::devtools_url());
+            devtools
+        };
+
         WebviewInstance {
             waker: tao_waker(shared.proxy.clone(), desktop_context.window.id()),
             desktop_context,
             dom,
+            viewport,
+            safe_area,
+            #[cfg(all(feature = "devtools", debug_assertions))]
+            devtools,
             _menu: menu,
             _web_context: web_context,
         }
     }
 
+    /// Update the viewport used by media queries after the window was resized or the theme changed
+    pub fn update_viewport(&mut self) {
+        let viewport = window_viewport(&self.desktop_context.window);
+        self.dom.in_runtime(|| self.viewport.set(viewport));
+    }
+
+    /// Update the insets used by use_safe_area after the page measured them
+    pub fn update_safe_area(&mut self, safe_area: SafeArea) {
+        self.dom.in_runtime(|| self.safe_area.set(safe_area));
+    }
+
     pub fn poll_vdom(&mut self) {
-        let mut cx = std::task::Context::from_waker(&self.waker);
+        // The waker is cloned so the loop can borrow the webview mutably after a render
+        let waker = self.waker.clone();
+        let mut cx = std::task::Context::from_waker(&waker);
 
         // Continously poll the virtualdom until it's pending
         // Wait for work will return Ready when it has edits to be sent to the webview
@@ -217,6 +285,69 @@ impl WebviewInstance {
             self.dom
                 .render_immediate(&mut *self.desktop_context.mutation_state.borrow_mut());
             self.desktop_context.send_edits();
+            self.wait_for_exits();
+            self.update_devtools();
+        }
+    }
+
+    /// Take a snapshot of the scope tree for the devtools after a render and move the highlight to the elements of the selected component
+    pub fn update_devtools(&mut self) {
+        #[cfg(all(feature = "devtools", debug_assertions))]
+        if let Some(elements) = self.devtools.update(&mut self.dom) {
+            let ids = elements
+                .iter()
+                .map(|id| id.0.to_string())
+                .collect::<Vec<_>>()
+                .join(",");
+            let script = format!(
+                r#"
+                (() => {{
+                    for (const element of document.querySelectorAll("[{attribute}]")) {{
+                        element.removeAttribute("{attribute}");
+                    }}
+                    for (const id of [{ids}]) {{
+                        const node = window.interpreter.nodes[id];
+                        // Text nodes can't be highlighted
+                        if (node instanceof Element) {{
+                            node.setAttribute("{attribute}", "");
+                        }}
+                    }}
+                }})()
+                "#,
+                attribute = dioxus_devtools::HIGHLIGHT_ATTRIBUTE,
+            );
+            _ = self.desktop_context.webview.evaluate_script(&script);
         }
     }
+
+    /// Wait for the exit animations of the presences removed in the last render. The exits end after their duration
+    pub fn wait_for_exits(&mut self) {
+        let exits = self
+            .desktop_context
+            .mutation_state
+            .borrow_mut()
+            .take_exits();
+        for (scope, duration) in exits {
+            self.dom.in_runtime(|| {
+                scope.spawn(async move {
+                    #[cfg(feature = "tokio")]
+                    tokio::time::sleep(duration).await;
+                    // Without a timer the nodes are removed right away
+                    #[cfg(not(feature = "tokio"))]
+                    let _ = duration;
+                    scope.finish_exit();
+                })
+            });
+        }
+    }
+}
+
+/// The logical size and color scheme of a window
+fn window_viewport(window: &Window) -> Viewport {
+    let size = window.inner_size().to_logical::<f64>(window.scale_factor());
+    Viewport {
+        width: size.width,
+        height: size.height,
+        prefers_dark: window.theme() == Theme::Dark,
+    }
 }
@@ -0,0 +1,180 @@
+#![doc = include_str!("../README.md")]
+#![doc(html_logo_url = "https://avatars.githubusercontent.com/u/79236386")]
+#![doc(html_favicon_url = "https://avatars.githubusercontent.com/u/79236386")]
+#![warn(missing_docs)]
+
+use std::{
+    collections::HashSet,
+    sync::{Arc, Mutex, MutexGuard},
+};
+
+use dioxus_lib::prelude::{
+    dioxus_core::{ElementId, ScopeInfo},
+    *,
+};
+use futures_channel::mpsc::{unbounded, UnboundedReceiver, UnboundedSender};
+
+mod overlay;
+mod protocol;
+
+#[cfg(feature = "native")]
+pub mod native;
+#[cfg(feature = "web")]
+pub mod web;
+
+pub use overlay::*;
+pub use protocol::*;
+
+/// The attribute renderers set on the elements of the highlighted component. The overlay styles every element with it.
+pub const HIGHLIGHT_ATTRIBUTE: &str = "data-dioxus-devtools-highlight";
+
+/// The state of the devtools of an app, shared between the [`DevtoolsOverlay`], websocket clients and the renderer
+///
+/// The renderer installs it in the root scope with [`Devtools::install`], or the overlay provides it. After every render the renderer takes a snapshot of the scope tree with [`Devtools::update`], which renders the overlay again and notifies the [subscribers](Devtools::subscribe) when the tree changed.
+#[derive(Clone)]
+pub struct Devtools {
+    inner: Arc<Mutex<DevtoolsState>>,
+}
+
+struct DevtoolsState {
+    tree: Vec<ScopeInfo>,
+    overlay: Option<ScopeId>,
+    highlighted: Option<ScopeId>,
+    highlighted_elements: Vec<ElementId>,
+    schedule_update: Arc<dyn Fn(ScopeId) + Send + Sync>,
+    subscribers: Vec<UnboundedSender<DevtoolsMessage>>,
+}
+
+impl DevtoolsState {
+    fn broadcast(&mut self, message: DevtoolsMessage) {
+        self.subscribers
+            .retain(|subscriber| subscriber.unbounded_send(message.clone()).is_ok());
+    }
+}
+
+impl Devtools {
+    /// Create the devtools of the component that runs
+    fn new() -> Self {
+        Self {
+            inner: Arc::new(Mutex::new(DevtoolsState {
+                tree: Vec::new(),
+                overlay: None,
+                highlighted: None,
+                highlighted_elements: Vec::new(),
+                schedule_update: schedule_update_any(),
+                subscribers: Vec::new(),
+            })),
+        }
+    }
+
+    fn state(&self) -> MutexGuard<'_, DevtoolsState> {
+        self.inner.lock().unwrap()
+    }
+
+    /// Provide the devtools in the root scope of a VirtualDom, or get them if they are already provided
+    pub fn install(dom: &VirtualDom) -> Self {
+        dom.in_runtime(|| {
+            ScopeId::ROOT
+                .in_runtime(|| has_context().unwrap_or_else(|| provide_context(Self::new())))
+        })
+    }
+
+    /// Get the devtools of a VirtualDom, if the renderer installed them or the app renders a [`DevtoolsOverlay`]
+    pub fn from_dom(dom: &VirtualDom) -> Option<Self> {
+        dom.in_runtime(|| ScopeId::ROOT.has_context())
+    }
+
+    /// The last snapshot of the scope tree, without the scopes of the overlay
+    pub fn tree(&self) -> Vec<ScopeInfo> {
+        self.state().tree.clone()
+    }
+
+    /// The component whose elements are highlighted
+    pub fn highlighted(&self) -> Option<ScopeId> {
+        self.state().highlighted
+    }
+
+    /// Highlight the elements of a component, or stop highlighting with `None`. The renderer applies the highlight after the next render.
+    pub fn highlight(&self, scope: Option<ScopeId>) {
+        let mut state = self.state();
+        let previous = std::mem::replace(&mut state.highlighted, scope);
+        state.broadcast(DevtoolsMessage::Highlighted(scope));
+
+        // Render the overlay, or the component if there is no overlay, so the renderer updates the highlight
+        if let Some(target) = state.overlay.or(scope).or(previous) {
+            (state.schedule_update)(target);
+        }
+    }
+
+    /// Render a component again, even if nothing it reads changed
+    pub fn rerender(&self, scope: ScopeId) {
+        (self.state().schedule_update)(scope);
+    }
+
+    /// Run a command from a devtools client
+    pub fn apply(&self, command: DevtoolsCommand) {
+        match command {
+            DevtoolsCommand::Highlight(scope) => self.highlight(scope),
+            DevtoolsCommand::Rerender(scope) => self.rerender(scope),
+        }
+    }
+
+    /// Receive the scope tree every time it changes and the highlighted component every time it is set
+    ///
+    /// The receiver gets the current tree and highlighted component right away.
+    pub fn subscribe(&self) -> UnboundedReceiver<DevtoolsMessage> {
+        let (tx, rx) = unbounded();
+        let mut state = self.state();
+        _ = tx.unbounded_send(DevtoolsMessage::Tree(state.tree.clone()));
+        _ = tx.unbounded_send(DevtoolsMessage::Highlighted(state.highlighted));
+        state.subscribers.push(tx);
+        rx
+    }
+
+    /// Take a new snapshot of the scope tree after a render, and render the overlay and notify the subscribers if the tree changed
+    ///
+    /// Returns the elements that should be highlighted if they changed since the last update. Renderers remove [`HIGHLIGHT_ATTRIBUTE`] from the elements of the last update and set it on the new ones.
+    pub fn update(&self, dom: &mut VirtualDom) -> Option<Vec<ElementId>> {
+        let mut state = self.state();
+        // The overlay renders every time the tree changes, so its own scopes are left out of the tree
+        let mut overlay_scopes = HashSet::new();
+        let mut tree: Vec<ScopeInfo> = dom
+            .scope_tree()
+            .into_iter()
+            .filter(|info| {
+                let in_overlay = Some(info.id) == state.overlay
+                    || info
+                        .parent
+                        .is_some_and(|parent| overlay_scopes.contains(&parent));
+                if in_overlay {
+                    overlay_scopes.insert(info.id);
+                }
+                !in_overlay
+            })
+            .collect();
+
+        for info in &mut tree {
+            info.children
+                .retain(|child| !overlay_scopes.contains(child));
+        }
+
+        if tree != state.tree {
+            state.tree = tree.clone();
+            state.broadcast(DevtoolsMessage::Tree(tree));
+            if let Some(overlay) = state.overlay {
+                dom.mark_dirty(overlay);
+            }
+        }
+
+        let elements = state
+            .highlighted
+            .and_then(|id| state.tree.iter().find(|info| info.id == id))
+            .map(|info| info.elements.clone())
+            .unwrap_or_default();
+        if elements == state.highlighted_elements {
+            return None;
+        }
+        state.highlighted_elements = elements.clone();
+        Some(elements)
+    }
+}
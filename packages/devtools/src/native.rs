@@ -0,0 +1,49 @@
+//! Expose the devtools of a native app over a websocket
+
+use std::pin::pin;
+
+use futures_util::{future::select, SinkExt, StreamExt};
+use tokio_tungstenite::{connect_async, tungstenite::Message};
+
+use crate::{Devtools, DevtoolsCommand};
+
+/// Connect the devtools to the websocket server at `url`. The server receives every [`DevtoolsMessage`](crate::DevtoolsMessage) and can send [`DevtoolsCommand`]s until the connection closes.
+///
+/// This must be called inside a tokio runtime. If no server is running, the app runs without it.
+pub fn connect(devtools: &Devtools, url: &str) {
+    let devtools = devtools.clone();
+    let url = url.to_string();
+    tokio::spawn(async move {
+        let socket = match connect_async(&url).await {
+            Ok((socket, _)) => socket,
+            Err(err) => {
+                tracing::debug!("No devtools server is running at {url}: {err}");
+                return;
+            }
+        };
+        let (mut write, mut read) = socket.split();
+
+        let mut messages = devtools.subscribe();
+        let send = async move {
+            while let Some(message) = messages.next().await {
+                let json = serde_json::to_string(&message).unwrap();
+                if write.send(Message::Text(json)).await.is_err() {
+                    break;
+                }
+            }
+        };
+        let receive = async move {
+            while let Some(Ok(message)) = read.next().await {
+                if let Message::Text(text) = message {
+                    match serde_json::from_str::<DevtoolsCommand>(&text) {
+                        Ok(command) => devtools.apply(command),
+                        Err(err) => tracing::warn!("Invalid devtools command {text:?}: {err}"),
+                    }
+                }
+            }
+        };
+
+        // Stop when either side of the connection closes
+        select(pin!(send), pin!(receive)).await;
+    });
+}
@@ -0,0 +1,142 @@
+use dioxus_lib::prelude::{dioxus_core::ScopeInfo, *};
+
+use crate::{Devtools, HIGHLIGHT_ATTRIBUTE};
+
+const STYLE: &str = r#"
+.dioxus-devtools {
+    position: fixed;
+    right: 8px;
+    bottom: 8px;
+    z-index: 2147483647;
+    max-height: 60vh;
+    max-width: 480px;
+    overflow: auto;
+    padding: 8px;
+    border-radius: 6px;
+    background: #282c34;
+    color: #abb2bf;
+    font: 12px monospace;
+}
+.dioxus-devtools-row {
+    display: flex;
+    gap: 8px;
+    align-items: center;
+    white-space: nowrap;
+}
+.dioxus-devtools-row[data-highlighted] {
+    color: #e06c75;
+}
+.dioxus-devtools-details {
+    margin: 0;
+    list-style: none;
+    white-space: pre;
+}
+.dioxus-devtools button {
+    font: inherit;
+}
+"#;
+
+/// A panel that lists the components of the app with how often and how long they rendered
+///
+/// The components can be highlighted on the page and rendered again from the panel. The props and hooks of the highlighted component are listed under it. Render it next to the rest of the app in debug builds:
+///
+/// ```rust, ignore
+/// fn app() -> Element {
+///     rsx! {
+///         Router::<Route> {}
+///         if cfg!(debug_assertions) {
+///             DevtoolsOverlay {}
+///         }
+///     }
+/// }
+/// ```
+///
+/// The renderer keeps the tree up to date. The web renderer does this in debug builds with the `devtools` feature.
+#[component]
+pub fn DevtoolsOverlay() -> Element {
+    let devtools = use_hook(|| {
+        let devtools = try_consume_context::<Devtools>()
+            .unwrap_or_else(|| provide_root_context(Devtools::new()));
+        devtools.state().overlay = current_scope_id();
+        devtools
+    });
+    use_drop({
+        let devtools = devtools.clone();
+        move || devtools.state().overlay = None
+    });
+    let mut open = use_signal(|| true);
+
+    let tree = devtools.tree();
+    let highlighted = devtools.highlighted();
+    let highlight_style = format!("[{HIGHLIGHT_ATTRIBUTE}] {{ outline: 2px solid #e06c75; }}");
+
+    rsx! {
+        style { "{STYLE}" "{highlight_style}" }
+        div { class: "dioxus-devtools",
+            button { onclick: move |_| open.toggle(),
+                if open() { "Hide components" } else { "Components" }
+            }
+            if open() {
+                if tree.is_empty() {
+                    p { "The renderer of this app does not update the devtools" }
+                }
+                for info in tree {
+                    {scope_row(&devtools, info, highlighted)}
+                }
+            }
+        }
+    }
+}
+
+fn scope_row(devtools: &Devtools, info: ScopeInfo, highlighted: Option<ScopeId>) -> Element {
+    let id = info.id;
+    let is_highlighted = highlighted == Some(id);
+    let indent = info.height * 12;
+    let render_time = info
+        .render_time
+        .map(|time| format!("{:.2}ms", time.as_secs_f64() * 1000.0));
+    let highlight = {
+        let devtools = devtools.clone();
+        move |_| devtools.highlight((!is_highlighted).then_some(id))
+    };
+    let rerender = {
+        let devtools = devtools.clone();
+        move |_| devtools.rerender(id)
+    };
+
+    rsx! {
+        div {
+            key: "{id.0}",
+            class: "dioxus-devtools-row",
+            "data-highlighted": is_highlighted.then_some("true"),
+            padding_left: "{indent}px",
+            span { "{info.name}" }
+            span { title: "renders", "×{info.render_count}" }
+            if let Some(render_time) = render_time {
+                span { title: "last render", "{render_time}" }
+            }
+            span { title: "hooks", "{info.hooks.len()} hooks" }
+            if info.suspended {
+                span { "suspended" }
+            }
+            button { onclick: highlight, if is_highlighted { "unhighlight" } else { "highlight" } }
+            button { onclick: rerender, "render" }
+        }
+        // Show the props and hooks of the highlighted component
+        if is_highlighted {
+            ul { class: "dioxus-devtools-details", padding_left: "{indent + 12}px",
+                for prop in info.props {
+                    li { key: "prop-{prop.name}", "{prop.name}: {prop.value}" }
+                }
+                for (index, hook) in info.hooks.into_iter().enumerate() {
+                    li { key: "hook-{index}", title: "{hook.type_name}",
+                        "hook {index}"
+                        if let Some(value) = hook.value {
+                            ": {value}"
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,36 @@
+use dioxus_lib::prelude::{dioxus_core::ScopeInfo, ScopeId};
+use serde::{Deserialize, Serialize};
+
+/// The address the app connects to when no other address is set with the `DIOXUS_DEVTOOLS_URL` environment variable
+pub const DEFAULT_DEVTOOLS_URL: &str = "ws://127.0.0.1:8097";
+
+/// A message the app sends to devtools clients as JSON
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", content = "data")]
+pub enum DevtoolsMessage {
+    /// The scope tree changed
+    Tree(Vec<ScopeInfo>),
+    /// A component was highlighted, or the highlight was removed
+    Highlighted(Option<ScopeId>),
+}
+
+/// A command a devtools client sends to the app as JSON
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", content = "data")]
+pub enum DevtoolsCommand {
+    /// Highlight the elements of a component, or stop highlighting with `null`
+    Highlight(Option<ScopeId>),
+    /// Render a component again
+    Rerender(ScopeId),
+}
+
+/// The address of the devtools websocket server the app connects to
+///
+/// It is read from the `DIOXUS_DEVTOOLS_URL` environment variable when the app starts, or when the app is compiled on the web, and defaults to [`DEFAULT_DEVTOOLS_URL`].
+pub fn devtools_url() -> String {
+    #[cfg(target_arch = "wasm32")]
+    let url = option_env!("DIOXUS_DEVTOOLS_URL").map(str::to_string);
+    #[cfg(not(target_arch = "wasm32"))]
+    let url = std::env::var("DIOXUS_DEVTOOLS_URL").ok();
+    url.unwrap_or_else(|| DEFAULT_DEVTOOLS_URL.to_string())
+}
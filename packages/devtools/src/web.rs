@@ -0,0 +1,65 @@
+//! Expose the devtools of a web app over a websocket
+
+use futures_channel::mpsc::unbounded;
+use futures_util::StreamExt;
+use wasm_bindgen::{prelude::Closure, JsCast};
+use web_sys::{MessageEvent, WebSocket};
+
+use crate::{Devtools, DevtoolsCommand};
+
+/// Connect the devtools to the websocket server at `url`. The server receives every [`DevtoolsMessage`](crate::DevtoolsMessage) and can send [`DevtoolsCommand`]s until the connection closes.
+///
+/// If no server is running, the app runs without it.
+pub fn connect(devtools: &Devtools, url: &str) {
+    let socket = match WebSocket::new(url) {
+        Ok(socket) => socket,
+        Err(err) => {
+            tracing::debug!("Failed to connect to the devtools server at {url}: {err:?}");
+            return;
+        }
+    };
+
+    let onmessage = {
+        let devtools = devtools.clone();
+        Closure::<dyn FnMut(MessageEvent)>::new(move |event: MessageEvent| {
+            let Some(text) = event.data().as_string() else {
+                return;
+            };
+            match serde_json::from_str::<DevtoolsCommand>(&text) {
+                Ok(command) => devtools.apply(command),
+                Err(err) => tracing::warn!("Invalid devtools command {text:?}: {err}"),
+            }
+        })
+    };
+    socket.set_onmessage(Some(onmessage.as_ref().unchecked_ref()));
+    onmessage.forget();
+
+    // The socket can only send once it is open. The close event also fires if it never opens.
+    let (open_tx, mut open_rx) = unbounded();
+    let onopen = {
+        let open_tx = open_tx.clone();
+        Closure::<dyn FnMut()>::new(move || _ = open_tx.unbounded_send(true))
+    };
+    let onclose = Closure::<dyn FnMut()>::new(move || _ = open_tx.unbounded_send(false));
+    socket.set_onopen(Some(onopen.as_ref().unchecked_ref()));
+    socket.set_onclose(Some(onclose.as_ref().unchecked_ref()));
+    onopen.forget();
+    onclose.forget();
+
+    let mut messages = devtools.subscribe();
+    wasm_bindgen_futures::spawn_local(async move {
+        if open_rx.next().await != Some(true) {
+            tracing::debug!("No devtools server is running at {}", socket.url());
+            return;
+        }
+        while let Some(message) = messages.next().await {
+            if socket.ready_state() != WebSocket::OPEN {
+                break;
+            }
+            let json = serde_json::to_string(&message).unwrap();
+            if socket.send_with_str(&json).is_err() {
+                break;
+            }
+        }
+    });
+}
@@ -0,0 +1,69 @@
+#![allow(non_snake_case)]
+
+use dioxus::prelude::*;
+use dioxus_devtools::{Devtools, DevtoolsOverlay};
+
+#[component]
+fn Counter() -> Element {
+    let mut count = use_signal(|| 0);
+    rsx! {
+        button { onclick: move |_| count += 1, "count {count}" }
+    }
+}
+
+fn app() -> Element {
+    rsx! {
+        Counter {}
+        DevtoolsOverlay {}
+    }
+}
+
+#[test]
+fn overlay_lists_the_components_of_the_app() {
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild(&mut dioxus_core::NoOpMutations);
+    let devtools = Devtools::from_dom(&dom).expect("the overlay provides the devtools");
+    assert!(devtools.tree().is_empty());
+
+    // The first update marks the overlay dirty so it shows the tree
+    assert_eq!(devtools.update(&mut dom), None);
+    let tree = devtools.tree();
+    let names: Vec<_> = tree.iter().map(|info| info.name.as_str()).collect();
+    assert_eq!(names, ["app", "Counter"]);
+    assert_eq!(tree[0].children, [tree[1].id]);
+
+    dom.render_immediate(&mut dioxus_core::NoOpMutations);
+    let html = dioxus_ssr::render(&dom);
+    assert!(html.contains("Counter"));
+    assert!(html.contains("×1"));
+
+    // Rendering the overlay again does not change the tree it shows
+    assert_eq!(devtools.update(&mut dom), None);
+    assert_eq!(devtools.tree(), tree);
+}
+
+#[test]
+fn highlight_and_rerender_components() {
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild(&mut dioxus_core::NoOpMutations);
+    let devtools = Devtools::from_dom(&dom).unwrap();
+    devtools.update(&mut dom);
+    let counter = devtools.tree()[1].clone();
+
+    dom.in_runtime(|| devtools.highlight(Some(counter.id)));
+    dom.render_immediate(&mut dioxus_core::NoOpMutations);
+    assert_eq!(devtools.update(&mut dom), Some(counter.elements.clone()));
+    assert!(!counter.elements.is_empty());
+    // The overlay lists the hooks of the highlighted component
+    assert!(dioxus_ssr::render(&dom).contains("hook 0: 0"));
+    assert_eq!(devtools.update(&mut dom), None);
+
+    dom.in_runtime(|| devtools.rerender(counter.id));
+    dom.render_immediate(&mut dioxus_core::NoOpMutations);
+    devtools.update(&mut dom);
+    assert_eq!(devtools.tree()[1].render_count, 2);
+
+    dom.in_runtime(|| devtools.highlight(None));
+    dom.render_immediate(&mut dioxus_core::NoOpMutations);
+    assert_eq!(devtools.update(&mut dom), Some(Vec::new()));
+}
@@ -0,0 +1,63 @@
+#![cfg(feature = "native")]
+#![allow(non_snake_case)]
+
+use dioxus::prelude::*;
+use dioxus_devtools::{native, Devtools, DevtoolsCommand, DevtoolsMessage};
+use futures_util::{SinkExt, StreamExt};
+use tokio::net::{TcpListener, TcpStream};
+use tokio_tungstenite::{tungstenite::Message, WebSocketStream};
+
+#[component]
+fn Counter() -> Element {
+    let count = use_signal(|| 0);
+    rsx! { "count {count}" }
+}
+
+fn app() -> Element {
+    rsx! { Counter {} }
+}
+
+async fn next_message(client: &mut WebSocketStream<TcpStream>) -> DevtoolsMessage {
+    loop {
+        if let Message::Text(text) = client.next().await.unwrap().unwrap() {
+            return serde_json::from_str(&text).unwrap();
+        }
+    }
+}
+
+#[tokio::test]
+async fn websocket_clients_inspect_and_rerender_components() {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("ws://{}", listener.local_addr().unwrap());
+
+    let mut dom = VirtualDom::new(app);
+    let devtools = Devtools::install(&dom);
+    dom.rebuild(&mut dioxus_core::NoOpMutations);
+    devtools.update(&mut dom);
+    native::connect(&devtools, &url);
+
+    let (stream, _) = listener.accept().await.unwrap();
+    let mut client = tokio_tungstenite::accept_async(stream).await.unwrap();
+
+    let DevtoolsMessage::Tree(tree) = next_message(&mut client).await else {
+        panic!("the client gets the tree first");
+    };
+    let counter = tree[1].clone();
+    assert_eq!(counter.name, "Counter");
+    assert_eq!(counter.hooks[0].value.as_deref(), Some("0"));
+    assert_eq!(
+        next_message(&mut client).await,
+        DevtoolsMessage::Highlighted(None)
+    );
+
+    let command = serde_json::to_string(&DevtoolsCommand::Rerender(counter.id)).unwrap();
+    client.send(Message::Text(command)).await.unwrap();
+    dom.wait_for_work().await;
+    dom.render_immediate(&mut dioxus_core::NoOpMutations);
+    devtools.update(&mut dom);
+
+    let DevtoolsMessage::Tree(tree) = next_message(&mut client).await else {
+        panic!("the tree changed");
+    };
+    assert_eq!(tree[1].render_count, 2);
+}
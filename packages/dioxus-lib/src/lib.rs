@@ -33,7 +33,7 @@ pub mod prelude {
 
     #[cfg(feature = "macro")]
     #[allow(deprecated)]
-    pub use dioxus_core_macro::{component, format_args_f, inline_props, render, rsx, Props};
+    pub use dioxus_core_macro::{component, format_args_f, html, inline_props, render, rsx, Props};
 
     #[cfg(feature = "launch")]
     pub use dioxus_config_macro::*;
@@ -0,0 +1,32 @@
+use std::io::Write;
+
+use base64::Engine;
+use dioxus_hooks::{ClipboardError, ClipboardFuture, ClipboardProvider};
+
+/// Copies text with the OSC 52 escape sequence, which is forwarded to the clipboard of the machine the terminal runs on, even over ssh.
+///
+/// Terminals don't answer requests to read the clipboard, or ask the user first, so the clipboard can't be read.
+pub(crate) struct Osc52Clipboard;
+
+impl ClipboardProvider for Osc52Clipboard {
+    fn read_text(&self) -> ClipboardFuture<String> {
+        Box::pin(async { Err(ClipboardError::Unsupported) })
+    }
+
+    fn write_text(&self, text: String) -> ClipboardFuture<()> {
+        let result = write_osc52(&text).map_err(|err| ClipboardError::Failed(err.to_string()));
+        Box::pin(async move { result })
+    }
+}
+
+fn write_osc52(text: &str) -> std::io::Result<()> {
+    let encoded = base64::engine::general_purpose::STANDARD.encode(text);
+    let mut sequence = format!("\x1b]52;c;{encoded}\x07");
+    // tmux only forwards the sequence to the terminal when it is wrapped in a passthrough sequence
+    if std::env::var_os("TMUX").is_some() {
+        sequence = format!("\x1bPtmux;{}\x1b\\", sequence.replace('\x1b', "\x1b\x1b"));
+    }
+    let mut stdout = std::io::stdout();
+    stdout.write_all(sequence.as_bytes())?;
+    stdout.flush()
+}
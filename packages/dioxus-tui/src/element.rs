@@ -1,12 +1,16 @@
 use std::{
     any::Any,
     fmt::{Display, Formatter},
+    time::Duration,
 };
 
-use dioxus_core::{ElementId, WriteMutations};
+use dioxus_core::{ElementId, ScopeId, WriteMutations};
 use dioxus_html::{
-    geometry::euclid::{Point2D, Rect, Size2D},
-    MountedData, MountedError, RenderedElementBacking,
+    geometry::{
+        euclid::{Point2D, Rect, Size2D},
+        PixelsSize, PixelsVector2D,
+    },
+    MountedError, RenderedElementBacking, ScrollBehavior,
 };
 
 use dioxus_native_core::{dioxus::DioxusNativeCoreMutationWriter, NodeId};
@@ -15,6 +19,7 @@ use plasmo::query::{ElementRef, Query};
 pub(crate) struct DioxusTUIMutationWriter<'a> {
     pub(crate) query: Query,
     pub(crate) events: &'a mut Vec<(ElementId, &'static str, Box<dyn Any>, bool)>,
+    pub(crate) exits: &'a mut Vec<(ScopeId, Duration)>,
     pub(crate) native_core_writer: DioxusNativeCoreMutationWriter<'a>,
 }
 
@@ -84,8 +89,7 @@ impl WriteMutations for DioxusTUIMutationWriter<'_> {
                 query: self.query.clone(),
                 id: self.native_core_writer.state.element_to_node_id(id),
             };
-            self.events
-                .push((id, "mounted", Box::new(MountedData::new(element)), false));
+            self.events.push((id, "mounted", Box::new(element), false));
         } else {
             self.native_core_writer.create_event_listener(name, id)
         }
@@ -102,6 +106,18 @@ impl WriteMutations for DioxusTUIMutationWriter<'_> {
     fn push_root(&mut self, id: ElementId) {
         self.native_core_writer.push_root(id)
     }
+
+    fn create_subtree_root(&mut self, subtree: usize, target: &str) {
+        self.native_core_writer.create_subtree_root(subtree, target)
+    }
+
+    fn append_to_subtree(&mut self, subtree: usize, m: usize) {
+        self.native_core_writer.append_to_subtree(subtree, m)
+    }
+
+    fn wait_for_exit(&mut self, scope: ScopeId, _id: ElementId, duration: Duration) {
+        self.exits.push((scope, duration));
+    }
 }
 
 #[derive(Clone)]
@@ -141,6 +157,60 @@ impl RenderedElementBacking for TuiElement {
         })
     }
 
+    fn get_scroll_offset(
+        &self,
+    ) -> std::pin::Pin<Box<dyn futures::Future<Output = dioxus_html::MountedResult<PixelsVector2D>>>>
+    {
+        let offset = self.element().scroll_offset();
+        Box::pin(async move { Ok(PixelsVector2D::new(offset.x as f64, offset.y as f64)) })
+    }
+
+    fn get_scroll_size(
+        &self,
+    ) -> std::pin::Pin<Box<dyn futures::Future<Output = dioxus_html::MountedResult<PixelsSize>>>>
+    {
+        let size = self.element().scroll_size();
+        Box::pin(async move { Ok(PixelsSize::new(size.width as f64, size.height as f64)) })
+    }
+
+    fn scroll(
+        &self,
+        offset: PixelsVector2D,
+        _behavior: ScrollBehavior,
+    ) -> std::pin::Pin<Box<dyn futures::Future<Output = dioxus_html::MountedResult<()>>>> {
+        // the terminal cannot animate scrolling, so every scroll is instant
+        self.query.scroll_to(
+            self.id,
+            plasmo::Point {
+                x: offset.x as f32,
+                y: offset.y as f32,
+            },
+        );
+        Box::pin(async { Ok(()) })
+    }
+
+    fn scroll_to(
+        &self,
+        _behavior: ScrollBehavior,
+    ) -> std::pin::Pin<Box<dyn futures::Future<Output = dioxus_html::MountedResult<()>>>> {
+        self.query.scroll_into_view(self.id);
+        Box::pin(async { Ok(()) })
+    }
+
+    fn set_focus(
+        &self,
+        focus: bool,
+    ) -> std::pin::Pin<Box<dyn futures::Future<Output = dioxus_html::MountedResult<()>>>> {
+        let result = if self.query.set_focus(self.id, focus) {
+            Ok(())
+        } else {
+            Err(MountedError::OperationFailed(Box::new(
+                TuiElementNotFocusable,
+            )))
+        };
+        Box::pin(async { result })
+    }
+
     fn as_any(&self) -> &dyn std::any::Any {
         self
     }
@@ -156,3 +226,17 @@ impl Display for TuiElementNotFound {
 }
 
 impl std::error::Error for TuiElementNotFound {}
+
+#[derive(Debug)]
+struct TuiElementNotFocusable;
+
+impl Display for TuiElementNotFocusable {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "TUI element cannot be focused. Give it a tabindex to make it focusable"
+        )
+    }
+}
+
+impl std::error::Error for TuiElementNotFocusable {}
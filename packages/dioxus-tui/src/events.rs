@@ -78,8 +78,12 @@ impl HtmlEventConverter for SerializedHtmlEventConverter {
         panic!("pointer events not supported")
     }
 
-    fn convert_scroll_data(&self, _: &PlatformEventData) -> ScrollData {
-        panic!("scroll events not supported")
+    fn convert_scroll_data(&self, event: &PlatformEventData) -> ScrollData {
+        if let plasmo::EventData::Scroll(event) = downcast(event) {
+            ScrollData::new(event)
+        } else {
+            panic!("event should be of type Scroll")
+        }
     }
 
     fn convert_selection_data(&self, _: &PlatformEventData) -> SelectionData {
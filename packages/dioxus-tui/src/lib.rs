@@ -2,24 +2,32 @@
 #![doc(html_logo_url = "https://avatars.githubusercontent.com/u/79236386")]
 #![doc(html_favicon_url = "https://avatars.githubusercontent.com/u/79236386")]
 
+mod clipboard;
 mod element;
 mod events;
+mod shortcut;
 
 use std::{
     any::Any,
-    ops::Deref,
+    ops::{Deref, DerefMut},
     rc::Rc,
-    sync::{Arc, RwLock},
+    sync::{Arc, Mutex, RwLock},
+    time::Duration,
 };
 
 use dioxus_core::{Element, ElementId, ScopeId, VirtualDom};
+use dioxus_hooks::{ClipboardProvider, ShortcutContext};
 use dioxus_html::PlatformEventData;
 use dioxus_native_core::dioxus::{DioxusState, NodeImmutableDioxusExt};
 use dioxus_native_core::prelude::*;
 
 use element::DioxusTUIMutationWriter;
-pub use plasmo::{query::Query, Config, RenderingMode, Size, TuiContext};
-use plasmo::{render, Driver};
+use futures::channel::mpsc::UnboundedSender;
+pub use plasmo::{
+    query::Query, Config, GraphicsProtocol, LayoutStats, OutputMode, RenderingMode, Size,
+    TestHarness, TuiContext,
+};
+use plasmo::{render, Driver, InputEvent, Taffy};
 
 pub mod launch {
     use super::*;
@@ -56,7 +64,63 @@ pub fn launch_cfg_with_props<P: Clone + 'static>(app: fn(P) -> Element, props: P
 pub fn launch_vdom_cfg(vdom: VirtualDom, cfg: Config) {
     dioxus_html::set_event_converter(Box::new(events::SerializedHtmlEventConverter));
 
-    render(cfg, |rdom, taffy, event_tx| {
+    // The test harness doesn't provide a clipboard because it doesn't render into a real terminal
+    let clipboard: Rc<dyn ClipboardProvider> = Rc::new(clipboard::Osc52Clipboard);
+    let vdom = vdom.with_root_context(clipboard);
+
+    render(cfg, create_renderer(vdom)).unwrap();
+}
+
+/// Renders an app into an in-memory buffer instead of the terminal so the output can be tested.
+///
+/// ```rust
+/// use dioxus::prelude::*;
+/// use dioxus_tui::TuiTestHarness;
+///
+/// fn app() -> Element {
+///     rsx! { "Hello world" }
+/// }
+///
+/// let harness = TuiTestHarness::new(app, 20, 1);
+/// assert_eq!(harness.line(0).trim_end(), "Hello world");
+/// ```
+///
+/// Inject terminal events with [`TestHarness::inject_event`] or with the [`TuiContext`] inside of the app, then call [`TestHarness::update`] to handle them and draw the app again.
+pub struct TuiTestHarness(TestHarness);
+
+impl TuiTestHarness {
+    /// Create a harness for an app that renders into a buffer with the given size in cells
+    pub fn new(app: fn() -> Element, width: u16, height: u16) -> Self {
+        Self::with_vdom(VirtualDom::new(app), Config::new(), width, height)
+    }
+
+    /// Create a harness for a virtual dom with a config that renders into a buffer with the given size in cells
+    pub fn with_vdom(vdom: VirtualDom, cfg: Config, width: u16, height: u16) -> Self {
+        dioxus_html::set_event_converter(Box::new(events::SerializedHtmlEventConverter));
+
+        Self(TestHarness::new(cfg, width, height, create_renderer(vdom)))
+    }
+}
+
+impl Deref for TuiTestHarness {
+    type Target = TestHarness;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for TuiTestHarness {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+fn create_renderer(
+    vdom: VirtualDom,
+) -> impl FnOnce(&Arc<RwLock<RealDom>>, &Arc<Mutex<Taffy>>, UnboundedSender<InputEvent>) -> DioxusRenderer
+{
+    move |rdom, taffy, event_tx| {
         let dioxus_state = {
             let mut rdom = rdom.write().unwrap();
             DioxusState::create(&mut rdom)
@@ -71,10 +135,15 @@ pub fn launch_vdom_cfg(vdom: VirtualDom, cfg: Config) {
 
         let queued_events = Vec::new();
 
+        // Shortcuts are matched against every key press, so there is nothing to register with the terminal
+        let shortcuts = vdom.in_runtime(|| ShortcutContext::provide(|_, _, _| Ok(())));
+
         let mut myself = DioxusRenderer {
             vdom,
             dioxus_state,
+            shortcuts,
             queued_events,
+            queued_exits: Vec::new(),
             #[cfg(all(feature = "hot-reload", debug_assertions))]
             hot_reload_rx: {
                 let (hot_reload_tx, hot_reload_rx) =
@@ -95,6 +164,7 @@ pub fn launch_vdom_cfg(vdom: VirtualDom, cfg: Config) {
                     .vdom
                     .in_runtime(|| ScopeId::ROOT.consume_context().unwrap()),
                 events: &mut myself.queued_events,
+                exits: &mut myself.queued_exits,
                 native_core_writer: dioxus_state.create_mutation_writer(&mut rdom),
             };
 
@@ -103,21 +173,26 @@ pub fn launch_vdom_cfg(vdom: VirtualDom, cfg: Config) {
         }
 
         myself
-    })
-    .unwrap();
+    }
 }
 
 struct DioxusRenderer {
     vdom: VirtualDom,
     dioxus_state: Rc<RwLock<DioxusState>>,
+    shortcuts: ShortcutContext,
     // Events that are queued up to be sent to the vdom next time the vdom is polled
     queued_events: Vec<(ElementId, &'static str, Box<dyn Any>, bool)>,
+    // The exit animations of removed presences that are waiting to be started
+    queued_exits: Vec<(ScopeId, Duration)>,
     #[cfg(all(feature = "hot-reload", debug_assertions))]
     hot_reload_rx: tokio::sync::mpsc::UnboundedReceiver<dioxus_hot_reload::HotReloadMsg>,
 }
 
 impl Driver for DioxusRenderer {
     fn update(&mut self, rdom: &Arc<RwLock<RealDom>>) {
+        // Poll the tasks that are ready before locking the dom. Tasks can query elements, which locks the dom
+        self.vdom.process_events();
+
         let mut rdom = rdom.write().unwrap();
         let mut dioxus_state = self.dioxus_state.write().unwrap();
 
@@ -126,6 +201,7 @@ impl Driver for DioxusRenderer {
                 .vdom
                 .in_runtime(|| ScopeId::ROOT.consume_context().unwrap()),
             events: &mut self.queued_events,
+            exits: &mut self.queued_exits,
             native_core_writer: dioxus_state.create_mutation_writer(&mut rdom),
         };
 
@@ -151,6 +227,13 @@ impl Driver for DioxusRenderer {
         }
     }
 
+    fn handle_key(&mut self, event: &crossterm::event::KeyEvent) -> bool {
+        match shortcut::shortcut_from_key(event) {
+            Some(shortcut) => self.vdom.in_runtime(|| self.shortcuts.dispatch(&shortcut)),
+            None => false,
+        }
+    }
+
     fn poll_async(&mut self) -> std::pin::Pin<Box<dyn futures::Future<Output = ()> + '_>> {
         // Add any queued events
         for (id, event, value, bubbles) in self.queued_events.drain(..) {
@@ -159,6 +242,16 @@ impl Driver for DioxusRenderer {
                 .handle_event(event, Rc::new(platform_event), id, bubbles);
         }
 
+        // The terminal can't animate, so exit animations end after their duration
+        for (scope, duration) in self.queued_exits.drain(..) {
+            self.vdom.in_runtime(|| {
+                scope.spawn(async move {
+                    tokio::time::sleep(duration).await;
+                    scope.finish_exit();
+                })
+            });
+        }
+
         #[cfg(all(feature = "hot-reload", debug_assertions))]
         return Box::pin(async {
             let hot_reload_wait = self.hot_reload_rx.recv();
@@ -181,6 +274,8 @@ impl Driver for DioxusRenderer {
                     dioxus_hot_reload::HotReloadMsg::UpdateTemplate(template) => {
                         self.vdom.replace_template(template);
                     }
+                    // The terminal renderer doesn't load any assets
+                    dioxus_hot_reload::HotReloadMsg::UpdateAsset(_) => {}
                     dioxus_hot_reload::HotReloadMsg::Shutdown => {
                         std::process::exit(0);
                     }
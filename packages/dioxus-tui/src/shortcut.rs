@@ -0,0 +1,43 @@
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use dioxus_hooks::{Shortcut, ShortcutModifiers};
+
+/// Convert a key press of crossterm to the shortcut it could trigger
+pub(crate) fn shortcut_from_key(event: &KeyEvent) -> Option<Shortcut> {
+    let mut modifiers = ShortcutModifiers {
+        ctrl: event.modifiers.contains(KeyModifiers::CONTROL),
+        alt: event.modifiers.contains(KeyModifiers::ALT),
+        shift: event.modifiers.contains(KeyModifiers::SHIFT),
+        meta: event.modifiers.contains(KeyModifiers::SUPER)
+            || event.modifiers.contains(KeyModifiers::META),
+    };
+    let key = match event.code {
+        KeyCode::Char(c) => {
+            // terminals report shifted letters as upper case, without the shift modifier
+            if c.is_ascii_uppercase() {
+                modifiers.shift = true;
+            }
+            c.to_string()
+        }
+        KeyCode::F(number) => format!("f{number}"),
+        KeyCode::Enter => "enter".to_string(),
+        KeyCode::Esc => "escape".to_string(),
+        KeyCode::Tab => "tab".to_string(),
+        KeyCode::BackTab => {
+            modifiers.shift = true;
+            "tab".to_string()
+        }
+        KeyCode::Backspace => "backspace".to_string(),
+        KeyCode::Delete => "delete".to_string(),
+        KeyCode::Insert => "insert".to_string(),
+        KeyCode::Home => "home".to_string(),
+        KeyCode::End => "end".to_string(),
+        KeyCode::PageUp => "pageup".to_string(),
+        KeyCode::PageDown => "pagedown".to_string(),
+        KeyCode::Up => "arrowup".to_string(),
+        KeyCode::Down => "arrowdown".to_string(),
+        KeyCode::Left => "arrowleft".to_string(),
+        KeyCode::Right => "arrowright".to_string(),
+        _ => return None,
+    };
+    Shortcut::new(modifiers, &key).ok()
+}
@@ -0,0 +1,336 @@
+use crossterm::event::{
+    Event, KeyCode, KeyEvent, KeyEventKind, KeyEventState, KeyModifiers, MouseEvent, MouseEventKind,
+};
+use dioxus::prelude::*;
+use dioxus_tui::TuiTestHarness;
+use ratatui::style::Color;
+
+fn key(code: KeyCode) -> Event {
+    key_with_modifiers(code, KeyModifiers::NONE)
+}
+
+fn key_with_modifiers(code: KeyCode, modifiers: KeyModifiers) -> Event {
+    Event::Key(KeyEvent {
+        code,
+        modifiers,
+        kind: KeyEventKind::Press,
+        state: KeyEventState::NONE,
+    })
+}
+
+#[test]
+fn draws_into_buffer() {
+    fn app() -> Element {
+        rsx! {
+            div {
+                width: "100%",
+                height: "100%",
+                flex_direction: "column",
+                div { color: "red", "hello" }
+                div { background_color: "blue", "world" }
+            }
+        }
+    }
+
+    let harness = TuiTestHarness::new(app, 10, 2);
+    assert_eq!(harness.line(0), "hello     ");
+    assert_eq!(harness.line(1), "world     ");
+    assert_eq!(harness.cell(0, 0).fg, Color::Rgb(255, 0, 0));
+    assert_eq!(harness.cell(0, 1).bg, Color::Rgb(0, 0, 255));
+    assert_eq!(harness.cursor(), None);
+}
+
+#[test]
+fn injected_events_update_the_buffer() {
+    fn app() -> Element {
+        let mut count = use_signal(|| 0);
+        rsx! {
+            div {
+                width: "100%",
+                height: "100%",
+                tabindex: "0",
+                onkeydown: move |_| count += 1,
+                "count: {count}"
+            }
+        }
+    }
+
+    let mut harness = TuiTestHarness::new(app, 10, 1);
+    assert_eq!(harness.line(0).trim_end(), "count: 0");
+
+    // focus the element, then press a key
+    harness.inject_event(key(KeyCode::Tab));
+    harness.inject_event(key(KeyCode::Char('a')));
+    harness.update();
+    assert_eq!(harness.line(0).trim_end(), "count: 1");
+
+    // events sent with the context of the app are handled the same way
+    harness.context().inject_event(key(KeyCode::Char('b')));
+    harness.update();
+    assert_eq!(harness.line(0).trim_end(), "count: 2");
+}
+
+#[test]
+fn shortcuts_are_handled_before_the_focused_element() {
+    fn app() -> Element {
+        let mut keys = use_signal(|| 0);
+        let mut shortcuts = use_signal(|| 0);
+        _ = use_shortcut("ctrl+k", move || shortcuts += 1);
+        rsx! {
+            div {
+                width: "100%",
+                height: "100%",
+                tabindex: "0",
+                onkeydown: move |_| keys += 1,
+                "{keys} {shortcuts}"
+            }
+        }
+    }
+
+    let mut harness = TuiTestHarness::new(app, 10, 1);
+    harness.inject_event(key(KeyCode::Tab));
+    harness.inject_event(key(KeyCode::Char('k')));
+    harness.update();
+    assert_eq!(harness.line(0).trim_end(), "1 0");
+
+    // the element doesn't receive the key of the shortcut
+    harness.inject_event(key_with_modifiers(
+        KeyCode::Char('k'),
+        KeyModifiers::CONTROL,
+    ));
+    harness.update();
+    assert_eq!(harness.line(0).trim_end(), "1 1");
+}
+
+#[test]
+fn text_inputs_move_the_cursor() {
+    fn app() -> Element {
+        rsx! {
+            input { width: "10px", height: "3px", value: "" }
+        }
+    }
+
+    let mut harness = TuiTestHarness::new(app, 10, 3);
+    harness.inject_event(key(KeyCode::Tab));
+    for c in "abc".chars() {
+        harness.inject_event(key(KeyCode::Char(c)));
+    }
+    harness.update();
+    assert_eq!(harness.line(1), "│abc     │");
+    // the cursor is after the text, inside of the border
+    assert_eq!(harness.cursor(), Some((4, 1)));
+}
+
+#[test]
+fn text_inputs_send_input_and_change_events() {
+    fn app() -> Element {
+        let mut input = use_signal(String::new);
+        let mut change = use_signal(String::new);
+        rsx! {
+            div {
+                width: "100%",
+                height: "100%",
+                flex_direction: "column",
+                input {
+                    width: "10px",
+                    height: "3px",
+                    oninput: move |evt| input.set(evt.value()),
+                    onchange: move |evt| change.set(evt.value()),
+                }
+                "input: {input}"
+                "change: {change}"
+            }
+        }
+    }
+
+    let mut harness = TuiTestHarness::new(app, 20, 5);
+    harness.inject_event(key(KeyCode::Tab));
+    for c in "abc".chars() {
+        harness.inject_event(key(KeyCode::Char(c)));
+    }
+    harness.update();
+    assert_eq!(harness.line(3).trim_end(), "input: abc");
+    assert_eq!(harness.line(4).trim_end(), "change:");
+
+    // typing replaces the text selected with shift and the arrow keys
+    harness.inject_event(key_with_modifiers(KeyCode::Left, KeyModifiers::SHIFT));
+    harness.inject_event(key_with_modifiers(KeyCode::Left, KeyModifiers::SHIFT));
+    harness.inject_event(key(KeyCode::Char('d')));
+    harness.update();
+    assert_eq!(harness.line(1), "│ad      │          ");
+    assert_eq!(harness.line(3).trim_end(), "input: ad");
+
+    // enter commits the text
+    harness.inject_event(key(KeyCode::Enter));
+    harness.update();
+    assert_eq!(harness.line(4).trim_end(), "change: ad");
+}
+
+#[test]
+fn textareas_edit_multiple_lines() {
+    fn app() -> Element {
+        let mut value = use_signal(String::new);
+        rsx! {
+            div {
+                width: "100%",
+                height: "100%",
+                flex_direction: "column",
+                textarea {
+                    width: "10px",
+                    height: "4px",
+                    oninput: move |evt| value.set(evt.value()),
+                }
+                "{value:?}"
+            }
+        }
+    }
+
+    let mut harness = TuiTestHarness::new(app, 10, 5);
+    harness.inject_event(key(KeyCode::Tab));
+    for c in "ab".chars() {
+        harness.inject_event(key(KeyCode::Char(c)));
+    }
+    harness.inject_event(key(KeyCode::Enter));
+    for c in "cd".chars() {
+        harness.inject_event(key(KeyCode::Char(c)));
+    }
+    harness.update();
+    assert_eq!(harness.line(1), "│ab      │");
+    assert_eq!(harness.line(2), "│cd      │");
+    assert_eq!(harness.line(4).trim_end(), "\"ab\\ncd\"");
+    // the cursor is at the end of the second line
+    assert_eq!(harness.cursor(), Some((3, 2)));
+
+    // backspace at the start of a line joins it with the line above
+    harness.inject_event(key(KeyCode::Home));
+    harness.inject_event(key(KeyCode::Backspace));
+    harness.update();
+    assert_eq!(harness.line(1), "│abcd    │");
+    assert_eq!(harness.line(2), "│        │");
+    assert_eq!(harness.cursor(), Some((3, 1)));
+}
+
+#[test]
+fn images_fall_back_to_half_blocks() {
+    fn image_path() -> String {
+        std::env::temp_dir()
+            .join("dioxus_tui_half_blocks.png")
+            .to_string_lossy()
+            .into_owned()
+    }
+
+    fn app() -> Element {
+        rsx! {
+            img { src: image_path(), width: "1px", height: "2px" }
+        }
+    }
+
+    // one pixel wide and four pixels tall, which is two cells
+    let rows = [[255, 0, 0], [0, 255, 0], [0, 0, 255], [255, 255, 255]];
+    image::RgbImage::from_fn(1, 4, |_, y| image::Rgb(rows[y as usize]))
+        .save(image_path())
+        .unwrap();
+
+    let harness = TuiTestHarness::new(app, 1, 2);
+    // the top pixel is the foreground of the half block and the bottom pixel is the background
+    assert_eq!(harness.cell(0, 0).symbol, "▀");
+    assert_eq!(harness.cell(0, 0).fg, Color::Rgb(255, 0, 0));
+    assert_eq!(harness.cell(0, 0).bg, Color::Rgb(0, 255, 0));
+    assert_eq!(harness.cell(0, 1).fg, Color::Rgb(0, 0, 255));
+    assert_eq!(harness.cell(0, 1).bg, Color::Rgb(255, 255, 255));
+}
+
+#[test]
+fn virtual_lists_render_the_rows_in_view() {
+    fn app() -> Element {
+        let handle = dioxus_virtualization::use_virtual_list();
+        rsx! {
+            div {
+                width: "100%",
+                height: "100%",
+                tabindex: "0",
+                onkeydown: move |_| handle.scroll_to_index(50, ScrollBehavior::Instant),
+                dioxus_virtualization::VirtualList {
+                    len: 1000,
+                    row_height: 1.0,
+                    overscan: 1,
+                    sticky: vec![0],
+                    handle,
+                    render_row: move |index| rsx! { div { "row {index}" } }
+                }
+            }
+        }
+    }
+
+    let mut harness = TuiTestHarness::new(app, 10, 3);
+    harness.update();
+    assert_eq!(harness.line(0).trim_end(), "row 0");
+    assert_eq!(harness.line(2).trim_end(), "row 2");
+
+    // the wheel scrolls the container, which renders the rows that scrolled into view
+    for kind in [MouseEventKind::Moved, MouseEventKind::ScrollDown] {
+        harness.inject_event(Event::Mouse(MouseEvent {
+            column: 0,
+            row: 1,
+            kind,
+            modifiers: KeyModifiers::NONE,
+        }));
+    }
+    harness.update();
+    // the header sticks to the top of the list
+    assert_eq!(harness.line(0).trim_end(), "row 0");
+    assert_ne!(harness.line(2).trim_end(), "row 2");
+
+    // jump to a row that is not rendered yet
+    harness.inject_event(key(KeyCode::Tab));
+    harness.inject_event(key(KeyCode::Char('a')));
+    harness.update();
+    assert_eq!(harness.line(1).trim_end(), "row 51");
+    assert_eq!(harness.line(2).trim_end(), "row 52");
+}
+
+#[test]
+fn mounted_elements_are_measured_focused_and_scrolled_into_view() {
+    fn app() -> Element {
+        let size = use_element_size();
+        let mut keys = use_signal(|| 0);
+        let (width, height) = size
+            .size()
+            .map(|size| (size.width, size.height))
+            .unwrap_or_default();
+        rsx! {
+            div {
+                width: "100%",
+                height: "2px",
+                flex_direction: "column",
+                overflow: "scroll",
+                div { height: "3px", flex_shrink: "0", "above" }
+                div {
+                    width: "8px",
+                    height: "1px",
+                    flex_shrink: "0",
+                    tabindex: "0",
+                    onmounted: move |event| async move {
+                        size.mount(event).await;
+                        let element = size.element().unwrap();
+                        element.set_focus(true).await.unwrap();
+                        element.scroll_to(ScrollBehavior::Instant).await.unwrap();
+                    },
+                    onkeydown: move |_| keys += 1,
+                    "{width}x{height} {keys}"
+                }
+                div { height: "3px", flex_shrink: "0", "below" }
+            }
+        }
+    }
+
+    let mut harness = TuiTestHarness::new(app, 10, 2);
+    harness.update();
+    // the container scrolled until the element was at the top
+    assert_eq!(harness.line(0).trim_end(), "8x1 0");
+
+    // the element was focused when it was mounted, so it receives keys without tabbing to it
+    harness.inject_event(key(KeyCode::Char('a')));
+    harness.update();
+    assert_eq!(harness.line(0).trim_end(), "8x1 1");
+}
@@ -0,0 +1,42 @@
+use dioxus::prelude::*;
+use dioxus_tui::{Query, TuiContext};
+use std::time::Duration;
+
+#[test]
+fn layout_is_reused_when_only_styles_change() {
+    dioxus_tui::launch_cfg(app, dioxus_tui::Config::new().with_headless());
+
+    fn app() -> Element {
+        let mut color = use_signal(|| 0);
+        let tui_ctx: TuiContext = consume_context();
+        let query: Query = consume_context();
+
+        use_future(move || {
+            let tui_ctx = tui_ctx.clone();
+            let query = query.clone();
+            async move {
+                for _ in 0..5 {
+                    tokio::time::sleep(Duration::from_millis(20)).await;
+                    color += 1;
+                }
+                tokio::time::sleep(Duration::from_millis(20)).await;
+
+                let stats = query.layout_stats();
+                assert!(stats.layouts_computed >= 1);
+                // Changing the color of a node does not change the layout
+                assert!(stats.layouts_reused >= 5, "{stats:?}");
+                assert!(stats.layout_nodes > 0);
+                tui_ctx.quit();
+            }
+        });
+
+        rsx! {
+            div {
+                width: "100%",
+                height: "100%",
+                color: "rgb({color}, 0, 0)",
+                div { "hello world" }
+            }
+        }
+    }
+}
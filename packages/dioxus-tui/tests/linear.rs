@@ -0,0 +1,128 @@
+use crossterm::event::{Event, KeyCode, KeyEvent, KeyEventKind, KeyEventState, KeyModifiers};
+use dioxus::prelude::*;
+use dioxus_tui::{Config, OutputMode, TuiContext, TuiTestHarness};
+
+fn key(code: KeyCode, modifiers: KeyModifiers) -> Event {
+    Event::Key(KeyEvent {
+        code,
+        modifiers,
+        kind: KeyEventKind::Press,
+        state: KeyEventState::NONE,
+    })
+}
+
+fn linear_harness(app: fn() -> Element) -> TuiTestHarness {
+    TuiTestHarness::with_vdom(
+        VirtualDom::new(app),
+        Config::new().with_output_mode(OutputMode::Linear),
+        20,
+        4,
+    )
+}
+
+#[test]
+fn elements_are_printed_in_document_order() {
+    fn app() -> Element {
+        rsx! {
+            div { flex_direction: "row",
+                div {
+                    h1 { "Settings" }
+                    "Theme: " b { "dark" }
+                }
+                div {
+                    button { "Save" }
+                    div { aria_hidden: "true", "decoration" }
+                    input { r#type: "checkbox", checked: "true", aria_label: "Notifications" }
+                    input { r#type: "password", value: "hunter2" }
+                    img { alt: "A cat" }
+                }
+            }
+        }
+    }
+
+    let harness = linear_harness(app);
+    assert_eq!(
+        harness.transcript(),
+        [
+            "[heading 1] Settings",
+            "Theme: dark",
+            "[button] Save",
+            "[checkbox, checked] Notifications",
+            "[password input]",
+            "[image] A cat",
+        ]
+    );
+    // nothing is drawn into the buffer
+    assert_eq!(harness.line(0).trim(), "");
+}
+
+#[test]
+fn only_changes_and_focus_are_printed() {
+    fn app() -> Element {
+        let mut count = use_signal(|| 0);
+        rsx! {
+            div { "Title" }
+            div { "Count: {count}" }
+            div {
+                tabindex: "0",
+                role: "button",
+                onkeydown: move |event| {
+                    if event.key() == Key::Enter {
+                        count += 1;
+                    }
+                },
+                "Increment"
+            }
+        }
+    }
+
+    let mut harness = linear_harness(app);
+    assert_eq!(
+        harness.transcript(),
+        ["Title", "Count: 0", "[button] Increment"]
+    );
+
+    harness.inject_event(key(KeyCode::Tab, KeyModifiers::NONE));
+    harness.update();
+    assert_eq!(harness.transcript()[3..], ["Focused: [button] Increment"]);
+
+    harness.inject_event(key(KeyCode::Enter, KeyModifiers::NONE));
+    harness.update();
+    assert_eq!(harness.transcript()[4..], ["Count: 1"]);
+}
+
+#[test]
+fn output_mode_can_be_toggled() {
+    fn app() -> Element {
+        let tui = consume_context::<TuiContext>();
+        rsx! {
+            div {
+                tabindex: "0",
+                onkeydown: move |_| tui.set_output_mode(OutputMode::Visual),
+                "hello"
+            }
+        }
+    }
+
+    let mut harness = TuiTestHarness::with_vdom(
+        VirtualDom::new(app),
+        Config::new().with_output_mode_toggle(KeyCode::F(2), KeyModifiers::NONE),
+        10,
+        1,
+    );
+    assert_eq!(harness.line(0).trim_end(), "hello");
+    assert!(harness.transcript().is_empty());
+
+    // the toggle key switches to the linear output, which prints the whole app
+    harness.inject_event(key(KeyCode::F(2), KeyModifiers::NONE));
+    harness.update();
+    assert_eq!(harness.output_mode(), OutputMode::Linear);
+    assert_eq!(harness.transcript(), ["hello"]);
+
+    // the app can switch back
+    harness.inject_event(key(KeyCode::Tab, KeyModifiers::NONE));
+    harness.inject_event(key(KeyCode::Char('a'), KeyModifiers::NONE));
+    harness.update();
+    assert_eq!(harness.output_mode(), OutputMode::Visual);
+    assert_eq!(harness.line(0).trim_end(), "hello");
+}
@@ -0,0 +1,101 @@
+#![allow(non_snake_case)]
+//! This benchmark measures how long a click takes to show up while a lot of background work is waiting to render.
+//!
+//! Every row below the button re-renders when the background signal changes. The click is rendered either with the
+//! rest of the work, or on its own with `render_with_priority` before the background work.
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use dioxus::prelude::*;
+use dioxus_core::{ElementId, EventPriority, NoOpMutations};
+use std::rc::Rc;
+use std::time::{Duration, Instant};
+
+criterion_group!(mbenches, input_latency);
+criterion_main!(mbenches);
+
+static BACKGROUND: GlobalSignal<usize> = Signal::global(|| 0);
+
+fn input_latency(c: &mut Criterion) {
+    set_event_converter(Box::new(dioxus::html::SerializedHtmlEventConverter));
+
+    let mut group = c.benchmark_group("input latency under load");
+
+    group.bench_function("render every lane", |b| {
+        let mut dom = VirtualDom::new(app);
+        dom.rebuild(&mut NoOpMutations);
+
+        b.iter_custom(|iters| {
+            let mut latency = Duration::ZERO;
+            for _ in 0..iters {
+                load(&mut dom);
+                let start = Instant::now();
+                click(&mut dom);
+                dom.render_immediate(&mut NoOpMutations);
+                latency += start.elapsed();
+            }
+            latency
+        })
+    });
+
+    group.bench_function("render the input lane first", |b| {
+        let mut dom = VirtualDom::new(app);
+        dom.rebuild(&mut NoOpMutations);
+
+        b.iter_custom(|iters| {
+            let mut latency = Duration::ZERO;
+            for _ in 0..iters {
+                load(&mut dom);
+                let start = Instant::now();
+                click(&mut dom);
+                dom.render_with_priority(&mut NoOpMutations, EventPriority::Immediate, || false);
+                latency += start.elapsed();
+                // The background work is rendered after the click is shown
+                dom.render_immediate(&mut NoOpMutations);
+            }
+            latency
+        })
+    });
+
+    group.finish();
+}
+
+/// Mark every row dirty from outside an event
+fn load(dom: &mut VirtualDom) {
+    dom.in_runtime(|| *BACKGROUND.write() += 1);
+}
+
+fn click(dom: &mut VirtualDom) {
+    dom.handle_event(
+        "click",
+        Rc::new(PlatformEventData::new(Box::<SerializedMouseData>::default())),
+        ElementId(1),
+        true,
+    );
+}
+
+fn app() -> Element {
+    rsx! {
+        Clicker {}
+        for row in 0..1_000_usize {
+            BackgroundRow { key: "{row}", row }
+        }
+    }
+}
+
+fn Clicker() -> Element {
+    let mut clicks = use_signal(|| 0);
+    rsx! {
+        button { onclick: move |_| clicks += 1, "{clicks}" }
+    }
+}
+
+#[component]
+fn BackgroundRow(row: usize) -> Element {
+    let background = BACKGROUND();
+    rsx! {
+        tr {
+            td { "{row}" }
+            td { "{background}" }
+        }
+    }
+}
@@ -27,6 +27,10 @@ pub mod events {
 #[cfg_attr(docsrs, doc(cfg(feature = "html")))]
 pub use dioxus_html as html;
 
+#[cfg(feature = "profiler")]
+#[cfg_attr(docsrs, doc(cfg(feature = "profiler")))]
+pub use dioxus_profiler as profiler;
+
 #[cfg(feature = "macro")]
 #[cfg_attr(docsrs, doc(cfg(feature = "macro")))]
 pub use dioxus_core_macro as core_macro;
@@ -49,7 +53,7 @@ pub mod prelude {
     #[cfg(feature = "macro")]
     #[cfg_attr(docsrs, doc(cfg(feature = "macro")))]
     #[allow(deprecated)]
-    pub use dioxus_core_macro::{component, format_args_f, inline_props, render, rsx, Props};
+    pub use dioxus_core_macro::{component, format_args_f, html, inline_props, render, rsx, Props};
 
     #[cfg(feature = "launch")]
     #[cfg_attr(docsrs, doc(cfg(feature = "launch")))]
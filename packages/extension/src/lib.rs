@@ -78,11 +78,8 @@ pub fn format_file(contents: String, use_tabs: bool, indent_size: usize) -> Form
 
 #[wasm_bindgen]
 pub fn translate_rsx(contents: String, _component: bool) -> String {
-    // Ensure we're loading valid HTML
-    let dom = html_parser::Dom::parse(&contents).unwrap();
-
-    let callbody = rsx_rosetta::rsx_from_html(&dom);
-
     // Convert the HTML to RSX
-    dioxus_autofmt::write_block_out(callbody).unwrap()
+    let conversion = rsx_rosetta::convert_html(&contents, &rsx_rosetta::ConvertOptions::new());
+
+    dioxus_autofmt::write_block_out(conversion.body).unwrap()
 }
@@ -69,7 +69,11 @@ use http::header::*;
 use std::sync::Arc;
 
 use crate::{
-    prelude::*, render::SSRState, serve_config::ServeConfig, server_context::DioxusServerContext,
+    middleware::{ServerFnCall, ServerFnMiddlewareStack},
+    prelude::*,
+    render::SSRState,
+    serve_config::ServeConfig,
+    server_context::DioxusServerContext,
 };
 
 /// A extension trait with utilities for integrating Dioxus with your Axum router.
@@ -97,6 +101,37 @@ pub trait DioxusRouterExt<S> {
     /// ```
     fn register_server_fns(self) -> Self;
 
+    /// Registers server functions with the default handler, running the middleware before and after every server function.
+    ///
+    /// # Example
+    /// ```rust
+    /// use dioxus_lib::prelude::*;
+    /// use dioxus_fullstack::prelude::*;
+    ///
+    /// struct Logger;
+    ///
+    /// #[async_trait::async_trait]
+    /// impl ServerFnMiddleware for Logger {
+    ///     async fn after(&self, call: &ServerFnCall, status: http::StatusCode) {
+    ///         println!("{} {}", call.path(), status);
+    ///     }
+    /// }
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let addr = std::net::SocketAddr::from(([127, 0, 0, 1], 8080));
+    ///     axum::Server::bind(&addr)
+    ///         .serve(
+    ///             axum::Router::new()
+    ///                 .register_server_fns_with_middleware(ServerFnMiddlewareStack::new().layer(Logger))
+    ///                 .into_make_service(),
+    ///         )
+    ///         .await
+    ///         .unwrap();
+    /// }
+    /// ```
+    fn register_server_fns_with_middleware(self, middleware: ServerFnMiddlewareStack) -> Self;
+
     /// Register the web RSX hot reloading endpoint. This will enable hot reloading for your application in debug mode when you call [`dioxus_hot_reload::hot_reload_init`].
     ///
     /// # Example
@@ -189,12 +224,17 @@ impl<S> DioxusRouterExt<S> for Router<S>
 where
     S: Send + Sync + Clone + 'static,
 {
-    fn register_server_fns(mut self) -> Self {
+    fn register_server_fns(self) -> Self {
+        self.register_server_fns_with_middleware(ServerFnMiddlewareStack::new())
+    }
+
+    fn register_server_fns_with_middleware(mut self, middleware: ServerFnMiddlewareStack) -> Self {
         use http::method::Method;
 
         for (path, method) in server_fn::axum::server_fn_paths() {
             tracing::trace!("Registering server function: {} {}", method, path);
-            let handler = move |req| handle_server_fns_inner(path, || {}, req);
+            let middleware = middleware.clone();
+            let handler = move |req| handle_server_fns_inner(path, || {}, middleware, req);
             self = match method {
                 Method::GET => self.route(path, get(handler)),
                 Method::POST => self.route(path, post(handler)),
@@ -257,7 +297,7 @@ where
         // Add server functions and render index.html
         self.serve_static_assets(cfg.assets_path.clone())
             .connect_hot_reload()
-            .register_server_fns()
+            .register_server_fns_with_middleware(cfg.server_fn_middleware.clone())
             .fallback(get(render_handler).with_state((cfg, Arc::new(build_virtual_dom), ssr_state)))
     }
 
@@ -281,6 +321,15 @@ where
                             })
                         }),
                     )
+                    .route(
+                        "/disconnect.js",
+                        get(|| async {
+                            (
+                                [(CONTENT_TYPE, "application/javascript")],
+                                include_str!("./assets/disconnect.js"),
+                            )
+                        }),
+                    )
                     .route("/hot_reload", get(hot_reload_handler)),
             )
         }
@@ -361,19 +410,79 @@ pub async fn render_handler_with_context<F: FnMut(&mut DioxusServerContext)>(
 ) -> impl IntoResponse {
     let (parts, _) = request.into_parts();
     let url = parts.uri.path_and_query().unwrap().to_string();
+    let request_headers = parts.headers.clone();
     let parts: Arc<tokio::sync::RwLock<http::request::Parts>> =
         Arc::new(tokio::sync::RwLock::new(parts));
     let mut server_context = DioxusServerContext::new(parts.clone());
     inject_context(&mut server_context);
 
+    // Load the session before rendering so components can check if the client has one
+    if let Some(store) = &cfg.session_store {
+        let _ = server_context.insert(store.clone());
+        if let Err(err) = server_context.session().await {
+            tracing::error!("Failed to load the session: {}", err);
+        }
+    }
+
+    // Use the nonce the context was given, or create one if the policy needs it
+    let mut nonce = server_context.get::<CspNonce>();
+    let policy = cfg.content_security_policy.as_deref();
+    if nonce.is_none() && policy.is_some_and(|policy| policy.contains("{nonce}")) {
+        let new_nonce = CspNonce::new();
+        let _ = server_context.insert(new_nonce.clone());
+        nonce = Some(new_nonce);
+    }
+    let policy = policy.and_then(|policy| {
+        let policy = match &nonce {
+            Some(CspNonce(nonce)) => policy.replace("{nonce}", nonce),
+            None => policy.to_string(),
+        };
+        HeaderValue::from_str(&policy)
+            .map_err(|err| tracing::error!("Invalid Content Security Policy: {}", err))
+            .ok()
+    });
+
+    if cfg.streaming && cfg.incremental.is_none() {
+        use futures_util::StreamExt;
+
+        let mut stream = Box::pin(ssr_state.render_stream(
+            url,
+            &cfg,
+            move || virtual_dom_factory(),
+            &server_context,
+        ));
+        // The headers are sent with the first chunk, so headers set after it is rendered are ignored
+        let first = stream.next().await;
+        let stream = futures_util::stream::iter(first).chain(stream);
+        let mut response = axum::response::Html::from(Body::from_stream(stream)).into_response();
+        if let Some(policy) = policy {
+            response
+                .headers_mut()
+                .insert(CONTENT_SECURITY_POLICY, policy);
+        }
+        let headers = server_context.response_parts().unwrap().headers.clone();
+        apply_request_parts_to_response(headers, &mut response);
+        return response;
+    }
+
     match ssr_state
         .render(url, &cfg, move || virtual_dom_factory(), &server_context)
         .await
     {
         Ok(rendered) => {
             let crate::render::RenderResponse { html, freshness } = rendered;
-            let mut response = axum::response::Html::from(html).into_response();
+            // The client already has this page, so only send the headers
+            let mut response = if freshness.not_modified(&request_headers) {
+                StatusCode::NOT_MODIFIED.into_response()
+            } else {
+                axum::response::Html::from(html).into_response()
+            };
             freshness.write(response.headers_mut());
+            if let Some(policy) = policy {
+                response
+                    .headers_mut()
+                    .insert(CONTENT_SECURITY_POLICY, policy);
+            }
             let headers = server_context.response_parts().unwrap().headers.clone();
             apply_request_parts_to_response(headers, &mut response);
             response
@@ -410,7 +519,7 @@ fn report_err<E: std::fmt::Display>(e: E) -> Response<axum::body::Body> {
         .unwrap()
 }
 
-/// A handler for Dioxus web hot reload websocket. This will send the updated static parts of the RSX to the client when they change.
+/// A handler for Dioxus web hot reload websocket. This will send the updated static parts of the RSX and any changed assets to the client.
 #[cfg(all(debug_assertions, feature = "hot-reload"))]
 pub async fn hot_reload_handler(ws: axum::extract::WebSocketUpgrade) -> impl IntoResponse {
     use axum::extract::ws::Message;
@@ -427,8 +536,9 @@ pub async fn hot_reload_handler(ws: axum::extract::WebSocketUpgrade) -> impl Int
                 let templates_read = state.templates.read().await;
 
                 for template in &*templates_read {
+                    let msg = dioxus_hot_reload::HotReloadMsg::UpdateTemplate(*template);
                     if socket
-                        .send(Message::Text(serde_json::to_string(&template).unwrap()))
+                        .send(Message::Text(serde_json::to_string(&msg).unwrap()))
                         .await
                         .is_err()
                     {
@@ -442,9 +552,9 @@ pub async fn hot_reload_handler(ws: axum::extract::WebSocketUpgrade) -> impl Int
         let mut rx =
             tokio_stream::wrappers::WatchStream::from_changes(state.message_receiver.clone());
         while let Some(change) = rx.next().await {
-            if let Some(template) = change {
-                let template = { serde_json::to_string(&template).unwrap() };
-                if socket.send(Message::Text(template)).await.is_err() {
+            if let Some(msg) = change {
+                let msg = serde_json::to_string(&msg).unwrap();
+                if socket.send(Message::Text(msg)).await.is_err() {
                     break;
                 };
             }
@@ -456,6 +566,7 @@ pub async fn hot_reload_handler(ws: axum::extract::WebSocketUpgrade) -> impl Int
 async fn handle_server_fns_inner(
     path: &str,
     additional_context: impl Fn() + 'static + Clone + Send,
+    middleware: ServerFnMiddlewareStack,
     req: Request<Body>,
 ) -> impl IntoResponse {
     use server_fn::middleware::Service;
@@ -473,6 +584,15 @@ async fn handle_server_fns_inner(
             let server_context = DioxusServerContext::new(Arc::new(tokio::sync::RwLock::new(parts)));
             additional_context();
 
+            let call = ServerFnCall::new(path_string.clone(), server_context.clone());
+            if let Err(rejection) = middleware.before(&call).await {
+                middleware.after(&call, rejection.status()).await;
+                let mut res = (rejection.status(), rejection.body()).into_response();
+                let mut res_options = server_context.response_parts_mut().unwrap();
+                res.headers_mut().extend(res_options.headers.drain());
+                return res;
+            }
+
             // store Accepts and Referrer in case we need them for redirect (below)
             let accepts_html = req
                 .headers()
@@ -482,8 +602,8 @@ async fn handle_server_fns_inner(
                 .unwrap_or(false);
             let referrer = req.headers().get(REFERER).cloned();
 
-            // actually run the server fn
-            let mut res = service.run(req).await;
+            // actually run the server fn with the server context middleware may have added to
+            let mut res = ProvideServerContext::new(service.run(req), server_context.clone()).await;
 
 
             // it it accepts text/html (i.e., is a plain form post) and doesn't already have a
@@ -498,6 +618,8 @@ async fn handle_server_fns_inner(
                 }
             }
 
+            middleware.after(&call, res.status()).await;
+
             // apply the response parts from the server context to the response
             let mut res_options = server_context.response_parts_mut().unwrap();
             res.headers_mut().extend(res_options.headers.drain());
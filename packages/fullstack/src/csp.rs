@@ -0,0 +1,168 @@
+//! Content Security Policy nonces for the scripts and styles of server rendered pages
+
+use base64::{engine::general_purpose::STANDARD, Engine};
+
+/// The Content Security Policy nonce of the current request.
+///
+/// With [`crate::prelude::ServeConfigBuilder::content_security_policy`] a new nonce is created for every page. To
+/// manage the policy yourself, insert a nonce into the server context before rendering, like with
+/// [`crate::prelude::render_handler_with_context`]. The nonce is added to every script, style and link tag of the page.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CspNonce(pub String);
+
+impl CspNonce {
+    /// Create a random nonce
+    pub fn new() -> Self {
+        let bytes: [u8; 16] = rand::random();
+        Self(STANDARD.encode(bytes))
+    }
+}
+
+impl Default for CspNonce {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Html with the places the nonce of a request goes in. The html is split when it is loaded, and the nonce is written
+/// between the parts when a page is rendered.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct NoncedHtml {
+    // The nonce attribute goes between each part and the next one
+    parts: Vec<String>,
+}
+
+impl NoncedHtml {
+    /// Find the script, style and link tags that don't have a nonce. The nonce goes after the name of each tag
+    pub(crate) fn new(html: &str) -> Self {
+        let mut parts = Vec::new();
+        let mut part_start = 0;
+        let mut idx = 0;
+
+        while let Some(offset) = html[idx..].find('<') {
+            idx += offset;
+            let rest = &html[idx..];
+
+            // Comments and declarations can contain anything up to their end
+            if rest.starts_with("<!--") {
+                idx += rest.find("-->").map(|end| end + 3).unwrap_or(rest.len());
+                continue;
+            }
+            if rest.starts_with("<!") || rest.starts_with("<?") || rest.starts_with("</") {
+                idx += rest.find('>').map(|end| end + 1).unwrap_or(rest.len());
+                continue;
+            }
+
+            let name_len = rest[1..]
+                .find(|c: char| !c.is_ascii_alphanumeric())
+                .unwrap_or(rest.len() - 1);
+            if name_len == 0 {
+                idx += 1;
+                continue;
+            }
+            let name = &rest[1..=name_len];
+            let name_end = idx + 1 + name_len;
+            let (tag_end, has_nonce) = scan_attributes(html, name_end);
+
+            let needs_nonce = ["script", "style", "link"]
+                .iter()
+                .any(|tag_name| name.eq_ignore_ascii_case(tag_name));
+            if needs_nonce && !has_nonce {
+                parts.push(html[part_start..name_end].to_string());
+                part_start = name_end;
+            }
+            idx = tag_end;
+
+            // The contents of scripts and styles are not html, so they end at the closing tag
+            if name.eq_ignore_ascii_case("script") || name.eq_ignore_ascii_case("style") {
+                let closing = format!("</{}", name.to_ascii_lowercase());
+                idx += html[idx..]
+                    .to_ascii_lowercase()
+                    .find(&closing)
+                    .unwrap_or(html.len() - idx);
+            }
+        }
+        parts.push(html[part_start..].to_string());
+
+        Self { parts }
+    }
+
+    /// Write the html with the nonce, or without one if the request doesn't have a nonce
+    pub(crate) fn write(
+        &self,
+        to: &mut impl std::io::Write,
+        nonce: Option<&str>,
+    ) -> std::io::Result<()> {
+        let (last, parts) = self.parts.split_last().expect("there is always a part");
+        for part in parts {
+            to.write_all(part.as_bytes())?;
+            if let Some(nonce) = nonce {
+                write!(to, r#" nonce="{nonce}""#)?;
+            }
+        }
+        to.write_all(last.as_bytes())
+    }
+}
+
+/// Read the attributes of a tag starting at `idx`. Returns the index after the end of the tag and whether the tag has a nonce attribute
+fn scan_attributes(html: &str, mut idx: usize) -> (usize, bool) {
+    let bytes = html.as_bytes();
+    let mut has_nonce = false;
+    while idx < bytes.len() {
+        match bytes[idx] {
+            b'>' => return (idx + 1, has_nonce),
+            b'"' | b'\'' => {
+                // Quoted values can contain '>'
+                let quote = bytes[idx];
+                idx += 1;
+                while idx < bytes.len() && bytes[idx] != quote {
+                    idx += 1;
+                }
+                idx += 1;
+            }
+            c if c.is_ascii_whitespace() || c == b'/' || c == b'=' => idx += 1,
+            _ => {
+                let start = idx;
+                while idx < bytes.len()
+                    && !bytes[idx].is_ascii_whitespace()
+                    && !matches!(bytes[idx], b'>' | b'=' | b'"' | b'\'' | b'/')
+                {
+                    idx += 1;
+                }
+                has_nonce |= html[start..idx].eq_ignore_ascii_case("nonce");
+            }
+        }
+    }
+    (bytes.len(), has_nonce)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn with_nonce(html: &str, nonce: Option<&str>) -> String {
+        let mut to = Vec::new();
+        NoncedHtml::new(html).write(&mut to, nonce).unwrap();
+        String::from_utf8(to).unwrap()
+    }
+
+    #[test]
+    fn nonces_are_added_to_scripts_styles_and_links() {
+        let html = r#"<head><LINK rel="stylesheet" href="a.css"><style>a { color: red }</style></head><body><div id="main"></div><script type="module">if (1 < 2) {}</script><script nonce="other" src="b.js"></script><scripts></scripts></body>"#;
+        assert_eq!(
+            with_nonce(html, Some("abc")),
+            r#"<head><LINK nonce="abc" rel="stylesheet" href="a.css"><style nonce="abc">a { color: red }</style></head><body><div id="main"></div><script nonce="abc" type="module">if (1 < 2) {}</script><script nonce="other" src="b.js"></script><scripts></scripts></body>"#
+        );
+        assert_eq!(with_nonce(html, None), html);
+        assert_ne!(CspNonce::new(), CspNonce::new());
+    }
+
+    #[test]
+    fn tags_in_comments_scripts_and_attributes_are_not_changed() {
+        let html = r#"<!-- <script> --><div title="<style>" data-nonce="x"></div><script>const tag = "<script>";</script><link data-x='>' nonce='a'>"#;
+        assert_eq!(
+            with_nonce(html, Some("abc")),
+            r#"<!-- <script> --><div title="<style>" data-nonce="x"></div><script nonce="abc">const tag = "<script>";</script><link data-x='>' nonce='a'>"#
+        );
+    }
+}
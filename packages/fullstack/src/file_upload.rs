@@ -0,0 +1,336 @@
+//! Server functions that receive files from the client.
+
+use std::{collections::VecDeque, path::Path, sync::Arc};
+
+use bytes::Bytes;
+use dioxus_lib::html::FileEngine;
+use futures_util::StreamExt;
+use http::Method;
+use server_fn::{
+    codec::{Encoding, FromReq, IntoReq},
+    request::{ClientReq, Req},
+    ServerFnError,
+};
+
+/// An encoding for server functions that take a [`FileUpload`].
+///
+/// Files are sent as a `multipart/form-data` body, so they don't need to be base64 encoded into JSON and the server can read each file as it arrives.
+pub struct MultipartFormData;
+
+impl Encoding for MultipartFormData {
+    const CONTENT_TYPE: &'static str = "multipart/form-data";
+    const METHOD: Method = Method::POST;
+}
+
+/// Files sent to a server function with the [`MultipartFormData`] input encoding.
+///
+/// On the client, create the upload from the files of an `onchange` event or from bytes. On the server, read the files one at a time with [`FileUpload::next_file`].
+///
+/// ```rust, ignore
+/// use dioxus::prelude::*;
+/// use dioxus_fullstack::prelude::*;
+///
+/// #[server(input = MultipartFormData)]
+/// async fn upload(files: FileUpload) -> Result<usize, ServerFnError> {
+///     let mut files = files;
+///     let mut total = 0;
+///     while let Some(mut file) = files.next_file().await? {
+///         println!("{:?} ({:?})", file.file_name(), file.content_type());
+///         while let Some(chunk) = file.chunk().await? {
+///             total += chunk.len();
+///         }
+///     }
+///     Ok(total)
+/// }
+///
+/// fn app() -> Element {
+///     rsx! {
+///         input {
+///             r#type: "file",
+///             multiple: true,
+///             onchange: move |evt| async move {
+///                 if let Some(files) = evt.files() {
+///                     let size = upload(FileUpload::from_file_engine(files).await).await;
+///                     println!("Uploaded {size:?} bytes");
+///                 }
+///             },
+///         }
+///     }
+/// }
+/// ```
+pub struct FileUpload {
+    inner: FileUploadInner,
+}
+
+enum FileUploadInner {
+    Files(VecDeque<UploadedFile>),
+    Multipart(multer::Multipart<'static>),
+}
+
+impl FileUpload {
+    /// The name of the form field every file is sent in.
+    pub const FIELD_NAME: &'static str = "file";
+
+    /// Create an upload without any files.
+    pub fn new() -> Self {
+        Self {
+            inner: FileUploadInner::Files(VecDeque::new()),
+        }
+    }
+
+    /// Add a file. The content type is guessed from the extension of the file name.
+    pub fn file(self, file_name: impl Into<String>, contents: impl Into<Bytes>) -> Self {
+        let file_name = file_name.into();
+        let content_type = mime_guess::from_path(&file_name)
+            .first_or_octet_stream()
+            .to_string();
+        self.file_with_content_type(file_name, content_type, contents)
+    }
+
+    /// Add a file with a content type.
+    pub fn file_with_content_type(
+        mut self,
+        file_name: impl Into<String>,
+        content_type: impl Into<String>,
+        contents: impl Into<Bytes>,
+    ) -> Self {
+        let file = UploadedFile {
+            name: Some(Self::FIELD_NAME.to_string()),
+            file_name: Some(file_name.into()),
+            content_type: Some(content_type.into()),
+            body: UploadedBody::Memory(Some(contents.into())),
+        };
+        match &mut self.inner {
+            FileUploadInner::Files(files) => files.push_back(file),
+            FileUploadInner::Multipart(_) => {
+                tracing::error!("Files can't be added to an upload received by the server")
+            }
+        }
+        self
+    }
+
+    /// Read every file of a [`FileEngine`], like the files of an `onchange` event or the files selected in a desktop file dialog.
+    ///
+    /// Only the name of each file is sent to the server, not the path on the client. Files that can't be read are skipped.
+    pub async fn from_file_engine(engine: Arc<dyn FileEngine>) -> Self {
+        let mut upload = Self::new();
+        for path in engine.files() {
+            let Some(contents) = engine.read_file(&path).await else {
+                tracing::error!("Failed to read file {path} for upload");
+                continue;
+            };
+            let file_name = Path::new(&path)
+                .file_name()
+                .map(|name| name.to_string_lossy().into_owned())
+                .unwrap_or(path);
+            upload = upload.file(file_name, contents);
+        }
+        upload
+    }
+
+    /// Get the next file of the upload. This returns `None` once every file has been read.
+    ///
+    /// On the server, the files are read from the request as they arrive, so the previous file must be dropped before getting the next one.
+    pub async fn next_file(&mut self) -> Result<Option<UploadedFile>, ServerFnError> {
+        match &mut self.inner {
+            FileUploadInner::Files(files) => Ok(files.pop_front()),
+            FileUploadInner::Multipart(multipart) => {
+                let field = multipart.next_field().await.map_err(multipart_error)?;
+                Ok(field.map(|field| UploadedFile {
+                    name: field.name().map(ToString::to_string),
+                    file_name: field.file_name().map(ToString::to_string),
+                    content_type: field.content_type().map(ToString::to_string),
+                    body: UploadedBody::Multipart(Box::new(field)),
+                }))
+            }
+        }
+    }
+
+    /// Encode the files in a `multipart/form-data` body and return the content type with the boundary of the body.
+    fn multipart_body(files: VecDeque<UploadedFile>) -> (String, Bytes) {
+        let files = files
+            .into_iter()
+            .filter_map(|file| match file.body {
+                UploadedBody::Memory(contents) => Some((
+                    file.name,
+                    file.file_name,
+                    file.content_type,
+                    contents.unwrap_or_default(),
+                )),
+                UploadedBody::Multipart(_) => None,
+            })
+            .collect::<Vec<_>>();
+
+        // The boundary can't appear in any of the files
+        let mut boundary = String::new();
+        for attempt in 0u32.. {
+            boundary = format!("----DioxusFormBoundary{attempt:08x}");
+            let contains_boundary = files.iter().any(|(_, _, _, contents)| {
+                contents
+                    .windows(boundary.len())
+                    .any(|window| window == boundary.as_bytes())
+            });
+            if !contains_boundary {
+                break;
+            }
+        }
+
+        let mut body = Vec::new();
+        for (name, file_name, content_type, contents) in files {
+            body.extend_from_slice(format!("--{boundary}\r\n").as_bytes());
+            body.extend_from_slice(
+                format!(
+                    "Content-Disposition: form-data; name=\"{}\"; filename=\"{}\"\r\n",
+                    escape_quoted(name.as_deref().unwrap_or(Self::FIELD_NAME)),
+                    escape_quoted(file_name.as_deref().unwrap_or_default()),
+                )
+                .as_bytes(),
+            );
+            if let Some(content_type) = content_type {
+                body.extend_from_slice(format!("Content-Type: {content_type}\r\n").as_bytes());
+            }
+            body.extend_from_slice(b"\r\n");
+            body.extend_from_slice(&contents);
+            body.extend_from_slice(b"\r\n");
+        }
+        body.extend_from_slice(format!("--{boundary}--\r\n").as_bytes());
+        (
+            format!("{}; boundary={boundary}", MultipartFormData::CONTENT_TYPE),
+            body.into(),
+        )
+    }
+}
+
+impl Default for FileUpload {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl std::fmt::Debug for FileUpload {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FileUpload").finish()
+    }
+}
+
+impl<CustErr, Request> IntoReq<MultipartFormData, Request, CustErr> for FileUpload
+where
+    Request: ClientReq<CustErr>,
+{
+    fn into_req(self, path: &str, accepts: &str) -> Result<Request, ServerFnError<CustErr>> {
+        let FileUploadInner::Files(files) = self.inner else {
+            return Err(ServerFnError::Request(
+                "An upload received by the server can't be sent again".to_string(),
+            ));
+        };
+        let (content_type, body) = FileUpload::multipart_body(files);
+        Request::try_new_post_bytes(path, accepts, &content_type, body)
+    }
+}
+
+impl<CustErr, Request> FromReq<MultipartFormData, Request, CustErr> for FileUpload
+where
+    Request: Req<CustErr> + Send + 'static,
+{
+    async fn from_req(req: Request) -> Result<Self, ServerFnError<CustErr>> {
+        let boundary = req
+            .to_content_type()
+            .and_then(|content_type| multer::parse_boundary(content_type).ok())
+            .ok_or_else(|| {
+                ServerFnError::Deserialization(
+                    "Missing the boundary of the multipart body".to_string(),
+                )
+            })?;
+        let stream = req.try_into_stream()?;
+        let multipart = multer::Multipart::new(
+            stream.map(|chunk| chunk.map_err(|e| e.to_string())),
+            boundary,
+        );
+        Ok(Self {
+            inner: FileUploadInner::Multipart(multipart),
+        })
+    }
+}
+
+/// A file of a [`FileUpload`].
+pub struct UploadedFile {
+    name: Option<String>,
+    file_name: Option<String>,
+    content_type: Option<String>,
+    body: UploadedBody,
+}
+
+enum UploadedBody {
+    Memory(Option<Bytes>),
+    Multipart(Box<multer::Field<'static>>),
+}
+
+impl UploadedFile {
+    /// The name of the form field the file was sent in.
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// The name of the file on the client.
+    pub fn file_name(&self) -> Option<&str> {
+        self.file_name.as_deref()
+    }
+
+    /// The mime type of the file, like `image/png`.
+    pub fn content_type(&self) -> Option<&str> {
+        self.content_type.as_deref()
+    }
+
+    /// Read the next chunk of the file. This returns `None` once the whole file has been read.
+    pub async fn chunk(&mut self) -> Result<Option<Bytes>, ServerFnError> {
+        match &mut self.body {
+            UploadedBody::Memory(contents) => Ok(contents.take()),
+            UploadedBody::Multipart(field) => field.chunk().await.map_err(multipart_error),
+        }
+    }
+
+    /// Read the rest of the file into memory.
+    pub async fn bytes(mut self) -> Result<Bytes, ServerFnError> {
+        let mut contents = Vec::new();
+        while let Some(chunk) = self.chunk().await? {
+            contents.extend_from_slice(&chunk);
+        }
+        Ok(contents.into())
+    }
+
+    /// Turn the file into an [`tokio::io::AsyncRead`], so it can be copied into a file with [`tokio::io::copy`] without reading the whole file into memory.
+    #[cfg(feature = "server")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "server")))]
+    pub fn into_reader(self) -> impl tokio::io::AsyncRead + Send + Unpin {
+        let chunks = futures_util::stream::unfold(self, |mut file| async move {
+            match file.chunk().await {
+                Ok(Some(chunk)) => Some((Ok(chunk), file)),
+                Ok(None) => None,
+                Err(err) => Some((Err(std::io::Error::other(err.to_string())), file)),
+            }
+        });
+        tokio_util::io::StreamReader::new(Box::pin(chunks))
+    }
+}
+
+impl std::fmt::Debug for UploadedFile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("UploadedFile")
+            .field("name", &self.name)
+            .field("file_name", &self.file_name)
+            .field("content_type", &self.content_type)
+            .finish()
+    }
+}
+
+fn multipart_error(err: multer::Error) -> ServerFnError {
+    ServerFnError::Deserialization(err.to_string())
+}
+
+/// Escape a value in a quoted header parameter the same way browsers do
+fn escape_quoted(value: &str) -> String {
+    value
+        .replace('"', "%22")
+        .replace('\r', "%0D")
+        .replace('\n', "%0A")
+}
@@ -0,0 +1,49 @@
+use dioxus_lib::prelude::*;
+
+/// Check if the client has a session.
+///
+/// The server checks the [`Session`](crate::prelude::Session) of the request while rendering the page and the client
+/// reads the answer when it hydrates, so both render the same. Set the signal after a server function logs the user
+/// in or out. Without a session store, or when the app was not rendered by the server, this is `false`.
+///
+/// # Example
+/// ```rust
+/// use dioxus_lib::prelude::*;
+/// use dioxus_fullstack::prelude::*;
+///
+/// fn app() -> Element {
+///     let signed_in = use_auth();
+///     rsx! {
+///         if signed_in() {
+///             "Welcome back"
+///         } else {
+///             "Please log in"
+///         }
+///     }
+/// }
+/// ```
+pub fn use_auth() -> Signal<bool> {
+    #[allow(unused)]
+    let key = use_hook(|| {
+        (
+            current_scope_id().expect("to be in a component").0,
+            current_hook_index(),
+        )
+    });
+
+    use_signal(move || {
+        #[cfg(feature = "server")]
+        {
+            let context = crate::server_context::server_context();
+            let signed_in = context
+                .get::<crate::session::Session>()
+                .is_some_and(|session| !session.is_empty());
+            let _ = context.push_hook_data(key, &signed_in);
+            signed_in
+        }
+        #[cfg(not(feature = "server"))]
+        {
+            crate::html_storage::deserialize::take_hook_data(key).unwrap_or_default()
+        }
+    })
+}
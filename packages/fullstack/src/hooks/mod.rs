@@ -1,2 +1,3 @@
+pub mod auth;
 pub mod server_cached;
 pub mod server_future;
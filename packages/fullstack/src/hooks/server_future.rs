@@ -3,6 +3,11 @@ use serde::{de::DeserializeOwned, Serialize};
 use std::future::Future;
 
 /// A future that resolves to a value.
+///
+/// The value the future resolves to during server rendering is serialized into the page. When the client hydrates,
+/// the first run of the future reads that value instead of running the future again, so the data is only loaded once.
+/// Values are matched to the hook by its scope and hook index, so the component tree must render the same on the server
+/// and the client.
 #[must_use = "Consider using `cx.spawn` to run a future without reading its value"]
 pub fn use_server_future<T, F>(_future: impl Fn() -> F + 'static) -> Option<Resource<T>>
 where
@@ -11,6 +16,13 @@ where
 {
     let mut cb = use_callback(_future);
     let mut first_run = use_hook(|| CopyValue::new(true));
+    #[allow(unused)]
+    let key = use_hook(|| {
+        (
+            current_scope_id().expect("to be in a component").0,
+            current_hook_index(),
+        )
+    });
 
     let resource = use_resource(move || {
         async move {
@@ -27,7 +39,7 @@ where
                 first_run.set(false);
 
                 #[cfg(feature = "web")]
-                if let Some(o) = crate::html_storage::deserialize::take_server_data::<T>() {
+                if let Some(o) = crate::html_storage::deserialize::take_hook_data::<T>(key) {
                     return o;
                 }
             }
@@ -38,7 +50,7 @@ where
             // If this is the first run and we are on the server, cache the data
             #[cfg(feature = "server")]
             if currently_in_first_run {
-                let _ = crate::server_context::server_context().push_html_data(&out);
+                let _ = crate::server_context::server_context().push_hook_data(key, &out);
             }
 
             #[allow(clippy::let_and_return)]
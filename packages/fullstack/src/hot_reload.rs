@@ -1,6 +1,6 @@
 use std::sync::Arc;
 
-use dioxus_lib::prelude::Template;
+use dioxus_hot_reload::HotReloadMsg;
 use tokio::sync::{
     watch::{channel, Receiver},
     RwLock,
@@ -11,7 +11,7 @@ pub struct HotReloadState {
     // The cache of all templates that have been modified since the last time we checked
     pub(crate) templates: Arc<RwLock<std::collections::HashSet<dioxus_lib::prelude::Template>>>,
     // The channel to send messages to the hot reload thread
-    pub(crate) message_receiver: Receiver<Option<Template>>,
+    pub(crate) message_receiver: Receiver<Option<HotReloadMsg>>,
 }
 
 impl Default for HotReloadState {
@@ -22,17 +22,22 @@ impl Default for HotReloadState {
         dioxus_hot_reload::connect({
             let templates = templates.clone();
             move |msg| match msg {
-                dioxus_hot_reload::HotReloadMsg::UpdateTemplate(template) => {
+                HotReloadMsg::UpdateTemplate(template) => {
                     {
                         let mut templates = templates.blocking_write();
                         templates.insert(template);
                     }
 
-                    if let Err(err) = tx.send(Some(template)) {
+                    if let Err(err) = tx.send(Some(HotReloadMsg::UpdateTemplate(template))) {
                         tracing::error!("Failed to send hot reload message: {}", err);
                     }
                 }
-                dioxus_hot_reload::HotReloadMsg::Shutdown => {
+                HotReloadMsg::UpdateAsset(asset) => {
+                    if let Err(err) = tx.send(Some(HotReloadMsg::UpdateAsset(asset))) {
+                        tracing::error!("Failed to send hot reload message: {}", err);
+                    }
+                }
+                HotReloadMsg::Shutdown => {
                     std::process::exit(0);
                 }
             }
@@ -36,15 +36,15 @@ static SERVER_DATA: once_cell::sync::Lazy<Option<HTMLDataCursor>> =
                     return None;
                 }
             };
-            let attribute = match element.get_attribute("data-serialized") {
-                Some(attribute) => attribute,
+            let serialized = match element.text_content() {
+                Some(serialized) => serialized,
                 None => {
-                    tracing::error!("Failed to get attribute: data-serialized");
+                    tracing::error!("Failed to get the text of dioxus-storage-data");
                     return None;
                 }
             };
 
-            let data: super::HTMLData = serde_from_bytes(attribute.as_bytes())?;
+            let data: super::HTMLData = serde_from_bytes(serialized.trim().as_bytes())?;
 
             Some(data.cursor())
         }
@@ -58,6 +58,11 @@ pub(crate) fn take_server_data<T: DeserializeOwned>() -> Option<T> {
     SERVER_DATA.as_ref()?.take()
 }
 
+/// Take the value the server pushed for a hook
+pub(crate) fn take_hook_data<T: DeserializeOwned>(key: super::HookKey) -> Option<T> {
+    SERVER_DATA.as_ref()?.take_hook(key)
+}
+
 #[cfg(not(feature = "server"))]
 /// Get the props from the document. This is only available in the browser.
 ///
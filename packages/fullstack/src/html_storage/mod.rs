@@ -1,6 +1,6 @@
 #![allow(unused)]
 use base64::Engine;
-use std::{io::Cursor, sync::atomic::AtomicUsize};
+use std::{collections::BTreeMap, io::Cursor, sync::atomic::AtomicUsize, sync::Mutex};
 
 use base64::engine::general_purpose::STANDARD;
 use serde::{de::DeserializeOwned, Serialize};
@@ -9,9 +9,16 @@ pub(crate) mod deserialize;
 
 pub(crate) mod serialize;
 
+/// Identifies a hook by its scope and hook index
+pub(crate) type HookKey = (usize, usize);
+
 #[derive(serde::Serialize, serde::Deserialize, Default)]
 pub(crate) struct HTMLData {
     pub data: Vec<Vec<u8>>,
+    /// Values of hooks like `use_server_future`. Server futures resolve in any order, so the values are keyed by the hook
+    /// instead of the order they were pushed in
+    #[serde(default)]
+    pub hooks: BTreeMap<HookKey, Vec<u8>>,
 }
 
 impl HTMLData {
@@ -21,10 +28,17 @@ impl HTMLData {
         self.data.push(serialized);
     }
 
+    pub(crate) fn push_hook<T: Serialize>(&mut self, key: HookKey, value: &T) {
+        let mut serialized = Vec::new();
+        serialize::serde_to_writable(value, &mut serialized).unwrap();
+        self.hooks.insert(key, serialized);
+    }
+
     pub(crate) fn cursor(self) -> HTMLDataCursor {
         HTMLDataCursor {
             data: self.data,
             index: AtomicUsize::new(0),
+            hooks: Mutex::new(self.hooks),
         }
     }
 }
@@ -32,6 +46,7 @@ impl HTMLData {
 pub(crate) struct HTMLDataCursor {
     data: Vec<Vec<u8>>,
     index: AtomicUsize,
+    hooks: Mutex<BTreeMap<HookKey, Vec<u8>>>,
 }
 
 impl HTMLDataCursor {
@@ -56,6 +71,12 @@ impl HTMLDataCursor {
             }
         }
     }
+
+    /// Take the value the server pushed for a hook. Each value can only be taken once.
+    pub fn take_hook<T: DeserializeOwned>(&self, key: HookKey) -> Option<T> {
+        let serialized = self.hooks.lock().unwrap().remove(&key)?;
+        deserialize::serde_from_bytes(&serialized)
+    }
 }
 
 #[test]
@@ -110,3 +131,27 @@ fn serialized_and_deserializes() {
         }
     }
 }
+
+#[test]
+fn hook_data_is_taken_by_key() {
+    let mut data = HTMLData::default();
+    data.push(&"cached");
+    // The second hook resolves first
+    data.push_hook((1, 3), &"second".to_string());
+    data.push_hook((1, 0), &"first".to_string());
+
+    let mut serialized = Vec::new();
+    serialize::serde_to_writable(&data, &mut serialized).unwrap();
+    let cursor = deserialize::serde_from_bytes::<HTMLData>(&serialized)
+        .unwrap()
+        .cursor();
+
+    assert_eq!(cursor.take_hook::<String>((1, 0)).as_deref(), Some("first"));
+    assert_eq!(
+        cursor.take_hook::<String>((1, 3)).as_deref(),
+        Some("second")
+    );
+    assert_eq!(cursor.take_hook::<String>((1, 3)), None);
+    assert_eq!(cursor.take_hook::<String>((2, 0)), None);
+    assert_eq!(cursor.take::<String>().as_deref(), Some("cached"));
+}
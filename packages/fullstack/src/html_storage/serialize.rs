@@ -33,9 +33,10 @@ pub(crate) fn encode_in_element(
     data: &super::HTMLData,
     write_to: &mut impl std::io::Write,
 ) -> Result<(), ciborium::ser::Error<std::io::Error>> {
+    // The data is base64, so it can't close the script tag. The type keeps the browser from running it
     write_to.write_all(
-        r#"<meta hidden="true" id="dioxus-storage-data" data-serialized=""#.as_bytes(),
+        r#"<script type="application/octet-stream" id="dioxus-storage-data">"#.as_bytes(),
     )?;
     serde_to_writable(&data, write_to)?;
-    Ok(write_to.write_all(r#"" />"#.as_bytes())?)
+    Ok(write_to.write_all(r#"</script>"#.as_bytes())?)
 }
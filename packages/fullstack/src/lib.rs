@@ -13,8 +13,10 @@ mod html_storage;
 mod axum_adapter;
 
 mod config;
+mod file_upload;
 mod hooks;
 pub mod launch;
+mod streaming;
 
 #[cfg(all(
     debug_assertions,
@@ -25,19 +27,33 @@ pub mod launch;
 mod hot_reload;
 pub use config::*;
 
+#[cfg(feature = "server")]
+mod middleware;
+
 #[cfg(feature = "server")]
 mod render;
 
+#[cfg(feature = "server")]
+mod csp;
+
 #[cfg(feature = "server")]
 mod serve_config;
 
 #[cfg(feature = "server")]
 mod server_context;
 
+#[cfg(feature = "server")]
+mod server_fn_cache;
+
+#[cfg(feature = "server")]
+mod session;
+
 /// A prelude of commonly used items in dioxus-fullstack.
 pub mod prelude {
     use crate::hooks;
-    pub use hooks::{server_cached::server_cached, server_future::use_server_future};
+    pub use hooks::{
+        auth::use_auth, server_cached::server_cached, server_future::use_server_future,
+    };
 
     #[cfg(feature = "axum")]
     #[cfg_attr(docsrs, doc(cfg(feature = "axum")))]
@@ -59,6 +75,16 @@ pub mod prelude {
     #[cfg_attr(docsrs, doc(cfg(feature = "router")))]
     pub use crate::router::FullstackRouterConfig;
 
+    #[cfg(feature = "server")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "server")))]
+    pub use crate::middleware::{
+        ServerFnCall, ServerFnMiddleware, ServerFnMiddlewareStack, ServerFnRejection,
+    };
+
+    #[cfg(feature = "server")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "server")))]
+    pub use crate::csp::CspNonce;
+
     #[cfg(feature = "server")]
     #[cfg_attr(docsrs, doc(cfg(feature = "server")))]
     pub use crate::serve_config::{ServeConfig, ServeConfigBuilder};
@@ -73,10 +99,23 @@ pub mod prelude {
         extract, server_context, DioxusServerContext, FromServerContext, ProvideServerContext,
     };
 
+    #[cfg(feature = "server")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "server")))]
+    pub use crate::server_fn_cache::ServerFnCache;
+
+    #[cfg(feature = "server")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "server")))]
+    pub use crate::session::{
+        new_session_id, CookieStore, MemoryStore, Session, SessionData, SessionError, SessionStore,
+        SESSION_COOKIE,
+    };
+
     #[cfg(feature = "server")]
     #[cfg_attr(docsrs, doc(cfg(feature = "server")))]
     pub use dioxus_ssr::incremental::IncrementalRendererConfig;
 
+    pub use crate::file_upload::{FileUpload, MultipartFormData, UploadedFile};
+    pub use crate::streaming::{ServerFnStream, StreamingJson};
     pub use dioxus_server_macro::*;
     pub use server_fn::{self, ServerFn as _, ServerFnError};
 }
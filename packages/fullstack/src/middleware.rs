@@ -0,0 +1,183 @@
+//! Middleware that runs around every server function call.
+
+use std::sync::Arc;
+
+use http::StatusCode;
+use server_fn::error::{NoCustomError, ServerFnError, ServerFnErrorSerde};
+
+use crate::server_context::DioxusServerContext;
+
+/// Code that runs before and after every server function, like authentication checks, logging or rate limiting.
+///
+/// Middleware is added with [`crate::prelude::ServeConfigBuilder::server_fn_middleware`] and runs for every server function registered by the server adapter, so the server functions don't need to be wrapped individually.
+///
+/// # Example
+/// ```rust
+/// use dioxus_fullstack::prelude::*;
+///
+/// struct RequireToken;
+///
+/// #[async_trait::async_trait]
+/// impl ServerFnMiddleware for RequireToken {
+///     async fn before(&self, call: &ServerFnCall) -> Result<(), ServerFnRejection> {
+///         let authorized = call
+///             .context()
+///             .request_parts()
+///             .headers
+///             .contains_key("authorization");
+///         match authorized {
+///             true => Ok(()),
+///             false => Err(ServerFnRejection::unauthorized("Missing token")),
+///         }
+///     }
+/// }
+/// ```
+#[async_trait::async_trait]
+pub trait ServerFnMiddleware: Send + Sync + 'static {
+    /// Run before the server function. Returning an error rejects the call: the server function doesn't run and the client receives the error instead.
+    ///
+    /// Values inserted into the server context with [`DioxusServerContext::insert`] can be read by the server function.
+    async fn before(&self, call: &ServerFnCall) -> Result<(), ServerFnRejection> {
+        let _ = call;
+        Ok(())
+    }
+
+    /// Run after the server function with the status of the response. This also runs for calls that were rejected by a middleware.
+    ///
+    /// Headers added to [`DioxusServerContext::response_parts_mut`] are added to the response.
+    async fn after(&self, call: &ServerFnCall, status: StatusCode) {
+        let _ = (call, status);
+    }
+}
+
+/// A server function call that is passed to [`ServerFnMiddleware`].
+pub struct ServerFnCall {
+    path: String,
+    context: DioxusServerContext,
+}
+
+impl ServerFnCall {
+    pub(crate) fn new(path: impl Into<String>, context: DioxusServerContext) -> Self {
+        Self {
+            path: path.into(),
+            context,
+        }
+    }
+
+    /// The path the server function is registered at.
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// The server context of the call. This contains the request parts and is shared with the server function.
+    pub fn context(&self) -> &DioxusServerContext {
+        &self.context
+    }
+}
+
+/// An error returned by [`ServerFnMiddleware::before`] to reject a server function call.
+///
+/// The client receives a [`ServerFnError::ServerError`] with the message.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ServerFnRejection {
+    status: StatusCode,
+    message: String,
+}
+
+impl ServerFnRejection {
+    /// Create a rejection with a status code and a message for the client.
+    pub fn new(status: StatusCode, message: impl Into<String>) -> Self {
+        Self {
+            status,
+            message: message.into(),
+        }
+    }
+
+    /// Reject the call with `400 Bad Request`.
+    pub fn bad_request(message: impl Into<String>) -> Self {
+        Self::new(StatusCode::BAD_REQUEST, message)
+    }
+
+    /// Reject the call with `401 Unauthorized`.
+    pub fn unauthorized(message: impl Into<String>) -> Self {
+        Self::new(StatusCode::UNAUTHORIZED, message)
+    }
+
+    /// Reject the call with `403 Forbidden`.
+    pub fn forbidden(message: impl Into<String>) -> Self {
+        Self::new(StatusCode::FORBIDDEN, message)
+    }
+
+    /// Reject the call with `429 Too Many Requests`.
+    pub fn too_many_requests(message: impl Into<String>) -> Self {
+        Self::new(StatusCode::TOO_MANY_REQUESTS, message)
+    }
+
+    /// The status code of the response.
+    pub fn status(&self) -> StatusCode {
+        self.status
+    }
+
+    /// The message sent to the client.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// The body of the response, serialized the same way as errors returned by server functions.
+    pub fn body(&self) -> String {
+        ServerFnError::<NoCustomError>::ServerError(self.message.clone())
+            .ser()
+            .unwrap_or_default()
+    }
+}
+
+impl std::fmt::Display for ServerFnRejection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.status, self.message)
+    }
+}
+
+impl std::error::Error for ServerFnRejection {}
+
+/// The middleware that runs around server functions, in the order it was added.
+#[derive(Clone, Default)]
+pub struct ServerFnMiddlewareStack {
+    layers: Vec<Arc<dyn ServerFnMiddleware>>,
+}
+
+impl ServerFnMiddlewareStack {
+    /// Create an empty stack.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a middleware. It runs after the middleware that was added before it, and its `after` hook runs before theirs.
+    pub fn layer(mut self, middleware: impl ServerFnMiddleware) -> Self {
+        self.push(middleware);
+        self
+    }
+
+    pub(crate) fn push(&mut self, middleware: impl ServerFnMiddleware) {
+        self.layers.push(Arc::new(middleware));
+    }
+
+    /// Returns true if there is no middleware.
+    pub fn is_empty(&self) -> bool {
+        self.layers.is_empty()
+    }
+
+    /// Run the `before` hook of every middleware until one rejects the call.
+    pub async fn before(&self, call: &ServerFnCall) -> Result<(), ServerFnRejection> {
+        for layer in &self.layers {
+            layer.before(call).await?;
+        }
+        Ok(())
+    }
+
+    /// Run the `after` hook of every middleware in reverse order.
+    pub async fn after(&self, call: &ServerFnCall, status: StatusCode) {
+        for layer in self.layers.iter().rev() {
+            layer.after(call, status).await;
+        }
+    }
+}
@@ -1,4 +1,5 @@
 //! A shared pool of renderers for efficient server side rendering.
+use crate::csp::CspNonce;
 use crate::render::dioxus_core::NoOpMutations;
 use crate::server_context::SERVER_CONTEXT;
 use dioxus_lib::prelude::VirtualDom;
@@ -6,6 +7,8 @@ use dioxus_ssr::{
     incremental::{IncrementalRendererConfig, RenderFreshness, WrapBody},
     Renderer,
 };
+use futures_util::Stream;
+use std::fmt::Write;
 use std::future::Future;
 use std::sync::Arc;
 use std::sync::RwLock;
@@ -59,46 +62,20 @@ impl SsrRendererPool {
 
                 spawn_platform(move || async move {
                     let mut vdom = virtual_dom_factory();
-                    let mut to = WriteBuffer { buffer: Vec::new() };
-                    // before polling the future, we need to set the context
-                    let prev_context = SERVER_CONTEXT.with(|ctx| ctx.replace(server_context));
-                    // poll the future, which may call server_context()
-                    tracing::info!("Rebuilding vdom");
-                    vdom.rebuild(&mut NoOpMutations);
-                    vdom.wait_for_suspense().await;
-                    tracing::info!("Suspense resolved");
-                    // after polling the future, we need to restore the context
-                    SERVER_CONTEXT.with(|ctx| ctx.replace(prev_context));
+                    rebuild_with_context(&mut vdom, server_context).await;
 
-                    if let Err(err) = wrapper.render_before_body(&mut *to) {
-                        let _ = tx.send(Err(err));
-                        return;
-                    }
-                    if let Err(err) = renderer.render_to(&mut to, &vdom) {
-                        let _ = tx.send(Err(
-                            dioxus_ssr::incremental::IncrementalRendererError::RenderError(err),
-                        ));
-                        return;
-                    }
-                    if let Err(err) = wrapper.render_after_body(&mut *to) {
-                        let _ = tx.send(Err(err));
-                        return;
-                    }
-                    match String::from_utf8(to.buffer) {
-                        Ok(html) => {
-                            let _ = tx.send(Ok((renderer, RenderFreshness::now(None), html)));
-                        }
-                        Err(err) => {
-                            _ = tx.send(Err(
-                                dioxus_ssr::incremental::IncrementalRendererError::Other(Box::new(
-                                    err,
-                                )),
-                            ));
-                        }
-                    }
+                    let rendered = render_chunk(|to| {
+                        wrapper.render_before_body(&mut **to)?;
+                        renderer.render_to(to, &vdom)?;
+                        wrapper.render_after_body(&mut **to)
+                    });
+                    let _ = tx.send((renderer, rendered));
                 });
-                let (renderer, freshness, html) = rx.await.unwrap()?;
+                let (renderer, rendered) = rx.await.unwrap();
+                // The renderer goes back to the pool even if the page failed to render
                 pool.write().unwrap().push(renderer);
+                let html = rendered?;
+                let freshness = RenderFreshness::now(None).with_etag(html.as_bytes());
                 Ok((freshness, html))
             }
             Self::Incremental(pool) => {
@@ -162,6 +139,152 @@ impl SsrRendererPool {
     }
 }
 
+/// Rebuild the VirtualDom and wait for suspense with the server context set
+async fn rebuild_with_context(vdom: &mut VirtualDom, server_context: Box<DioxusServerContext>) {
+    // before polling the future, we need to set the context
+    let prev_context = SERVER_CONTEXT.with(|ctx| ctx.replace(server_context));
+    // poll the future, which may call server_context()
+    tracing::info!("Rebuilding vdom");
+    vdom.rebuild(&mut NoOpMutations);
+    vdom.wait_for_suspense().await;
+    tracing::info!("Suspense resolved");
+    // after polling the future, we need to restore the context
+    SERVER_CONTEXT.with(|ctx| ctx.replace(prev_context));
+}
+
+type RenderedChunk = Result<String, dioxus_ssr::incremental::IncrementalRendererError>;
+
+/// Render part of a page to a string
+fn render_chunk(
+    render: impl FnOnce(
+        &mut WriteBuffer,
+    ) -> Result<(), dioxus_ssr::incremental::IncrementalRendererError>,
+) -> RenderedChunk {
+    let mut to = WriteBuffer { buffer: Vec::new() };
+    render(&mut to)?;
+    String::from_utf8(to.buffer)
+        .map_err(|err| dioxus_ssr::incremental::IncrementalRendererError::Other(Box::new(err)))
+}
+
+/// Stream a page to the client. The server context must be set while the page is streamed
+async fn stream_page(
+    renderer: &mut Renderer,
+    wrapper: &FullstackRenderer,
+    virtual_dom_factory: impl FnOnce() -> VirtualDom,
+    tx: &tokio::sync::mpsc::UnboundedSender<RenderedChunk>,
+) {
+    // The head of the page doesn't depend on the app, so it is sent before the app is rendered
+    let head = render_chunk(|to| wrapper.render_before_body(&mut **to));
+    if !send_chunk(tx, head) {
+        return;
+    }
+
+    let mut vdom = virtual_dom_factory();
+    tracing::info!("Rebuilding vdom");
+    vdom.rebuild(&mut NoOpMutations);
+    // Suspense boundaries show their fallback once a component below them suspends
+    vdom.render_immediate(&mut NoOpMutations);
+
+    let body = render_chunk(|to| Ok(renderer.render_to(to, &vdom)?));
+    if !send_chunk(tx, body) {
+        return;
+    }
+    let mut suspended = renderer.suspended_boundaries().to_vec();
+    if !suspended.is_empty() && !send_chunk(tx, Ok(wrapper.script(SWAP_SUSPENSE_BOUNDARY))) {
+        return;
+    }
+
+    loop {
+        // Send the boundaries that resolved. The html of a boundary may contain more suspended boundaries
+        let mut idx = 0;
+        while let Some(boundary) = suspended.get(idx).copied() {
+            let Some(scope) = vdom.get_scope(boundary) else {
+                suspended.remove(idx);
+                continue;
+            };
+            if scope
+                .suspense_boundary()
+                .is_some_and(|context| context.has_suspended())
+            {
+                idx += 1;
+                continue;
+            }
+
+            suspended.remove(idx);
+            let resolved = render_chunk(|to| {
+                write!(to, r#"<template id="ds-{}">"#, boundary.0)?;
+                renderer.render_scope(to, &vdom, boundary)?;
+                write!(to, "</template>")?;
+                let swap = format!(r#"window.__dioxusSwap("{}")"#, boundary.0);
+                write!(to, "{}", wrapper.script(&swap))?;
+                Ok(())
+            });
+            if !send_chunk(tx, resolved) {
+                return;
+            }
+            suspended.extend_from_slice(renderer.suspended_boundaries());
+        }
+
+        if suspended.is_empty() {
+            break;
+        }
+        vdom.wait_for_work().await;
+        vdom.render_immediate(&mut NoOpMutations);
+    }
+    tracing::info!("Suspense resolved");
+
+    let tail = render_chunk(|to| wrapper.render_after_body(&mut **to));
+    send_chunk(tx, tail);
+}
+
+/// Defines `window.__dioxusSwap`, which swaps the html of a suspense boundary that resolved in place of its fallback
+///
+/// The hydration ids are numbered again afterwards, so they match the ids of a page that was rendered at once. The
+/// server numbers them in the order they are written, which is the order of the nodes in the page
+const SWAP_SUSPENSE_BOUNDARY: &str = r#"window.__dioxusSwap = function (id) {
+    const template = document.getElementById("ds-" + id);
+    const comments = document.createTreeWalker(document.body, NodeFilter.SHOW_COMMENT);
+    let start = null;
+    let end = null;
+    while (comments.nextNode()) {
+        const comment = comments.currentNode;
+        if (comment.data === "ds-" + id) start = comment;
+        if (comment.data === "/ds-" + id) end = comment;
+    }
+    if (template && start && end) {
+        while (start.nextSibling !== end) start.nextSibling.remove();
+        end.replaceWith(template.content);
+        start.remove();
+    }
+    template?.remove();
+    document.currentScript?.remove();
+
+    let index = 0;
+    const nodes = document.createTreeWalker(
+        document.body,
+        NodeFilter.SHOW_ELEMENT | NodeFilter.SHOW_COMMENT
+    );
+    while (nodes.nextNode()) {
+        const node = nodes.currentNode;
+        if (node.nodeType === Node.COMMENT_NODE) {
+            if (node.data.startsWith("node-id")) node.data = "node-id" + index++;
+        } else if (node.hasAttribute("data-node-hydration")) {
+            const listeners = node.getAttribute("data-node-hydration").split(",").slice(1);
+            node.setAttribute("data-node-hydration", [index++, ...listeners].join(","));
+        }
+    }
+};
+document.currentScript?.remove();"#;
+
+/// Send part of a streamed page. Returns false if the page failed to render or the client disconnected
+fn send_chunk(
+    tx: &tokio::sync::mpsc::UnboundedSender<RenderedChunk>,
+    chunk: RenderedChunk,
+) -> bool {
+    let rendered = chunk.is_ok();
+    tx.send(chunk).is_ok() && rendered
+}
+
 /// State used in server side rendering. This utilizes a pool of [`dioxus_ssr::Renderer`]s to cache static templates between renders.
 #[derive(Clone)]
 pub struct SSRState {
@@ -210,6 +333,62 @@ impl SSRState {
 
         Ok(RenderResponse { html, freshness })
     }
+
+    /// Render the application to a stream of HTML chunks.
+    ///
+    /// The head of the page is sent before the application is rendered, so the browser can start loading the page while server futures are still running. The body is sent as soon as it is rendered, with the fallback of every suspense boundary that is still suspended. Each boundary is sent once it resolves and swapped in place of its fallback, and the rest of the page is sent once every boundary resolved. If incremental rendering is enabled, the whole page is sent as one chunk.
+    pub fn render_stream(
+        &self,
+        route: String,
+        cfg: &ServeConfig,
+        virtual_dom_factory: impl FnOnce() -> VirtualDom + Send + Sync + 'static,
+        server_context: &DioxusServerContext,
+    ) -> impl Stream<Item = RenderedChunk> + Send + 'static {
+        let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
+
+        match &*self.renderers {
+            SsrRendererPool::Renderer(_) => {
+                let renderers = self.renderers.clone();
+                let wrapper = FullstackRenderer {
+                    cfg: cfg.clone(),
+                    server_context: server_context.clone(),
+                };
+                let server_context = Box::new(server_context.clone());
+
+                spawn_platform(move || async move {
+                    let SsrRendererPool::Renderer(pool) = &*renderers else {
+                        unreachable!("the pool of renderers never changes")
+                    };
+                    let mut renderer = pool.write().unwrap().pop().unwrap_or_else(pre_renderer);
+
+                    // before polling the future, we need to set the context
+                    let prev_context = SERVER_CONTEXT.with(|ctx| ctx.replace(server_context));
+                    stream_page(&mut renderer, &wrapper, virtual_dom_factory, &tx).await;
+                    // after polling the future, we need to restore the context
+                    SERVER_CONTEXT.with(|ctx| ctx.replace(prev_context));
+
+                    // The renderer goes back to the pool even if the page failed to render or the client disconnected
+                    pool.write().unwrap().push(renderer);
+                });
+            }
+            SsrRendererPool::Incremental(_) => {
+                // Incremental rendering caches whole pages, so there is nothing to stream
+                let state = self.clone();
+                let cfg = cfg.clone();
+                let server_context = server_context.clone();
+                tokio::spawn(async move {
+                    let rendered = state
+                        .render(route, &cfg, virtual_dom_factory, &server_context)
+                        .await;
+                    let _ = tx.send(rendered.map(|rendered| rendered.html));
+                });
+            }
+        }
+
+        futures_util::stream::unfold(rx, |mut rx| async move {
+            rx.recv().await.map(|chunk| (chunk, rx))
+        })
+    }
 }
 
 struct FullstackRenderer {
@@ -217,6 +396,23 @@ struct FullstackRenderer {
     server_context: DioxusServerContext,
 }
 
+impl FullstackRenderer {
+    /// The Content Security Policy nonce of the request
+    fn nonce(&self) -> Option<String> {
+        self.server_context
+            .get::<CspNonce>()
+            .map(|CspNonce(nonce)| nonce)
+    }
+
+    /// An inline script with the Content Security Policy nonce of the request
+    fn script(&self, body: &str) -> String {
+        match self.nonce() {
+            Some(nonce) => format!(r#"<script nonce="{nonce}">{body}</script>"#),
+            None => format!("<script>{body}</script>"),
+        }
+    }
+}
+
 impl dioxus_ssr::incremental::WrapBody for FullstackRenderer {
     fn render_before_body<R: std::io::Write>(
         &self,
@@ -224,7 +420,7 @@ impl dioxus_ssr::incremental::WrapBody for FullstackRenderer {
     ) -> Result<(), dioxus_ssr::incremental::IncrementalRendererError> {
         let ServeConfig { index, .. } = &self.cfg;
 
-        to.write_all(index.pre_main.as_bytes())?;
+        index.pre_main.write(to, self.nonce().as_deref())?;
 
         Ok(())
     }
@@ -265,40 +461,19 @@ impl dioxus_ssr::incremental::WrapBody for FullstackRenderer {
         #[cfg(all(debug_assertions, feature = "hot-reload"))]
         {
             // In debug mode, we need to add a script to the page that will reload the page if the websocket disconnects to make full recompile hot reloads work
-            let disconnect_js = r#"(function () {
-    const protocol = window.location.protocol === 'https:' ? 'wss:' : 'ws:';
-    const url = protocol + '//' + window.location.host + '/_dioxus/disconnect';
-    const poll_interval = 1000;
-    const reload_upon_connect = () => {
-        console.log('Disconnected from server. Attempting to reconnect...');
-        window.setTimeout(
-            () => {
-                // Try to reconnect to the websocket
-                const ws = new WebSocket(url);
-                ws.onopen = () => {
-                    // If we reconnect, reload the page
-                    window.location.reload();
-                }
-                // Otherwise, try again in a second
-                reload_upon_connect();
-            },
-            poll_interval);
-    };
-
-    // on initial page load connect to the disconnect ws
-    const ws = new WebSocket(url);
-    // if we disconnect, start polling
-    ws.onclose = reload_upon_connect;
-})()"#;
-
-            to.write_all(r#"<script>"#.as_bytes())?;
-            to.write_all(disconnect_js.as_bytes())?;
-            to.write_all(r#"</script>"#.as_bytes())?;
+            // The script is served from a file so strict Content Security Policies without a nonce allow it
+            match self.nonce() {
+                Some(nonce) => write!(
+                    to,
+                    r#"<script nonce="{nonce}" src="/_dioxus/disconnect.js"></script>"#
+                )?,
+                None => write!(to, r#"<script src="/_dioxus/disconnect.js"></script>"#)?,
+            }
         }
 
         let ServeConfig { index, .. } = &self.cfg;
 
-        to.write_all(index.post_main.as_bytes())?;
+        index.post_main.write(to, self.nonce().as_deref())?;
 
         Ok(())
     }
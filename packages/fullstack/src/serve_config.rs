@@ -5,6 +5,12 @@ use std::fs::File;
 use std::io::Read;
 use std::path::PathBuf;
 
+use std::sync::Arc;
+
+use crate::csp::NoncedHtml;
+use crate::middleware::{ServerFnMiddleware, ServerFnMiddlewareStack};
+use crate::session::{SessionMiddleware, SessionStore};
+
 /// A ServeConfig is used to configure how to serve a Dioxus application. It contains information about how to serve static assets, and what content to render with [`dioxus-ssr`].
 #[derive(Clone, Default)]
 pub struct ServeConfigBuilder {
@@ -14,6 +20,10 @@ pub struct ServeConfigBuilder {
     pub(crate) assets_path: Option<PathBuf>,
     pub(crate) incremental:
         Option<std::sync::Arc<dioxus_ssr::incremental::IncrementalRendererConfig>>,
+    pub(crate) streaming: bool,
+    pub(crate) server_fn_middleware: ServerFnMiddlewareStack,
+    pub(crate) session_store: Option<Arc<dyn SessionStore>>,
+    pub(crate) content_security_policy: Option<String>,
 }
 
 /// A template for incremental rendering that does nothing.
@@ -45,6 +55,10 @@ impl ServeConfigBuilder {
             index_path: None,
             assets_path: None,
             incremental: None,
+            streaming: false,
+            server_fn_middleware: ServerFnMiddlewareStack::new(),
+            session_store: None,
+            content_security_policy: None,
         }
     }
 
@@ -54,6 +68,46 @@ impl ServeConfigBuilder {
         self
     }
 
+    /// Stream the server side rendered page to the client. (defaults to false)
+    ///
+    /// The head of the index.html file is sent before the app is rendered so the browser can start loading scripts and styles while server futures are still running. Suspense boundaries are sent with their fallback and each boundary is streamed in and swapped into place as soon as it resolves.
+    ///
+    /// Headers are sent with the first chunk. Headers set from the server context before the response starts, like the session cookie, are sent, but headers set after the head of the page is sent, like the ones set while the app renders, are ignored. Streaming is ignored when incremental rendering is enabled.
+    pub fn streaming(mut self, streaming: bool) -> Self {
+        self.streaming = streaming;
+        self
+    }
+
+    /// Add middleware that runs before and after every server function. Middleware runs in the order it is added.
+    pub fn server_fn_middleware(mut self, middleware: impl ServerFnMiddleware) -> Self {
+        self.server_fn_middleware.push(middleware);
+        self
+    }
+
+    /// Store sessions in the store. The [`crate::prelude::Session`] of a request can be extracted in server functions,
+    /// and the server rendered page knows if the client has a session.
+    pub fn session_store(mut self, store: impl SessionStore) -> Self {
+        let store: Arc<dyn SessionStore> = Arc::new(store);
+        self.server_fn_middleware
+            .push(SessionMiddleware(store.clone()));
+        self.session_store = Some(store);
+        self
+    }
+
+    /// Send a Content Security Policy header with every rendered page. Each `{nonce}` in the policy is replaced with a
+    /// new [`crate::prelude::CspNonce`] for every request, and the nonce is added to the scripts and styles of the page.
+    ///
+    /// ```rust,ignore
+    /// ServeConfigBuilder::new()
+    ///     .content_security_policy("script-src 'nonce-{nonce}' 'wasm-unsafe-eval'; style-src 'nonce-{nonce}'")
+    /// ```
+    ///
+    /// Pages from the incremental cache keep the nonce they were rendered with, so a policy with a nonce should not be used with incremental rendering.
+    pub fn content_security_policy(mut self, policy: impl Into<String>) -> Self {
+        self.content_security_policy = Some(policy.into());
+        self
+    }
+
     /// Set the contents of the index.html file to be served. (precedence over index_path)
     pub fn index_html(mut self, index_html: String) -> Self {
         self.index_html = Some(index_html);
@@ -99,10 +153,24 @@ impl ServeConfigBuilder {
             .unwrap_or_else(|| load_index_path(index_path));
 
         let index = load_index_html(index_html, root_id);
+
+        if self.incremental.is_some()
+            && self
+                .content_security_policy
+                .as_deref()
+                .is_some_and(|policy| policy.contains("{nonce}"))
+        {
+            tracing::warn!("The Content Security Policy uses a nonce, but incremental rendering caches pages with the nonce they were first rendered with. Browsers will block the scripts of cached pages.");
+        }
+
         ServeConfig {
             index,
             assets_path,
             incremental: self.incremental,
+            streaming: self.streaming,
+            server_fn_middleware: self.server_fn_middleware,
+            session_store: self.session_store,
+            content_security_policy: self.content_security_policy,
         }
     }
 }
@@ -129,15 +197,15 @@ fn load_index_html(contents: String, root_id: &'static str) -> IndexHtml {
     );
 
     IndexHtml {
-        pre_main,
-        post_main,
+        pre_main: NoncedHtml::new(&pre_main),
+        post_main: NoncedHtml::new(&post_main),
     }
 }
 
 #[derive(Clone)]
 pub(crate) struct IndexHtml {
-    pub(crate) pre_main: String,
-    pub(crate) post_main: String,
+    pub(crate) pre_main: NoncedHtml,
+    pub(crate) post_main: NoncedHtml,
 }
 
 /// Used to configure how to serve a Dioxus application. It contains information about how to serve static assets, and what content to render with [`dioxus-ssr`].
@@ -149,6 +217,10 @@ pub struct ServeConfig {
     pub(crate) assets_path: PathBuf,
     pub(crate) incremental:
         Option<std::sync::Arc<dioxus_ssr::incremental::IncrementalRendererConfig>>,
+    pub(crate) streaming: bool,
+    pub(crate) server_fn_middleware: ServerFnMiddlewareStack,
+    pub(crate) session_store: Option<Arc<dyn SessionStore>>,
+    pub(crate) content_security_policy: Option<String>,
 }
 
 impl ServeConfig {
@@ -112,6 +112,17 @@ mod server_fn_impl {
             })
         }
 
+        /// Insert the value of a hook into the html data store, so the hook can read it when the client hydrates
+        pub(crate) fn push_hook_data<T: serde::Serialize>(
+            &self,
+            key: crate::html_storage::HookKey,
+            value: &T,
+        ) -> Result<(), PoisonError<RwLockWriteGuard<'_, HTMLData>>> {
+            self.html_data.write().map(|mut map| {
+                map.push_hook(key, value);
+            })
+        }
+
         /// Get the html data store
         pub(crate) fn html_data(&self) -> LockResult<RwLockReadGuard<'_, HTMLData>> {
             self.html_data.read()
@@ -0,0 +1,178 @@
+//! A cache for the results of server functions declared with `#[server(cache = "60s")]`
+
+use std::{
+    any::Any,
+    collections::HashMap,
+    future::Future,
+    sync::{Arc, RwLock},
+    time::{Duration, Instant},
+};
+
+use serde::Serialize;
+
+type CacheKey = (&'static str, String);
+
+struct CachedResult {
+    value: Arc<dyn Any + Send + Sync>,
+    expires: Instant,
+}
+
+/// The results of server functions with the `cache` argument, keyed by the server function and its arguments
+///
+/// Only successful results are cached. A cached result is returned until it expires or is invalidated.
+///
+/// ```rust,ignore
+/// #[server(cache = "60s")]
+/// pub async fn blog_post(id: usize) -> Result<String, ServerFnError> {
+///     load_post(id).await
+/// }
+///
+/// #[server]
+/// pub async fn edit_blog_post(id: usize, content: String) -> Result<(), ServerFnError> {
+///     save_post(id, content).await?;
+///     // The next call to blog_post with this id runs again
+///     ServerFnCache::global().invalidate(&BlogPost { id });
+///     Ok(())
+/// }
+/// ```
+#[derive(Default)]
+pub struct ServerFnCache {
+    results: RwLock<HashMap<CacheKey, CachedResult>>,
+}
+
+static GLOBAL_CACHE: once_cell::sync::Lazy<ServerFnCache> =
+    once_cell::sync::Lazy::new(ServerFnCache::default);
+
+impl ServerFnCache {
+    /// The cache the `cache` argument of the `#[server]` macro uses
+    pub fn global() -> &'static ServerFnCache {
+        &GLOBAL_CACHE
+    }
+
+    /// Return the cached result of the server function `F` for the arguments, or run `f` and cache its result for `ttl`
+    pub async fn cached<F, T, E>(
+        &self,
+        args: ServerFnCacheKey,
+        ttl: Duration,
+        f: impl Future<Output = Result<T, E>>,
+    ) -> Result<T, E>
+    where
+        F: server_fn::ServerFn,
+        T: Clone + Send + Sync + 'static,
+    {
+        let key = (F::PATH, args.finish());
+        if let Some(value) = self.get::<T>(&key) {
+            tracing::trace!("server function cache hit {:?}", key);
+            return Ok(value);
+        }
+
+        let value = f.await?;
+        self.results.write().unwrap().insert(
+            key,
+            CachedResult {
+                value: Arc::new(value.clone()),
+                expires: Instant::now() + ttl,
+            },
+        );
+        Ok(value)
+    }
+
+    fn get<T: Clone + 'static>(&self, key: &CacheKey) -> Option<T> {
+        let results = self.results.read().unwrap();
+        let result = results.get(key)?;
+        if result.expires <= Instant::now() {
+            drop(results);
+            self.results.write().unwrap().remove(key);
+            return None;
+        }
+        result.value.downcast_ref::<T>().cloned()
+    }
+
+    /// Remove the cached result of a server function for one set of arguments. The arguments are the struct the
+    /// `#[server]` macro generates for the function.
+    pub fn invalidate<F: server_fn::ServerFn + Serialize>(&self, args: &F) {
+        let Ok(args) = serde_json::to_string(args) else {
+            return;
+        };
+        self.results.write().unwrap().remove(&(F::PATH, args));
+    }
+
+    /// Remove the cached results of a server function for all arguments
+    pub fn invalidate_fn<F: server_fn::ServerFn>(&self) {
+        self.results
+            .write()
+            .unwrap()
+            .retain(|(path, _), _| *path != F::PATH);
+    }
+
+    /// Remove all cached results
+    pub fn clear(&self) {
+        self.results.write().unwrap().clear();
+    }
+
+    #[doc(hidden)]
+    /// Start the key of the arguments of a call. Used by the `#[server]` macro.
+    pub fn key() -> ServerFnCacheKey {
+        ServerFnCacheKey::default()
+    }
+}
+
+#[doc(hidden)]
+/// The arguments of a call to a cached server function. They are serialized the same way as the struct the `#[server]`
+/// macro generates for the function, so [`ServerFnCache::invalidate`] finds the same entry.
+#[derive(Default)]
+pub struct ServerFnCacheKey {
+    args: serde_json::Map<String, serde_json::Value>,
+}
+
+impl ServerFnCacheKey {
+    #[doc(hidden)]
+    pub fn arg<T: Serialize>(mut self, name: &str, value: &T) -> Self {
+        let value = serde_json::to_value(value).unwrap_or(serde_json::Value::Null);
+        self.args.insert(name.to_string(), value);
+        self
+    }
+
+    fn finish(self) -> String {
+        serde_json::Value::Object(self.args).to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    static CALLS: AtomicUsize = AtomicUsize::new(0);
+
+    #[server(cache = "60s")]
+    async fn double(value: usize) -> Result<usize, ServerFnError> {
+        CALLS.fetch_add(1, Ordering::SeqCst);
+        Ok(value * 2)
+    }
+
+    #[test]
+    fn caches_results_by_arguments() {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .build()
+            .unwrap();
+        runtime.block_on(async {
+            assert_eq!(double(1).await.unwrap(), 2);
+            assert_eq!(double(1).await.unwrap(), 2);
+            assert_eq!(CALLS.load(Ordering::SeqCst), 1);
+
+            // Other arguments are cached separately
+            assert_eq!(double(2).await.unwrap(), 4);
+            assert_eq!(CALLS.load(Ordering::SeqCst), 2);
+
+            ServerFnCache::global().invalidate(&Double { value: 1 });
+            assert_eq!(double(1).await.unwrap(), 2);
+            assert_eq!(double(2).await.unwrap(), 4);
+            assert_eq!(CALLS.load(Ordering::SeqCst), 3);
+
+            ServerFnCache::global().invalidate_fn::<Double>();
+            assert_eq!(double(2).await.unwrap(), 4);
+            assert_eq!(CALLS.load(Ordering::SeqCst), 4);
+        });
+    }
+}
@@ -0,0 +1,545 @@
+//! Sessions that are stored with a cookie and read from server functions and server rendering
+
+use std::{
+    collections::{BTreeMap, HashMap},
+    sync::{Arc, Mutex, RwLock},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use hmac::{Hmac, Mac};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use sha2::Sha256;
+
+use crate::middleware::{ServerFnCall, ServerFnMiddleware, ServerFnRejection};
+use crate::server_context::{DioxusServerContext, FromServerContext};
+
+/// The name of the cookie that identifies the session
+pub const SESSION_COOKIE: &str = "dioxus-session";
+
+/// The values stored in a session
+pub type SessionData = BTreeMap<String, serde_json::Value>;
+
+/// Where sessions are stored. The session cookie holds whatever [`SessionStore::save`] returns.
+///
+/// Stores are added with [`crate::prelude::ServeConfigBuilder::session_store`]. Implement this trait to keep sessions
+/// in a database like redis.
+///
+/// # Example
+/// ```rust, ignore
+/// struct RedisStore(redis::Client);
+///
+/// #[async_trait::async_trait]
+/// impl SessionStore for RedisStore {
+///     async fn load(&self, cookie: &str) -> Option<SessionData> {
+///         let mut connection = self.0.get_async_connection().await.ok()?;
+///         let data: String = connection.get(cookie).await.ok()?;
+///         serde_json::from_str(&data).ok()
+///     }
+///
+///     async fn save(&self, cookie: Option<&str>, data: &SessionData) -> Result<String, SessionError> {
+///         let id = cookie.map(ToString::to_string).unwrap_or_else(new_session_id);
+///         let mut connection = self.0.get_async_connection().await.map_err(SessionError::store)?;
+///         connection.set(&id, serde_json::to_string(data)?).await.map_err(SessionError::store)?;
+///         Ok(id)
+///     }
+///
+///     async fn destroy(&self, cookie: &str) {
+///         if let Ok(mut connection) = self.0.get_async_connection().await {
+///             let _: Result<(), _> = connection.del(cookie).await;
+///         }
+///     }
+/// }
+/// ```
+#[async_trait::async_trait]
+pub trait SessionStore: Send + Sync + 'static {
+    /// Load the session the cookie refers to. Returns `None` if the session doesn't exist or the cookie is invalid.
+    async fn load(&self, cookie: &str) -> Option<SessionData>;
+
+    /// Save the session and return the value of the session cookie. `cookie` is the current cookie, if the client
+    /// sent one and it was already saved during this request. The first save of each request passes `None`, so the
+    /// store creates a new session id that an attacker can't know in advance.
+    async fn save(&self, cookie: Option<&str>, data: &SessionData) -> Result<String, SessionError>;
+
+    /// Remove the session the cookie refers to
+    async fn destroy(&self, cookie: &str);
+}
+
+/// Create a random id for a session
+pub fn new_session_id() -> String {
+    let bytes: [u8; 32] = rand::random();
+    URL_SAFE_NO_PAD.encode(bytes)
+}
+
+/// Keeps sessions in memory. Sessions are lost when the server restarts.
+#[derive(Default)]
+pub struct MemoryStore {
+    sessions: RwLock<HashMap<String, SessionData>>,
+}
+
+impl MemoryStore {
+    /// Create an empty store
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait::async_trait]
+impl SessionStore for MemoryStore {
+    async fn load(&self, cookie: &str) -> Option<SessionData> {
+        self.sessions.read().unwrap().get(cookie).cloned()
+    }
+
+    async fn save(&self, cookie: Option<&str>, data: &SessionData) -> Result<String, SessionError> {
+        let id = cookie
+            .filter(|cookie| self.sessions.read().unwrap().contains_key(*cookie))
+            .map(ToString::to_string)
+            .unwrap_or_else(new_session_id);
+        self.sessions
+            .write()
+            .unwrap()
+            .insert(id.clone(), data.clone());
+        Ok(id)
+    }
+
+    async fn destroy(&self, cookie: &str) {
+        self.sessions.write().unwrap().remove(cookie);
+    }
+}
+
+/// Keeps the whole session in the cookie, signed with a secret key so the client can't change it.
+///
+/// The client can read the values of the session, so don't store secrets in it. The server can't revoke a cookie it
+/// signed, so sessions expire after [`CookieStore::max_age`] even if the client keeps the cookie.
+pub struct CookieStore {
+    key: Vec<u8>,
+    max_age: Duration,
+}
+
+/// The session and when it expires, which is what the cookie of a [`CookieStore`] holds
+#[derive(Serialize, Deserialize)]
+struct SignedSession {
+    /// Seconds since the unix epoch
+    expires: u64,
+    data: SessionData,
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs()
+}
+
+impl CookieStore {
+    /// Create a store that signs the sessions with the key. Use at least 32 random bytes, and keep the key the same
+    /// across restarts and servers.
+    ///
+    /// Sessions expire a week after they were last saved.
+    pub fn new(key: impl Into<Vec<u8>>) -> Self {
+        Self {
+            key: key.into(),
+            max_age: Duration::from_secs(7 * 24 * 60 * 60),
+        }
+    }
+
+    /// Set how long a session is valid after it was last saved
+    pub fn max_age(mut self, max_age: Duration) -> Self {
+        self.max_age = max_age;
+        self
+    }
+
+    fn sign(&self, data: &[u8]) -> Vec<u8> {
+        let mut mac =
+            Hmac::<Sha256>::new_from_slice(&self.key).expect("HMAC takes keys of any size");
+        mac.update(data);
+        mac.finalize().into_bytes().to_vec()
+    }
+}
+
+#[async_trait::async_trait]
+impl SessionStore for CookieStore {
+    async fn load(&self, cookie: &str) -> Option<SessionData> {
+        let (data, signature) = cookie.split_once('.')?;
+        let data = URL_SAFE_NO_PAD.decode(data).ok()?;
+        let signature = URL_SAFE_NO_PAD.decode(signature).ok()?;
+        let mut mac = Hmac::<Sha256>::new_from_slice(&self.key).ok()?;
+        mac.update(&data);
+        mac.verify_slice(&signature).ok()?;
+        let session: SignedSession = serde_json::from_slice(&data).ok()?;
+        (session.expires > now()).then_some(session.data)
+    }
+
+    async fn save(&self, _: Option<&str>, data: &SessionData) -> Result<String, SessionError> {
+        let data = serde_json::to_vec(&SignedSession {
+            expires: now().saturating_add(self.max_age.as_secs()),
+            data: data.clone(),
+        })?;
+        let signature = self.sign(&data);
+        Ok(format!(
+            "{}.{}",
+            URL_SAFE_NO_PAD.encode(&data),
+            URL_SAFE_NO_PAD.encode(signature)
+        ))
+    }
+
+    async fn destroy(&self, _: &str) {}
+}
+
+/// The session of the current request, shared by everything that extracts it during the request.
+///
+/// Extract it in a server function with `extract::<Session, _>()` or [`DioxusServerContext::session`]. Changes are
+/// saved to the store right away and the session cookie is added to the response. The cookie is only sent over https
+/// and can't be read from javascript.
+///
+/// The first change in each request moves the session to a new id and removes the old one from the store, so a
+/// session id that was planted in the browser before the user logged in is useless afterwards.
+///
+/// # Example
+/// ```rust, ignore
+/// #[server]
+/// async fn login(name: String, password: String) -> Result<(), ServerFnError> {
+///     check_password(&name, &password).await?;
+///     let session: Session = extract().await?;
+///     session.insert("user", &name).await?;
+///     Ok(())
+/// }
+///
+/// #[server]
+/// async fn current_user() -> Result<Option<String>, ServerFnError> {
+///     let session: Session = extract().await?;
+///     Ok(session.get("user"))
+/// }
+/// ```
+#[derive(Clone)]
+pub struct Session {
+    context: DioxusServerContext,
+    store: Arc<dyn SessionStore>,
+    state: Arc<Mutex<SessionState>>,
+}
+
+struct SessionState {
+    cookie: Option<String>,
+    /// If the session was saved during this request, so it already moved to a new id
+    saved: bool,
+    data: SessionData,
+}
+
+impl Session {
+    /// Get a value of the session
+    pub fn get<T: DeserializeOwned>(&self, key: &str) -> Option<T> {
+        let state = self.state.lock().unwrap();
+        serde_json::from_value(state.data.get(key)?.clone()).ok()
+    }
+
+    /// Check if the session has no values. A client without a session has an empty session.
+    pub fn is_empty(&self) -> bool {
+        self.state.lock().unwrap().data.is_empty()
+    }
+
+    /// Set a value of the session and save it
+    pub async fn insert<T: Serialize>(&self, key: &str, value: &T) -> Result<(), SessionError> {
+        let value = serde_json::to_value(value)?;
+        self.state
+            .lock()
+            .unwrap()
+            .data
+            .insert(key.to_string(), value);
+        self.save().await
+    }
+
+    /// Remove a value of the session and save it
+    pub async fn remove(&self, key: &str) -> Result<(), SessionError> {
+        self.state.lock().unwrap().data.remove(key);
+        self.save().await
+    }
+
+    /// Remove the session from the store and the client, like when the user logs out
+    pub async fn clear(&self) {
+        let cookie = {
+            let mut state = self.state.lock().unwrap();
+            state.data.clear();
+            state.cookie.take()
+        };
+        if let Some(cookie) = cookie {
+            self.store.destroy(&cookie).await;
+        }
+        self.set_cookie(&format!(
+            "{SESSION_COOKIE}=; Path=/; HttpOnly; Secure; SameSite=Lax; Max-Age=0"
+        ));
+    }
+
+    async fn save(&self) -> Result<(), SessionError> {
+        let (previous, cookie, data) = {
+            let mut state = self.state.lock().unwrap();
+            let previous = if state.saved {
+                None
+            } else {
+                state.cookie.take()
+            };
+            state.saved = true;
+            (previous, state.cookie.clone(), state.data.clone())
+        };
+        let cookie = self.store.save(cookie.as_deref(), &data).await?;
+        if let Some(previous) = previous {
+            self.store.destroy(&previous).await;
+        }
+        self.set_cookie(&format!(
+            "{SESSION_COOKIE}={cookie}; Path=/; HttpOnly; Secure; SameSite=Lax"
+        ));
+        self.state.lock().unwrap().cookie = Some(cookie);
+        Ok(())
+    }
+
+    /// Set the session cookie of the response, replacing the session cookie that was set earlier in the request and
+    /// keeping any other cookies
+    fn set_cookie(&self, cookie: &str) {
+        let Ok(value) = http::HeaderValue::from_str(cookie) else {
+            return;
+        };
+        if let Ok(mut parts) = self.context.response_parts_mut() {
+            let session_prefix = format!("{SESSION_COOKIE}=");
+            let others = parts
+                .headers
+                .get_all(http::header::SET_COOKIE)
+                .iter()
+                .filter(|header| !header.as_bytes().starts_with(session_prefix.as_bytes()))
+                .cloned()
+                .collect::<Vec<_>>();
+            parts.headers.remove(http::header::SET_COOKIE);
+            for header in others {
+                parts.headers.append(http::header::SET_COOKIE, header);
+            }
+            parts.headers.append(http::header::SET_COOKIE, value);
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl FromServerContext for Session {
+    type Rejection = SessionError;
+
+    async fn from_request(req: &DioxusServerContext) -> Result<Self, Self::Rejection> {
+        if let Some(session) = req.get::<Session>() {
+            return Ok(session);
+        }
+
+        let store = req
+            .get::<Arc<dyn SessionStore>>()
+            .ok_or(SessionError::MissingStore)?;
+        let cookie = session_cookie(&req.parts.read().await.headers);
+        let data = match &cookie {
+            Some(cookie) => store.load(cookie).await,
+            None => None,
+        };
+        let session = Session {
+            context: req.clone(),
+            store,
+            state: Arc::new(Mutex::new(SessionState {
+                // An unknown cookie can't be reused
+                cookie: data.is_some().then_some(cookie).flatten(),
+                saved: false,
+                data: data.unwrap_or_default(),
+            })),
+        };
+
+        // Later extractions share the session
+        let _ = req.clone().insert(session.clone());
+        Ok(session)
+    }
+}
+
+impl DioxusServerContext {
+    /// Get the session of the request. See [`Session`].
+    pub async fn session(&self) -> Result<Session, SessionError> {
+        Session::from_request(self).await
+    }
+}
+
+fn session_cookie(headers: &http::HeaderMap) -> Option<String> {
+    headers
+        .get_all(http::header::COOKIE)
+        .iter()
+        .filter_map(|header| header.to_str().ok())
+        .flat_map(|header| header.split(';'))
+        .find_map(|cookie| {
+            let (name, value) = cookie.trim().split_once('=')?;
+            (name == SESSION_COOKIE).then(|| value.to_string())
+        })
+}
+
+/// Makes the session store available to server functions
+pub(crate) struct SessionMiddleware(pub(crate) Arc<dyn SessionStore>);
+
+#[async_trait::async_trait]
+impl ServerFnMiddleware for SessionMiddleware {
+    async fn before(&self, call: &ServerFnCall) -> Result<(), ServerFnRejection> {
+        let _ = call.context().clone().insert(self.0.clone());
+        Ok(())
+    }
+}
+
+/// An error while reading or saving a session
+#[derive(Debug, thiserror::Error)]
+pub enum SessionError {
+    /// No session store was added to the server
+    #[error("No session store was added. Add one with ServeConfigBuilder::session_store")]
+    MissingStore,
+    /// The session could not be serialized
+    #[error("Failed to serialize the session: {0}")]
+    Serialize(#[from] serde_json::Error),
+    /// The store failed to save the session
+    #[error("Failed to save the session: {0}")]
+    Store(Box<dyn std::error::Error + Send + Sync>),
+}
+
+impl SessionError {
+    /// Create an error from an error of the store
+    pub fn store(error: impl std::error::Error + Send + Sync + 'static) -> Self {
+        Self::Store(Box::new(error))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn block_on<F: std::future::Future>(f: F) -> F::Output {
+        tokio::runtime::Builder::new_current_thread()
+            .build()
+            .unwrap()
+            .block_on(f)
+    }
+
+    fn context(store: &Arc<dyn SessionStore>, cookie: Option<&str>) -> DioxusServerContext {
+        let mut request = http::Request::builder();
+        if let Some(cookie) = cookie {
+            request = request.header(http::header::COOKIE, format!("theme=dark; {cookie}"));
+        }
+        let (parts, _) = request.body(()).unwrap().into_parts();
+        let mut context = DioxusServerContext::new(Arc::new(tokio::sync::RwLock::new(parts)));
+        context.insert(store.clone()).unwrap();
+        context
+    }
+
+    fn set_cookie(context: &DioxusServerContext) -> String {
+        let parts = context.response_parts().unwrap();
+        let header = parts
+            .headers
+            .get_all(http::header::SET_COOKIE)
+            .iter()
+            .map(|header| header.to_str().unwrap())
+            .find(|header| header.starts_with(SESSION_COOKIE))
+            .unwrap();
+        header.split(';').next().unwrap().to_string()
+    }
+
+    #[test]
+    fn sessions_are_loaded_from_the_cookie() {
+        // Signed cookies hold the whole session, so the server can't revoke them
+        for (store, revocable) in [
+            (Arc::new(MemoryStore::new()) as Arc<dyn SessionStore>, true),
+            (Arc::new(CookieStore::new([7; 32])), false),
+        ] {
+            block_on(async {
+                let first = context(&store, None);
+                let session = first.session().await.unwrap();
+                assert!(session.is_empty());
+                session.insert("user", &"ealmloff").await.unwrap();
+                let cookie = set_cookie(&first);
+
+                // The next request sends the cookie back
+                let second = context(&store, Some(&cookie));
+                let session = second.session().await.unwrap();
+                assert_eq!(session.get::<String>("user").as_deref(), Some("ealmloff"));
+
+                session.clear().await;
+                assert_eq!(set_cookie(&second), format!("{SESSION_COOKIE}="));
+                let third = context(&store, Some(&cookie));
+                assert_eq!(third.session().await.unwrap().is_empty(), revocable);
+            });
+        }
+    }
+
+    #[test]
+    fn signed_cookies_cannot_be_changed() {
+        block_on(async {
+            let store = CookieStore::new([7; 32]);
+            let mut data = SessionData::new();
+            data.insert("user".to_string(), "ealmloff".into());
+            let cookie = store.save(None, &data).await.unwrap();
+            assert_eq!(store.load(&cookie).await, Some(data.clone()));
+
+            data.insert("user".to_string(), "admin".into());
+            let forged = URL_SAFE_NO_PAD.encode(serde_json::to_vec(&data).unwrap());
+            let signature = cookie.split_once('.').unwrap().1;
+            assert_eq!(store.load(&format!("{forged}.{signature}")).await, None);
+            assert_eq!(CookieStore::new([8; 32]).load(&cookie).await, None);
+        });
+    }
+
+    #[test]
+    fn writing_moves_the_session_to_a_new_id() {
+        block_on(async {
+            let store: Arc<dyn SessionStore> = Arc::new(MemoryStore::new());
+            let first = context(&store, None);
+            first
+                .session()
+                .await
+                .unwrap()
+                .insert("count", &1)
+                .await
+                .unwrap();
+            let planted = set_cookie(&first);
+
+            let second = context(&store, Some(&planted));
+            second.response_parts_mut().unwrap().headers.append(
+                http::header::SET_COOKIE,
+                http::HeaderValue::from_static("theme=dark"),
+            );
+            let session = second.session().await.unwrap();
+            session.insert("user", &"ealmloff").await.unwrap();
+            let cookie = set_cookie(&second);
+            assert_ne!(cookie, planted);
+            assert!(store
+                .load(planted.split_once('=').unwrap().1)
+                .await
+                .is_none());
+
+            // Later writes in the same request keep the new id and replace the session cookie
+            session.insert("count", &2).await.unwrap();
+            assert_eq!(set_cookie(&second), cookie);
+            let parts = second.response_parts().unwrap();
+            let headers = parts
+                .headers
+                .get_all(http::header::SET_COOKIE)
+                .iter()
+                .map(|header| header.to_str().unwrap())
+                .collect::<Vec<_>>();
+            assert_eq!(headers.len(), 2);
+            assert_eq!(headers[0], "theme=dark");
+            assert!(headers[1].contains("; Secure;"));
+        });
+    }
+
+    #[test]
+    fn signed_sessions_expire() {
+        block_on(async {
+            let store = CookieStore::new([7; 32]).max_age(Duration::ZERO);
+            let cookie = store.save(None, &SessionData::new()).await.unwrap();
+            assert_eq!(store.load(&cookie).await, None);
+        });
+    }
+
+    #[test]
+    fn extracting_twice_shares_the_session() {
+        block_on(async {
+            let store: Arc<dyn SessionStore> = Arc::new(MemoryStore::new());
+            let context = context(&store, None);
+            let first = context.session().await.unwrap();
+            first.insert("count", &1).await.unwrap();
+            let second = context.session().await.unwrap();
+            assert_eq!(second.get::<i32>("count"), Some(1));
+        });
+    }
+}
@@ -0,0 +1,165 @@
+//! Server functions that stream a sequence of values to the client.
+
+use std::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use bytes::Bytes;
+use futures_util::{Stream, StreamExt};
+use http::Method;
+use serde::{de::DeserializeOwned, Serialize};
+use server_fn::{
+    codec::{Encoding, FromRes, IntoRes},
+    error::{NoCustomError, ServerFnErrorSerde},
+    response::{ClientRes, Res},
+    ServerFnError,
+};
+
+/// An encoding for server functions that return a [`ServerFnStream`].
+///
+/// Each value is sent as a line of JSON as soon as the server produces it, so the client can handle values like progress updates while the server function is still running.
+pub struct StreamingJson;
+
+impl Encoding for StreamingJson {
+    const CONTENT_TYPE: &'static str = "application/x-ndjson";
+    const METHOD: Method = Method::POST;
+}
+
+/// A stream of values returned from a server function with the [`StreamingJson`] output encoding.
+///
+/// On the server, create the stream from any [`Stream`] of values. On the client, the server function returns a stream that yields each value as it arrives. Errors that happen while the stream is running are sent to the client as a [`ServerFnError`].
+///
+/// ```rust, ignore
+/// use dioxus::prelude::*;
+/// use dioxus_fullstack::prelude::*;
+///
+/// #[server(output = StreamingJson)]
+/// async fn import_files() -> Result<ServerFnStream<usize>, ServerFnError> {
+///     let (tx, rx) = futures::channel::mpsc::unbounded();
+///     tokio::spawn(async move {
+///         for progress in 0..=100 {
+///             import_next_file().await;
+///             let _ = tx.unbounded_send(progress);
+///         }
+///     });
+///     Ok(ServerFnStream::new(rx))
+/// }
+///
+/// fn app() -> Element {
+///     let mut progress = use_signal(|| 0);
+///
+///     rsx! {
+///         button {
+///             onclick: move |_| async move {
+///                 let mut stream = import_files().await?;
+///                 while let Some(value) = stream.next().await {
+///                     progress.set(value?);
+///                 }
+///                 Ok::<_, ServerFnError>(())
+///             },
+///             "Import"
+///         }
+///         "{progress}%"
+///     }
+/// }
+/// ```
+pub struct ServerFnStream<T> {
+    stream: Pin<Box<dyn Stream<Item = Result<T, ServerFnError>> + Send>>,
+}
+
+impl<T: Send + 'static> ServerFnStream<T> {
+    /// Create a stream from a stream of values.
+    pub fn new(stream: impl Stream<Item = T> + Send + 'static) -> Self {
+        Self::from_results(stream.map(Ok))
+    }
+
+    /// Create a stream from a stream of results. Errors are sent to the client without ending the stream.
+    pub fn from_results(
+        stream: impl Stream<Item = Result<T, ServerFnError>> + Send + 'static,
+    ) -> Self {
+        Self {
+            stream: Box::pin(stream),
+        }
+    }
+}
+
+impl<T> std::fmt::Debug for ServerFnStream<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ServerFnStream").finish()
+    }
+}
+
+impl<T> Stream for ServerFnStream<T> {
+    type Item = Result<T, ServerFnError>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.stream.as_mut().poll_next(cx)
+    }
+}
+
+impl<T, CustErr, Response> IntoRes<StreamingJson, Response, CustErr> for ServerFnStream<T>
+where
+    T: Serialize + Send + 'static,
+    Response: Res<CustErr>,
+    CustErr: 'static,
+{
+    async fn into_res(self) -> Result<Response, ServerFnError<CustErr>> {
+        let lines = self.stream.map(|item| Ok(encode_line(item)));
+        Response::try_from_stream(StreamingJson::CONTENT_TYPE, lines)
+    }
+}
+
+impl<T, CustErr, Response> FromRes<StreamingJson, Response, CustErr> for ServerFnStream<T>
+where
+    T: DeserializeOwned + Send + 'static,
+    Response: ClientRes<CustErr> + Send,
+{
+    async fn from_res(res: Response) -> Result<Self, ServerFnError<CustErr>> {
+        let chunks = res.try_into_stream()?;
+        let mut buffer = Vec::new();
+        let values = chunks.flat_map(move |chunk| {
+            let values = match chunk {
+                Ok(chunk) => {
+                    buffer.extend_from_slice(&chunk);
+                    let mut values = Vec::new();
+                    // Only decode lines that are complete. The rest of the line is in the next chunk
+                    while let Some(end) = buffer.iter().position(|byte| *byte == b'\n') {
+                        let line = buffer.drain(..=end).collect::<Vec<_>>();
+                        values.push(decode_line(&line[..end]));
+                    }
+                    values
+                }
+                Err(err) => vec![Err(err)],
+            };
+            futures_util::stream::iter(values)
+        });
+        Ok(ServerFnStream::from_results(values))
+    }
+}
+
+/// Encode a value or error as a line of JSON. Errors are sent in the same format as errors returned from server functions
+fn encode_line<T: Serialize>(item: Result<T, ServerFnError>) -> Bytes {
+    let line = match &item {
+        Ok(value) => serde_json::to_vec(&Ok::<_, String>(value)),
+        Err(err) => serde_json::to_vec(&Err::<&T, _>(err.ser().unwrap_or_default())),
+    };
+    let mut line = line.unwrap_or_else(|err| {
+        serde_json::to_vec(&Err::<&T, _>(
+            ServerFnError::<NoCustomError>::Serialization(err.to_string())
+                .ser()
+                .unwrap_or_default(),
+        ))
+        .unwrap_or_default()
+    });
+    line.push(b'\n');
+    line.into()
+}
+
+fn decode_line<T: DeserializeOwned>(line: &[u8]) -> Result<T, ServerFnError> {
+    match serde_json::from_slice::<Result<T, String>>(line) {
+        Ok(Ok(value)) => Ok(value),
+        Ok(Err(err)) => Err(ServerFnError::de(&err)),
+        Err(err) => Err(ServerFnError::Deserialization(err.to_string())),
+    }
+}
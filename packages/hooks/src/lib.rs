@@ -94,3 +94,39 @@ pub use use_hook_did_run::*;
 
 mod use_signal;
 pub use use_signal::*;
+
+mod use_media_query;
+pub use use_media_query::*;
+
+mod use_safe_area;
+pub use use_safe_area::*;
+
+mod use_clipboard;
+pub use use_clipboard::*;
+
+mod use_shortcut;
+pub use use_shortcut::*;
+
+mod use_deep_link;
+pub use use_deep_link::*;
+
+mod use_back_handler;
+pub use use_back_handler::*;
+
+mod use_app_lifecycle;
+pub use use_app_lifecycle::*;
+
+#[cfg(feature = "form")]
+mod use_form;
+#[cfg(feature = "form")]
+pub use use_form::*;
+
+#[cfg(feature = "element-size")]
+mod use_element_size;
+#[cfg(feature = "element-size")]
+pub use use_element_size::*;
+
+#[cfg(feature = "websocket")]
+mod use_websocket;
+#[cfg(feature = "websocket")]
+pub use use_websocket::*;
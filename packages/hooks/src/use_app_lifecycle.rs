@@ -0,0 +1,99 @@
+use dioxus_core::{
+    prelude::{current_scope_id, try_consume_context, use_drop, use_hook, Runtime},
+    ScopeId,
+};
+use dioxus_signals::{CopyValue, Writable};
+use slab::Slab;
+use std::{cell::RefCell, rc::Rc};
+
+/// A change in the lifecycle of a mobile app
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum AppLifecycle {
+    /// The app moved to the background. It may be stopped without another event, so this is the time to save state.
+    Paused,
+    /// The app is in the foreground again
+    Resumed,
+}
+
+/// Call a handler when the app is paused or resumed
+///
+/// ```rust, ignore
+/// fn app() -> Element {
+///     let mut draft = use_signal(String::new);
+///     use_app_lifecycle(move |event| {
+///         if event == AppLifecycle::Paused {
+///             save_draft(&draft.read());
+///         }
+///     });
+///
+///     rsx! {
+///         textarea { value: "{draft}", oninput: move |event| draft.set(event.value()) }
+///     }
+/// }
+/// ```
+///
+/// The handler is called in the scope of the component. On renderers without lifecycle events it is never called.
+pub fn use_app_lifecycle(handler: impl FnMut(AppLifecycle) + 'static) {
+    // Keep the latest handler, like use_callback
+    let mut current = use_hook(|| CopyValue::new(None));
+    current.set(Some(Box::new(handler) as LifecycleCallback));
+
+    let registration = use_hook(|| {
+        let context = try_consume_context::<AppLifecycleContext>()?;
+        let id = context.handlers.borrow_mut().insert(LifecycleHandler {
+            scope: current_scope_id().unwrap(),
+            handler: current,
+        });
+        Some((context, id))
+    });
+    use_drop(move || {
+        if let Some((context, id)) = registration {
+            context.handlers.borrow_mut().try_remove(id);
+        }
+    });
+}
+
+/// The lifecycle handlers of an app.
+///
+/// Renderers with lifecycle events provide this in the root scope with [`AppLifecycleContext::provide`] and call [`AppLifecycleContext::dispatch`] for every event.
+#[derive(Clone)]
+pub struct AppLifecycleContext {
+    handlers: Rc<RefCell<Slab<LifecycleHandler>>>,
+}
+
+type LifecycleCallback = Box<dyn FnMut(AppLifecycle)>;
+
+struct LifecycleHandler {
+    scope: ScopeId,
+    handler: CopyValue<Option<LifecycleCallback>>,
+}
+
+impl AppLifecycleContext {
+    /// Provide the lifecycle handlers in the root scope. This must be called inside the runtime of the VirtualDom.
+    pub fn provide() -> Self {
+        ScopeId::ROOT.provide_context(Self {
+            handlers: Rc::new(RefCell::new(Slab::new())),
+        })
+    }
+
+    /// Call every handler with the event. This must be called inside the runtime of the VirtualDom.
+    pub fn dispatch(&self, event: AppLifecycle) {
+        let Some(runtime) = Runtime::current() else {
+            return;
+        };
+        // handlers may register or remove other handlers
+        let handlers: Vec<_> = self
+            .handlers
+            .borrow()
+            .iter()
+            .map(|(_, handler)| (handler.scope, handler.handler))
+            .collect();
+        for (scope, mut handler) in handlers {
+            runtime.on_scope(scope, || {
+                if let Some(handler) = handler.write().as_mut() {
+                    handler(event);
+                }
+            });
+        }
+    }
+}
@@ -0,0 +1,152 @@
+use crate::{use_callback, UseCallback};
+use dioxus_core::{
+    prelude::{try_consume_context, use_drop, use_hook},
+    ScopeId,
+};
+use slab::Slab;
+use std::{cell::RefCell, rc::Rc};
+
+/// Handle the back button of the platform, like the hardware back button on Android, while `enabled` is true.
+///
+/// Only the enabled handler that was registered last is called. While no handler is enabled, the platform handles the back button itself, which usually closes the app. The router registers a handler that goes back in its history, so a component that registers a handler after the router intercepts the back button before the router does.
+///
+/// ```rust, ignore
+/// fn Dialog(mut open: Signal<bool>) -> Element {
+///     // close the dialog instead of leaving the page
+///     use_back_handler(open(), move || open.set(false));
+///
+///     rsx! {
+///         if open() {
+///             dialog { open: true, "Press back to close" }
+///         }
+///     }
+/// }
+/// ```
+///
+/// On renderers without a back button the handler is never called.
+pub fn use_back_handler(enabled: bool, handler: impl FnMut() + 'static) {
+    let callback = use_callback(handler);
+    let (context, id) = use_hook(|| {
+        let context = try_consume_context::<BackHandlerContext>()
+            .unwrap_or_else(|| BackHandlerContext::new(|_| {}));
+        let id = context.register(callback, enabled);
+        (context, id)
+    });
+    context.set_enabled(id, enabled);
+    use_drop(move || context.remove(id));
+}
+
+/// The back button handlers of an app.
+///
+/// Renderers with a back button provide this in the root scope with [`BackHandlerContext::provide`] and call [`BackHandlerContext::back`] when the back button is pressed.
+#[derive(Clone)]
+pub struct BackHandlerContext {
+    inner: Rc<RefCell<BackHandlers>>,
+    #[allow(clippy::type_complexity)]
+    on_change: Rc<RefCell<Box<dyn FnMut(bool)>>>,
+}
+
+struct BackHandlers {
+    handlers: Slab<RegisteredHandler>,
+    /// The order the handlers were registered in
+    order: Vec<usize>,
+    enabled: bool,
+}
+
+struct RegisteredHandler {
+    callback: UseCallback<()>,
+    enabled: bool,
+}
+
+impl BackHandlerContext {
+    /// Provide the back button handlers in the root scope. This must be called inside the runtime of the VirtualDom.
+    ///
+    /// `on_change` is called with `true` when the first handler is enabled and with `false` when no handler is enabled anymore, so the renderer only intercepts the back button while the app handles it.
+    pub fn provide(on_change: impl FnMut(bool) + 'static) -> Self {
+        ScopeId::ROOT.provide_context(Self::new(on_change))
+    }
+
+    fn new(on_change: impl FnMut(bool) + 'static) -> Self {
+        Self {
+            inner: Rc::new(RefCell::new(BackHandlers {
+                handlers: Slab::new(),
+                order: Vec::new(),
+                enabled: false,
+            })),
+            on_change: Rc::new(RefCell::new(Box::new(on_change))),
+        }
+    }
+
+    /// Check if any handler is enabled
+    pub fn enabled(&self) -> bool {
+        self.inner.borrow().enabled
+    }
+
+    /// Call the enabled handler that was registered last. Returns `false` if no handler is enabled and the platform should handle the back button.
+    pub fn back(&self) -> bool {
+        let callback = {
+            let inner = self.inner.borrow();
+            inner
+                .order
+                .iter()
+                .rev()
+                .map(|id| &inner.handlers[*id])
+                .find(|handler| handler.enabled)
+                .map(|handler| handler.callback)
+        };
+        match callback {
+            Some(mut callback) => {
+                callback.call();
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn register(&self, callback: UseCallback<()>, enabled: bool) -> usize {
+        let id = {
+            let mut inner = self.inner.borrow_mut();
+            let id = inner
+                .handlers
+                .insert(RegisteredHandler { callback, enabled });
+            inner.order.push(id);
+            id
+        };
+        self.update();
+        id
+    }
+
+    fn remove(&self, id: usize) {
+        {
+            let mut inner = self.inner.borrow_mut();
+            inner.handlers.try_remove(id);
+            inner.order.retain(|handler| *handler != id);
+        }
+        self.update();
+    }
+
+    fn set_enabled(&self, id: usize, enabled: bool) {
+        {
+            let mut inner = self.inner.borrow_mut();
+            match inner.handlers.get_mut(id) {
+                Some(handler) if handler.enabled != enabled => handler.enabled = enabled,
+                _ => return,
+            }
+        }
+        self.update();
+    }
+
+    /// Tell the renderer if it should intercept the back button after the handlers changed
+    fn update(&self) {
+        let enabled = {
+            let mut inner = self.inner.borrow_mut();
+            let enabled = inner.handlers.iter().any(|(_, handler)| handler.enabled);
+            if enabled == inner.enabled {
+                return;
+            }
+            inner.enabled = enabled;
+            enabled
+        };
+        (self.on_change.borrow_mut())(enabled);
+    }
+}
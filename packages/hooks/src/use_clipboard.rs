@@ -0,0 +1,131 @@
+use dioxus_core::prelude::{try_consume_context, use_hook};
+use dioxus_signals::{CopyValue, Readable};
+use std::{future::Future, pin::Pin, rc::Rc};
+
+/// Get the clipboard of the platform the app is rendered on.
+///
+/// The same code copies and pastes on every renderer:
+/// - On the web, the clipboard is accessed with the async Clipboard API. Browsers only allow this in a secure context, and may ask the user before the app can read the clipboard.
+/// - On desktop, the system clipboard is used directly.
+/// - In the terminal, text is copied with an OSC 52 escape sequence. Terminals don't let apps read the clipboard, so reading always fails.
+///
+/// Renderers without a clipboard return [`ClipboardError::Unsupported`] from every method.
+///
+/// ```rust, ignore
+/// fn app() -> Element {
+///     let clipboard = use_clipboard();
+///     let mut text = use_signal(String::new);
+///
+///     rsx! {
+///         input { value: "{text}", oninput: move |event| text.set(event.value()) }
+///         button {
+///             onclick: move |_| async move {
+///                 _ = clipboard.write_text(text()).await;
+///             },
+///             "Copy"
+///         }
+///         button {
+///             onclick: move |_| async move {
+///                 if let Ok(pasted) = clipboard.read_text().await {
+///                     text.set(pasted);
+///                 }
+///             },
+///             "Paste"
+///         }
+///     }
+/// }
+/// ```
+pub fn use_clipboard() -> Clipboard {
+    use_hook(|| Clipboard {
+        provider: CopyValue::new(try_consume_context::<Rc<dyn ClipboardProvider>>()),
+    })
+}
+
+/// The clipboard of the platform
+#[derive(Clone, Copy)]
+pub struct Clipboard {
+    provider: CopyValue<Option<Rc<dyn ClipboardProvider>>>,
+}
+
+impl Clipboard {
+    /// Read the text on the clipboard
+    pub async fn read_text(&self) -> Result<String, ClipboardError> {
+        match self.provider.cloned() {
+            Some(provider) => provider.read_text().await,
+            None => Err(ClipboardError::Unsupported),
+        }
+    }
+
+    /// Replace the contents of the clipboard with text
+    pub async fn write_text(&self, text: impl Into<String>) -> Result<(), ClipboardError> {
+        match self.provider.cloned() {
+            Some(provider) => provider.write_text(text.into()).await,
+            None => Err(ClipboardError::Unsupported),
+        }
+    }
+
+    /// Read the image on the clipboard
+    pub async fn read_image(&self) -> Result<ClipboardImage, ClipboardError> {
+        match self.provider.cloned() {
+            Some(provider) => provider.read_image().await,
+            None => Err(ClipboardError::Unsupported),
+        }
+    }
+
+    /// Replace the contents of the clipboard with an image
+    pub async fn write_image(&self, image: ClipboardImage) -> Result<(), ClipboardError> {
+        match self.provider.cloned() {
+            Some(provider) => provider.write_image(image).await,
+            None => Err(ClipboardError::Unsupported),
+        }
+    }
+}
+
+/// An image on the clipboard
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ClipboardImage {
+    /// The width of the image in pixels
+    pub width: usize,
+    /// The height of the image in pixels
+    pub height: usize,
+    /// The pixels of the image, row by row, with four bytes per pixel in RGBA order
+    pub rgba: Vec<u8>,
+}
+
+/// An error that occurred while accessing the clipboard
+#[derive(thiserror::Error, Debug, Clone, PartialEq, Eq)]
+pub enum ClipboardError {
+    /// The renderer can't access the clipboard, or can't store this kind of data on it
+    #[error("the clipboard is not supported by this renderer")]
+    Unsupported,
+    /// The clipboard doesn't contain data of the requested kind
+    #[error("the clipboard doesn't contain data of the requested kind")]
+    Empty,
+    /// The platform denied access to the clipboard or failed to access it
+    #[error("failed to access the clipboard: {0}")]
+    Failed(String),
+}
+
+/// A future returned by a [`ClipboardProvider`]
+pub type ClipboardFuture<T> = Pin<Box<dyn Future<Output = Result<T, ClipboardError>>>>;
+
+/// The clipboard of a renderer.
+///
+/// Renderers provide an `Rc<dyn ClipboardProvider>` in the root scope so [`use_clipboard`] can access the clipboard without knowing which platform it runs on. Images are not supported unless a renderer implements the image methods.
+pub trait ClipboardProvider {
+    /// Read the text on the clipboard
+    fn read_text(&self) -> ClipboardFuture<String>;
+
+    /// Replace the contents of the clipboard with text
+    fn write_text(&self, text: String) -> ClipboardFuture<()>;
+
+    /// Read the image on the clipboard
+    fn read_image(&self) -> ClipboardFuture<ClipboardImage> {
+        Box::pin(async { Err(ClipboardError::Unsupported) })
+    }
+
+    /// Replace the contents of the clipboard with an image
+    fn write_image(&self, _image: ClipboardImage) -> ClipboardFuture<()> {
+        Box::pin(async { Err(ClipboardError::Unsupported) })
+    }
+}
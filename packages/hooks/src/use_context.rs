@@ -2,6 +2,9 @@ use dioxus_core::{
     prelude::{consume_context, provide_context, try_consume_context},
     use_hook,
 };
+use dioxus_signals::{ReadOnlySignal, Readable, Signal};
+
+use crate::use_memo;
 
 /// Consume some context in the tree, providing a sharable handle to the value
 ///
@@ -31,6 +34,31 @@ pub fn use_context<T: 'static + Clone>() -> T {
     use_hook(|| consume_context::<T>())
 }
 
+/// Select part of a [`Signal`] that is provided as context
+///
+/// Reading a shared signal subscribes the component to every write to it. The selected value is a memo instead, so the component only renders again when the selected part changes, compared with `PartialEq`.
+/// ```rust
+/// # use dioxus::prelude::*;
+/// #[derive(Clone, Copy, PartialEq)]
+/// enum Theme { Light, Dark }
+/// struct AppState { theme: Theme, unread: usize }
+///
+/// #[component]
+/// fn ThemeToggle() -> Element {
+///     // Changes to the unread count don't render the toggle again
+///     let theme = use_context_selector(|state: &AppState| state.theme);
+///     rsx! { "dark mode: {theme() == Theme::Dark}" }
+/// }
+/// ```
+#[must_use]
+#[track_caller]
+pub fn use_context_selector<T: 'static, R: PartialEq + 'static>(
+    select: impl Fn(&T) -> R + 'static,
+) -> ReadOnlySignal<R> {
+    let state = use_context::<Signal<T>>();
+    use_memo(move || select(&*state.read()))
+}
+
 /// Provide some context via the tree and return a reference to it
 ///
 /// Once the context has been provided, it is immutable. Mutations should be done via interior mutability.
@@ -0,0 +1,55 @@
+use dioxus_core::{
+    prelude::{try_consume_context, use_hook},
+    ScopeId,
+};
+use dioxus_signals::{ReadOnlySignal, Signal, Writable};
+
+/// Get the last URL the app was opened with through its custom URL scheme, like `myapp://settings/profile`.
+///
+/// Renderers that can register a URL scheme, like desktop, provide a [`DeepLinkContext`]. The URL the app was started with is available in the first render, and the component is rendered again every time the app is opened with another URL, even if it is the same URL as before. On renderers without deep links this is always `None`.
+///
+/// ```rust, ignore
+/// fn app() -> Element {
+///     let link = use_deep_link();
+///
+///     rsx! {
+///         if let Some(url) = link() {
+///             "Opened with {url}"
+///         }
+///     }
+/// }
+/// ```
+///
+/// The router can navigate to the route in the URL on its own with `RouterConfig::deep_links`.
+pub fn use_deep_link() -> ReadOnlySignal<Option<String>> {
+    let context = use_hook(|| {
+        try_consume_context::<DeepLinkContext>().unwrap_or_else(|| DeepLinkContext {
+            url: Signal::new(None),
+        })
+    });
+    context.url.into()
+}
+
+/// The URLs a renderer was opened with.
+///
+/// Renderers that support deep links provide this in the root scope with [`DeepLinkContext::provide`] and call [`DeepLinkContext::open`] when the app is opened with a URL. [`use_deep_link`] reads the last URL.
+#[derive(Clone, Copy)]
+pub struct DeepLinkContext {
+    url: Signal<Option<String>>,
+}
+
+impl DeepLinkContext {
+    /// Provide the URL the app was started with in the root scope. This must be called inside the runtime of the VirtualDom
+    pub fn provide(url: Option<String>) -> Self {
+        let context = Self {
+            url: Signal::new_in_scope(url, ScopeId::ROOT),
+        };
+        ScopeId::ROOT.provide_context(context)
+    }
+
+    /// Tell the app it was opened with a URL. Components that read the URL are rendered again
+    pub fn open(&self, url: impl Into<String>) {
+        let mut signal = self.url;
+        signal.set(Some(url.into()));
+    }
+}
@@ -1,9 +1,18 @@
+use std::{
+    cell::{Cell, RefCell},
+    rc::Rc,
+};
+
 use dioxus_core::prelude::*;
 use dioxus_signals::ReactiveContext;
 
 /// `use_effect` will subscribe to any changes in the signal values it captures
 /// effects will always run after first mount and then whenever the signal values change
 /// If the use_effect call was skipped due to an early return, the effect will no longer activate.
+///
+/// Effects run after the renderer has applied the mutations of the render that happened when the signals changed,
+/// so they can read the layout of elements or use element references. Use [`use_immediate_effect`] to run the
+/// effect as soon as the signals change instead.
 /// ```rust
 /// fn app() -> Element {
 ///     let mut count = use_signal(|| 0);
@@ -18,19 +27,43 @@ use dioxus_signals::ReactiveContext;
 /// }
 /// ```
 #[track_caller]
-pub fn use_effect(mut callback: impl FnMut() + 'static) {
-    // let mut run_effect = use_hook(|| CopyValue::new(true));
-    // use_hook_did_run(move |did_run| run_effect.set(did_run));
+pub fn use_effect(callback: impl FnMut() + 'static) {
+    let location = std::panic::Location::caller();
+
+    use_hook(|| {
+        let rc = ReactiveContext::new_with_origin(location);
+        let effect = QueuedEffect {
+            rc,
+            callback: Rc::new(RefCell::new(callback)),
+            queued: Rc::new(Cell::new(false)),
+        };
+
+        // Run the effect for the first time after the component is mounted
+        effect.queue();
+
+        spawn(async move {
+            loop {
+                // Wait for context to change
+                rc.changed().await;
+
+                effect.queue();
+            }
+        });
+    });
+}
 
+/// Like [`use_effect`], but the effect runs as soon as the signals it reads change, before the renderer has
+/// applied the mutations of the next render.
+///
+/// This is useful for effects that update other state, so the update is included in the same render.
+#[track_caller]
+pub fn use_immediate_effect(mut callback: impl FnMut() + 'static) {
     let location = std::panic::Location::caller();
 
     use_hook(|| {
         spawn(async move {
             let rc = ReactiveContext::new_with_origin(location);
             loop {
-                // Wait for the dom the be finished with sync work
-                // flush_sync().await;
-
                 // Run the effect
                 rc.run_in(&mut callback);
 
@@ -40,3 +73,26 @@ pub fn use_effect(mut callback: impl FnMut() + 'static) {
         });
     });
 }
+
+/// An effect that waits for the next render to be committed before running
+struct QueuedEffect<F> {
+    rc: ReactiveContext,
+    callback: Rc<RefCell<F>>,
+    // Only queue the effect once if the signals change several times before it runs
+    queued: Rc<Cell<bool>>,
+}
+
+impl<F: FnMut() + 'static> QueuedEffect<F> {
+    fn queue(&self) {
+        if self.queued.replace(true) {
+            return;
+        }
+        let rc = self.rc;
+        let callback = self.callback.clone();
+        let queued = self.queued.clone();
+        queue_effect(move || {
+            queued.set(false);
+            rc.run_in(&mut *callback.borrow_mut());
+        });
+    }
+}
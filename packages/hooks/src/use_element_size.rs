@@ -0,0 +1,80 @@
+use std::rc::Rc;
+
+use dioxus_core::prelude::use_hook;
+use dioxus_html::{
+    geometry::{euclid::Rect, PixelsSize},
+    MountedData, MountedEvent,
+};
+use dioxus_signals::{Readable, Signal, Writable};
+
+/// Create a handle that measures an element once it is mounted.
+///
+/// Pass the `onmounted` event of the element to [`ElementSize::mount`]. The size is read from the renderer with [`MountedData::get_client_rect`], which every renderer with layout supports: the web reads the bounding rect of the element, desktop and liveview query the webview, and the TUI reads the layout of the node. The size is `None` until the element is mounted and measured, or if the renderer cannot measure elements.
+///
+/// The size is not updated when the layout changes on its own. Call [`ElementSize::measure`] to read it again, for example after the user resizes the element.
+///
+/// ```rust, ignore
+/// fn app() -> Element {
+///     let size = use_element_size();
+///
+///     rsx! {
+///         textarea {
+///             onmounted: move |event| size.mount(event),
+///             onmouseup: move |_| size.measure(),
+///         }
+///         if let Some(size) = size.size() {
+///             "{size.width} x {size.height}"
+///         }
+///     }
+/// }
+/// ```
+pub fn use_element_size() -> ElementSize {
+    use_hook(|| ElementSize {
+        element: Signal::new(None),
+        rect: Signal::new(None),
+    })
+}
+
+/// A handle to an element and its measured size. Create one with [`use_element_size`].
+#[derive(Clone, Copy, PartialEq)]
+pub struct ElementSize {
+    element: Signal<Option<Rc<MountedData>>>,
+    rect: Signal<Option<Rect<f64, f64>>>,
+}
+
+impl ElementSize {
+    /// Store the element from an `onmounted` event and measure it
+    pub async fn mount(self, event: MountedEvent) {
+        let mut element = self.element;
+        element.set(Some(event.data()));
+        self.measure().await;
+    }
+
+    /// Read the size of the element from the renderer again. Does nothing if the element is not mounted
+    pub async fn measure(self) {
+        let Some(element) = self.element.peek().clone() else {
+            return;
+        };
+        let mut rect = self.rect;
+        let measured = element.get_client_rect().await.ok();
+        if *rect.peek() != measured {
+            rect.set(measured);
+        }
+    }
+
+    /// The mounted element, if it was passed to [`ElementSize::mount`]
+    pub fn element(&self) -> Option<Rc<MountedData>> {
+        self.element.read().clone()
+    }
+
+    /// The bounding rect of the element relative to the viewport when it was last measured
+    pub fn rect(&self) -> Option<Rect<f64, f64>> {
+        *self.rect.read()
+    }
+
+    /// The size of the element when it was last measured
+    pub fn size(&self) -> Option<PixelsSize> {
+        self.rect()
+            .map(|rect| PixelsSize::new(rect.width(), rect.height()))
+    }
+}
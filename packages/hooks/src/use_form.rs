@@ -0,0 +1,447 @@
+use std::{
+    cell::RefCell,
+    collections::{HashMap, HashSet},
+    fmt::Display,
+    future::Future,
+    pin::Pin,
+    rc::Rc,
+    str::FromStr,
+};
+
+use dioxus_core::prelude::*;
+use dioxus_html::FormData;
+use dioxus_signals::{CopyValue, Readable, Signal, Writable};
+
+/// A value that can be edited in a form created with [`use_form`].
+pub trait Validate {
+    /// Check the value and return the errors of every field that is invalid
+    fn validate(&self) -> ValidationErrors;
+}
+
+/// The errors of each field in a form, keyed by the field name.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ValidationErrors {
+    fields: HashMap<&'static str, Vec<String>>,
+}
+
+impl ValidationErrors {
+    /// Create an empty set of errors
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add an error to a field
+    pub fn add(&mut self, field: &'static str, message: impl Into<String>) {
+        self.fields.entry(field).or_default().push(message.into());
+    }
+
+    /// Get the errors of a field
+    pub fn field(&self, field: &str) -> &[String] {
+        self.fields
+            .get(field)
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
+    /// Returns true if no field has an error
+    pub fn is_empty(&self) -> bool {
+        self.fields.is_empty()
+    }
+
+    /// Iterate over every field that has errors
+    pub fn iter(&self) -> impl Iterator<Item = (&'static str, &[String])> {
+        self.fields
+            .iter()
+            .map(|(field, errors)| (*field, errors.as_slice()))
+    }
+
+    fn extend(&mut self, other: &ValidationErrors) {
+        for (field, errors) in other.iter() {
+            self.fields
+                .entry(field)
+                .or_default()
+                .extend_from_slice(errors);
+        }
+    }
+}
+
+type AsyncValidator<T> = Rc<dyn Fn(T) -> Pin<Box<dyn Future<Output = ValidationErrors>>>>;
+
+#[derive(Default)]
+struct FormState {
+    dirty: HashSet<&'static str>,
+    touched: HashSet<&'static str>,
+    errors: ValidationErrors,
+    parse_errors: ValidationErrors,
+    async_errors: ValidationErrors,
+    validating: bool,
+    submitting: bool,
+    submit_count: usize,
+}
+
+/// Create a form that edits a value of type `T`.
+///
+/// Each field of the value is bound to an input with [`UseForm::field`]. The field generates the `oninput` and `onblur` handlers for the input and tracks if the field is dirty (edited), touched (blurred) and its errors.
+///
+/// The value is validated with [`Validate`] every time a field changes. Validation that needs to wait for something, like checking if a username is taken on a server, can be added with [`UseForm::async_validator`].
+///
+/// ```rust, ignore
+/// #[derive(Clone, Default)]
+/// struct SignUp {
+///     name: String,
+///     age: u8,
+/// }
+///
+/// impl Validate for SignUp {
+///     fn validate(&self) -> ValidationErrors {
+///         let mut errors = ValidationErrors::new();
+///         if self.name.is_empty() {
+///             errors.add("name", "Enter your name");
+///         }
+///         if self.age < 13 {
+///             errors.add("age", "You must be at least 13 years old");
+///         }
+///         errors
+///     }
+/// }
+///
+/// fn app() -> Element {
+///     let form = use_form(SignUp::default);
+///     let name = form.field("name", |form| &mut form.name);
+///     let age = form.field("age", |form| &mut form.age);
+///
+///     rsx! {
+///         form {
+///             prevent_default: "onsubmit",
+///             onsubmit: form.on_submit(|sign_up: SignUp| async move {
+///                 println!("Welcome {}!", sign_up.name);
+///             }),
+///             input { value: "{name}", oninput: name.oninput(), onblur: name.onblur() }
+///             if let Some(error) = name.visible_error() {
+///                 p { "{error}" }
+///             }
+///             input { r#type: "number", value: "{age}", oninput: age.oninput(), onblur: age.onblur() }
+///             if let Some(error) = age.visible_error() {
+///                 p { "{error}" }
+///             }
+///             button { disabled: form.is_submitting(), "Sign up" }
+///         }
+///     }
+/// }
+/// ```
+pub fn use_form<T: Validate + Clone + 'static>(init: impl FnOnce() -> T) -> UseForm<T> {
+    use_hook(|| {
+        let value = init();
+        let state = FormState {
+            errors: value.validate(),
+            ..Default::default()
+        };
+        UseForm {
+            initial: CopyValue::new(value.clone()),
+            value: CopyValue::new(value),
+            state: Signal::new(state),
+            async_validator: CopyValue::new(None),
+            validation: CopyValue::new(None),
+        }
+    })
+}
+
+/// A form created with [`use_form`].
+pub struct UseForm<T: 'static> {
+    initial: CopyValue<T>,
+    // The value is only read through the state signal, which is written every time the value changes
+    value: CopyValue<T>,
+    state: Signal<FormState>,
+    async_validator: CopyValue<Option<AsyncValidator<T>>>,
+    validation: CopyValue<Option<Task>>,
+}
+
+impl<T: 'static> Clone for UseForm<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: 'static> Copy for UseForm<T> {}
+
+impl<T: Validate + Clone + 'static> UseForm<T> {
+    /// Bind a field of the value to an input. The name is used to look up the errors of the field in [`ValidationErrors`].
+    pub fn field<V: 'static>(
+        &self,
+        name: &'static str,
+        lens: fn(&mut T) -> &mut V,
+    ) -> FormField<T, V> {
+        FormField {
+            form: *self,
+            name,
+            lens,
+        }
+    }
+
+    /// Get a clone of the current value. Reading the value in a component subscribes the component to changes
+    pub fn value(&self) -> T {
+        self.state.read();
+        self.value.read().clone()
+    }
+
+    /// Replace the whole value and validate it
+    pub fn set_value(&self, value: T) {
+        let mut current = self.value;
+        current.set(value);
+        let mut state = self.state;
+        state.write().parse_errors = ValidationErrors::new();
+        self.validate();
+    }
+
+    /// Set the validator that runs after the sync validation every time the value changes.
+    ///
+    /// Only the latest validation runs: if the value changes while the validator is running, it is cancelled and started again with the new value.
+    pub fn async_validator<F>(&self, validator: impl Fn(T) -> F + 'static)
+    where
+        F: Future<Output = ValidationErrors> + 'static,
+    {
+        let mut async_validator = self.async_validator;
+        async_validator.set(Some(Rc::new(move |value| Box::pin(validator(value)))));
+    }
+
+    /// Get the errors of every field, including the errors from the async validator
+    pub fn errors(&self) -> ValidationErrors {
+        let state = self.state.read();
+        let mut errors = state.errors.clone();
+        errors.extend(&state.parse_errors);
+        errors.extend(&state.async_errors);
+        errors
+    }
+
+    /// Returns true if no field has an error
+    pub fn is_valid(&self) -> bool {
+        let state = self.state.read();
+        state.errors.is_empty() && state.parse_errors.is_empty() && state.async_errors.is_empty()
+    }
+
+    /// Returns true if any field was edited
+    pub fn is_dirty(&self) -> bool {
+        !self.state.read().dirty.is_empty()
+    }
+
+    /// Returns true while the async validator is running
+    pub fn is_validating(&self) -> bool {
+        self.state.read().validating
+    }
+
+    /// Returns true while the form is being submitted
+    pub fn is_submitting(&self) -> bool {
+        self.state.read().submitting
+    }
+
+    /// Get the number of times the form was submitted
+    pub fn submit_count(&self) -> usize {
+        self.state.read().submit_count
+    }
+
+    /// Reset the form to its initial value and clear the dirty and touched state of every field
+    pub fn reset(&self) {
+        self.cancel_validation();
+        let mut value = self.value;
+        let initial = self.initial.read().clone();
+        let errors = initial.validate();
+        value.set(initial);
+        let mut state = self.state;
+        state.set(FormState {
+            errors,
+            ..Default::default()
+        });
+    }
+
+    /// Run the sync validation and restart the async validation with the current value
+    pub fn validate(&self) {
+        let errors = self.value.read().validate();
+        let mut state = self.state;
+        state.write().errors = errors;
+
+        self.cancel_validation();
+        if self.async_validator.read().is_some() {
+            let mut validation = self.validation;
+            validation.set(Some(spawn(self.validate_async())));
+        }
+    }
+
+    /// Create an event handler that submits the form. This is usually used as the `onsubmit` handler of a `form` element.
+    ///
+    /// Submitting marks every field as touched and validates the value. The handler is only called if the value is valid.
+    pub fn on_submit<E, F>(&self, handler: impl FnMut(T) -> F + 'static) -> impl FnMut(Event<E>)
+    where
+        F: Future<Output = ()> + 'static,
+    {
+        let form = *self;
+        let handler = Rc::new(RefCell::new(handler));
+        move |_| {
+            let handler = handler.clone();
+            form.submit(move |value| handler.borrow_mut()(value));
+        }
+    }
+
+    /// Submit the form, calling the handler with the value if it is valid. Returns the task that validates and submits the form.
+    pub fn submit<F>(&self, handler: impl FnOnce(T) -> F + 'static) -> Task
+    where
+        F: Future<Output = ()> + 'static,
+    {
+        self.cancel_validation();
+        let form = *self;
+        let mut state = self.state;
+        {
+            let mut state = state.write();
+            state.submit_count += 1;
+            state.submitting = true;
+            state.errors = form.value.read().validate();
+        }
+
+        spawn(async move {
+            form.validate_async().await;
+            if form.is_valid() {
+                let value = form.value.read().clone();
+                handler(value).await;
+            }
+            state.write().submitting = false;
+        })
+    }
+
+    fn validate_async(self) -> impl Future<Output = ()> {
+        let validator = self.async_validator.read().clone();
+        let value = self.value.read().clone();
+        let mut state = self.state;
+        async move {
+            let Some(validator) = validator else {
+                return;
+            };
+            state.write().validating = true;
+            let errors = validator(value).await;
+            let mut state = state.write();
+            state.async_errors = errors;
+            state.validating = false;
+        }
+    }
+
+    fn cancel_validation(&self) {
+        let mut validation = self.validation;
+        let task = validation.write().take();
+        if let Some(task) = task {
+            task.cancel();
+        }
+    }
+
+    fn changed(&self, field: &'static str) {
+        let mut state = self.state;
+        state.write().dirty.insert(field);
+        self.validate();
+    }
+}
+
+/// A field of a form bound to an input with [`UseForm::field`].
+///
+/// Displaying the field displays its value, so it can be used as the `value` of an input.
+pub struct FormField<T: 'static, V: 'static> {
+    form: UseForm<T>,
+    name: &'static str,
+    lens: fn(&mut T) -> &mut V,
+}
+
+impl<T: 'static, V: 'static> Clone for FormField<T, V> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: 'static, V: 'static> Copy for FormField<T, V> {}
+
+impl<T: Validate + Clone + 'static, V: Clone + 'static> FormField<T, V> {
+    /// Get the name of the field
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Get a clone of the value of the field. Reading the value in a component subscribes the component to changes
+    pub fn value(&self) -> V {
+        self.form.state.read();
+        let mut value = self.form.value;
+        let mut value = value.write();
+        (self.lens)(&mut value).clone()
+    }
+
+    /// Set the value of the field, mark it as dirty and validate the form
+    pub fn set(&self, value: V) {
+        let mut form_value = self.form.value;
+        *(self.lens)(&mut form_value.write()) = value;
+        let mut state = self.form.state;
+        state.write().parse_errors.fields.remove(self.name);
+        self.form.changed(self.name);
+    }
+
+    /// Get the errors of the field
+    pub fn errors(&self) -> Vec<String> {
+        self.form.errors().field(self.name).to_vec()
+    }
+
+    /// Get the first error of the field if the field was touched or the form was submitted. This is the error that should usually be shown to the user.
+    pub fn visible_error(&self) -> Option<String> {
+        if !self.is_touched() {
+            return None;
+        }
+        self.errors().into_iter().next()
+    }
+
+    /// Returns true if the field was edited
+    pub fn is_dirty(&self) -> bool {
+        self.form.state.read().dirty.contains(self.name)
+    }
+
+    /// Returns true if the input of the field lost focus or the form was submitted
+    pub fn is_touched(&self) -> bool {
+        let state = self.form.state.read();
+        state.touched.contains(self.name) || state.submit_count > 0
+    }
+
+    /// Mark the field as touched
+    pub fn touch(&self) {
+        let mut state = self.form.state;
+        if !state.peek().touched.contains(self.name) {
+            state.write().touched.insert(self.name);
+        }
+    }
+
+    /// Create an event handler that marks the field as touched. This is usually used as the `onblur` handler of the input.
+    pub fn onblur<E>(&self) -> impl FnMut(Event<E>) {
+        let field = *self;
+        move |_| field.touch()
+    }
+}
+
+impl<T, V> FormField<T, V>
+where
+    T: Validate + Clone + 'static,
+    V: FromStr + Clone + 'static,
+    V::Err: Display,
+{
+    /// Create an event handler that parses the value of the input and sets the field. This is usually used as the `oninput` handler of the input.
+    ///
+    /// If the value can't be parsed, the field keeps its last value and the parse error is added to the errors of the field.
+    pub fn oninput(&self) -> impl FnMut(Event<FormData>) {
+        let field = *self;
+        move |event| match event.value().parse() {
+            Ok(value) => field.set(value),
+            Err(error) => {
+                let mut state = field.form.state;
+                let mut state = state.write();
+                state.dirty.insert(field.name);
+                state.parse_errors.fields.remove(field.name);
+                state.parse_errors.add(field.name, error.to_string());
+            }
+        }
+    }
+}
+
+impl<T: Validate + Clone + 'static, V: Clone + Display + 'static> Display for FormField<T, V> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.value().fmt(f)
+    }
+}
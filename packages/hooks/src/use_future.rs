@@ -14,6 +14,10 @@ use std::future::Future;
 /// To run a future on the server, you should use `spawn` directly.
 /// `use_future` **won't return a value**.
 /// If you want to return a value from a future, use `use_resource` instead.
+///
+/// Signals read in the closure, before the future is created, are dependencies of the future. When any of them change,
+/// the running future is cancelled and the closure is called again to start a new one. Signals read inside the future
+/// itself are not tracked.
 /// ```rust
 /// fn app() -> Element {
 ///     let mut count = use_signal(|| 0);
@@ -41,9 +45,11 @@ where
     F: Future + 'static,
 {
     let mut state = use_signal(|| UseFutureState::Pending);
+    let rc = use_hook(ReactiveContext::new);
 
     let mut callback = use_callback(move || {
-        let fut = future();
+        // Subscribe to the signals read while creating the future
+        let fut = rc.run_in(&mut future);
         spawn(async move {
             flush_sync().await;
             state.set(UseFutureState::Pending);
@@ -53,7 +59,20 @@ where
     });
 
     // Create the task inside a copyvalue so we can reset it in-place later
-    let task = use_hook(|| CopyValue::new(callback.call()));
+    let mut task = use_hook(|| CopyValue::new(callback.call()));
+
+    use_hook(|| {
+        spawn(async move {
+            loop {
+                // Wait for the dependencies to change
+                rc.changed().await;
+
+                // Cancel the old future and start a new one
+                task.write().cancel();
+                task.set(callback.call());
+            }
+        })
+    });
 
     // Early returns in dioxus have consequences for use_memo, use_resource, and use_future, etc
     // We *don't* want futures to be running if the component early returns. It's a rather weird behavior to have
@@ -98,10 +117,7 @@ pub enum UseFutureState {
 }
 
 impl UseFuture {
-    /// Restart the future with new dependencies.
-    ///
-    /// Will not cancel the previous future, but will ignore any values that it
-    /// generates.
+    /// Cancel the running future and start it again.
     pub fn restart(&mut self) {
         self.task.write().cancel();
         let new_task = self.callback.call();
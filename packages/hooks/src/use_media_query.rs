@@ -0,0 +1,243 @@
+use dioxus_core::{
+    prelude::{try_consume_context, use_hook},
+    ScopeId,
+};
+use dioxus_signals::{Readable, Signal, Writable};
+
+/// Returns true if a CSS media query like `"(max-width: 600px)"` matches the window the app is rendered in.
+///
+/// The component is rendered again when the result might have changed, so responsive layouts can be written once and used on every renderer:
+/// - On the web, the query is evaluated with `window.matchMedia` and updated with its change events. This requires the `media-query` feature.
+/// - Renderers without `matchMedia`, like desktop, provide a [`ViewportContext`] that is updated when the window is resized. The query is evaluated against that viewport.
+///
+/// If there is no way to evaluate the query, this returns false.
+///
+/// ```rust, ignore
+/// fn app() -> Element {
+///     let mobile = use_media_query("(max-width: 600px)");
+///
+///     rsx! {
+///         if mobile {
+///             MobileNav {}
+///         } else {
+///             Sidebar {}
+///         }
+///     }
+/// }
+/// ```
+pub fn use_media_query(query: &str) -> bool {
+    #[cfg(all(target_arch = "wasm32", feature = "media-query"))]
+    if let Some(matches) = web::use_match_media(query) {
+        return matches;
+    }
+
+    let viewport = use_hook(try_consume_context::<ViewportContext>);
+    match viewport {
+        Some(viewport) => viewport.viewport().matches(query),
+        None => false,
+    }
+}
+
+/// The size and color scheme of the window an app is rendered in. This is used to evaluate media queries on renderers without `matchMedia`.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Viewport {
+    /// The width of the window in logical pixels
+    pub width: f64,
+    /// The height of the window in logical pixels
+    pub height: f64,
+    /// If the user prefers a dark color scheme
+    pub prefers_dark: bool,
+}
+
+impl Viewport {
+    /// Returns true if a media query matches this viewport.
+    ///
+    /// This supports query lists separated by commas, the `not` and `only` keywords, the `all`, `screen` and `print` media types, and the `width`, `height`, `orientation`, `aspect-ratio` and `prefers-color-scheme` features with their `min-` and `max-` variants. Lengths may be in `px`, `em` or `rem`. Queries that can't be parsed don't match.
+    pub fn matches(&self, query: &str) -> bool {
+        query
+            .split(',')
+            .any(|query| self.matches_query(query).unwrap_or(false))
+    }
+
+    fn matches_query(&self, query: &str) -> Option<bool> {
+        let query = query.trim().to_ascii_lowercase();
+        let (negated, query) = match query.strip_prefix("not ") {
+            Some(rest) => (true, rest.trim_start()),
+            None => (false, query.strip_prefix("only ").unwrap_or(&query)),
+        };
+
+        let mut matches = true;
+        for (i, condition) in query.split(" and ").enumerate() {
+            let condition = condition.trim();
+            let result = match condition
+                .strip_prefix('(')
+                .and_then(|condition| condition.strip_suffix(')'))
+            {
+                Some(feature) => self.matches_feature(feature.trim())?,
+                // Only the first part of a query may be a media type
+                None if i == 0 => match condition {
+                    "all" | "screen" => true,
+                    "print" => false,
+                    _ => return None,
+                },
+                None => return None,
+            };
+            matches &= result;
+        }
+
+        Some(matches != negated)
+    }
+
+    fn matches_feature(&self, feature: &str) -> Option<bool> {
+        let Some((name, value)) = feature.split_once(':') else {
+            // A feature without a value matches if it is not zero
+            return match feature {
+                "width" => Some(self.width > 0.),
+                "height" => Some(self.height > 0.),
+                "orientation" | "prefers-color-scheme" | "aspect-ratio" => Some(true),
+                _ => None,
+            };
+        };
+        let (name, value) = (name.trim(), value.trim());
+
+        let (compare, name): (fn(f64, f64) -> bool, _) =
+            if let Some(name) = name.strip_prefix("min-") {
+                (|actual, expected| actual >= expected, name)
+            } else if let Some(name) = name.strip_prefix("max-") {
+                (|actual, expected| actual <= expected, name)
+            } else {
+                (|actual, expected| actual == expected, name)
+            };
+
+        match name {
+            "width" => Some(compare(self.width, parse_length(value)?)),
+            "height" => Some(compare(self.height, parse_length(value)?)),
+            "aspect-ratio" => Some(compare(self.width / self.height, parse_ratio(value)?)),
+            "orientation" => match value {
+                "portrait" => Some(self.height >= self.width),
+                "landscape" => Some(self.width > self.height),
+                _ => None,
+            },
+            "prefers-color-scheme" => match value {
+                "dark" => Some(self.prefers_dark),
+                "light" => Some(!self.prefers_dark),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+}
+
+/// Parse a length in logical pixels
+fn parse_length(value: &str) -> Option<f64> {
+    // Media queries resolve em and rem against the initial font size
+    const FONT_SIZE: f64 = 16.;
+    if let Some(value) = value.strip_suffix("px") {
+        value.trim().parse().ok()
+    } else if let Some(value) = value
+        .strip_suffix("rem")
+        .or_else(|| value.strip_suffix("em"))
+    {
+        value
+            .trim()
+            .parse::<f64>()
+            .ok()
+            .map(|value| value * FONT_SIZE)
+    } else {
+        // Zero is the only length that doesn't need a unit
+        value.parse::<f64>().ok().filter(|value| *value == 0.)
+    }
+}
+
+/// Parse a ratio like `16/9`
+fn parse_ratio(value: &str) -> Option<f64> {
+    match value.split_once('/') {
+        Some((width, height)) => {
+            Some(width.trim().parse::<f64>().ok()? / height.trim().parse::<f64>().ok()?)
+        }
+        None => value.parse().ok(),
+    }
+}
+
+/// The viewport of a renderer that evaluates media queries itself.
+///
+/// Renderers without `matchMedia` provide this in the root scope with [`ViewportContext::provide`] and call [`ViewportContext::set`] when the window is resized. [`use_media_query`] reads the viewport so components are rendered again when it changes.
+#[derive(Clone, Copy)]
+pub struct ViewportContext {
+    viewport: Signal<Viewport>,
+}
+
+impl ViewportContext {
+    /// Provide the viewport in the root scope. This must be called inside the runtime of the VirtualDom
+    pub fn provide(viewport: Viewport) -> Self {
+        let context = Self {
+            viewport: Signal::new_in_scope(viewport, ScopeId::ROOT),
+        };
+        ScopeId::ROOT.provide_context(context)
+    }
+
+    /// Get the current viewport. Reading the viewport in a component subscribes the component to changes
+    pub fn viewport(&self) -> Viewport {
+        *self.viewport.read()
+    }
+
+    /// Update the viewport. Components that read the viewport are only rendered again if it changed
+    pub fn set(&self, viewport: Viewport) {
+        let mut signal = self.viewport;
+        if *signal.peek() != viewport {
+            signal.set(viewport);
+        }
+    }
+}
+
+#[cfg(all(target_arch = "wasm32", feature = "media-query"))]
+mod web {
+    use std::{cell::RefCell, rc::Rc, sync::Arc};
+
+    use dioxus_core::prelude::{schedule_update, use_hook};
+    use wasm_bindgen::{prelude::Closure, JsCast};
+    use web_sys::MediaQueryList;
+
+    /// Evaluate a query with `matchMedia`. Returns None if `matchMedia` is not available
+    pub(super) fn use_match_media(query: &str) -> Option<bool> {
+        let update = use_hook(schedule_update);
+        let state = use_hook(|| Rc::new(RefCell::new(None::<MatchMedia>)));
+
+        let mut state = state.borrow_mut();
+        // The query changed since the last render
+        if state.as_ref().map(|state| state.query.as_str()) != Some(query) {
+            *state = MatchMedia::new(query, update);
+        }
+        state.as_ref().map(|state| state.list.matches())
+    }
+
+    /// A media query list that renders the component again when it changes. The listener is removed when this is dropped.
+    struct MatchMedia {
+        query: String,
+        list: MediaQueryList,
+        listener: Closure<dyn FnMut()>,
+    }
+
+    impl MatchMedia {
+        fn new(query: &str, update: Arc<dyn Fn() + Send + Sync>) -> Option<Self> {
+            let list = web_sys::window()?.match_media(query).ok()??;
+            let listener = Closure::<dyn FnMut()>::new(move || update());
+            list.add_event_listener_with_callback("change", listener.as_ref().unchecked_ref())
+                .ok()?;
+            Some(Self {
+                query: query.to_string(),
+                list,
+                listener,
+            })
+        }
+    }
+
+    impl Drop for MatchMedia {
+        fn drop(&mut self) {
+            _ = self.list.remove_event_listener_with_callback(
+                "change",
+                self.listener.as_ref().unchecked_ref(),
+            );
+        }
+    }
+}
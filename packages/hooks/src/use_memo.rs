@@ -1,3 +1,5 @@
+use std::{cell::RefCell, rc::Rc};
+
 use crate::dependency::Dependency;
 use crate::use_signal;
 use dioxus_core::prelude::*;
@@ -160,3 +162,47 @@ where
 
     selector
 }
+
+/// Compute a value during render and cache it until the dependencies change. `is_equal` decides if the dependencies are the same as the dependencies of the cached value.
+///
+/// Unlike [`use_memo_with_dependencies`], the value is computed again in the same render that the dependencies change in, and signals read in `compute` are not tracked.
+/// Dependencies that don't implement `PartialEq`, or that should only be compared by some of their fields, can be compared with `is_equal`.
+///
+/// ```rust
+/// use dioxus::prelude::*;
+///
+/// #[derive(Clone)]
+/// struct Document {
+///     id: usize,
+///     version: usize,
+///     text: String,
+/// }
+///
+/// #[component]
+/// fn WordCount(document: ReadOnlySignal<Document>) -> Element {
+///     let words = use_memo_by(
+///         document(),
+///         |old, new| old.id == new.id && old.version == new.version,
+///         |document| document.text.split_whitespace().count(),
+///     );
+///
+///     rsx! { "{words} words" }
+/// }
+/// ```
+#[track_caller]
+pub fn use_memo_by<D: 'static, R: Clone + 'static>(
+    dependencies: D,
+    is_equal: impl FnOnce(&D, &D) -> bool,
+    compute: impl FnOnce(&D) -> R,
+) -> R {
+    let cache = use_hook(|| Rc::new(RefCell::new(None::<(D, R)>)));
+    let mut cache = cache.borrow_mut();
+
+    let unchanged = matches!(&*cache, Some((cached, _)) if is_equal(cached, &dependencies));
+    if !unchanged {
+        let value = compute(&dependencies);
+        *cache = Some((dependencies, value));
+    }
+
+    cache.as_ref().map(|(_, value)| value.clone()).unwrap()
+}
@@ -0,0 +1,85 @@
+use dioxus_core::{
+    prelude::{try_consume_context, use_hook},
+    ScopeId,
+};
+use dioxus_signals::{Readable, Signal, Writable};
+
+/// Returns the insets of the window that are covered by the system, like a notch, rounded corners, the home indicator or the on-screen keyboard.
+///
+/// The component is rendered again when the insets change, for example when the device is rotated or the keyboard opens. Renderers that can't measure the insets, like desktop windows, report zero insets.
+///
+/// ```rust, ignore
+/// fn app() -> Element {
+///     let insets = use_safe_area();
+///
+///     rsx! {
+///         div {
+///             padding_top: "{insets.top}px",
+///             // keep the toolbar above the keyboard
+///             padding_bottom: "{insets.bottom.max(insets.keyboard)}px",
+///             Content {}
+///             Toolbar {}
+///         }
+///     }
+/// }
+/// ```
+pub fn use_safe_area() -> SafeArea {
+    let context = use_hook(try_consume_context::<SafeAreaContext>);
+    match context {
+        Some(context) => context.safe_area(),
+        None => SafeArea::default(),
+    }
+}
+
+/// The insets of a window in logical pixels
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct SafeArea {
+    /// The inset from the top edge, like the status bar or a notch
+    pub top: f64,
+    /// The inset from the right edge
+    pub right: f64,
+    /// The inset from the bottom edge, like the home indicator
+    pub bottom: f64,
+    /// The inset from the left edge
+    pub left: f64,
+    /// The height of the on-screen keyboard, or zero if it is closed
+    pub keyboard: f64,
+}
+
+impl SafeArea {
+    /// Returns true if the on-screen keyboard is open
+    pub fn keyboard_open(&self) -> bool {
+        self.keyboard > 0.
+    }
+}
+
+/// The safe area of a renderer that can measure it.
+///
+/// Renderers provide this in the root scope with [`SafeAreaContext::provide`] and call [`SafeAreaContext::set`] when the insets change. [`use_safe_area`] reads the insets so components are rendered again when they change.
+#[derive(Clone, Copy)]
+pub struct SafeAreaContext {
+    safe_area: Signal<SafeArea>,
+}
+
+impl SafeAreaContext {
+    /// Provide the safe area in the root scope. This must be called inside the runtime of the VirtualDom
+    pub fn provide(safe_area: SafeArea) -> Self {
+        let context = Self {
+            safe_area: Signal::new_in_scope(safe_area, ScopeId::ROOT),
+        };
+        ScopeId::ROOT.provide_context(context)
+    }
+
+    /// Get the current insets. Reading the insets in a component subscribes the component to changes
+    pub fn safe_area(&self) -> SafeArea {
+        *self.safe_area.read()
+    }
+
+    /// Update the insets. Components that read the insets are only rendered again if they changed
+    pub fn set(&self, safe_area: SafeArea) {
+        let mut signal = self.safe_area;
+        if *signal.peek() != safe_area {
+            signal.set(safe_area);
+        }
+    }
+}
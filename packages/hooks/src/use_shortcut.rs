@@ -0,0 +1,321 @@
+use crate::{use_callback, UseCallback};
+use dioxus_core::{
+    prelude::{try_consume_context, use_drop, use_hook},
+    ScopeId,
+};
+use slab::Slab;
+use std::{cell::RefCell, fmt::Display, rc::Rc, str::FromStr};
+
+/// Call a handler when a keyboard shortcut like `"ctrl+k"` is pressed anywhere in the app.
+///
+/// The shortcut is registered the first time the component is rendered and removed when the component is dropped. It works the same on every renderer:
+/// - On the web, the shortcut is handled with a keydown listener on the document, which is removed when no shortcut is registered. The default action of the browser is prevented when the shortcut is handled.
+/// - On desktop, the shortcut is registered in the same registry as `use_global_shortcut`.
+/// - In the terminal, the shortcut is matched against the key events of crossterm before they reach the focused element.
+///
+/// Shortcuts are parsed with [`Shortcut::from_str`]. Each shortcut can only be registered once at a time, so registering a shortcut that is already registered returns [`ShortcutError::Conflict`]. On renderers without a keyboard the handler is never called.
+///
+/// ```rust, ignore
+/// fn app() -> Element {
+///     let mut open = use_signal(|| false);
+///     _ = use_shortcut("ctrl+k", move || open.set(true));
+///
+///     rsx! {
+///         if open() {
+///             CommandPalette { open }
+///         }
+///     }
+/// }
+/// ```
+pub fn use_shortcut(shortcut: &str, handler: impl FnMut() + 'static) -> Result<(), ShortcutError> {
+    let callback = use_callback(handler);
+    let registered = use_hook(|| {
+        let context = try_consume_context::<ShortcutContext>()?;
+        let id = shortcut
+            .parse()
+            .and_then(|shortcut| context.register(shortcut, callback));
+        Some((context, id))
+    });
+    use_drop({
+        let registered = registered.clone();
+        move || {
+            if let Some((context, Ok(id))) = registered {
+                context.remove(id);
+            }
+        }
+    });
+    match registered {
+        Some((_, Err(err))) => Err(err),
+        _ => Ok(()),
+    }
+}
+
+/// A key combined with modifiers, like `ctrl+shift+p`
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Shortcut {
+    modifiers: ShortcutModifiers,
+    key: String,
+}
+
+/// The modifier keys that have to be held for a [`Shortcut`]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct ShortcutModifiers {
+    /// The control key
+    pub ctrl: bool,
+    /// The alt key, or the option key on macOS
+    pub alt: bool,
+    /// The shift key
+    pub shift: bool,
+    /// The meta key, like the command key on macOS or the windows key
+    pub meta: bool,
+}
+
+/// The names of keys that are not letters, digits or function keys, with the name of their physical key in the `code` of a `KeyboardEvent`
+const NAMED_KEYS: &[(&str, &str)] = &[
+    ("enter", "Enter"),
+    ("escape", "Escape"),
+    ("space", "Space"),
+    ("tab", "Tab"),
+    ("backspace", "Backspace"),
+    ("delete", "Delete"),
+    ("insert", "Insert"),
+    ("home", "Home"),
+    ("end", "End"),
+    ("pageup", "PageUp"),
+    ("pagedown", "PageDown"),
+    ("arrowup", "ArrowUp"),
+    ("arrowdown", "ArrowDown"),
+    ("arrowleft", "ArrowLeft"),
+    ("arrowright", "ArrowRight"),
+    ("comma", "Comma"),
+    ("period", "Period"),
+    ("slash", "Slash"),
+    ("backslash", "Backslash"),
+    ("semicolon", "Semicolon"),
+    ("quote", "Quote"),
+    ("backquote", "Backquote"),
+    ("minus", "Minus"),
+    ("equal", "Equal"),
+    ("bracketleft", "BracketLeft"),
+    ("bracketright", "BracketRight"),
+];
+
+/// Other names of keys that are accepted when a shortcut is parsed
+const KEY_ALIASES: &[(&str, &str)] = &[
+    ("esc", "escape"),
+    ("return", "enter"),
+    ("del", "delete"),
+    ("up", "arrowup"),
+    ("down", "arrowdown"),
+    ("left", "arrowleft"),
+    ("right", "arrowright"),
+    (" ", "space"),
+    (",", "comma"),
+    (".", "period"),
+    ("/", "slash"),
+    ("\\", "backslash"),
+    (";", "semicolon"),
+    ("'", "quote"),
+    ("`", "backquote"),
+    ("-", "minus"),
+    ("=", "equal"),
+    ("[", "bracketleft"),
+    ("]", "bracketright"),
+];
+
+impl Shortcut {
+    /// Create a shortcut from the name of a key, like `"k"`, `"enter"`, `"f5"` or `","`. Letters are case insensitive.
+    pub fn new(modifiers: ShortcutModifiers, key: &str) -> Result<Self, ShortcutError> {
+        let key = key.to_lowercase();
+        let key = KEY_ALIASES
+            .iter()
+            .find(|(alias, _)| *alias == key)
+            .map(|(_, name)| name.to_string())
+            .unwrap_or(key);
+        let valid = match key.as_bytes() {
+            [c] => c.is_ascii_alphanumeric(),
+            [b'f', number @ ..] => std::str::from_utf8(number)
+                .ok()
+                .and_then(|number| number.parse::<u8>().ok())
+                .is_some_and(|number| (1..=24).contains(&number)),
+            _ => false,
+        } || NAMED_KEYS.iter().any(|(name, _)| *name == key);
+        if !valid {
+            return Err(ShortcutError::Invalid(format!("unknown key {key:?}")));
+        }
+        Ok(Self { modifiers, key })
+    }
+
+    /// Create a shortcut from the `code` of a `KeyboardEvent`, like `"KeyK"` or `"ArrowUp"`. Returns `None` for keys that can't be part of a shortcut.
+    pub fn from_code(modifiers: ShortcutModifiers, code: &str) -> Option<Self> {
+        let key = code
+            .strip_prefix("Key")
+            .or_else(|| code.strip_prefix("Digit"))
+            .filter(|key| key.len() == 1)
+            .or_else(|| {
+                NAMED_KEYS
+                    .iter()
+                    .find(|(_, name)| *name == code)
+                    .map(|(key, _)| *key)
+            })
+            .unwrap_or(code);
+        Self::new(modifiers, key).ok()
+    }
+
+    /// The modifiers that have to be held
+    pub fn modifiers(&self) -> ShortcutModifiers {
+        self.modifiers
+    }
+
+    /// The normalized name of the key, like `"k"`, `"enter"` or `"comma"`
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    /// The `code` of a `KeyboardEvent` for the physical key of this shortcut, like `"KeyK"` or `"ArrowUp"`
+    pub fn code(&self) -> String {
+        match self.key.as_bytes() {
+            [c] if c.is_ascii_digit() => format!("Digit{}", self.key),
+            [_] => format!("Key{}", self.key.to_uppercase()),
+            _ => NAMED_KEYS
+                .iter()
+                .find(|(key, _)| *key == self.key)
+                .map(|(_, code)| code.to_string())
+                .unwrap_or_else(|| self.key.to_uppercase()),
+        }
+    }
+}
+
+impl FromStr for Shortcut {
+    type Err = ShortcutError;
+
+    /// Parse a shortcut like `"ctrl+shift+p"`. The modifiers `ctrl`, `alt`, `shift` and `meta` come before the key, separated by `+`. `control`, `option`, `cmd`, `command` and `super` are accepted as well.
+    fn from_str(shortcut: &str) -> Result<Self, Self::Err> {
+        let mut modifiers = ShortcutModifiers::default();
+        let mut parts = shortcut.split('+').map(str::trim).peekable();
+        while let Some(part) = parts.next() {
+            if parts.peek().is_none() {
+                return Self::new(modifiers, part);
+            }
+            let modifier = match part.to_lowercase().as_str() {
+                "ctrl" | "control" => &mut modifiers.ctrl,
+                "alt" | "option" => &mut modifiers.alt,
+                "shift" => &mut modifiers.shift,
+                "meta" | "cmd" | "command" | "super" => &mut modifiers.meta,
+                _ => {
+                    return Err(ShortcutError::Invalid(format!(
+                        "unknown modifier {part:?} in {shortcut:?}"
+                    )))
+                }
+            };
+            *modifier = true;
+        }
+        Err(ShortcutError::Invalid("the shortcut is empty".to_string()))
+    }
+}
+
+impl Display for Shortcut {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let modifiers = [
+            (self.modifiers.ctrl, "ctrl+"),
+            (self.modifiers.alt, "alt+"),
+            (self.modifiers.shift, "shift+"),
+            (self.modifiers.meta, "meta+"),
+        ];
+        for (held, name) in modifiers {
+            if held {
+                f.write_str(name)?;
+            }
+        }
+        f.write_str(&self.key)
+    }
+}
+
+/// An error that occurred while registering a shortcut
+#[derive(thiserror::Error, Debug, Clone, PartialEq, Eq)]
+pub enum ShortcutError {
+    /// The shortcut couldn't be parsed
+    #[error("invalid shortcut: {0}")]
+    Invalid(String),
+    /// The shortcut is already registered, or the platform doesn't let apps handle it
+    #[error("the shortcut {0} is already in use")]
+    Conflict(Shortcut),
+    /// The platform failed to register the shortcut
+    #[error("failed to register the shortcut: {0}")]
+    Failed(String),
+}
+
+/// The keyboard shortcuts of an app.
+///
+/// Renderers with a keyboard provide this in the root scope with [`ShortcutContext::provide`] and call [`ShortcutContext::dispatch`] when a key is pressed.
+#[derive(Clone)]
+pub struct ShortcutContext {
+    shortcuts: Rc<RefCell<Slab<RegisteredShortcut>>>,
+    #[allow(clippy::type_complexity)]
+    on_change:
+        Rc<RefCell<Box<dyn FnMut(&ShortcutContext, &Shortcut, bool) -> Result<(), ShortcutError>>>>,
+}
+
+struct RegisteredShortcut {
+    shortcut: Shortcut,
+    callback: UseCallback<()>,
+}
+
+impl ShortcutContext {
+    /// Provide the shortcuts in the root scope. This must be called inside the runtime of the VirtualDom.
+    ///
+    /// `on_change` is called with `true` before a shortcut is registered and with `false` after it is removed, so the renderer can listen for the shortcut. If it returns an error, the shortcut is not registered.
+    pub fn provide(
+        on_change: impl FnMut(&ShortcutContext, &Shortcut, bool) -> Result<(), ShortcutError> + 'static,
+    ) -> Self {
+        ScopeId::ROOT.provide_context(Self {
+            shortcuts: Rc::new(RefCell::new(Slab::new())),
+            on_change: Rc::new(RefCell::new(Box::new(on_change))),
+        })
+    }
+
+    /// Call the handler of a shortcut that was pressed. Returns `false` if the shortcut is not registered and the key should be handled as usual.
+    pub fn dispatch(&self, shortcut: &Shortcut) -> bool {
+        let callback = self
+            .shortcuts
+            .borrow()
+            .iter()
+            .find(|(_, registered)| registered.shortcut == *shortcut)
+            .map(|(_, registered)| registered.callback);
+        match callback {
+            Some(mut callback) => {
+                callback.call();
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn register(
+        &self,
+        shortcut: Shortcut,
+        callback: UseCallback<()>,
+    ) -> Result<usize, ShortcutError> {
+        let registered = self
+            .shortcuts
+            .borrow()
+            .iter()
+            .any(|(_, registered)| registered.shortcut == shortcut);
+        if registered {
+            return Err(ShortcutError::Conflict(shortcut));
+        }
+        (self.on_change.borrow_mut())(self, &shortcut, true)?;
+        Ok(self
+            .shortcuts
+            .borrow_mut()
+            .insert(RegisteredShortcut { shortcut, callback }))
+    }
+
+    fn remove(&self, id: usize) {
+        let removed = self.shortcuts.borrow_mut().try_remove(id);
+        if let Some(removed) = removed {
+            // The shortcut is already removed, so there is nothing to do if the renderer fails to forget it
+            _ = (self.on_change.borrow_mut())(self, &removed.shortcut, false);
+        }
+    }
+}
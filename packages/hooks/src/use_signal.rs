@@ -1,5 +1,5 @@
 use dioxus_core::prelude::*;
-use dioxus_signals::{Signal, SignalData, Storage, SyncStorage, UnsyncStorage};
+use dioxus_signals::{Readable, Signal, SignalData, Storage, SyncStorage, UnsyncStorage};
 
 /// Creates a new Signal. Signals are a Copy state management solution with automatic dependency tracking.
 ///
@@ -88,10 +88,39 @@ fn use_maybe_signal_sync<T: 'static, U: Storage<SignalData<T>>>(
     // use_before_render(move || signal.unsubscribe(current_scope_id().unwrap()));
 
     use_hook(|| {
-        Signal::new_with_caller(
+        let signal: Signal<T, U> = Signal::new_with_caller(
             f(),
             #[cfg(debug_assertions)]
             caller,
-        )
+        );
+
+        // Show the value of the signal in the devtools
+        #[cfg(debug_assertions)]
+        describe_hook(current_hook_index(), move || match signal.try_read() {
+            Ok(value) => debug_value(&*value),
+            Err(_) => "<borrowed>".to_string(),
+        });
+
+        signal
     })
 }
+
+/// Format values of common types. Signals don't require their value to implement [`Debug`](std::fmt::Debug), so other types are shown by name.
+#[cfg(debug_assertions)]
+fn debug_value<T: 'static>(value: &T) -> String {
+    let value: &dyn std::any::Any = value;
+    macro_rules! debug_types {
+        ($($ty:ty);*) => {
+            $(
+                if let Some(value) = value.downcast_ref::<$ty>() {
+                    return format!("{value:?}");
+                }
+            )*
+        };
+    }
+    debug_types!(
+        bool; char; i8; i16; i32; i64; i128; isize; u8; u16; u32; u64; u128; usize; f32; f64;
+        String; &'static str; ()
+    );
+    std::any::type_name::<T>().to_string()
+}
@@ -0,0 +1,466 @@
+use dioxus_core::{
+    prelude::{spawn, use_hook},
+    Task,
+};
+use dioxus_signals::*;
+use futures_channel::mpsc::{unbounded, UnboundedReceiver, UnboundedSender};
+use futures_util::{
+    future::{self, Either},
+    pin_mut, StreamExt,
+};
+use serde::{de::DeserializeOwned, Serialize};
+use std::time::Duration;
+
+use platform::{sleep, Connection};
+
+/// Connect to a WebSocket server and exchange messages serialized as JSON.
+///
+/// The connection is opened when the component is created and closed when the component is dropped. If the connection fails or is lost, it is reconnected with an exponential backoff as configured in the [`WebSocketOptions`].
+///
+/// Reading [`UseWebSocket::latest`] or [`UseWebSocket::state`] subscribes the component to new messages and connection changes. Use [`UseWebSocket::recv`] to process every message in order.
+///
+/// On the web, the connection uses the browser's `WebSocket`. On other platforms, it uses `tokio-tungstenite` and must be polled from inside of a tokio runtime.
+///
+/// ```rust, no_run
+/// use dioxus::prelude::*;
+///
+/// #[derive(serde::Serialize, serde::Deserialize, Clone)]
+/// struct Chat {
+///     text: String,
+/// }
+///
+/// fn app() -> Element {
+///     let socket = use_websocket::<Chat, Chat>("ws://localhost:8080", WebSocketOptions::default());
+///
+///     rsx! {
+///         button {
+///             onclick: move |_| socket.send(Chat { text: "Hello".to_string() }),
+///             "Send"
+///         }
+///         if let Some(message) = socket.latest().read().as_ref() {
+///             "{message.text}"
+///         }
+///     }
+/// }
+/// ```
+#[must_use]
+pub fn use_websocket<In, Out>(
+    url: impl ToString,
+    options: WebSocketOptions,
+) -> UseWebSocket<In, Out>
+where
+    In: DeserializeOwned + Clone + 'static,
+    Out: Serialize + 'static,
+{
+    use_hook(|| {
+        let (tx, rx) = unbounded();
+        let socket = UseWebSocket {
+            state: Signal::new(WebSocketState::Connecting),
+            latest: Signal::new(None),
+            error: Signal::new(None),
+            outgoing: CopyValue::new(tx),
+            incoming: CopyValue::new(None),
+            buffer: CopyValue::new(None),
+            task: CopyValue::new(None),
+        };
+        let mut task = socket.task;
+        task.set(Some(spawn(socket.run(url.to_string(), options, rx))));
+        socket
+    })
+}
+
+/// Options for the connection of [`use_websocket`]
+#[derive(Clone, Debug, PartialEq)]
+pub struct WebSocketOptions {
+    reconnect: bool,
+    initial_backoff: Duration,
+    max_backoff: Duration,
+    max_retries: Option<usize>,
+}
+
+impl Default for WebSocketOptions {
+    fn default() -> Self {
+        Self {
+            reconnect: true,
+            initial_backoff: Duration::from_millis(500),
+            max_backoff: Duration::from_secs(30),
+            max_retries: None,
+        }
+    }
+}
+
+impl WebSocketOptions {
+    /// Create the default options. The connection is reconnected forever with a backoff from half a second up to 30 seconds.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set whether the connection is reconnected after it fails or is closed by the server
+    pub fn with_reconnect(mut self, reconnect: bool) -> Self {
+        self.reconnect = reconnect;
+        self
+    }
+
+    /// Set the delay before the first reconnect. The delay doubles after each failed attempt until it reaches the max delay
+    pub fn with_backoff(mut self, initial: Duration, max: Duration) -> Self {
+        self.initial_backoff = initial;
+        self.max_backoff = max;
+        self
+    }
+
+    /// Set the number of reconnects that are attempted before giving up, or None to keep trying forever. The count is reset every time a connection opens
+    pub fn with_max_retries(mut self, max_retries: Option<usize>) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+}
+
+/// The state of the connection of [`use_websocket`]
+#[derive(Clone, Copy, PartialEq, Hash, Eq, Debug)]
+pub enum WebSocketState {
+    /// The connection is being opened
+    Connecting,
+
+    /// The connection is open and messages are sent immediately
+    Open,
+
+    /// The connection was lost and is waiting to reconnect
+    Reconnecting,
+
+    /// The connection is closed and will not be reconnected
+    Closed,
+}
+
+/// An error from the connection of [`use_websocket`]
+#[derive(thiserror::Error, Clone, Debug, PartialEq)]
+pub enum WebSocketError {
+    /// The connection could not be opened
+    #[error("Failed to connect: {0}")]
+    Connect(String),
+
+    /// The connection failed after it was opened
+    #[error("Connection failed: {0}")]
+    Connection(String),
+
+    /// A message could not be serialized
+    #[error("Failed to serialize message: {0}")]
+    Serialize(String),
+
+    /// A message from the server could not be deserialized
+    #[error("Failed to deserialize message: {0}")]
+    Deserialize(String),
+}
+
+/// A handle to the connection of [`use_websocket`]
+pub struct UseWebSocket<In: 'static, Out: 'static> {
+    state: Signal<WebSocketState>,
+    latest: Signal<Option<In>>,
+    error: Signal<Option<WebSocketError>>,
+    outgoing: CopyValue<UnboundedSender<Out>>,
+    incoming: CopyValue<Option<UnboundedReceiver<In>>>,
+    buffer: CopyValue<Option<UnboundedSender<In>>>,
+    task: CopyValue<Option<Task>>,
+}
+
+impl<In: DeserializeOwned + Clone + 'static, Out: Serialize + 'static> UseWebSocket<In, Out> {
+    /// Queue a message to send to the server. Messages sent while the connection is not open are sent once it opens
+    pub fn send(&self, message: Out) {
+        let _ = self.outgoing.read().unbounded_send(message);
+    }
+
+    /// Wait for the next message from the server. Messages are buffered from the first call to this method, so calling it in a loop does not miss any messages.
+    ///
+    /// Returns None once the connection is closed and will not be reconnected.
+    pub async fn recv(&mut self) -> Option<In> {
+        if self.incoming.peek().is_none() {
+            if *self.state.peek() == WebSocketState::Closed {
+                return None;
+            }
+            let (tx, rx) = unbounded();
+            self.buffer.set(Some(tx));
+            self.incoming.set(Some(rx));
+        }
+        let mut incoming = self.incoming;
+        future::poll_fn(|cx| {
+            incoming
+                .write()
+                .as_mut()
+                .expect("the receiver is created before it is polled")
+                .poll_next_unpin(cx)
+        })
+        .await
+    }
+
+    /// The last message received from the server
+    pub fn latest(&self) -> ReadOnlySignal<Option<In>> {
+        self.latest.into()
+    }
+
+    /// The state of the connection
+    pub fn state(&self) -> ReadOnlySignal<WebSocketState> {
+        self.state.into()
+    }
+
+    /// The last error from the connection. This is not cleared when the connection reconnects
+    pub fn error(&self) -> ReadOnlySignal<Option<WebSocketError>> {
+        self.error.into()
+    }
+
+    /// Close the connection. It will not be reconnected
+    pub fn close(&mut self) {
+        if let Some(task) = self.task.write().take() {
+            task.cancel();
+        }
+        self.state.set(WebSocketState::Closed);
+        self.buffer.set(None);
+    }
+
+    async fn run(
+        mut self,
+        url: String,
+        options: WebSocketOptions,
+        mut outgoing: UnboundedReceiver<Out>,
+    ) {
+        let mut backoff = options.initial_backoff;
+        let mut retries = 0;
+        loop {
+            match Connection::connect(&url).await {
+                Ok(mut connection) => {
+                    self.state.set(WebSocketState::Open);
+                    backoff = options.initial_backoff;
+                    retries = 0;
+                    loop {
+                        let event = {
+                            let received = connection.receive();
+                            pin_mut!(received);
+                            match future::select(outgoing.next(), received).await {
+                                Either::Left((message, _)) => Either::Left(message),
+                                Either::Right((message, _)) => Either::Right(message),
+                            }
+                        };
+                        match event {
+                            Either::Left(Some(message)) => {
+                                let text = match serde_json::to_string(&message) {
+                                    Ok(text) => text,
+                                    Err(err) => {
+                                        self.error
+                                            .set(Some(WebSocketError::Serialize(err.to_string())));
+                                        continue;
+                                    }
+                                };
+                                if let Err(err) = connection.send(text).await {
+                                    self.error.set(Some(err));
+                                    break;
+                                }
+                            }
+                            // The handle was dropped
+                            Either::Left(None) => return,
+                            Either::Right(Some(Ok(text))) => self.receive(&text),
+                            Either::Right(Some(Err(err))) => {
+                                self.error.set(Some(err));
+                                break;
+                            }
+                            // The server closed the connection
+                            Either::Right(None) => break,
+                        }
+                    }
+                }
+                Err(err) => self.error.set(Some(err)),
+            }
+
+            let out_of_retries = options.max_retries.is_some_and(|max| retries >= max);
+            if !options.reconnect || out_of_retries {
+                break;
+            }
+            retries += 1;
+            self.state.set(WebSocketState::Reconnecting);
+            sleep(backoff).await;
+            backoff = (backoff * 2).min(options.max_backoff);
+            self.state.set(WebSocketState::Connecting);
+        }
+
+        self.state.set(WebSocketState::Closed);
+        // End the stream of messages for anyone waiting in recv
+        self.buffer.set(None);
+    }
+
+    fn receive(&mut self, text: &str) {
+        match serde_json::from_str::<In>(text) {
+            Ok(message) => {
+                if let Some(buffer) = &*self.buffer.peek() {
+                    let _ = buffer.unbounded_send(message.clone());
+                }
+                self.latest.set(Some(message));
+            }
+            Err(err) => self
+                .error
+                .set(Some(WebSocketError::Deserialize(err.to_string()))),
+        }
+    }
+}
+
+// manual impl since deriving doesn't work with generics
+impl<In, Out> Copy for UseWebSocket<In, Out> {}
+
+impl<In, Out> Clone for UseWebSocket<In, Out> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+mod platform {
+    use super::WebSocketError;
+    use futures_util::{SinkExt, StreamExt};
+    use std::time::Duration;
+    use tokio::net::TcpStream;
+    use tokio_tungstenite::{tungstenite::Message, MaybeTlsStream, WebSocketStream};
+
+    pub(super) struct Connection(WebSocketStream<MaybeTlsStream<TcpStream>>);
+
+    impl Connection {
+        pub(super) async fn connect(url: &str) -> Result<Self, WebSocketError> {
+            let (stream, _) = tokio_tungstenite::connect_async(url)
+                .await
+                .map_err(|err| WebSocketError::Connect(err.to_string()))?;
+            Ok(Self(stream))
+        }
+
+        pub(super) async fn send(&mut self, text: String) -> Result<(), WebSocketError> {
+            self.0
+                .send(Message::Text(text))
+                .await
+                .map_err(|err| WebSocketError::Connection(err.to_string()))
+        }
+
+        /// Wait for the next text message. Returns None when the connection is closed
+        pub(super) async fn receive(&mut self) -> Option<Result<String, WebSocketError>> {
+            loop {
+                let message = match self.0.next().await? {
+                    Ok(message) => message,
+                    Err(err) => return Some(Err(WebSocketError::Connection(err.to_string()))),
+                };
+                match message {
+                    Message::Text(text) => return Some(Ok(text)),
+                    Message::Binary(bytes) => {
+                        return Some(
+                            String::from_utf8(bytes)
+                                .map_err(|err| WebSocketError::Deserialize(err.to_string())),
+                        )
+                    }
+                    Message::Close(_) => return None,
+                    // tungstenite answers pings for us
+                    Message::Ping(_) | Message::Pong(_) | Message::Frame(_) => {}
+                }
+            }
+        }
+    }
+
+    pub(super) async fn sleep(duration: Duration) {
+        tokio::time::sleep(duration).await
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+mod platform {
+    use super::WebSocketError;
+    use futures_channel::mpsc::{unbounded, UnboundedReceiver};
+    use futures_util::StreamExt;
+    use std::time::Duration;
+    use wasm_bindgen::{prelude::Closure, JsCast, JsValue};
+    use web_sys::{BinaryType, MessageEvent, WebSocket};
+
+    enum SocketEvent {
+        Open,
+        Message(Result<String, WebSocketError>),
+        Error,
+        Close,
+    }
+
+    pub(super) struct Connection {
+        socket: WebSocket,
+        events: UnboundedReceiver<SocketEvent>,
+        _callbacks: [Closure<dyn FnMut(JsValue)>; 4],
+    }
+
+    impl Connection {
+        pub(super) async fn connect(url: &str) -> Result<Self, WebSocketError> {
+            let socket =
+                WebSocket::new(url).map_err(|err| WebSocketError::Connect(format!("{err:?}")))?;
+            socket.set_binary_type(BinaryType::Arraybuffer);
+
+            let (tx, events) = unbounded();
+            let callback = |to_event: fn(JsValue) -> SocketEvent| {
+                let tx = tx.clone();
+                Closure::<dyn FnMut(JsValue)>::new(move |event| {
+                    let _ = tx.unbounded_send(to_event(event));
+                })
+            };
+            let onopen = callback(|_| SocketEvent::Open);
+            let onmessage = callback(|event| SocketEvent::Message(message_text(event)));
+            let onerror = callback(|_| SocketEvent::Error);
+            let onclose = callback(|_| SocketEvent::Close);
+            socket.set_onopen(Some(onopen.as_ref().unchecked_ref()));
+            socket.set_onmessage(Some(onmessage.as_ref().unchecked_ref()));
+            socket.set_onerror(Some(onerror.as_ref().unchecked_ref()));
+            socket.set_onclose(Some(onclose.as_ref().unchecked_ref()));
+
+            let mut connection = Self {
+                socket,
+                events,
+                _callbacks: [onopen, onmessage, onerror, onclose],
+            };
+            match connection.events.next().await {
+                Some(SocketEvent::Open) => Ok(connection),
+                _ => Err(WebSocketError::Connect(format!(
+                    "Failed to connect to {url}"
+                ))),
+            }
+        }
+
+        pub(super) async fn send(&mut self, text: String) -> Result<(), WebSocketError> {
+            self.socket
+                .send_with_str(&text)
+                .map_err(|err| WebSocketError::Connection(format!("{err:?}")))
+        }
+
+        /// Wait for the next text message. Returns None when the connection is closed
+        pub(super) async fn receive(&mut self) -> Option<Result<String, WebSocketError>> {
+            loop {
+                match self.events.next().await? {
+                    SocketEvent::Message(message) => return Some(message),
+                    SocketEvent::Error => {
+                        return Some(Err(WebSocketError::Connection(
+                            "The socket reported an error".to_string(),
+                        )))
+                    }
+                    SocketEvent::Close => return None,
+                    SocketEvent::Open => {}
+                }
+            }
+        }
+    }
+
+    fn message_text(event: JsValue) -> Result<String, WebSocketError> {
+        let data = event.unchecked_into::<MessageEvent>().data();
+        if let Some(text) = data.as_string() {
+            return Ok(text);
+        }
+        let bytes = js_sys::Uint8Array::new(&data).to_vec();
+        String::from_utf8(bytes).map_err(|err| WebSocketError::Deserialize(err.to_string()))
+    }
+
+    impl Drop for Connection {
+        fn drop(&mut self) {
+            self.socket.set_onopen(None);
+            self.socket.set_onmessage(None);
+            self.socket.set_onerror(None);
+            self.socket.set_onclose(None);
+            let _ = self.socket.close();
+        }
+    }
+
+    pub(super) async fn sleep(duration: Duration) {
+        gloo_timers::future::sleep(duration).await
+    }
+}
@@ -0,0 +1,95 @@
+use dioxus::prelude::*;
+use dioxus_hooks::{AppLifecycleContext, BackHandlerContext};
+use std::{cell::RefCell, rc::Rc};
+
+type Log = Rc<RefCell<Vec<String>>>;
+
+#[derive(Props, Clone, PartialEq)]
+struct AppProps {
+    log: Log,
+    dialog_open: bool,
+}
+
+fn app(props: AppProps) -> Element {
+    let log = props.log.clone();
+    use_back_handler(true, move || log.borrow_mut().push("app".to_string()));
+
+    let log = props.log.clone();
+    use_app_lifecycle(move |event| log.borrow_mut().push(format!("{event:?}")));
+
+    let open = use_signal(|| props.dialog_open);
+
+    rsx! {
+        Dialog { log: props.log.clone(), open }
+    }
+}
+
+#[component]
+fn Dialog(log: Log, open: Signal<bool>) -> Element {
+    use_back_handler(open(), move || {
+        log.borrow_mut().push("dialog".to_string());
+        open.set(false);
+    });
+    None
+}
+
+#[test]
+fn the_last_enabled_handler_is_called() {
+    let log = Log::default();
+    let enabled = Rc::new(RefCell::new(Vec::new()));
+    let mut dom = VirtualDom::new_with_props(
+        app,
+        AppProps {
+            log: log.clone(),
+            dialog_open: true,
+        },
+    );
+    let back = dom.in_runtime(|| {
+        let enabled = enabled.clone();
+        BackHandlerContext::provide(move |value| enabled.borrow_mut().push(value))
+    });
+    dom.rebuild_in_place();
+    assert_eq!(*enabled.borrow(), [true]);
+
+    // The dialog was registered last, and closes itself
+    assert!(dom.in_runtime(|| back.back()));
+    dom.render_immediate_to_vec();
+    assert!(dom.in_runtime(|| back.back()));
+    assert_eq!(*log.borrow(), ["dialog", "app"]);
+
+    // The renderer is told when no handler is enabled anymore
+    drop(dom);
+    assert_eq!(*enabled.borrow(), [true, false]);
+}
+
+#[test]
+fn lifecycle_events_reach_every_handler() {
+    let log = Log::default();
+    let mut dom = VirtualDom::new_with_props(
+        app,
+        AppProps {
+            log: log.clone(),
+            dialog_open: false,
+        },
+    );
+    let lifecycle = dom.in_runtime(AppLifecycleContext::provide);
+    dom.rebuild_in_place();
+
+    dom.in_runtime(|| lifecycle.dispatch(AppLifecycle::Paused));
+    dom.in_runtime(|| lifecycle.dispatch(AppLifecycle::Resumed));
+    assert_eq!(*log.borrow(), ["Paused", "Resumed"]);
+}
+
+#[test]
+fn handlers_are_never_called_without_a_renderer_that_supports_them() {
+    let log = Log::default();
+    let mut dom = VirtualDom::new_with_props(
+        app,
+        AppProps {
+            log: log.clone(),
+            dialog_open: true,
+        },
+    );
+    dom.rebuild_in_place();
+    assert!(log.borrow().is_empty());
+}
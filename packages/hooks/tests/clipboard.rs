@@ -0,0 +1,65 @@
+use dioxus::prelude::*;
+use dioxus_hooks::{Clipboard, ClipboardError, ClipboardFuture, ClipboardProvider};
+use std::{cell::RefCell, rc::Rc};
+
+/// A clipboard that only stores text
+#[derive(Default)]
+struct MemoryClipboard(RefCell<Option<String>>);
+
+impl ClipboardProvider for MemoryClipboard {
+    fn read_text(&self) -> ClipboardFuture<String> {
+        let text = self.0.borrow().clone();
+        Box::pin(async move { text.ok_or(ClipboardError::Empty) })
+    }
+
+    fn write_text(&self, text: String) -> ClipboardFuture<()> {
+        *self.0.borrow_mut() = Some(text);
+        Box::pin(async { Ok(()) })
+    }
+}
+
+/// The clipboard is owned by the component, so the dom must be kept alive while it is used
+fn clipboard_of(provider: Option<Rc<dyn ClipboardProvider>>) -> (VirtualDom, Clipboard) {
+    let clipboard = Rc::new(RefCell::new(None));
+    let mut dom = VirtualDom::new_with_props(
+        |clipboard: Rc<RefCell<Option<Clipboard>>>| {
+            *clipboard.borrow_mut() = Some(use_clipboard());
+            None
+        },
+        clipboard.clone(),
+    );
+    if let Some(provider) = provider {
+        dom = dom.with_root_context(provider);
+    }
+    dom.rebuild_in_place();
+    let clipboard = clipboard.borrow_mut().take().unwrap();
+    (dom, clipboard)
+}
+
+#[tokio::test]
+async fn text_round_trips_through_the_provider() {
+    let (_dom, clipboard) = clipboard_of(Some(Rc::new(MemoryClipboard::default())));
+
+    assert_eq!(clipboard.read_text().await, Err(ClipboardError::Empty));
+    clipboard.write_text("copied").await.unwrap();
+    assert_eq!(clipboard.read_text().await.as_deref(), Ok("copied"));
+    // Providers that don't implement images reject them
+    assert_eq!(
+        clipboard.read_image().await,
+        Err(ClipboardError::Unsupported)
+    );
+}
+
+#[tokio::test]
+async fn the_clipboard_is_unsupported_without_a_provider() {
+    let (_dom, clipboard) = clipboard_of(None);
+
+    assert_eq!(
+        clipboard.read_text().await,
+        Err(ClipboardError::Unsupported)
+    );
+    assert_eq!(
+        clipboard.write_text("copied").await,
+        Err(ClipboardError::Unsupported)
+    );
+}
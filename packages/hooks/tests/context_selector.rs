@@ -0,0 +1,67 @@
+#![allow(unused, non_upper_case_globals, non_snake_case)]
+use std::cell::Cell;
+use std::time::Duration;
+
+use dioxus::prelude::*;
+use dioxus_core::NoOpMutations;
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+enum Theme {
+    Light,
+    Dark,
+}
+
+struct AppState {
+    theme: Theme,
+    unread: usize,
+}
+
+thread_local! {
+    static STATE: Cell<Option<Signal<AppState>>> = const { Cell::new(None) };
+    static RENDERS: Cell<usize> = const { Cell::new(0) };
+    static THEME: Cell<Option<Theme>> = const { Cell::new(None) };
+}
+
+fn app() -> Element {
+    let state = use_context_provider(|| {
+        Signal::new(AppState {
+            theme: Theme::Light,
+            unread: 0,
+        })
+    });
+    STATE.with(|current| current.set(Some(state)));
+    rsx! { ThemeToggle {} }
+}
+
+#[component]
+fn ThemeToggle() -> Element {
+    let theme = use_context_selector(|state: &AppState| state.theme);
+    RENDERS.with(|renders| renders.set(renders.get() + 1));
+    THEME.with(|current| current.set(Some(theme())));
+    rsx! { "{theme:?}" }
+}
+
+async fn write(dom: &mut VirtualDom, f: impl FnOnce(&mut AppState)) {
+    let mut state = STATE.with(Cell::get).unwrap();
+    dom.in_runtime(|| f(&mut state.write()));
+    // The selector is updated by a task, which may mark the component dirty
+    _ = tokio::time::timeout(Duration::from_millis(100), dom.wait_for_work()).await;
+    dom.render_immediate(&mut NoOpMutations);
+}
+
+#[tokio::test]
+async fn selectors_skip_unrelated_changes() {
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild(&mut NoOpMutations);
+    assert_eq!(RENDERS.with(Cell::get), 1);
+
+    write(&mut dom, |state| state.unread += 1).await;
+    assert_eq!(RENDERS.with(Cell::get), 1);
+
+    write(&mut dom, |state| state.theme = Theme::Dark).await;
+    assert_eq!(RENDERS.with(Cell::get), 2);
+    assert_eq!(THEME.with(Cell::get), Some(Theme::Dark));
+
+    write(&mut dom, |state| state.unread += 1).await;
+    assert_eq!(RENDERS.with(Cell::get), 2);
+}
@@ -0,0 +1,46 @@
+use dioxus::prelude::*;
+use dioxus_hooks::DeepLinkContext;
+use std::{cell::RefCell, rc::Rc};
+
+type Opened = Rc<RefCell<Vec<Option<String>>>>;
+
+fn app(opened: Opened) -> Element {
+    let link = use_deep_link();
+    opened.borrow_mut().push(link());
+    rsx! { div {} }
+}
+
+#[tokio::test]
+async fn rerenders_every_time_a_link_is_opened() {
+    let opened = Opened::default();
+    let mut dom = VirtualDom::new_with_props(app, opened.clone());
+    let context = dom.in_runtime(|| DeepLinkContext::provide(Some("myapp://start".into())));
+    dom.rebuild_in_place();
+    assert_eq!(*opened.borrow(), [Some("myapp://start".to_string())]);
+
+    // Opening the same link again still reaches the app
+    for _ in 0..2 {
+        dom.in_runtime(|| context.open("myapp://settings"));
+        tokio::select! {
+            _ = dom.wait_for_work() => {}
+            _ = tokio::time::sleep(std::time::Duration::from_millis(100)) => panic!("the app was not marked dirty"),
+        }
+        dom.render_immediate_to_vec();
+    }
+    assert_eq!(
+        *opened.borrow(),
+        [
+            Some("myapp://start".to_string()),
+            Some("myapp://settings".to_string()),
+            Some("myapp://settings".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn no_link_without_a_renderer_that_supports_it() {
+    let opened = Opened::default();
+    let mut dom = VirtualDom::new_with_props(app, opened.clone());
+    dom.rebuild_in_place();
+    assert_eq!(*opened.borrow(), [None]);
+}
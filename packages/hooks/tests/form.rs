@@ -0,0 +1,145 @@
+#![allow(unused, non_upper_case_globals, non_snake_case)]
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::time::Duration;
+
+use dioxus::prelude::*;
+use dioxus_core::NoOpMutations;
+use dioxus_hooks::*;
+
+#[derive(Clone, Default, PartialEq, Debug)]
+struct SignUp {
+    name: String,
+    age: u8,
+}
+
+impl Validate for SignUp {
+    fn validate(&self) -> ValidationErrors {
+        let mut errors = ValidationErrors::new();
+        if self.name.is_empty() {
+            errors.add("name", "Enter your name");
+        }
+        if self.age < 13 {
+            errors.add("age", "Too young");
+        }
+        errors
+    }
+}
+
+#[derive(Default)]
+struct FormLog {
+    form: Option<UseForm<SignUp>>,
+    submitted: Vec<SignUp>,
+}
+
+fn app(log: Rc<RefCell<FormLog>>) -> Element {
+    let form = use_form(SignUp::default);
+    form.async_validator(|sign_up: SignUp| async move {
+        let mut errors = ValidationErrors::new();
+        if sign_up.name == "taken" {
+            errors.add("name", "Name is taken");
+        }
+        errors
+    });
+    log.borrow_mut().form = Some(form);
+
+    rsx! { div {} }
+}
+
+#[tokio::test]
+async fn fields_track_their_state_and_errors() {
+    let log = Rc::new(RefCell::new(FormLog::default()));
+    let mut dom = VirtualDom::new_with_props(app, log.clone());
+    dom.rebuild_in_place();
+
+    let form = log.borrow().form.unwrap();
+    in_root(&dom, || {
+        let name = form.field("name", |form| &mut form.name);
+        assert!(!name.is_dirty());
+        assert!(!name.is_touched());
+        assert_eq!(name.errors(), ["Enter your name"]);
+        // Errors are only shown once the field is touched
+        assert_eq!(name.visible_error(), None);
+
+        name.set("taken".to_string());
+        name.touch();
+        assert!(name.is_dirty());
+        assert!(name.is_touched());
+        assert!(name.errors().is_empty());
+    });
+
+    // The async validator runs after the value changes
+    settle(&mut dom).await;
+    in_root(&dom, || {
+        let name = form.field("name", |form| &mut form.name);
+        assert_eq!(name.visible_error().as_deref(), Some("Name is taken"));
+        assert!(!form.is_validating());
+
+        name.set("Ferris".to_string());
+    });
+    settle(&mut dom).await;
+    in_root(&dom, || {
+        let errors = form.errors();
+        assert!(errors.field("name").is_empty());
+        assert_eq!(errors.field("age"), ["Too young"]);
+    });
+}
+
+#[tokio::test]
+async fn submit_only_calls_the_handler_with_valid_values() {
+    let log = Rc::new(RefCell::new(FormLog::default()));
+    let mut dom = VirtualDom::new_with_props(app, log.clone());
+    dom.rebuild_in_place();
+
+    let form = log.borrow().form.unwrap();
+    let submit = |dom: &mut VirtualDom| {
+        let log = log.clone();
+        in_root(dom, || {
+            form.submit(move |sign_up| async move {
+                log.borrow_mut().submitted.push(sign_up);
+            })
+        });
+    };
+
+    submit(&mut dom);
+    settle(&mut dom).await;
+    assert!(log.borrow().submitted.is_empty());
+    in_root(&dom, || {
+        // Submitting touches every field
+        let age = form.field("age", |form| &mut form.age);
+        assert_eq!(age.visible_error().as_deref(), Some("Too young"));
+        assert_eq!(form.submit_count(), 1);
+    });
+
+    in_root(&dom, || {
+        form.set_value(SignUp {
+            name: "Ferris".to_string(),
+            age: 13,
+        });
+    });
+    submit(&mut dom);
+    settle(&mut dom).await;
+    assert_eq!(
+        log.borrow().submitted,
+        [SignUp {
+            name: "Ferris".to_string(),
+            age: 13,
+        }]
+    );
+    in_root(&dom, || assert!(!form.is_submitting()));
+}
+
+/// Run a closure in the root scope, so tasks can be spawned
+fn in_root<O>(dom: &VirtualDom, f: impl FnOnce() -> O) -> O {
+    dom.in_runtime(|| ScopeId::ROOT.in_runtime(f))
+}
+
+/// Render until there is no more work to do
+async fn settle(dom: &mut VirtualDom) {
+    loop {
+        tokio::select! {
+            _ = dom.wait_for_work() => dom.render_immediate(&mut NoOpMutations),
+            _ = tokio::time::sleep(Duration::from_millis(50)) => break,
+        }
+    }
+}
@@ -0,0 +1,72 @@
+#![allow(unused, non_upper_case_globals, non_snake_case)]
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::time::Duration;
+
+use dioxus::prelude::*;
+use dioxus_core::NoOpMutations;
+use dioxus_signals::*;
+
+#[derive(Default)]
+struct FutureLog {
+    dependency: Option<Signal<usize>>,
+    started: Vec<usize>,
+    cancelled: Vec<usize>,
+}
+
+/// Pushes the value to the cancelled list when the future is dropped before it finishes
+struct CancelGuard(Rc<RefCell<FutureLog>>, usize);
+
+impl Drop for CancelGuard {
+    fn drop(&mut self) {
+        self.0.borrow_mut().cancelled.push(self.1);
+    }
+}
+
+#[tokio::test]
+async fn futures_restart_when_dependencies_change() {
+    let log = Rc::new(RefCell::new(FutureLog::default()));
+
+    let mut dom = VirtualDom::new_with_props(
+        |log: Rc<RefCell<FutureLog>>| {
+            let dependency = use_signal(|| 0);
+            log.borrow_mut().dependency = Some(dependency);
+
+            use_future(move || {
+                // Reading the signal here makes it a dependency of the future
+                let value = dependency();
+                let log = log.clone();
+                async move {
+                    log.borrow_mut().started.push(value);
+                    let _guard = CancelGuard(log, value);
+                    std::future::pending::<()>().await;
+                }
+            });
+
+            rsx! { div {} }
+        },
+        log.clone(),
+    );
+
+    dom.rebuild_in_place();
+    settle(&mut dom).await;
+    assert_eq!(log.borrow().started, [0]);
+
+    let mut dependency = log.borrow().dependency.unwrap();
+    dom.in_runtime(|| dependency.set(1));
+    settle(&mut dom).await;
+
+    let log = log.borrow();
+    assert_eq!(log.started, [0, 1]);
+    assert_eq!(log.cancelled, [0]);
+}
+
+/// Render until there is no more work to do
+async fn settle(dom: &mut VirtualDom) {
+    loop {
+        tokio::select! {
+            _ = dom.wait_for_work() => dom.render_immediate(&mut NoOpMutations),
+            _ = tokio::time::sleep(Duration::from_millis(50)) => break,
+        }
+    }
+}
@@ -0,0 +1,66 @@
+use dioxus_hooks::Viewport;
+
+const PHONE: Viewport = Viewport {
+    width: 400.,
+    height: 800.,
+    prefers_dark: false,
+};
+
+const DESKTOP: Viewport = Viewport {
+    width: 1280.,
+    height: 720.,
+    prefers_dark: true,
+};
+
+#[test]
+fn width_and_height() {
+    assert!(PHONE.matches("(max-width: 600px)"));
+    assert!(!DESKTOP.matches("(max-width: 600px)"));
+    assert!(DESKTOP.matches("(min-width: 80em)"));
+    assert!(PHONE.matches("(min-height: 800px)"));
+    assert!(PHONE.matches("screen and (max-width: 600px) and (orientation: portrait)"));
+    assert!(!DESKTOP.matches("(orientation: portrait)"));
+}
+
+#[test]
+fn lists_and_negation() {
+    assert!(DESKTOP.matches("(max-width: 600px), (prefers-color-scheme: dark)"));
+    assert!(PHONE.matches("(max-width: 600px), (prefers-color-scheme: dark)"));
+    assert!(DESKTOP.matches("not all and (max-width: 600px)"));
+    assert!(!PHONE.matches("print"));
+    assert!(DESKTOP.matches("(min-aspect-ratio: 16/9)"));
+}
+
+#[test]
+fn invalid_queries_dont_match() {
+    assert!(!PHONE.matches("(max-width: 600)"));
+    assert!(!PHONE.matches("(hover: hover)"));
+    assert!(!PHONE.matches("tv"));
+}
+
+#[tokio::test]
+async fn rerenders_when_the_viewport_changes() {
+    use dioxus::prelude::*;
+    use dioxus_hooks::ViewportContext;
+    use std::{cell::Cell, rc::Rc};
+
+    let mobile = Rc::new(Cell::new(None));
+    let mut dom = VirtualDom::new_with_props(
+        |mobile: Rc<Cell<Option<bool>>>| {
+            mobile.set(Some(use_media_query("(max-width: 600px)")));
+            rsx! { div {} }
+        },
+        mobile.clone(),
+    );
+    let viewport = dom.in_runtime(|| ViewportContext::provide(DESKTOP));
+    dom.rebuild_in_place();
+    assert_eq!(mobile.get(), Some(false));
+
+    dom.in_runtime(|| viewport.set(PHONE));
+    tokio::select! {
+        _ = dom.wait_for_work() => {}
+        _ = tokio::time::sleep(std::time::Duration::from_millis(100)) => panic!("the app was not marked dirty"),
+    }
+    dom.render_immediate_to_vec();
+    assert_eq!(mobile.get(), Some(true));
+}
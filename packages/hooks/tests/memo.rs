@@ -0,0 +1,52 @@
+#![allow(unused, non_upper_case_globals, non_snake_case)]
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use dioxus::prelude::*;
+
+#[test]
+fn memo_by_recomputes_when_dependencies_change() {
+    #[derive(Clone)]
+    struct Query {
+        text: &'static str,
+        // The render doesn't change the result, so it isn't compared
+        render: usize,
+    }
+
+    thread_local! {
+        static QUERIES: RefCell<Vec<&'static str>> = const { RefCell::new(Vec::new()) };
+        static COMPUTED: RefCell<Vec<&'static str>> = const { RefCell::new(Vec::new()) };
+        static WORDS: RefCell<Vec<usize>> = const { RefCell::new(Vec::new()) };
+    }
+
+    fn app() -> Element {
+        let render = WORDS.with(|words| words.borrow().len());
+        let query = Query {
+            text: QUERIES.with(|queries| queries.borrow()[render]),
+            render,
+        };
+
+        let words = use_memo_by(
+            query,
+            |old, new| old.text == new.text,
+            |query| {
+                COMPUTED.with(|computed| computed.borrow_mut().push(query.text));
+                query.text.split_whitespace().count()
+            },
+        );
+        WORDS.with(|all| all.borrow_mut().push(words));
+
+        rsx! { "{words}" }
+    }
+
+    QUERIES.with(|queries| *queries.borrow_mut() = vec!["a b", "a b", "a b c", "a b c"]);
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+    for _ in 0..3 {
+        dom.mark_dirty(ScopeId::ROOT);
+        dom.render_immediate(&mut dioxus_core::NoOpMutations);
+    }
+
+    WORDS.with(|words| assert_eq!(*words.borrow(), [2, 2, 3, 3]));
+    COMPUTED.with(|computed| assert_eq!(*computed.borrow(), ["a b", "a b c"]));
+}
@@ -0,0 +1,54 @@
+use dioxus::prelude::*;
+use dioxus_hooks::{SafeArea, SafeAreaContext};
+use std::{cell::Cell, rc::Rc};
+
+const NOTCH: SafeArea = SafeArea {
+    top: 47.,
+    right: 0.,
+    bottom: 34.,
+    left: 0.,
+    keyboard: 0.,
+};
+
+#[tokio::test]
+async fn rerenders_when_the_keyboard_opens() {
+    let insets = Rc::new(Cell::new(None));
+    let mut dom = VirtualDom::new_with_props(
+        |insets: Rc<Cell<Option<SafeArea>>>| {
+            insets.set(Some(use_safe_area()));
+            rsx! { div {} }
+        },
+        insets.clone(),
+    );
+    let safe_area = dom.in_runtime(|| SafeAreaContext::provide(NOTCH));
+    dom.rebuild_in_place();
+    assert_eq!(insets.get(), Some(NOTCH));
+    assert!(!NOTCH.keyboard_open());
+
+    let keyboard = SafeArea {
+        keyboard: 291.,
+        ..NOTCH
+    };
+    dom.in_runtime(|| safe_area.set(keyboard));
+    tokio::select! {
+        _ = dom.wait_for_work() => {}
+        _ = tokio::time::sleep(std::time::Duration::from_millis(100)) => panic!("the app was not marked dirty"),
+    }
+    dom.render_immediate_to_vec();
+    assert_eq!(insets.get(), Some(keyboard));
+    assert!(keyboard.keyboard_open());
+}
+
+#[test]
+fn insets_are_zero_without_a_renderer_that_measures_them() {
+    let insets = Rc::new(Cell::new(None));
+    let mut dom = VirtualDom::new_with_props(
+        |insets: Rc<Cell<Option<SafeArea>>>| {
+            insets.set(Some(use_safe_area()));
+            rsx! { div {} }
+        },
+        insets.clone(),
+    );
+    dom.rebuild_in_place();
+    assert_eq!(insets.get(), Some(SafeArea::default()));
+}
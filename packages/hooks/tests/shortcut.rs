@@ -0,0 +1,177 @@
+use dioxus::prelude::*;
+use dioxus_hooks::{Shortcut, ShortcutContext, ShortcutError, ShortcutModifiers};
+use std::{cell::RefCell, rc::Rc};
+
+type Log = Rc<RefCell<Vec<String>>>;
+
+#[derive(Props, Clone, PartialEq)]
+struct AppProps {
+    log: Log,
+    shortcuts: Vec<&'static str>,
+}
+
+fn app(props: AppProps) -> Element {
+    let mut show = use_signal(|| true);
+    let log = props.log.clone();
+    // pressing escape removes the registered shortcuts
+    _ = use_shortcut("esc", move || {
+        log.borrow_mut().push("esc".to_string());
+        show.set(false);
+    });
+
+    rsx! {
+        if show() {
+            for shortcut in props.shortcuts.clone() {
+                Registered { log: props.log.clone(), shortcut }
+            }
+        }
+    }
+}
+
+#[component]
+fn Registered(log: Log, shortcut: &'static str) -> Element {
+    let handler_log = log.clone();
+    let result = use_shortcut(shortcut, move || {
+        handler_log.borrow_mut().push(shortcut.to_string())
+    });
+    if let Err(err) = result {
+        log.borrow_mut().push(err.to_string());
+    }
+    None
+}
+
+fn press(shortcut: &str) -> Shortcut {
+    shortcut.parse().unwrap()
+}
+
+#[test]
+fn shortcuts_are_parsed() {
+    let shortcut = press("Ctrl+Shift+P");
+    assert_eq!(shortcut.key(), "p");
+    assert_eq!(shortcut.code(), "KeyP");
+    assert_eq!(
+        shortcut.modifiers(),
+        ShortcutModifiers {
+            ctrl: true,
+            shift: true,
+            ..Default::default()
+        }
+    );
+    assert_eq!(shortcut.to_string(), "ctrl+shift+p");
+
+    // aliases are normalized, so the same shortcut can be written in different ways
+    assert_eq!(press("cmd+,"), press("meta+comma"));
+    assert_eq!(press("alt+up").code(), "ArrowUp");
+    assert_eq!(press("f12").code(), "F12");
+    assert_eq!(
+        Shortcut::from_code(ShortcutModifiers::default(), "Digit1"),
+        Some(press("1"))
+    );
+    assert_eq!(
+        Shortcut::from_code(ShortcutModifiers::default(), "ArrowLeft"),
+        Some(press("left"))
+    );
+
+    assert!(matches!(
+        "ctrl+hyper+k".parse::<Shortcut>(),
+        Err(ShortcutError::Invalid(_))
+    ));
+    assert!(matches!(
+        "ctrl+".parse::<Shortcut>(),
+        Err(ShortcutError::Invalid(_))
+    ));
+    assert!(matches!(
+        "f25".parse::<Shortcut>(),
+        Err(ShortcutError::Invalid(_))
+    ));
+}
+
+#[test]
+fn shortcuts_call_their_handler_until_they_are_removed() {
+    let log = Log::default();
+    let changes = Rc::new(RefCell::new(Vec::new()));
+    let mut dom = VirtualDom::new_with_props(
+        app,
+        AppProps {
+            log: log.clone(),
+            shortcuts: vec!["ctrl+k", "ctrl+shift+k"],
+        },
+    );
+    let shortcuts = dom.in_runtime(|| {
+        let changes = changes.clone();
+        ShortcutContext::provide(move |_, shortcut, registered| {
+            changes
+                .borrow_mut()
+                .push(format!("{shortcut} {registered}"));
+            Ok(())
+        })
+    });
+    dom.rebuild_in_place();
+
+    assert!(dom.in_runtime(|| shortcuts.dispatch(&press("ctrl+k"))));
+    assert!(dom.in_runtime(|| shortcuts.dispatch(&press("ctrl+shift+k"))));
+    assert!(!dom.in_runtime(|| shortcuts.dispatch(&press("k"))));
+    assert_eq!(*log.borrow(), ["ctrl+k", "ctrl+shift+k"]);
+
+    // The shortcuts are removed with the components that registered them
+    assert!(dom.in_runtime(|| shortcuts.dispatch(&press("escape"))));
+    dom.render_immediate_to_vec();
+    assert!(!dom.in_runtime(|| shortcuts.dispatch(&press("ctrl+k"))));
+    assert_eq!(
+        *changes.borrow(),
+        [
+            "escape true",
+            "ctrl+k true",
+            "ctrl+shift+k true",
+            "ctrl+k false",
+            "ctrl+shift+k false"
+        ]
+    );
+}
+
+#[test]
+fn conflicting_shortcuts_are_rejected() {
+    let log = Log::default();
+    let mut dom = VirtualDom::new_with_props(
+        app,
+        AppProps {
+            log: log.clone(),
+            shortcuts: vec!["ctrl+k", "Control+K", "ctrl+w"],
+        },
+    );
+    let shortcuts = dom.in_runtime(|| {
+        // The renderer can reject shortcuts the platform doesn't let apps handle
+        ShortcutContext::provide(|_, shortcut, registered| {
+            if registered && *shortcut == press("ctrl+w") {
+                return Err(ShortcutError::Conflict(shortcut.clone()));
+            }
+            Ok(())
+        })
+    });
+    dom.rebuild_in_place();
+
+    assert_eq!(
+        *log.borrow(),
+        [
+            "the shortcut ctrl+k is already in use",
+            "the shortcut ctrl+w is already in use"
+        ]
+    );
+    // The shortcut that was registered first keeps working
+    assert!(dom.in_runtime(|| shortcuts.dispatch(&press("ctrl+k"))));
+    assert!(!dom.in_runtime(|| shortcuts.dispatch(&press("ctrl+w"))));
+}
+
+#[test]
+fn handlers_are_never_called_without_a_renderer_that_supports_them() {
+    let log = Log::default();
+    let mut dom = VirtualDom::new_with_props(
+        app,
+        AppProps {
+            log: log.clone(),
+            shortcuts: vec!["ctrl+k"],
+        },
+    );
+    dom.rebuild_in_place();
+    assert!(log.borrow().is_empty());
+}
@@ -0,0 +1,83 @@
+use std::{cell::RefCell, rc::Rc, time::Duration};
+
+use dioxus::prelude::*;
+use futures_util::{SinkExt, StreamExt};
+use tokio::net::TcpListener;
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+struct Ping {
+    count: u32,
+}
+
+/// Accept a single connection and send every message back with the count incremented
+async fn echo_server() -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("ws://{}", listener.local_addr().unwrap());
+    tokio::spawn(async move {
+        let (stream, _) = listener.accept().await.unwrap();
+        let mut socket = tokio_tungstenite::accept_async(stream).await.unwrap();
+        while let Some(Ok(message)) = socket.next().await {
+            if let Ok(text) = message.to_text() {
+                let ping: Ping = serde_json::from_str(text).unwrap();
+                let pong = Ping {
+                    count: ping.count + 1,
+                };
+                let pong = serde_json::to_string(&pong).unwrap();
+                socket.send(pong.into()).await.unwrap();
+            }
+        }
+    });
+    url
+}
+
+#[tokio::test]
+async fn websocket_sends_and_receives() {
+    #[derive(Clone)]
+    struct Props {
+        url: String,
+        received: Rc<RefCell<Vec<Ping>>>,
+    }
+
+    let received = Rc::new(RefCell::new(Vec::new()));
+    let url = echo_server().await;
+    let mut dom = VirtualDom::new_with_props(
+        |props: Props| {
+            let mut socket = use_websocket::<Ping, Ping>(props.url, WebSocketOptions::new());
+            use_hook(|| {
+                socket.send(Ping { count: 0 });
+                socket.send(Ping { count: 10 });
+                let received = props.received.clone();
+                spawn(async move {
+                    while let Some(ping) = socket.recv().await {
+                        received.borrow_mut().push(ping);
+                        // Stop the wait for work manually
+                        needs_update();
+                    }
+                })
+            });
+
+            rsx! {
+                div { "{socket.state():?}" }
+            }
+        },
+        Props {
+            url,
+            received: received.clone(),
+        },
+    );
+    dom.rebuild_in_place();
+
+    tokio::time::timeout(Duration::from_secs(10), async {
+        while received.borrow().len() < 2 {
+            dom.wait_for_work().await;
+            dom.render_immediate(&mut dioxus_core::NoOpMutations);
+        }
+    })
+    .await
+    .expect("the messages were not echoed");
+
+    assert_eq!(
+        *received.borrow(),
+        vec![Ping { count: 1 }, Ping { count: 11 }]
+    );
+}
@@ -1,13 +1,16 @@
 use std::{
     io::Write,
-    path::PathBuf,
+    path::{Path, PathBuf},
     str::FromStr,
     sync::{Arc, Mutex},
 };
 
-use crate::HotReloadMsg;
+use crate::{asset_path, HotReloadMsg, ASSET_DIRS};
 use dioxus_rsx::{
-    hot_reload::{FileMap, FileMapBuildResult, UpdateResult},
+    hot_reload::{
+        workspace_dependencies as workspace_dependencies_of, FileMap, FileMapBuildResult,
+        UpdateResult,
+    },
     HotReloadingContext,
 };
 use interprocess_docfix::local_socket::LocalSocketListener;
@@ -20,6 +23,8 @@ pub struct Config<Ctx: HotReloadingContext> {
     root_path: &'static str,
     listening_paths: &'static [&'static str],
     excluded_paths: &'static [&'static str],
+    crates: &'static [&'static str],
+    workspace_dependencies: bool,
     log: bool,
     rebuild_with: Option<Box<dyn FnMut() -> bool + Send + 'static>>,
     phantom: std::marker::PhantomData<Ctx>,
@@ -31,6 +36,8 @@ impl<Ctx: HotReloadingContext> Default for Config<Ctx> {
             root_path: "",
             listening_paths: &[""],
             excluded_paths: &["./target"],
+            crates: &[],
+            workspace_dependencies: true,
             log: true,
             rebuild_with: None,
             phantom: std::marker::PhantomData,
@@ -45,6 +52,8 @@ impl Config<HtmlCtx> {
             root_path: "",
             listening_paths: &[""],
             excluded_paths: &["./target"],
+            crates: &[],
+            workspace_dependencies: true,
             log: true,
             rebuild_with: None,
             phantom: std::marker::PhantomData,
@@ -99,6 +108,19 @@ impl<Ctx: HotReloadingContext> Config<Ctx> {
         }
     }
 
+    /// Set the directories of other crates to hot reload rsx in, relative to the root path. This is useful for component libraries that aren't found automatically with [`Config::with_workspace_dependencies`].
+    pub fn with_crates(self, crates: &'static [&'static str]) -> Self {
+        Self { crates, ..self }
+    }
+
+    /// Set whether to hot reload rsx in the workspace members the project depends on. This is enabled by default.
+    pub fn with_workspace_dependencies(self, workspace_dependencies: bool) -> Self {
+        Self {
+            workspace_dependencies,
+            ..self
+        }
+    }
+
     /// Sets paths to ignore changes on. This will override any paths set in the [`Config::with_paths`] method in the case of conflicts.
     pub fn excluded_paths(self, paths: &'static [&'static str]) -> Self {
         Self {
@@ -116,33 +138,55 @@ pub fn init<Ctx: HotReloadingContext + Send + 'static>(cfg: Config<Ctx>) {
         log,
         mut rebuild_with,
         excluded_paths,
+        crates,
+        workspace_dependencies,
         phantom: _,
     } = cfg;
 
     if let Ok(crate_dir) = PathBuf::from_str(root_path) {
-        // try to find the gitignore file
-        let gitignore_file_path = crate_dir.join(".gitignore");
-        let (gitignore, _) = ignore::gitignore::Gitignore::new(gitignore_file_path);
+        let mut watched_crates = vec![WatchedCrate::new(crate_dir.clone(), excluded_paths)];
 
-        // convert the excluded paths to absolute paths
-        let excluded_paths = excluded_paths
+        // find the other crates to hot reload rsx in
+        let mut dependency_dirs = crates
             .iter()
-            .map(|path| crate_dir.join(PathBuf::from(path)))
+            .map(|path| crate_dir.join(path))
             .collect::<Vec<_>>();
+        if workspace_dependencies {
+            match workspace_dependencies_of(&crate_dir) {
+                Ok(dirs) => dependency_dirs.extend(dirs),
+                Err(err) => {
+                    if log {
+                        println!("hot reloading failed to find workspace dependencies:\n{err:?}");
+                    }
+                }
+            }
+        }
+        for dir in dependency_dirs {
+            if !watched_crates.iter().any(|watched| watched.dir == dir) {
+                watched_crates.push(WatchedCrate::new(dir, &["./target"]));
+            }
+        }
 
         let channels = Arc::new(Mutex::new(Vec::new()));
         let FileMapBuildResult {
-            map: file_map,
-            errors,
+            map: mut file_map,
+            mut errors,
         } = FileMap::<Ctx>::create_with_filter(crate_dir.clone(), |path| {
-            // skip excluded paths
-            excluded_paths.iter().any(|p| path.starts_with(p)) ||
-                // respect .gitignore
-                gitignore
-                    .matched_path_or_any_parents(path, path.is_dir())
-                    .is_ignore()
+            watched_crates[0].is_ignored(path, path.is_dir())
         })
         .unwrap();
+        // crates inside of the root directory were already added
+        for watched in watched_crates
+            .iter()
+            .filter(|watched| !watched.dir.starts_with(&crate_dir))
+        {
+            match file_map.add_crate_with_filter(watched.dir.clone(), |path| {
+                watched.is_ignored(path, path.is_dir())
+            }) {
+                Ok(crate_errors) => errors.extend(crate_errors),
+                Err(err) => errors.push(err),
+            }
+        }
         for err in errors {
             if log {
                 println!("hot reloading failed to initialize:\n{err:?}");
@@ -215,8 +259,16 @@ pub fn init<Ctx: HotReloadingContext + Send + 'static>(cfg: Config<Ctx>) {
                     let mut watcher =
                         RecommendedWatcher::new(tx, notify::Config::default()).unwrap();
 
-                    for path in listening_paths {
-                        let full_path = crate_dir.join(path);
+                    let dependency_dirs = watched_crates
+                        .iter()
+                        .skip(1)
+                        .filter(|watched| !watched.dir.starts_with(&crate_dir))
+                        .map(|watched| watched.dir.clone());
+                    let watched_paths = listening_paths
+                        .iter()
+                        .map(|path| crate_dir.join(path))
+                        .chain(dependency_dirs);
+                    for full_path in watched_paths {
                         if let Err(err) = watcher.watch(&full_path, RecursiveMode::Recursive) {
                             if log {
                                 println!(
@@ -261,17 +313,18 @@ pub fn init<Ctx: HotReloadingContext + Send + 'static>(cfg: Config<Ctx>) {
                                     .paths
                                     .iter()
                                     .filter(|path| {
+                                        // skip files outside of the watched crates
+                                        let Some(watched) = watched_crate(&watched_crates, path)
+                                        else {
+                                            return false;
+                                        };
                                         // skip non rust files
-                                        matches!(
+                                        (matches!(
                                             path.extension().and_then(|p| p.to_str()),
                                             Some("rs" | "toml" | "css" | "html" | "js")
-                                        ) &&
-                                        // skip excluded paths
-                                        !excluded_paths.iter().any(|p| path.starts_with(p)) &&
-                                        // respect .gitignore
-                                        !gitignore
-                                            .matched_path_or_any_parents(path, false)
-                                            .is_ignore()
+                                        ) || find_asset(&crate_dir, path).is_some()) &&
+                                        // skip excluded paths and respect .gitignore
+                                        !watched.is_ignored(path, false)
                                     })
                                     .collect::<Vec<_>>();
 
@@ -282,17 +335,34 @@ pub fn init<Ctx: HotReloadingContext + Send + 'static>(cfg: Config<Ctx>) {
 
                                 let mut channels = channels.lock().unwrap();
                                 for path in real_paths {
+                                    // assets are reloaded by the client without rebuilding the application
+                                    if let Some(asset) = find_asset(&crate_dir, path) {
+                                        if log {
+                                            println!("Reloading asset {}", asset.display());
+                                        }
+                                        channels.retain_mut(|channel| {
+                                            send_msg(
+                                                HotReloadMsg::UpdateAsset(asset.clone()),
+                                                channel,
+                                            )
+                                        });
+                                        continue;
+                                    }
+
                                     // if this file type cannot be hot reloaded, rebuild the application
                                     if path.extension().and_then(|p| p.to_str()) != Some("rs")
                                         && rebuild()
                                     {
                                         return;
                                     }
-                                    // find changes to the rsx in the file
+                                    // find changes to the rsx in the file, relative to the crate it is in
+                                    let Some(watched) = watched_crate(&watched_crates, path) else {
+                                        continue;
+                                    };
                                     match file_map
                                         .lock()
                                         .unwrap()
-                                        .update_rsx(path, crate_dir.as_path())
+                                        .update_rsx(path, watched.dir.as_path())
                                     {
                                         Ok(UpdateResult::UpdatedRsx(msgs)) => {
                                             for msg in msgs {
@@ -337,6 +407,56 @@ pub fn init<Ctx: HotReloadingContext + Send + 'static>(cfg: Config<Ctx>) {
     }
 }
 
+/// A crate the rsx is hot reloaded in
+struct WatchedCrate {
+    dir: PathBuf,
+    gitignore: ignore::gitignore::Gitignore,
+    excluded_paths: Vec<PathBuf>,
+}
+
+impl WatchedCrate {
+    fn new(dir: PathBuf, excluded_paths: &[&str]) -> Self {
+        // try to find the gitignore file
+        let (gitignore, _) = ignore::gitignore::Gitignore::new(dir.join(".gitignore"));
+
+        // convert the excluded paths to absolute paths
+        let excluded_paths = excluded_paths
+            .iter()
+            .map(|path| dir.join(PathBuf::from(path)))
+            .collect();
+
+        Self {
+            dir,
+            gitignore,
+            excluded_paths,
+        }
+    }
+
+    /// Check if a path in the crate is excluded or ignored by git
+    fn is_ignored(&self, path: &Path, is_dir: bool) -> bool {
+        self.excluded_paths.iter().any(|p| path.starts_with(p))
+            || self
+                .gitignore
+                .matched_path_or_any_parents(path, is_dir)
+                .is_ignore()
+    }
+}
+
+/// Find the crate a path belongs to. Crates can be nested in the directory of other crates, so the innermost crate is used
+fn watched_crate<'a>(crates: &'a [WatchedCrate], path: &Path) -> Option<&'a WatchedCrate> {
+    crates
+        .iter()
+        .filter(|watched| path.starts_with(&watched.dir))
+        .max_by_key(|watched| watched.dir.components().count())
+}
+
+/// Find the asset a changed file belongs to in any of the asset directories
+fn find_asset(crate_dir: &Path, path: &Path) -> Option<PathBuf> {
+    ASSET_DIRS
+        .iter()
+        .find_map(|dir| asset_path(&crate_dir.join(dir), path))
+}
+
 fn send_msg(msg: HotReloadMsg, channel: &mut impl Write) -> bool {
     if let Ok(msg) = serde_json::to_string(&msg) {
         if channel.write_all(msg.as_bytes()).is_err() {
@@ -1,6 +1,6 @@
 use std::{
     io::{BufRead, BufReader},
-    path::PathBuf,
+    path::{Path, PathBuf},
 };
 
 use dioxus_core::Template;
@@ -15,15 +15,34 @@ mod file_watcher;
 pub use file_watcher::*;
 
 /// A message the hot reloading server sends to the client
-#[derive(Debug, Serialize, Deserialize, Clone, Copy)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(bound(deserialize = "'de: 'static"))]
 pub enum HotReloadMsg {
     /// A template has been updated
     UpdateTemplate(Template),
+    /// An asset like a stylesheet or an image has been updated. The path is relative to the asset directory
+    UpdateAsset(PathBuf),
     /// The program needs to be recompiled, and the client should shut down
     Shutdown,
 }
 
+/// The directories assets are hot reloaded from if the project doesn't configure an asset directory
+pub const ASSET_DIRS: &[&str] = &["assets", "public"];
+
+/// The extensions of assets that can be hot reloaded without recompiling the program
+pub const ASSET_EXTENSIONS: &[&str] = &[
+    "css", "js", "png", "jpg", "jpeg", "gif", "svg", "webp", "avif", "ico",
+];
+
+/// If the file is an asset in the asset directory that can be hot reloaded, returns the path of the asset relative to the asset directory
+pub fn asset_path(asset_dir: &Path, path: &Path) -> Option<PathBuf> {
+    let extension = path.extension()?.to_str()?;
+    if !ASSET_EXTENSIONS.contains(&extension) {
+        return None;
+    }
+    path.strip_prefix(asset_dir).ok().map(Path::to_path_buf)
+}
+
 /// Connect to the hot reloading listener. The callback provided will be called every time a template change is detected
 pub fn connect(mut f: impl FnMut(HotReloadMsg) + Send + 'static) {
     std::thread::spawn(move || {
@@ -0,0 +1,48 @@
+//! Support for the `class: ["btn", active.then_some("active")]` list syntax in rsx
+
+/// A single entry in a `class: [...]` list.
+///
+/// Strings are always added, and optional entries are only added if they are `Some`.
+///
+/// ```rust, ignore
+/// rsx! {
+///     button { class: ["btn", active.then_some("btn-active"), variant_class] }
+/// }
+/// ```
+pub trait ClassListItem {
+    /// Append this class to the space separated list of classes
+    fn push_class(self, classes: &mut String);
+}
+
+impl ClassListItem for &str {
+    fn push_class(self, classes: &mut String) {
+        let class = self.trim();
+        if class.is_empty() {
+            return;
+        }
+        if !classes.is_empty() {
+            classes.push(' ');
+        }
+        classes.push_str(class);
+    }
+}
+
+impl ClassListItem for String {
+    fn push_class(self, classes: &mut String) {
+        self.as_str().push_class(classes)
+    }
+}
+
+impl ClassListItem for &String {
+    fn push_class(self, classes: &mut String) {
+        self.as_str().push_class(classes)
+    }
+}
+
+impl<T: ClassListItem> ClassListItem for Option<T> {
+    fn push_class(self, classes: &mut String) {
+        if let Some(class) = self {
+            class.push_class(classes)
+        }
+    }
+}
@@ -0,0 +1,349 @@
+//! A typed builder for the `style` attribute
+
+use std::fmt::{Display, Formatter, Write};
+
+use dioxus_core::prelude::IntoAttributeValue;
+use dioxus_core::AttributeValue;
+
+/// The inline styles of an element, built one typed property at a time.
+///
+/// ```rust, ignore
+/// rsx! {
+///     div {
+///         style: Style::new().display_flex().gap(px(8)).padding(rem(1.5)).color("rebeccapurple"),
+///         "Hello"
+///     }
+/// }
+/// ```
+///
+/// The element receives the same `style` string attribute it would get from a formatted string, like
+/// `display:flex;gap:8px;padding:1.5rem;color:rebeccapurple;`. Properties are written straight into that string as
+/// they are added, so building a style only allocates the attribute itself. Properties without a typed method can be
+/// added with [`Style::property`].
+#[derive(Clone, Default, PartialEq, Eq, Debug)]
+pub struct Style {
+    css: String,
+}
+
+impl Style {
+    /// Create a style without any properties
+    pub const fn new() -> Self {
+        Self { css: String::new() }
+    }
+
+    /// Add any CSS property. Later properties override earlier ones, like in CSS.
+    pub fn property(mut self, name: &str, value: impl Display) -> Self {
+        let _ = write!(self.css, "{name}:{value};");
+        self
+    }
+
+    /// Returns true if no properties were added
+    pub fn is_empty(&self) -> bool {
+        self.css.is_empty()
+    }
+
+    /// Get the CSS of the style, like `display:flex;gap:8px;`
+    pub fn as_str(&self) -> &str {
+        &self.css
+    }
+
+    /// Set `display`
+    pub fn display(self, display: DisplayKind) -> Self {
+        self.property("display", display)
+    }
+
+    /// Set `display: flex`
+    pub fn display_flex(self) -> Self {
+        self.display(DisplayKind::Flex)
+    }
+
+    /// Set `display: grid`
+    pub fn display_grid(self) -> Self {
+        self.display(DisplayKind::Grid)
+    }
+
+    /// Set `display: block`
+    pub fn display_block(self) -> Self {
+        self.display(DisplayKind::Block)
+    }
+
+    /// Set `display: none`
+    pub fn display_none(self) -> Self {
+        self.display(DisplayKind::None)
+    }
+
+    /// Set `position`
+    pub fn position(self, position: Position) -> Self {
+        self.property("position", position)
+    }
+
+    /// Set `flex-direction`
+    pub fn flex_direction(self, direction: FlexDirection) -> Self {
+        self.property("flex-direction", direction)
+    }
+
+    /// Set `align-items`
+    pub fn align_items(self, align: Align) -> Self {
+        self.property("align-items", align)
+    }
+
+    /// Set `align-self`
+    pub fn align_self(self, align: Align) -> Self {
+        self.property("align-self", align)
+    }
+
+    /// Set `justify-content`
+    pub fn justify_content(self, justify: Justify) -> Self {
+        self.property("justify-content", justify)
+    }
+
+    /// Set `color` to any CSS color, like `"red"` or `"#ff0000"`
+    pub fn color(self, color: impl Display) -> Self {
+        self.property("color", color)
+    }
+
+    /// Set `background-color` to any CSS color, like `"red"` or `"#ff0000"`
+    pub fn background_color(self, color: impl Display) -> Self {
+        self.property("background-color", color)
+    }
+
+    /// Set `opacity`
+    pub fn opacity(self, opacity: f64) -> Self {
+        self.property("opacity", opacity)
+    }
+
+    /// Set `flex-grow`
+    pub fn flex_grow(self, grow: f64) -> Self {
+        self.property("flex-grow", grow)
+    }
+
+    /// Set `flex-shrink`
+    pub fn flex_shrink(self, shrink: f64) -> Self {
+        self.property("flex-shrink", shrink)
+    }
+
+    /// Set `z-index`
+    pub fn z_index(self, index: i64) -> Self {
+        self.property("z-index", index)
+    }
+
+    /// Set `font-weight`, like `400` for normal text and `700` for bold text
+    pub fn font_weight(self, weight: u16) -> Self {
+        self.property("font-weight", weight)
+    }
+}
+
+macro_rules! length_properties {
+    ($($name:ident: $css:literal;)*) => {
+        impl Style {
+            $(
+                #[doc = concat!("Set `", $css, "`")]
+                pub fn $name(self, length: impl Into<Length>) -> Self {
+                    self.property($css, length.into())
+                }
+            )*
+        }
+    };
+}
+
+length_properties! {
+    width: "width";
+    height: "height";
+    min_width: "min-width";
+    min_height: "min-height";
+    max_width: "max-width";
+    max_height: "max-height";
+    margin: "margin";
+    margin_top: "margin-top";
+    margin_right: "margin-right";
+    margin_bottom: "margin-bottom";
+    margin_left: "margin-left";
+    padding: "padding";
+    padding_top: "padding-top";
+    padding_right: "padding-right";
+    padding_bottom: "padding-bottom";
+    padding_left: "padding-left";
+    gap: "gap";
+    row_gap: "row-gap";
+    column_gap: "column-gap";
+    top: "top";
+    right: "right";
+    bottom: "bottom";
+    left: "left";
+    flex_basis: "flex-basis";
+    font_size: "font-size";
+    line_height: "line-height";
+    border_radius: "border-radius";
+    border_width: "border-width";
+}
+
+impl Display for Style {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.css)
+    }
+}
+
+impl IntoAttributeValue for Style {
+    fn into_value(self) -> AttributeValue {
+        AttributeValue::Text(self.css)
+    }
+}
+
+/// A CSS length, created with [`px`], [`em`], [`rem`], [`percent`], [`vw`] or [`vh`]
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Length {
+    /// `auto`
+    Auto,
+    /// Pixels
+    Px(f64),
+    /// Multiples of the font size of the element
+    Em(f64),
+    /// Multiples of the font size of the root element
+    Rem(f64),
+    /// A percentage of the size of the parent
+    Percent(f64),
+    /// A percentage of the width of the viewport
+    Vw(f64),
+    /// A percentage of the height of the viewport
+    Vh(f64),
+}
+
+impl Display for Length {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Length::Auto => f.write_str("auto"),
+            // unitless zero is valid for every length
+            Length::Px(value)
+            | Length::Em(value)
+            | Length::Rem(value)
+            | Length::Percent(value)
+            | Length::Vw(value)
+            | Length::Vh(value)
+                if *value == 0.0 =>
+            {
+                f.write_str("0")
+            }
+            Length::Px(value) => write!(f, "{value}px"),
+            Length::Em(value) => write!(f, "{value}em"),
+            Length::Rem(value) => write!(f, "{value}rem"),
+            Length::Percent(value) => write!(f, "{value}%"),
+            Length::Vw(value) => write!(f, "{value}vw"),
+            Length::Vh(value) => write!(f, "{value}vh"),
+        }
+    }
+}
+
+/// A length in pixels
+pub fn px(value: impl Into<f64>) -> Length {
+    Length::Px(value.into())
+}
+
+/// A length in multiples of the font size of the element
+pub fn em(value: impl Into<f64>) -> Length {
+    Length::Em(value.into())
+}
+
+/// A length in multiples of the font size of the root element
+pub fn rem(value: impl Into<f64>) -> Length {
+    Length::Rem(value.into())
+}
+
+/// A length in percent of the size of the parent
+pub fn percent(value: impl Into<f64>) -> Length {
+    Length::Percent(value.into())
+}
+
+/// A length in percent of the width of the viewport
+pub fn vw(value: impl Into<f64>) -> Length {
+    Length::Vw(value.into())
+}
+
+/// A length in percent of the height of the viewport
+pub fn vh(value: impl Into<f64>) -> Length {
+    Length::Vh(value.into())
+}
+
+macro_rules! keywords {
+    ($(#[$attr:meta])* $name:ident { $($(#[$variant_attr:meta])* $variant:ident => $css:literal,)* }) => {
+        $(#[$attr])*
+        #[derive(Clone, Copy, PartialEq, Eq, Debug)]
+        pub enum $name {
+            $(
+                $(#[$variant_attr])*
+                #[doc = concat!("`", $css, "`")]
+                $variant,
+            )*
+        }
+
+        impl Display for $name {
+            fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+                f.write_str(match self {
+                    $(Self::$variant => $css,)*
+                })
+            }
+        }
+    };
+}
+
+keywords! {
+    /// A value of the `display` property
+    DisplayKind {
+        None => "none",
+        Block => "block",
+        Inline => "inline",
+        InlineBlock => "inline-block",
+        Flex => "flex",
+        InlineFlex => "inline-flex",
+        Grid => "grid",
+        InlineGrid => "inline-grid",
+        Contents => "contents",
+    }
+}
+
+keywords! {
+    /// A value of the `position` property
+    Position {
+        Static => "static",
+        Relative => "relative",
+        Absolute => "absolute",
+        Fixed => "fixed",
+        Sticky => "sticky",
+    }
+}
+
+keywords! {
+    /// A value of the `flex-direction` property
+    FlexDirection {
+        Row => "row",
+        RowReverse => "row-reverse",
+        Column => "column",
+        ColumnReverse => "column-reverse",
+    }
+}
+
+keywords! {
+    /// A value of the `align-items` and `align-self` properties
+    Align {
+        Start => "start",
+        End => "end",
+        FlexStart => "flex-start",
+        FlexEnd => "flex-end",
+        Center => "center",
+        Baseline => "baseline",
+        Stretch => "stretch",
+    }
+}
+
+keywords! {
+    /// A value of the `justify-content` property
+    Justify {
+        Start => "start",
+        End => "end",
+        FlexStart => "flex-start",
+        FlexEnd => "flex-end",
+        Center => "center",
+        SpaceBetween => "space-between",
+        SpaceAround => "space-around",
+        SpaceEvenly => "space-evenly",
+    }
+}
@@ -0,0 +1,70 @@
+/// Declare custom elements that can be used in rsx just like the built-in HTML elements.
+///
+/// Each element becomes a zero-sized struct with its tag name, namespace, and attributes. Custom elements
+/// implement [`GlobalAttributes`](crate::GlobalAttributes), so attributes like `class` and `id` work out of the box.
+///
+/// - `name = "tag-name"` renders the element with a different tag name (useful for web components with dashes)
+/// - `name in "namespace"` sets the namespace of the element
+/// - `attribute: Type = "attribute-name"` renders the attribute with a different name
+///
+/// Like the built-in elements, names can't contain underscores because rsx treats those as components.
+///
+/// The declared elements must be in the `dioxus_elements` module that rsx uses:
+///
+/// ```rust, ignore
+/// mod dioxus_elements {
+///     pub use dioxus::html::*;
+///
+///     dioxus::html::custom_elements! {
+///         /// A canvas that renders with a custom shader
+///         shadercanvas = "shader-canvas" {
+///             width: u32,
+///             shader_source: &str = "shader-source",
+///         }
+///     }
+/// }
+///
+/// rsx! {
+///     shadercanvas { width: 100, shader_source: "void main() {{}}" }
+/// }
+/// ```
+#[macro_export]
+macro_rules! custom_elements {
+    (
+        $(
+            $(#[$attr:meta])*
+            $name:ident $(= $tag:literal)? $(in $namespace:literal)? {
+                $(
+                    $(#[$attr_method:meta])*
+                    $fil:ident: $vil:ty $(= $attr_name:literal)?
+                ),* $(,)?
+            }
+        )*
+    ) => {
+        $(
+            #[allow(non_camel_case_types)]
+            $(#[$attr])*
+            pub struct $name;
+
+            #[allow(non_upper_case_globals)]
+            impl $name {
+                pub const TAG_NAME: &'static str = $crate::custom_elements!(@name $name $($tag)?);
+                pub const NAME_SPACE: Option<&'static str> = $crate::custom_elements!(@namespace $($namespace)?);
+
+                $(
+                    $(#[$attr_method])*
+                    #[doc = concat!("\n\nAccepts a `", stringify!($vil), "`.")]
+                    pub const $fil: $crate::AttributeDiscription =
+                        ($crate::custom_elements!(@name $fil $($attr_name)?), None, false);
+                )*
+            }
+
+            impl $crate::GlobalAttributes for $name {}
+        )*
+    };
+
+    (@name $name:ident) => { stringify!($name) };
+    (@name $name:ident $rename:literal) => { $rename };
+    (@namespace) => { None };
+    (@namespace $namespace:literal) => { Some($namespace) };
+}
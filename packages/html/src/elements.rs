@@ -361,6 +361,12 @@ macro_rules! builder_constructors {
             None
         }
 
+        /// Map a CSS property like `font-size` to the style attribute in a rsx style map like `font_size`
+        #[cfg(feature = "html-to-rsx")]
+        pub fn map_html_style_to_rsx(css: &str) -> Option<&'static str> {
+            crate::map_html_global_style_to_rsx(css).or_else(|| crate::map_html_svg_style_to_rsx(css))
+        }
+
         #[cfg(feature = "html-to-rsx")]
         pub fn map_html_element_to_rsx(html: &str) -> Option<&'static str> {
             $(
@@ -384,6 +390,14 @@ macro_rules! builder_constructors {
             );
         )*
 
+        /// The elements with their namespace and the names of their own attributes, to check the names dioxus-rsx knows against
+        #[cfg(test)]
+        pub(crate) const ELEMENT_NAMES: &[(&str, Option<&str>, &[&str])] = &[
+            $(
+                (stringify!($name), $name::NAME_SPACE, &[$(stringify!($fil)),*]),
+            )*
+        ];
+
         pub(crate) mod extensions {
             use super::*;
             $(
@@ -1940,3 +1954,44 @@ builder_constructors! {
         encoding: String DEFAULT,
     };
 }
+
+#[test]
+fn rsx_knows_the_element_names() {
+    use dioxus_rsx::html_names::*;
+
+    fn sorted<'a>(names: impl IntoIterator<Item = &'a str>) -> Vec<&'a str> {
+        let mut names = names.into_iter().collect::<Vec<_>>();
+        names.sort_unstable();
+        names
+    }
+
+    for (namespaced, known) in [(false, HTML_ELEMENTS), (true, SVG_ELEMENTS)] {
+        let elements = ELEMENT_NAMES
+            .iter()
+            .filter(|(_, namespace, _)| namespace.is_some() == namespaced);
+        assert_eq!(
+            sorted(elements.clone().map(|(name, _, _)| *name)),
+            sorted(known.iter().map(|(name, _)| *name)),
+            "the elements in dioxus_rsx::html_names are out of date"
+        );
+        for (name, _, attributes) in elements {
+            let (_, known) = known.iter().find(|(known, _)| known == name).unwrap();
+            assert_eq!(
+                sorted(attributes.iter().copied()),
+                sorted(known.iter().copied()),
+                "the attributes of `{name}` in dioxus_rsx::html_names are out of date"
+            );
+        }
+    }
+
+    assert_eq!(
+        sorted(<div as GlobalAttributes>::ATTRIBUTE_NAMES.iter().copied()),
+        sorted(GLOBAL_ATTRIBUTES.iter().copied()),
+        "the global attributes in dioxus_rsx::html_names are out of date"
+    );
+    assert_eq!(
+        sorted(<svg as SvgAttributes>::ATTRIBUTE_NAMES.iter().copied()),
+        sorted(SVG_ATTRIBUTES.iter().copied()),
+        "the svg attributes in dioxus_rsx::html_names are out of date"
+    );
+}
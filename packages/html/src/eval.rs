@@ -2,7 +2,9 @@
 
 use dioxus_core::prelude::*;
 use generational_box::{AnyStorage, GenerationalBox, UnsyncStorage};
+use serde::{de::DeserializeOwned, Serialize};
 use std::future::{poll_fn, Future, IntoFuture};
+use std::marker::PhantomData;
 use std::pin::Pin;
 use std::rc::Rc;
 use std::task::{Context, Poll};
@@ -118,6 +120,31 @@ impl UseEval {
         })
         .await
     }
+
+    /// Send and receive typed messages instead of [`serde_json::Value`]s. Messages are serialized with serde, so the same types work with the evaluated JavaScript on every platform.
+    ///
+    /// ```rust, ignore
+    /// #[derive(Serialize, Deserialize)]
+    /// struct Size { width: f64, height: f64 }
+    ///
+    /// let mut eval = eval(r#"
+    ///     let size = { width: window.innerWidth, height: window.innerHeight };
+    ///     dioxus.send(size);
+    ///     let scale = await dioxus.recv();
+    ///     return size.width * scale;
+    /// "#)
+    /// .typed::<f64, Size>();
+    ///
+    /// let size = eval.recv().await?;
+    /// eval.send(&2.0)?;
+    /// let width: f64 = eval.join().await?;
+    /// ```
+    pub fn typed<S: Serialize, R: DeserializeOwned>(self) -> TypedEval<S, R> {
+        TypedEval {
+            eval: self,
+            message_types: PhantomData,
+        }
+    }
 }
 
 impl IntoFuture for UseEval {
@@ -129,6 +156,46 @@ impl IntoFuture for UseEval {
     }
 }
 
+/// Evaluated JavaScript that is sent messages of type `S` and sends back messages of type `R`. Create one with [`UseEval::typed`].
+pub struct TypedEval<S, R> {
+    eval: UseEval,
+    message_types: PhantomData<fn(S) -> R>,
+}
+
+impl<S, R> Clone for TypedEval<S, R> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<S, R> Copy for TypedEval<S, R> {}
+
+impl<S: Serialize, R: DeserializeOwned> TypedEval<S, R> {
+    /// Sends a message to the evaluated JavaScript.
+    pub fn send(&self, message: &S) -> Result<(), EvalError> {
+        let data = serde_json::to_value(message)
+            .map_err(|err| EvalError::Communication(err.to_string()))?;
+        self.eval.send(data)
+    }
+
+    /// Receives the next message from the evaluated JavaScript.
+    pub async fn recv(&mut self) -> Result<R, EvalError> {
+        let data = self.eval.recv().await?;
+        serde_json::from_value(data).map_err(|err| EvalError::Communication(err.to_string()))
+    }
+
+    /// Gets the return value of the evaluated JavaScript.
+    pub async fn join<T: DeserializeOwned>(self) -> Result<T, EvalError> {
+        let data = self.eval.join().await?;
+        serde_json::from_value(data).map_err(|err| EvalError::Communication(err.to_string()))
+    }
+
+    /// Gets the untyped handle to the evaluated JavaScript
+    pub fn untyped(self) -> UseEval {
+        self.eval
+    }
+}
+
 /// Represents an error when evaluating JavaScript
 #[derive(Debug)]
 pub enum EvalError {
@@ -144,3 +211,57 @@ pub enum EvalError {
     /// Represents an error communicating between JavaScript and Rust.
     Communication(String),
 }
+
+#[test]
+fn typed_evals_serialize_messages() {
+    use std::cell::RefCell;
+    use std::collections::VecDeque;
+
+    /// Sends every message back to Rust
+    struct Echo(Rc<RefCell<VecDeque<serde_json::Value>>>);
+
+    impl Evaluator for Echo {
+        fn send(&self, data: serde_json::Value) -> Result<(), EvalError> {
+            self.0.borrow_mut().push_back(data);
+            Ok(())
+        }
+        fn poll_recv(&mut self, _: &mut Context<'_>) -> Poll<Result<serde_json::Value, EvalError>> {
+            Poll::Ready(self.0.borrow_mut().pop_front().ok_or(EvalError::Finished))
+        }
+        fn poll_join(&mut self, _: &mut Context<'_>) -> Poll<Result<serde_json::Value, EvalError>> {
+            Poll::Ready(Ok(serde_json::json!(1)))
+        }
+    }
+
+    /// The echo never waits, so every future is ready the first time it is polled
+    fn ready<F: Future>(future: F) -> F::Output {
+        fn noop(_: *const ()) {}
+        fn clone(data: *const ()) -> std::task::RawWaker {
+            std::task::RawWaker::new(data, &VTABLE)
+        }
+        static VTABLE: std::task::RawWakerVTable =
+            std::task::RawWakerVTable::new(clone, noop, noop, noop);
+        let waker = unsafe { std::task::Waker::from_raw(clone(std::ptr::null())) };
+        let mut future = std::pin::pin!(future);
+        match future.as_mut().poll(&mut Context::from_waker(&waker)) {
+            Poll::Ready(output) => output,
+            Poll::Pending => panic!("the future was not ready"),
+        }
+    }
+
+    let owner = UnsyncStorage::owner();
+    let evaluator = owner.insert(Box::new(Echo(Default::default())) as Box<dyn Evaluator>);
+    let mut eval = UseEval::new(evaluator).typed::<(String, u32), (String, u32)>();
+
+    eval.send(&("hello".to_string(), 2)).unwrap();
+    assert_eq!(ready(eval.recv()).unwrap(), ("hello".to_string(), 2));
+
+    // Messages that don't match the type are communication errors
+    eval.untyped().send(serde_json::json!("hello")).unwrap();
+    assert!(matches!(
+        ready(eval.recv()),
+        Err(EvalError::Communication(_))
+    ));
+
+    assert_eq!(ready(eval.join::<u32>()).unwrap(), 1);
+}
@@ -14,13 +14,19 @@ impl<E: HasClipboardData> From<E> for ClipboardData {
 
 impl std::fmt::Debug for ClipboardData {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("ClipboardData").finish()
+        f.debug_struct("ClipboardData")
+            .field("formats", &self.formats())
+            .finish()
     }
 }
 
 impl PartialEq for ClipboardData {
-    fn eq(&self, _other: &Self) -> bool {
-        true
+    fn eq(&self, other: &Self) -> bool {
+        let formats = self.formats();
+        formats == other.formats()
+            && formats
+                .iter()
+                .all(|format| self.data(format) == other.data(format))
     }
 }
 
@@ -32,6 +38,28 @@ impl ClipboardData {
         }
     }
 
+    /// The plain text on the clipboard, or the text that is being copied or cut. This is the `text/plain` format.
+    pub fn text(&self) -> Option<String> {
+        self.data("text/plain")
+    }
+
+    /// The html on the clipboard, or the html that is being copied or cut. This is the `text/html` format.
+    pub fn html(&self) -> Option<String> {
+        self.data("text/html")
+    }
+
+    /// The data of the clipboard in a format like `text/plain` or `text/uri-list`
+    ///
+    /// The clipboard can only be read in `onpaste`, or in `oncopy` and `oncut` after the data has been set by the page.
+    pub fn data(&self, format: &str) -> Option<String> {
+        self.inner.data(format)
+    }
+
+    /// The formats of the data on the clipboard
+    pub fn formats(&self) -> Vec<String> {
+        self.inner.formats()
+    }
+
     /// Downcast this event to a concrete event type
     pub fn downcast<T: 'static>(&self) -> Option<&T> {
         self.inner.as_ref().as_any().downcast_ref::<T>()
@@ -40,18 +68,44 @@ impl ClipboardData {
 
 #[cfg(feature = "serialize")]
 /// A serialized version of ClipboardData
-#[derive(serde::Serialize, serde::Deserialize, Debug, PartialEq, Clone)]
-pub struct SerializedClipboardData {}
+#[derive(serde::Serialize, serde::Deserialize, Debug, PartialEq, Clone, Default)]
+pub struct SerializedClipboardData {
+    /// The data on the clipboard, keyed by format
+    #[serde(default)]
+    data: std::collections::BTreeMap<String, String>,
+}
+
+#[cfg(feature = "serialize")]
+impl SerializedClipboardData {
+    /// Create a new SerializedClipboardData with the data on the clipboard keyed by format
+    pub fn new(data: impl IntoIterator<Item = (String, String)>) -> Self {
+        Self {
+            data: data.into_iter().collect(),
+        }
+    }
+}
 
 #[cfg(feature = "serialize")]
 impl From<&ClipboardData> for SerializedClipboardData {
-    fn from(_: &ClipboardData) -> Self {
-        Self {}
+    fn from(data: &ClipboardData) -> Self {
+        Self::new(
+            data.formats()
+                .into_iter()
+                .filter_map(|format| Some((format.clone(), data.data(&format)?))),
+        )
     }
 }
 
 #[cfg(feature = "serialize")]
 impl HasClipboardData for SerializedClipboardData {
+    fn data(&self, format: &str) -> Option<String> {
+        self.data.get(format).cloned()
+    }
+
+    fn formats(&self) -> Vec<String> {
+        self.data.keys().cloned().collect()
+    }
+
     fn as_any(&self) -> &dyn std::any::Any {
         self
     }
@@ -75,6 +129,16 @@ impl<'de> serde::Deserialize<'de> for ClipboardData {
 }
 
 pub trait HasClipboardData: std::any::Any {
+    /// The data on the clipboard in a format like `text/plain`
+    fn data(&self, _format: &str) -> Option<String> {
+        None
+    }
+
+    /// The formats of the data on the clipboard
+    fn formats(&self) -> Vec<String> {
+        Vec::new()
+    }
+
     /// return self as Any
     fn as_any(&self) -> &dyn std::any::Any;
 }
@@ -115,7 +115,10 @@ pub struct SerializedDragData {
 
 #[cfg(feature = "serialize")]
 impl SerializedDragData {
-    fn new(drag: &DragData, files: Option<crate::file_data::SerializedFileEngine>) -> Self {
+    pub(crate) fn new(
+        drag: &DragData,
+        files: Option<crate::file_data::SerializedFileEngine>,
+    ) -> Self {
         Self {
             mouse: crate::point_interaction::SerializedPointInteraction::from(drag),
             files,
@@ -207,7 +207,7 @@ impl SerializedFormData {
         }
     }
 
-    fn from_lossy(data: &FormData) -> Self {
+    pub(crate) fn from_lossy(data: &FormData) -> Self {
         Self {
             value: data.value(),
             values: data.values(),
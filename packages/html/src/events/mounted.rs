@@ -2,6 +2,8 @@
 
 use euclid::Rect;
 
+use crate::geometry::{PixelsSize, PixelsVector2D};
+
 use std::{
     fmt::{Display, Formatter},
     future::Future,
@@ -30,6 +32,25 @@ pub trait RenderedElementBacking: std::any::Any {
         Box::pin(async { Err(MountedError::NotSupported) })
     }
 
+    /// Get the distance the content of the element is scrolled
+    fn get_scroll_offset(&self) -> Pin<Box<dyn Future<Output = MountedResult<PixelsVector2D>>>> {
+        Box::pin(async { Err(MountedError::NotSupported) })
+    }
+
+    /// Get the size of the content of the element, including the parts that are scrolled out of view
+    fn get_scroll_size(&self) -> Pin<Box<dyn Future<Output = MountedResult<PixelsSize>>>> {
+        Box::pin(async { Err(MountedError::NotSupported) })
+    }
+
+    /// Scroll the content of the element to an offset
+    fn scroll(
+        &self,
+        _offset: PixelsVector2D,
+        _behavior: ScrollBehavior,
+    ) -> Pin<Box<dyn Future<Output = MountedResult<()>>>> {
+        Box::pin(async { Err(MountedError::NotSupported) })
+    }
+
     /// Set the focus on the element
     fn set_focus(&self, _focus: bool) -> Pin<Box<dyn Future<Output = MountedResult<()>>>> {
         Box::pin(async { Err(MountedError::NotSupported) })
@@ -43,6 +64,7 @@ impl RenderedElementBacking for () {
 }
 
 /// The way that scrolling should be performed
+#[derive(Clone, Copy, Debug, PartialEq)]
 #[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
 pub enum ScrollBehavior {
     /// Scroll to the element immediately
@@ -87,6 +109,25 @@ impl MountedData {
         self.inner.scroll_to(behavior)
     }
 
+    /// Get the distance the content of the element is scrolled
+    pub async fn get_scroll_offset(&self) -> MountedResult<PixelsVector2D> {
+        self.inner.get_scroll_offset().await
+    }
+
+    /// Get the size of the content of the element, including the parts that are scrolled out of view
+    pub async fn get_scroll_size(&self) -> MountedResult<PixelsSize> {
+        self.inner.get_scroll_size().await
+    }
+
+    /// Scroll the content of the element to an offset
+    pub fn scroll(
+        &self,
+        offset: PixelsVector2D,
+        behavior: ScrollBehavior,
+    ) -> Pin<Box<dyn Future<Output = MountedResult<()>>>> {
+        self.inner.scroll(offset, behavior)
+    }
+
     /// Set the focus on the element
     pub fn set_focus(&self, focus: bool) -> Pin<Box<dyn Future<Output = MountedResult<()>>>> {
         self.inner.set_focus(focus)
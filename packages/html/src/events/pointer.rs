@@ -2,6 +2,7 @@ use dioxus_core::Event;
 use keyboard_types::Modifiers;
 
 use crate::{geometry::*, input_data::*, prelude::*};
+use std::{future::Future, pin::Pin};
 
 /// A synthetic event that wraps a web-style [`PointerEvent`](https://developer.mozilla.org/en-US/docs/Web/API/PointerEvent)
 pub type PointerEvent = Event<PointerData>;
@@ -100,6 +101,17 @@ pub trait HasPointerData: PointerInteraction {
     /// Gets if the pointer represents the primary pointer of this pointer type.
     fn is_primary(&self) -> bool;
 
+    /// Capture the pointer on the element that received the event, so it receives the pointer events of this pointer
+    /// even after the pointer leaves the element
+    fn set_pointer_capture(&self) -> Pin<Box<dyn Future<Output = MountedResult<()>>>> {
+        Box::pin(async { Err(MountedError::NotSupported) })
+    }
+
+    /// Release the capture of the pointer set with [`HasPointerData::set_pointer_capture`]
+    fn release_pointer_capture(&self) -> Pin<Box<dyn Future<Output = MountedResult<()>>>> {
+        Box::pin(async { Err(MountedError::NotSupported) })
+    }
+
     /// return self as Any
     fn as_any(&self) -> &dyn std::any::Any;
 }
@@ -187,6 +199,19 @@ impl PointerData {
     pub fn is_primary(&self) -> bool {
         self.inner.is_primary()
     }
+
+    /// Capture the pointer on the element that received the event. Until the pointer is released or the capture is
+    /// released, the element receives all of the events of this pointer, even when the pointer leaves the element.
+    ///
+    /// This is useful for dragging and drawing. Capture the pointer in `onpointerdown`, while the pointer is pressed.
+    pub async fn set_pointer_capture(&self) -> MountedResult<()> {
+        self.inner.set_pointer_capture().await
+    }
+
+    /// Release the capture of the pointer set with [`PointerData::set_pointer_capture`]
+    pub async fn release_pointer_capture(&self) -> MountedResult<()> {
+        self.inner.release_pointer_capture().await
+    }
 }
 
 impl InteractionLocation for PointerData {
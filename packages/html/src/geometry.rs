@@ -29,6 +29,10 @@ pub type PagePoint = Point2D<f64, PageSpace>;
 pub struct Pixels;
 /// A vector expressed in Pixels
 pub type PixelsVector = Vector3D<f64, Pixels>;
+/// A two dimensional vector expressed in Pixels
+pub type PixelsVector2D = Vector2D<f64, Pixels>;
+/// A size expressed in Pixels
+pub type PixelsSize = Size2D<f64, Pixels>;
 
 /// A unit in terms of Lines
 ///
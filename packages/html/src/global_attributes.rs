@@ -53,21 +53,40 @@ macro_rules! html_to_rsx_attribute_mapping {
         $(#[$attr:meta])*
         $name:ident: $lit:literal;
     ) => {
-        if $matching == stringify!($lit) {
+        if $matching == $lit {
             return Some(stringify!($name));
         }
     };
+    // Style properties are not html attributes, they are mapped from css with html_to_rsx_style_mapping
     (
         $matching:ident;
         $(#[$attr:meta])*
         $name:ident: $lit:literal, $ns:literal;
     ) => {
-        if $matching == stringify!($lit) {
+        if $ns != "style" && $matching == $lit {
             return Some(stringify!($name));
         }
     };
 }
 
+#[cfg(feature = "html-to-rsx")]
+macro_rules! html_to_rsx_style_mapping {
+    (
+        $matching:ident;
+        $(#[$attr:meta])*
+        $name:ident: $lit:literal, $ns:literal;
+    ) => {
+        if $ns == "style" && $matching == $lit {
+            return Some(stringify!($name));
+        }
+    };
+    (
+        $matching:ident;
+        $(#[$attr:meta])*
+        $name:ident $(: $lit:literal)?;
+    ) => {};
+}
+
 macro_rules! trait_methods {
     (
         @base
@@ -75,6 +94,7 @@ macro_rules! trait_methods {
         $trait:ident;
         $fn:ident;
         $fn_html_to_rsx:ident;
+        $fn_html_style_to_rsx:ident;
         $(
             $(#[$attr:meta])*
             $name:ident $(: $($arg:literal),*)*;
@@ -82,6 +102,9 @@ macro_rules! trait_methods {
     ) => {
         $(#[$trait_attr])*
         pub trait $trait {
+            #[cfg(test)]
+            const ATTRIBUTE_NAMES: &'static [&'static str] = &[$(stringify!($name)),*];
+
             $(
                 $(#[$attr])*
                 const $name: AttributeDiscription = trait_methods! { $name $(: $($arg),*)*; };
@@ -111,6 +134,18 @@ macro_rules! trait_methods {
             None
         }
 
+        #[cfg(feature = "html-to-rsx")]
+        #[doc = "Converts a CSS property to an RSX style attribute"]
+        pub(crate) fn $fn_html_style_to_rsx(css: &str) -> Option<&'static str> {
+            $(
+                html_to_rsx_style_mapping! {
+                    css;
+                    $name$(: $($arg),*)*;
+                }
+            )*
+            None
+        }
+
         impl_extension_attributes![GLOBAL $trait { $($name,)* }];
     };
 
@@ -130,9 +165,12 @@ trait_methods! {
     GlobalAttributes;
     map_global_attributes;
     map_html_global_attributes_to_rsx;
+    map_html_global_style_to_rsx;
 
     /// Prevent the default action for this element.
     ///
+    /// Prefer the `prevent_default` event modifier on the listener itself: `onclick |prevent_default|: move |_| {}`
+    ///
     /// For more information, see the MDN docs:
     /// <https://developer.mozilla.org/en-US/docs/Web/API/Event/preventDefault>
     prevent_default: "dioxus-prevent-default";
@@ -260,6 +298,8 @@ trait_methods! {
     /// HTML from code is risky because it’s easy to inadvertently expose your users to a cross-site scripting (XSS)
     /// attack. So, you can set HTML directly from Dioxus, but you have to type out dangerous_inner_html to remind
     /// yourself that it’s dangerous
+    ///
+    /// To render a block of html without adding your own wrapper element, use `raw!(html)` in rsx instead.
     dangerous_inner_html;
 
     // This macro creates an explicit method call for each of the style attributes.
@@ -1645,9 +1685,12 @@ trait_methods! {
     SvgAttributes;
     map_svg_attributes;
     map_html_svg_attributes_to_rsx;
+    map_html_svg_style_to_rsx;
 
     /// Prevent the default action for this element.
     ///
+    /// Prefer the `prevent_default` event modifier on the listener itself: `onclick |prevent_default|: move |_| {}`
+    ///
     /// For more information, see the MDN docs:
     /// <https://developer.mozilla.org/en-US/docs/Web/API/Event/preventDefault>
     prevent_default: "dioxus-prevent-default";
@@ -1820,6 +1863,9 @@ trait_methods! {
     /// <https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/filter>
     filter: "filter";
 
+    /// <https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/filterUnits>
+    filter_units: "filterUnits";
+
     /// <https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/filterRes>
     filterRes: "filterRes";
 
@@ -1920,7 +1966,7 @@ trait_methods! {
     image_rendering: "image-rendering";
 
     /// <https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/_in>
-    _in: "_in";
+    _in: "in";
 
     /// <https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/in2>
     in2: "in2";
@@ -2000,6 +2046,9 @@ trait_methods! {
     /// <https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/mask>
     mask: "mask";
 
+    /// <https://developer.mozilla.org/en-US/docs/Web/CSS/mask-type>
+    mask_type: "mask-type";
+
     /// <https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/maskContentUnits>
     mask_content_units: "maskContentUnits";
 
@@ -2378,6 +2427,36 @@ trait_methods! {
     /// <https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/xmlns>
     xmlns: "xmlns";
 
+    /// <https://developer.mozilla.org/en-US/docs/Web/SVG/Namespaces_Crash_Course>
+    xmlns_xlink: "xmlns:xlink", "http://www.w3.org/2000/xmlns/";
+
+    /// <https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/xlink:href>
+    xlink_href: "xlink:href", "http://www.w3.org/1999/xlink";
+
+    /// <https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/xlink:title>
+    xlink_title: "xlink:title", "http://www.w3.org/1999/xlink";
+
+    /// <https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/xlink:arcrole>
+    xlink_arcrole: "xlink:arcrole", "http://www.w3.org/1999/xlink";
+
+    /// <https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/xlink:role>
+    xlink_role: "xlink:role", "http://www.w3.org/1999/xlink";
+
+    /// <https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/xlink:show>
+    xlink_show: "xlink:show", "http://www.w3.org/1999/xlink";
+
+    /// <https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/xlink:actuate>
+    xlink_actuate: "xlink:actuate", "http://www.w3.org/1999/xlink";
+
+    /// <https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/xlink:type>
+    xlink_type: "xlink:type", "http://www.w3.org/1999/xlink";
+
+    /// <https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/xml:lang>
+    xml_lang: "xml:lang", "http://www.w3.org/XML/1998/namespace";
+
+    /// <https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/xml:space>
+    xml_space: "xml:space", "http://www.w3.org/XML/1998/namespace";
+
     /// <https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/xChannelSelector>
     x_channel_selector: "xChannelSelector";
 
@@ -16,11 +16,14 @@
 //!
 //! Currently, we don't validate for structures, but do validate attributes.
 
+mod class_list;
+pub mod css;
+mod custom_elements;
 mod elements;
 #[cfg(feature = "hot-reload-context")]
 pub use elements::HtmlCtx;
 #[cfg(feature = "html-to-rsx")]
-pub use elements::{map_html_attribute_to_rsx, map_html_element_to_rsx};
+pub use elements::{map_html_attribute_to_rsx, map_html_element_to_rsx, map_html_style_to_rsx};
 pub mod events;
 pub(crate) mod file_data;
 pub use file_data::*;
@@ -30,6 +33,7 @@ pub mod input_data;
 #[cfg(feature = "native-bind")]
 pub mod native_bind;
 pub mod point_interaction;
+mod raw_html;
 mod render_template;
 #[cfg(feature = "wasm-bind")]
 mod web_sys_bind;
@@ -40,9 +44,11 @@ mod transit;
 #[cfg(feature = "serialize")]
 pub use transit::*;
 
+pub use class_list::*;
 pub use elements::*;
 pub use events::*;
 pub use global_attributes::*;
+pub use raw_html::*;
 pub use render_template::*;
 
 #[cfg(feature = "eval")]
@@ -54,6 +60,7 @@ pub mod extensions {
 }
 
 pub mod prelude {
+    pub use crate::css::*;
     pub use crate::elements::extensions::*;
     #[cfg(feature = "eval")]
     pub use crate::eval::*;
@@ -0,0 +1,15 @@
+//! Support for the `raw!(html)` node in rsx
+
+/// Referenced by every `raw!(html)` node in rsx.
+///
+/// `raw!` renders a string as html without escaping it, which opens the door to cross site scripting if the string
+/// contains user input. Enable the `raw-html-lint` feature to get a warning for every `raw!` node so they can be audited.
+/// A single use can be allowed with `#[allow(deprecated)]` on the surrounding item.
+#[doc(hidden)]
+#[cfg_attr(
+    feature = "raw-html-lint",
+    deprecated(
+        note = "raw! renders html without escaping it. Make sure the html does not contain untrusted input."
+    )
+)]
+pub const RAW_HTML: () = ();
@@ -59,78 +59,129 @@ fn fun_name(
         F::deserialize(f)
     }
 
-    let data = match name {
-        // Mouse
-        "click" | "contextmenu" | "dblclick" | "doubleclick" | "mousedown" | "mouseenter"
-        | "mouseleave" | "mousemove" | "mouseout" | "mouseover" | "mouseup" => Mouse(de(data)?),
+    let Some(kind) = EventKind::from_name(name) else {
+        return Err(serde_value::DeserializerError::UnknownVariant(
+            name.to_string(),
+            &[],
+        ));
+    };
 
-        // Clipboard
-        "copy" | "cut" | "paste" => Clipboard(de(data)?),
+    let data = match kind {
+        EventKind::Mouse => Mouse(de(data)?),
+        EventKind::Clipboard => Clipboard(de(data)?),
+        EventKind::Composition => Composition(de(data)?),
+        EventKind::Keyboard => Keyboard(de(data)?),
+        EventKind::Focus => Focus(de(data)?),
+        EventKind::Form => Form(de(data)?),
+        EventKind::Drag => Drag(de(data)?),
+        EventKind::Pointer => Pointer(de(data)?),
+        EventKind::Selection => Selection(de(data)?),
+        EventKind::Touch => Touch(de(data)?),
+        EventKind::Scroll => Scroll(de(data)?),
+        EventKind::Wheel => Wheel(de(data)?),
+        EventKind::Media => Media(de(data)?),
+        EventKind::Animation => Animation(de(data)?),
+        EventKind::Transition => Transition(de(data)?),
+        EventKind::Toggle => Toggle(de(data)?),
+        EventKind::Image => Image(de(data)?),
+        EventKind::Mounted => Mounted,
+    };
 
-        // Composition
-        "compositionend" | "compositionstart" | "compositionupdate" => Composition(de(data)?),
+    Ok(data)
+}
 
-        // Keyboard
-        "keydown" | "keypress" | "keyup" => Keyboard(de(data)?),
+/// The type of data an event carries
+#[derive(Clone, Copy)]
+enum EventKind {
+    Mouse,
+    Clipboard,
+    Composition,
+    Keyboard,
+    Focus,
+    Form,
+    Drag,
+    Pointer,
+    Selection,
+    Touch,
+    Scroll,
+    Wheel,
+    Media,
+    Animation,
+    Transition,
+    Toggle,
+    Image,
+    Mounted,
+}
 
-        // Focus
-        "blur" | "focus" | "focusin" | "focusout" => Focus(de(data)?),
+impl EventKind {
+    fn from_name(name: &str) -> Option<Self> {
+        let kind = match name {
+            // Mouse
+            "click" | "contextmenu" | "dblclick" | "doubleclick" | "mousedown" | "mouseenter"
+            | "mouseleave" | "mousemove" | "mouseout" | "mouseover" | "mouseup" => Self::Mouse,
 
-        // Form
-        "change" | "input" | "invalid" | "reset" | "submit" => Form(de(data)?),
+            // Clipboard
+            "copy" | "cut" | "paste" => Self::Clipboard,
 
-        // Drag
-        "drag" | "dragend" | "dragenter" | "dragexit" | "dragleave" | "dragover" | "dragstart"
-        | "drop" => Drag(de(data)?),
+            // Composition
+            "compositionend" | "compositionstart" | "compositionupdate" => Self::Composition,
 
-        // Pointer
-        "pointerlockchange" | "pointerlockerror" | "pointerdown" | "pointermove" | "pointerup"
-        | "pointerover" | "pointerout" | "pointerenter" | "pointerleave" | "gotpointercapture"
-        | "lostpointercapture" => Pointer(de(data)?),
+            // Keyboard
+            "keydown" | "keypress" | "keyup" => Self::Keyboard,
 
-        // Selection
-        "selectstart" | "selectionchange" | "select" => Selection(de(data)?),
+            // Focus
+            "blur" | "focus" | "focusin" | "focusout" => Self::Focus,
 
-        // Touch
-        "touchcancel" | "touchend" | "touchmove" | "touchstart" => Touch(de(data)?),
+            // Form
+            "change" | "input" | "invalid" | "reset" | "submit" => Self::Form,
 
-        // Scroll
-        "scroll" => Scroll(de(data)?),
+            // Drag
+            "drag" | "dragend" | "dragenter" | "dragexit" | "dragleave" | "dragover"
+            | "dragstart" | "drop" => Self::Drag,
 
-        // Wheel
-        "wheel" => Wheel(de(data)?),
+            // Pointer
+            "pointerlockchange" | "pointerlockerror" | "pointerdown" | "pointermove"
+            | "pointerup" | "pointerover" | "pointerout" | "pointerenter" | "pointerleave"
+            | "gotpointercapture" | "lostpointercapture" => Self::Pointer,
 
-        // Media
-        "abort" | "canplay" | "canplaythrough" | "durationchange" | "emptied" | "encrypted"
-        | "ended" | "interruptbegin" | "interruptend" | "loadeddata" | "loadedmetadata"
-        | "loadstart" | "pause" | "play" | "playing" | "progress" | "ratechange" | "seeked"
-        | "seeking" | "stalled" | "suspend" | "timeupdate" | "volumechange" | "waiting"
-        | "loadend" | "timeout" => Media(de(data)?),
+            // Selection
+            "selectstart" | "selectionchange" | "select" => Self::Selection,
 
-        // Animation
-        "animationstart" | "animationend" | "animationiteration" => Animation(de(data)?),
+            // Touch
+            "touchcancel" | "touchend" | "touchmove" | "touchstart" => Self::Touch,
 
-        // Transition
-        "transitionend" => Transition(de(data)?),
+            // Scroll
+            "scroll" => Self::Scroll,
 
-        // Toggle
-        "toggle" => Toggle(de(data)?),
+            // Wheel
+            "wheel" => Self::Wheel,
 
-        "load" | "error" => Image(de(data)?),
+            // Media
+            "abort" | "canplay" | "canplaythrough" | "durationchange" | "emptied" | "encrypted"
+            | "ended" | "interruptbegin" | "interruptend" | "loadeddata" | "loadedmetadata"
+            | "loadstart" | "pause" | "play" | "playing" | "progress" | "ratechange" | "seeked"
+            | "seeking" | "stalled" | "suspend" | "timeupdate" | "volumechange" | "waiting"
+            | "loadend" | "timeout" => Self::Media,
 
-        // Mounted
-        "mounted" => Mounted,
+            // Animation
+            "animationstart" | "animationend" | "animationiteration" => Self::Animation,
 
-        // OtherData => "abort" | "afterprint" | "beforeprint" | "beforeunload" | "hashchange" | "languagechange" | "message" | "offline" | "online" | "pagehide" | "pageshow" | "popstate" | "rejectionhandled" | "storage" | "unhandledrejection" | "unload" | "userproximity" | "vrdisplayactivate" | "vrdisplayblur" | "vrdisplayconnect" | "vrdisplaydeactivate" | "vrdisplaydisconnect" | "vrdisplayfocus" | "vrdisplaypointerrestricted" | "vrdisplaypointerunrestricted" | "vrdisplaypresentchange";
-        other => {
-            return Err(serde_value::DeserializerError::UnknownVariant(
-                other.to_string(),
-                &[],
-            ))
-        }
-    };
+            // Transition
+            "transitionend" => Self::Transition,
 
-    Ok(data)
+            // Toggle
+            "toggle" => Self::Toggle,
+
+            "load" | "error" => Self::Image,
+
+            // Mounted
+            "mounted" => Self::Mounted,
+
+            // OtherData => "abort" | "afterprint" | "beforeprint" | "beforeunload" | "hashchange" | "languagechange" | "message" | "offline" | "online" | "pagehide" | "pageshow" | "popstate" | "rejectionhandled" | "storage" | "unhandledrejection" | "unload" | "userproximity" | "vrdisplayactivate" | "vrdisplayblur" | "vrdisplayconnect" | "vrdisplaydeactivate" | "vrdisplaydisconnect" | "vrdisplayfocus" | "vrdisplaypointerrestricted" | "vrdisplaypointerunrestricted" | "vrdisplaypresentchange";
+            _ => return None,
+        };
+        Some(kind)
+    }
 }
 
 #[cfg(feature = "serialize")]
@@ -165,6 +216,37 @@ pub enum EventData {
 }
 
 impl EventData {
+    /// Serialize the data of an event created by any renderer, for example to send a web event to another thread. Returns None if the event name is not known.
+    ///
+    /// The data is converted with the event converter of the renderer. Files in form and drag events are not serialized.
+    pub fn from_platform(name: &str, data: &PlatformEventData) -> Option<Self> {
+        let data = match EventKind::from_name(name)? {
+            EventKind::Mouse => EventData::Mouse((&MouseData::from(data)).into()),
+            EventKind::Clipboard => EventData::Clipboard((&ClipboardData::from(data)).into()),
+            EventKind::Composition => EventData::Composition((&CompositionData::from(data)).into()),
+            EventKind::Keyboard => EventData::Keyboard((&KeyboardData::from(data)).into()),
+            EventKind::Focus => EventData::Focus((&FocusData::from(data)).into()),
+            EventKind::Form => {
+                EventData::Form(SerializedFormData::from_lossy(&FormData::from(data)))
+            }
+            EventKind::Drag => {
+                EventData::Drag(SerializedDragData::new(&DragData::from(data), None))
+            }
+            EventKind::Pointer => EventData::Pointer((&PointerData::from(data)).into()),
+            EventKind::Selection => EventData::Selection((&SelectionData::from(data)).into()),
+            EventKind::Touch => EventData::Touch((&TouchData::from(data)).into()),
+            EventKind::Scroll => EventData::Scroll((&ScrollData::from(data)).into()),
+            EventKind::Wheel => EventData::Wheel((&WheelData::from(data)).into()),
+            EventKind::Media => EventData::Media((&MediaData::from(data)).into()),
+            EventKind::Animation => EventData::Animation((&AnimationData::from(data)).into()),
+            EventKind::Transition => EventData::Transition((&TransitionData::from(data)).into()),
+            EventKind::Toggle => EventData::Toggle((&ToggleData::from(data)).into()),
+            EventKind::Image => EventData::Image((&ImageData::from(data)).into()),
+            EventKind::Mounted => EventData::Mounted,
+        };
+        Some(data)
+    }
+
     pub fn into_any(self) -> Rc<dyn Any> {
         match self {
             EventData::Mouse(data) => {
@@ -261,6 +343,62 @@ fn test_back_and_forth() {
     assert_eq!(data, p);
 }
 
+#[test]
+fn platform_events_serialize_by_name() {
+    set_event_converter(Box::new(SerializedHtmlEventConverter));
+
+    let mouse = SerializedMouseData::default();
+    let data = PlatformEventData::new(Box::new(mouse.clone()));
+    assert_eq!(
+        EventData::from_platform("click", &data),
+        Some(EventData::Mouse(mouse))
+    );
+
+    let data = PlatformEventData::new(Box::new(MountedData::new(())));
+    assert_eq!(
+        EventData::from_platform("mounted", &data),
+        Some(EventData::Mounted)
+    );
+    assert_eq!(EventData::from_platform("not-an-event", &data), None);
+}
+
+#[test]
+fn clipboard_and_composition_data_deserialize() {
+    set_event_converter(Box::new(SerializedHtmlEventConverter));
+
+    let paste: HtmlEvent = serde_json::from_str(
+        r#"{
+  "element": 0,
+  "name": "paste",
+  "bubbles": true,
+  "data": { "data": { "text/plain": "hello", "text/html": "<b>hello</b>" } }
+}"#,
+    )
+    .unwrap();
+    let event = paste.data.into_any();
+    let clipboard = ClipboardData::from(event.downcast_ref::<PlatformEventData>().unwrap());
+    assert_eq!(clipboard.text().as_deref(), Some("hello"));
+    assert_eq!(clipboard.html().as_deref(), Some("<b>hello</b>"));
+    assert_eq!(clipboard.data("text/uri-list"), None);
+    assert_eq!(clipboard.formats(), ["text/html", "text/plain"]);
+
+    // copy events without data on the clipboard send an empty object
+    let copy: HtmlEvent =
+        serde_json::from_str(r#"{ "element": 0, "name": "copy", "bubbles": true, "data": {} }"#)
+            .unwrap();
+    let event = copy.data.into_any();
+    let clipboard = ClipboardData::from(event.downcast_ref::<PlatformEventData>().unwrap());
+    assert_eq!(clipboard.text(), None);
+
+    let composition: HtmlEvent = serde_json::from_str(
+        r#"{ "element": 0, "name": "compositionend", "bubbles": true, "data": { "data": "日本" } }"#,
+    )
+    .unwrap();
+    let event = composition.data.into_any();
+    let composition = CompositionData::from(event.downcast_ref::<PlatformEventData>().unwrap());
+    assert_eq!(composition.data(), "日本");
+}
+
 /// A trait for converting from a serialized event to a concrete event type.
 pub struct SerializedHtmlEventConverter;
 
@@ -307,11 +307,57 @@ impl HasPointerData for PointerEvent {
         self.is_primary()
     }
 
+    fn set_pointer_capture(
+        &self,
+    ) -> std::pin::Pin<Box<dyn std::future::Future<Output = crate::MountedResult<()>>>> {
+        let result = pointer_target(self).and_then(|target| {
+            target
+                .set_pointer_capture(self.pointer_id())
+                .map_err(|err| {
+                    crate::MountedError::OperationFailed(Box::new(PointerCaptureError(err)))
+                })
+        });
+        Box::pin(async { result })
+    }
+
+    fn release_pointer_capture(
+        &self,
+    ) -> std::pin::Pin<Box<dyn std::future::Future<Output = crate::MountedResult<()>>>> {
+        let result = pointer_target(self).and_then(|target| {
+            target
+                .release_pointer_capture(self.pointer_id())
+                .map_err(|err| {
+                    crate::MountedError::OperationFailed(Box::new(PointerCaptureError(err)))
+                })
+        });
+        Box::pin(async { result })
+    }
+
     fn as_any(&self) -> &dyn std::any::Any {
         self
     }
 }
 
+fn pointer_target(event: &PointerEvent) -> crate::MountedResult<web_sys::Element> {
+    event
+        .target()
+        .and_then(|target| target.dyn_into::<web_sys::Element>().ok())
+        .ok_or_else(|| {
+            crate::MountedError::OperationFailed(Box::new(PointerCaptureError(event.into())))
+        })
+}
+
+#[derive(Debug)]
+struct PointerCaptureError(JsValue);
+
+impl std::fmt::Display for PointerCaptureError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "failed to capture pointer {:?}", self.0)
+    }
+}
+
+impl std::error::Error for PointerCaptureError {}
+
 impl InteractionLocation for PointerEvent {
     fn client_coordinates(&self) -> ClientPoint {
         ClientPoint::new(self.client_x().into(), self.client_y().into())
@@ -456,6 +502,51 @@ impl crate::RenderedElementBacking for web_sys::Element {
         Box::pin(async { Ok(()) })
     }
 
+    fn get_scroll_offset(
+        &self,
+    ) -> std::pin::Pin<
+        Box<
+            dyn std::future::Future<Output = crate::MountedResult<crate::geometry::PixelsVector2D>>,
+        >,
+    > {
+        let offset = crate::geometry::PixelsVector2D::new(
+            self.scroll_left() as f64,
+            self.scroll_top() as f64,
+        );
+        Box::pin(async move { Ok(offset) })
+    }
+
+    fn get_scroll_size(
+        &self,
+    ) -> std::pin::Pin<
+        Box<dyn std::future::Future<Output = crate::MountedResult<crate::geometry::PixelsSize>>>,
+    > {
+        let size = crate::geometry::PixelsSize::new(
+            self.scroll_width() as f64,
+            self.scroll_height() as f64,
+        );
+        Box::pin(async move { Ok(size) })
+    }
+
+    fn scroll(
+        &self,
+        offset: crate::geometry::PixelsVector2D,
+        behavior: crate::ScrollBehavior,
+    ) -> std::pin::Pin<Box<dyn std::future::Future<Output = crate::MountedResult<()>>>> {
+        let behavior = match behavior {
+            crate::ScrollBehavior::Instant => web_sys::ScrollBehavior::Instant,
+            crate::ScrollBehavior::Smooth => web_sys::ScrollBehavior::Smooth,
+        };
+        self.scroll_to_with_scroll_to_options(
+            web_sys::ScrollToOptions::new()
+                .left(offset.x)
+                .top(offset.y)
+                .behavior(behavior),
+        );
+
+        Box::pin(async { Ok(()) })
+    }
+
     fn set_focus(
         &self,
         focus: bool,
@@ -489,6 +580,29 @@ impl HasScrollData for Event {
 }
 
 impl HasClipboardData for Event {
+    fn data(&self, format: &str) -> Option<String> {
+        let data = self
+            .dyn_ref::<web_sys::ClipboardEvent>()?
+            .clipboard_data()?
+            .get_data(format)
+            .ok()?;
+        (!data.is_empty()).then_some(data)
+    }
+
+    fn formats(&self) -> Vec<String> {
+        let Some(data) = self
+            .dyn_ref::<web_sys::ClipboardEvent>()
+            .and_then(|event| event.clipboard_data())
+        else {
+            return Vec::new();
+        };
+        data.types()
+            .iter()
+            .filter_map(|format| format.as_string())
+            .filter(|format| format != "Files")
+            .collect()
+    }
+
     fn as_any(&self) -> &dyn std::any::Any {
         self
     }
@@ -1,6 +1,8 @@
 // this handler is only provided on the desktop and liveview implementations since this
 // method is not used by the web implementation
-this.handler = async function (event, name, bubbles) {
+this.handler = async function (event) {
+  const name = event.type;
+  const bubbles = this.listeners.bubbles(name);
   let target = event.target;
   if (target != null) {
     let preventDefaultRequests = null;
@@ -42,7 +44,7 @@ this.handler = async function (event, name, bubbles) {
       }
     }
 
-    const realId = find_real_id(target);
+    const realId = this.listeners.find(event);
 
     if (
       preventDefaultRequests &&
@@ -146,69 +148,76 @@ this.handler = async function (event, name, bubbles) {
   }
 }
 
-function find_real_id(target) {
-  let realId = null;
-  if (target instanceof Element) {
-    realId = target.getAttribute(`data-dioxus-id`);
-  }
-  // walk the tree to find the real element
-  while (realId == null) {
-    // we've reached the root we don't want to send an event
-    if (target.parentElement === null) {
-      return;
-    }
-
-    target = target.parentElement;
-    if (target instanceof Element) {
-      realId = target.getAttribute(`data-dioxus-id`);
-    }
-  }
-  return realId;
-}
-
 class ListenerMap {
   constructor(root) {
-    // bubbling events can listen at the root element
+    // the number of listeners for each event name. Every event name is listened to once at the root element
     this.global = {};
-    // non bubbling events listen at the element the listener was created at
+    // the non bubbling events each element listens to, keyed by the id of the element
     this.local = {};
     this.root = null;
+    // the roots of portal subtrees that are outside of the root element
+    this.subtreeRoots = [];
+    // the listener for every event, set when the interpreter is initialized
+    this.handler = null;
   }
 
-  create(event_name, element, bubbles, handler) {
-    if (bubbles) {
-      if (this.global[event_name] === undefined) {
-        this.global[event_name] = {};
-        this.global[event_name].active = 1;
-        this.root.addEventListener(event_name, handler);
-      } else {
-        this.global[event_name].active++;
+  addSubtreeRoot(root) {
+    if (this.subtreeRoots.includes(root) || this.root.contains(root) || root.contains(this.root)) {
+      return;
+    }
+    this.subtreeRoots.push(root);
+    for (const event_name in this.global) {
+      root.addEventListener(event_name, this.handler, !this.global[event_name].bubbles);
+    }
+  }
+
+  create(event_name, element, bubbles) {
+    if (this.global[event_name] === undefined) {
+      // events that don't bubble still pass through the root in the capture phase
+      this.global[event_name] = { active: 1, bubbles };
+      this.root.addEventListener(event_name, this.handler, !bubbles);
+      for (const root of this.subtreeRoots) {
+        root.addEventListener(event_name, this.handler, !bubbles);
       }
+    } else {
+      this.global[event_name].active++;
     }
-    else {
+
+    if (!bubbles) {
       const id = element.getAttribute("data-dioxus-id");
       if (!this.local[id]) {
         this.local[id] = {};
       }
-      element.addEventListener(event_name, handler);
+      this.local[id][event_name] = (this.local[id][event_name] || 0) + 1;
     }
   }
 
   remove(element, event_name, bubbles) {
-    if (bubbles) {
-      this.global[event_name].active--;
-      if (this.global[event_name].active === 0) {
-        this.root.removeEventListener(event_name, this.global[event_name].callback);
-        delete this.global[event_name];
+    if (!bubbles) {
+      const id = element.getAttribute("data-dioxus-id");
+      const local = this.local[id];
+      if (local && local[event_name]) {
+        local[event_name]--;
+        if (local[event_name] === 0) {
+          delete local[event_name];
+        }
+        if (Object.keys(local).length === 0) {
+          delete this.local[id];
+        }
       }
     }
-    else {
-      const id = element.getAttribute("data-dioxus-id");
-      delete this.local[id][event_name];
-      if (this.local[id].length === 0) {
-        delete this.local[id];
+
+    const global = this.global[event_name];
+    if (global === undefined) {
+      return;
+    }
+    global.active--;
+    if (global.active === 0) {
+      this.root.removeEventListener(event_name, this.handler, !global.bubbles);
+      for (const root of this.subtreeRoots) {
+        root.removeEventListener(event_name, this.handler, !global.bubbles);
       }
-      element.removeEventListener(event_name, this.global[event_name].callback);
+      delete this.global[event_name];
     }
   }
 
@@ -216,6 +225,38 @@ class ListenerMap {
     const id = element.getAttribute("data-dioxus-id");
     delete this.local[id];
   }
+
+  bubbles(event_name) {
+    const global = this.global[event_name];
+    return global === undefined || global.bubbles;
+  }
+
+  // find the id of the element an event is sent to from the path of the event
+  find(event) {
+    const path = event.composedPath();
+    if (this.bubbles(event.type)) {
+      // bubbling events are sent to the closest element with an id, the virtual dom bubbles them from there
+      for (const node of path) {
+        if (node instanceof Element && node.hasAttribute("data-dioxus-id")) {
+          return node.getAttribute("data-dioxus-id");
+        }
+      }
+      return null;
+    }
+
+    // non bubbling events are sent to the closest element listening to them. Events that don't bubble in the
+    // browser either only reach their target
+    const candidates = event.bubbles ? path : [event.target];
+    for (const node of candidates) {
+      if (node instanceof Element) {
+        const id = node.getAttribute("data-dioxus-id");
+        if (id !== null && this.local[id] && this.local[id][event.type]) {
+          return id;
+        }
+      }
+    }
+    return null;
+  }
 }
 this.LoadChild = function (array) {
   // iterate through each number and get that child
@@ -233,6 +274,7 @@ this.listeners = new ListenerMap();
 this.nodes = [];
 this.stack = [];
 this.templates = {};
+this.subtrees = {};
 this.end = null;
 
 this.AppendChildren = function (id, many) {
@@ -243,10 +285,30 @@ this.AppendChildren = function (id, many) {
   }
 }
 
+this.CreateSubtreeRoot = function (subtree, target) {
+  let root = document.getElementById(target);
+  if (root === null) {
+    root = document.createElement("div");
+    root.id = target;
+    document.body.appendChild(root);
+  }
+  this.subtrees[subtree] = root;
+  this.listeners.addSubtreeRoot(root);
+}
+
+this.AppendToSubtree = function (subtree, many) {
+  let root = this.subtrees[subtree];
+  let els = this.stack.splice(this.stack.length - many);
+  for (let k = 0; k < many; k++) {
+    root.appendChild(els[k]);
+  }
+}
+
 this.initialize = function (root) {
   this.nodes = [root];
   this.stack = [root];
   this.listeners.root = root;
+  this.listeners.handler = (event) => this.handler(event);
 }
 
 this.getClientRect = function (id) {
@@ -273,6 +335,35 @@ this.scrollTo = function (id, behavior) {
   return true;
 }
 
+this.getScrollOffset = function (id) {
+  const node = this.nodes[id];
+  if (!node) {
+    return;
+  }
+  return [node.scrollLeft, node.scrollTop];
+}
+
+this.getScrollSize = function (id) {
+  const node = this.nodes[id];
+  if (!node) {
+    return;
+  }
+  return [node.scrollWidth, node.scrollHeight];
+}
+
+this.scroll = function (id, x, y, behavior) {
+  const node = this.nodes[id];
+  if (!node) {
+    return false;
+  }
+  node.scrollTo({
+    left: x,
+    top: y,
+    behavior: behavior,
+  });
+  return true;
+}
+
 /// Set the focus on the element
 this.setFocus = function (id, focus) {
   const node = this.nodes[id];
@@ -287,6 +378,24 @@ this.setFocus = function (id, focus) {
   return true;
 }
 
+/// Capture or release the pointer on the element
+this.setPointerCapture = function (id, pointerId, capture) {
+  const node = this.nodes[id];
+  if (!node) {
+    return false;
+  }
+  try {
+    if (capture) {
+      node.setPointerCapture(pointerId);
+    } else {
+      node.releasePointerCapture(pointerId);
+    }
+  } catch {
+    return false;
+  }
+  return true;
+}
+
 function get_mouse_data(event) {
   const {
     altKey,
@@ -326,8 +435,17 @@ async function serialize_event(event) {
   switch (event.type) {
     case "copy":
     case "cut":
-    case "past": {
-      return {};
+    case "paste": {
+      const data = {};
+      const clipboardData = event.clipboardData;
+      if (clipboardData) {
+        for (const format of clipboardData.types) {
+          if (format !== "Files") {
+            data[format] = clipboardData.getData(format);
+          }
+        }
+      }
+      return { data };
     }
     case "compositionend":
     case "compositionstart":
@@ -20,15 +20,30 @@ mod js {
             // non bubbling events listen at the element the listener was created at
             this.local = {};
             this.root = root;
+            // the roots of portal subtrees that are outside of the root element
+            this.subtreeRoots = [];
             this.handler = null;
         }
 
+        addSubtreeRoot(root) {
+            if (this.subtreeRoots.includes(root) || this.root.contains(root) || root.contains(this.root)) {
+                return;
+            }
+            this.subtreeRoots.push(root);
+            for (const event_name in this.global) {
+                root.addEventListener(event_name, this.handler);
+            }
+        }
+
         create(event_name, element, bubbles) {
             if (bubbles) {
                 if (this.global[event_name] === undefined) {
                     this.global[event_name] = {};
                     this.global[event_name].active = 1;
                     this.root.addEventListener(event_name, this.handler);
+                    for (const root of this.subtreeRoots) {
+                        root.addEventListener(event_name, this.handler);
+                    }
                 } else {
                     this.global[event_name].active++;
                 }
@@ -47,6 +62,9 @@ mod js {
                 this.global[event_name].active--;
                 if (this.global[event_name].active === 0) {
                     this.root.removeEventListener(event_name, this.global[event_name].callback);
+                    for (const root of this.subtreeRoots) {
+                        root.removeEventListener(event_name, this.handler);
+                    }
                     delete this.global[event_name];
                 }
             }
@@ -81,6 +99,7 @@ mod js {
     this.nodes = [];
     this.stack = [];
     this.templates = {};
+    this.subtrees = {};
     this.save_template = function(nodes, tmpl_id) {
         this.templates[tmpl_id] = nodes;
     }
@@ -134,6 +153,23 @@ mod js {
             root.appendChild(els[k]);
         }
     }
+    this.CreateSubtreeRoot = function (subtree, target) {
+        let root = document.getElementById(target);
+        if (root === null) {
+            root = document.createElement('div');
+            root.id = target;
+            document.body.appendChild(root);
+        }
+        this.subtrees[subtree] = root;
+        this.listeners.addSubtreeRoot(root);
+    }
+    this.AppendToSubtree = function (subtree, many) {
+        let root = this.subtrees[subtree];
+        let els = this.stack.splice(this.stack.length-many);
+        for (let k = 0; k < many; k++) {
+            root.appendChild(els[k]);
+        }
+    }
     "#;
 
     fn mount_to_root() {
@@ -148,6 +184,12 @@ mod js {
     fn pop_root() {
         "{this.stack.pop();}"
     }
+    fn create_subtree_root(subtree: u32, target: &str) {
+        "{this.CreateSubtreeRoot($subtree$, $target$);}"
+    }
+    fn append_to_subtree(subtree: u32, many: u16) {
+        "{this.AppendToSubtree($subtree$, $many$);}"
+    }
     fn replace_with(id: u32, n: u16) {
         "{const root = this.nodes[$id$]; let els = this.stack.splice(this.stack.length-$n$); if (root.listening) { this.listeners.removeAllNonBubbling(root); } root.replaceWith(...els);}"
     }
@@ -286,6 +328,12 @@ pub mod binary_protocol {
         fn pop_root() {
             "{this.stack.pop();}"
         }
+        fn create_subtree_root(subtree: u32, target: &str) {
+            "{this.CreateSubtreeRoot($subtree$, $target$);}"
+        }
+        fn append_to_subtree(subtree: u32, many: u16) {
+            "{this.AppendToSubtree($subtree$, $many$);}"
+        }
         fn replace_with(id: u32, n: u16) {
             "{let root = this.nodes[$id$]; let els = this.stack.splice(this.stack.length-$n$); if (root.listening) { this.listeners.removeAllNonBubbling(root); } root.replaceWith(...els);}"
         }
@@ -339,13 +387,11 @@ pub mod binary_protocol {
                     })
                 );
             } else {
-                this.listeners.create(event_name, node, bubbles, (event) => {
-                    this.handler(event, event_name, bubbles);
-                });
+                this.listeners.create(event_name, node, bubbles);
             }"#
         }
         fn remove_event_listener(event_name: &str<u8, evt>, id: u32, bubbles: u8) {
-            "{let node = this.nodes[$id$]; node.listening -= 1; node.removeAttribute('data-dioxus-id'); this.listeners.remove(node, $event_name$, $bubbles$);}"
+            "{let node = this.nodes[$id$]; this.listeners.remove(node, $event_name$, $bubbles$ == 1); node.listening -= 1; if (!node.listening) { node.removeAttribute('data-dioxus-id'); }}"
         }
         fn set_text(id: u32, text: &str) {
             "{this.nodes[$id$].textContent = $text$;}"
@@ -1,6 +1,8 @@
+use std::time::Duration;
+
 use dioxus_html::event_bubbles;
 
-use dioxus_core::{TemplateAttribute, TemplateNode, WriteMutations};
+use dioxus_core::{ScopeId, TemplateAttribute, TemplateNode, WriteMutations};
 use sledgehammer_utils::rustc_hash::FxHashMap;
 
 use crate::binary_protocol::Channel;
@@ -14,6 +16,8 @@ pub struct MutationState {
     templates: FxHashMap<String, u16>,
     /// The channel that we are applying mutations to
     channel: Channel,
+    /// The exit animations of removed presences the renderer needs to wait for
+    exits: Vec<(ScopeId, Duration)>,
 }
 
 impl MutationState {
@@ -40,6 +44,11 @@ impl MutationState {
         &mut self.channel
     }
 
+    /// Take the exit animations started since they were last taken. The renderer calls [`ScopeId::finish_exit`] for each of them once the exit duration passed
+    pub fn take_exits(&mut self) -> Vec<(ScopeId, Duration)> {
+        std::mem::take(&mut self.exits)
+    }
+
     fn create_template_node(&mut self, node: &'static TemplateNode) {
         use TemplateNode::*;
         match node {
@@ -197,4 +206,16 @@ impl WriteMutations for MutationState {
     fn push_root(&mut self, _id: dioxus_core::ElementId) {
         self.channel.push_root(0);
     }
+
+    fn create_subtree_root(&mut self, subtree: usize, target: &str) {
+        self.channel.create_subtree_root(subtree as u32, target);
+    }
+
+    fn append_to_subtree(&mut self, subtree: usize, m: usize) {
+        self.channel.append_to_subtree(subtree as u32, m as u16);
+    }
+
+    fn wait_for_exit(&mut self, scope: ScopeId, _id: dioxus_core::ElementId, duration: Duration) {
+        self.exits.push((scope, duration));
+    }
 }
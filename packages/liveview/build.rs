@@ -0,0 +1,66 @@
+use dioxus_interpreter_js::binary_protocol::SLEDGEHAMMER_JS;
+use minify_js::{minify, Session, TopLevelMode};
+
+// The interpreter and the code that connects it to the websocket are minified when the crate is built instead of every time a page is served
+//
+// There is no rerun-if-changed directive, so the glue is minified again when any file of the crate changes. The interpreter is inlined from dioxus-interpreter-js, and changes to it rerun the script because it is a build dependency.
+fn main() {
+    let serialize_file_uploads = r#"if (
+        target.tagName === "INPUT" &&
+        (event.type === "change" || event.type === "input")
+      ) {
+        const type = target.getAttribute("type");
+        if (type === "file") {
+          async function read_files() {
+            const files = target.files;
+            const file_contents = {};
+
+            for (let i = 0; i < files.length; i++) {
+              const file = files[i];
+
+              file_contents[file.name] = Array.from(
+                new Uint8Array(await file.arrayBuffer())
+              );
+            }
+            let file_engine = {
+              files: file_contents,
+            };
+            contents.files = file_engine;
+
+            if (realId === null) {
+              return;
+            }
+            const message = window.interpreter.serializeIpcMessage("user_event", {
+              name: name,
+              element: parseInt(realId),
+              data: contents,
+              bubbles,
+            });
+            window.ipc.postMessage(message);
+          }
+          read_files();
+          return;
+        }
+      }"#;
+    let mut interpreter = SLEDGEHAMMER_JS
+        .replace("/*POST_EVENT_SERIALIZATION*/", serialize_file_uploads)
+        .replace("export", "");
+    while let Some(import_start) = interpreter.find("import") {
+        let import_end = interpreter[import_start..]
+            .find([';', '\n'])
+            .map(|i| i + import_start)
+            .unwrap_or_else(|| interpreter.len());
+        interpreter.replace_range(import_start..import_end, "");
+    }
+
+    let main_js = std::fs::read_to_string("src/main.js").unwrap();
+
+    let js = format!("{interpreter}\n{main_js}");
+
+    let session = Session::new();
+    let mut out = Vec::new();
+    minify(&session, TopLevelMode::Module, js.as_bytes(), &mut out).unwrap();
+
+    let out_dir = std::env::var("OUT_DIR").unwrap();
+    std::fs::write(std::path::Path::new(&out_dir).join("glue.js"), out).unwrap();
+}
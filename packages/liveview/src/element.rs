@@ -1,5 +1,8 @@
 use dioxus_core::ElementId;
-use dioxus_html::{geometry::euclid::Rect, MountedResult, RenderedElementBacking};
+use dioxus_html::{
+    geometry::{euclid::Rect, PixelsSize, PixelsVector2D},
+    MountedResult, RenderedElementBacking,
+};
 
 use crate::query::QueryEngine;
 
@@ -73,6 +76,80 @@ impl RenderedElementBacking for LiveviewElement {
         })
     }
 
+    fn get_scroll_offset(
+        &self,
+    ) -> std::pin::Pin<
+        Box<dyn futures_util::Future<Output = dioxus_html::MountedResult<PixelsVector2D>>>,
+    > {
+        let script = format!("return window.interpreter.getScrollOffset({});", self.id.0);
+
+        let fut = self
+            .query
+            .new_query::<Option<PixelsVector2D>>(&script)
+            .resolve();
+        Box::pin(async move {
+            match fut.await {
+                Ok(Some(offset)) => Ok(offset),
+                Ok(None) => MountedResult::Err(dioxus_html::MountedError::OperationFailed(
+                    Box::new(DesktopQueryError::FailedToQuery),
+                )),
+                Err(err) => {
+                    MountedResult::Err(dioxus_html::MountedError::OperationFailed(Box::new(err)))
+                }
+            }
+        })
+    }
+
+    fn get_scroll_size(
+        &self,
+    ) -> std::pin::Pin<Box<dyn futures_util::Future<Output = dioxus_html::MountedResult<PixelsSize>>>>
+    {
+        let script = format!("return window.interpreter.getScrollSize({});", self.id.0);
+
+        let fut = self
+            .query
+            .new_query::<Option<PixelsSize>>(&script)
+            .resolve();
+        Box::pin(async move {
+            match fut.await {
+                Ok(Some(size)) => Ok(size),
+                Ok(None) => MountedResult::Err(dioxus_html::MountedError::OperationFailed(
+                    Box::new(DesktopQueryError::FailedToQuery),
+                )),
+                Err(err) => {
+                    MountedResult::Err(dioxus_html::MountedError::OperationFailed(Box::new(err)))
+                }
+            }
+        })
+    }
+
+    fn scroll(
+        &self,
+        offset: PixelsVector2D,
+        behavior: dioxus_html::ScrollBehavior,
+    ) -> std::pin::Pin<Box<dyn futures_util::Future<Output = dioxus_html::MountedResult<()>>>> {
+        let script = format!(
+            "return window.interpreter.scroll({}, {}, {}, {});",
+            self.id.0,
+            offset.x,
+            offset.y,
+            serde_json::to_string(&behavior).expect("Failed to serialize ScrollBehavior")
+        );
+
+        let fut = self.query.new_query::<bool>(&script).resolve();
+        Box::pin(async move {
+            match fut.await {
+                Ok(true) => Ok(()),
+                Ok(false) => MountedResult::Err(dioxus_html::MountedError::OperationFailed(
+                    Box::new(DesktopQueryError::FailedToQuery),
+                )),
+                Err(err) => {
+                    MountedResult::Err(dioxus_html::MountedError::OperationFailed(Box::new(err)))
+                }
+            }
+        })
+    }
+
     fn set_focus(
         &self,
         focus: bool,
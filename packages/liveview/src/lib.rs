@@ -15,6 +15,8 @@ mod config;
 mod eval;
 mod events;
 pub use config::*;
+mod throttle;
+pub use throttle::*;
 #[cfg(feature = "axum")]
 pub mod launch;
 
@@ -30,67 +32,8 @@ pub enum LiveViewError {
     SendingFailed,
 }
 
-fn handle_edits_code() -> String {
-    use dioxus_interpreter_js::binary_protocol::SLEDGEHAMMER_JS;
-    use minify_js::{minify, Session, TopLevelMode};
-
-    let serialize_file_uploads = r#"if (
-        target.tagName === "INPUT" &&
-        (event.type === "change" || event.type === "input")
-      ) {
-        const type = target.getAttribute("type");
-        if (type === "file") {
-          async function read_files() {
-            const files = target.files;
-            const file_contents = {};
-
-            for (let i = 0; i < files.length; i++) {
-              const file = files[i];
-
-              file_contents[file.name] = Array.from(
-                new Uint8Array(await file.arrayBuffer())
-              );
-            }
-            let file_engine = {
-              files: file_contents,
-            };
-            contents.files = file_engine;
-
-            if (realId === null) {
-              return;
-            }
-            const message = window.interpreter.serializeIpcMessage("user_event", {
-              name: name,
-              element: parseInt(realId),
-              data: contents,
-              bubbles,
-            });
-            window.ipc.postMessage(message);
-          }
-          read_files();
-          return;
-        }
-      }"#;
-    let mut interpreter = SLEDGEHAMMER_JS
-        .replace("/*POST_EVENT_SERIALIZATION*/", serialize_file_uploads)
-        .replace("export", "");
-    while let Some(import_start) = interpreter.find("import") {
-        let import_end = interpreter[import_start..]
-            .find(|c| c == ';' || c == '\n')
-            .map(|i| i + import_start)
-            .unwrap_or_else(|| interpreter.len());
-        interpreter.replace_range(import_start..import_end, "");
-    }
-
-    let main_js = include_str!("./main.js");
-
-    let js = format!("{interpreter}\n{main_js}");
-
-    let session = Session::new();
-    let mut out = Vec::new();
-    minify(&session, TopLevelMode::Module, js.as_bytes(), &mut out).unwrap();
-    String::from_utf8(out).unwrap()
-}
+/// The minified interpreter and websocket client. This is generated by the build script
+const HANDLE_EDITS_CODE: &str = include_str!(concat!(env!("OUT_DIR"), "/glue.js"));
 
 /// This script that gets injected into your app connects this page to the websocket endpoint
 ///
@@ -128,7 +71,7 @@ pub fn interpreter_glue(url_or_path: &str) -> String {
         "return path;"
     };
 
-    let handle_edits = handle_edits_code();
+    let handle_edits = HANDLE_EDITS_CODE;
 
     format!(
         r#"
@@ -9,8 +9,19 @@ function main() {
 
 class IPC {
   constructor(root) {
+    this.root = root;
+    // The number of times we tried to reconnect since the last successful connection
+    this.retries = 0;
+    // How many milliseconds the client waits between sending each throttled event. The server sends this before any edits
+    this.throttle = {};
+    // The latest event that is waiting to be sent for each throttled event and element
+    this.pending = new Map();
+    this.connect();
+  }
+
+  connect() {
     window.interpreter = new JSChannel();
-    window.interpreter.initialize(root);
+    window.interpreter.initialize(this.root);
     const ws = new WebSocket(WS_ADDR);
     ws.binaryType = "arraybuffer";
 
@@ -18,14 +29,24 @@ class IPC {
       ws.send("__ping__");
     }
 
+    let pingInterval = null;
+
     ws.onopen = () => {
+      // Every connection gets a new virtual dom that renders the page from scratch
+      this.root.replaceChildren();
+      this.retries = 0;
       // we ping every 30 seconds to keep the websocket alive
-      setInterval(ping, 30000);
+      pingInterval = setInterval(ping, 30000);
       ws.send(window.interpreter.serializeIpcMessage("initialize"));
     };
 
-    ws.onerror = (err) => {
-      // todo: retry the connection
+    ws.onclose = () => {
+      clearInterval(pingInterval);
+      this.clearPending();
+      // Reconnect with an exponential backoff so a server that restarts doesn't get flooded
+      const delay = Math.min(500 * 2 ** this.retries, 10000);
+      this.retries += 1;
+      setTimeout(() => this.connect(), delay);
     };
 
     ws.onmessage = (message) => {
@@ -51,6 +72,9 @@ class IPC {
             case "query":
               Function("Eval", `"use strict";${event.data};`)();
               break;
+            case "throttle":
+              this.throttle = event.data;
+              break;
           }
         }
       }
@@ -60,7 +84,70 @@ class IPC {
   }
 
   postMessage(msg) {
+    // Events that happen while the connection is down are dropped
+    if (this.ws.readyState !== WebSocket.OPEN) {
+      return;
+    }
+
+    const interval = this.throttleInterval(msg);
+    if (interval == null) {
+      // Send any throttled events first so the server gets events in the order they happened
+      this.flushPending();
+      this.ws.send(msg);
+      return;
+    }
+
+    const { name, element } = JSON.parse(msg).params;
+    const key = `${name}:${element}`;
+    const pending = this.pending.get(key);
+    if (pending) {
+      // Only the latest event is sent at the end of the interval
+      pending.msg = msg;
+      return;
+    }
+
+    // The first event is sent immediately, and the events during the interval are coalesced into the latest one
     this.ws.send(msg);
+    const entry = { msg: null };
+    entry.timeout = setTimeout(() => this.sendPending(key), interval);
+    this.pending.set(key, entry);
+  }
+
+  throttleInterval(msg) {
+    if (Object.keys(this.throttle).length === 0) {
+      return null;
+    }
+    const { method, params } = JSON.parse(msg);
+    if (method !== "user_event") {
+      return null;
+    }
+    return this.throttle[params.name] ?? null;
+  }
+
+  sendPending(key) {
+    const entry = this.pending.get(key);
+    this.pending.delete(key);
+    if (entry && entry.msg != null && this.ws.readyState === WebSocket.OPEN) {
+      // Keep coalescing for another interval after sending the latest event
+      this.postMessage(entry.msg);
+    }
+  }
+
+  flushPending() {
+    for (const [key, entry] of this.pending) {
+      clearTimeout(entry.timeout);
+      this.pending.delete(key);
+      if (entry.msg != null) {
+        this.ws.send(entry.msg);
+      }
+    }
+  }
+
+  clearPending() {
+    for (const entry of this.pending.values()) {
+      clearTimeout(entry.timeout);
+    }
+    this.pending.clear();
   }
 }
 
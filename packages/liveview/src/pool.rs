@@ -3,19 +3,20 @@ use crate::{
     eval::init_eval,
     events::SerializedHtmlEventConverter,
     query::{QueryEngine, QueryResult},
-    LiveViewError,
+    EventThrottle, LiveViewError,
 };
 use dioxus_core::prelude::*;
 use dioxus_html::{EventData, HtmlEvent, PlatformEventData};
 use dioxus_interpreter_js::MutationState;
 use futures_util::{pin_mut, SinkExt, StreamExt};
 use serde::Serialize;
-use std::{rc::Rc, time::Duration};
+use std::{collections::HashMap, rc::Rc, time::Duration};
 use tokio_util::task::LocalPoolHandle;
 
 #[derive(Clone)]
 pub struct LiveViewPool {
     pub(crate) pool: LocalPoolHandle,
+    pub(crate) throttle: EventThrottle,
 }
 
 impl Default for LiveViewPool {
@@ -31,9 +32,16 @@ impl LiveViewPool {
 
         LiveViewPool {
             pool: LocalPoolHandle::new(16),
+            throttle: EventThrottle::default(),
         }
     }
 
+    /// Set how often the clients of this pool send continuous events like `mousemove` or `scroll`.
+    pub fn with_event_throttle(mut self, throttle: EventThrottle) -> Self {
+        self.throttle = throttle;
+        self
+    }
+
     pub async fn launch(
         &self,
         ws: impl LiveViewSocket,
@@ -57,7 +65,12 @@ impl LiveViewPool {
         ws: impl LiveViewSocket,
         make_app: F,
     ) -> Result<(), LiveViewError> {
-        match self.pool.spawn_pinned(move || run(make_app(), ws)).await {
+        let throttle = self.throttle.clone();
+        match self
+            .pool
+            .spawn_pinned(move || run_with_throttle(make_app(), ws, throttle))
+            .await
+        {
             Ok(Ok(_)) => Ok(()),
             Ok(Err(e)) => Err(e),
             Err(_) => Err(LiveViewError::SendingFailed),
@@ -115,7 +128,16 @@ impl<S> LiveViewSocket for S where
 /// As long as your framework can provide a Sink and Stream of Bytes, you can use this function.
 ///
 /// You might need to transform the error types of the web backend into the LiveView error type.
-pub async fn run(mut vdom: VirtualDom, ws: impl LiveViewSocket) -> Result<(), LiveViewError> {
+pub async fn run(vdom: VirtualDom, ws: impl LiveViewSocket) -> Result<(), LiveViewError> {
+    run_with_throttle(vdom, ws, EventThrottle::default()).await
+}
+
+/// The same as [`run`], but with a custom [`EventThrottle`] for the events the client sends.
+pub async fn run_with_throttle(
+    mut vdom: VirtualDom,
+    ws: impl LiveViewSocket,
+    throttle: EventThrottle,
+) -> Result<(), LiveViewError> {
     #[cfg(all(feature = "hot-reload", debug_assertions))]
     let mut hot_reload_rx = {
         let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
@@ -138,6 +160,12 @@ pub async fn run(mut vdom: VirtualDom, ws: impl LiveViewSocket) -> Result<(), Li
     // pin the futures so we can use select!
     pin_mut!(ws);
 
+    // tell the client how often to send continuous events before it sends any
+    ws.send(text_frame(
+        &serde_json::to_string(&ClientUpdate::Throttle(throttle.client_intervals())).unwrap(),
+    ))
+    .await?;
+
     if let Some(edits) = {
         vdom.rebuild(&mut mutations);
         take_edits(&mut mutations)
@@ -218,6 +246,8 @@ pub async fn run(mut vdom: VirtualDom, ws: impl LiveViewSocket) -> Result<(), Li
                     dioxus_hot_reload::HotReloadMsg::UpdateTemplate(new_template) => {
                         vdom.replace_template(new_template);
                     }
+                    // Assets are loaded by the browser, not the liveview server
+                    dioxus_hot_reload::HotReloadMsg::UpdateAsset(_) => {}
                     dioxus_hot_reload::HotReloadMsg::Shutdown => {
                         std::process::exit(0);
                     },
@@ -236,6 +266,16 @@ pub async fn run(mut vdom: VirtualDom, ws: impl LiveViewSocket) -> Result<(), Li
         // render the vdom
         vdom.render_immediate(&mut mutations);
 
+        // exit animations end after their duration
+        for (scope, duration) in mutations.take_exits() {
+            vdom.in_runtime(|| {
+                scope.spawn(async move {
+                    tokio::time::sleep(duration).await;
+                    scope.finish_exit();
+                })
+            });
+        }
+
         if let Some(edits) = take_edits(&mut mutations) {
             ws.send(edits).await?;
         }
@@ -257,7 +297,9 @@ fn take_edits(mutations: &mut MutationState) -> Option<Vec<u8>> {
 
 #[derive(Serialize)]
 #[serde(tag = "type", content = "data")]
-enum ClientUpdate {
+enum ClientUpdate<'a> {
     #[serde(rename = "query")]
     Query(String),
+    #[serde(rename = "throttle")]
+    Throttle(HashMap<&'a str, u128>),
 }
@@ -0,0 +1,75 @@
+use std::{collections::HashMap, time::Duration};
+
+/// The events that fire continuously while the user interacts with the page
+const CONTINUOUS_EVENTS: &[&str] = &[
+    "mousemove",
+    "pointermove",
+    "touchmove",
+    "drag",
+    "dragover",
+    "wheel",
+    "scroll",
+];
+
+/// Limits how often the client sends continuous events like `mousemove` or `scroll` to the server.
+///
+/// Throttled events are coalesced on the client: the first event is sent right away, and only the latest event of each element is sent at the end of every interval. Other events flush the throttled events first, so the server still receives events in order.
+///
+/// By default, continuous events are sent at most every 50ms and all other events, including `input`, are sent immediately.
+///
+/// ```rust
+/// use std::time::Duration;
+/// use dioxus_liveview::{EventThrottle, LiveViewPool};
+///
+/// let throttle = EventThrottle::default()
+///     .event("mousemove", Duration::from_millis(100))
+///     .event("input", Duration::from_millis(200));
+/// let pool = LiveViewPool::new().with_event_throttle(throttle);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct EventThrottle {
+    intervals: HashMap<String, Duration>,
+}
+
+impl Default for EventThrottle {
+    fn default() -> Self {
+        CONTINUOUS_EVENTS
+            .iter()
+            .fold(Self::new(), |throttle, event| {
+                throttle.event(*event, Duration::from_millis(50))
+            })
+    }
+}
+
+impl EventThrottle {
+    /// Create a throttle that sends every event immediately.
+    pub fn new() -> Self {
+        Self {
+            intervals: HashMap::new(),
+        }
+    }
+
+    /// Send an event at most once per interval. An interval of zero sends the event immediately.
+    pub fn event(mut self, name: impl Into<String>, interval: Duration) -> Self {
+        let name = name.into();
+        if interval.is_zero() {
+            self.intervals.remove(&name);
+        } else {
+            self.intervals.insert(name, interval);
+        }
+        self
+    }
+
+    /// Get the interval an event is throttled to, if the event is throttled.
+    pub fn interval(&self, name: &str) -> Option<Duration> {
+        self.intervals.get(name).copied()
+    }
+
+    /// The intervals in milliseconds, in the format the client expects
+    pub(crate) fn client_intervals(&self) -> HashMap<&str, u128> {
+        self.intervals
+            .iter()
+            .map(|(name, interval)| (name.as_str(), interval.as_millis()))
+            .collect()
+    }
+}
@@ -3,3 +3,9 @@
 #![doc(html_favicon_url = "https://avatars.githubusercontent.com/u/79236386")]
 
 pub use dioxus_desktop::*;
+
+/// The lifecycle of the app and the back button, which the mobile renderer sends to these hooks
+pub use dioxus_hooks::{use_app_lifecycle, use_back_handler, AppLifecycle};
+
+/// The insets of the screen that are covered by a notch, the home indicator or the on-screen keyboard
+pub use dioxus_hooks::{use_safe_area, SafeArea};
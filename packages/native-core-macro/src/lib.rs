@@ -202,7 +202,7 @@ pub fn partial_derive_state(_: TokenStream, input: TokenStream) -> TokenStream {
                 .collect::<Vec<_>>();
             quote! {
                 let raw_node: (#(*const #node_dependencies,)*) = {
-                    let (#(#temps,)*) = (#(&#node_view,)*).get(id).unwrap_or_else(|err| panic!("Failed to get node view {:?}", err));
+                    let (#(#temps,)*) = (#(#node_view,)*).get(id).unwrap_or_else(|err| panic!("Failed to get node view {:?}", err));
                     (#(#temps as *const _,)*)
                 };
             }
@@ -236,7 +236,7 @@ pub fn partial_derive_state(_: TokenStream, input: TokenStream) -> TokenStream {
                 .collect::<Vec<_>>();
             quote! {
                 let raw_parent = tree.parent_id_advanced(id, Self::TRAVERSE_SHADOW_DOM).and_then(|parent_id| {
-                    let raw_parent: Option<(#(*const #parent_dependencies,)*)> = (#(&#parent_view,)*).get(parent_id).ok().map(|c| {
+                    let raw_parent: Option<(#(*const #parent_dependencies,)*)> = (#(#parent_view,)*).get(parent_id).ok().map(|c| {
                         let (#(#temps,)*) = c;
                         (#(#temps as *const _,)*)
                     });
@@ -273,7 +273,7 @@ pub fn partial_derive_state(_: TokenStream, input: TokenStream) -> TokenStream {
                 .collect::<Vec<_>>();
             quote! {
                 let raw_children: Vec<_> = tree.children_ids_advanced(id, Self::TRAVERSE_SHADOW_DOM).into_iter().filter_map(|id| {
-                    let raw_children: Option<(#(*const #child_dependencies,)*)> = (#(&#child_view,)*).get(id).ok().map(|c| {
+                    let raw_children: Option<(#(*const #child_dependencies,)*)> = (#(#child_view,)*).get(id).ok().map(|c| {
                         let (#(#temps,)*) = c;
                         (#(#temps as *const _,)*)
                     });
@@ -322,6 +322,28 @@ pub fn partial_derive_state(_: TokenStream, input: TokenStream) -> TokenStream {
         }
     });
 
+    // Borrow every view immutably inside the closures passed to run_pass. The states of this pass are read through the shared pointer while no closure borrows them mutably
+    let other_views = (0..combined_dependencies.len())
+        .filter(|i| *i != this_type_idx)
+        .map(|i| format_ident!("__data{}", i));
+    let borrow_views = quote! {
+        #(let #other_views = &#other_views;)*
+        // Safety: run_pass never calls the closures that borrow the states mutably while this closure runs
+        let #this_view = unsafe { __states.get() };
+    };
+    let get_dependencies = quote! {
+        let node_data: &NodeType<_> = node_types.get(id).unwrap_or_else(|err| panic!("Failed to get node type {:?}", err));
+        // get all of the states from the tree view
+        // Safety: No node has itself as a parent or child.
+        #get_node_view
+        #get_parent_view
+        #get_child_view
+
+        #deref_node_view
+        #deref_parent_view
+        #deref_child_view
+    };
+
     quote!(
         #(#attrs)*
         #defaultness #unsafety #impl_token #generics #trait_ #for_ #self_ty {
@@ -330,7 +352,7 @@ pub fn partial_derive_state(_: TokenStream, input: TokenStream) -> TokenStream {
             #(#items)*
 
             fn workload_system(type_id: std::any::TypeId, dependants: std::sync::Arc<dioxus_native_core::prelude::Dependants>, pass_direction: dioxus_native_core::prelude::PassDirection) -> dioxus_native_core::exports::shipyard::WorkloadSystem {
-                use dioxus_native_core::exports::shipyard::{IntoWorkloadSystem, Get, AddComponent};
+                use dioxus_native_core::exports::shipyard::{IntoWorkloadSystem, Get, AddComponent, Remove};
                 use dioxus_native_core::tree::TreeRef;
                 use dioxus_native_core::prelude::{NodeType, NodeView};
 
@@ -340,31 +362,29 @@ pub fn partial_derive_state(_: TokenStream, input: TokenStream) -> TokenStream {
                     let (#(#split_views,)*) = data;
                     let tree = run_view.tree.clone();
                     let node_types = run_view.node_type.clone();
+                    // The states of this pass are shared between the closures so nodes at the same height can be updated in parallel
+                    let __states = dioxus_native_core::prelude::SharedViewPtr::new(&mut #this_view);
                     dioxus_native_core::prelude::run_pass(type_id, dependants.clone(), pass_direction, run_view, |id, context| {
-                        let node_data: &NodeType<_> = node_types.get(id).unwrap_or_else(|err| panic!("Failed to get node type {:?}", err));
-                        // get all of the states from the tree view
-                        // Safety: No node has itself as a parent or child.
-                        let raw_myself: Option<*mut Self> = (&mut #this_view).get(id).ok().map(|c| c as *mut _);
-                        #get_node_view
-                        #get_parent_view
-                        #get_child_view
+                        #borrow_views
+                        if #this_view.contains(id) {
+                            return None;
+                        }
+                        #get_dependencies
 
-                        let myself: Option<&mut Self> = unsafe { raw_myself.map(|val| &mut *val) };
-                        #deref_node_view
-                        #deref_parent_view
-                        #deref_child_view
+                        let view = NodeView::new(id, node_data, &node_mask);
+                        Some(Self::create(view, node, parent, children, context))
+                    }, |id, state| {
+                        // Safety: States are inserted one at a time while no states are read
+                        unsafe { __states.get_mut() }.add_component_unchecked(id, state);
+                    }, |id| {
+                        // Safety: States are taken one at a time while no states are read
+                        unsafe { __states.get_mut() }.remove(id).unwrap_or_else(|| panic!("Failed to get state {:?}", id))
+                    }, |id, myself: &mut Self, context| {
+                        #borrow_views
+                        #get_dependencies
 
                         let view = NodeView::new(id, node_data, &node_mask);
-                        if let Some(myself) = myself {
-                            myself
-                                .update(view, node, parent, children, context)
-                        }
-                        else {
-                            (&mut #this_view).add_component_unchecked(
-                                id,
-                                Self::create(view, node, parent, children, context));
-                            true
-                        }
+                        myself.update(view, node, parent, children, context)
                     })
                 }).into_workload_system().unwrap()
             }
@@ -0,0 +1,193 @@
+//! Benchmarks resolving passes over large trees, both when every state is created and when every node changed after the states were created. Compare the results with and without the `parallel` feature:
+//!
+//! ```sh
+//! cargo bench -p dioxus-native-core --bench passes
+//! cargo bench -p dioxus-native-core --bench passes --features parallel
+//! ```
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use dioxus_native_core::exports::shipyard::Component;
+use dioxus_native_core::prelude::*;
+use dioxus_native_core::real_dom::NodeTypeMut;
+use dioxus_native_core_macro::partial_derive_state;
+
+criterion_group!(mbenches, update_passes, update_changed_nodes);
+criterion_main!(mbenches);
+
+/// The depth of a node in the tree, resolved from the root to the leaves
+#[derive(Default, Debug, Clone, Copy, PartialEq, Component)]
+struct Depth(usize);
+
+#[partial_derive_state]
+impl State for Depth {
+    type ParentDependencies = (Self,);
+    type ChildDependencies = ();
+    type NodeDependencies = ();
+
+    const NODE_MASK: NodeMaskBuilder<'static> = NodeMaskBuilder::new();
+
+    fn update<'a>(
+        &mut self,
+        _: NodeView,
+        _: <Self::NodeDependencies as Dependancy>::ElementBorrowed<'a>,
+        parent: Option<<Self::ParentDependencies as Dependancy>::ElementBorrowed<'a>>,
+        _: Vec<<Self::ChildDependencies as Dependancy>::ElementBorrowed<'a>>,
+        _: &SendAnyMap,
+    ) -> bool {
+        let new = Depth(parent.map(|(parent,)| parent.0 + 1).unwrap_or_default());
+        let changed = *self != new;
+        *self = new;
+        changed
+    }
+}
+
+/// The number of nodes in the subtree of a node, resolved from the leaves to the root
+#[derive(Default, Debug, Clone, Copy, PartialEq, Component)]
+struct SubtreeSize(usize);
+
+#[partial_derive_state]
+impl State for SubtreeSize {
+    type ParentDependencies = ();
+    type ChildDependencies = (Self,);
+    type NodeDependencies = ();
+
+    const NODE_MASK: NodeMaskBuilder<'static> = NodeMaskBuilder::new();
+
+    fn update<'a>(
+        &mut self,
+        _: NodeView,
+        _: <Self::NodeDependencies as Dependancy>::ElementBorrowed<'a>,
+        _: Option<<Self::ParentDependencies as Dependancy>::ElementBorrowed<'a>>,
+        children: Vec<<Self::ChildDependencies as Dependancy>::ElementBorrowed<'a>>,
+        _: &SendAnyMap,
+    ) -> bool {
+        let new = SubtreeSize(1 + children.iter().map(|(child,)| child.0).sum::<usize>());
+        let changed = *self != new;
+        *self = new;
+        changed
+    }
+}
+
+/// Text attributes that depend on the state of the node, resolved in any order
+#[derive(Default, Debug, Clone, PartialEq, Component)]
+struct Label(String);
+
+#[partial_derive_state]
+impl State for Label {
+    type ParentDependencies = ();
+    type ChildDependencies = ();
+    type NodeDependencies = (Depth, SubtreeSize);
+
+    const NODE_MASK: NodeMaskBuilder<'static> = NodeMaskBuilder::new()
+        .with_tag()
+        .with_attrs(AttributeMaskBuilder::Some(&["title"]));
+
+    fn update<'a>(
+        &mut self,
+        view: NodeView,
+        (depth, size): <Self::NodeDependencies as Dependancy>::ElementBorrowed<'a>,
+        _: Option<<Self::ParentDependencies as Dependancy>::ElementBorrowed<'a>>,
+        _: Vec<<Self::ChildDependencies as Dependancy>::ElementBorrowed<'a>>,
+        _: &SendAnyMap,
+    ) -> bool {
+        let title = view
+            .attributes()
+            .into_iter()
+            .flatten()
+            .find_map(|attr| attr.value.as_text())
+            .unwrap_or_default();
+        let new = Label(format!(
+            "{} {title} at depth {} with {} nodes",
+            view.tag().unwrap_or("text"),
+            depth.0,
+            size.0
+        ));
+        let changed = *self != new;
+        *self = new;
+        changed
+    }
+}
+
+const TREES: [(usize, u32); 4] = [(2, 12), (4, 7), (10, 4), (100, 2)];
+
+/// Create a tree where every node has `width` children until the tree is `depth` levels deep
+fn create_tree(width: usize, depth: u32) -> RealDom {
+    let mut rdom = RealDom::new([
+        Depth::to_type_erased(),
+        SubtreeSize::to_type_erased(),
+        Label::to_type_erased(),
+    ]);
+    let mut level = vec![rdom.root_id()];
+    for _ in 0..depth {
+        let mut next_level = Vec::with_capacity(level.len() * width);
+        for parent in level {
+            for _ in 0..width {
+                let child = rdom
+                    .create_node(NodeType::Element(ElementNode::new("div", None)))
+                    .id();
+                rdom.get_mut(parent).unwrap().add_child(child);
+                next_level.push(child);
+            }
+        }
+        level = next_level;
+    }
+    rdom
+}
+
+fn update_passes(c: &mut Criterion) {
+    let mut group = c.benchmark_group("Update passes");
+    group.sample_size(10);
+
+    for (width, depth) in TREES {
+        let nodes: usize = (1..=depth).map(|level| width.pow(level)).sum();
+        group.bench_with_input(
+            BenchmarkId::new("nodes", format!("{nodes} ({width} wide, {depth} deep)")),
+            &(width, depth),
+            |b, &(width, depth)| {
+                b.iter_with_setup(
+                    || create_tree(width, depth),
+                    |mut rdom| {
+                        rdom.update_state(SendAnyMap::new());
+                        rdom
+                    },
+                )
+            },
+        );
+    }
+}
+
+/// Change an attribute of every node after the states are created, so only the update path is measured
+fn update_changed_nodes(c: &mut Criterion) {
+    let mut group = c.benchmark_group("Update changed nodes");
+    group.sample_size(10);
+
+    for (width, depth) in TREES {
+        let nodes: usize = (1..=depth).map(|level| width.pow(level)).sum();
+        group.bench_with_input(
+            BenchmarkId::new("nodes", format!("{nodes} ({width} wide, {depth} deep)")),
+            &(width, depth),
+            |b, &(width, depth)| {
+                b.iter_with_setup(
+                    || {
+                        let mut rdom = create_tree(width, depth);
+                        rdom.update_state(SendAnyMap::new());
+                        let mut ids = Vec::new();
+                        rdom.traverse_depth_first(|node| ids.push(node.id()));
+                        for id in ids {
+                            if let NodeTypeMut::Element(mut element) =
+                                rdom.get_mut(id).unwrap().node_type_mut()
+                            {
+                                element.set_attribute("title".to_string(), "changed".to_string());
+                            }
+                        }
+                        rdom
+                    },
+                    |mut rdom| {
+                        rdom.update_state(SendAnyMap::new());
+                        rdom
+                    },
+                )
+            },
+        );
+    }
+}
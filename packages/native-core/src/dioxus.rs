@@ -1,7 +1,9 @@
 //! Integration between Dioxus and the RealDom
 
+use std::time::Duration;
+
 use crate::tree::TreeMut;
-use dioxus_core::{AttributeValue, ElementId, TemplateNode, WriteMutations};
+use dioxus_core::{AttributeValue, ElementId, ScopeId, TemplateNode, WriteMutations};
 use rustc_hash::{FxHashMap, FxHashSet};
 use shipyard::Component;
 
@@ -23,6 +25,8 @@ pub struct DioxusState {
     templates: FxHashMap<String, Vec<NodeId>>,
     stack: Vec<NodeId>,
     node_id_mapping: Vec<Option<NodeId>>,
+    subtree_roots: FxHashMap<String, NodeId>,
+    subtrees: FxHashMap<usize, NodeId>,
 }
 
 impl DioxusState {
@@ -35,6 +39,8 @@ impl DioxusState {
             templates: FxHashMap::default(),
             stack: vec![root_id],
             node_id_mapping: vec![Some(root_id)],
+            subtree_roots: FxHashMap::default(),
+            subtrees: FxHashMap::default(),
         }
     }
 
@@ -251,13 +257,63 @@ impl<V: FromAnyValue + Send + Sync> WriteMutations for DioxusNativeCoreMutationW
 
     fn remove_node(&mut self, id: ElementId) {
         let node_id = self.state.element_to_node_id(id);
-        self.rdom.get_mut(node_id).unwrap().remove();
+        // The nodes of an exiting presence may already be removed with a parent
+        if let Some(mut node) = self.rdom.get_mut(node_id) {
+            node.remove();
+        }
     }
 
     fn push_root(&mut self, id: ElementId) {
         let node_id = self.state.element_to_node_id(id);
         self.state.stack.push(node_id);
     }
+
+    /// The root of a subtree is an absolutely positioned `div` with the target as its id at the end of the root node. Portals with the same target share the root.
+    fn create_subtree_root(&mut self, subtree: usize, target: &str) {
+        let root_id = match self.state.subtree_roots.get(target) {
+            Some(root_id) => *root_id,
+            None => {
+                let attributes = [
+                    (("id", None), target),
+                    (("position", Some("style")), "absolute"),
+                ]
+                .into_iter()
+                .map(|((name, namespace), value)| {
+                    (
+                        OwnedAttributeDiscription {
+                            name: name.to_string(),
+                            namespace: namespace.map(|s: &str| s.to_string()),
+                        },
+                        OwnedAttributeValue::Text(value.to_string()),
+                    )
+                })
+                .collect();
+                let node = NodeType::Element(ElementNode {
+                    tag: "div".to_string(),
+                    namespace: None,
+                    attributes,
+                    listeners: FxHashSet::default(),
+                });
+                let root_id = self.rdom.create_node(node).id();
+                let parent = self.rdom.root_id();
+                self.rdom.get_mut(parent).unwrap().add_child(root_id);
+                self.state.subtree_roots.insert(target.to_string(), root_id);
+                root_id
+            }
+        };
+        self.state.subtrees.insert(subtree, root_id);
+    }
+
+    fn append_to_subtree(&mut self, subtree: usize, m: usize) {
+        let children = self.state.stack.split_off(self.state.stack.len() - m);
+        let parent = self.state.subtrees[&subtree];
+        for child in children {
+            self.rdom.get_mut(parent).unwrap().add_child(child);
+        }
+    }
+
+    /// Native core doesn't run animations. Renderers built on it wait for the exit and call [`ScopeId::finish_exit`] themselves
+    fn wait_for_exit(&mut self, _scope: ScopeId, _id: ElementId, _duration: Duration) {}
 }
 
 fn create_template_node<V: FromAnyValue + Send + Sync>(
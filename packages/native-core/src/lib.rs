@@ -7,6 +7,9 @@ use std::any::Any;
 use std::hash::BuildHasherDefault;
 
 use node_ref::NodeMask;
+
+// the state derive macros refer to this crate by name
+extern crate self as dioxus_native_core;
 use rustc_hash::FxHasher;
 
 pub mod custom_element;
@@ -18,7 +21,11 @@ pub mod node;
 pub mod node_ref;
 pub mod node_watcher;
 mod passes;
+pub mod pseudo_class;
+pub mod query;
 pub mod real_dom;
+pub mod stable_id;
+pub mod style;
 pub mod tree;
 pub mod utils;
 
@@ -39,9 +46,12 @@ pub mod prelude {
     pub use crate::dioxus::*;
     pub use crate::node::{ElementNode, FromAnyValue, NodeType, OwnedAttributeView, TextNode};
     pub use crate::node_ref::{AttributeMaskBuilder, NodeMaskBuilder, NodeView};
-    pub use crate::passes::{run_pass, PassDirection, RunPassView, TypeErasedState};
+    pub use crate::passes::{run_pass, PassDirection, RunPassView, SharedViewPtr, TypeErasedState};
     pub use crate::passes::{Dependancy, DependancyView, Dependants, State};
+    pub use crate::pseudo_class::{Interaction, PseudoClasses};
+    pub use crate::query::Selector;
     pub use crate::real_dom::{NodeImmutable, NodeMut, NodeRef, RealDom};
+    pub use crate::style::{ComputedStyle, ResolvedStyle, Stylesheet};
     pub use crate::NodeId;
     pub use crate::SendAnyMap;
 }
@@ -132,6 +132,24 @@ impl AttributeMask {
         }
     }
 
+    /// Get the attributes that are visible in both masks
+    pub fn intersection(&self, other: &Self) -> Self {
+        match (self, other) {
+            (AttributeMask::All, other) | (other, AttributeMask::All) => other.clone(),
+            (AttributeMask::Some(s), AttributeMask::Some(o)) => {
+                AttributeMask::Some(s.intersection(o).cloned().collect())
+            }
+        }
+    }
+
+    /// Check if no attributes are visible in the mask
+    pub fn is_empty(&self) -> bool {
+        match self {
+            AttributeMask::All => false,
+            AttributeMask::Some(attrs) => attrs.is_empty(),
+        }
+    }
+
     /// Check if two attribute masks overlap
     fn overlaps(&self, other: &Self) -> bool {
         match (self, other) {
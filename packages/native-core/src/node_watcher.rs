@@ -1,6 +1,12 @@
 //! Helpers for watching for changes in the DOM tree.
 
-use crate::{node::FromAnyValue, node_ref::AttributeMask, prelude::*};
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use crate::{
+    node::FromAnyValue,
+    node_ref::{AttributeMask, NodeMask},
+    prelude::*,
+};
 
 /// A trait for watching for changes in the DOM tree.
 pub trait NodeWatcher<V: FromAnyValue + Send + Sync> {
@@ -13,7 +19,177 @@ pub trait NodeWatcher<V: FromAnyValue + Send + Sync> {
 }
 
 /// A trait for watching for changes to attributes of an element.
+///
+/// To only react to specific attributes on specific nodes after the state of the dom is updated, use [`RealDom::subscribe_node_attributes`] instead.
 pub trait AttributeWatcher<V: FromAnyValue + Send + Sync> {
     /// Called before update_state is called on the RealDom
     fn on_attributes_changed(&self, _node: NodeMut<V>, _attributes: &AttributeMask) {}
 }
+
+/// The id of a subscription created with [`RealDom::subscribe_attributes`] or [`RealDom::subscribe_node_attributes`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AttributeSubscriptionId(usize);
+
+type AttributeCallback<V> = Box<dyn FnMut(NodeRef<V>, &AttributeMask) + Send + Sync>;
+
+struct AttributeSubscription<V: FromAnyValue + Send + Sync> {
+    /// The node the subscription watches or None if it watches every node
+    node: Option<NodeId>,
+    attributes: AttributeMask,
+    callback: AttributeCallback<V>,
+}
+
+/// All of the attribute subscriptions in a [`RealDom`] indexed by the node they watch
+pub(crate) struct AttributeSubscriptions<V: FromAnyValue + Send + Sync> {
+    next_id: usize,
+    subscriptions: FxHashMap<AttributeSubscriptionId, AttributeSubscription<V>>,
+    any_node: FxHashSet<AttributeSubscriptionId>,
+    by_node: FxHashMap<NodeId, FxHashSet<AttributeSubscriptionId>>,
+}
+
+impl<V: FromAnyValue + Send + Sync> Default for AttributeSubscriptions<V> {
+    fn default() -> Self {
+        Self {
+            next_id: 0,
+            subscriptions: FxHashMap::default(),
+            any_node: FxHashSet::default(),
+            by_node: FxHashMap::default(),
+        }
+    }
+}
+
+impl<V: FromAnyValue + Send + Sync> AttributeSubscriptions<V> {
+    pub(crate) fn subscribe(
+        &mut self,
+        node: Option<NodeId>,
+        attributes: AttributeMask,
+        callback: AttributeCallback<V>,
+    ) -> AttributeSubscriptionId {
+        let id = AttributeSubscriptionId(self.next_id);
+        self.next_id += 1;
+        match node {
+            Some(node) => {
+                self.by_node.entry(node).or_default().insert(id);
+            }
+            None => {
+                self.any_node.insert(id);
+            }
+        }
+        self.subscriptions.insert(
+            id,
+            AttributeSubscription {
+                node,
+                attributes,
+                callback,
+            },
+        );
+        id
+    }
+
+    pub(crate) fn unsubscribe(&mut self, id: AttributeSubscriptionId) {
+        if let Some(subscription) = self.subscriptions.remove(&id) {
+            match subscription.node {
+                Some(node) => {
+                    if let Some(subscriptions) = self.by_node.get_mut(&node) {
+                        subscriptions.remove(&id);
+                        if subscriptions.is_empty() {
+                            self.by_node.remove(&node);
+                        }
+                    }
+                }
+                None => {
+                    self.any_node.remove(&id);
+                }
+            }
+        }
+    }
+
+    /// Drop every subscription to a node that was removed from the dom
+    pub(crate) fn remove_node(&mut self, node: NodeId) {
+        if let Some(subscriptions) = self.by_node.remove(&node) {
+            for id in subscriptions {
+                self.subscriptions.remove(&id);
+            }
+        }
+    }
+
+    /// Call the subscriptions that watch any of the updated attributes
+    pub(crate) fn notify(
+        &mut self,
+        rdom: &RealDom<V>,
+        nodes_updated: &FxHashMap<NodeId, NodeMask>,
+    ) {
+        if self.subscriptions.is_empty() {
+            return;
+        }
+
+        for (id, mask) in nodes_updated {
+            let Some(node) = rdom.get(*id) else {
+                continue;
+            };
+            let node_subscriptions = self.by_node.get(id).into_iter().flatten();
+            for subscription_id in self.any_node.iter().chain(node_subscriptions) {
+                let subscription = self.subscriptions.get_mut(subscription_id).unwrap();
+                let changed = subscription.attributes.intersection(mask.attributes());
+                if !changed.is_empty() {
+                    (subscription.callback)(node, &changed);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn attribute_subscriptions() {
+    use crate::real_dom::NodeTypeMut;
+    use std::sync::{Arc, Mutex};
+
+    fn set_attribute(rdom: &mut RealDom, id: NodeId, name: &str, value: &str) {
+        let mut node = rdom.get_mut(id).unwrap();
+        if let NodeTypeMut::Element(mut element) = node.node_type_mut() {
+            element.set_attribute(name.to_string(), value.to_string());
+        };
+    }
+
+    let mut rdom: RealDom = RealDom::new([]);
+    let root = rdom.root_id();
+    let first = rdom.create_node(ElementNode::new("div", None)).id();
+    let second = rdom.create_node(ElementNode::new("div", None)).id();
+    rdom.get_mut(root).unwrap().add_child(first);
+    rdom.get_mut(root).unwrap().add_child(second);
+    rdom.update_state(SendAnyMap::new());
+
+    let any_node = Arc::new(Mutex::new(Vec::new()));
+    let first_node = Arc::new(Mutex::new(Vec::new()));
+    {
+        let any_node = any_node.clone();
+        rdom.subscribe_attributes(AttributeMask::single("width"), move |node, changed| {
+            assert!(changed.contains("width") && !changed.contains("height"));
+            any_node.lock().unwrap().push(node.id());
+        });
+    }
+    let subscription = {
+        let first_node = first_node.clone();
+        rdom.subscribe_node_attributes(first, AttributeMask::All, move |node, changed| {
+            assert_eq!(node.id(), first);
+            assert!(changed.contains("height"));
+            first_node.lock().unwrap().push(node.id());
+        })
+    };
+
+    set_attribute(&mut rdom, second, "width", "10");
+    set_attribute(&mut rdom, second, "height", "10");
+    set_attribute(&mut rdom, first, "height", "10");
+    rdom.update_state(SendAnyMap::new());
+    assert_eq!(*any_node.lock().unwrap(), [second]);
+    assert_eq!(*first_node.lock().unwrap(), [first]);
+
+    // nothing changed, so nothing is notified
+    rdom.update_state(SendAnyMap::new());
+    assert_eq!(any_node.lock().unwrap().len(), 1);
+
+    rdom.unsubscribe_attributes(subscription);
+    set_attribute(&mut rdom, first, "height", "20");
+    rdom.update_state(SendAnyMap::new());
+    assert_eq!(first_node.lock().unwrap().len(), 1);
+}
@@ -23,17 +23,6 @@ impl DirtyNodes {
     pub fn add_node(&mut self, node_id: NodeId) {
         self.nodes_dirty.insert(node_id);
     }
-
-    pub fn is_empty(&self) -> bool {
-        self.nodes_dirty.is_empty()
-    }
-
-    pub fn pop(&mut self) -> Option<NodeId> {
-        self.nodes_dirty.iter().next().copied().map(|id| {
-            self.nodes_dirty.remove(&id);
-            id
-        })
-    }
 }
 
 /// Tracks the dirty nodes sorted by height for each pass. We resolve passes based on the height of the node in order to avoid resolving any node twice in a pass.
@@ -66,30 +55,16 @@ impl DirtyNodeStates {
         }
     }
 
-    fn pop_front(&self, pass_id: TypeId) -> Option<(u16, NodeId)> {
-        let mut values = self.dirty.get(&pass_id)?.write();
-        let mut value = values.first_entry()?;
-        let height = *value.key();
-        let ids = value.get_mut();
-        let id = ids.pop()?;
-        if ids.is_empty() {
-            value.remove_entry();
-        }
-
-        Some((height, id))
+    /// Remove all the dirty nodes with the lowest height
+    fn pop_front_level(&self, pass_id: TypeId) -> Option<(u16, Vec<NodeId>)> {
+        let (height, nodes) = self.dirty.get(&pass_id)?.write().pop_first()?;
+        Some((height, nodes.nodes_dirty.into_iter().collect()))
     }
 
-    fn pop_back(&self, pass_id: TypeId) -> Option<(u16, NodeId)> {
-        let mut values = self.dirty.get(&pass_id)?.write();
-        let mut value = values.last_entry()?;
-        let height = *value.key();
-        let ids = value.get_mut();
-        let id = ids.pop()?;
-        if ids.is_empty() {
-            value.remove_entry();
-        }
-
-        Some((height, id))
+    /// Remove all the dirty nodes with the highest height
+    fn pop_back_level(&self, pass_id: TypeId) -> Option<(u16, Vec<NodeId>)> {
+        let (height, nodes) = self.dirty.get(&pass_id)?.write().pop_last()?;
+        Some((height, nodes.nodes_dirty.into_iter().collect()))
     }
 }
 
@@ -186,15 +161,29 @@ pub struct RunPassView<'a, V: FromAnyValue + Send + Sync = ()> {
     any_map: UniqueView<'a, SendAnyMapWrapper>,
 }
 
+/// The number of dirty nodes at the same height required before a level of a pass is updated in parallel
+#[cfg(feature = "parallel")]
+const PARALLEL_LEVEL_THRESHOLD: usize = 64;
+
 // This is used by the macro
 /// Updates the given pass, marking any nodes that were changed
+///
+/// Dirty nodes are resolved one height at a time. Nodes at the same height never depend on each other in a pass, so with the `parallel` feature enabled, large levels are created and updated in parallel. Passes that don't depend on each other are run in parallel by the workload.
+/// - `create_state` creates the state for a node if it does not exist yet. It only reads states, so it can run in parallel.
+/// - `insert_state` adds a state to the node. States are inserted sequentially because adding a state can move the states of other nodes.
+/// - `take_state` removes the state of a node that already has the state.
+/// - `update_state` updates a state that was taken out of the node and returns true if the state changed. It only reads the states left in the storage, so it can run in parallel.
 #[doc(hidden)]
-pub fn run_pass<V: FromAnyValue + Send + Sync>(
+#[allow(clippy::too_many_arguments)]
+pub fn run_pass<V: FromAnyValue + Send + Sync, S: Send>(
     type_id: TypeId,
     dependants: Arc<Dependants>,
     pass_direction: PassDirection,
     view: RunPassView<V>,
-    mut update_node: impl FnMut(NodeId, &SendAnyMap) -> bool,
+    create_state: impl Fn(NodeId, &SendAnyMap) -> Option<S> + Sync,
+    mut insert_state: impl FnMut(NodeId, S),
+    mut take_state: impl FnMut(NodeId) -> S,
+    update_state: impl Fn(NodeId, &mut S, &SendAnyMap) -> bool + Sync,
 ) {
     let RunPassView {
         tree,
@@ -204,34 +193,103 @@ pub fn run_pass<V: FromAnyValue + Send + Sync>(
         ..
     } = view;
     let ctx = ctx.as_ref();
-    match pass_direction {
-        PassDirection::ParentToChild => {
-            while let Some((height, id)) = dirty.pop_front(type_id) {
-                if (update_node)(id, ctx) {
-                    nodes_updated.insert(id);
-                    dependants.mark_dirty(&dirty, id, &tree, height);
-                }
-            }
-        }
-        PassDirection::ChildToParent => {
-            while let Some((height, id)) = dirty.pop_back(type_id) {
-                if (update_node)(id, ctx) {
-                    nodes_updated.insert(id);
-                    dependants.mark_dirty(&dirty, id, &tree, height);
+
+    let pop_level = || match pass_direction {
+        PassDirection::ParentToChild => dirty.pop_front_level(type_id),
+        PassDirection::ChildToParent | PassDirection::AnyOrder => dirty.pop_back_level(type_id),
+    };
+    let mark_updated = |id: NodeId, height: u16| {
+        nodes_updated.insert(id);
+        dependants.mark_dirty(&dirty, id, &tree, height);
+    };
+
+    while let Some((height, ids)) = pop_level() {
+        let create = |id: &NodeId| (*id, create_state(*id, ctx));
+        #[cfg(feature = "parallel")]
+        let created: Vec<_> = if ids.len() >= PARALLEL_LEVEL_THRESHOLD {
+            use rayon::prelude::*;
+            ids.par_iter().map(create).collect()
+        } else {
+            ids.iter().map(create).collect()
+        };
+        #[cfg(not(feature = "parallel"))]
+        let created: Vec<_> = ids.iter().map(create).collect();
+
+        let mut existing = Vec::with_capacity(created.len());
+        for (id, state) in created {
+            match state {
+                Some(state) => {
+                    insert_state(id, state);
+                    mark_updated(id, height);
                 }
+                None => existing.push(id),
             }
         }
-        PassDirection::AnyOrder => {
-            while let Some((height, id)) = dirty.pop_back(type_id) {
-                if (update_node)(id, ctx) {
-                    nodes_updated.insert(id);
-                    dependants.mark_dirty(&dirty, id, &tree, height);
-                }
+
+        // The states of the level are moved out of the storage while they are updated. Updates only borrow the storage immutably to read the states of other levels, and each state they write is owned by one of them
+        let mut states: Vec<_> = existing
+            .into_iter()
+            .map(|id| (id, take_state(id)))
+            .collect();
+        let update = |(id, state): &mut (NodeId, S)| update_state(*id, state, ctx);
+
+        #[cfg(feature = "parallel")]
+        let changed: Vec<_> = if states.len() >= PARALLEL_LEVEL_THRESHOLD {
+            use rayon::prelude::*;
+            states.par_iter_mut().map(update).collect()
+        } else {
+            states.iter_mut().map(update).collect()
+        };
+        #[cfg(not(feature = "parallel"))]
+        let changed: Vec<_> = states.iter_mut().map(update).collect();
+
+        for ((id, state), changed) in states.into_iter().zip(changed) {
+            insert_state(id, state);
+            if changed {
+                mark_updated(id, height);
             }
         }
     }
 }
 
+// This is used by the macro
+/// A pointer to the storage of a [`State`] that is shared between the closures passed to [`run_pass`]
+#[doc(hidden)]
+pub struct SharedViewPtr<T>(*mut T);
+
+impl<T> SharedViewPtr<T> {
+    #[doc(hidden)]
+    pub fn new(view: &mut T) -> Self {
+        Self(view)
+    }
+
+    /// # Safety
+    /// The view must not be borrowed while the mutable reference is alive. [`run_pass`] only calls the closures that borrow the view mutably between the closures that read it
+    #[doc(hidden)]
+    #[allow(clippy::mut_from_ref)]
+    pub unsafe fn get_mut(&self) -> &mut T {
+        &mut *self.0
+    }
+
+    /// # Safety
+    /// The view must not be borrowed mutably while the reference is alive
+    #[doc(hidden)]
+    pub unsafe fn get(&self) -> &T {
+        &*self.0
+    }
+}
+
+impl<T> Clone for SharedViewPtr<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for SharedViewPtr<T> {}
+
+unsafe impl<T: Sync> Send for SharedViewPtr<T> {}
+unsafe impl<T: Sync> Sync for SharedViewPtr<T> {}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub(crate) struct Dependant {
     pub(crate) type_id: TypeId,
@@ -377,3 +435,159 @@ impl_dependancy!(A, B, C, D, E, F, G);
 impl_dependancy!(A, B, C, D, E, F, G, H);
 impl_dependancy!(A, B, C, D, E, F, G, H, I);
 impl_dependancy!(A, B, C, D, E, F, G, H, I, J);
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::real_dom::NodeTypeMut;
+    use dioxus_native_core_macro::partial_derive_state;
+    use shipyard::Component;
+
+    fn width(view: &NodeView) -> usize {
+        view.attributes()
+            .into_iter()
+            .flatten()
+            .find_map(|attr| attr.value.as_text()?.parse().ok())
+            .unwrap_or_default()
+    }
+
+    /// The sum of the widths of a node and its ancestors
+    #[derive(Default, Debug, Clone, Copy, PartialEq, Component)]
+    struct Offset(usize);
+
+    #[partial_derive_state]
+    impl State for Offset {
+        type ParentDependencies = (Self,);
+        type ChildDependencies = ();
+        type NodeDependencies = ();
+
+        const NODE_MASK: NodeMaskBuilder<'static> =
+            NodeMaskBuilder::new().with_attrs(AttributeMaskBuilder::Some(&["width"]));
+
+        fn update<'a>(
+            &mut self,
+            view: NodeView,
+            _: <Self::NodeDependencies as Dependancy>::ElementBorrowed<'a>,
+            parent: Option<<Self::ParentDependencies as Dependancy>::ElementBorrowed<'a>>,
+            _: Vec<<Self::ChildDependencies as Dependancy>::ElementBorrowed<'a>>,
+            _: &SendAnyMap,
+        ) -> bool {
+            let new = Offset(width(&view) + parent.map(|(parent,)| parent.0).unwrap_or_default());
+            let changed = *self != new;
+            *self = new;
+            changed
+        }
+    }
+
+    /// The sum of the widths of a node and its descendants
+    #[derive(Default, Debug, Clone, Copy, PartialEq, Component)]
+    struct TotalWidth(usize);
+
+    #[partial_derive_state]
+    impl State for TotalWidth {
+        type ParentDependencies = ();
+        type ChildDependencies = (Self,);
+        type NodeDependencies = ();
+
+        const NODE_MASK: NodeMaskBuilder<'static> =
+            NodeMaskBuilder::new().with_attrs(AttributeMaskBuilder::Some(&["width"]));
+
+        fn update<'a>(
+            &mut self,
+            view: NodeView,
+            _: <Self::NodeDependencies as Dependancy>::ElementBorrowed<'a>,
+            _: Option<<Self::ParentDependencies as Dependancy>::ElementBorrowed<'a>>,
+            children: Vec<<Self::ChildDependencies as Dependancy>::ElementBorrowed<'a>>,
+            _: &SendAnyMap,
+        ) -> bool {
+            let new =
+                TotalWidth(width(&view) + children.iter().map(|(child,)| child.0).sum::<usize>());
+            let changed = *self != new;
+            *self = new;
+            changed
+        }
+    }
+
+    // The levels are wider than the parallel threshold, so they are created and updated in parallel with the `parallel` feature enabled
+    const WIDTH: usize = 100;
+
+    /// Create a tree with `WIDTH` nodes under the root and two nodes under each of those
+    fn wide_tree() -> (RealDom, Vec<NodeId>) {
+        let mut rdom: RealDom =
+            RealDom::new([Offset::to_type_erased(), TotalWidth::to_type_erased()]);
+        let root = rdom.root_id();
+        let mut ids = Vec::new();
+        for _ in 0..WIDTH {
+            let parent = rdom
+                .create_node(NodeType::Element(ElementNode::new("div", None)))
+                .id();
+            rdom.get_mut(root).unwrap().add_child(parent);
+            ids.push(parent);
+            for _ in 0..2 {
+                let child = rdom
+                    .create_node(NodeType::Element(ElementNode::new("div", None)))
+                    .id();
+                rdom.get_mut(parent).unwrap().add_child(child);
+                ids.push(child);
+            }
+        }
+        (rdom, ids)
+    }
+
+    fn set_width(rdom: &mut RealDom, id: NodeId, width: usize) {
+        if let NodeTypeMut::Element(mut element) = rdom.get_mut(id).unwrap().node_type_mut() {
+            element.set_attribute("width".to_string(), width.to_string());
+        }
+    }
+
+    fn offset(rdom: &RealDom, id: NodeId) -> usize {
+        rdom.get(id).unwrap().get::<Offset>().unwrap().0
+    }
+
+    fn total_width(rdom: &RealDom, id: NodeId) -> usize {
+        rdom.get(id).unwrap().get::<TotalWidth>().unwrap().0
+    }
+
+    #[test]
+    fn wide_levels_are_created() {
+        let (mut rdom, ids) = wide_tree();
+        for &id in &ids {
+            set_width(&mut rdom, id, 1);
+        }
+        let (updated, _) = rdom.update_state(SendAnyMap::new());
+        assert_eq!(updated.len(), ids.len() + 1);
+
+        let root = rdom.root_id();
+        assert_eq!(total_width(&rdom, root), WIDTH * 3);
+        for parent in ids.chunks(3) {
+            assert_eq!(offset(&rdom, parent[0]), 1);
+            assert_eq!(total_width(&rdom, parent[0]), 3);
+            assert_eq!(offset(&rdom, parent[1]), 2);
+            assert_eq!(total_width(&rdom, parent[2]), 1);
+        }
+    }
+
+    #[test]
+    fn wide_levels_are_updated() {
+        let (mut rdom, ids) = wide_tree();
+        rdom.update_state(SendAnyMap::new());
+
+        // Every node already has its states, so only the update path runs
+        for (i, &id) in ids.iter().enumerate() {
+            set_width(&mut rdom, id, i);
+        }
+        let (updated, _) = rdom.update_state(SendAnyMap::new());
+        assert_eq!(updated.len(), ids.len() + 1);
+
+        let root = rdom.root_id();
+        assert_eq!(total_width(&rdom, root), (0..ids.len()).sum::<usize>());
+        for (i, parent) in ids.chunks(3).enumerate() {
+            let first = i * 3;
+            assert_eq!(offset(&rdom, parent[0]), first);
+            assert_eq!(offset(&rdom, parent[1]), first + first + 1);
+            assert_eq!(offset(&rdom, parent[2]), first + first + 2);
+            assert_eq!(total_width(&rdom, parent[0]), first * 3 + 3);
+            assert_eq!(total_width(&rdom, parent[1]), first + 1);
+        }
+    }
+}
@@ -0,0 +1,240 @@
+//! Track the interaction pseudo-classes (`:hover`, `:active`, `:focus` and `:focus-within`) of every node
+//!
+//! Renderers feed their input events into the [`RealDom`] with [`RealDom::set_interaction`] and register the [`PseudoClasses`] state. The state resolves which nodes match each pseudo-class the next time the state of the [`RealDom`] is updated, so other states can depend on it to style interactive nodes. Selector queries resolve these pseudo-classes automatically.
+//!
+//! ```rust
+//! use dioxus_native_core::prelude::*;
+//!
+//! let mut rdom: RealDom = RealDom::new([PseudoClasses::to_type_erased()]);
+//! let root_id = rdom.root_id();
+//! let list = rdom.create_node(NodeType::Element(ElementNode::new("ul", None))).id();
+//! let item = rdom.create_node(NodeType::Element(ElementNode::new("li", None))).id();
+//! rdom.get_mut(root_id).unwrap().add_child(list);
+//! rdom.get_mut(list).unwrap().add_child(item);
+//!
+//! // the mouse moved over the item
+//! rdom.set_interaction(Interaction::Hover, Some(item));
+//! rdom.update_state(SendAnyMap::new());
+//!
+//! // ancestors of the hovered node are hovered as well
+//! assert!(rdom.get(list).unwrap().get::<PseudoClasses>().unwrap().hover());
+//! assert_eq!(rdom.query("ul:hover > li:hover").unwrap(), vec![item]);
+//! ```
+
+use shipyard::{Component, Unique};
+
+use crate::{
+    node::FromAnyValue,
+    node_ref::{NodeMaskBuilder, NodeView},
+    prelude::*,
+};
+
+/// An interaction a renderer can set on a node with [`RealDom::set_interaction`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Interaction {
+    /// The pointer is over the node
+    Hover,
+    /// The node is being activated, for example by pressing the mouse button down on it
+    Active,
+    /// The node has focus
+    Focus,
+}
+
+impl Interaction {
+    const ALL: [Interaction; 3] = [Interaction::Hover, Interaction::Active, Interaction::Focus];
+
+    fn index(self) -> usize {
+        match self {
+            Interaction::Hover => 0,
+            Interaction::Active => 1,
+            Interaction::Focus => 2,
+        }
+    }
+}
+
+/// The node each interaction currently targets
+#[derive(Unique, Default)]
+struct InteractionTargets([Option<NodeId>; 3]);
+
+/// The interaction pseudo-classes of a node. Register this state with [`PseudoClasses::to_type_erased`] to track them.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Component)]
+pub struct PseudoClasses {
+    /// The interactions that target this node directly
+    targeted: [bool; 3],
+    hover: bool,
+    active: bool,
+    focus_within: bool,
+}
+
+impl PseudoClasses {
+    /// If the node or any of its descendants is hovered (`:hover`)
+    pub fn hover(&self) -> bool {
+        self.hover
+    }
+
+    /// If the node or any of its descendants is active (`:active`)
+    pub fn active(&self) -> bool {
+        self.active
+    }
+
+    /// If the node has focus (`:focus`)
+    pub fn focus(&self) -> bool {
+        self.targeted[Interaction::Focus.index()]
+    }
+
+    /// If the node or any of its descendants has focus (`:focus-within`)
+    pub fn focus_within(&self) -> bool {
+        self.focus_within
+    }
+
+    /// Check if the node matches a pseudo-class by name (without the `:`). Returns None if the pseudo-class is not tracked by this state.
+    pub fn matches(&self, pseudo_class: &str) -> Option<bool> {
+        match pseudo_class {
+            "hover" => Some(self.hover()),
+            "active" => Some(self.active()),
+            "focus" => Some(self.focus()),
+            "focus-within" => Some(self.focus_within()),
+            _ => None,
+        }
+    }
+}
+
+#[dioxus_native_core_macro::partial_derive_state]
+impl State for PseudoClasses {
+    type ParentDependencies = ();
+    type ChildDependencies = (Self,);
+    type NodeDependencies = ();
+
+    const NODE_MASK: NodeMaskBuilder<'static> = NodeMaskBuilder::new();
+
+    fn update<'a>(
+        &mut self,
+        _: NodeView,
+        _: <Self::NodeDependencies as Dependancy>::ElementBorrowed<'a>,
+        _: Option<<Self::ParentDependencies as Dependancy>::ElementBorrowed<'a>>,
+        children: Vec<<Self::ChildDependencies as Dependancy>::ElementBorrowed<'a>>,
+        _: &SendAnyMap,
+    ) -> bool {
+        let new = PseudoClasses {
+            targeted: self.targeted,
+            hover: self.targeted[Interaction::Hover.index()]
+                || children.iter().any(|(child,)| child.hover),
+            active: self.targeted[Interaction::Active.index()]
+                || children.iter().any(|(child,)| child.active),
+            focus_within: self.targeted[Interaction::Focus.index()]
+                || children.iter().any(|(child,)| child.focus_within),
+        };
+        let changed = *self != new;
+        *self = new;
+        changed
+    }
+}
+
+impl<V: FromAnyValue + Send + Sync> RealDom<V> {
+    /// Set the node an interaction targets, or clear it with `None`. The [`PseudoClasses`] of the node and its ancestors are updated the next time [`RealDom::update_state`] is called.
+    pub fn set_interaction(&mut self, interaction: Interaction, node: Option<NodeId>) {
+        if self
+            .world
+            .borrow::<shipyard::UniqueView<InteractionTargets>>()
+            .is_err()
+        {
+            self.world.add_unique(InteractionTargets::default());
+        }
+        let old = {
+            let mut targets = self
+                .world
+                .borrow::<shipyard::UniqueViewMut<InteractionTargets>>()
+                .unwrap();
+            std::mem::replace(&mut targets.0[interaction.index()], node)
+        };
+        if old == node {
+            return;
+        }
+        if let Some(old) = old {
+            self.set_targeted(old, interaction, false);
+        }
+        if let Some(node) = node {
+            self.set_targeted(node, interaction, true);
+        }
+    }
+
+    /// Get the node an interaction currently targets
+    pub fn interaction_target(&self, interaction: Interaction) -> Option<NodeId> {
+        self.world
+            .borrow::<shipyard::UniqueView<InteractionTargets>>()
+            .ok()
+            .and_then(|targets| targets.0[interaction.index()])
+    }
+
+    /// Get every interaction that currently targets a node
+    pub fn interactions(&self, node: NodeId) -> impl Iterator<Item = Interaction> + '_ {
+        Interaction::ALL
+            .into_iter()
+            .filter(move |interaction| self.interaction_target(*interaction) == Some(node))
+    }
+
+    fn set_targeted(&mut self, node: NodeId, interaction: Interaction, targeted: bool) {
+        let Some(mut node) = self.get_mut(node) else {
+            return;
+        };
+        if let Some(mut state) = node.get_mut::<PseudoClasses>() {
+            state.targeted[interaction.index()] = targeted;
+            return;
+        }
+        // The state has not been created yet, create it with the interaction
+        let mut state = PseudoClasses::default();
+        state.targeted[interaction.index()] = targeted;
+        node.insert(state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn div(rdom: &mut RealDom, parent: NodeId) -> NodeId {
+        let id = rdom
+            .create_node(NodeType::Element(ElementNode::new("div", None)))
+            .id();
+        rdom.get_mut(parent).unwrap().add_child(id);
+        id
+    }
+
+    fn state(rdom: &RealDom, id: NodeId) -> PseudoClasses {
+        *rdom.get(id).unwrap().get::<PseudoClasses>().unwrap()
+    }
+
+    #[test]
+    fn interactions_propagate_to_ancestors() {
+        let mut rdom: RealDom = RealDom::new([PseudoClasses::to_type_erased()]);
+        let root = rdom.root_id();
+        let parent = div(&mut rdom, root);
+        let first = div(&mut rdom, parent);
+        let second = div(&mut rdom, parent);
+        rdom.update_state(SendAnyMap::new());
+        assert_eq!(state(&rdom, parent), PseudoClasses::default());
+
+        rdom.set_interaction(Interaction::Focus, Some(first));
+        rdom.set_interaction(Interaction::Hover, Some(second));
+        rdom.update_state(SendAnyMap::new());
+        assert!(state(&rdom, first).focus());
+        assert!(!state(&rdom, parent).focus());
+        assert!(state(&rdom, parent).focus_within());
+        assert!(state(&rdom, parent).hover());
+        assert!(!state(&rdom, first).hover());
+        assert_eq!(
+            rdom.interactions(first).collect::<Vec<_>>(),
+            [Interaction::Focus]
+        );
+        assert_eq!(rdom.query(":focus-within > :hover").unwrap(), vec![second]);
+
+        // moving the focus clears it from the old node and its ancestors
+        rdom.set_interaction(Interaction::Focus, Some(root));
+        rdom.set_interaction(Interaction::Hover, None);
+        rdom.update_state(SendAnyMap::new());
+        assert!(!state(&rdom, first).focus());
+        assert!(!state(&rdom, parent).focus_within());
+        assert!(!state(&rdom, parent).hover());
+        assert_eq!(rdom.interaction_target(Interaction::Focus), Some(root));
+    }
+}
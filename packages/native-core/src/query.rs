@@ -0,0 +1,946 @@
+//! Query the [`RealDom`] with CSS selectors
+//!
+//! Selectors only read the parts of a node they need through a [`NodeView`], so [`Selector::mask`] can be used
+//! to check if the results of a query could have changed after an update.
+//!
+//! ```rust
+//! use dioxus_native_core::prelude::*;
+//!
+//! let mut rdom: RealDom = RealDom::new([]);
+//! let root_id = rdom.root_id();
+//! let sidebar = rdom.create_node(NodeType::Element(ElementNode::new("div", None))).id();
+//! rdom.get_mut(root_id).unwrap().add_child(sidebar);
+//!
+//! assert_eq!(rdom.query("div").unwrap(), vec![sidebar]);
+//! assert!(rdom.query("div > button").unwrap().is_empty());
+//! ```
+//!
+//! Supported syntax:
+//! - Type (`div`), universal (`*`), id (`#main`) and class (`.sidebar`) selectors
+//! - Attribute selectors (`[href]`, `[type=text]`, `[class~=a]`, `[lang|=en]`, `[href^=https]`, `[src$=".png"]`, `[title*=dioxus]`)
+//! - Descendant (`a b`), child (`a > b`), next sibling (`a + b`) and subsequent sibling (`a ~ b`) combinators
+//! - Selector lists (`h1, h2`)
+//! - The `:first-child`, `:last-child`, `:only-child`, `:empty` and `:not(..)` pseudo-classes
+//!
+//! - The `:hover`, `:active`, `:focus` and `:focus-within` pseudo-classes if the [`PseudoClasses`] state is registered
+//!
+//! Any other pseudo-class depends on renderer state. Use [`RealDom::query_with`] to resolve them.
+
+use std::{fmt::Display, str::FromStr, sync::Arc};
+
+use crate::{
+    node::{FromAnyValue, NodeType},
+    node_ref::{AttributeMask, NodeMask, NodeView},
+    pseudo_class::PseudoClasses,
+    real_dom::{NodeImmutable, NodeRef, RealDom},
+    tree::TreeRef,
+    NodeId,
+};
+
+/// A parsed list of CSS selectors. A node matches the selector if it matches any selector in the list.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Selector {
+    alternatives: Vec<ComplexSelector>,
+    mask: NodeMask,
+}
+
+impl Selector {
+    /// Parse a selector list like `div.sidebar > button:focus, #main`
+    pub fn parse(selector: &str) -> Result<Self, SelectorParseError> {
+        let mut parser = Parser {
+            src: selector,
+            position: 0,
+        };
+        let selector = parser.parse_selector_list()?;
+        parser.skip_whitespace();
+        match parser.peek() {
+            Some(c) => Err(parser.error(format!("unexpected character `{c}`"))),
+            None => Ok(selector),
+        }
+    }
+
+    /// The parts of a node this selector reads. If an update does not overlap with this mask, the result of the query did not change unless the structure of the tree changed.
+    pub fn mask(&self) -> &NodeMask {
+        &self.mask
+    }
+
+    /// Check if a node matches this selector. Renderer specific pseudo-classes never match.
+    pub fn matches<V: FromAnyValue + Send + Sync>(&self, node: &NodeRef<V>) -> bool {
+        self.matches_with(node, &|_, _| false)
+    }
+
+    /// Check if a node matches this selector, calling `pseudo_class` to resolve pseudo-classes like `:focus` that depend on renderer state.
+    pub fn matches_with<V: FromAnyValue + Send + Sync>(
+        &self,
+        node: &NodeRef<V>,
+        pseudo_class: &dyn Fn(&NodeRef<V>, &str) -> bool,
+    ) -> bool {
+        let cx = MatchContext {
+            mask: &self.mask,
+            rdom: node.real_dom(),
+            pseudo_class,
+        };
+        self.matches_in(node, &cx)
+    }
+
+    /// Get the highest [`Specificity`] of the selectors in the list that match a node, or None if the node does not match. Renderer specific pseudo-classes never match.
+    pub fn match_specificity<V: FromAnyValue + Send + Sync>(
+        &self,
+        node: &NodeRef<V>,
+    ) -> Option<Specificity> {
+        let cx = MatchContext {
+            mask: &self.mask,
+            rdom: node.real_dom(),
+            pseudo_class: &|_, _| false,
+        };
+        self.alternatives
+            .iter()
+            .filter(|selector| selector.matches(node, &cx))
+            .map(ComplexSelector::specificity)
+            .max()
+    }
+
+    fn matches_in<V: FromAnyValue + Send + Sync>(
+        &self,
+        node: &NodeRef<V>,
+        cx: &MatchContext<V>,
+    ) -> bool {
+        self.alternatives
+            .iter()
+            .any(|selector| selector.matches(node, cx))
+    }
+
+    /// Check if the selector only reads the node and its ancestors. Selectors with sibling combinators or pseudo-classes other than `:not(..)` also read the siblings, children or interaction state of a node.
+    pub fn matches_from_ancestors(&self) -> bool {
+        self.alternatives
+            .iter()
+            .all(ComplexSelector::matches_from_ancestors)
+    }
+
+    /// Like [`Selector::match_specificity`], but matches against a snapshot of the element and its ancestors. Selectors that do not [match from ancestors](Selector::matches_from_ancestors) never match.
+    pub(crate) fn snapshot_specificity(&self, element: &ElementSnapshot) -> Option<Specificity> {
+        self.alternatives
+            .iter()
+            .filter(|selector| selector.matches_snapshot_at(selector.compounds.len() - 1, element))
+            .map(ComplexSelector::specificity)
+            .max()
+    }
+
+    fn matches_snapshot(&self, element: &ElementSnapshot) -> bool {
+        self.alternatives
+            .iter()
+            .any(|selector| selector.matches_snapshot_at(selector.compounds.len() - 1, element))
+    }
+
+    /// The highest specificity of any selector in the list
+    fn max_specificity(&self) -> Specificity {
+        self.alternatives
+            .iter()
+            .map(ComplexSelector::specificity)
+            .max()
+            .unwrap_or_default()
+    }
+}
+
+/// The specificity of a selector, compared by the number of id selectors, then the number of class, attribute and pseudo-class selectors and then the number of type selectors. If multiple rules set the same property, the rule with the highest specificity wins.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Specificity {
+    /// The number of id selectors
+    pub ids: u32,
+    /// The number of class, attribute and pseudo-class selectors
+    pub classes: u32,
+    /// The number of type selectors
+    pub types: u32,
+}
+
+impl std::ops::Add for Specificity {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self {
+            ids: self.ids + other.ids,
+            classes: self.classes + other.classes,
+            types: self.types + other.types,
+        }
+    }
+}
+
+impl FromStr for Selector {
+    type Err = SelectorParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+/// An error that occurred while parsing a [`Selector`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SelectorParseError {
+    /// The byte offset in the selector the error occurred at
+    pub position: usize,
+    /// A description of the error
+    pub message: String,
+}
+
+impl Display for SelectorParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "invalid selector: {} at position {}",
+            self.message, self.position
+        )
+    }
+}
+
+impl std::error::Error for SelectorParseError {}
+
+impl<V: FromAnyValue + Send + Sync> RealDom<V> {
+    /// Find all nodes that match a CSS selector in document order. Renderer specific pseudo-classes like `:focus` never match, use [`RealDom::query_with`] to resolve them.
+    pub fn query(&self, selector: &str) -> Result<Vec<NodeId>, SelectorParseError> {
+        let selector = Selector::parse(selector)?;
+        Ok(self.query_with(&selector, |_, _| false))
+    }
+
+    /// Find all nodes that match a parsed [`Selector`] in document order, calling `pseudo_class` with the node and the name of the pseudo-class (without the `:`) to resolve pseudo-classes like `:focus`.
+    pub fn query_with(
+        &self,
+        selector: &Selector,
+        pseudo_class: impl Fn(&NodeRef<V>, &str) -> bool,
+    ) -> Vec<NodeId> {
+        let mut matches = Vec::new();
+        self.traverse_depth_first(|node| {
+            if selector.matches_with(&node, &pseudo_class) {
+                matches.push(node.id());
+            }
+        });
+        matches
+    }
+}
+
+/// The tag and attributes of an element and its ancestors. States can't read other nodes while the [`RealDom`] is updated, so they match selectors against a snapshot they pass down from the parent instead.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct ElementSnapshot {
+    pub tag: String,
+    /// The attributes without a namespace that selectors read
+    pub attributes: Vec<(String, String)>,
+    /// The parent element or None if the parent is the root of the RealDom
+    pub parent: Option<Arc<ElementSnapshot>>,
+}
+
+impl ElementSnapshot {
+    fn attribute(&self, name: &str) -> Option<&str> {
+        self.attributes
+            .iter()
+            .find(|(attribute, _)| attribute == name)
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+struct MatchContext<'a, V: FromAnyValue + Send + Sync> {
+    mask: &'a NodeMask,
+    rdom: &'a RealDom<V>,
+    pseudo_class: &'a dyn Fn(&NodeRef<V>, &str) -> bool,
+}
+
+/// Compound selectors joined by combinators
+#[derive(Debug, Clone, PartialEq)]
+struct ComplexSelector {
+    /// The compound selectors in source order
+    compounds: Vec<CompoundSelector>,
+    /// The combinator between `compounds[i]` and `compounds[i + 1]`
+    combinators: Vec<Combinator>,
+}
+
+impl ComplexSelector {
+    fn specificity(&self) -> Specificity {
+        self.compounds
+            .iter()
+            .map(CompoundSelector::specificity)
+            .fold(Specificity::default(), |a, b| a + b)
+    }
+
+    fn matches<V: FromAnyValue + Send + Sync>(
+        &self,
+        node: &NodeRef<V>,
+        cx: &MatchContext<V>,
+    ) -> bool {
+        self.matches_at(self.compounds.len() - 1, node, cx)
+    }
+
+    /// Match the selector from right to left starting at the compound at `idx`
+    fn matches_at<V: FromAnyValue + Send + Sync>(
+        &self,
+        idx: usize,
+        node: &NodeRef<V>,
+        cx: &MatchContext<V>,
+    ) -> bool {
+        if !self.compounds[idx].matches(node, cx) {
+            return false;
+        }
+        if idx == 0 {
+            return true;
+        }
+
+        match self.combinators[idx - 1] {
+            Combinator::Child => {
+                parent_element(node, cx).is_some_and(|parent| self.matches_at(idx - 1, &parent, cx))
+            }
+            Combinator::Descendant => {
+                let mut current = parent_element(node, cx);
+                while let Some(ancestor) = current {
+                    if self.matches_at(idx - 1, &ancestor, cx) {
+                        return true;
+                    }
+                    current = parent_element(&ancestor, cx);
+                }
+                false
+            }
+            Combinator::NextSibling => sibling_elements(node, cx)
+                .0
+                .first()
+                .is_some_and(|sibling| self.matches_at(idx - 1, sibling, cx)),
+            Combinator::SubsequentSibling => sibling_elements(node, cx)
+                .0
+                .iter()
+                .any(|sibling| self.matches_at(idx - 1, sibling, cx)),
+        }
+    }
+}
+
+impl ComplexSelector {
+    fn matches_from_ancestors(&self) -> bool {
+        self.combinators
+            .iter()
+            .all(|combinator| matches!(combinator, Combinator::Child | Combinator::Descendant))
+            && self
+                .compounds
+                .iter()
+                .all(CompoundSelector::matches_from_ancestors)
+    }
+
+    fn matches_snapshot_at(&self, idx: usize, element: &ElementSnapshot) -> bool {
+        if !self.compounds[idx].matches_snapshot(element) {
+            return false;
+        }
+        if idx == 0 {
+            return true;
+        }
+
+        match self.combinators[idx - 1] {
+            Combinator::Child => element
+                .parent
+                .as_deref()
+                .is_some_and(|parent| self.matches_snapshot_at(idx - 1, parent)),
+            Combinator::Descendant => {
+                let mut current = element.parent.as_deref();
+                while let Some(ancestor) = current {
+                    if self.matches_snapshot_at(idx - 1, ancestor) {
+                        return true;
+                    }
+                    current = ancestor.parent.as_deref();
+                }
+                false
+            }
+            Combinator::NextSibling | Combinator::SubsequentSibling => false,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Combinator {
+    /// `a b`
+    Descendant,
+    /// `a > b`
+    Child,
+    /// `a + b`
+    NextSibling,
+    /// `a ~ b`
+    SubsequentSibling,
+}
+
+/// A sequence of simple selectors that all apply to the same node like `div.sidebar[open]:first-child`
+#[derive(Debug, Clone, PartialEq, Default)]
+struct CompoundSelector {
+    /// The tag of the element or None for the universal selector
+    tag: Option<String>,
+    ids: Vec<String>,
+    classes: Vec<String>,
+    attributes: Vec<AttributeSelector>,
+    pseudo_classes: Vec<PseudoClass>,
+}
+
+impl CompoundSelector {
+    fn matches<V: FromAnyValue + Send + Sync>(
+        &self,
+        node: &NodeRef<V>,
+        cx: &MatchContext<V>,
+    ) -> bool {
+        // The root of the RealDom is not part of the document
+        if node.id() == cx.rdom.root_id() {
+            return false;
+        }
+
+        let node_type = node.node_type();
+        if !matches!(&*node_type, NodeType::Element(_)) {
+            return false;
+        }
+        let view = NodeView::new(node.id(), &node_type, cx.mask);
+        let attribute = |name: &str| {
+            view.attributes().and_then(|mut attributes| {
+                attributes
+                    .find(|attr| attr.attribute.name == name && attr.attribute.namespace.is_none())
+                    .map(|attr| attr.value.to_string())
+            })
+        };
+
+        self.matches_element(view.tag(), &attribute)
+            && self
+                .pseudo_classes
+                .iter()
+                .all(|pseudo_class| pseudo_class.matches(node, cx))
+    }
+
+    fn matches_snapshot(&self, element: &ElementSnapshot) -> bool {
+        self.matches_element(Some(&element.tag), &|name| {
+            element.attribute(name).map(str::to_string)
+        }) && self
+            .pseudo_classes
+            .iter()
+            .all(|pseudo_class| match pseudo_class {
+                PseudoClass::Not(selector) => !selector.matches_snapshot(element),
+                _ => false,
+            })
+    }
+
+    /// Check the type, id, class and attribute selectors
+    fn matches_element(
+        &self,
+        tag: Option<&str>,
+        attribute: &dyn Fn(&str) -> Option<String>,
+    ) -> bool {
+        if let Some(expected) = &self.tag {
+            if tag != Some(expected.as_str()) {
+                return false;
+            }
+        }
+
+        if !self.ids.is_empty() {
+            let id = attribute("id");
+            if !self
+                .ids
+                .iter()
+                .all(|expected| id.as_deref() == Some(expected.as_str()))
+            {
+                return false;
+            }
+        }
+
+        if !self.classes.is_empty() {
+            let class = attribute("class").unwrap_or_default();
+            if !self
+                .classes
+                .iter()
+                .all(|expected| class.split_whitespace().any(|class| class == expected))
+            {
+                return false;
+            }
+        }
+
+        self.attributes
+            .iter()
+            .all(|selector| selector.matches(attribute(&selector.name).as_deref()))
+    }
+
+    fn matches_from_ancestors(&self) -> bool {
+        self.pseudo_classes
+            .iter()
+            .all(|pseudo_class| match pseudo_class {
+                PseudoClass::Not(selector) => selector.matches_from_ancestors(),
+                _ => false,
+            })
+    }
+
+    fn specificity(&self) -> Specificity {
+        let mut specificity = Specificity {
+            ids: self.ids.len() as u32,
+            classes: (self.classes.len() + self.attributes.len()) as u32,
+            types: self.tag.is_some() as u32,
+        };
+        for pseudo_class in &self.pseudo_classes {
+            specificity = specificity
+                + match pseudo_class {
+                    // `:not` takes the specificity of its most specific argument
+                    PseudoClass::Not(selector) => selector.max_specificity(),
+                    _ => Specificity {
+                        classes: 1,
+                        ..Default::default()
+                    },
+                };
+        }
+        specificity
+    }
+
+    fn add_to_mask(&self, mask: &mut NodeMask) {
+        if self.tag.is_some() {
+            mask.set_tag();
+        }
+        if !self.ids.is_empty() {
+            mask.add_attributes(AttributeMask::single("id"));
+        }
+        if !self.classes.is_empty() {
+            mask.add_attributes(AttributeMask::single("class"));
+        }
+        for attribute in &self.attributes {
+            mask.add_attributes(AttributeMask::single(&attribute.name));
+        }
+        for pseudo_class in &self.pseudo_classes {
+            if let PseudoClass::Not(selector) = pseudo_class {
+                *mask = mask.union(&selector.mask);
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct AttributeSelector {
+    name: String,
+    /// The operator and value the attribute is compared with or None if the attribute only needs to exist
+    value: Option<(AttributeOperator, String)>,
+}
+
+impl AttributeSelector {
+    fn matches(&self, value: Option<&str>) -> bool {
+        let Some(value) = value else {
+            return false;
+        };
+        let Some((operator, expected)) = &self.value else {
+            return true;
+        };
+        let expected = expected.as_str();
+        match operator {
+            AttributeOperator::Equals => value == expected,
+            AttributeOperator::Includes => value.split_whitespace().any(|word| word == expected),
+            AttributeOperator::DashMatch => {
+                value == expected
+                    || value
+                        .strip_prefix(expected)
+                        .is_some_and(|rest| rest.starts_with('-'))
+            }
+            AttributeOperator::Prefix => !expected.is_empty() && value.starts_with(expected),
+            AttributeOperator::Suffix => !expected.is_empty() && value.ends_with(expected),
+            AttributeOperator::Substring => !expected.is_empty() && value.contains(expected),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum AttributeOperator {
+    /// `[attr=value]`
+    Equals,
+    /// `[attr~=value]`
+    Includes,
+    /// `[attr|=value]`
+    DashMatch,
+    /// `[attr^=value]`
+    Prefix,
+    /// `[attr$=value]`
+    Suffix,
+    /// `[attr*=value]`
+    Substring,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum PseudoClass {
+    FirstChild,
+    LastChild,
+    OnlyChild,
+    Empty,
+    Not(Box<Selector>),
+    /// A pseudo-class that depends on the state of the renderer like `:focus`
+    Custom(String),
+}
+
+impl PseudoClass {
+    fn matches<V: FromAnyValue + Send + Sync>(
+        &self,
+        node: &NodeRef<V>,
+        cx: &MatchContext<V>,
+    ) -> bool {
+        match self {
+            PseudoClass::FirstChild => sibling_elements(node, cx).0.is_empty(),
+            PseudoClass::LastChild => sibling_elements(node, cx).1.is_empty(),
+            PseudoClass::OnlyChild => {
+                let (before, after) = sibling_elements(node, cx);
+                before.is_empty() && after.is_empty()
+            }
+            PseudoClass::Empty => node
+                .children()
+                .iter()
+                .all(|child| match &*child.node_type() {
+                    NodeType::Text(text) => text.text.is_empty(),
+                    NodeType::Element(_) => false,
+                    NodeType::Placeholder => true,
+                }),
+            PseudoClass::Not(selector) => !selector.matches_in(node, cx),
+            PseudoClass::Custom(name) => node
+                .get::<PseudoClasses>()
+                .and_then(|state| state.matches(name))
+                .unwrap_or_else(|| (cx.pseudo_class)(node, name)),
+        }
+    }
+}
+
+fn is_element<V: FromAnyValue + Send + Sync>(node: &NodeRef<V>) -> bool {
+    matches!(&*node.node_type(), NodeType::Element(_))
+}
+
+fn parent_element<'a, V: FromAnyValue + Send + Sync>(
+    node: &NodeRef<V>,
+    cx: &MatchContext<'a, V>,
+) -> Option<NodeRef<'a, V>> {
+    let parent = node.parent_id()?;
+    (parent != cx.rdom.root_id())
+        .then(|| cx.rdom.get(parent))
+        .flatten()
+}
+
+/// The element siblings before the node (nearest first) and after the node
+fn sibling_elements<'a, V: FromAnyValue + Send + Sync>(
+    node: &NodeRef<V>,
+    cx: &MatchContext<'a, V>,
+) -> (Vec<NodeRef<'a, V>>, Vec<NodeRef<'a, V>>) {
+    let Some(parent) = node.parent_id() else {
+        return Default::default();
+    };
+    let siblings = cx.rdom.tree_ref().children_ids(parent);
+    let Some(index) = siblings.iter().position(|id| *id == node.id()) else {
+        return Default::default();
+    };
+    let element = |id: &NodeId| cx.rdom.get(*id).filter(is_element);
+
+    (
+        siblings[..index].iter().rev().filter_map(element).collect(),
+        siblings[index + 1..].iter().filter_map(element).collect(),
+    )
+}
+
+struct Parser<'a> {
+    src: &'a str,
+    position: usize,
+}
+
+impl Parser<'_> {
+    fn error(&self, message: impl Into<String>) -> SelectorParseError {
+        SelectorParseError {
+            position: self.position,
+            message: message.into(),
+        }
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.src[self.position..].chars().next()
+    }
+
+    fn next(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.position += c.len_utf8();
+        Some(c)
+    }
+
+    fn eat(&mut self, expected: char) -> bool {
+        let matches = self.peek() == Some(expected);
+        if matches {
+            self.position += expected.len_utf8();
+        }
+        matches
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), SelectorParseError> {
+        if self.eat(expected) {
+            Ok(())
+        } else {
+            Err(self.error(format!("expected `{expected}`")))
+        }
+    }
+
+    /// Skip whitespace and return true if any whitespace was skipped
+    fn skip_whitespace(&mut self) -> bool {
+        let start = self.position;
+        while self.peek().is_some_and(char::is_whitespace) {
+            self.next();
+        }
+        self.position != start
+    }
+
+    fn parse_selector_list(&mut self) -> Result<Selector, SelectorParseError> {
+        let mut alternatives = vec![self.parse_complex()?];
+        loop {
+            self.skip_whitespace();
+            if !self.eat(',') {
+                break;
+            }
+            alternatives.push(self.parse_complex()?);
+        }
+
+        let mut mask = NodeMask::default();
+        for compound in alternatives.iter().flat_map(|alt| &alt.compounds) {
+            compound.add_to_mask(&mut mask);
+        }
+
+        Ok(Selector { alternatives, mask })
+    }
+
+    fn parse_complex(&mut self) -> Result<ComplexSelector, SelectorParseError> {
+        self.skip_whitespace();
+        let mut compounds = vec![self.parse_compound()?];
+        let mut combinators = Vec::new();
+
+        loop {
+            let had_whitespace = self.skip_whitespace();
+            let combinator = match self.peek() {
+                Some('>') => Combinator::Child,
+                Some('+') => Combinator::NextSibling,
+                Some('~') => Combinator::SubsequentSibling,
+                Some(',' | ')') | None => break,
+                Some(_) if had_whitespace => Combinator::Descendant,
+                Some(c) => return Err(self.error(format!("unexpected character `{c}`"))),
+            };
+            if combinator != Combinator::Descendant {
+                self.next();
+                self.skip_whitespace();
+            }
+            combinators.push(combinator);
+            compounds.push(self.parse_compound()?);
+        }
+
+        Ok(ComplexSelector {
+            compounds,
+            combinators,
+        })
+    }
+
+    fn parse_compound(&mut self) -> Result<CompoundSelector, SelectorParseError> {
+        let start = self.position;
+        let mut compound = CompoundSelector::default();
+
+        if !self.eat('*') && self.peek().is_some_and(is_ident_char) {
+            compound.tag = Some(self.parse_ident()?);
+        }
+
+        loop {
+            match self.peek() {
+                Some('#') => {
+                    self.next();
+                    compound.ids.push(self.parse_ident()?);
+                }
+                Some('.') => {
+                    self.next();
+                    compound.classes.push(self.parse_ident()?);
+                }
+                Some('[') => {
+                    self.next();
+                    compound.attributes.push(self.parse_attribute()?);
+                }
+                Some(':') => {
+                    self.next();
+                    compound.pseudo_classes.push(self.parse_pseudo_class()?);
+                }
+                _ => break,
+            }
+        }
+
+        if self.position == start {
+            return Err(self.error("expected a selector"));
+        }
+
+        Ok(compound)
+    }
+
+    fn parse_ident(&mut self) -> Result<String, SelectorParseError> {
+        let start = self.position;
+        while self.peek().is_some_and(is_ident_char) {
+            self.next();
+        }
+        if self.position == start {
+            return Err(self.error("expected an identifier"));
+        }
+        Ok(self.src[start..self.position].to_string())
+    }
+
+    fn parse_attribute(&mut self) -> Result<AttributeSelector, SelectorParseError> {
+        self.skip_whitespace();
+        let name = self.parse_ident()?;
+        self.skip_whitespace();
+
+        let operator = match self.next() {
+            Some(']') => return Ok(AttributeSelector { name, value: None }),
+            Some('=') => AttributeOperator::Equals,
+            Some(c @ ('~' | '|' | '^' | '$' | '*')) => {
+                self.expect('=')?;
+                match c {
+                    '~' => AttributeOperator::Includes,
+                    '|' => AttributeOperator::DashMatch,
+                    '^' => AttributeOperator::Prefix,
+                    '$' => AttributeOperator::Suffix,
+                    _ => AttributeOperator::Substring,
+                }
+            }
+            _ => return Err(self.error("expected `]` or an attribute operator")),
+        };
+
+        self.skip_whitespace();
+        let value = match self.peek() {
+            Some(quote @ ('"' | '\'')) => {
+                self.next();
+                let start = self.position;
+                loop {
+                    match self.next() {
+                        Some(c) if c == quote => break,
+                        Some(_) => {}
+                        None => return Err(self.error("unterminated string")),
+                    }
+                }
+                self.src[start..self.position - 1].to_string()
+            }
+            _ => self.parse_ident()?,
+        };
+        self.skip_whitespace();
+        self.expect(']')?;
+
+        Ok(AttributeSelector {
+            name,
+            value: Some((operator, value)),
+        })
+    }
+
+    fn parse_pseudo_class(&mut self) -> Result<PseudoClass, SelectorParseError> {
+        let name = self.parse_ident()?;
+        Ok(match name.as_str() {
+            "first-child" => PseudoClass::FirstChild,
+            "last-child" => PseudoClass::LastChild,
+            "only-child" => PseudoClass::OnlyChild,
+            "empty" => PseudoClass::Empty,
+            "not" => {
+                self.expect('(')?;
+                let selector = self.parse_selector_list()?;
+                self.skip_whitespace();
+                self.expect(')')?;
+                PseudoClass::Not(Box::new(selector))
+            }
+            _ => PseudoClass::Custom(name),
+        })
+    }
+}
+
+fn is_ident_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '-' || c == '_'
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::node::{ElementNode, OwnedAttributeValue, TextNode};
+
+    fn element(rdom: &mut RealDom, parent: NodeId, tag: &str, attrs: &[(&str, &str)]) -> NodeId {
+        let mut element = ElementNode::new(tag, None);
+        for (name, value) in attrs {
+            element.attributes.insert(
+                name.to_string().into(),
+                OwnedAttributeValue::Text(value.to_string()),
+            );
+        }
+        let id = rdom.create_node(NodeType::Element(element)).id();
+        rdom.get_mut(parent).unwrap().add_child(id);
+        id
+    }
+
+    #[test]
+    fn combinators() {
+        let mut rdom: RealDom = RealDom::new([]);
+        let root = rdom.root_id();
+        let sidebar = element(&mut rdom, root, "div", &[("class", "sidebar dark")]);
+        let first = element(&mut rdom, sidebar, "button", &[("id", "first")]);
+        let text = rdom.create_node(TextNode::new("hello".into())).id();
+        rdom.get_mut(sidebar).unwrap().add_child(text);
+        let wrapper = element(&mut rdom, sidebar, "span", &[]);
+        let nested = element(&mut rdom, wrapper, "button", &[("type", "submit")]);
+        let last = element(&mut rdom, sidebar, "button", &[]);
+
+        assert_eq!(rdom.query("button").unwrap(), vec![first, nested, last]);
+        assert_eq!(
+            rdom.query("div.sidebar > button").unwrap(),
+            vec![first, last]
+        );
+        assert_eq!(rdom.query(".dark span button").unwrap(), vec![nested]);
+        assert_eq!(rdom.query("#first + span").unwrap(), vec![wrapper]);
+        assert_eq!(rdom.query("#first ~ button").unwrap(), vec![last]);
+        assert_eq!(
+            rdom.query("button:first-child").unwrap(),
+            vec![first, nested]
+        );
+        assert_eq!(rdom.query("div > :last-child").unwrap(), vec![last]);
+        assert_eq!(rdom.query("button:only-child").unwrap(), vec![nested]);
+        assert_eq!(
+            rdom.query("button:not(#first, [type])").unwrap(),
+            vec![last]
+        );
+        assert_eq!(
+            rdom.query("[type^=sub], div").unwrap(),
+            vec![sidebar, nested]
+        );
+        assert_eq!(rdom.query("[class~='dark']").unwrap(), vec![sidebar]);
+        assert!(rdom.query(":empty").unwrap().contains(&last));
+        assert!(rdom
+            .query("*")
+            .unwrap()
+            .iter()
+            .all(|id| *id != root && *id != text));
+    }
+
+    #[test]
+    fn custom_pseudo_classes() {
+        let mut rdom: RealDom = RealDom::new([]);
+        let root = rdom.root_id();
+        let sidebar = element(&mut rdom, root, "div", &[("class", "sidebar")]);
+        let _first = element(&mut rdom, sidebar, "button", &[]);
+        let focused = element(&mut rdom, sidebar, "button", &[]);
+
+        let selector = Selector::parse("div.sidebar > button:focus").unwrap();
+        assert!(rdom.query_with(&selector, |_, _| false).is_empty());
+        assert_eq!(
+            rdom.query_with(&selector, |node, pseudo_class| {
+                pseudo_class == "focus" && node.id() == focused
+            }),
+            vec![focused]
+        );
+    }
+
+    #[test]
+    fn mask() {
+        let selector = Selector::parse("div.sidebar > [data-open], :not(#main)").unwrap();
+        assert!(selector.mask().tag());
+        for attribute in ["class", "data-open", "id"] {
+            assert!(selector.mask().attributes().contains(attribute));
+        }
+        assert!(!selector.mask().attributes().contains("style"));
+    }
+
+    #[test]
+    fn parse_errors() {
+        for invalid in [
+            "",
+            "div >",
+            "div,",
+            "[href",
+            "div:not(a",
+            "a $ b",
+            ".",
+            "[a^b]",
+        ] {
+            assert!(
+                Selector::parse(invalid).is_err(),
+                "{invalid} should not parse"
+            );
+        }
+    }
+}
@@ -17,8 +17,10 @@ use crate::custom_element::{
 use crate::node::{
     ElementNode, FromAnyValue, NodeType, OwnedAttributeDiscription, OwnedAttributeValue, TextNode,
 };
-use crate::node_ref::{NodeMask, NodeMaskBuilder};
-use crate::node_watcher::{AttributeWatcher, NodeWatcher};
+use crate::node_ref::{AttributeMask, NodeMask, NodeMaskBuilder};
+use crate::node_watcher::{
+    AttributeSubscriptionId, AttributeSubscriptions, AttributeWatcher, NodeWatcher,
+};
 use crate::passes::{Dependant, DirtyNodeStates, PassDirection, TypeErasedState};
 use crate::prelude::AttributeMaskBuilder;
 use crate::tree::{TreeMut, TreeMutView, TreeRef, TreeRefView};
@@ -73,6 +75,16 @@ impl<V: FromAnyValue + Send + Sync> NodesDirty<V> {
         }
     }
 
+    /// Mark a single state of a node as dirty if the state is tracked
+    pub(crate) fn mark_state_dirty(&mut self, node_id: NodeId, state: TypeId) {
+        if self.passes.iter().any(|pass| pass.this_type_id == state) {
+            self.passes_updated
+                .entry(node_id)
+                .or_default()
+                .insert(state);
+        }
+    }
+
     /// Mark a node that has had a parent changed
     fn mark_parent_added_or_removed(&mut self, node_id: NodeId) {
         let hm = self.passes_updated.entry(node_id).or_default();
@@ -115,6 +127,7 @@ pub struct RealDom<V: FromAnyValue + Send + Sync = ()> {
     pub(crate) dirty_nodes: NodesDirty<V>,
     node_watchers: NodeWatchers<V>,
     attribute_watchers: AttributeWatchers<V>,
+    attribute_subscriptions: Arc<RwLock<AttributeSubscriptions<V>>>,
     workload: ScheduledWorkload,
     root_id: NodeId,
     custom_elements: Arc<RwLock<CustomElementRegistry<V>>>,
@@ -213,6 +226,7 @@ impl<V: FromAnyValue + Send + Sync> RealDom<V> {
             },
             node_watchers: Default::default(),
             attribute_watchers: Default::default(),
+            attribute_subscriptions: Default::default(),
             workload,
             root_id,
             custom_elements: Default::default(),
@@ -380,6 +394,12 @@ impl<V: FromAnyValue + Send + Sync> RealDom<V> {
 
         let dirty = self.world.remove_unique::<DirtyNodesResult>().unwrap();
 
+        // call attribute subscriptions now that the state of every node is up to date
+        let subscriptions = self.attribute_subscriptions.clone();
+        if let Ok(mut subscriptions) = subscriptions.try_write() {
+            subscriptions.notify(self, &nodes_updated);
+        };
+
         (dirty.0, nodes_updated)
     }
 
@@ -500,6 +520,43 @@ impl<V: FromAnyValue + Send + Sync> RealDom<V> {
             .push(Box::new(watcher));
     }
 
+    /// Subscribe to changes of the `attributes` on any node. After [`RealDom::update_state`], `callback` is called with each node that had any of the attributes changed and the attributes that changed.
+    pub fn subscribe_attributes(
+        &mut self,
+        attributes: AttributeMask,
+        callback: impl FnMut(NodeRef<V>, &AttributeMask) + Send + Sync + 'static,
+    ) -> AttributeSubscriptionId {
+        self.attribute_subscriptions.write().unwrap().subscribe(
+            None,
+            attributes,
+            Box::new(callback),
+        )
+    }
+
+    /// Subscribe to changes of the `attributes` on a single node. After [`RealDom::update_state`], `callback` is called with the node and the attributes that changed if any of the attributes changed.
+    ///
+    /// The subscription is dropped when the node is removed.
+    pub fn subscribe_node_attributes(
+        &mut self,
+        node: NodeId,
+        attributes: AttributeMask,
+        callback: impl FnMut(NodeRef<V>, &AttributeMask) + Send + Sync + 'static,
+    ) -> AttributeSubscriptionId {
+        self.attribute_subscriptions.write().unwrap().subscribe(
+            Some(node),
+            attributes,
+            Box::new(callback),
+        )
+    }
+
+    /// Remove a subscription created with [`RealDom::subscribe_attributes`] or [`RealDom::subscribe_node_attributes`]
+    pub fn unsubscribe_attributes(&mut self, subscription: AttributeSubscriptionId) {
+        self.attribute_subscriptions
+            .write()
+            .unwrap()
+            .unsubscribe(subscription);
+    }
+
     /// Returns a reference to the underlying world. Any changes made to the world will not update the reactive system.
     pub fn raw_world(&self) -> &World {
         &self.world
@@ -959,6 +1016,9 @@ impl<'a, V: FromAnyValue + Send + Sync> NodeMut<'a, V> {
         for watcher in &mut *watchers.write().unwrap() {
             watcher.on_node_removed(NodeMut::new(self.id(), self.dom));
         }
+        if let Ok(mut subscriptions) = self.dom.attribute_subscriptions.try_write() {
+            subscriptions.remove_node(self.id());
+        }
     }
 
     /// mark that this node was moved for the incremental system
@@ -0,0 +1,223 @@
+//! Stable identifiers for nodes that survive re-creation
+//!
+//! [`NodeId`]s are tied to a single node in the [`RealDom`]. When a template is swapped during hot reloading or a list item is re-created, the new node gets a new [`NodeId`] even though it represents the same logical node. [`StableIds`] assigns [`StableId`]s that follow the logical node instead, so out-of-process renderers and test harnesses can keep tracking a node across updates.
+//!
+//! Nodes that are still in the tree keep their id, even if they move. New nodes inherit the id of the removed node that was at the same logical path. A path is made of the key of every node from the root, or the index of the node in its parent if it has no key.
+//!
+//! ```rust
+//! use dioxus_native_core::prelude::*;
+//! use dioxus_native_core::stable_id::StableIds;
+//!
+//! let mut rdom: RealDom = RealDom::new([]);
+//! let root = rdom.root_id();
+//! let old = rdom.create_node(NodeType::Element(ElementNode::new("div", None))).id();
+//! rdom.get_mut(root).unwrap().add_child(old);
+//!
+//! let mut ids = StableIds::new();
+//! ids.update(&rdom);
+//! let stable_id = ids.get(old).unwrap();
+//!
+//! // replace the node with a new node in the same position
+//! let new = rdom.create_node(NodeType::Element(ElementNode::new("div", None))).id();
+//! rdom.get_mut(old).unwrap().remove();
+//! rdom.get_mut(root).unwrap().add_child(new);
+//!
+//! ids.update(&rdom);
+//! assert_eq!(ids.get(new), Some(stable_id));
+//! assert_eq!(ids.node(stable_id), Some(new));
+//! ```
+
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use crate::{
+    node::{FromAnyValue, NodeType},
+    prelude::*,
+};
+
+/// An identifier for a logical node that is stable across re-creations of the node. See the [module level documentation](self) for more details.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct StableId(u64);
+
+impl StableId {
+    /// Get the raw value of the id. This can be sent to other processes
+    pub fn as_u64(&self) -> u64 {
+        self.0
+    }
+}
+
+/// One step in the path from the root to a node
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum PathSegment {
+    /// The node has a key
+    Key(String),
+    /// The index of a node without a key in its parent
+    Index(usize),
+}
+
+/// A mapping between the [`NodeId`]s in a [`RealDom`] and [`StableId`]s
+#[derive(Debug)]
+pub struct StableIds {
+    key_attribute: String,
+    next_id: u64,
+    by_node: FxHashMap<NodeId, StableId>,
+    by_id: FxHashMap<StableId, NodeId>,
+    by_path: FxHashMap<Vec<PathSegment>, StableId>,
+}
+
+impl Default for StableIds {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl StableIds {
+    /// Create a new mapping that keys nodes by their `id` attribute
+    pub fn new() -> Self {
+        Self::with_key_attribute("id")
+    }
+
+    /// Create a new mapping that keys nodes by the attribute with the given name
+    pub fn with_key_attribute(key_attribute: impl Into<String>) -> Self {
+        Self {
+            key_attribute: key_attribute.into(),
+            next_id: 0,
+            by_node: FxHashMap::default(),
+            by_id: FxHashMap::default(),
+            by_path: FxHashMap::default(),
+        }
+    }
+
+    /// Get the stable id of a node. Returns None if the node was created after the last call to [`StableIds::update`]
+    pub fn get(&self, node: NodeId) -> Option<StableId> {
+        self.by_node.get(&node).copied()
+    }
+
+    /// Get the node a stable id currently refers to. Returns None if the logical node was removed
+    pub fn node(&self, id: StableId) -> Option<NodeId> {
+        self.by_id.get(&id).copied()
+    }
+
+    /// Assign stable ids to the nodes that are currently in the tree. Call this after the [`RealDom`] is updated.
+    pub fn update<V: FromAnyValue + Send + Sync>(&mut self, rdom: &RealDom<V>) {
+        let mut nodes = Vec::new();
+        let mut stack = vec![(rdom.root_id(), Vec::new())];
+        while let Some((id, path)) = stack.pop() {
+            let node = rdom.get(id).unwrap();
+            for (index, child) in node.children().iter().enumerate() {
+                let mut child_path = path.clone();
+                child_path.push(match self.key(child) {
+                    Some(key) => PathSegment::Key(key),
+                    None => PathSegment::Index(index),
+                });
+                stack.push((child.id(), child_path));
+            }
+            nodes.push((id, path));
+        }
+
+        // Nodes that are still in the tree keep their id before any id is reused
+        let surviving: FxHashSet<StableId> = nodes
+            .iter()
+            .filter_map(|(id, _)| self.by_node.get(id).copied())
+            .collect();
+
+        let mut by_node = FxHashMap::default();
+        let mut by_id = FxHashMap::default();
+        let mut by_path = FxHashMap::default();
+        let mut reused = FxHashSet::default();
+        for (id, path) in nodes {
+            let stable_id = match self.by_node.get(&id) {
+                Some(stable_id) => *stable_id,
+                None => match self.by_path.get(&path) {
+                    Some(old) if !surviving.contains(old) && reused.insert(*old) => *old,
+                    _ => {
+                        let stable_id = StableId(self.next_id);
+                        self.next_id += 1;
+                        stable_id
+                    }
+                },
+            };
+            by_node.insert(id, stable_id);
+            by_id.insert(stable_id, id);
+            by_path.insert(path, stable_id);
+        }
+
+        self.by_node = by_node;
+        self.by_id = by_id;
+        self.by_path = by_path;
+    }
+
+    fn key<V: FromAnyValue + Send + Sync>(&self, node: &NodeRef<V>) -> Option<String> {
+        match &*node.node_type() {
+            NodeType::Element(element) => element
+                .attributes
+                .iter()
+                .find(|(attr, _)| attr.name == self.key_attribute && attr.namespace.is_none())
+                .map(|(_, value)| value.to_string()),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::node::{ElementNode, OwnedAttributeValue};
+
+    fn element(rdom: &mut RealDom, key: Option<&str>) -> NodeId {
+        let mut element = ElementNode::new("div", None);
+        if let Some(key) = key {
+            element.attributes.insert(
+                "id".to_string().into(),
+                OwnedAttributeValue::Text(key.to_string()),
+            );
+        }
+        rdom.create_node(NodeType::Element(element)).id()
+    }
+
+    #[test]
+    fn ids_follow_logical_nodes() {
+        let mut rdom: RealDom = RealDom::new([]);
+        let root = rdom.root_id();
+        let list = element(&mut rdom, None);
+        rdom.get_mut(root).unwrap().add_child(list);
+        let first = element(&mut rdom, Some("first"));
+        let second = element(&mut rdom, Some("second"));
+        rdom.get_mut(list).unwrap().add_child(first);
+        rdom.get_mut(list).unwrap().add_child(second);
+
+        let mut ids = StableIds::new();
+        ids.update(&rdom);
+        let list_id = ids.get(list).unwrap();
+        let first_id = ids.get(first).unwrap();
+        let second_id = ids.get(second).unwrap();
+        assert_ne!(first_id, second_id);
+
+        // re-create the list with the keyed items in the opposite order
+        rdom.get_mut(list).unwrap().remove();
+        let new_list = element(&mut rdom, None);
+        rdom.get_mut(root).unwrap().add_child(new_list);
+        let new_second = element(&mut rdom, Some("second"));
+        let new_first = element(&mut rdom, Some("first"));
+        rdom.get_mut(new_list).unwrap().add_child(new_second);
+        rdom.get_mut(new_list).unwrap().add_child(new_first);
+
+        ids.update(&rdom);
+        assert_eq!(ids.get(new_list), Some(list_id));
+        assert_eq!(ids.get(new_first), Some(first_id));
+        assert_eq!(ids.get(new_second), Some(second_id));
+        assert_eq!(ids.node(first_id), Some(new_first));
+        assert_eq!(ids.get(first), None);
+
+        // unkeyed nodes are matched by their index in the parent
+        let third = element(&mut rdom, None);
+        rdom.get_mut(new_list).unwrap().add_child(third);
+        ids.update(&rdom);
+        let third_id = ids.get(third).unwrap();
+        rdom.get_mut(third).unwrap().remove();
+        let replacement = element(&mut rdom, None);
+        rdom.get_mut(new_list).unwrap().add_child(replacement);
+        ids.update(&rdom);
+        assert_eq!(ids.get(replacement), Some(third_id));
+        assert_eq!(ids.get(new_first), Some(first_id));
+    }
+}
@@ -0,0 +1,730 @@
+//! Cascade stylesheets over the [`RealDom`]
+//!
+//! A [`Stylesheet`] is a list of rules that pair a [`Selector`] with declarations. [`RealDom::cascade`] matches the rules against every element, resolves conflicts by importance, specificity and source order, applies the inline style of the element and inherits [inherited properties](is_inherited) from the parent. The result is stored as the [`ComputedStyle`] of every node so renderers can read a single resolved value for each property instead of parsing style attributes themselves.
+//!
+//! Supported syntax:
+//! - Style rules with any [`Selector`] the RealDom can query: `div.sidebar > button, #main { color: red; }`
+//! - `!important` declarations
+//! - The `inherit`, `initial` and `unset` keywords
+//! - Comments (`/* .. */`)
+//!
+//! Values are not parsed, they are stored as the text they were declared with.
+//!
+//! To resolve styles incrementally as the tree changes, register the [`ResolvedStyle`] state and insert the stylesheet into the context of [`RealDom::update_state`] instead.
+//!
+//! ```rust
+//! use dioxus_native_core::prelude::*;
+//!
+//! let mut rdom: RealDom = RealDom::new([]);
+//! let root_id = rdom.root_id();
+//! let mut element = ElementNode::new("div", None);
+//! element.attributes.insert("class".to_string().into(), "warning".to_string().into());
+//! let div = rdom.create_node(NodeType::Element(element)).id();
+//! let text = rdom.create_node(NodeType::Text(TextNode::new("careful".to_string()))).id();
+//! rdom.get_mut(root_id).unwrap().add_child(div);
+//! rdom.get_mut(div).unwrap().add_child(text);
+//!
+//! let stylesheet = Stylesheet::parse("div { color: blue; padding: 1px } .warning { color: red }").unwrap();
+//! rdom.cascade(&stylesheet);
+//!
+//! let div_style = rdom.get(div).unwrap().get::<ComputedStyle>().unwrap().clone();
+//! assert_eq!(div_style.get("color"), Some("red"));
+//! assert_eq!(div_style.get("padding"), Some("1px"));
+//!
+//! // the text inherits the color, but not the padding
+//! let text_style = rdom.get(text).unwrap().get::<ComputedStyle>().unwrap().clone();
+//! assert_eq!(text_style.get("color"), Some("red"));
+//! assert_eq!(text_style.get("padding"), None);
+//! ```
+
+use std::{any::TypeId, fmt::Display, ops::Deref, str::FromStr, sync::Arc};
+
+use rustc_hash::{FxHashMap, FxHashSet};
+use shipyard::Component;
+
+use crate::{
+    node::{FromAnyValue, NodeType, OwnedAttributeDiscription, OwnedAttributeValue},
+    node_ref::NodeMask,
+    prelude::*,
+    query::{ElementSnapshot, Selector, Specificity},
+};
+
+/// A parsed list of style rules
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Stylesheet {
+    rules: Vec<StyleRule>,
+    mask: NodeMask,
+}
+
+impl Stylesheet {
+    /// Parse a stylesheet like `div.sidebar > button { color: red; }`
+    pub fn parse(stylesheet: &str) -> Result<Self, StylesheetParseError> {
+        let src = strip_comments(stylesheet);
+        let mut rules = Vec::new();
+        let mut position = 0;
+        loop {
+            let rest = &src[position..];
+            let start = position + (rest.len() - rest.trim_start().len());
+            if start == src.len() {
+                break;
+            }
+            if src[start..].starts_with('@') {
+                return Err(StylesheetParseError {
+                    position: start,
+                    message: "at-rules are not supported".to_string(),
+                });
+            }
+
+            let open = src[start..]
+                .find('{')
+                .map(|offset| start + offset)
+                .ok_or_else(|| StylesheetParseError {
+                    position: start,
+                    message: "expected `{` after the selector".to_string(),
+                })?;
+            let selector = Selector::parse(src[start..open].trim_end()).map_err(|err| {
+                StylesheetParseError {
+                    position: start + err.position,
+                    message: err.message,
+                }
+            })?;
+
+            let close = src[open..]
+                .find('}')
+                .map(|offset| open + offset)
+                .ok_or_else(|| StylesheetParseError {
+                    position: open,
+                    message: "unclosed `{`".to_string(),
+                })?;
+            rules.push(StyleRule {
+                selector,
+                declarations: parse_declarations(&src[open + 1..close]),
+            });
+            position = close + 1;
+        }
+
+        let mask = rules.iter().fold(NodeMask::default(), |mask, rule| {
+            mask.union(rule.selector.mask())
+        });
+        Ok(Self { rules, mask })
+    }
+
+    /// The rules in the stylesheet in source order
+    pub fn rules(&self) -> &[StyleRule] {
+        &self.rules
+    }
+
+    /// Append the rules of another stylesheet. The appended rules come later in source order, so they win over rules in this stylesheet with the same specificity
+    pub fn extend(&mut self, other: Stylesheet) {
+        self.rules.extend(other.rules);
+        self.mask = self.mask.union(&other.mask);
+    }
+
+    /// The parts of a node the selectors in the stylesheet read
+    pub fn mask(&self) -> &NodeMask {
+        &self.mask
+    }
+}
+
+impl FromStr for Stylesheet {
+    type Err = StylesheetParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+/// A selector and the declarations that apply to every node that matches it
+#[derive(Debug, Clone, PartialEq)]
+pub struct StyleRule {
+    /// The nodes this rule applies to
+    pub selector: Selector,
+    /// The declarations in source order
+    pub declarations: Vec<Declaration>,
+}
+
+/// A single `property: value` pair
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Declaration {
+    /// The lowercase name of the property
+    pub property: String,
+    /// The value of the property without the `!important` flag
+    pub value: String,
+    /// If the declaration was marked `!important`
+    pub important: bool,
+}
+
+/// An error that occurred while parsing a [`Stylesheet`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StylesheetParseError {
+    /// The byte offset in the stylesheet the error occurred at
+    pub position: usize,
+    /// A description of the error
+    pub message: String,
+}
+
+impl Display for StylesheetParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "invalid stylesheet: {} at position {}",
+            self.message, self.position
+        )
+    }
+}
+
+impl std::error::Error for StylesheetParseError {}
+
+/// Replace comments with whitespace so positions in errors still point into the original stylesheet
+fn strip_comments(src: &str) -> String {
+    let mut stripped = String::with_capacity(src.len());
+    let mut rest = src;
+    while let Some(start) = rest.find("/*") {
+        stripped.push_str(&rest[..start]);
+        let comment_len = rest[start + 2..]
+            .find("*/")
+            .map(|end| end + 4)
+            .unwrap_or(rest.len() - start);
+        stripped.extend(std::iter::repeat(' ').take(comment_len));
+        rest = &rest[start + comment_len..];
+    }
+    stripped.push_str(rest);
+    stripped
+}
+
+/// Parse a list of declarations like the contents of a rule or a `style` attribute. Invalid declarations are skipped like in a browser.
+pub fn parse_declarations(declarations: &str) -> Vec<Declaration> {
+    declarations
+        .split(';')
+        .filter_map(|declaration| {
+            let (property, value) = declaration.split_once(':')?;
+            let property = property.trim().to_lowercase();
+            let mut value = value.trim();
+            let mut important = false;
+            if let Some((rest, flag)) = value.rsplit_once('!') {
+                if flag.trim().eq_ignore_ascii_case("important") {
+                    value = rest.trim_end();
+                    important = true;
+                }
+            }
+            if property.is_empty() || value.is_empty() {
+                return None;
+            }
+            Some(Declaration {
+                property,
+                value: value.to_string(),
+                important,
+            })
+        })
+        .collect()
+}
+
+/// Check if a property is inherited from the parent when a node does not declare it. Custom properties (`--name`) are always inherited.
+pub fn is_inherited(property: &str) -> bool {
+    property.starts_with("--") || INHERITED_PROPERTIES.binary_search(&property).is_ok()
+}
+
+// sorted so they can be binary searched
+const INHERITED_PROPERTIES: &[&str] = &[
+    "border-collapse",
+    "border-spacing",
+    "caption-side",
+    "color",
+    "cursor",
+    "direction",
+    "empty-cells",
+    "font",
+    "font-family",
+    "font-size",
+    "font-style",
+    "font-variant",
+    "font-weight",
+    "hyphens",
+    "letter-spacing",
+    "line-height",
+    "list-style",
+    "list-style-image",
+    "list-style-position",
+    "list-style-type",
+    "overflow-wrap",
+    "quotes",
+    "tab-size",
+    "text-align",
+    "text-indent",
+    "text-shadow",
+    "text-transform",
+    "visibility",
+    "white-space",
+    "word-break",
+    "word-spacing",
+    "word-wrap",
+    "writing-mode",
+];
+
+/// The resolved style of a node after [`RealDom::cascade`]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Component)]
+pub struct ComputedStyle {
+    properties: FxHashMap<String, String>,
+}
+
+impl ComputedStyle {
+    /// Get the value of a property
+    pub fn get(&self, property: &str) -> Option<&str> {
+        self.properties.get(property).map(|value| value.as_str())
+    }
+
+    /// Iterate over every property with a value in arbitrary order
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.properties
+            .iter()
+            .map(|(property, value)| (property.as_str(), value.as_str()))
+    }
+
+    /// Create the style of a node that does not declare anything
+    fn inherit_from(parent: Option<&ComputedStyle>) -> Self {
+        let properties = parent
+            .map(|parent| {
+                parent
+                    .properties
+                    .iter()
+                    .filter(|(property, _)| is_inherited(property))
+                    .map(|(property, value)| (property.clone(), value.clone()))
+                    .collect()
+            })
+            .unwrap_or_default();
+        Self { properties }
+    }
+
+    fn apply(&mut self, declaration: &Declaration, parent: Option<&ComputedStyle>) {
+        let inherit = match declaration.value.as_str() {
+            "inherit" => true,
+            "initial" => false,
+            "unset" => is_inherited(&declaration.property),
+            value => {
+                self.properties
+                    .insert(declaration.property.clone(), value.to_string());
+                return;
+            }
+        };
+        match parent
+            .and_then(|parent| parent.get(&declaration.property))
+            .filter(|_| inherit)
+        {
+            Some(value) => {
+                self.properties
+                    .insert(declaration.property.clone(), value.to_string());
+            }
+            None => {
+                self.properties.remove(&declaration.property);
+            }
+        }
+    }
+}
+
+/// Where a declaration came from, in increasing priority for declarations with the same importance
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Origin {
+    Stylesheet(Specificity),
+    Inline,
+}
+
+impl<V: FromAnyValue + Send + Sync> RealDom<V> {
+    /// Match a stylesheet against every node and store the [`ComputedStyle`] of each node. Returns the nodes whose computed style changed.
+    ///
+    /// Inline styles from the `style` attribute or attributes in the `style` namespace win over the stylesheet unless the stylesheet declaration is `!important`. The cascade reads the whole tree, call this after the tree is updated.
+    pub fn cascade(&mut self, stylesheet: &Stylesheet) -> FxHashSet<NodeId> {
+        let mut computed: FxHashMap<NodeId, ComputedStyle> = FxHashMap::default();
+        self.traverse_depth_first(|node| {
+            let parent = node.parent_id().and_then(|parent| computed.get(&parent));
+            let style = compute_style(&node, stylesheet, parent);
+            computed.insert(node.id(), style);
+        });
+
+        let mut changed = FxHashSet::default();
+        for (id, style) in computed {
+            let mut node = self.get_mut(id).unwrap();
+            if node.get::<ComputedStyle>().as_deref() != Some(&style) {
+                node.insert(style);
+                changed.insert(id);
+            }
+        }
+        changed
+    }
+}
+
+fn compute_style<V: FromAnyValue + Send + Sync>(
+    node: &NodeRef<V>,
+    stylesheet: &Stylesheet,
+    parent: Option<&ComputedStyle>,
+) -> ComputedStyle {
+    let node_type = node.node_type();
+    let NodeType::Element(element) = &*node_type else {
+        return ComputedStyle::inherit_from(parent);
+    };
+    let matched = stylesheet.rules().iter().filter_map(|rule| {
+        rule.selector
+            .match_specificity(node)
+            .map(|specificity| (specificity, rule))
+    });
+    cascade_declarations(matched, inline_declarations(&element.attributes), parent)
+}
+
+/// Parse the `style` attribute and the attributes in the `style` namespace of an element
+fn inline_declarations<'a, V: FromAnyValue + 'a>(
+    attributes: impl IntoIterator<Item = (&'a OwnedAttributeDiscription, &'a OwnedAttributeValue<V>)>,
+) -> Vec<Declaration> {
+    let mut inline = Vec::new();
+    for (attribute, value) in attributes {
+        match (attribute.name.as_str(), attribute.namespace.as_deref()) {
+            ("style", None) => inline.extend(parse_declarations(&value.to_string())),
+            (name, Some("style")) => inline.extend(parse_declarations(&format!("{name}: {value}"))),
+            _ => {}
+        }
+    }
+    inline
+}
+
+/// Resolve the matched rules and inline declarations of an element into its computed style
+fn cascade_declarations<'a>(
+    matched: impl Iterator<Item = (Specificity, &'a StyleRule)>,
+    inline: Vec<Declaration>,
+    parent: Option<&ComputedStyle>,
+) -> ComputedStyle {
+    let mut declarations: Vec<(bool, Origin, &Declaration)> = Vec::new();
+    for (specificity, rule) in matched {
+        declarations.extend(rule.declarations.iter().map(|declaration| {
+            (
+                declaration.important,
+                Origin::Stylesheet(specificity),
+                declaration,
+            )
+        }));
+    }
+    declarations.extend(
+        inline
+            .iter()
+            .map(|declaration| (declaration.important, Origin::Inline, declaration)),
+    );
+
+    // The sort is stable, so declarations with the same priority stay in source order and the last one wins
+    declarations.sort_by_key(|(important, origin, _)| (*important, *origin));
+    let mut style = ComputedStyle::inherit_from(parent);
+    for (_, _, declaration) in declarations {
+        style.apply(declaration, parent);
+    }
+
+    style
+}
+
+/// The style of a node resolved from the [`Stylesheet`] in the context of [`RealDom::update_state`]. Register this state with [`ResolvedStyle::to_type_erased`] and insert the stylesheet into the context as an `Arc<Stylesheet>`.
+///
+/// Unlike [`RealDom::cascade`], the style is resolved incrementally: only nodes whose tag, inline style or attributes read by the stylesheet changed are resolved again, along with the descendants whose style or selector matches depend on them. Call [`RealDom::mark_stylesheet_changed`] when the stylesheet in the context changes.
+///
+/// States can only read the node and its ancestors while the RealDom is updated, so rules with selectors that don't [match from ancestors](Selector::matches_from_ancestors), like `li + li` or `:first-child`, are skipped.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Component)]
+pub struct ResolvedStyle {
+    style: ComputedStyle,
+    /// The snapshot selectors of descendants are matched against. None for the root and text nodes
+    element: Option<Arc<ElementSnapshot>>,
+}
+
+impl Deref for ResolvedStyle {
+    type Target = ComputedStyle;
+
+    fn deref(&self) -> &Self::Target {
+        &self.style
+    }
+}
+
+#[dioxus_native_core_macro::partial_derive_state]
+impl State for ResolvedStyle {
+    type ParentDependencies = (Self,);
+    type ChildDependencies = ();
+    type NodeDependencies = ();
+
+    const NODE_MASK: NodeMaskBuilder<'static> = NodeMaskBuilder::new()
+        .with_tag()
+        .with_attrs(AttributeMaskBuilder::All);
+
+    fn update<'a>(
+        &mut self,
+        node_view: NodeView,
+        _: <Self::NodeDependencies as Dependancy>::ElementBorrowed<'a>,
+        parent: Option<<Self::ParentDependencies as Dependancy>::ElementBorrowed<'a>>,
+        _: Vec<<Self::ChildDependencies as Dependancy>::ElementBorrowed<'a>>,
+        context: &SendAnyMap,
+    ) -> bool {
+        let parent = parent.as_ref().map(|(parent,)| &**parent);
+        let parent_style = parent.map(|parent| &parent.style);
+        let stylesheet = context.get::<Arc<Stylesheet>>();
+
+        let new = match (parent, node_view.tag()) {
+            // The root of the RealDom is not part of the document
+            (Some(parent), Some(tag)) => {
+                let attributes: Vec<_> = node_view.attributes().into_iter().flatten().collect();
+                let selector_attributes =
+                    stylesheet.map(|stylesheet| stylesheet.mask().attributes());
+                let element = Arc::new(ElementSnapshot {
+                    tag: tag.to_string(),
+                    attributes: attributes
+                        .iter()
+                        .filter(|attr| {
+                            attr.attribute.namespace.is_none()
+                                && selector_attributes
+                                    .is_some_and(|mask| mask.contains(&attr.attribute.name))
+                        })
+                        .map(|attr| (attr.attribute.name.clone(), attr.value.to_string()))
+                        .collect(),
+                    parent: parent.element.clone(),
+                });
+                let matched = stylesheet
+                    .into_iter()
+                    .flat_map(|stylesheet| stylesheet.rules())
+                    .filter(|rule| rule.selector.matches_from_ancestors())
+                    .filter_map(|rule| {
+                        rule.selector
+                            .snapshot_specificity(&element)
+                            .map(|specificity| (specificity, rule))
+                    });
+                let inline =
+                    inline_declarations(attributes.iter().map(|attr| (attr.attribute, attr.value)));
+                ResolvedStyle {
+                    style: cascade_declarations(matched, inline, parent_style),
+                    element: Some(element),
+                }
+            }
+            _ => ResolvedStyle {
+                style: ComputedStyle::inherit_from(parent_style),
+                element: None,
+            },
+        };
+        let changed = *self != new;
+        *self = new;
+        changed
+    }
+}
+
+impl<V: FromAnyValue + Send + Sync> RealDom<V> {
+    /// Mark the nodes whose [`ResolvedStyle`] may change when the stylesheet in the context of [`RealDom::update_state`] is replaced. Only the nodes matched by rules that were added, removed or moved are resolved again, unless the new rules read attributes the old rules did not.
+    pub fn mark_stylesheet_changed(&mut self, old: &Stylesheet, new: &Stylesheet) {
+        let state = TypeId::of::<ResolvedStyle>();
+        let mut affected = Vec::new();
+        if old.mask() == new.mask() {
+            let unchanged = old
+                .rules()
+                .iter()
+                .zip(new.rules())
+                .take_while(|(old, new)| old == new)
+                .count();
+            let changed: Vec<_> = old.rules()[unchanged..]
+                .iter()
+                .chain(&new.rules()[unchanged..])
+                .map(|rule| &rule.selector)
+                .filter(|selector| selector.matches_from_ancestors())
+                .collect();
+            if changed.is_empty() {
+                return;
+            }
+            self.traverse_depth_first(|node| {
+                if changed.iter().any(|selector| selector.matches(&node)) {
+                    affected.push(node.id());
+                }
+            });
+        } else {
+            // The snapshots of every element need to include the new attributes
+            self.traverse_depth_first(|node| affected.push(node.id()));
+        }
+        for id in affected {
+            self.dirty_nodes.mark_state_dirty(id, state);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::node::{ElementNode, OwnedAttributeDiscription, OwnedAttributeValue};
+    use crate::real_dom::NodeTypeMut;
+
+    fn element(rdom: &mut RealDom, parent: NodeId, tag: &str, attrs: &[(&str, &str)]) -> NodeId {
+        let mut element = ElementNode::new(tag, None);
+        for (name, value) in attrs {
+            element.attributes.insert(
+                name.to_string().into(),
+                OwnedAttributeValue::Text(value.to_string()),
+            );
+        }
+        let id = rdom.create_node(NodeType::Element(element)).id();
+        rdom.get_mut(parent).unwrap().add_child(id);
+        id
+    }
+
+    fn style(rdom: &RealDom, id: NodeId, property: &str) -> Option<String> {
+        rdom.get(id)
+            .unwrap()
+            .get::<ComputedStyle>()
+            .unwrap()
+            .get(property)
+            .map(|value| value.to_string())
+    }
+
+    #[test]
+    fn cascade() {
+        let mut rdom: RealDom = RealDom::new([]);
+        let root = rdom.root_id();
+        let main = element(&mut rdom, root, "div", &[("id", "main")]);
+        let button = element(
+            &mut rdom,
+            main,
+            "button",
+            &[("class", "primary"), ("style", "margin: 2px; color: green")],
+        );
+        let plain = element(&mut rdom, main, "button", &[]);
+
+        let stylesheet = Stylesheet::parse(
+            "/* rules */
+            #main { color: blue; font-weight: bold; border: 1px }
+            button { color: black; border: inherit }
+            .primary { color: white !important; font-weight: normal }
+            button { font-weight: unset; }",
+        )
+        .unwrap();
+        let changed = rdom.cascade(&stylesheet);
+        assert!(changed.contains(&button));
+
+        // important declarations win over inline styles
+        assert_eq!(style(&rdom, button, "color").as_deref(), Some("white"));
+        assert_eq!(style(&rdom, button, "margin").as_deref(), Some("2px"));
+        // `unset` inherits inherited properties even from a rule with a lower specificity that comes later
+        assert_eq!(
+            style(&rdom, button, "font-weight").as_deref(),
+            Some("normal")
+        );
+        assert_eq!(style(&rdom, plain, "font-weight").as_deref(), Some("bold"));
+        assert_eq!(style(&rdom, plain, "color").as_deref(), Some("black"));
+        // non-inherited properties are only inherited with `inherit`
+        assert_eq!(style(&rdom, plain, "border").as_deref(), Some("1px"));
+        assert_eq!(style(&rdom, root, "color"), None);
+
+        // attributes in the style namespace are inline styles
+        if let NodeTypeMut::Element(mut element) = rdom.get_mut(plain).unwrap().node_type_mut() {
+            element.set_attribute(
+                OwnedAttributeDiscription {
+                    name: "color".to_string(),
+                    namespace: Some("style".to_string()),
+                },
+                OwnedAttributeValue::Text("red".to_string()),
+            );
+        }
+        let changed = rdom.cascade(&stylesheet);
+        assert_eq!(changed.into_iter().collect::<Vec<_>>(), vec![plain]);
+        assert_eq!(style(&rdom, plain, "color").as_deref(), Some("red"));
+    }
+
+    #[test]
+    fn resolved_style() {
+        fn update(rdom: &mut RealDom, stylesheet: &Arc<Stylesheet>) -> FxHashSet<NodeId> {
+            let mut ctx = SendAnyMap::new();
+            ctx.insert(stylesheet.clone());
+            rdom.update_state(ctx).0.into_iter().collect()
+        }
+
+        fn resolved(rdom: &RealDom, id: NodeId, property: &str) -> Option<String> {
+            rdom.get(id)
+                .unwrap()
+                .get::<ResolvedStyle>()
+                .unwrap()
+                .get(property)
+                .map(|value| value.to_string())
+        }
+
+        let mut rdom: RealDom = RealDom::new([ResolvedStyle::to_type_erased()]);
+        let root = rdom.root_id();
+        let sidebar = element(&mut rdom, root, "div", &[("class", "sidebar")]);
+        let first = element(&mut rdom, sidebar, "button", &[("style", "margin: 1px")]);
+        let second = element(&mut rdom, sidebar, "button", &[("title", "second")]);
+        let other = element(&mut rdom, root, "p", &[]);
+
+        let stylesheet = Arc::new(
+            Stylesheet::parse(
+                ".sidebar { color: gray } .sidebar > button { color: red } button + button { color: blue } .collapsed button { margin: 0 }",
+            )
+            .unwrap(),
+        );
+        update(&mut rdom, &stylesheet);
+        assert_eq!(resolved(&rdom, sidebar, "color").as_deref(), Some("gray"));
+        assert_eq!(resolved(&rdom, first, "color").as_deref(), Some("red"));
+        assert_eq!(resolved(&rdom, first, "margin").as_deref(), Some("1px"));
+        // sibling selectors are skipped
+        assert_eq!(resolved(&rdom, second, "color").as_deref(), Some("red"));
+        assert_eq!(resolved(&rdom, other, "color"), None);
+
+        // changing the class of the sidebar only resolves the sidebar and its descendants again
+        if let NodeTypeMut::Element(mut element) = rdom.get_mut(sidebar).unwrap().node_type_mut() {
+            element.set_attribute(
+                OwnedAttributeDiscription {
+                    name: "class".to_string(),
+                    namespace: None,
+                },
+                OwnedAttributeValue::Text("sidebar collapsed".to_string()),
+            );
+        }
+        let changed = update(&mut rdom, &stylesheet);
+        assert_eq!(changed, FxHashSet::from_iter([sidebar, first, second]));
+        // inline styles win over the stylesheet
+        assert_eq!(resolved(&rdom, first, "margin").as_deref(), Some("1px"));
+        assert_eq!(resolved(&rdom, second, "margin").as_deref(), Some("0"));
+
+        // attributes the stylesheet does not read don't change the style
+        if let NodeTypeMut::Element(mut element) = rdom.get_mut(second).unwrap().node_type_mut() {
+            element.set_attribute(
+                OwnedAttributeDiscription {
+                    name: "title".to_string(),
+                    namespace: None,
+                },
+                OwnedAttributeValue::Text("renamed".to_string()),
+            );
+        }
+        assert!(update(&mut rdom, &stylesheet).is_empty());
+
+        // only the nodes matched by the new rules are resolved again
+        let mut extended = (*stylesheet).clone();
+        extended.extend(Stylesheet::parse("p { color: green }").unwrap());
+        let extended = Arc::new(extended);
+        rdom.mark_stylesheet_changed(&stylesheet, &extended);
+        let changed = update(&mut rdom, &extended);
+        assert_eq!(changed, FxHashSet::from_iter([other]));
+        assert_eq!(resolved(&rdom, other, "color").as_deref(), Some("green"));
+    }
+
+    #[test]
+    fn parse_errors() {
+        for (invalid, position) in [
+            ("div { color: red", 4),
+            ("div color: red }", 0),
+            ("div > { color: red }", 5),
+            ("@media print { div {} }", 0),
+        ] {
+            assert_eq!(
+                Stylesheet::parse(invalid).unwrap_err().position,
+                position,
+                "{invalid}"
+            );
+        }
+        assert_eq!(
+            parse_declarations("color: red !important; invalid; margin:0"),
+            vec![
+                Declaration {
+                    property: "color".to_string(),
+                    value: "red".to_string(),
+                    important: true,
+                },
+                Declaration {
+                    property: "margin".to_string(),
+                    value: "0".to_string(),
+                    important: false,
+                },
+            ]
+        );
+    }
+}
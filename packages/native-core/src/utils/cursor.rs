@@ -20,7 +20,8 @@ pub trait Text {
 
 impl Text for str {
     fn line(&self, number: usize) -> Option<&str> {
-        self.lines().nth(number)
+        // split instead of lines so a trailing newline starts a new empty line
+        self.split('\n').nth(number)
     }
 
     fn length(&self) -> usize {
@@ -28,7 +29,7 @@ impl Text for str {
     }
 
     fn line_count(&self) -> usize {
-        self.lines().count()
+        self.split('\n').count()
     }
 
     fn character(&self, idx: usize) -> Option<char> {
@@ -36,9 +37,10 @@ impl Text for str {
     }
 
     fn len_before_line(&self, line: usize) -> usize {
-        self.lines()
+        // every line before this one ends with a newline
+        self.split('\n')
             .take(line)
-            .map(|l| l.chars().count())
+            .map(|l| l.chars().count() + 1)
             .sum::<usize>()
     }
 }
@@ -53,14 +55,24 @@ pub trait TextEditable<T: Text + ?Sized>: AsRef<T> {
 
 impl TextEditable<str> for String {
     fn insert_character(&mut self, idx: usize, text: char) {
-        self.insert(idx, text);
+        self.insert(byte_index(self, idx), text);
     }
 
     fn delete_range(&mut self, range: Range<usize>) {
-        self.replace_range(range, "");
+        let start = byte_index(self, range.start);
+        let end = byte_index(self, range.end);
+        self.replace_range(start..end, "");
     }
 }
 
+/// Convert a character index into a byte index of the string
+fn byte_index(text: &str, idx: usize) -> usize {
+    text.char_indices()
+        .nth(idx)
+        .map(|(byte, _)| byte)
+        .unwrap_or(text.len())
+}
+
 /// A cursor position
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Pos {
@@ -328,6 +340,8 @@ impl Cursor {
             Enter => {
                 if text.as_ref().length() + 1 - self.selection_len(text.as_ref()) <= max_text_length
                 {
+                    self.start.realize_col(text.as_ref());
+                    self.delete_selection(text);
                     text.insert_character(self.start.idx(text.as_ref()), '\n');
                     self.start.col = 0;
                     self.start.down(text.as_ref());
@@ -539,3 +553,74 @@ fn cursor_input() {
 
     assert_eq!(text.to_string(), goal_text);
 }
+
+#[test]
+fn cursor_multiline_input() {
+    let mut cursor = Cursor::from_start(Pos::new(0, 0));
+    let mut text = "héllo\nwörld".to_string();
+    let input = |code, key, modifiers, cursor: &mut Cursor, text: &mut String| {
+        cursor.handle_input(&code, &key, &modifiers, text, 100);
+    };
+
+    input(
+        keyboard_types::Code::ArrowDown,
+        keyboard_types::Key::ArrowDown,
+        Modifiers::empty(),
+        &mut cursor,
+        &mut text,
+    );
+    input(
+        keyboard_types::Code::KeyX,
+        keyboard_types::Key::Character("x".to_string()),
+        Modifiers::empty(),
+        &mut cursor,
+        &mut text,
+    );
+    assert_eq!(text, "héllo\nxwörld");
+    assert_eq!(cursor.start, Pos::new(1, 1));
+
+    // backspace at the start of a line joins it with the line above
+    input(
+        keyboard_types::Code::ArrowLeft,
+        keyboard_types::Key::ArrowLeft,
+        Modifiers::empty(),
+        &mut cursor,
+        &mut text,
+    );
+    input(
+        keyboard_types::Code::Backspace,
+        keyboard_types::Key::Backspace,
+        Modifiers::empty(),
+        &mut cursor,
+        &mut text,
+    );
+    assert_eq!(text, "hélloxwörld");
+    assert_eq!(cursor.start, Pos::new(5, 0));
+
+    input(
+        keyboard_types::Code::Enter,
+        keyboard_types::Key::Enter,
+        Modifiers::empty(),
+        &mut cursor,
+        &mut text,
+    );
+    assert_eq!(text, "héllo\nxwörld");
+    assert_eq!(cursor.start, Pos::new(0, 1));
+
+    // typing replaces the selection
+    input(
+        keyboard_types::Code::End,
+        keyboard_types::Key::End,
+        Modifiers::SHIFT,
+        &mut cursor,
+        &mut text,
+    );
+    input(
+        keyboard_types::Code::KeyY,
+        keyboard_types::Key::Character("y".to_string()),
+        Modifiers::empty(),
+        &mut cursor,
+        &mut text,
+    );
+    assert_eq!(text, "héllo\ny");
+}
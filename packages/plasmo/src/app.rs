@@ -0,0 +1,302 @@
+use std::{
+    rc::Rc,
+    sync::{Arc, Mutex, RwLock},
+};
+
+use crossterm::event::{Event as TermEvent, KeyCode, KeyEventKind, KeyModifiers};
+use dioxus_native_core::{prelude::*, FxDashSet, SendAnyMap};
+use futures::channel::mpsc::{UnboundedReceiver, UnboundedSender};
+use futures_channel::mpsc::unbounded;
+use taffy::{geometry::Point, Taffy};
+
+use crate::{
+    bubble_event_to_widgets,
+    focus::{Focus, FocusRequest},
+    image::ImagePlacement,
+    layout::{compute_layout, LayoutStatsTracker, TaffyLayout, TextMeasurer},
+    linear::LinearOutput,
+    prevent_default::PreventDefault,
+    render, scroll,
+    style_attributes::StyleModifier,
+    widgets::register_widgets,
+    Config, Driver, Event, InputEvent, OutputMode, Query, RinkInputHandler,
+};
+
+/// The position of the terminal cursor. Widgets that accept text input move the cursor while they are focused.
+#[derive(Default, shipyard::Unique)]
+pub(crate) struct TerminalCursor(pub(crate) Option<(u16, u16)>);
+
+pub(crate) fn cursor_position(rdom: &RealDom) -> Option<(u16, u16)> {
+    rdom.raw_world()
+        .borrow::<shipyard::UniqueView<TerminalCursor>>()
+        .ok()
+        .and_then(|cursor| cursor.0)
+}
+
+/// The state of a running app that is shared between the terminal and the in-memory [`crate::TestHarness`]
+pub(crate) struct App<R> {
+    pub(crate) cfg: Config,
+    pub(crate) rdom: Arc<RwLock<RealDom>>,
+    pub(crate) taffy: Arc<Mutex<Taffy>>,
+    pub(crate) driver: R,
+    handler: RinkInputHandler,
+    register_event: Box<dyn FnMut(TermEvent)>,
+    text_measurer: TextMeasurer,
+    layout_stats: LayoutStatsTracker,
+    to_rerender: FxDashSet<NodeId>,
+    updated: bool,
+    /// The images drawn with a graphics protocol in the last frame
+    images: Vec<ImagePlacement>,
+    /// The images the terminal currently shows
+    drawn_images: Vec<ImagePlacement>,
+    output_mode: OutputMode,
+    /// The lines printed in the linear output mode
+    linear: LinearOutput,
+    /// The channel for raw terminal events
+    pub(crate) raw_event_tx: UnboundedSender<InputEvent>,
+    pub(crate) raw_event_reciever: UnboundedReceiver<InputEvent>,
+    /// The channel for fully resolved events
+    pub(crate) event_reciever: UnboundedReceiver<Event>,
+}
+
+impl<R: Driver> App<R> {
+    pub(crate) fn new(
+        cfg: Config,
+        create_renderer: impl FnOnce(
+            &Arc<RwLock<RealDom>>,
+            &Arc<Mutex<Taffy>>,
+            UnboundedSender<InputEvent>,
+        ) -> R,
+    ) -> Self {
+        let mut rdom = RealDom::new([
+            TaffyLayout::to_type_erased(),
+            Focus::to_type_erased(),
+            StyleModifier::to_type_erased(),
+            PreventDefault::to_type_erased(),
+        ]);
+
+        // Setup input handling
+
+        // The event channel for fully resolved events
+        let (event_tx, event_reciever) = unbounded();
+
+        // The event channel for raw terminal events
+        let (raw_event_tx, raw_event_reciever) = unbounded();
+
+        register_widgets(&mut rdom, event_tx);
+
+        let (handler, register_event) = RinkInputHandler::create(&mut rdom);
+
+        let rdom = Arc::new(RwLock::new(rdom));
+        let taffy = Arc::new(Mutex::new(Taffy::new()));
+        let driver = create_renderer(&rdom, &taffy, raw_event_tx.clone());
+        let text_measurer = TextMeasurer(driver.text_measurer());
+
+        // insert the query engine, layout stats and cursor into the rdom
+        let query_engine = Query::new(rdom.clone(), taffy.clone());
+        let layout_stats = LayoutStatsTracker::default();
+        {
+            let mut rdom = rdom.write().unwrap();
+            rdom.raw_world_mut().add_unique(query_engine);
+            rdom.raw_world_mut().add_unique(layout_stats.clone());
+            rdom.raw_world_mut().add_unique(TerminalCursor::default());
+            rdom.raw_world_mut()
+                .add_unique(scroll::ScrolledNodes::default());
+            rdom.raw_world_mut().add_unique(FocusRequest::default());
+        }
+
+        let mut myself = Self {
+            cfg,
+            rdom,
+            taffy,
+            driver,
+            handler,
+            register_event: Box::new(register_event),
+            text_measurer,
+            layout_stats,
+            to_rerender: FxDashSet::default(),
+            updated: true,
+            images: Vec::new(),
+            drawn_images: Vec::new(),
+            output_mode: cfg.output_mode,
+            linear: LinearOutput::default(),
+            raw_event_tx,
+            raw_event_reciever,
+            event_reciever,
+        };
+        myself.driver.update(&myself.rdom);
+        myself.update_state();
+        myself
+    }
+
+    /// Returns true if the app needs to be drawn again
+    pub(crate) fn needs_redraw(&self) -> bool {
+        !self.to_rerender.is_empty() || self.updated
+    }
+
+    /// Layout and draw the app into a frame
+    pub(crate) fn draw(&mut self, frame: &mut ratatui::Frame) {
+        self.updated = false;
+        let rdom = self.rdom.write().unwrap();
+        let mut taffy = self.taffy.lock().expect("taffy lock poisoned");
+        // size is guaranteed to not change when rendering
+        compute_layout(frame.size(), &mut taffy, &rdom, &self.layout_stats);
+        let root = rdom.get(rdom.root_id()).unwrap();
+        self.images.clear();
+        render::render_vnode(
+            frame,
+            &taffy,
+            root,
+            self.cfg,
+            Point::ZERO,
+            scroll::UNCLIPPED,
+            &mut self.images,
+        );
+        if let Some((x, y)) = cursor_position(&rdom) {
+            frame.set_cursor(x, y);
+        }
+    }
+
+    /// Returns the images to draw with the graphics protocol if they changed since they were last drawn
+    pub(crate) fn take_changed_images(&mut self) -> Option<Vec<ImagePlacement>> {
+        (self.images != self.drawn_images).then(|| {
+            self.drawn_images = self.images.clone();
+            self.images.clone()
+        })
+    }
+
+    /// How the app is currently shown
+    pub(crate) fn output_mode(&self) -> OutputMode {
+        self.output_mode
+    }
+
+    fn set_output_mode(&mut self, output_mode: OutputMode) {
+        if output_mode != self.output_mode {
+            self.output_mode = output_mode;
+            self.updated = true;
+            // the terminal was cleared, so everything needs to be shown again
+            self.drawn_images.clear();
+            self.linear.reset();
+        }
+    }
+
+    /// Layout the app and return the lines to print in the linear output mode
+    pub(crate) fn draw_linear(&mut self) -> Vec<String> {
+        self.layout_headless();
+        let focused = self.handler.state().focus_state.last_focused_id;
+        self.linear.update(&self.rdom.read().unwrap(), focused)
+    }
+
+    /// Layout the app without drawing it
+    pub(crate) fn layout_headless(&mut self) {
+        self.updated = false;
+        let rdom = self.rdom.read().unwrap();
+        compute_layout(
+            ratatui::layout::Rect {
+                x: 0,
+                y: 0,
+                width: 1000,
+                height: 1000,
+            },
+            &mut self.taffy.lock().expect("taffy lock poisoned"),
+            &rdom,
+            &self.layout_stats,
+        );
+    }
+
+    /// Queue a raw event. Returns false if the app should quit
+    pub(crate) fn handle_input(&mut self, event: InputEvent) -> bool {
+        match event {
+            InputEvent::UserInput(event) => {
+                match &event {
+                    TermEvent::Key(key) => {
+                        if matches!(key.code, KeyCode::Char('C' | 'c'))
+                            && key.modifiers.contains(KeyModifiers::CONTROL)
+                            && self.cfg.ctrl_c_quit
+                        {
+                            return false;
+                        }
+                        if self.cfg.output_mode_toggle == Some((key.code, key.modifiers)) {
+                            if key.kind != KeyEventKind::Release {
+                                self.set_output_mode(self.output_mode.toggled());
+                            }
+                            return true;
+                        }
+                        if key.kind != KeyEventKind::Release && self.driver.handle_key(key) {
+                            return true;
+                        }
+                    }
+                    TermEvent::Resize(_, _) => {
+                        self.updated = true;
+                        // resizing clears the terminal along with any images on it
+                        self.drawn_images.clear();
+                    }
+                    _ => {}
+                }
+                (self.register_event)(event);
+                true
+            }
+            InputEvent::SetOutputMode(output_mode) => {
+                self.set_output_mode(output_mode);
+                true
+            }
+            InputEvent::Close => false,
+        }
+    }
+
+    /// Resolve the queued events, send them to the driver and update the dom
+    pub(crate) fn update(&mut self, event_recieved: Option<Event>) {
+        let cursor = cursor_position(&self.rdom.read().unwrap());
+        if let Some(evt) = event_recieved {
+            self.driver
+                .handle_event(&self.rdom, evt.id, evt.name, Rc::new(evt.data), evt.bubbles);
+        }
+        {
+            let evts = self.handler.get_events(
+                &self.taffy.lock().expect("taffy lock poisoned"),
+                &mut self.rdom.write().unwrap(),
+            );
+            self.updated |= self.handler.state().focus_state.clean();
+            self.updated |= self.handler.state().clean_scroll();
+
+            for e in evts {
+                bubble_event_to_widgets(&mut self.rdom.write().unwrap(), &e);
+                self.driver
+                    .handle_event(&self.rdom, e.id, e.name, Rc::new(e.data), e.bubbles);
+            }
+        }
+        // updates the dom's nodes
+        self.driver.update(&self.rdom);
+        self.update_state();
+        self.updated |= cursor != cursor_position(&self.rdom.read().unwrap());
+
+        // tasks that ran in the update may have focused a node with a query. Move the focus now instead of waiting for the next event
+        let focus_requested = self
+            .rdom
+            .read()
+            .unwrap()
+            .raw_world()
+            .borrow::<shipyard::UniqueView<FocusRequest>>()
+            .is_ok_and(|request| request.0.is_some());
+        if focus_requested {
+            self.update(None);
+        }
+    }
+
+    /// Update the style and layout
+    fn update_state(&mut self) {
+        let mut rdom = self.rdom.write().unwrap();
+        let mut any_map = SendAnyMap::new();
+        any_map.insert(self.taffy.clone());
+        any_map.insert(self.layout_stats.clone());
+        any_map.insert(self.text_measurer.clone());
+        let (to_rerender, dirty) = rdom.update_state(any_map);
+        let text_mask = NodeMaskBuilder::new().with_text().build();
+        for (id, mask) in dirty {
+            if mask.overlaps(&text_mask) {
+                to_rerender.insert(id);
+            }
+        }
+        self.to_rerender = to_rerender;
+    }
+}
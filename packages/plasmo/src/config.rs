@@ -1,3 +1,7 @@
+use crossterm::event::{KeyCode, KeyModifiers};
+
+use crate::OutputMode;
+
 #[derive(Clone, Copy)]
 #[non_exhaustive]
 pub struct Config {
@@ -7,6 +11,12 @@ pub struct Config {
     pub(crate) ctrl_c_quit: bool,
     /// Controls if the terminal should dislay anything, usefull for testing.
     pub(crate) headless: bool,
+    /// The protocol images are drawn with. This is detected from the terminal by default.
+    pub(crate) graphics_protocol: GraphicsProtocol,
+    /// How the app is shown when it starts
+    pub(crate) output_mode: OutputMode,
+    /// The key that switches between the visual and the linear output
+    pub(crate) output_mode_toggle: Option<(KeyCode, KeyModifiers)>,
 }
 
 impl Config {
@@ -34,6 +44,33 @@ impl Config {
             ..self
         }
     }
+
+    /// Draw images with a graphics protocol instead of the protocol detected from the terminal.
+    pub fn with_graphics_protocol(self, graphics_protocol: GraphicsProtocol) -> Self {
+        Self {
+            graphics_protocol,
+            ..self
+        }
+    }
+
+    /// Show the app with an output mode when it starts. The mode can be changed while the app runs with
+    /// [`crate::TuiContext::set_output_mode`] or the key set with [`Config::with_output_mode_toggle`].
+    pub fn with_output_mode(self, output_mode: OutputMode) -> Self {
+        Self {
+            output_mode,
+            ..self
+        }
+    }
+
+    /// Switch between the visual and the linear output when the key is pressed. The key is not sent to the app.
+    ///
+    /// There is no toggle key by default, so apps can offer the linear output without taking a key from the app.
+    pub fn with_output_mode_toggle(self, code: KeyCode, modifiers: KeyModifiers) -> Self {
+        Self {
+            output_mode_toggle: Some((code, modifiers)),
+            ..self
+        }
+    }
 }
 
 impl Default for Config {
@@ -42,6 +79,9 @@ impl Default for Config {
             rendering_mode: Default::default(),
             ctrl_c_quit: true,
             headless: false,
+            graphics_protocol: Default::default(),
+            output_mode: Default::default(),
+            output_mode_toggle: None,
         }
     }
 }
@@ -56,3 +96,66 @@ pub enum RenderingMode {
     #[default]
     Rgb,
 }
+
+/// How the images of `img` elements are drawn
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum GraphicsProtocol {
+    /// Detect the protocol with [`GraphicsProtocol::detect`] when the app starts
+    #[default]
+    Auto,
+    /// The kitty graphics protocol, supported by kitty and Ghostty
+    Kitty,
+    /// The inline images protocol of iTerm2, also supported by WezTerm
+    Iterm2,
+    /// Sixel graphics, supported by foot, mlterm and xterm with sixel support
+    Sixel,
+    /// Draw two pixels in every cell with unicode half blocks. This works in every terminal with colors
+    HalfBlocks,
+}
+
+impl GraphicsProtocol {
+    /// Detect the graphics protocol of the terminal from the environment variables it sets.
+    ///
+    /// Images fall back to half blocks inside of tmux and screen, because they don't pass graphics through to the terminal.
+    pub fn detect() -> Self {
+        let var = |name: &str| std::env::var(name).unwrap_or_default();
+        let term = var("TERM");
+        let term_program = var("TERM_PROGRAM");
+
+        if !var("TMUX").is_empty() || term.starts_with("screen") || term.starts_with("tmux") {
+            Self::HalfBlocks
+        } else if !var("KITTY_WINDOW_ID").is_empty()
+            || term == "xterm-kitty"
+            || term == "xterm-ghostty"
+            || term_program == "ghostty"
+        {
+            Self::Kitty
+        } else if term_program == "iTerm.app"
+            || term_program == "WezTerm"
+            || !var("ITERM_SESSION_ID").is_empty()
+        {
+            Self::Iterm2
+        } else if term.contains("sixel")
+            || term.starts_with("foot")
+            || term.starts_with("mlterm")
+            || term_program == "mlterm"
+        {
+            Self::Sixel
+        } else {
+            Self::HalfBlocks
+        }
+    }
+
+    /// Resolve [`GraphicsProtocol::Auto`] to the protocol of the terminal
+    pub(crate) fn resolve(self) -> Self {
+        match self {
+            Self::Auto => Self::detect(),
+            protocol => protocol,
+        }
+    }
+
+    /// Returns true if images are drawn by the terminal instead of in the cells of the frame
+    pub(crate) fn draws_graphics(self) -> bool {
+        matches!(self, Self::Kitty | Self::Iterm2 | Self::Sixel)
+    }
+}
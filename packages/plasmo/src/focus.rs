@@ -10,7 +10,7 @@ use dioxus_native_core_macro::partial_derive_state;
 use once_cell::sync::Lazy;
 use rustc_hash::FxHashSet;
 use shipyard::Component;
-use shipyard::{Get, ViewMut};
+use shipyard::{Get, Unique, ViewMut};
 
 use std::{cmp::Ordering, num::NonZeroU16};
 
@@ -139,6 +139,10 @@ static FOCUS_EVENTS: Lazy<FxHashSet<&str>> =
     Lazy::new(|| ["keydown", "keypress", "keyup"].into_iter().collect());
 const FOCUS_ATTRIBUTES: &[&str] = &["tabindex"];
 
+/// A node a query focused or blurred. The focus moves the next time events are resolved
+#[derive(Debug, Default, Unique)]
+pub(crate) struct FocusRequest(pub(crate) Option<(NodeId, bool)>);
+
 pub(crate) struct FocusState {
     pub(crate) focus_iter: PersistantElementIter,
     pub(crate) last_focused_id: Option<NodeId>,
@@ -284,6 +288,17 @@ impl FocusState {
         self.dirty = true;
     }
 
+    /// Remove the focus from a node if it is focused
+    pub(crate) fn blur(&mut self, rdom: &mut RealDom, id: NodeId) {
+        if self.last_focused_id == Some(id) {
+            self.last_focused_id = None;
+            if let Some(mut node) = rdom.get_mut(id) {
+                node.insert(Focused(false));
+            }
+            self.dirty = true;
+        }
+    }
+
     pub(crate) fn clean(&mut self) -> bool {
         let old = self.dirty;
         self.dirty = false;
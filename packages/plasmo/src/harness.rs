@@ -0,0 +1,183 @@
+use std::sync::{Arc, Mutex, RwLock};
+
+use crossterm::event::Event as TermEvent;
+use dioxus_native_core::real_dom::RealDom;
+use futures::channel::mpsc::UnboundedSender;
+use ratatui::{
+    backend::TestBackend,
+    buffer::{Buffer, Cell},
+    Terminal,
+};
+use taffy::Taffy;
+
+use crate::{
+    app::{cursor_position, App},
+    Config, Driver, InputEvent, OutputMode, TuiContext,
+};
+
+/// The maximum number of times the app is updated in a single call to [`TestHarness::update`]. This keeps an app that always has more work from hanging the test.
+const MAX_UPDATES: usize = 100;
+
+/// Renders an app into an in-memory buffer instead of the terminal so the output can be tested.
+///
+/// Events injected with [`TestHarness::inject_event`] or with a [`TuiContext`] inside of the app are handled the next time [`TestHarness::update`] is called.
+///
+/// The harness drives its own async runtime, so it must not be used inside of another runtime.
+pub struct TestHarness {
+    app: App<Box<dyn Driver>>,
+    terminal: Terminal<TestBackend>,
+    runtime: tokio::runtime::Runtime,
+    cursor: Option<(u16, u16)>,
+    closed: bool,
+    /// The lines printed in the linear output mode
+    transcript: Vec<String>,
+}
+
+impl TestHarness {
+    /// Create a harness that renders into a buffer with the given size in cells. The app is drawn once before this returns.
+    pub fn new<R: Driver + 'static>(
+        cfg: Config,
+        width: u16,
+        height: u16,
+        create_renderer: impl FnOnce(
+            &Arc<RwLock<RealDom>>,
+            &Arc<Mutex<Taffy>>,
+            UnboundedSender<InputEvent>,
+        ) -> R,
+    ) -> Self {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .expect("failed to create the test runtime");
+        let app = {
+            let _guard = runtime.enter();
+            App::new(cfg, |rdom, taffy, event_tx| {
+                Box::new(create_renderer(rdom, taffy, event_tx)) as Box<dyn Driver>
+            })
+        };
+        let terminal = Terminal::new(TestBackend::new(width, height)).unwrap();
+        let mut myself = Self {
+            app,
+            terminal,
+            runtime,
+            cursor: None,
+            closed: false,
+            transcript: Vec::new(),
+        };
+        myself.update();
+        myself
+    }
+
+    /// Get a context that injects events into the app, like the [`TuiContext`] the app receives
+    pub fn context(&self) -> TuiContext {
+        TuiContext::new(self.app.raw_event_tx.clone())
+    }
+
+    /// Queue a terminal event. The event is handled the next time [`TestHarness::update`] is called
+    pub fn inject_event(&self, event: TermEvent) {
+        self.context().inject_event(event);
+    }
+
+    /// Resize the buffer and send a resize event to the app. The app is drawn with the new size the next time [`TestHarness::update`] is called
+    pub fn resize(&mut self, width: u16, height: u16) {
+        self.terminal.backend_mut().resize(width, height);
+        self.inject_event(TermEvent::Resize(width, height));
+    }
+
+    /// Handle the queued events and any work the app has, then draw the app into the buffer if it changed
+    pub fn update(&mut self) {
+        let Self {
+            app,
+            runtime,
+            terminal,
+            closed,
+            transcript,
+            ..
+        } = self;
+        runtime.block_on(async {
+            for _ in 0..MAX_UPDATES {
+                if *closed {
+                    break;
+                }
+                // like the terminal event loop, draw before handling events so queries see the current layout
+                if app.needs_redraw() {
+                    draw(app, terminal, transcript);
+                }
+                let mut progressed = false;
+                while let Ok(Some(input)) = app.raw_event_reciever.try_next() {
+                    progressed = true;
+                    if !app.handle_input(input) {
+                        *closed = true;
+                    }
+                }
+                // like the terminal event loop, handle one event from the widgets at a time
+                let event_recieved = app.event_reciever.try_next().ok().flatten();
+                progressed |= event_recieved.is_some();
+                progressed |= futures::poll!(app.driver.poll_async()).is_ready();
+                if !progressed {
+                    break;
+                }
+                app.update(event_recieved);
+                // let the runtime drive any timers or io the app is waiting on
+                tokio::task::yield_now().await;
+            }
+        });
+
+        if self.app.needs_redraw() {
+            draw(&mut self.app, &mut self.terminal, &mut self.transcript);
+        }
+        self.cursor = cursor_position(&self.app.rdom.read().unwrap());
+    }
+
+    /// Get the buffer the app was last drawn into
+    pub fn buffer(&self) -> &Buffer {
+        self.terminal.backend().buffer()
+    }
+
+    /// Get the cell at a position in the buffer
+    pub fn cell(&self, x: u16, y: u16) -> &Cell {
+        self.buffer().get(x, y)
+    }
+
+    /// Get the text of a row in the buffer
+    pub fn line(&self, y: u16) -> String {
+        let buffer = self.buffer();
+        (buffer.area.left()..buffer.area.right())
+            .map(|x| buffer.get(x, y).symbol.as_str())
+            .collect()
+    }
+
+    /// Get the position of the terminal cursor, or None if it is hidden
+    pub fn cursor(&self) -> Option<(u16, u16)> {
+        self.cursor
+    }
+
+    /// Get every line the app printed in [`OutputMode::Linear`], including the announcements of the focused element
+    pub fn transcript(&self) -> &[String] {
+        &self.transcript
+    }
+
+    /// Get the current output mode of the app
+    pub fn output_mode(&self) -> OutputMode {
+        self.app.output_mode()
+    }
+
+    /// Returns true if the app quit with [`TuiContext::quit`] or `ctrl+c`
+    pub fn is_closed(&self) -> bool {
+        self.closed
+    }
+}
+
+/// Draw the app into the buffer, or print it into the transcript in the linear output mode
+fn draw(
+    app: &mut App<Box<dyn Driver>>,
+    terminal: &mut Terminal<TestBackend>,
+    transcript: &mut Vec<String>,
+) {
+    match app.output_mode() {
+        OutputMode::Visual => {
+            terminal.draw(|frame| app.draw(frame)).unwrap();
+        }
+        OutputMode::Linear => transcript.extend(app.draw_linear()),
+    }
+}
@@ -4,11 +4,12 @@ use crossterm::event::{
 };
 use dioxus_html::{
     HasFileData, HasFormData, HasKeyboardData, HasWheelData, SerializedFocusData,
-    SerializedKeyboardData, SerializedMouseData, SerializedWheelData,
+    SerializedKeyboardData, SerializedMouseData, SerializedScrollData, SerializedWheelData,
 };
 use dioxus_native_core::prelude::*;
 use dioxus_native_core::real_dom::NodeImmutable;
 use rustc_hash::{FxHashMap, FxHashSet};
+use shipyard::UniqueViewMut;
 
 use dioxus_html::geometry::euclid::{Point2D, Rect, Size2D};
 use dioxus_html::geometry::{
@@ -30,9 +31,11 @@ use std::{
 use taffy::geometry::{Point, Size};
 use taffy::{prelude::Layout, Taffy};
 
-use crate::focus::{Focus, Focused};
+use crate::focus::{Focus, FocusRequest, Focused};
 use crate::layout::TaffyLayout;
-use crate::{get_abs_layout, layout_to_screen_space, FocusState};
+use crate::prevent_default::PreventDefault;
+use crate::scroll;
+use crate::{get_abs_layout, layout_to_screen_space, unit_to_layout_space, FocusState};
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct Event {
@@ -49,6 +52,7 @@ pub enum EventData {
     Focus(SerializedFocusData),
     Wheel(SerializedWheelData),
     Form(FormData),
+    Scroll(SerializedScrollData),
 }
 
 impl EventData {
@@ -59,6 +63,7 @@ impl EventData {
             EventData::Focus(f) => Rc::new(f),
             EventData::Wheel(w) => Rc::new(w),
             EventData::Form(f) => Rc::new(f),
+            EventData::Scroll(s) => Rc::new(s),
         }
     }
 }
@@ -105,6 +110,7 @@ pub struct InnerInputState {
     wheel: Option<SerializedWheelData>,
     last_key_pressed: Option<(SerializedKeyboardData, Instant)>,
     pub(crate) focus_state: FocusState,
+    scrolled: bool,
     // subscribers: Vec<Rc<dyn Fn() + 'static>>,
 }
 
@@ -116,6 +122,7 @@ impl InnerInputState {
             last_key_pressed: None,
             // subscribers: Vec::new(),
             focus_state: FocusState::create(rdom),
+            scrolled: false,
         }
     }
 
@@ -202,6 +209,8 @@ impl InnerInputState {
 
         let old_focus = self.focus_state.last_focused_id;
 
+        self.apply_focus_request(dom);
+
         evts.retain(|e| match &e.1 {
             EventData::Keyboard(k) => match k.code() {
                 Code::Tab => !self
@@ -216,6 +225,9 @@ impl InnerInputState {
             self.apply_event(e);
         }
 
+        self.scroll(evts, layout, dom);
+        self.resolve_scroll_events(resolved_events, dom);
+
         self.resolve_mouse_events(previous_mouse, resolved_events, layout, dom);
 
         if old_focus != self.focus_state.last_focused_id {
@@ -249,6 +261,128 @@ impl InnerInputState {
         // }
     }
 
+    /// Scroll elements with scrollable overflow with the mouse wheel and the keyboard
+    fn scroll(&mut self, evts: &[EventCore], layout: &Taffy, dom: &mut RealDom) {
+        for (name, data) in evts {
+            let (start, amount, prevent) = match (*name, data) {
+                ("wheel", EventData::Wheel(w)) => {
+                    let Some(hovered) = self.hovered_node(layout, dom) else {
+                        continue;
+                    };
+                    let delta = w.delta().strip_units();
+                    let amount = match w.delta() {
+                        WheelDelta::Pages(_) => ScrollAmount::Pages(delta.x as f32, delta.y as f32),
+                        _ => ScrollAmount::Lines(delta.x as f32, delta.y as f32),
+                    };
+                    (hovered, amount, PreventDefault::Wheel)
+                }
+                ("keydown", EventData::Keyboard(k)) => {
+                    let amount = match k.key() {
+                        Key::ArrowUp => ScrollAmount::Lines(0.0, -1.0),
+                        Key::ArrowDown => ScrollAmount::Lines(0.0, 1.0),
+                        Key::ArrowLeft => ScrollAmount::Lines(-1.0, 0.0),
+                        Key::ArrowRight => ScrollAmount::Lines(1.0, 0.0),
+                        Key::PageUp => ScrollAmount::Pages(0.0, -1.0),
+                        Key::PageDown => ScrollAmount::Pages(0.0, 1.0),
+                        Key::Home => ScrollAmount::Lines(0.0, f32::NEG_INFINITY),
+                        Key::End => ScrollAmount::Lines(0.0, f32::INFINITY),
+                        _ => continue,
+                    };
+                    // the keyboard scrolls the focused element, or the element under the mouse if nothing is focused
+                    let start = match self.focus_state.last_focused_id {
+                        Some(focused) => {
+                            let [x, y] = scroll::overflow(&dom.get(focused).unwrap());
+                            if !x.scrolls() && !y.scrolls() {
+                                continue;
+                            }
+                            focused
+                        }
+                        None => match self.hovered_node(layout, dom) {
+                            Some(hovered) => hovered,
+                            None => continue,
+                        },
+                    };
+                    (start, amount, PreventDefault::KeyDown)
+                }
+                _ => continue,
+            };
+
+            // scroll the closest element that can move, unless the default behavior is prevented
+            let mut current = Some(start);
+            while let Some(id) = current {
+                let node = dom.get(id).unwrap();
+                if node.get::<PreventDefault>().map(|p| *p) == Some(prevent) {
+                    break;
+                }
+                let delta = amount.resolve(scroll::viewport_size(&node, layout));
+                current = node.parent_id();
+                if scroll::scroll_by(dom, layout, id, delta) {
+                    break;
+                }
+            }
+        }
+    }
+
+    /// Move the focus to the node a query focused or away from the node a query blurred
+    fn apply_focus_request(&mut self, dom: &mut RealDom) {
+        let request = match dom.raw_world().borrow::<UniqueViewMut<FocusRequest>>() {
+            Ok(mut request) => request.0.take(),
+            Err(_) => return,
+        };
+        match request {
+            Some((id, true)) if dom.get(id).is_some() => self.focus_state.set_focus(dom, id),
+            Some((id, false)) => self.focus_state.blur(dom, id),
+            _ => {}
+        }
+    }
+
+    /// Send scroll events to the nodes that were scrolled by the user or by a query
+    fn resolve_scroll_events(&mut self, resolved_events: &mut Vec<Event>, dom: &RealDom) {
+        let scrolled = match dom
+            .raw_world()
+            .borrow::<UniqueViewMut<scroll::ScrolledNodes>>()
+        {
+            Ok(mut scrolled) => std::mem::take(&mut scrolled.0),
+            Err(_) => return,
+        };
+        self.scrolled |= !scrolled.is_empty();
+
+        let mut dispatched = FxHashSet::default();
+        for id in scrolled {
+            let Some(node) = dom.get(id) else {
+                continue;
+            };
+            let listening = matches!(
+                &*node.node_type(),
+                NodeType::Element(ElementNode { listeners, .. }) if listeners.contains("scroll")
+            );
+            if listening && dispatched.insert(id) {
+                resolved_events.push(Event {
+                    name: "scroll",
+                    id,
+                    data: EventData::Scroll(SerializedScrollData {}),
+                    bubbles: event_bubbles("scroll"),
+                });
+            }
+        }
+    }
+
+    /// The deepest node under the mouse
+    fn hovered_node(&self, layout: &Taffy, dom: &RealDom) -> Option<NodeId> {
+        let point = self.mouse.as_ref()?.screen_coordinates();
+        // use the center of the cell the mouse is in
+        let point = Point {
+            x: unit_to_layout_space(point.x as f32 + 0.5),
+            y: unit_to_layout_space(point.y as f32 + 0.5),
+        };
+        scroll::node_at(dom, layout, point)
+    }
+
+    /// Returns true if an element was scrolled since the last call
+    pub(crate) fn clean_scroll(&mut self) -> bool {
+        std::mem::take(&mut self.scrolled)
+    }
+
     fn resolve_mouse_events(
         &mut self,
         previous_mouse: Option<SerializedMouseData>,
@@ -599,6 +733,34 @@ impl InnerInputState {
     // }
 }
 
+/// How far an input scrolls an element
+#[derive(Clone, Copy)]
+enum ScrollAmount {
+    /// A number of cells on each axis
+    Lines(f32, f32),
+    /// A number of viewports on each axis
+    Pages(f32, f32),
+}
+
+impl ScrollAmount {
+    fn resolve(self, viewport: Size<f32>) -> Point<f32> {
+        match self {
+            ScrollAmount::Lines(x, y) => Point {
+                x: unit_to_layout_space(x),
+                y: unit_to_layout_space(y),
+            },
+            ScrollAmount::Pages(x, y) => {
+                // keep one line of the last page visible
+                let line = unit_to_layout_space(1.0);
+                Point {
+                    x: x * (viewport.width - line).max(line),
+                    y: y * (viewport.height - line).max(line),
+                }
+            }
+        }
+    }
+}
+
 pub struct RinkInputHandler {
     state: Rc<RefCell<InnerInputState>>,
     queued_events: Rc<RefCell<Vec<EventCore>>>,
@@ -0,0 +1,251 @@
+//! Images of `img` elements. Images are drawn with a terminal graphics protocol when the terminal supports one, and with unicode half blocks otherwise.
+
+use std::{
+    collections::HashMap,
+    io::{self, Write},
+    sync::{Arc, Mutex},
+};
+
+use base64::Engine;
+use crossterm::{
+    cursor::{MoveTo, RestorePosition, SavePosition},
+    queue,
+};
+use image::{imageops::FilterType, ImageOutputFormat, RgbaImage};
+use once_cell::sync::Lazy;
+use ratatui::layout::Rect;
+use taffy::prelude::Size;
+
+use crate::{GraphicsProtocol, LeafElement};
+
+/// The size of a cell in pixels if the terminal doesn't report it
+const DEFAULT_CELL_SIZE: (u32, u32) = (8, 16);
+
+/// The decoded images by their `src`. Images that failed to load are cached as `None` so they are not read again every frame
+static IMAGES: Lazy<Mutex<HashMap<String, Option<Arc<RgbaImage>>>>> = Lazy::new(Default::default);
+
+/// Load the image at a path. Images are only read from disk the first time they are used
+pub(crate) fn load(src: &str) -> Option<Arc<RgbaImage>> {
+    let mut images = IMAGES.lock().expect("image cache poisoned");
+    images
+        .entry(src.to_string())
+        .or_insert_with(|| {
+            image::open(src)
+                .ok()
+                .map(|image| Arc::new(image.into_rgba8()))
+        })
+        .clone()
+}
+
+/// The size of an image in cells if the element doesn't set it. One side is scaled to keep the aspect ratio of the image if the other side is known
+pub(crate) fn intrinsic_size(
+    element: &LeafElement,
+    known_dimensions: Size<Option<f32>>,
+) -> Option<Size<f32>> {
+    if element.tag != "img" {
+        return None;
+    }
+    let (_, src) = element.attributes.iter().find(|(name, _)| name == "src")?;
+    let image = load(src)?;
+    let (cell_width, cell_height) = cell_size();
+    let width = image.width() as f32 / cell_width as f32;
+    let height = image.height() as f32 / cell_height as f32;
+    Some(match (known_dimensions.width, known_dimensions.height) {
+        (Some(known_width), Some(known_height)) => Size {
+            width: known_width,
+            height: known_height,
+        },
+        (Some(known_width), None) if width > 0.0 => Size {
+            width: known_width,
+            height: (height * known_width / width).round(),
+        },
+        (None, Some(known_height)) if height > 0.0 => Size {
+            width: (width * known_height / height).round(),
+            height: known_height,
+        },
+        _ => Size {
+            width: width.round(),
+            height: height.round(),
+        },
+    })
+}
+
+/// The size of a cell in pixels
+fn cell_size() -> (u32, u32) {
+    #[cfg(unix)]
+    {
+        // SAFETY: TIOCGWINSZ only writes into the winsize struct we pass
+        let mut size: libc::winsize = unsafe { std::mem::zeroed() };
+        let result = unsafe { libc::ioctl(libc::STDOUT_FILENO, libc::TIOCGWINSZ, &mut size) };
+        if result == 0 && size.ws_col > 0 && size.ws_row > 0 && size.ws_xpixel > 0 {
+            return (
+                (size.ws_xpixel / size.ws_col) as u32,
+                (size.ws_ypixel / size.ws_row) as u32,
+            );
+        }
+    }
+    DEFAULT_CELL_SIZE
+}
+
+/// An image that is drawn with a graphics protocol after the cells of the frame are drawn
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct ImagePlacement {
+    pub src: String,
+    /// The cells the image covers
+    pub area: Rect,
+}
+
+/// Draw images with a graphics protocol. The cells under the images must already be drawn
+pub(crate) fn draw_images(
+    out: &mut impl Write,
+    protocol: GraphicsProtocol,
+    images: &[ImagePlacement],
+) -> io::Result<()> {
+    if protocol == GraphicsProtocol::Kitty {
+        // kitty keeps images until they are deleted, even if the cells under them change
+        write!(out, "\x1b_Ga=d,d=A,q=2\x1b\\")?;
+    }
+    queue!(out, SavePosition)?;
+    for placement in images {
+        let Some(image) = load(&placement.src) else {
+            continue;
+        };
+        let Rect {
+            x,
+            y,
+            width,
+            height,
+        } = placement.area;
+        let (cell_width, cell_height) = cell_size();
+        let image = image::imageops::resize(
+            &*image,
+            width as u32 * cell_width,
+            height as u32 * cell_height,
+            FilterType::Triangle,
+        );
+        queue!(out, MoveTo(x, y))?;
+        match protocol {
+            GraphicsProtocol::Kitty => write_kitty(out, &image, width, height)?,
+            GraphicsProtocol::Iterm2 => write_iterm2(out, &image, width, height)?,
+            GraphicsProtocol::Sixel => write_sixel(out, &image)?,
+            GraphicsProtocol::Auto | GraphicsProtocol::HalfBlocks => {}
+        }
+    }
+    queue!(out, RestorePosition)?;
+    out.flush()
+}
+
+/// Send the raw pixels with the [kitty graphics protocol](https://sw.kovidgoyal.net/kitty/graphics-protocol/). The data is sent in chunks of 4096 bytes
+fn write_kitty(out: &mut impl Write, image: &RgbaImage, columns: u16, rows: u16) -> io::Result<()> {
+    let data = base64::engine::general_purpose::STANDARD.encode(image.as_raw());
+    let chunks = data.as_bytes().chunks(4096).collect::<Vec<_>>();
+    for (i, chunk) in chunks.iter().enumerate() {
+        let more = (i + 1 < chunks.len()) as u8;
+        if i == 0 {
+            // q=2 keeps the terminal from answering on stdin and C=1 keeps the cursor in place
+            write!(
+                out,
+                "\x1b_Ga=T,f=32,s={},v={},c={columns},r={rows},q=2,C=1,m={more};",
+                image.width(),
+                image.height()
+            )?;
+        } else {
+            write!(out, "\x1b_Gm={more};")?;
+        }
+        out.write_all(chunk)?;
+        write!(out, "\x1b\\")?;
+    }
+    Ok(())
+}
+
+/// Send the image as a png with the [inline images protocol](https://iterm2.com/documentation-images.html) of iTerm2
+fn write_iterm2(
+    out: &mut impl Write,
+    image: &RgbaImage,
+    columns: u16,
+    rows: u16,
+) -> io::Result<()> {
+    let mut png = io::Cursor::new(Vec::new());
+    image
+        .write_to(&mut png, ImageOutputFormat::Png)
+        .map_err(io::Error::other)?;
+    let png = png.into_inner();
+    write!(
+        out,
+        "\x1b]1337;File=inline=1;size={};width={columns};height={rows};preserveAspectRatio=0:{}\x07",
+        png.len(),
+        base64::engine::general_purpose::STANDARD.encode(&png)
+    )
+}
+
+/// Send the image as sixels. Colors are reduced to a 6x6x6 color cube and transparent pixels are not drawn
+fn write_sixel(out: &mut impl Write, image: &RgbaImage) -> io::Result<()> {
+    const LEVELS: u32 = 6;
+    let color_index = |pixel: &image::Rgba<u8>| -> Option<usize> {
+        let [r, g, b, a] = pixel.0;
+        if a < 128 {
+            return None;
+        }
+        let level = |c: u8| (c as u32 * (LEVELS - 1) + 127) / 255;
+        Some((level(r) * LEVELS * LEVELS + level(g) * LEVELS + level(b)) as usize)
+    };
+
+    // P2=1 leaves the pixels that are not drawn transparent
+    write!(out, "\x1bP0;1;0q\"1;1;{};{}", image.width(), image.height())?;
+    for index in 0..(LEVELS * LEVELS * LEVELS) {
+        let percent = |level: u32| level * 100 / (LEVELS - 1);
+        write!(
+            out,
+            "#{index};2;{};{};{}",
+            percent(index / (LEVELS * LEVELS)),
+            percent(index / LEVELS % LEVELS),
+            percent(index % LEVELS)
+        )?;
+    }
+
+    let width = image.width() as usize;
+    for band in 0..image.height().div_ceil(6) {
+        // the six bits of every column for every color in the band
+        let mut colors: Vec<Option<Vec<u8>>> = vec![None; (LEVELS * LEVELS * LEVELS) as usize];
+        for row in 0..6 {
+            let y = band * 6 + row;
+            if y >= image.height() {
+                break;
+            }
+            for x in 0..image.width() {
+                if let Some(index) = color_index(image.get_pixel(x, y)) {
+                    colors[index].get_or_insert_with(|| vec![0; width])[x as usize] |= 1 << row;
+                }
+            }
+        }
+
+        let mut first = true;
+        for (index, columns) in colors.iter().enumerate() {
+            let Some(columns) = columns else {
+                continue;
+            };
+            if !first {
+                // return to the start of the band to draw the next color
+                write!(out, "$")?;
+            }
+            first = false;
+            write!(out, "#{index}")?;
+            let mut x = 0;
+            while x < columns.len() {
+                let bits = columns[x];
+                let repeat = columns[x..].iter().take_while(|b| **b == bits).count();
+                let sixel = (63 + bits) as char;
+                if repeat > 3 {
+                    write!(out, "!{repeat}{sixel}")?;
+                } else {
+                    for _ in 0..repeat {
+                        write!(out, "{sixel}")?;
+                    }
+                }
+                x += repeat;
+            }
+        }
+        write!(out, "-")?;
+    }
+    write!(out, "\x1b\\")
+}
@@ -1,6 +1,6 @@
 use std::sync::{Arc, Mutex};
 
-use dioxus_native_core::exports::shipyard::Component;
+use dioxus_native_core::exports::shipyard::{Component, Unique};
 use dioxus_native_core::layout_attributes::{
     apply_layout_attributes_cfg, BorderWidths, LayoutConfigeration,
 };
@@ -8,9 +8,227 @@ use dioxus_native_core::node::OwnedAttributeView;
 use dioxus_native_core::node_ref::{AttributeMaskBuilder, NodeMaskBuilder, NodeView};
 use dioxus_native_core::prelude::*;
 use dioxus_native_core_macro::partial_derive_state;
+use taffy::node::MeasureFunc;
 use taffy::prelude::*;
 
-use crate::{screen_to_layout_space, unit_to_layout_space};
+use crate::{layout_to_screen_space, screen_to_layout_space, unit_to_layout_space};
+
+/// Statistics about how often the layout of the tree was recomputed. Read them with [`crate::Query::layout_stats`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct LayoutStats {
+    /// The number of frames the layout was recomputed because a node that effects layout changed or the terminal was resized
+    pub layouts_computed: usize,
+    /// The number of frames the previous layout was reused because nothing that effects layout changed
+    pub layouts_reused: usize,
+    /// The number of layout nodes that were created or had their style or children changed. Only these nodes and their ancestors are remeasured, every other node reuses its cached measurements
+    pub nodes_invalidated: usize,
+    /// The number of nodes in the layout tree the last time the layout was computed
+    pub layout_nodes: usize,
+}
+
+/// Tracks the [`LayoutStats`] of a tui app. This is shared between the layout pass and the render loop.
+#[derive(Clone, Default, Unique)]
+pub(crate) struct LayoutStatsTracker(Arc<Mutex<LayoutStats>>);
+
+impl LayoutStatsTracker {
+    pub fn get(&self) -> LayoutStats {
+        *self.0.lock().expect("layout stats lock poisoned")
+    }
+
+    fn update(&self, f: impl FnOnce(&mut LayoutStats)) {
+        f(&mut self.0.lock().expect("layout stats lock poisoned"))
+    }
+}
+
+/// Measures the intrinsic size of leaf nodes with the metrics of a renderer. Return a measurer from [`crate::Driver::text_measurer`] to replace the default [`CharCountMeasure`].
+///
+/// All sizes are in screen space (cells in the terminal). `known_dimensions` are the sizes the layout already resolved from the style of the node, and `available_space` is the space the parent has left for the node.
+pub trait MeasureText: Send + Sync {
+    /// Measure the size of a text node
+    fn measure_text(
+        &self,
+        text: &str,
+        known_dimensions: Size<Option<f32>>,
+        available_space: Size<AvailableSpace>,
+    ) -> Size<f32>;
+
+    /// Measure the size of an element without children like an image. Returns `None` if the element has no intrinsic size.
+    fn measure_element(
+        &self,
+        _element: &LeafElement,
+        _known_dimensions: Size<Option<f32>>,
+        _available_space: Size<AvailableSpace>,
+    ) -> Option<Size<f32>> {
+        None
+    }
+}
+
+/// The default [`MeasureText`] of the tui: every character is one cell wide and text never wraps. Images are as large as their pixels in cells.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CharCountMeasure;
+
+impl MeasureText for CharCountMeasure {
+    fn measure_text(&self, text: &str, _: Size<Option<f32>>, _: Size<AvailableSpace>) -> Size<f32> {
+        Size {
+            width: text.chars().count() as f32,
+            height: 1.0,
+        }
+    }
+
+    fn measure_element(
+        &self,
+        element: &LeafElement,
+        known_dimensions: Size<Option<f32>>,
+        _: Size<AvailableSpace>,
+    ) -> Option<Size<f32>> {
+        // images are sized by the image they load
+        crate::image::intrinsic_size(element, known_dimensions)
+    }
+}
+
+/// An element without children that is passed to [`MeasureText::measure_element`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct LeafElement {
+    /// The tag of the element
+    pub tag: String,
+    /// The layout attributes of the element along with the `src` and `alt` attributes
+    pub attributes: Vec<(String, String)>,
+}
+
+/// The [`MeasureText`] registered by the renderer. This is passed to the layout pass in the context.
+#[derive(Clone)]
+pub(crate) struct TextMeasurer(pub Arc<dyn MeasureText>);
+
+impl Default for TextMeasurer {
+    fn default() -> Self {
+        Self(Arc::new(CharCountMeasure))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Leaf {
+    Text(String),
+    Element(LeafElement),
+}
+
+impl Leaf {
+    /// Create a taffy measure function that converts between layout and screen space
+    fn measure(&self, measurer: &TextMeasurer) -> MeasureFunc {
+        let leaf = self.clone();
+        let measurer = measurer.0.clone();
+        MeasureFunc::Boxed(Box::new(move |known_dimensions, available_space| {
+            let known_dimensions = known_dimensions.map(|d| d.map(layout_to_screen_space));
+            let available_space = available_space.map(|space| match space {
+                AvailableSpace::Definite(d) => AvailableSpace::Definite(layout_to_screen_space(d)),
+                other => other,
+            });
+            let size = match &leaf {
+                Leaf::Text(text) => measurer.measure_text(text, known_dimensions, available_space),
+                Leaf::Element(element) => measurer
+                    .measure_element(element, known_dimensions, available_space)
+                    .unwrap_or(Size::ZERO),
+            };
+            size.map(unit_to_layout_space)
+        }))
+    }
+}
+
+/// Compute the layout of the root node to fill `size`.
+///
+/// Taffy caches the measurements of every node that was not invalidated, so only dirty subtrees and their ancestors are remeasured. If nothing was invalidated since the last layout, the previous layout is reused without walking the tree.
+pub(crate) fn compute_layout(
+    size: ratatui::layout::Rect,
+    taffy: &mut Taffy,
+    rdom: &RealDom,
+    stats: &LayoutStatsTracker,
+) {
+    let width = screen_to_layout_space(size.width);
+    let height = screen_to_layout_space(size.height);
+    let root_node = rdom
+        .get(rdom.root_id())
+        .unwrap()
+        .get::<TaffyLayout>()
+        .unwrap()
+        .node
+        .unwrap();
+
+    // the root node fills the entire area
+    let mut style = taffy.style(root_node).unwrap().clone();
+    let new_size = Size {
+        width: Dimension::Points(width),
+        height: Dimension::Points(height),
+    };
+    if style.size != new_size {
+        style.size = new_size;
+        taffy.set_style(root_node, style).unwrap();
+    }
+
+    // Changing the style or children of any node marks all of its ancestors dirty
+    if !taffy.dirty(root_node).unwrap() {
+        stats.update(|stats| stats.layouts_reused += 1);
+        return;
+    }
+
+    let size = Size {
+        width: AvailableSpace::Definite(width),
+        height: AvailableSpace::Definite(height),
+    };
+    taffy.compute_layout(root_node, size).unwrap();
+    stats.update(|stats| {
+        stats.layouts_computed += 1;
+        stats.layout_nodes = taffy.total_node_count();
+    });
+}
+
+#[test]
+fn leaves_are_measured_in_screen_space() {
+    /// Wraps text into lines that fit in the available width
+    struct Wrapping;
+
+    impl MeasureText for Wrapping {
+        fn measure_text(
+            &self,
+            text: &str,
+            known_dimensions: Size<Option<f32>>,
+            available_space: Size<AvailableSpace>,
+        ) -> Size<f32> {
+            let len = text.chars().count() as f32;
+            let width = match (known_dimensions.width, available_space.width) {
+                (Some(width), _) | (None, AvailableSpace::Definite(width)) => width.min(len),
+                _ => len,
+            };
+            Size {
+                width,
+                height: (len / width).ceil(),
+            }
+        }
+    }
+
+    let measurer = TextMeasurer(Arc::new(Wrapping));
+    let mut taffy = Taffy::new();
+    let text = Leaf::Text("hello world".to_string());
+    let leaf = taffy
+        .new_leaf_with_measure(Style::default(), text.measure(&measurer))
+        .unwrap();
+    let root = taffy
+        .new_with_children(
+            Style {
+                flex_direction: FlexDirection::Column,
+                size: Size {
+                    width: Dimension::Points(screen_to_layout_space(4)),
+                    height: Dimension::Auto,
+                },
+                ..Default::default()
+            },
+            &[leaf],
+        )
+        .unwrap();
+    taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+    let size = taffy.layout(leaf).unwrap().size;
+    assert_eq!(size.width, screen_to_layout_space(4));
+    assert_eq!(size.height, screen_to_layout_space(3));
+}
 
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub(crate) enum PossiblyUninitalized<T> {
@@ -36,6 +254,8 @@ impl<T> Default for PossiblyUninitalized<T> {
 pub(crate) struct TaffyLayout {
     pub style: Style,
     pub node: PossiblyUninitalized<Node>,
+    /// The content the node is measured from if it has no children
+    leaf: Option<Leaf>,
 }
 
 #[partial_derive_state]
@@ -46,6 +266,7 @@ impl State for TaffyLayout {
 
     const NODE_MASK: NodeMaskBuilder<'static> = NodeMaskBuilder::new()
         .with_attrs(AttributeMaskBuilder::Some(SORTED_LAYOUT_ATTRS))
+        .with_tag()
         .with_text();
 
     // The layout state should be effected by the shadow dom
@@ -62,29 +283,33 @@ impl State for TaffyLayout {
         let mut changed = false;
         let taffy: &Arc<Mutex<Taffy>> = ctx.get().unwrap();
         let mut taffy = taffy.lock().expect("poisoned taffy");
+        let invalidated = || {
+            if let Some(stats) = ctx.get::<LayoutStatsTracker>() {
+                stats.update(|stats| stats.nodes_invalidated += 1);
+            }
+        };
+        let measurer = ctx.get::<TextMeasurer>().cloned().unwrap_or_default();
         let mut style = Style::default();
         if let Some(text) = node_view.text() {
-            let char_len = text.chars().count();
-
-            style = Style {
-                size: Size {
-                    // characters are 1 point tall
-                    height: Dimension::Points(screen_to_layout_space(1)),
-
-                    // text is as long as it is declared
-                    width: Dimension::Points(screen_to_layout_space(char_len as u16)),
-                },
-                ..Default::default()
-            };
+            // text is sized by the measurer of the renderer
+            let leaf = Some(Leaf::Text(text.to_string()));
             if let PossiblyUninitalized::Initialized(n) = self.node {
-                if self.style != style {
-                    taffy.set_style(n, style.clone()).unwrap();
+                if self.leaf != leaf {
+                    taffy
+                        .set_measure(n, leaf.as_ref().map(|leaf| leaf.measure(&measurer)))
+                        .unwrap();
+                    invalidated();
+                    changed = true;
                 }
             } else {
-                self.node =
-                    PossiblyUninitalized::Initialized(taffy.new_leaf(style.clone()).unwrap());
+                let measure = leaf.as_ref().unwrap().measure(&measurer);
+                self.node = PossiblyUninitalized::Initialized(
+                    taffy.new_leaf_with_measure(style.clone(), measure).unwrap(),
+                );
+                invalidated();
                 changed = true;
             }
+            self.leaf = leaf;
         } else {
             // gather up all the styles from the attribute list
             if let Some(attributes) = node_view.attributes() {
@@ -187,21 +412,49 @@ impl State for TaffyLayout {
                 ..style.clone()
             };
 
+            // elements without children may have an intrinsic size like an image
+            let leaf = child_layout.is_empty().then(|| {
+                Leaf::Element(LeafElement {
+                    tag: node_view.tag().unwrap_or_default().to_string(),
+                    attributes: node_view
+                        .attributes()
+                        .into_iter()
+                        .flatten()
+                        .map(|attr| (attr.attribute.name.clone(), attr.value.to_string()))
+                        .collect(),
+                })
+            });
+
             if let PossiblyUninitalized::Initialized(n) = self.node {
-                if self.style != style {
+                let style_changed = self.style != style;
+                if style_changed {
                     taffy.set_style(n, scaled_style).unwrap();
                 }
-                if taffy.children(n).unwrap() != child_layout {
+                let children_changed = taffy.children(n).unwrap() != child_layout;
+                if children_changed {
                     taffy.set_children(n, &child_layout).unwrap();
                 }
-            } else {
-                self.node = PossiblyUninitalized::Initialized(
+                let leaf_changed = self.leaf != leaf;
+                if leaf_changed {
                     taffy
-                        .new_with_children(scaled_style, &child_layout)
-                        .unwrap(),
-                );
+                        .set_measure(n, leaf.as_ref().map(|leaf| leaf.measure(&measurer)))
+                        .unwrap();
+                }
+                if style_changed || children_changed || leaf_changed {
+                    invalidated();
+                }
+            } else {
+                let node = match &leaf {
+                    Some(leaf) => {
+                        taffy.new_leaf_with_measure(scaled_style, leaf.measure(&measurer))
+                    }
+                    None => taffy.new_with_children(scaled_style, &child_layout),
+                };
+                self.node = PossiblyUninitalized::Initialized(node.unwrap());
+                invalidated();
                 changed = true;
             }
+            self.leaf = leaf;
         }
         if self.style != style {
             changed = true;
@@ -223,11 +476,12 @@ impl State for TaffyLayout {
     }
 }
 
-// these are the attributes in layout_attiributes in native-core
+// these are the attributes in layout_attiributes in native-core and the attributes leaf elements are measured with
 const SORTED_LAYOUT_ATTRS: &[&str] = &[
     "align-content",
     "align-items",
     "align-self",
+    "alt",
     "animation",
     "animation-delay",
     "animation-direction",
@@ -358,6 +612,7 @@ const SORTED_LAYOUT_ATTRS: &[&str] = &[
     "quotes",
     "resize",
     "right",
+    "src",
     "tab-size",
     "table-layout",
     "top",
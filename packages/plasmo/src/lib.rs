@@ -2,47 +2,54 @@
 #![doc(html_logo_url = "https://avatars.githubusercontent.com/u/79236386")]
 #![doc(html_favicon_url = "https://avatars.githubusercontent.com/u/79236386")]
 
-use crate::focus::Focus;
 use anyhow::Result;
+use app::App;
 use crossterm::{
-    cursor::{MoveTo, RestorePosition, SavePosition, Show},
-    event::{DisableMouseCapture, EnableMouseCapture, Event as TermEvent, KeyCode, KeyModifiers},
+    event::{DisableMouseCapture, EnableMouseCapture, Event as TermEvent, KeyEvent},
     execute,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
 use dioxus_native_core::{prelude::*, tree::TreeRef};
-use dioxus_native_core::{real_dom::RealDom, FxDashSet, NodeId, SendAnyMap};
+use dioxus_native_core::{real_dom::RealDom, NodeId};
 use focus::FocusState;
 use futures::{channel::mpsc::UnboundedSender, pin_mut, Future, StreamExt};
-use futures_channel::mpsc::unbounded;
 use layout::TaffyLayout;
-use prevent_default::PreventDefault;
 use ratatui::{backend::CrosstermBackend, Terminal};
-use std::{io, time::Duration};
+use std::{
+    io::{self, Write},
+    time::Duration,
+};
 use std::{
     pin::Pin,
     sync::{Arc, Mutex},
 };
 use std::{rc::Rc, sync::RwLock};
-use style_attributes::StyleModifier;
 pub use taffy::{geometry::Point, prelude::*};
 use tokio::select;
-use widgets::{register_widgets, RinkWidgetResponder, RinkWidgetTraitObject};
+use widgets::{RinkWidgetResponder, RinkWidgetTraitObject};
 
+mod app;
 mod config;
 mod focus;
+mod harness;
 mod hooks;
+mod image;
 mod layout;
+mod linear;
 mod prevent_default;
 pub mod query;
 mod render;
+mod scroll;
 mod style;
 mod style_attributes;
 mod widget;
 mod widgets;
 
 pub use config::*;
+pub use harness::TestHarness;
 pub use hooks::*;
+pub use layout::{CharCountMeasure, LayoutStats, LeafElement, MeasureText};
+pub use linear::OutputMode;
 pub use query::Query;
 
 // the layout space has a multiplier of 10 to minimize rounding errors
@@ -78,6 +85,13 @@ impl TuiContext {
             .unbounded_send(InputEvent::UserInput(event))
             .unwrap();
     }
+
+    /// Switch to an output mode, like the screen reader friendly [`OutputMode::Linear`]
+    pub fn set_output_mode(&self, output_mode: OutputMode) {
+        self.tx
+            .unbounded_send(InputEvent::SetOutputMode(output_mode))
+            .unwrap();
+    }
 }
 
 pub fn render<R: Driver>(
@@ -88,22 +102,22 @@ pub fn render<R: Driver>(
         UnboundedSender<InputEvent>,
     ) -> R,
 ) -> Result<()> {
-    let mut rdom = RealDom::new([
-        TaffyLayout::to_type_erased(),
-        Focus::to_type_erased(),
-        StyleModifier::to_type_erased(),
-        PreventDefault::to_type_erased(),
-    ]);
-
-    // Setup input handling
-
-    // The event channel for fully resolved events
-    let (event_tx, mut event_reciever) = unbounded();
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()?;
+    let cfg = if cfg.headless {
+        cfg
+    } else {
+        cfg.with_graphics_protocol(cfg.graphics_protocol.resolve())
+    };
+    // the driver may spawn tasks when it is created
+    let mut app = {
+        let _guard = runtime.enter();
+        App::new(cfg, create_renderer)
+    };
 
-    // The event channel for raw terminal events
-    let (raw_event_tx, mut raw_event_reciever) = unbounded();
-    let event_tx_clone = raw_event_tx.clone();
     if !cfg.headless {
+        let raw_event_tx = app.raw_event_tx.clone();
         std::thread::spawn(move || {
             // Timeout after 10ms when waiting for events
             let tick_rate = Duration::from_millis(10);
@@ -121,213 +135,137 @@ pub fn render<R: Driver>(
         });
     }
 
-    register_widgets(&mut rdom, event_tx);
-
-    let (handler, mut register_event) = RinkInputHandler::create(&mut rdom);
-
-    let rdom = Arc::new(RwLock::new(rdom));
-    let taffy = Arc::new(Mutex::new(Taffy::new()));
-    let mut renderer = create_renderer(&rdom, &taffy, event_tx_clone);
-
-    // insert the query engine into the rdom
-    let query_engine = Query::new(rdom.clone(), taffy.clone());
-    {
-        let mut rdom = rdom.write().unwrap();
-        rdom.raw_world_mut().add_unique(query_engine);
-    }
-
-    tokio::runtime::Builder::new_current_thread()
-        .enable_all()
-        .build()?
-        .block_on(async {
-            {
-                renderer.update(&rdom);
-                let mut any_map = SendAnyMap::new();
-                any_map.insert(taffy.clone());
-                let mut rdom = rdom.write().unwrap();
-                let _ = rdom.update_state(any_map);
-            }
+    runtime.block_on(async {
+        let mut terminal = (!cfg.headless).then(|| {
+            enable_raw_mode().unwrap();
+            let backend = CrosstermBackend::new(io::stdout());
+            Terminal::new(backend).unwrap()
+        });
+        // the mode the terminal is set up for
+        let mut shown_mode = None;
+
+        loop {
+            /*
+            -> render the nodes in the right place with tui/crossterm
+            -> wait for changes
+            -> resolve events
+            -> lazily update the layout and style based on nodes changed
+            use simd to compare lines for diffing?
+            todo: lazy re-rendering
+            */
 
-            let mut terminal = (!cfg.headless).then(|| {
-                enable_raw_mode().unwrap();
-                let mut stdout = std::io::stdout();
-                execute!(
-                    stdout,
-                    EnterAlternateScreen,
-                    EnableMouseCapture,
-                    MoveTo(0, 1000)
-                )
-                .unwrap();
-                let backend = CrosstermBackend::new(io::stdout());
-                Terminal::new(backend).unwrap()
-            });
             if let Some(terminal) = &mut terminal {
-                terminal.clear().unwrap();
+                if shown_mode != Some(app.output_mode()) {
+                    show_output_mode(terminal, shown_mode, app.output_mode())?;
+                    shown_mode = Some(app.output_mode());
+                }
             }
 
-            let mut to_rerender = FxDashSet::default();
-            to_rerender.insert(rdom.read().unwrap().root_id());
-            let mut updated = true;
-
-            loop {
-                /*
-                -> render the nodes in the right place with tui/crossterm
-                -> wait for changes
-                -> resolve events
-                -> lazily update the layout and style based on nodes changed
-                use simd to compare lines for diffing?
-                todo: lazy re-rendering
-                */
-
-                if !to_rerender.is_empty() || updated {
-                    updated = false;
-                    fn resize(dims: ratatui::layout::Rect, taffy: &mut Taffy, rdom: &RealDom) {
-                        let width = screen_to_layout_space(dims.width);
-                        let height = screen_to_layout_space(dims.height);
-                        let root_node = rdom
-                            .get(rdom.root_id())
-                            .unwrap()
-                            .get::<TaffyLayout>()
-                            .unwrap()
-                            .node
-                            .unwrap();
-
-                        // the root node fills the entire area
-                        let mut style = taffy.style(root_node).unwrap().clone();
-                        let new_size = Size {
-                            width: Dimension::Points(width),
-                            height: Dimension::Points(height),
-                        };
-                        if style.size != new_size {
-                            style.size = new_size;
-                            taffy.set_style(root_node, style).unwrap();
+            if app.needs_redraw() {
+                match (&mut terminal, app.output_mode()) {
+                    (Some(terminal), OutputMode::Visual) => {
+                        terminal.draw(|frame| app.draw(frame))?;
+                        if let Some(images) = app.take_changed_images() {
+                            // images that moved leave pixels behind that the terminal diff doesn't know about
+                            terminal.clear()?;
+                            terminal.draw(|frame| app.draw(frame))?;
+                            image::draw_images(
+                                terminal.backend_mut(),
+                                cfg.graphics_protocol,
+                                &images,
+                            )?;
                         }
-
-                        let size = Size {
-                            width: AvailableSpace::Definite(width),
-                            height: AvailableSpace::Definite(height),
-                        };
-                        taffy.compute_layout(root_node, size).unwrap();
-                    }
-                    if let Some(terminal) = &mut terminal {
-                        execute!(terminal.backend_mut(), SavePosition).unwrap();
-                        terminal.draw(|frame| {
-                            let rdom = rdom.write().unwrap();
-                            let mut taffy = taffy.lock().expect("taffy lock poisoned");
-                            // size is guaranteed to not change when rendering
-                            resize(frame.size(), &mut taffy, &rdom);
-                            let root = rdom.get(rdom.root_id()).unwrap();
-                            render::render_vnode(frame, &taffy, root, cfg, Point::ZERO);
-                        })?;
-                        execute!(terminal.backend_mut(), RestorePosition, Show).unwrap();
-                    } else {
-                        let rdom = rdom.read().unwrap();
-                        resize(
-                            ratatui::layout::Rect {
-                                x: 0,
-                                y: 0,
-                                width: 1000,
-                                height: 1000,
-                            },
-                            &mut taffy.lock().expect("taffy lock poisoned"),
-                            &rdom,
-                        );
                     }
-                }
-
-                let mut event_recieved = None;
-                {
-                    let wait = renderer.poll_async();
-
-                    pin_mut!(wait);
-
-                    select! {
-                        _ = wait => {
-
-                        },
-                        evt = raw_event_reciever.next() => {
-                            match evt.as_ref().unwrap() {
-                                InputEvent::UserInput(event) => match event {
-                                    TermEvent::Key(key) => {
-                                        if matches!(key.code, KeyCode::Char('C' | 'c'))
-                                        && key.modifiers.contains(KeyModifiers::CONTROL)
-                                        && cfg.ctrl_c_quit
-                                        {
-                                            break;
-                                        }
-                                    }
-                                    TermEvent::Resize(_, _) => updated = true,
-                                    _ => {}
-                                },
-                                InputEvent::Close => break,
-                            };
-
-                            if let InputEvent::UserInput(evt) = evt.unwrap() {
-                                register_event(evt);
-                            }
-                        },
-                        Some(evt) = event_reciever.next() => {
-                            event_recieved = Some(evt);
+                    (Some(terminal), OutputMode::Linear) => {
+                        let out = terminal.backend_mut();
+                        for line in app.draw_linear() {
+                            // raw mode doesn't move the cursor to the start of the next line
+                            write!(out, "{line}\r\n")?;
                         }
+                        out.flush()?;
                     }
+                    (None, _) => app.layout_headless(),
                 }
+            }
 
-                {
-                    if let Some(evt) = event_recieved {
-                        renderer.handle_event(
-                            &rdom,
-                            evt.id,
-                            evt.name,
-                            Rc::new(evt.data),
-                            evt.bubbles,
-                        );
-                    }
-                    {
-                        let evts = handler.get_events(
-                            &taffy.lock().expect("taffy lock poisoned"),
-                            &mut rdom.write().unwrap(),
-                        );
-                        updated |= handler.state().focus_state.clean();
-
-                        for e in evts {
-                            bubble_event_to_widgets(&mut rdom.write().unwrap(), &e);
-                            renderer.handle_event(&rdom, e.id, e.name, Rc::new(e.data), e.bubbles);
-                        }
-                    }
-                    // updates the dom's nodes
-                    renderer.update(&rdom);
-                    // update the style and layout
-                    let mut rdom = rdom.write().unwrap();
-                    let mut any_map = SendAnyMap::new();
-                    any_map.insert(taffy.clone());
-                    let (new_to_rerender, dirty) = rdom.update_state(any_map);
-                    to_rerender = new_to_rerender;
-                    let text_mask = NodeMaskBuilder::new().with_text().build();
-                    for (id, mask) in dirty {
-                        if mask.overlaps(&text_mask) {
-                            to_rerender.insert(id);
-                        }
+            let mut event_recieved = None;
+            let mut input = None;
+            {
+                let wait = app.driver.poll_async();
+
+                pin_mut!(wait);
+
+                select! {
+                    _ = wait => {
+
+                    },
+                    evt = app.raw_event_reciever.next() => {
+                        input = evt;
+                    },
+                    Some(evt) = app.event_reciever.next() => {
+                        event_recieved = Some(evt);
                     }
                 }
             }
+            if let Some(input) = input {
+                if !app.handle_input(input) {
+                    break;
+                }
+            }
 
-            if let Some(terminal) = &mut terminal {
-                disable_raw_mode()?;
+            app.update(event_recieved);
+        }
+
+        if let Some(terminal) = &mut terminal {
+            if shown_mode == Some(OutputMode::Visual) {
                 execute!(
                     terminal.backend_mut(),
                     LeaveAlternateScreen,
                     DisableMouseCapture
                 )?;
-                terminal.show_cursor()?;
             }
+            disable_raw_mode()?;
+            terminal.show_cursor()?;
+        }
 
-            Ok(())
-        })
+        Ok(())
+    })
+}
+
+/// Set up the terminal for an output mode. The visual output is drawn in the alternate screen and captures the mouse,
+/// the linear output is printed to the main screen so the screen reader reads it like any other program.
+fn show_output_mode(
+    terminal: &mut Terminal<CrosstermBackend<io::Stdout>>,
+    old: Option<OutputMode>,
+    new: OutputMode,
+) -> Result<()> {
+    match new {
+        OutputMode::Visual => {
+            execute!(
+                terminal.backend_mut(),
+                EnterAlternateScreen,
+                EnableMouseCapture
+            )?;
+            terminal.clear()?;
+        }
+        OutputMode::Linear => {
+            if old == Some(OutputMode::Visual) {
+                execute!(
+                    terminal.backend_mut(),
+                    LeaveAlternateScreen,
+                    DisableMouseCapture
+                )?;
+            }
+            terminal.show_cursor()?;
+        }
+    }
+    Ok(())
 }
 
 #[derive(Debug)]
 pub enum InputEvent {
     UserInput(TermEvent),
+    SetOutputMode(OutputMode),
     Close,
 }
 
@@ -342,6 +280,45 @@ pub trait Driver {
         bubbles: bool,
     );
     fn poll_async(&mut self) -> Pin<Box<dyn Future<Output = ()> + '_>>;
+
+    /// Handle a key press before it is sent to the focused element, like a keyboard shortcut of the app. Returns true if the key was handled and should not be sent to the element.
+    fn handle_key(&mut self, _event: &KeyEvent) -> bool {
+        false
+    }
+
+    /// The [`MeasureText`] used to size text and other leaf nodes during layout. Defaults to [`CharCountMeasure`].
+    fn text_measurer(&self) -> Arc<dyn MeasureText> {
+        Arc::new(CharCountMeasure)
+    }
+}
+
+impl<D: Driver + ?Sized> Driver for Box<D> {
+    fn update(&mut self, rdom: &Arc<RwLock<RealDom>>) {
+        (**self).update(rdom)
+    }
+
+    fn handle_event(
+        &mut self,
+        rdom: &Arc<RwLock<RealDom>>,
+        id: NodeId,
+        event: &str,
+        value: Rc<EventData>,
+        bubbles: bool,
+    ) {
+        (**self).handle_event(rdom, id, event, value, bubbles)
+    }
+
+    fn poll_async(&mut self) -> Pin<Box<dyn Future<Output = ()> + '_>> {
+        (**self).poll_async()
+    }
+
+    fn handle_key(&mut self, event: &KeyEvent) -> bool {
+        (**self).handle_key(event)
+    }
+
+    fn text_measurer(&self) -> Arc<dyn MeasureText> {
+        (**self).text_measurer()
+    }
 }
 
 /// Before sending the event to drivers, we need to bubble it up the tree to any widgets that are listening
@@ -389,8 +366,9 @@ pub(crate) fn get_abs_layout(node: NodeRef, taffy: &Taffy) -> Layout {
         let parent_layout = taffy
             .layout(parent.get::<TaffyLayout>().unwrap().node.unwrap())
             .unwrap();
-        node_layout.location.x += parent_layout.location.x;
-        node_layout.location.y += parent_layout.location.y;
+        let scroll_offset = scroll::scroll_offset(&parent, taffy);
+        node_layout.location.x += parent_layout.location.x - scroll_offset.x;
+        node_layout.location.y += parent_layout.location.y - scroll_offset.y;
     }
     node_layout
 }
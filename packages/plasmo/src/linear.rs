@@ -0,0 +1,281 @@
+//! A linear text output of the app for terminal screen readers.
+//!
+//! Screen readers read the text a terminal app prints, but the cells of a two dimensional layout are read row by row,
+//! which mixes up the text of elements that are next to each other. In [`OutputMode::Linear`] the app is printed as
+//! lines of text in the order of the document instead, with the role of controls in front of their label.
+
+use dioxus_native_core::{
+    node::{OwnedAttributeDiscription, OwnedAttributeValue},
+    prelude::*,
+    tree::TreeRef,
+};
+use rustc_hash::FxHashMap;
+
+/// How the app is shown in the terminal
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum OutputMode {
+    /// Draw the layout of the app in the alternate screen
+    #[default]
+    Visual,
+    /// Print the content of the app as lines of text in the order of the document, for terminal screen readers.
+    ///
+    /// When the app changes, only the lines that changed are printed. When the focus moves, the focused element is
+    /// announced. The mouse is not captured in this mode.
+    Linear,
+}
+
+impl OutputMode {
+    /// Switch between the visual and the linear output
+    pub fn toggled(self) -> Self {
+        match self {
+            Self::Visual => Self::Linear,
+            Self::Linear => Self::Visual,
+        }
+    }
+}
+
+/// Elements that continue the line of their parent instead of starting a new one
+const INLINE_TAGS: &[&str] = &[
+    "span", "b", "strong", "i", "em", "u", "s", "small", "code", "kbd", "label", "abbr", "mark",
+    "sub", "sup", "time",
+];
+
+/// Roles that are read as a single control with a label
+const CONTROL_ROLES: &[&str] = &[
+    "button", "link", "checkbox", "radio", "switch", "tab", "menuitem", "option", "slider",
+];
+
+/// The lines printed in linear mode and the element that had focus when they were printed
+#[derive(Default)]
+pub(crate) struct LinearOutput {
+    lines: Vec<String>,
+    focused: Option<NodeId>,
+}
+
+impl LinearOutput {
+    /// Returns the lines to print since the last update: the lines that are new or changed, and the element that
+    /// gained focus
+    pub(crate) fn update(&mut self, rdom: &RealDom, focused: Option<NodeId>) -> Vec<String> {
+        let lines = linearize(rdom);
+
+        // print the lines that were not printed before. Lines that moved are not printed again
+        let mut previous: FxHashMap<&str, usize> = FxHashMap::default();
+        for line in &self.lines {
+            *previous.entry(line.as_str()).or_default() += 1;
+        }
+        let mut changed = Vec::new();
+        for line in &lines {
+            match previous.get_mut(line.as_str()) {
+                Some(count) if *count > 0 => *count -= 1,
+                _ => changed.push(line.clone()),
+            }
+        }
+
+        if focused != self.focused {
+            self.focused = focused;
+            let description = focused
+                .and_then(|id| rdom.get(id))
+                .and_then(|node| describe(&node));
+            if let Some(description) = description {
+                changed.push(format!("Focused: {description}"));
+            }
+        }
+
+        self.lines = lines;
+        changed
+    }
+
+    /// Forget what was printed, so the whole app is printed by the next update
+    pub(crate) fn reset(&mut self) {
+        *self = Self::default();
+    }
+}
+
+/// Get the text of the app as lines in the order of the document
+pub(crate) fn linearize(rdom: &RealDom) -> Vec<String> {
+    let mut linearizer = Linearizer {
+        rdom,
+        lines: Vec::new(),
+        current: String::new(),
+    };
+    linearizer.visit(rdom.root_id());
+    linearizer.flush();
+    linearizer.lines
+}
+
+/// Describe a single element, like the element that gained focus
+fn describe(node: &NodeRef) -> Option<String> {
+    let node_type = node.node_type();
+    match &*node_type {
+        NodeType::Element(element) => {
+            control_line(node, element).or_else(|| Some(collapse(&text_content(node))))
+        }
+        NodeType::Text(text) => Some(collapse(&text.text)),
+        NodeType::Placeholder => None,
+    }
+    .filter(|description| !description.is_empty())
+}
+
+struct Linearizer<'a> {
+    rdom: &'a RealDom,
+    lines: Vec<String>,
+    current: String,
+}
+
+impl Linearizer<'_> {
+    fn visit(&mut self, id: NodeId) {
+        let Some(node) = self.rdom.get(id) else {
+            return;
+        };
+        let node_type = node.node_type();
+        match &*node_type {
+            NodeType::Text(text) => self.push_text(&text.text),
+            NodeType::Placeholder => {}
+            NodeType::Element(element) => {
+                if is_hidden(element) {
+                    return;
+                }
+                if let Some(line) = control_line(&node, element) {
+                    self.flush();
+                    self.lines.push(line);
+                    return;
+                }
+
+                let block = !INLINE_TAGS.contains(&element.tag.as_str());
+                if block {
+                    self.flush();
+                }
+                for child in self.rdom.tree_ref().children_ids_advanced(id, true) {
+                    self.visit(child);
+                }
+                if block {
+                    self.flush();
+                }
+            }
+        }
+    }
+
+    fn push_text(&mut self, text: &str) {
+        let text = collapse(text);
+        if text.is_empty() {
+            return;
+        }
+        if !self.current.is_empty() {
+            self.current.push(' ');
+        }
+        self.current.push_str(&text);
+    }
+
+    fn flush(&mut self) {
+        if !self.current.is_empty() {
+            self.lines.push(std::mem::take(&mut self.current));
+        }
+    }
+}
+
+/// The line of an element that is read as a single control, like a button or a heading
+fn control_line(node: &NodeRef, element: &ElementNode) -> Option<String> {
+    let attribute = |name: &str| attribute(element, name);
+    let label = || {
+        attribute("aria-label")
+            .map(|label| collapse(&label))
+            .unwrap_or_else(|| collapse(&text_content(node)))
+    };
+    let with_label = |role: &str, label: String| {
+        if label.is_empty() {
+            format!("[{role}]")
+        } else {
+            format!("[{role}] {label}")
+        }
+    };
+
+    let tag = element.tag.as_str();
+    let line = match tag {
+        "button" => with_label("button", label()),
+        "a" => with_label("link", label()),
+        "img" => with_label("image", attribute("alt").unwrap_or_default()),
+        "textarea" => with_label("text area", value(element)),
+        "input" => {
+            let input_type = attribute("type").unwrap_or_default().trim().to_lowercase();
+            match input_type.as_str() {
+                "button" => with_label("button", value(element)),
+                "checkbox" => {
+                    let checked = attribute("checked").as_deref() == Some("true");
+                    let role = if checked {
+                        "checkbox, checked"
+                    } else {
+                        "checkbox, not checked"
+                    };
+                    with_label(role, attribute("aria-label").unwrap_or_default())
+                }
+                // never read passwords out loud
+                "password" => "[password input]".to_string(),
+                "number" => with_label("number input", value(element)),
+                "range" => with_label("slider", value(element)),
+                _ => with_label("text input", value(element)),
+            }
+        }
+        "h1" | "h2" | "h3" | "h4" | "h5" | "h6" => {
+            with_label(&format!("heading {}", &tag[1..]), label())
+        }
+        _ => {
+            let role = attribute("role").filter(|role| CONTROL_ROLES.contains(&role.as_str()))?;
+            with_label(&role, label())
+        }
+    };
+    Some(line)
+}
+
+fn attribute(element: &ElementNode, name: &str) -> Option<String> {
+    let value = element.attributes.get(&OwnedAttributeDiscription {
+        name: name.to_string(),
+        namespace: None,
+    })?;
+    match value {
+        OwnedAttributeValue::Text(text) => Some(text.clone()),
+        OwnedAttributeValue::Bool(value) => Some(value.to_string()),
+        OwnedAttributeValue::Int(value) => Some(value.to_string()),
+        OwnedAttributeValue::Float(value) => Some(value.to_string()),
+        OwnedAttributeValue::Custom(_) => None,
+    }
+}
+
+fn value(element: &ElementNode) -> String {
+    attribute(element, "value").unwrap_or_default()
+}
+
+fn is_hidden(element: &ElementNode) -> bool {
+    attribute(element, "aria-hidden").as_deref() == Some("true")
+        || attribute(element, "display").as_deref().map(str::trim) == Some("none")
+        || attribute(element, "hidden").is_some_and(|hidden| hidden != "false")
+}
+
+/// The text of all of the children of a node
+fn text_content(node: &NodeRef) -> String {
+    let mut text = String::new();
+    let rdom = node.real_dom();
+    let mut stack = vec![node.id()];
+    while let Some(id) = stack.pop() {
+        let Some(node) = rdom.get(id) else {
+            continue;
+        };
+        let node_type = node.node_type();
+        match &*node_type {
+            NodeType::Text(node_text) => {
+                text.push(' ');
+                text.push_str(&node_text.text);
+            }
+            NodeType::Element(element) if is_hidden(element) => {}
+            _ => {
+                let children = rdom.tree_ref().children_ids_advanced(id, true);
+                stack.extend(children.into_iter().rev());
+            }
+        }
+    }
+    text
+}
+
+/// Collapse runs of whitespace into a single space, like the browser does
+fn collapse(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
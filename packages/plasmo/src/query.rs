@@ -1,14 +1,19 @@
 use std::sync::{Arc, Mutex, MutexGuard, RwLock, RwLockReadGuard};
 
 use dioxus_native_core::prelude::*;
-use shipyard::Unique;
+use shipyard::{Unique, UniqueView, UniqueViewMut};
 use taffy::{
     geometry::Point,
     prelude::{Layout, Size},
     Taffy,
 };
 
-use crate::{get_abs_layout, layout_to_screen_space};
+use crate::{
+    focus::{Focus, FocusRequest},
+    get_abs_layout,
+    layout::{LayoutStats, LayoutStatsTracker},
+    layout_to_screen_space, scroll, unit_to_layout_space,
+};
 
 /// Allows querying the layout of nodes after rendering. It will only provide a correct value after a node is rendered.
 /// Provided as a root context for all tui applictions.
@@ -61,6 +66,52 @@ impl Query {
             id,
         )
     }
+
+    /// Scroll a node with scrollable overflow to an offset in screen space. Returns true if the node moved
+    pub fn scroll_to(&self, id: NodeId, offset: Point<f32>) -> bool {
+        let mut rdom = self.rdom.write().expect("rdom lock poisoned");
+        let stretch = self.stretch.lock().expect("taffy lock poisoned");
+        let offset = Point {
+            x: unit_to_layout_space(offset.x),
+            y: unit_to_layout_space(offset.y),
+        };
+        scroll::scroll_to(&mut rdom, &stretch, id, offset)
+    }
+
+    /// Scroll the ancestors of a node with scrollable overflow until the node is at the top of each ancestor. Returns true if any ancestor moved
+    pub fn scroll_into_view(&self, id: NodeId) -> bool {
+        let mut rdom = self.rdom.write().expect("rdom lock poisoned");
+        let stretch = self.stretch.lock().expect("taffy lock poisoned");
+        scroll::scroll_into_view(&mut rdom, &stretch, id)
+    }
+
+    /// Focus or blur a node. The focus moves and the focus events are sent the next time the app updates. Returns false if the node cannot be focused
+    pub fn set_focus(&self, id: NodeId, focus: bool) -> bool {
+        let rdom = self.rdom.read().expect("rdom lock poisoned");
+        let Some(node) = rdom.get(id) else {
+            return false;
+        };
+        let focusable = node
+            .get::<Focus>()
+            .is_some_and(|focus| focus.level.focusable());
+        if focus && !focusable {
+            return false;
+        }
+        let Ok(mut request) = rdom.raw_world().borrow::<UniqueViewMut<FocusRequest>>() else {
+            return false;
+        };
+        request.0 = Some((id, focus));
+        true
+    }
+
+    /// Get statistics about how often the layout was recomputed or reused from the cache
+    pub fn layout_stats(&self) -> LayoutStats {
+        let rdom = self.rdom.read().expect("rdom lock poisoned");
+        rdom.raw_world()
+            .borrow::<UniqueView<LayoutStatsTracker>>()
+            .map(|stats| stats.get())
+            .unwrap_or_default()
+    }
 }
 
 pub struct ElementRef<'a> {
@@ -92,6 +143,21 @@ impl<'a> ElementRef<'a> {
     pub fn layout(&self) -> Option<Layout> {
         get_layout(self.inner.get(self.id).unwrap(), &self.stretch)
     }
+
+    /// The distance the node is scrolled in screen space
+    pub fn scroll_offset(&self) -> Point<f32> {
+        let offset = scroll::scroll_offset(&self.inner.get(self.id).unwrap(), &self.stretch);
+        Point {
+            x: layout_to_screen_space(offset.x),
+            y: layout_to_screen_space(offset.y),
+        }
+    }
+
+    /// The size of the content of the node in screen space, including the parts that are scrolled out of view
+    pub fn scroll_size(&self) -> Size<f32> {
+        let size = scroll::scroll_size(&self.inner.get(self.id).unwrap(), &self.stretch);
+        size.map(layout_to_screen_space)
+    }
 }
 
 pub(crate) fn get_layout(node: NodeRef, stretch: &Taffy) -> Option<Layout> {
@@ -1,4 +1,4 @@
-use dioxus_native_core::{prelude::*, tree::TreeRef};
+use dioxus_native_core::{node::OwnedAttributeDiscription, prelude::*, tree::TreeRef};
 use ratatui::{layout::Rect, style::Color};
 use taffy::{
     geometry::Point,
@@ -8,11 +8,12 @@ use taffy::{
 
 use crate::{
     focus::Focused,
+    image::{self, ImagePlacement},
     layout::TaffyLayout,
-    layout_to_screen_space,
+    layout_to_screen_space, scroll,
     style::{RinkColor, RinkStyle},
     style_attributes::{BorderEdge, BorderStyle, StyleModifier},
-    widget::{RinkBuffer, RinkCell, RinkWidget, WidgetWithContext},
+    widget::{HalfBlockImage, RinkBuffer, RinkCell, RinkWidget, WidgetWithContext},
     Config,
 };
 
@@ -24,6 +25,8 @@ pub(crate) fn render_vnode(
     node: NodeRef,
     cfg: Config,
     parent_location: Point<f32>,
+    clip: taffy::geometry::Rect<f32>,
+    images: &mut Vec<ImagePlacement>,
 ) {
     if let NodeType::Placeholder = &*node.node_type() {
         return;
@@ -37,12 +40,13 @@ pub(crate) fn render_vnode(
     location.x += parent_location.x;
     location.y += parent_location.y;
 
+    // nodes inside of scrolled elements may be partially off screen
     let Point { x: fx, y: fy } = location;
-    let x = layout_to_screen_space(fx).round() as u16;
-    let y = layout_to_screen_space(fy).round() as u16;
+    let x = layout_to_screen_space(fx).round() as i32;
+    let y = layout_to_screen_space(fy).round() as i32;
     let Size { width, height } = *size;
-    let width = layout_to_screen_space(fx + width).round() as u16 - x;
-    let height = layout_to_screen_space(fy + height).round() as u16 - y;
+    let width = (layout_to_screen_space(fx + width).round() as i32 - x).max(0) as u16;
+    let height = (layout_to_screen_space(fy + height).round() as i32 - y).max(0) as u16;
 
     match &*node.node_type() {
         NodeType::Text(text) => {
@@ -67,32 +71,106 @@ pub(crate) fn render_vnode(
                 text: &text.text,
                 style: node.get::<StyleModifier>().unwrap().core,
             };
-            let area = Rect::new(x, y, width, height);
 
-            // the renderer will panic if a node is rendered out of range even if the size is zero
-            if area.width > 0 && area.height > 0 {
-                frame.render_widget(WidgetWithContext::new(label, cfg), area);
+            if width > 0 && height > 0 {
+                // text is allowed to spill out of the text node, but not out of the clip area
+                let text_width = width.max(text.text.len() as u16);
+                let area = visible_area(x, y, text_width, height, clip, frame.size());
+                if area.area() > 0 {
+                    frame.render_widget(
+                        WidgetWithContext::new(label, cfg, (x, y), (text_width, height)),
+                        area,
+                    );
+                }
             }
         }
-        NodeType::Element { .. } => {
-            let area = Rect::new(x, y, width, height);
-
-            // the renderer will panic if a node is rendered out of range even if the size is zero
-            if area.width > 0 && area.height > 0 {
-                frame.render_widget(WidgetWithContext::new(node, cfg), area);
+        NodeType::Element(element) => {
+            let area = visible_area(x, y, width, height, clip, frame.size());
+
+            if area.area() > 0 {
+                frame.render_widget(
+                    WidgetWithContext::new(node, cfg, (x, y), (width, height)),
+                    area,
+                );
+
+                let src = (element.tag == "img")
+                    .then(|| {
+                        element.attributes.get(&OwnedAttributeDiscription {
+                            name: "src".to_string(),
+                            namespace: None,
+                        })
+                    })
+                    .flatten()
+                    .and_then(|src| src.as_text());
+                if let Some((src, image)) = src.and_then(|src| Some((src, image::load(src)?))) {
+                    // graphics can't be cut off, so images that are partially hidden are drawn with half blocks
+                    let fully_visible = area.x as i32 == x
+                        && area.y as i32 == y
+                        && area.width == width
+                        && area.height == height;
+                    if cfg.graphics_protocol.draws_graphics() && fully_visible {
+                        images.push(ImagePlacement {
+                            src: src.to_string(),
+                            area,
+                        });
+                    } else {
+                        frame.render_widget(
+                            WidgetWithContext::new(
+                                HalfBlockImage { image: &image },
+                                cfg,
+                                (x, y),
+                                (width, height),
+                            ),
+                            area,
+                        );
+                    }
+                }
             }
 
+            // the children of elements with overflow are scrolled and clipped to the element
+            let (child_location, child_clip) =
+                scroll::child_viewport(&node, layout, location, clip);
             let node_id = node.id();
             let rdom = node.real_dom();
             for child_id in rdom.tree_ref().children_ids_advanced(node_id, true) {
                 let c = rdom.get(child_id).unwrap();
-                render_vnode(frame, layout, c, cfg, location);
+                render_vnode(frame, layout, c, cfg, child_location, child_clip, images);
             }
         }
         NodeType::Placeholder => unreachable!(),
     }
 }
 
+/// Get the part of an area in screen space that is inside of the clip area in layout space and the frame
+fn visible_area(
+    x: i32,
+    y: i32,
+    width: u16,
+    height: u16,
+    clip: taffy::geometry::Rect<f32>,
+    frame: Rect,
+) -> Rect {
+    // an unclipped side is infinite, which saturates when it is cast
+    let to_screen = |layout: f32| layout_to_screen_space(layout).round() as i32;
+    let left = x.max(to_screen(clip.left)).max(frame.left() as i32);
+    let top = y.max(to_screen(clip.top)).max(frame.top() as i32);
+    let right = (x + width as i32)
+        .min(to_screen(clip.right))
+        .min(frame.right() as i32);
+    let bottom = (y + height as i32)
+        .min(to_screen(clip.bottom))
+        .min(frame.bottom() as i32);
+    if right <= left || bottom <= top {
+        return Rect::default();
+    }
+    Rect {
+        x: left as u16,
+        y: top as u16,
+        width: (right - left) as u16,
+        height: (bottom - top) as u16,
+    }
+}
+
 impl RinkWidget for NodeRef<'_> {
     fn render(self, area: Rect, mut buf: RinkBuffer<'_>) {
         use ratatui::symbols::line::*;
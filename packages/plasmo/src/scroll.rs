@@ -0,0 +1,420 @@
+//! Scrolling for elements with scrollable overflow (`overflow: scroll` or `overflow: auto`)
+
+use dioxus_native_core::{prelude::*, real_dom::NodeImmutable, tree::TreeRef};
+use shipyard::{Component, Unique, UniqueViewMut};
+use taffy::{
+    geometry::{Point, Rect},
+    prelude::{LengthPercentage, Size},
+    Taffy,
+};
+
+use crate::{
+    get_abs_layout,
+    layout::{PossiblyUninitalized, TaffyLayout},
+    style_attributes::{Overflow, StyleModifier},
+};
+
+/// The distance an element is scrolled in layout space
+#[derive(Debug, Clone, Copy, Default, PartialEq, Component)]
+pub(crate) struct ScrollOffset {
+    x: f32,
+    y: f32,
+}
+
+/// The nodes that were scrolled since the scroll events were last resolved
+#[derive(Debug, Default, Unique)]
+pub(crate) struct ScrolledNodes(pub(crate) Vec<NodeId>);
+
+/// The overflow of a node on the x and y axis
+pub(crate) fn overflow(node: &NodeRef) -> [Overflow; 2] {
+    node.get::<StyleModifier>()
+        .map(|style| style.modifier.overflow())
+        .unwrap_or_default()
+}
+
+/// The sizes of a node that limit how far it can be scrolled in layout space
+struct ScrollArea {
+    /// The size of the node
+    size: Size<f32>,
+    /// The width of the borders of the node
+    border: Rect<f32>,
+    /// The furthest extent of the children of the node, relative to the node
+    content: Size<f32>,
+}
+
+impl ScrollArea {
+    fn new(node: &NodeRef, taffy: &Taffy) -> Self {
+        let layout = node.get::<TaffyLayout>().unwrap();
+        let size = taffy.layout(layout.node.unwrap()).unwrap().size;
+        // css does not support percentage border widths
+        let resolve = |length| match length {
+            LengthPercentage::Points(points) => points,
+            LengthPercentage::Percent(_) => 0.0,
+        };
+        let border = Rect {
+            left: resolve(layout.style.border.left),
+            right: resolve(layout.style.border.right),
+            top: resolve(layout.style.border.top),
+            bottom: resolve(layout.style.border.bottom),
+        };
+
+        let mut content = Size::ZERO;
+        let rdom = node.real_dom();
+        for child in rdom.tree_ref().children_ids_advanced(node.id(), true) {
+            let child = rdom.get(child).unwrap();
+            let Some(child_layout) = child.get::<TaffyLayout>() else {
+                continue;
+            };
+            let PossiblyUninitalized::Initialized(child_node) = child_layout.node else {
+                continue;
+            };
+            let child_layout = taffy.layout(child_node).unwrap();
+            content.width = content
+                .width
+                .max(child_layout.location.x + child_layout.size.width);
+            content.height = content
+                .height
+                .max(child_layout.location.y + child_layout.size.height);
+        }
+
+        Self {
+            size,
+            border,
+            content,
+        }
+    }
+
+    /// The area inside the borders of the node, relative to the node
+    fn viewport(&self) -> Rect<f32> {
+        Rect {
+            left: self.border.left,
+            right: self.size.width - self.border.right,
+            top: self.border.top,
+            bottom: self.size.height - self.border.bottom,
+        }
+    }
+
+    fn max_offset(&self) -> Point<f32> {
+        let viewport = self.viewport();
+        Point {
+            x: (self.content.width - viewport.right).max(0.0),
+            y: (self.content.height - viewport.bottom).max(0.0),
+        }
+    }
+}
+
+/// Get the distance a node is scrolled in layout space. Nodes that cannot be scrolled always have an offset of zero
+pub(crate) fn scroll_offset(node: &NodeRef, taffy: &Taffy) -> Point<f32> {
+    let [overflow_x, overflow_y] = overflow(node);
+    if !overflow_x.scrolls() && !overflow_y.scrolls() {
+        return Point::ZERO;
+    }
+    let Some(offset) = node.get::<ScrollOffset>().map(|offset| *offset) else {
+        return Point::ZERO;
+    };
+    // the content may have shrunk since the node was scrolled
+    let max = ScrollArea::new(node, taffy).max_offset();
+    Point {
+        x: clamp_axis(overflow_x, offset.x, max.x),
+        y: clamp_axis(overflow_y, offset.y, max.y),
+    }
+}
+
+fn clamp_axis(overflow: Overflow, offset: f32, max: f32) -> f32 {
+    if overflow.scrolls() {
+        offset.clamp(0.0, max)
+    } else {
+        0.0
+    }
+}
+
+/// Scroll a node by a distance in layout space. Returns true if the node moved
+pub(crate) fn scroll_by(rdom: &mut RealDom, taffy: &Taffy, id: NodeId, delta: Point<f32>) -> bool {
+    let Some(node) = rdom.get(id) else {
+        return false;
+    };
+    let [overflow_x, overflow_y] = overflow(&node);
+    if !overflow_x.scrolls() && !overflow_y.scrolls() {
+        return false;
+    }
+    let old = scroll_offset(&node, taffy);
+    let max = ScrollArea::new(&node, taffy).max_offset();
+    let new = Point {
+        x: clamp_axis(overflow_x, old.x + delta.x, max.x),
+        y: clamp_axis(overflow_y, old.y + delta.y, max.y),
+    };
+    if new == old {
+        return false;
+    }
+    rdom.get_mut(id)
+        .unwrap()
+        .insert(ScrollOffset { x: new.x, y: new.y });
+    if let Ok(mut scrolled) = rdom.raw_world().borrow::<UniqueViewMut<ScrolledNodes>>() {
+        scrolled.0.push(id);
+    }
+    true
+}
+
+/// Scroll a node to an offset in layout space. Returns true if the node moved
+pub(crate) fn scroll_to(rdom: &mut RealDom, taffy: &Taffy, id: NodeId, offset: Point<f32>) -> bool {
+    let Some(node) = rdom.get(id) else {
+        return false;
+    };
+    let old = scroll_offset(&node, taffy);
+    let delta = Point {
+        x: offset.x - old.x,
+        y: offset.y - old.y,
+    };
+    scroll_by(rdom, taffy, id, delta)
+}
+
+/// Scroll the ancestors of a node so the top left corner of the node is at the top left corner of each scrollable ancestor, like `scrollIntoView` on the web. Returns true if any ancestor moved
+pub(crate) fn scroll_into_view(rdom: &mut RealDom, taffy: &Taffy, id: NodeId) -> bool {
+    let mut moved = false;
+    let mut current = id;
+    loop {
+        let Some(parent) = rdom.tree_ref().parent_id_advanced(current, true) else {
+            return moved;
+        };
+        current = parent;
+        let Some(node) = rdom.get(id) else {
+            return moved;
+        };
+        let container = rdom.get(parent).unwrap();
+        let [overflow_x, overflow_y] = overflow(&container);
+        if !overflow_x.scrolls() && !overflow_y.scrolls() {
+            continue;
+        }
+        // scrolling an inner ancestor moves the node, so the position is read again for every ancestor
+        let target = get_abs_layout(node, taffy).location;
+        let viewport = ScrollArea::new(&container, taffy).viewport();
+        let location = get_abs_layout(container, taffy).location;
+        let delta = Point {
+            x: target.x - (location.x + viewport.left),
+            y: target.y - (location.y + viewport.top),
+        };
+        moved |= scroll_by(rdom, taffy, parent, delta);
+    }
+}
+
+/// An area that does not clip anything
+pub(crate) const UNCLIPPED: Rect<f32> = Rect {
+    left: f32::NEG_INFINITY,
+    right: f32::INFINITY,
+    top: f32::NEG_INFINITY,
+    bottom: f32::INFINITY,
+};
+
+/// Get the location the children of a node are offset from and the area they are visible in. The location of the node and the clip area of the node are in absolute layout space.
+pub(crate) fn child_viewport(
+    node: &NodeRef,
+    taffy: &Taffy,
+    location: Point<f32>,
+    clip: Rect<f32>,
+) -> (Point<f32>, Rect<f32>) {
+    let [overflow_x, overflow_y] = overflow(node);
+    if !overflow_x.clips() && !overflow_y.clips() {
+        return (location, clip);
+    }
+    let viewport = ScrollArea::new(node, taffy).viewport();
+    let mut child_clip = clip;
+    if overflow_x.clips() {
+        child_clip.left = clip.left.max(location.x + viewport.left);
+        child_clip.right = clip.right.min(location.x + viewport.right);
+    }
+    if overflow_y.clips() {
+        child_clip.top = clip.top.max(location.y + viewport.top);
+        child_clip.bottom = clip.bottom.min(location.y + viewport.bottom);
+    }
+    let offset = scroll_offset(node, taffy);
+    let child_location = Point {
+        x: location.x - offset.x,
+        y: location.y - offset.y,
+    };
+    (child_location, child_clip)
+}
+
+/// The size of the area inside the borders of a node in layout space
+pub(crate) fn viewport_size(node: &NodeRef, taffy: &Taffy) -> Size<f32> {
+    let viewport = ScrollArea::new(node, taffy).viewport();
+    Size {
+        width: (viewport.right - viewport.left).max(0.0),
+        height: (viewport.bottom - viewport.top).max(0.0),
+    }
+}
+
+/// The size of the content of a node in layout space, including the parts that are scrolled out of view
+pub(crate) fn scroll_size(node: &NodeRef, taffy: &Taffy) -> Size<f32> {
+    let area = ScrollArea::new(node, taffy);
+    let viewport = viewport_size(node, taffy);
+    let max = area.max_offset();
+    Size {
+        width: viewport.width + max.x,
+        height: viewport.height + max.y,
+    }
+}
+
+/// Find the deepest node at a point in layout space. The parts of nodes that are clipped by an ancestor are ignored.
+pub(crate) fn node_at(rdom: &RealDom, taffy: &Taffy, point: Point<f32>) -> Option<NodeId> {
+    fn visit(
+        node: NodeRef,
+        taffy: &Taffy,
+        parent_location: Point<f32>,
+        clip: Rect<f32>,
+        point: Point<f32>,
+        hit: &mut Option<NodeId>,
+    ) {
+        let Some(layout) = node.get::<TaffyLayout>() else {
+            return;
+        };
+        let PossiblyUninitalized::Initialized(taffy_node) = layout.node else {
+            return;
+        };
+        let layout = taffy.layout(taffy_node).unwrap();
+        let location = Point {
+            x: parent_location.x + layout.location.x,
+            y: parent_location.y + layout.location.y,
+        };
+        if point.x >= location.x.max(clip.left)
+            && point.x < (location.x + layout.size.width).min(clip.right)
+            && point.y >= location.y.max(clip.top)
+            && point.y < (location.y + layout.size.height).min(clip.bottom)
+        {
+            *hit = Some(node.id());
+        }
+
+        let (child_location, child_clip) = child_viewport(&node, taffy, location, clip);
+        let rdom = node.real_dom();
+        for child in rdom.tree_ref().children_ids_advanced(node.id(), true) {
+            visit(
+                rdom.get(child).unwrap(),
+                taffy,
+                child_location,
+                child_clip,
+                point,
+                hit,
+            );
+        }
+    }
+
+    let mut hit = None;
+    visit(
+        rdom.get(rdom.root_id()).unwrap(),
+        taffy,
+        Point::ZERO,
+        UNCLIPPED,
+        point,
+        &mut hit,
+    );
+    hit
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use dioxus_native_core::node::{ElementNode, OwnedAttributeValue};
+
+    use super::*;
+    use crate::{
+        layout::{compute_layout, LayoutStatsTracker},
+        screen_to_layout_space,
+    };
+
+    fn div(rdom: &mut RealDom, parent: NodeId, attributes: &[(&str, &str)]) -> NodeId {
+        let mut element = ElementNode::new("div", None);
+        for (name, value) in attributes {
+            element.attributes.insert(
+                name.to_string().into(),
+                OwnedAttributeValue::Text(value.to_string()),
+            );
+        }
+        let id = rdom.create_node(NodeType::Element(element)).id();
+        rdom.get_mut(parent).unwrap().add_child(id);
+        id
+    }
+
+    #[test]
+    fn scroll_containers_clamp_and_clip() {
+        let mut rdom = RealDom::new([
+            TaffyLayout::to_type_erased(),
+            StyleModifier::to_type_erased(),
+        ]);
+        let root = rdom.root_id();
+        let column = div(
+            &mut rdom,
+            root,
+            &[
+                ("display", "flex"),
+                ("flex-direction", "column"),
+                ("width", "5px"),
+            ],
+        );
+        let list = div(
+            &mut rdom,
+            column,
+            &[
+                ("display", "flex"),
+                ("flex-direction", "column"),
+                ("height", "3px"),
+                ("overflow-y", "scroll"),
+            ],
+        );
+        let items: Vec<_> = (0..10)
+            .map(|_| div(&mut rdom, list, &[("height", "1px"), ("flex-shrink", "0")]))
+            .collect();
+        let below = div(
+            &mut rdom,
+            column,
+            &[("height", "1px"), ("flex-shrink", "0")],
+        );
+
+        let taffy = Arc::new(Mutex::new(Taffy::new()));
+        let mut ctx = SendAnyMap::new();
+        ctx.insert(taffy.clone());
+        rdom.update_state(ctx);
+        let mut taffy = taffy.lock().unwrap();
+        let screen = ratatui::layout::Rect::new(0, 0, 10, 10);
+        compute_layout(screen, &mut taffy, &rdom, &LayoutStatsTracker::default());
+
+        let cell = |y: u16| Point {
+            x: screen_to_layout_space(0) + 5.0,
+            y: screen_to_layout_space(y) + 5.0,
+        };
+        assert_eq!(node_at(&rdom, &taffy, cell(0)), Some(items[0]));
+        // the fourth item overflows the list, so the node under it is hit instead
+        assert_eq!(node_at(&rdom, &taffy, cell(3)), Some(below));
+
+        // the list can only be scrolled until the last item is visible
+        assert!(scroll_by(
+            &mut rdom,
+            &taffy,
+            list,
+            Point { x: 0.0, y: 1000.0 }
+        ));
+        let offset = scroll_offset(&rdom.get(list).unwrap(), &taffy);
+        assert_eq!(
+            offset,
+            Point {
+                x: 0.0,
+                y: screen_to_layout_space(7)
+            }
+        );
+        assert!(!scroll_by(
+            &mut rdom,
+            &taffy,
+            list,
+            Point { x: 0.0, y: 10.0 }
+        ));
+        assert_eq!(node_at(&rdom, &taffy, cell(0)), Some(items[7]));
+        assert_eq!(node_at(&rdom, &taffy, cell(2)), Some(items[9]));
+
+        // elements without scrollable overflow never move
+        assert!(!scroll_by(
+            &mut rdom,
+            &taffy,
+            root,
+            Point { x: 0.0, y: 10.0 }
+        ));
+    }
+}
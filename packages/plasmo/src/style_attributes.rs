@@ -9,7 +9,7 @@
 - [x] pub flex_shrink: f32,
 - [x] pub flex_basis: Dimension,
 
-- [x] pub overflow: Overflow, ---> kinda implemented... taffy doesnt have support for directional overflow
+- [x] pub overflow: Overflow, ---> handled by the renderer, taffy doesnt have support for overflow
 
 - [x] pub align_items: AlignItems,
 - [x] pub align_self: AlignSelf,
@@ -132,6 +132,51 @@ impl State for StyleModifier {
 #[derive(Default, Clone, PartialEq, Debug)]
 pub struct TuiModifier {
     pub borders: Borders,
+    pub overflow_x: Overflow,
+    pub overflow_y: Overflow,
+}
+
+impl TuiModifier {
+    /// The overflow on the x and y axis. Like in css, a visible axis is treated as auto if the other axis is not visible
+    pub fn overflow(&self) -> [Overflow; 2] {
+        match [self.overflow_x, self.overflow_y] {
+            [Overflow::Visible, Overflow::Visible] => [Overflow::Visible; 2],
+            [Overflow::Visible, y] => [Overflow::Auto, y],
+            [x, Overflow::Visible] => [x, Overflow::Auto],
+            overflow => overflow,
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum Overflow {
+    #[default]
+    Visible,
+    Hidden,
+    Scroll,
+    Auto,
+}
+
+impl Overflow {
+    fn parse(value: &str) -> Option<Self> {
+        match value {
+            "visible" => Some(Overflow::Visible),
+            "hidden" | "clip" => Some(Overflow::Hidden),
+            "scroll" => Some(Overflow::Scroll),
+            "auto" => Some(Overflow::Auto),
+            _ => None,
+        }
+    }
+
+    /// If content that overflows the element is hidden
+    pub fn clips(&self) -> bool {
+        *self != Overflow::Visible
+    }
+
+    /// If the user can scroll to content that overflows the element
+    pub fn scrolls(&self) -> bool {
+        matches!(self, Overflow::Scroll | Overflow::Auto)
+    }
 }
 
 #[derive(Default, Clone, PartialEq, Debug)]
@@ -317,6 +362,8 @@ pub fn apply_style_attributes(
 
         "outline-color" | "outline-offset" | "outline-style" | "outline-width" => {}
 
+        "overflow" | "overflow-x" | "overflow-y" => apply_overflow(name, value, style),
+
         "page-break-after" | "page-break-before" | "page-break-inside" => {}
 
         "perspective" | "perspective-origin" => {}
@@ -618,6 +665,34 @@ fn apply_text(name: &str, value: &str, style: &mut StyleModifier) {
     }
 }
 
+fn apply_overflow(name: &str, value: &str, style: &mut StyleModifier) {
+    let mut values = value.split_whitespace().map(Overflow::parse);
+    match name {
+        "overflow" => match (values.next(), values.next()) {
+            (Some(Some(x)), None) => {
+                style.modifier.overflow_x = x;
+                style.modifier.overflow_y = x;
+            }
+            (Some(Some(x)), Some(Some(y))) => {
+                style.modifier.overflow_x = x;
+                style.modifier.overflow_y = y;
+            }
+            _ => {}
+        },
+        "overflow-x" => {
+            if let Some(Some(x)) = values.next() {
+                style.modifier.overflow_x = x;
+            }
+        }
+        "overflow-y" => {
+            if let Some(Some(y)) = values.next() {
+                style.modifier.overflow_y = y;
+            }
+        }
+        _ => {}
+    }
+}
+
 fn apply_transition(_name: &str, _value: &str, _style: &mut StyleModifier) {
     todo!("Implement transitions")
 }
@@ -710,6 +785,9 @@ const SORTED_STYLE_ATTRS: &[&str] = &[
     "outline-offset",
     "outline-style",
     "outline-width",
+    "overflow",
+    "overflow-x",
+    "overflow-y",
     "page-break-after",
     "page-break-before",
     "page-break-inside",
@@ -13,19 +13,35 @@ use crate::{
 pub struct RinkBuffer<'a> {
     buf: &'a mut Buffer,
     cfg: Config,
+    /// The position of the widget's area in the buffer
+    offset: (i32, i32),
+    /// The part of the buffer the widget is visible in
+    clip: Rect,
 }
 
 impl<'a> RinkBuffer<'a> {
-    fn new(buf: &'a mut Buffer, cfg: Config) -> RinkBuffer<'a> {
-        Self { buf, cfg }
+    fn new(buf: &'a mut Buffer, cfg: Config, offset: (i32, i32), clip: Rect) -> RinkBuffer<'a> {
+        let clip = clip.intersection(buf.area);
+        Self {
+            buf,
+            cfg,
+            offset,
+            clip,
+        }
     }
 
     pub fn set(&mut self, x: u16, y: u16, new: RinkCell) {
-        let area = self.buf.area();
-        if x < area.x || x >= area.width + area.x || y < area.y || y >= area.height + area.y {
+        let (x, y) = (x as i32 + self.offset.0, y as i32 + self.offset.1);
+        let area = self.clip;
+        if x < area.left() as i32
+            || x >= area.right() as i32
+            || y < area.top() as i32
+            || y >= area.bottom() as i32
+        {
             // panic!("({x}, {y}) is not in {area:?}");
             return;
         }
+        let (x, y) = (x as u16, y as u16);
         let cell = self.buf.get_mut(x, y);
         cell.bg = convert(self.cfg.rendering_mode, new.bg.blend(cell.bg));
         if new.symbol.is_empty() {
@@ -45,20 +61,92 @@ pub trait RinkWidget {
     fn render(self, area: Rect, buf: RinkBuffer);
 }
 
+/// Draws an image with two pixels in every cell: the top pixel is the foreground of an upper half block and the bottom pixel is the background.
+pub struct HalfBlockImage<'a> {
+    pub image: &'a image::RgbaImage,
+}
+
+impl RinkWidget for HalfBlockImage<'_> {
+    fn render(self, area: Rect, mut buf: RinkBuffer) {
+        if area.area() == 0 {
+            return;
+        }
+        let image = image::imageops::resize(
+            self.image,
+            area.width as u32,
+            area.height as u32 * 2,
+            image::imageops::FilterType::Triangle,
+        );
+        let color = |x: u16, y: u16| {
+            let [r, g, b, alpha] = image.get_pixel(x as u32, y as u32).0;
+            RinkColor {
+                color: Color::Rgb(r, g, b),
+                alpha,
+            }
+        };
+        for y in 0..area.height {
+            for x in 0..area.width {
+                let (top, bottom) = (color(x, y * 2), color(x, y * 2 + 1));
+                // the transparent half shows the background of the cell
+                let cell = match (top.alpha, bottom.alpha) {
+                    (0, 0) => continue,
+                    (0, _) => RinkCell {
+                        symbol: "▄".to_string(),
+                        fg: bottom,
+                        bg: top,
+                        modifier: Modifier::empty(),
+                    },
+                    _ => RinkCell {
+                        symbol: "▀".to_string(),
+                        fg: top,
+                        bg: bottom,
+                        modifier: Modifier::empty(),
+                    },
+                };
+                buf.set(area.left() + x, area.top() + y, cell);
+            }
+        }
+    }
+}
+
+/// A widget that may be partially outside of the area it is rendered into. Only the cells inside the area are drawn.
 pub struct WidgetWithContext<T: RinkWidget> {
     widget: T,
     config: Config,
+    /// The position of the widget in the buffer, which may be off screen
+    origin: (i32, i32),
+    size: (u16, u16),
 }
 
 impl<T: RinkWidget> WidgetWithContext<T> {
-    pub fn new(widget: T, config: Config) -> WidgetWithContext<T> {
-        WidgetWithContext { widget, config }
+    pub fn new(
+        widget: T,
+        config: Config,
+        origin: (i32, i32),
+        size: (u16, u16),
+    ) -> WidgetWithContext<T> {
+        WidgetWithContext {
+            widget,
+            config,
+            origin,
+            size,
+        }
     }
 }
 
 impl<T: RinkWidget> Widget for WidgetWithContext<T> {
     fn render(self, area: Rect, buf: &mut Buffer) {
-        self.widget.render(area, RinkBuffer::new(buf, self.config));
+        let (width, height) = self.size;
+        // the area is not clamped like Rect::new, because widgets inside scrolled elements can be larger than the screen
+        self.widget.render(
+            Rect {
+                x: 0,
+                y: 0,
+                width,
+                height,
+            },
+            RinkBuffer::new(buf, self.config, self.origin, area),
+        );
     }
 }
 
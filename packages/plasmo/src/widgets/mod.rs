@@ -5,6 +5,7 @@ mod number;
 mod password;
 mod slider;
 mod text_like;
+mod textarea;
 mod textbox;
 
 use std::sync::{Arc, RwLock};
@@ -23,6 +24,7 @@ pub(crate) fn register_widgets(rdom: &mut RealDom, sender: UnboundedSender<Event
     rdom.raw_world().add_unique(WidgetContext { sender });
 
     rdom.register_custom_element::<RinkWidgetWrapper<input::Input>>();
+    rdom.register_custom_element::<RinkWidgetWrapper<textarea::TextArea>>();
 }
 
 trait RinkWidget: Sync + Send + CustomElement + 'static {
@@ -1,6 +1,5 @@
-use std::{collections::HashMap, io::stdout};
+use std::{cmp::Ordering, collections::HashMap};
 
-use crossterm::{cursor::MoveTo, execute};
 use dioxus_html::{
     input_data::keyboard_types::Key, prelude::*, HasKeyboardData, SerializedKeyboardData,
     SerializedMouseData,
@@ -11,12 +10,13 @@ use dioxus_native_core::{
     node_ref::AttributeMask,
     prelude::{ElementNode, NodeType},
     real_dom::{ElementNodeMut, NodeImmutable, NodeMut, NodeTypeMut, RealDom},
-    utils::cursor::{Cursor, Pos},
+    utils::cursor::{Cursor, Pos, Text},
     NodeId,
 };
-use shipyard::UniqueView;
+use shipyard::{UniqueView, UniqueViewMut};
 use taffy::geometry::Point;
 
+use crate::app::TerminalCursor;
 use crate::hooks::FormData;
 use crate::{query::get_layout, Event, EventData, Query};
 
@@ -26,6 +26,11 @@ pub(crate) trait TextLikeController {
     fn display_text(&self, text: &str) -> String {
         text.to_string()
     }
+
+    /// Returns true if the text can span multiple lines like a textarea
+    fn multiline(&self) -> bool {
+        false
+    }
 }
 
 #[derive(Debug, Default)]
@@ -33,13 +38,81 @@ pub(crate) struct EmptyController;
 
 impl TextLikeController for EmptyController {}
 
+/// The nodes that display one line of the text
+#[derive(Debug)]
+struct LineNodes {
+    wrapper: NodeId,
+    pre_cursor_text: NodeId,
+    highlighted_text: NodeId,
+    post_cursor_text: NodeId,
+}
+
+impl LineNodes {
+    fn create(rdom: &mut RealDom) -> Self {
+        let pre_text = rdom.create_node(String::new());
+        let pre_text_id = pre_text.id();
+        let highlighted_text = rdom.create_node(String::new());
+        let highlighted_text_id = highlighted_text.id();
+        let mut highlighted_text_span = rdom.create_node(NodeType::Element(ElementNode {
+            tag: "span".to_string(),
+            attributes: [(
+                OwnedAttributeDiscription {
+                    name: "background-color".to_string(),
+                    namespace: Some("style".to_string()),
+                },
+                "rgba(255, 255, 255, 50%)".to_string().into(),
+            )]
+            .into_iter()
+            .collect(),
+            ..Default::default()
+        }));
+        highlighted_text_span.add_child(highlighted_text_id);
+        let highlighted_text_span_id = highlighted_text_span.id();
+        let post_text = rdom.create_node(String::new());
+        let post_text_id = post_text.id();
+        let mut wrapper = rdom.create_node(NodeType::Element(ElementNode {
+            tag: "div".to_string(),
+            attributes: [
+                (
+                    OwnedAttributeDiscription {
+                        name: "display".to_string(),
+                        namespace: Some("style".to_string()),
+                    },
+                    "flex".to_string().into(),
+                ),
+                // empty lines still take up a row
+                (
+                    OwnedAttributeDiscription {
+                        name: "min-height".to_string(),
+                        namespace: Some("style".to_string()),
+                    },
+                    "1px".to_string().into(),
+                ),
+            ]
+            .into_iter()
+            .collect(),
+            ..Default::default()
+        }));
+        wrapper.add_child(pre_text_id);
+        wrapper.add_child(highlighted_text_span_id);
+        wrapper.add_child(post_text_id);
+
+        Self {
+            wrapper: wrapper.id(),
+            pre_cursor_text: pre_text_id,
+            highlighted_text: highlighted_text_id,
+            post_cursor_text: post_text_id,
+        }
+    }
+}
+
 #[derive(Debug, Default)]
 pub(crate) struct TextLike<C: TextLikeController = EmptyController> {
     text: String,
+    /// The text the last change event was sent with
+    committed_text: String,
     div_wrapper: NodeId,
-    pre_cursor_text: NodeId,
-    highlighted_text: NodeId,
-    post_cursor_text: NodeId,
+    lines: Vec<LineNodes>,
     cursor: Cursor,
     dragging: bool,
     border: bool,
@@ -124,78 +197,171 @@ impl<C: TextLikeController> TextLike<C> {
             .and_then(|value| value.as_text())
             .map(|value| value.to_string())
         {
-            self.text = value;
+            self.text = if self.controller.multiline() {
+                value
+            } else {
+                value.replace('\n', "")
+            };
+            self.committed_text = self.text.clone();
+            self.clamp_cursor();
         }
     }
 
+    /// Set the text as if the user edited it
     pub(crate) fn set_text(&mut self, text: String, rdom: &mut RealDom, id: NodeId) {
         self.text = text;
-        self.write_value(rdom, id);
+        self.clamp_cursor();
+        self.write_value(rdom);
+        self.send_event(rdom, id, "input");
     }
 
     pub(crate) fn text(&self) -> &str {
         self.text.as_str()
     }
 
-    fn write_value(&self, rdom: &mut RealDom, id: NodeId) {
-        let start_highlight = self.cursor.first().idx(self.text.as_str());
-        let end_highlight = self.cursor.last().idx(self.text.as_str());
-        let (text_before_first_cursor, text_after_first_cursor) =
-            self.text.split_at(start_highlight);
-        let (text_highlighted, text_after_second_cursor) =
-            text_after_first_cursor.split_at(end_highlight - start_highlight);
-
-        if let Some(mut text) = rdom.get_mut(self.pre_cursor_text) {
-            let node_type = text.node_type_mut();
-            let NodeTypeMut::Text(mut text) = node_type else {
-                panic!("input must be an element")
-            };
-            *text.text_mut() = self.controller.display_text(text_before_first_cursor);
+    /// Keep the cursor inside of the text after the text is replaced
+    fn clamp_cursor(&mut self) {
+        let text = self.text.as_str();
+        let clamp = |pos: &mut Pos| {
+            let last_row = text.line_count().saturating_sub(1);
+            if pos.row > last_row {
+                pos.row = last_row;
+                pos.col = usize::MAX;
+            }
+            pos.realize_col(text);
+        };
+        clamp(&mut self.cursor.start);
+        if let Some(end) = &mut self.cursor.end {
+            clamp(end);
         }
+    }
 
-        if let Some(mut text) = rdom.get_mut(self.highlighted_text) {
-            let node_type = text.node_type_mut();
-            let NodeTypeMut::Text(mut text) = node_type else {
-                panic!("input must be an element")
-            };
-            *text.text_mut() = self.controller.display_text(text_highlighted);
+    /// Update the text nodes to show the text and the selection
+    fn write_value(&mut self, rdom: &mut RealDom) {
+        let lines = self.text.split('\n').collect::<Vec<_>>();
+
+        // add or remove the nodes for lines that were added or removed
+        while self.lines.len() < lines.len() {
+            let line = LineNodes::create(rdom);
+            rdom.get_mut(self.div_wrapper)
+                .unwrap()
+                .add_child(line.wrapper);
+            self.lines.push(line);
+        }
+        while self.lines.len() > lines.len() {
+            let line = self.lines.pop().unwrap();
+            if let Some(mut wrapper) = rdom.get_mut(line.wrapper) {
+                wrapper.remove();
+            }
         }
 
-        if let Some(mut text) = rdom.get_mut(self.post_cursor_text) {
-            let node_type = text.node_type_mut();
-            let NodeTypeMut::Text(mut text) = node_type else {
-                panic!("input must be an element")
+        let text = self.text.as_str();
+        let first = self.cursor.first();
+        let last = self.cursor.last();
+        for (row, (line, nodes)) in lines.iter().zip(&self.lines).enumerate() {
+            // the part of the selection on this line in characters
+            let len = line.chars().count();
+            let start = match row.cmp(&first.row) {
+                Ordering::Less => len,
+                Ordering::Equal => first.col(text),
+                Ordering::Greater => 0,
             };
-            *text.text_mut() = self.controller.display_text(text_after_second_cursor);
+            let end = match row.cmp(&last.row) {
+                Ordering::Less => len,
+                Ordering::Equal => last.col(text),
+                Ordering::Greater => 0,
+            }
+            .max(start);
+            let (before, rest) = line.split_at(byte_index(line, start));
+            let (highlighted, after) = rest.split_at(byte_index(rest, end - start));
+
+            for (id, segment) in [
+                (nodes.pre_cursor_text, before),
+                (nodes.highlighted_text, highlighted),
+                (nodes.post_cursor_text, after),
+            ] {
+                if let Some(mut text) = rdom.get_mut(id) {
+                    let node_type = text.node_type_mut();
+                    let NodeTypeMut::Text(mut text) = node_type else {
+                        panic!("input must be an element")
+                    };
+                    *text.text_mut() = self.controller.display_text(segment);
+                }
+            }
         }
+    }
 
-        // send the event
-        {
-            let world = rdom.raw_world_mut();
-            let data: FormData = FormData {
-                value: self.text.clone(),
-                values: HashMap::new(),
-                files: None,
-            };
-            let ctx: UniqueView<WidgetContext> = world.borrow().expect("expected widget context");
-
-            ctx.send(Event {
-                id,
-                name: "input",
-                data: EventData::Form(data),
-                bubbles: true,
-            });
+    /// Send an input or change event with the current text
+    fn send_event(&self, rdom: &mut RealDom, id: NodeId, name: &'static str) {
+        let world = rdom.raw_world_mut();
+        let data: FormData = FormData {
+            value: self.text.clone(),
+            values: HashMap::new(),
+            files: None,
+        };
+        let ctx: UniqueView<WidgetContext> = world.borrow().expect("expected widget context");
+
+        ctx.send(Event {
+            id,
+            name,
+            data: EventData::Form(data),
+            bubbles: true,
+        });
+    }
+
+    /// Send a change event if the text changed since the last change event
+    fn commit(&mut self, rdom: &mut RealDom, id: NodeId) {
+        if self.text != self.committed_text {
+            self.committed_text = self.text.clone();
+            self.send_event(rdom, id, "change");
         }
     }
 
+    /// Move the terminal cursor to the end of the selection that is moving
+    fn update_cursor(&self, rdom: &mut RealDom) {
+        let taffy = {
+            let query: UniqueView<Query> = rdom.raw_world().borrow().unwrap();
+            query.stretch.clone()
+        };
+        let taffy = taffy.lock().unwrap();
+
+        let layout = get_layout(rdom.get(self.div_wrapper).unwrap(), &taffy).unwrap();
+        let Point { x, y } = layout.location;
+
+        let caret = self.cursor.end.as_ref().unwrap_or(&self.cursor.start);
+        let (col, row) = (caret.col(self.text.as_str()), caret.row);
+        let (x, y) = (col as u16 + x as u16, row as u16 + y as u16);
+        drop(taffy);
+        move_cursor(rdom, Some((x, y)));
+    }
+
+    /// Get the position in the text under a point relative to the text
+    fn position_at(&self, x: f64, y: f64) -> Pos {
+        let row = if self.controller.multiline() {
+            (y.max(0.0) as usize).min(self.text.as_str().line_count().saturating_sub(1))
+        } else {
+            // textboxs are only one line tall
+            0
+        };
+        let mut pos = Pos::new(x.max(0.0) as usize, row);
+        pos.realize_col(self.text.as_str());
+        pos
+    }
+
     fn handle_keydown(&mut self, mut root: NodeMut, data: &SerializedKeyboardData) {
         let key = data.key();
         let modifiers = data.modifiers();
         let code = data.code();
+        let id = root.id();
+        let rdom = root.real_dom_mut();
 
-        if key == Key::Enter {
+        // enter commits a single line input
+        if key == Key::Enter && !self.controller.multiline() {
+            self.commit(rdom, id);
             return;
         }
+
+        let previous_text = self.text.clone();
         self.cursor.handle_input(
             &code,
             &key,
@@ -204,88 +370,33 @@ impl<C: TextLikeController> TextLike<C> {
             self.max_len.unwrap_or(1000),
         );
 
-        let id = root.id();
-
-        let rdom = root.real_dom_mut();
-        self.write_value(rdom, id);
-        let world = rdom.raw_world_mut();
-
-        // move cursor to new position
-        let taffy = {
-            let query: UniqueView<Query> = world.borrow().unwrap();
-            query.stretch.clone()
-        };
-
-        let taffy = taffy.lock().unwrap();
-
-        let layout = get_layout(rdom.get(self.div_wrapper).unwrap(), &taffy).unwrap();
-        let Point { x, y } = layout.location;
-
-        let Pos { col, row } = self.cursor.start;
-        let (x, y) = (col as u16 + x as u16, row as u16 + y as u16);
-        if let Ok(pos) = crossterm::cursor::position() {
-            if pos != (x, y) {
-                execute!(stdout(), MoveTo(x, y)).unwrap();
-            }
-        } else {
-            execute!(stdout(), MoveTo(x, y)).unwrap();
+        self.write_value(rdom);
+        if self.text != previous_text {
+            self.send_event(rdom, id, "input");
         }
+        self.update_cursor(rdom);
     }
 
     fn handle_mousemove(&mut self, mut root: NodeMut, data: &SerializedMouseData) {
         if self.dragging {
-            let id = root.id();
             let offset = data.element_coordinates();
-            let mut new = Pos::new(offset.x as usize, offset.y as usize);
+            let new = self.position_at(offset.x, offset.y);
 
-            // textboxs are only one line tall
-            new.row = 0;
-
-            if new != self.cursor.start {
-                self.cursor.end = Some(new);
-            }
+            self.cursor.end = (new != self.cursor.start).then_some(new);
             let rdom = root.real_dom_mut();
-            self.write_value(rdom, id);
+            self.write_value(rdom);
+            self.update_cursor(rdom);
         }
     }
 
     fn handle_mousedown(&mut self, mut root: NodeMut, data: &SerializedMouseData) {
         let offset = data.element_coordinates();
-        let mut new = Pos::new(offset.x as usize, offset.y as usize);
-
-        // textboxs are only one line tall
-        new.row = 0;
-
-        new.realize_col(self.text.as_str());
-        self.cursor = Cursor::from_start(new);
+        self.cursor = Cursor::from_start(self.position_at(offset.x, offset.y));
         self.dragging = true;
 
-        let id = root.id();
-
-        // move cursor to new position
         let rdom = root.real_dom_mut();
-        let world = rdom.raw_world_mut();
-        let taffy = {
-            let query: UniqueView<Query> = world.borrow().unwrap();
-            query.stretch.clone()
-        };
-
-        let taffy = taffy.lock().unwrap();
-
-        let layout = get_layout(rdom.get(self.div_wrapper).unwrap(), &taffy).unwrap();
-        let Point { x, y } = layout.location;
-
-        let Pos { col, row } = self.cursor.start;
-        let (x, y) = (col as u16 + x as u16, row as u16 + y as u16);
-        if let Ok(pos) = crossterm::cursor::position() {
-            if pos != (x, y) {
-                execute!(stdout(), MoveTo(x, y)).unwrap();
-            }
-        } else {
-            execute!(stdout(), MoveTo(x, y)).unwrap();
-        }
-
-        self.write_value(rdom, id)
+        self.write_value(rdom);
+        self.update_cursor(rdom);
     }
 }
 
@@ -315,44 +426,29 @@ impl<C: TextLikeController + Send + Sync + Default + 'static> CustomElement for
 
         let rdom = root.real_dom_mut();
 
-        let pre_text = rdom.create_node(String::new());
-        let pre_text_id = pre_text.id();
-        let highlighted_text = rdom.create_node(String::new());
-        let highlighted_text_id = highlighted_text.id();
-        let mut highlighted_text_span = rdom.create_node(NodeType::Element(ElementNode {
-            tag: "span".to_string(),
-            attributes: [(
-                OwnedAttributeDiscription {
-                    name: "background-color".to_string(),
-                    namespace: Some("style".to_string()),
-                },
-                "rgba(255, 255, 255, 50%)".to_string().into(),
-            )]
-            .into_iter()
-            .collect(),
-            ..Default::default()
-        }));
-        highlighted_text_span.add_child(highlighted_text_id);
-        let highlighted_text_span_id = highlighted_text_span.id();
-        let post_text = rdom.create_node(value.clone().unwrap_or_default());
-        let post_text_id = post_text.id();
         let mut div_wrapper = rdom.create_node(NodeType::Element(ElementNode {
             tag: "div".to_string(),
-            attributes: [(
-                OwnedAttributeDiscription {
-                    name: "display".to_string(),
-                    namespace: Some("style".to_string()),
-                },
-                "flex".to_string().into(),
-            )]
+            attributes: [
+                (
+                    OwnedAttributeDiscription {
+                        name: "display".to_string(),
+                        namespace: Some("style".to_string()),
+                    },
+                    "flex".to_string().into(),
+                ),
+                (
+                    OwnedAttributeDiscription {
+                        name: "flex-direction".to_string(),
+                        namespace: Some("style".to_string()),
+                    },
+                    "column".to_string().into(),
+                ),
+            ]
             .into_iter()
             .collect(),
             ..Default::default()
         }));
         let div_wrapper_id = div_wrapper.id();
-        div_wrapper.add_child(pre_text_id);
-        div_wrapper.add_child(highlighted_text_span_id);
-        div_wrapper.add_child(post_text_id);
 
         div_wrapper.add_event_listener("mousemove");
         div_wrapper.add_event_listener("mousedown");
@@ -362,15 +458,18 @@ impl<C: TextLikeController + Send + Sync + Default + 'static> CustomElement for
         root.add_event_listener("keydown");
         root.add_event_listener("focusout");
 
-        Self {
-            pre_cursor_text: pre_text_id,
-            highlighted_text: highlighted_text_id,
-            post_cursor_text: post_text_id,
+        let mut myself = Self {
             div_wrapper: div_wrapper_id,
             cursor: Cursor::default(),
-            text: value.unwrap_or_default(),
             ..Default::default()
-        }
+        };
+        myself.text = match value {
+            Some(value) if !myself.controller.multiline() => value.replace('\n', ""),
+            value => value.unwrap_or_default(),
+        };
+        myself.committed_text = myself.text.clone();
+        myself.write_value(root.real_dom_mut());
+        myself
     }
 
     fn attributes_changed(
@@ -389,8 +488,7 @@ impl<C: TextLikeController + Send + Sync + Default + 'static> CustomElement for
                     self.update_size_attr(&mut el);
                     self.update_max_width_attr(&el);
                 }
-                let id = root.id();
-                self.write_value(root.real_dom_mut(), id);
+                self.write_value(root.real_dom_mut());
             }
             AttributeMask::Some(attrs) => {
                 {
@@ -409,14 +507,23 @@ impl<C: TextLikeController + Send + Sync + Default + 'static> CustomElement for
                     }
                 }
                 if attrs.contains("value") {
-                    let id = root.id();
-                    self.write_value(root.real_dom_mut(), id);
+                    self.write_value(root.real_dom_mut());
                 }
             }
         }
     }
 }
 
+/// Move the terminal cursor, or hide it with `None`
+fn move_cursor(rdom: &mut RealDom, position: Option<(u16, u16)>) {
+    if let Ok(mut cursor) = rdom
+        .raw_world_mut()
+        .borrow::<UniqueViewMut<TerminalCursor>>()
+    {
+        cursor.0 = position;
+    }
+}
+
 impl<C: TextLikeController + Send + Sync + Default + 'static> RinkWidget for TextLike<C> {
     fn handle_event(&mut self, event: &crate::Event, node: NodeMut) {
         match event.name {
@@ -451,10 +558,22 @@ impl<C: TextLikeController + Send + Sync + Default + 'static> RinkWidget for Tex
             }
 
             "focusout" => {
-                execute!(stdout(), MoveTo(0, 1000)).unwrap();
+                let mut node = node;
+                let id = node.id();
+                let rdom = node.real_dom_mut();
+                move_cursor(rdom, None);
+                self.commit(rdom, id);
             }
 
             _ => {}
         }
     }
 }
+
+/// Convert a character index into a byte index of the string
+fn byte_index(text: &str, idx: usize) -> usize {
+    text.char_indices()
+        .nth(idx)
+        .map(|(byte, _)| byte)
+        .unwrap_or(text.len())
+}
@@ -0,0 +1,64 @@
+use dioxus_native_core::{
+    custom_element::CustomElement,
+    real_dom::{NodeImmutable, NodeMut},
+    NodeId,
+};
+
+use super::{
+    text_like::{TextLike, TextLikeController},
+    RinkWidget,
+};
+
+/// A text input that spans multiple lines. Enter inserts a new line instead of committing the text.
+#[derive(Debug, Default)]
+pub(crate) struct TextArea {
+    text: TextLike<TextAreaController>,
+}
+
+#[derive(Debug, Default)]
+pub(crate) struct TextAreaController;
+
+impl TextLikeController for TextAreaController {
+    fn multiline(&self) -> bool {
+        true
+    }
+}
+
+impl CustomElement for TextArea {
+    const NAME: &'static str = "textarea";
+
+    fn roots(&self) -> Vec<NodeId> {
+        self.text.roots()
+    }
+
+    fn create(mut root: NodeMut) -> Self {
+        {
+            // the text is set with the value attribute, so the children are not rendered
+            let children = root.child_ids();
+            let rdom = root.real_dom_mut();
+            for child in children {
+                if let Some(mut child) = rdom.get_mut(child) {
+                    child.remove();
+                }
+            }
+        }
+
+        TextArea {
+            text: TextLike::create(root),
+        }
+    }
+
+    fn attributes_changed(
+        &mut self,
+        root: NodeMut,
+        attributes: &dioxus_native_core::node_ref::AttributeMask,
+    ) {
+        self.text.attributes_changed(root, attributes)
+    }
+}
+
+impl RinkWidget for TextArea {
+    fn handle_event(&mut self, event: &crate::Event, node: NodeMut) {
+        self.text.handle_event(event, node)
+    }
+}
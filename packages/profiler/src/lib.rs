@@ -0,0 +1,313 @@
+#![doc = include_str!("../README.md")]
+#![doc(html_logo_url = "https://avatars.githubusercontent.com/u/79236386")]
+#![doc(html_favicon_url = "https://avatars.githubusercontent.com/u/79236386")]
+#![warn(missing_docs)]
+
+use std::{
+    collections::HashMap,
+    fmt::Debug,
+    path::Path,
+    sync::{Arc, Mutex},
+    thread::ThreadId,
+    time::Duration,
+};
+
+use tracing::{
+    field::{Field, Visit},
+    span::{Attributes, Id, Record},
+    Subscriber,
+};
+use tracing_subscriber::{layer::Context, prelude::*, registry::LookupSpan, Layer};
+
+type Clock = Arc<dyn Fn() -> Duration + Send + Sync>;
+
+/// Collects the tracing spans of an app, like the renders, diffs and events of the VirtualDom
+///
+/// Add the [`Profiler::layer`] to a tracing subscriber, or install it as the global subscriber with [`Profiler::init`]. The spans of the renderers are at the `trace` level.
+#[derive(Clone)]
+pub struct Profiler {
+    events: Arc<Mutex<Vec<TraceEvent>>>,
+    clock: Clock,
+}
+
+impl Default for Profiler {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Profiler {
+    /// Create a profiler. Times are measured from its creation.
+    ///
+    /// `std::time::Instant` is not available on `wasm32-unknown-unknown`, so the times are zero there unless a clock is set with [`Profiler::with_clock`].
+    pub fn new() -> Self {
+        #[cfg(not(target_arch = "wasm32"))]
+        let clock = {
+            let start = std::time::Instant::now();
+            move || start.elapsed()
+        };
+        #[cfg(target_arch = "wasm32")]
+        let clock = || Duration::ZERO;
+
+        Self {
+            events: Default::default(),
+            clock: Arc::new(clock),
+        }
+    }
+
+    /// Measure the spans with `clock`, like `performance.now()` in the browser. Set the clock before creating the layer.
+    pub fn with_clock(mut self, clock: impl Fn() -> Duration + Send + Sync + 'static) -> Self {
+        self.clock = Arc::new(clock);
+        self
+    }
+
+    /// A tracing layer that records the spans into this profiler
+    pub fn layer(&self) -> ProfilerLayer {
+        ProfilerLayer {
+            events: self.events.clone(),
+            clock: self.clock.clone(),
+            threads: Default::default(),
+        }
+    }
+
+    /// Install a global tracing subscriber that only records the spans into this profiler
+    pub fn init(&self) -> Result<(), tracing_subscriber::util::TryInitError> {
+        tracing_subscriber::registry().with(self.layer()).try_init()
+    }
+
+    /// The spans recorded so far, in the order they were exited
+    pub fn events(&self) -> Vec<TraceEvent> {
+        self.events.lock().unwrap().clone()
+    }
+
+    /// Forget the spans recorded so far
+    pub fn clear(&self) {
+        self.events.lock().unwrap().clear();
+    }
+
+    /// The time each component spent rendering, from the slowest to the fastest
+    pub fn component_timings(&self) -> Vec<ComponentTiming> {
+        let mut timings = HashMap::<String, ComponentTiming>::new();
+        for event in self.events.lock().unwrap().iter() {
+            if event.name != "render" {
+                continue;
+            }
+            let Some(component) = event.field("component") else {
+                continue;
+            };
+            let timing = timings
+                .entry(component.to_string())
+                .or_insert_with(|| ComponentTiming {
+                    component: component.to_string(),
+                    renders: 0,
+                    total: Duration::ZERO,
+                    slowest: Duration::ZERO,
+                });
+            timing.renders += 1;
+            timing.total += event.duration;
+            timing.slowest = timing.slowest.max(event.duration);
+        }
+
+        let mut timings: Vec<_> = timings.into_values().collect();
+        timings.sort_by(|a, b| {
+            b.total
+                .cmp(&a.total)
+                .then_with(|| a.component.cmp(&b.component))
+        });
+        timings
+    }
+
+    /// The recorded spans in the JSON format of `chrome://tracing` and <https://ui.perfetto.dev>
+    pub fn to_chrome_trace(&self) -> String {
+        let events: Vec<_> = self
+            .events
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|event| {
+                let args: serde_json::Map<_, _> = event
+                    .fields
+                    .iter()
+                    .map(|(name, value)| (name.clone(), value.clone().into()))
+                    .collect();
+                serde_json::json!({
+                    "name": event.label(),
+                    "cat": event.target,
+                    "ph": "X",
+                    "ts": micros(event.start),
+                    "dur": micros(event.duration),
+                    "pid": 1,
+                    "tid": event.thread,
+                    "args": args,
+                })
+            })
+            .collect();
+
+        serde_json::json!({
+            "traceEvents": events,
+            "displayTimeUnit": "ms",
+        })
+        .to_string()
+    }
+
+    /// Write the recorded spans to a file that can be opened in `chrome://tracing` or <https://ui.perfetto.dev>
+    pub fn write_chrome_trace(&self, path: impl AsRef<Path>) -> std::io::Result<()> {
+        std::fs::write(path, self.to_chrome_trace())
+    }
+}
+
+fn micros(duration: Duration) -> f64 {
+    duration.as_secs_f64() * 1_000_000.0
+}
+
+/// One time a span was entered and exited
+#[derive(Debug, Clone, PartialEq)]
+pub struct TraceEvent {
+    /// The name of the span, like `render`
+    pub name: String,
+    /// The module that created the span
+    pub target: String,
+    /// When the span was entered, since the profiler was created
+    pub start: Duration,
+    /// How long the span was entered
+    pub duration: Duration,
+    /// The thread the span was entered on, numbered from 1 in the order the threads were seen
+    pub thread: usize,
+    /// The fields of the span, like the component and scope of a render
+    pub fields: Vec<(String, String)>,
+}
+
+impl TraceEvent {
+    /// The value of a field of the span
+    pub fn field(&self, name: &str) -> Option<&str> {
+        self.fields
+            .iter()
+            .find(|(field, _)| field == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// The name of the span with the component it belongs to, like `render Counter`
+    pub fn label(&self) -> String {
+        match self.field("component") {
+            Some(component) => format!("{} {component}", self.name),
+            None => self.name.clone(),
+        }
+    }
+}
+
+/// The renders of one component collected by a [`Profiler`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct ComponentTiming {
+    /// The name of the component
+    pub component: String,
+    /// How many times the component rendered
+    pub renders: usize,
+    /// The time of all renders together
+    pub total: Duration,
+    /// The time of the slowest render
+    pub slowest: Duration,
+}
+
+/// The tracing layer of a [`Profiler`]
+pub struct ProfilerLayer {
+    events: Arc<Mutex<Vec<TraceEvent>>>,
+    clock: Clock,
+    threads: Mutex<Vec<ThreadId>>,
+}
+
+impl ProfilerLayer {
+    fn thread(&self) -> usize {
+        let id = std::thread::current().id();
+        let mut threads = self.threads.lock().unwrap();
+        match threads.iter().position(|thread| *thread == id) {
+            Some(index) => index + 1,
+            None => {
+                threads.push(id);
+                threads.len()
+            }
+        }
+    }
+}
+
+/// The fields and the last enter time of a span, kept in the extensions of the span
+struct SpanTiming {
+    fields: Vec<(String, String)>,
+    entered: Duration,
+}
+
+impl<S> Layer<S> for ProfilerLayer
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+{
+    fn on_new_span(&self, attrs: &Attributes<'_>, id: &Id, ctx: Context<'_, S>) {
+        let Some(span) = ctx.span(id) else {
+            return;
+        };
+        let mut timing = SpanTiming {
+            fields: Vec::new(),
+            entered: Duration::ZERO,
+        };
+        attrs.record(&mut FieldVisitor(&mut timing.fields));
+        span.extensions_mut().insert(timing);
+    }
+
+    fn on_record(&self, id: &Id, values: &Record<'_>, ctx: Context<'_, S>) {
+        let Some(span) = ctx.span(id) else {
+            return;
+        };
+        let mut extensions = span.extensions_mut();
+        if let Some(timing) = extensions.get_mut::<SpanTiming>() {
+            values.record(&mut FieldVisitor(&mut timing.fields));
+        }
+    }
+
+    fn on_enter(&self, id: &Id, ctx: Context<'_, S>) {
+        let Some(span) = ctx.span(id) else {
+            return;
+        };
+        let mut extensions = span.extensions_mut();
+        if let Some(timing) = extensions.get_mut::<SpanTiming>() {
+            timing.entered = (self.clock)();
+        }
+    }
+
+    fn on_exit(&self, id: &Id, ctx: Context<'_, S>) {
+        let Some(span) = ctx.span(id) else {
+            return;
+        };
+        let extensions = span.extensions();
+        let Some(timing) = extensions.get::<SpanTiming>() else {
+            return;
+        };
+        let event = TraceEvent {
+            name: span.name().to_string(),
+            target: span.metadata().target().to_string(),
+            start: timing.entered,
+            duration: (self.clock)().saturating_sub(timing.entered),
+            thread: self.thread(),
+            fields: timing.fields.clone(),
+        };
+        self.events.lock().unwrap().push(event);
+    }
+}
+
+struct FieldVisitor<'a>(&'a mut Vec<(String, String)>);
+
+impl FieldVisitor<'_> {
+    fn set(&mut self, field: &Field, value: String) {
+        match self.0.iter_mut().find(|(name, _)| name == field.name()) {
+            Some((_, old)) => *old = value,
+            None => self.0.push((field.name().to_string(), value)),
+        }
+    }
+}
+
+impl Visit for FieldVisitor<'_> {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.set(field, value.to_string());
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+        self.set(field, format!("{value:?}"));
+    }
+}
@@ -0,0 +1,72 @@
+#![allow(non_snake_case)]
+
+use dioxus::prelude::*;
+use dioxus_profiler::Profiler;
+use tracing_subscriber::prelude::*;
+
+#[component]
+fn Counter(count: usize) -> Element {
+    rsx! { "count {count}" }
+}
+
+fn app() -> Element {
+    let count = use_signal(|| 0);
+    rsx! {
+        Counter { count: count() }
+        Counter { count: count() + 1 }
+    }
+}
+
+#[test]
+fn records_renders_of_components() {
+    let profiler = Profiler::new();
+    let subscriber = tracing_subscriber::registry().with(profiler.layer());
+
+    tracing::subscriber::with_default(subscriber, || {
+        let mut dom = VirtualDom::new(app);
+        dom.rebuild(&mut dioxus_core::NoOpMutations);
+        dom.mark_dirty(ScopeId::ROOT);
+        dom.render_immediate(&mut dioxus_core::NoOpMutations);
+    });
+
+    let events = profiler.events();
+    let renders: Vec<_> = events
+        .iter()
+        .filter(|event| event.name == "render")
+        .map(|event| event.label())
+        .collect();
+    // The props of the counters did not change, so only the app renders again
+    assert_eq!(
+        renders,
+        [
+            "render app",
+            "render Counter",
+            "render Counter",
+            "render app"
+        ]
+    );
+    assert!(events.iter().any(|event| event.label() == "diff app"));
+    assert!(events.iter().all(|event| event.thread == 1));
+
+    let timings = profiler.component_timings();
+    let counter = timings
+        .iter()
+        .find(|timing| timing.component == "Counter")
+        .unwrap();
+    assert_eq!(counter.renders, 2);
+    assert!(counter.slowest <= counter.total);
+
+    let trace: serde_json::Value = serde_json::from_str(&profiler.to_chrome_trace()).unwrap();
+    let trace_events = trace["traceEvents"].as_array().unwrap();
+    assert_eq!(trace_events.len(), events.len());
+    let render = trace_events
+        .iter()
+        .find(|event| event["name"] == "render Counter")
+        .unwrap();
+    assert_eq!(render["ph"], "X");
+    assert_eq!(render["cat"], "dioxus_core::scope_arena");
+    assert!(render["args"]["scope"].is_string());
+
+    profiler.clear();
+    assert!(profiler.events().is_empty());
+}
@@ -42,6 +42,8 @@ mod segment;
 /// 1. By there specificity this order: Query Routes ("/?:query"), Static Routes ("/route"), Dynamic Routes ("/:route"), Catch All Routes ("/:..route")
 /// 2. By the order they are defined in the enum
 ///
+/// Two routes or redirects that match exactly the same paths are a compile error, because the second one could never be matched.
+///
 /// All features:
 /// ```rust, skip
 /// #[rustfmt::skip]
@@ -238,6 +240,74 @@ struct RouteEnum {
     site_map: Vec<SiteMapSegment>,
 }
 
+/// Returns an error if two routes or redirects match exactly the same paths. The router always picks the first one, so the second can never be matched.
+fn check_for_duplicate_routes(
+    routes: &[Route],
+    nests: &[Nest],
+    redirects: &[Redirect],
+) -> syn::Result<()> {
+    // The parts of a path that decide what it matches. Dynamic segments with different types match different paths
+    #[derive(PartialEq)]
+    enum Matches {
+        Static(String),
+        Dynamic(String),
+        CatchAll(String),
+    }
+
+    fn full_path<'a>(
+        nest_ids: &[NestId],
+        segments: &'a [RouteSegment],
+        nests: &'a [Nest],
+    ) -> Vec<Matches> {
+        nest_ids
+            .iter()
+            .flat_map(|id| nests[id.0].segments.iter())
+            .chain(segments)
+            // Empty static segments are skipped when matching, so `/blog/` matches the same paths as `/blog`
+            .filter(
+                |segment| !matches!(segment, RouteSegment::Static(segment) if segment.is_empty()),
+            )
+            .map(|segment| match segment {
+                RouteSegment::Static(segment) => Matches::Static(segment.clone()),
+                RouteSegment::Dynamic(_, ty) => Matches::Dynamic(quote!(#ty).to_string()),
+                RouteSegment::CatchAll(_, ty) => Matches::CatchAll(quote!(#ty).to_string()),
+            })
+            .collect()
+    }
+
+    // Child routes match any path that their child enum matches, so they can't be compared
+    let mut seen: Vec<(Vec<Matches>, String)> = Vec::new();
+    let leaves = routes
+        .iter()
+        .filter(|route| matches!(route.ty, RouteType::Leaf { .. }))
+        .map(|route| {
+            let name = format!("`{}`", route.route_name);
+            let span = route.route_name.span();
+            (full_path(&route.nests, &route.segments, nests), name, span)
+        });
+    let redirects = redirects.iter().map(|redirect| {
+        let name = format!("the redirect from {}", redirect.route.value());
+        let span = redirect.route.span();
+        (
+            full_path(&redirect.nests, &redirect.segments, nests),
+            name,
+            span,
+        )
+    });
+
+    for (path, name, span) in leaves.chain(redirects) {
+        if let Some((_, first)) = seen.iter().find(|(other, _)| *other == path) {
+            return Err(syn::Error::new(
+                span,
+                format!("{name} matches the same paths as {first}, so it can never be matched. Change the path or remove one of the routes."),
+            ));
+        }
+        seen.push((path, name));
+    }
+
+    Ok(())
+}
+
 impl RouteEnum {
     fn parse(data: syn::ItemEnum) -> syn::Result<Self> {
         let name = &data.ident;
@@ -429,6 +499,8 @@ impl RouteEnum {
             children.push(current);
         }
 
+        check_for_duplicate_routes(&routes, &nests, &redirects)?;
+
         let myself = Self {
             name: name.clone(),
             routes,
@@ -257,6 +257,10 @@ pub fn Link(props: LinkProps) -> Element {
     let tag_target = new_tab.then_some("_blank");
 
     let is_external = matches!(parsed_route, NavigationTarget::External(_));
+    let href = match is_external {
+        true => href,
+        false => router.href(href),
+    };
     let is_router_nav = !is_external && !new_tab;
     let prevent_default = is_router_nav.then_some("onclick").unwrap_or_default();
     let rel = rel.or_else(|| is_external.then_some("noopener noreferrer".to_string()));
@@ -265,7 +269,7 @@ pub fn Link(props: LinkProps) -> Element {
 
     let action = move |event| {
         if do_default && is_router_nav {
-            router.push_any(router.resolve_into_routable(to.clone()));
+            router.push(to.clone());
         }
 
         if let Some(handler) = onclick.clone() {
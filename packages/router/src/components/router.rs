@@ -77,21 +77,56 @@ where
 {
     use crate::prelude::{outlet::OutletContext, RouterContext};
 
-    use_hook(|| {
-        provide_context(RouterContext::new(
-            (props
-                .config
-                .config
-                .take()
-                .expect("use_context_provider ran twice"))(),
-            schedule_update_any(),
-        ));
+    let deep_links = use_hook(|| {
+        let config = (props
+            .config
+            .config
+            .take()
+            .expect("use_context_provider ran twice"))();
+        let deep_links = config.deep_links;
+        provide_context(RouterContext::new(config, schedule_update_any()));
 
         provide_context(OutletContext::<R> {
             current_level: 0,
             _marker: std::marker::PhantomData,
         });
+
+        deep_links
+    });
+
+    // The back button of the platform goes back in the history of the router. Back handlers of components that are
+    // registered after the router are called first
+    let router = use_hook(|| {
+        let router = consume_context::<RouterContext>();
+        router.subscribe(current_scope_id().unwrap());
+        router
+    });
+    use_back_handler(router.can_go_back(), move || router.go_back());
+
+    let link = use_deep_link();
+    use_effect(move || {
+        if !deep_links {
+            return;
+        }
+        let Some(url) = link() else {
+            return;
+        };
+        let route = deep_link_route(&url);
+        match route.parse::<R>() {
+            Ok(route) => {
+                consume_context::<RouterContext>().push(route);
+            }
+            Err(err) => {
+                tracing::warn!("The app was opened with {url}, which is not a route: {err}")
+            }
+        }
     });
 
     rsx! { Outlet::<R> {} }
 }
+
+/// The route in a deep link is everything after the scheme, like `/settings?tab=1` in `myapp://settings?tab=1`
+fn deep_link_route(url: &str) -> String {
+    let without_scheme = url.split_once(':').map_or(url, |(_, rest)| rest);
+    format!("/{}", without_scheme.trim_start_matches('/'))
+}
@@ -1,6 +1,8 @@
 use dioxus_lib::prelude::*;
 
-use crate::{routable::Routable, utils::use_router_internal::use_router_internal};
+use crate::{
+    routable::Routable, scroll::run_script, utils::use_router_internal::use_router_internal,
+};
 
 pub(crate) struct OutletContext<R> {
     pub current_level: usize,
@@ -40,6 +42,13 @@ impl<R> OutletContext<R> {
             }
         });
 
+        // scroll once the new route is rendered
+        if current_level == 0 {
+            if let Some(script) = router.take_pending_scroll() {
+                spawn(async move { run_script(&script) });
+            }
+        }
+
         if let Some(error) = router.render_error() {
             if current_level == 0 {
                 return Some(error);
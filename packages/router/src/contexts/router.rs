@@ -12,6 +12,7 @@ use crate::{
     prelude::{AnyHistoryProvider, IntoRoutable},
     routable::Routable,
     router_cfg::RouterConfig,
+    scroll::{split_fragment, ScrollManager},
 };
 
 /// An error that can occur when navigating.
@@ -23,6 +24,8 @@ pub(crate) type RoutingCallback<R> =
     Arc<dyn Fn(GenericRouterContext<R>) -> Option<NavigationTarget<R>>>;
 pub(crate) type AnyRoutingCallback =
     Arc<dyn Fn(RouterContext) -> Option<NavigationTarget<Rc<dyn Any>>>>;
+/// A function that rewrites the `href` of links to internal routes.
+pub(crate) type RewriteHref = Arc<dyn Fn(&str) -> String>;
 
 struct RouterContextInner {
     /// The current prefix.
@@ -39,6 +42,10 @@ struct RouterContextInner {
     failure_external_navigation: fn() -> Element,
 
     any_route_to_string: fn(&dyn Any) -> String,
+
+    rewrite_href: Option<RewriteHref>,
+
+    scroll: ScrollManager,
 }
 
 impl RouterContextInner {
@@ -62,6 +69,19 @@ impl RouterContextInner {
             }
         }
     }
+
+    fn push(&mut self, route: Rc<dyn Any>, fragment: Option<&str>) {
+        let current = (self.any_route_to_string)(&*self.history.current_route());
+        // pushing the current route again only scrolls to the fragment
+        if (self.any_route_to_string)(&*route) == current {
+            self.history.push(route);
+            self.scroll.replaced(fragment);
+        } else {
+            self.scroll.leave();
+            self.history.push(route);
+            self.scroll.pushed(fragment);
+        }
+    }
 }
 
 /// A collection of router data that manages all routing functionality.
@@ -82,9 +102,12 @@ impl RouterContext {
         let subscriber_update = mark_dirty.clone();
         let subscribers = Arc::new(RwLock::new(HashSet::new()));
 
+        let history = cfg.take_history();
+        let scroll = ScrollManager::new(cfg.scroll_restoration, history.restores_scroll());
+
         let mut myself = RouterContextInner {
             prefix: Default::default(),
-            history: cfg.take_history(),
+            history,
             unresolved_error: None,
             subscribers: subscribers.clone(),
             subscriber_update,
@@ -120,6 +143,10 @@ impl RouterContext {
                     })
                     .to_string()
             },
+
+            rewrite_href: cfg.rewrite_href.take(),
+
+            scroll,
         };
 
         // set the updater
@@ -157,7 +184,12 @@ impl RouterContext {
     /// Will fail silently if there is no previous location to go to.
     pub fn go_back(&self) {
         {
-            self.inner.clone().write().history.go_back();
+            let mut write = self.inner.clone().write();
+            if write.history.can_go_back() {
+                write.scroll.leave();
+                write.history.go_back();
+                write.scroll.went_back();
+            }
         }
 
         self.change_route();
@@ -167,37 +199,32 @@ impl RouterContext {
     ///
     /// Will fail silently if there is no next location to go to.
     pub fn go_forward(&self) {
-        {
-            self.inner.clone().write().history.go_forward();
-        }
-
-        self.change_route();
-    }
-
-    pub(crate) fn push_any(
-        &self,
-        target: NavigationTarget<Rc<dyn Any>>,
-    ) -> Option<ExternalNavigationFailure> {
         {
             let mut write = self.inner.clone().write();
-            match target {
-                NavigationTarget::Internal(p) => write.history.push(p),
-                NavigationTarget::External(e) => return write.external(e),
+            if write.history.can_go_forward() {
+                write.scroll.leave();
+                write.history.go_forward();
+                write.scroll.went_forward();
             }
         }
 
-        self.change_route()
+        self.change_route();
     }
 
     /// Push a new location.
     ///
     /// The previous location will be available to go back to.
+    ///
+    /// If the target has a `#fragment`, the router scrolls to the element with that id after
+    /// navigating. A target that is only a fragment scrolls without navigating.
     pub fn push(&self, target: impl Into<IntoRoutable>) -> Option<ExternalNavigationFailure> {
-        let target = self.resolve_into_routable(target.into());
+        let target = target.into();
+        let fragment = self.fragment(&target)?;
+        let target = self.resolve_into_routable(target);
         {
             let mut write = self.inner.clone().write();
             match target {
-                NavigationTarget::Internal(p) => write.history.push(p),
+                NavigationTarget::Internal(p) => write.push(p, fragment.as_deref()),
                 NavigationTarget::External(e) => return write.external(e),
             }
         }
@@ -208,13 +235,20 @@ impl RouterContext {
     /// Replace the current location.
     ///
     /// The previous location will **not** be available to go back to.
+    ///
+    /// Fragments are handled like in [`RouterContext::push`].
     pub fn replace(&self, target: impl Into<IntoRoutable>) -> Option<ExternalNavigationFailure> {
-        let target = self.resolve_into_routable(target.into());
+        let target = target.into();
+        let fragment = self.fragment(&target)?;
+        let target = self.resolve_into_routable(target);
 
         {
             let mut state = self.inner.clone().write();
             match target {
-                NavigationTarget::Internal(p) => state.history.replace(p),
+                NavigationTarget::Internal(p) => {
+                    state.history.replace(p);
+                    state.scroll.replaced(fragment.as_deref());
+                }
                 NavigationTarget::External(e) => return state.external(e),
             }
         }
@@ -243,6 +277,14 @@ impl RouterContext {
         (self.inner.read().any_route_to_string)(route)
     }
 
+    /// Get the `href` a link to an internal route should render.
+    pub(crate) fn href(&self, route: String) -> String {
+        match &self.inner.read().rewrite_href {
+            Some(rewrite) => rewrite(&route),
+            None => route,
+        }
+    }
+
     pub(crate) fn resolve_into_routable(
         &self,
         into_routable: IntoRoutable,
@@ -259,6 +301,29 @@ impl RouterContext {
         }
     }
 
+    /// Get the fragment of a navigation target.
+    ///
+    /// Returns [`None`] if the target is only a fragment, after scrolling to it.
+    fn fragment(&self, target: &IntoRoutable) -> Option<Option<String>> {
+        let IntoRoutable::FromStr(url) = target else {
+            return Some(None);
+        };
+        match split_fragment(url) {
+            ("", fragment) => {
+                if let Some(fragment) = fragment {
+                    self.inner.read().scroll.scroll_to_fragment(&fragment);
+                }
+                None
+            }
+            (_, fragment) => Some(fragment),
+        }
+    }
+
+    /// Take the scroll that should run after the new route is rendered.
+    pub(crate) fn take_pending_scroll(&self) -> Option<String> {
+        self.inner.clone().write().scroll.take_pending()
+    }
+
     /// The prefix that is currently active.
     pub fn prefix(&self) -> Option<String> {
         self.inner.read().prefix.clone()
@@ -278,6 +278,16 @@ pub trait HistoryProvider<R: Routable> {
         false
     }
 
+    /// Check whether the [`HistoryProvider`] restores the scroll position itself.
+    ///
+    /// If this returns [`false`] and scroll restoration is enabled in the
+    /// [`RouterConfig`](crate::prelude::RouterConfig), the router saves and restores the scroll
+    /// position of every page with JavaScript instead.
+    #[must_use]
+    fn restores_scroll(&self) -> bool {
+        false
+    }
+
     /// Provide the [`HistoryProvider`] with an update callback.
     ///
     /// Some [`HistoryProvider`]s may receive URL updates from outside the router. When such
@@ -315,6 +325,11 @@ pub(crate) trait AnyHistoryProvider {
         false
     }
 
+    #[must_use]
+    fn restores_scroll(&self) -> bool {
+        false
+    }
+
     #[allow(unused_variables)]
     fn updater(&mut self, callback: Arc<dyn Fn() + Send + Sync>) {}
 }
@@ -386,6 +401,10 @@ where
         self.inner.external(url)
     }
 
+    fn restores_scroll(&self) -> bool {
+        self.inner.restores_scroll()
+    }
+
     fn updater(&mut self, callback: Arc<dyn Fn() + Send + Sync>) {
         self.inner.updater(callback)
     }
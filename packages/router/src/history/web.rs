@@ -227,6 +227,10 @@ where
         self.navigate_external(url)
     }
 
+    fn restores_scroll(&self) -> bool {
+        self.do_scroll_restoration
+    }
+
     fn updater(&mut self, callback: std::sync::Arc<dyn Fn() + Send + Sync>) {
         let w = self.window.clone();
         let h = self.history.clone();
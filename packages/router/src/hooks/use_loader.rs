@@ -0,0 +1,160 @@
+use std::future::Future;
+
+use dioxus_lib::prelude::*;
+
+/// A value that can be returned from a route loader.
+///
+/// With the `fullstack` feature, loaders run on the server during the initial render and the data is sent to the client, so the value must be serializable.
+#[cfg(feature = "fullstack")]
+pub trait LoaderValue: Clone + serde::Serialize + serde::de::DeserializeOwned + 'static {}
+
+#[cfg(feature = "fullstack")]
+impl<T: Clone + serde::Serialize + serde::de::DeserializeOwned + 'static> LoaderValue for T {}
+
+/// A value that can be returned from a route loader.
+///
+/// With the `fullstack` feature, loaders run on the server during the initial render and the data is sent to the client, so the value must be serializable.
+#[cfg(not(feature = "fullstack"))]
+pub trait LoaderValue: Clone + 'static {}
+
+#[cfg(not(feature = "fullstack"))]
+impl<T: Clone + 'static> LoaderValue for T {}
+
+/// Load data in a layout before the routes inside it render.
+///
+/// The layout is suspended until the future resolves, so the [`Outlet`](crate::prelude::Outlet) and every child route only render once the data is ready. The data is provided to the routes inside the layout, which can read it with [`use_loader_data`].
+///
+/// The loader runs again when any signal read in the closure changes. The layout is suspended while it reloads.
+///
+/// With the `fullstack` feature, the loader runs on the server during the initial render and the client is hydrated with the loaded data instead of running it again.
+///
+/// ```rust
+/// # use dioxus::prelude::*;
+/// # use dioxus_router::prelude::*;
+/// #[derive(Clone, Routable)]
+/// #[rustfmt::skip]
+/// enum Route {
+///     #[layout(Account)]
+///         #[route("/")]
+///         Profile {},
+/// }
+///
+/// #[derive(Clone)]
+/// struct User {
+///     name: String,
+/// }
+///
+/// #[component]
+/// fn Account() -> Element {
+///     use_loader(|| async { User { name: "Ferris".to_string() } })?;
+///
+///     rsx! { Outlet::<Route> {} }
+/// }
+///
+/// #[component]
+/// fn Profile() -> Element {
+///     let user = use_loader_data::<User>().cloned();
+///
+///     rsx! { h1 { "{user.name}" } }
+/// }
+/// #
+/// # #[component]
+/// # fn App() -> Element {
+/// #     rsx! {
+/// #         Router::<Route> {
+/// #             config: || RouterConfig::default().history(MemoryHistory::default())
+/// #         }
+/// #     }
+/// # }
+/// #
+/// # let mut vdom = VirtualDom::new(App);
+/// # vdom.rebuild_in_place();
+/// # assert_eq!(dioxus_ssr::render(&vdom), "<h1>Ferris</h1>");
+/// ```
+pub fn use_loader<T, F>(loader: impl Fn() -> F + 'static) -> Option<LoaderData<T>>
+where
+    T: LoaderValue,
+    F: Future<Output = T> + 'static,
+{
+    #[cfg(feature = "fullstack")]
+    let resource = dioxus_fullstack::prelude::use_server_future(loader)?;
+    #[cfg(not(feature = "fullstack"))]
+    let resource = use_client_loader(loader)?;
+
+    Some(use_hook(|| {
+        provide_context(LoaderData {
+            value: resource.value(),
+        })
+    }))
+}
+
+/// Run the loader on the client, suspending until it resolves
+#[cfg(not(feature = "fullstack"))]
+fn use_client_loader<T, F>(loader: impl Fn() -> F + 'static) -> Option<Resource<T>>
+where
+    T: 'static,
+    F: Future<Output = T> + 'static,
+{
+    let resource = use_resource(loader);
+
+    // Poll the loader right away so data that is already available doesn't suspend the layout
+    use_hook(|| {
+        let _ = resource.task().poll_now();
+    });
+
+    match resource.state().cloned() {
+        UseResourceState::Pending => {
+            suspend();
+            None
+        }
+        _ => Some(resource),
+    }
+}
+
+/// Read the data loaded by the closest layout that called [`use_loader`] with the same type.
+///
+/// # Panics
+///
+/// Panics if no layout above this component loaded data of this type.
+#[must_use]
+pub fn use_loader_data<T: LoaderValue>() -> LoaderData<T> {
+    use_hook(|| {
+        try_consume_context::<LoaderData<T>>().unwrap_or_else(|| {
+            panic!(
+                "use_loader_data::<{}>() must be called in a route inside a layout that loads it with use_loader",
+                std::any::type_name::<T>()
+            )
+        })
+    })
+}
+
+/// The data loaded by a layout with [`use_loader`].
+pub struct LoaderData<T: 'static> {
+    value: ReadOnlySignal<Option<T>>,
+}
+
+impl<T: 'static> Clone for LoaderData<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: 'static> Copy for LoaderData<T> {}
+
+impl<T: Clone + 'static> LoaderData<T> {
+    /// Get a clone of the loaded data. Reading the data in a component subscribes the component to changes when the loader runs again
+    pub fn cloned(&self) -> T {
+        self.value
+            .read()
+            .clone()
+            .expect("The layout only renders its routes after the loader resolves")
+    }
+
+    /// Get a clone of the loaded data without subscribing to changes
+    pub fn peek(&self) -> T {
+        self.value
+            .peek()
+            .clone()
+            .expect("The layout only renders its routes after the loader resolves")
+    }
+}
@@ -1,11 +1,12 @@
 //! Extensions to the incremental renderer to support pre-caching static routes.
 use core::pin::Pin;
 use std::future::Future;
+use std::rc::Rc;
 use std::str::FromStr;
 
 use dioxus_lib::prelude::*;
 use dioxus_ssr::incremental::{
-    IncrementalRenderer, IncrementalRendererError, RenderFreshness, WrapBody,
+    IncrementalRenderer, IncrementalRendererError, RenderFreshness, StaticSiteManifest, WrapBody,
 };
 
 use crate::prelude::*;
@@ -69,6 +70,132 @@ where
     Ok(())
 }
 
+/// Options for [`generate_static_site`].
+pub struct StaticSiteConfig<Rt> {
+    routes: Vec<Rt>,
+    rewrite_href: Option<Rc<dyn Fn(&str) -> String>>,
+}
+
+impl<Rt: Routable> Default for StaticSiteConfig<Rt>
+where
+    <Rt as FromStr>::Err: std::fmt::Display,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Rt: Routable> StaticSiteConfig<Rt>
+where
+    <Rt as FromStr>::Err: std::fmt::Display,
+{
+    /// Create a config that generates every static route and rewrites the `href` of links to the generated files with [`index_html_href`].
+    pub fn new() -> Self {
+        Self {
+            routes: Rt::static_routes(),
+            rewrite_href: Some(Rc::new(index_html_href)),
+        }
+    }
+
+    /// Generate routes with dynamic segments, like `/blog/:id`, in addition to the static routes.
+    pub fn routes(mut self, routes: impl IntoIterator<Item = Rt>) -> Self {
+        self.routes.extend(routes);
+        self
+    }
+
+    /// Set the function that rewrites the `href` of every [`Link`] to an internal route. See [`RouterConfig::rewrite_href`].
+    pub fn rewrite_href(mut self, rewrite: impl Fn(&str) -> String + 'static) -> Self {
+        self.rewrite_href = Some(Rc::new(rewrite));
+        self
+    }
+
+    /// Keep the `href` of links as the route. Use this if the server hosting the site serves `index.html` files for directories.
+    pub fn keep_href(mut self) -> Self {
+        self.rewrite_href = None;
+        self
+    }
+}
+
+/// Rewrite the `href` of a route to the `index.html` file it is generated in. `/blog?page=2` becomes `/blog/index.html?page=2`.
+pub fn index_html_href(route: &str) -> String {
+    let query_start = route.find(['?', '#']).unwrap_or(route.len());
+    let (path, query) = route.split_at(query_start);
+    let path = path.trim_end_matches('/');
+    format!("{path}/index.html{query}")
+}
+
+/// Render every route of a static site to a HTML file in the static directory of the renderer.
+///
+/// Each route is rendered to `index.html` in the folder of the route, after any suspended components resolve. Returns a manifest of the generated pages, which is also written to `manifest.json` in the static directory.
+///
+/// ```rust, no_run
+/// # use dioxus::prelude::*;
+/// # use dioxus_router::prelude::*;
+/// # use dioxus_ssr::incremental::{DefaultRenderer, IncrementalRendererConfig};
+/// # #[component]
+/// # fn Home() -> Element { None }
+/// # #[component]
+/// # fn Post(id: usize) -> Element { None }
+/// #[derive(Clone, PartialEq, Routable)]
+/// enum Route {
+///     #[route("/")]
+///     Home {},
+///     #[route("/post/:id")]
+///     Post { id: usize },
+/// }
+///
+/// # async fn generate() {
+/// let mut renderer = IncrementalRendererConfig::new().static_dir("./dist").build();
+/// let manifest = generate_static_site(
+///     &mut renderer,
+///     &DefaultRenderer::default(),
+///     StaticSiteConfig::<Route>::new().routes((0..10).map(|id| Route::Post { id })),
+/// )
+/// .await
+/// .unwrap();
+/// assert_eq!(manifest.pages().len(), 11);
+/// # }
+/// ```
+pub async fn generate_static_site<Rt, R: WrapBody + Send + Sync>(
+    renderer: &mut IncrementalRenderer,
+    wrapper: &R,
+    config: StaticSiteConfig<Rt>,
+) -> Result<StaticSiteManifest, IncrementalRendererError>
+where
+    Rt: Routable,
+    <Rt as FromStr>::Err: std::fmt::Display,
+{
+    let mut manifest = StaticSiteManifest::new();
+    for route in config.routes {
+        let path = route.to_string();
+        if manifest.page(&path).is_some() {
+            continue;
+        }
+
+        render_route_with(
+            renderer,
+            route,
+            &mut tokio::io::sink(),
+            |vdom| {
+                Box::pin(async move {
+                    vdom.rebuild_in_place();
+                    vdom.wait_for_suspense().await;
+                })
+            },
+            wrapper,
+            config.rewrite_href.clone(),
+        )
+        .await?;
+
+        if !renderer.add_to_manifest(&path, &mut manifest) {
+            tracing::error!("Static route {} was not written to a file", path);
+        }
+    }
+
+    renderer.write_manifest(&manifest)?;
+    Ok(manifest)
+}
+
 /// Render a route to a writer.
 pub async fn render_route<
     R: WrapBody + Send + Sync,
@@ -82,13 +209,37 @@ pub async fn render_route<
     modify_vdom: F,
     wrapper: &R,
 ) -> Result<RenderFreshness, IncrementalRendererError>
+where
+    Rt: Routable,
+    <Rt as FromStr>::Err: std::fmt::Display,
+    W: tokio::io::AsyncWrite + Unpin + Send,
+{
+    render_route_with(renderer, route, writer, modify_vdom, wrapper, None).await
+}
+
+async fn render_route_with<
+    R: WrapBody + Send + Sync,
+    Rt,
+    W,
+    F: FnOnce(&mut VirtualDom) -> Pin<Box<dyn Future<Output = ()> + '_>>,
+>(
+    renderer: &mut IncrementalRenderer,
+    route: Rt,
+    writer: &mut W,
+    modify_vdom: F,
+    wrapper: &R,
+    rewrite_href: Option<Rc<dyn Fn(&str) -> String>>,
+) -> Result<RenderFreshness, IncrementalRendererError>
 where
     Rt: Routable,
     <Rt as FromStr>::Err: std::fmt::Display,
     W: tokio::io::AsyncWrite + Unpin + Send,
 {
     #[derive(Clone)]
-    struct RootProps<Rt>(Rt);
+    struct RootProps<Rt> {
+        route: Rt,
+        rewrite_href: Option<Rc<dyn Fn(&str) -> String>>,
+    }
 
     impl<Rt> PartialEq for RootProps<Rt> {
         fn eq(&self, _: &Self) -> bool {
@@ -101,10 +252,20 @@ where
         R: Routable,
         <R as FromStr>::Err: std::fmt::Display,
     {
-        let path = props.0;
+        let RootProps {
+            route,
+            rewrite_href,
+        } = props;
         rsx! {
             Router::<R> {
-                config: || RouterConfig::default().history(MemoryHistory::with_initial_path(path))
+                config: move || {
+                    let config = RouterConfig::default()
+                        .history(MemoryHistory::with_initial_path(route.clone()));
+                    match rewrite_href.clone() {
+                        Some(rewrite) => config.rewrite_href(move |href| rewrite(href)),
+                        None => config,
+                    }
+                }
             }
         }
     }
@@ -112,7 +273,15 @@ where
     renderer
         .render(
             route.to_string(),
-            || VirtualDom::new_with_props(RenderPath, RootProps(route)),
+            || {
+                VirtualDom::new_with_props(
+                    RenderPath,
+                    RootProps {
+                        route,
+                        rewrite_href,
+                    },
+                )
+            },
             writer,
             modify_vdom,
             wrapper,
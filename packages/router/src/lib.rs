@@ -39,6 +39,8 @@ mod contexts {
 
 mod router_cfg;
 
+mod scroll;
+
 mod history;
 
 /// Hooks for interacting with the router in components.
@@ -51,6 +53,9 @@ pub mod hooks {
 
     mod use_navigator;
     pub use use_navigator::*;
+
+    mod use_loader;
+    pub use use_loader::*;
 }
 
 pub use hooks::router;
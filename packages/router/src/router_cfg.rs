@@ -1,4 +1,4 @@
-use crate::contexts::router::RoutingCallback;
+use crate::contexts::router::{RewriteHref, RoutingCallback};
 use crate::history::HistoryProvider;
 use crate::prelude::*;
 use crate::routable::Routable;
@@ -27,6 +27,9 @@ pub struct RouterConfig<R: Routable> {
     pub(crate) history: Option<Box<dyn AnyHistoryProvider>>,
     pub(crate) on_update: Option<RoutingCallback<R>>,
     pub(crate) initial_route: Option<R>,
+    pub(crate) scroll_restoration: bool,
+    pub(crate) rewrite_href: Option<RewriteHref>,
+    pub(crate) deep_links: bool,
 }
 
 impl<R: Routable + Clone> Default for RouterConfig<R>
@@ -39,6 +42,9 @@ where
             history: None,
             on_update: None,
             initial_route: None,
+            scroll_restoration: true,
+            rewrite_href: None,
+            deep_links: false,
         }
     }
 }
@@ -55,7 +61,7 @@ where
         ));
         self.history
             .take()
-            .unwrap_or_else(|| default_history(initial_route))
+            .unwrap_or_else(|| default_history(initial_route, self.scroll_restoration))
     }
 }
 
@@ -105,6 +111,49 @@ where
         }
     }
 
+    /// Whether the router should manage the scroll position when navigating.
+    ///
+    /// If enabled, the router scrolls to the top of the page when a new route is pushed, restores
+    /// the previous scroll position when going back or forward, and scrolls to the element with
+    /// the id in the `#fragment` of a target like `/blog#comments` after navigating.
+    ///
+    /// `WebHistory` does this with `history.scrollRestoration`. Other history providers, like
+    /// the ones used on desktop and liveview, save and restore the position with JavaScript.
+    ///
+    /// Defaults to [`true`].
+    pub fn scroll_restoration(self, scroll_restoration: bool) -> Self {
+        Self {
+            scroll_restoration,
+            ..self
+        }
+    }
+
+    /// A function that rewrites the `href` of every [`Link`] to an internal route.
+    ///
+    /// This only changes the `href` attribute that is rendered. Clicking the link still navigates
+    /// to the route inside the app. This is useful for static sites that need links to point to
+    /// the generated HTML files, or for apps served under a base path.
+    ///
+    /// Defaults to [`None`].
+    pub fn rewrite_href(self, rewrite: impl Fn(&str) -> String + 'static) -> Self {
+        Self {
+            rewrite_href: Some(Arc::new(rewrite)),
+            ..self
+        }
+    }
+
+    /// Whether the router should navigate to the route in the URL the app is opened with.
+    ///
+    /// Renderers that support custom URL schemes, like desktop, tell the app about the URLs it is
+    /// opened with through [`use_deep_link`]. If enabled, the router pushes the part of the URL
+    /// after the scheme as a route, so `myapp://settings/profile?tab=1` navigates to
+    /// `/settings/profile?tab=1`. URLs that don't match a route are ignored.
+    ///
+    /// Defaults to [`false`].
+    pub fn deep_links(self, deep_links: bool) -> Self {
+        Self { deep_links, ..self }
+    }
+
     /// A component to render when an external navigation fails.
     ///
     /// Defaults to a router-internal component called [`FailureExternalNavigation`]
@@ -118,16 +167,20 @@ where
 
 /// Get the default history provider for the current platform.
 #[allow(unreachable_code, unused)]
-fn default_history<R: Routable + Clone>(initial_route: R) -> Box<dyn AnyHistoryProvider>
+fn default_history<R: Routable + Clone>(
+    initial_route: R,
+    scroll_restoration: bool,
+) -> Box<dyn AnyHistoryProvider>
 where
     <R as std::str::FromStr>::Err: std::fmt::Display,
 {
     // If we're on the web and have wasm, use the web history provider
 
     #[cfg(all(target_arch = "wasm32", feature = "web"))]
-    return Box::new(AnyHistoryProviderImplWrapper::new(
-        WebHistory::<R>::default(),
-    ));
+    return Box::new(AnyHistoryProviderImplWrapper::new(WebHistory::<R>::new(
+        None,
+        scroll_restoration,
+    )));
 
     // If we're using fullstack and server side rendering, use the memory history provider
     #[cfg(all(feature = "fullstack", feature = "ssr"))]
@@ -0,0 +1,147 @@
+//! Scroll restoration and fragment navigation for every renderer that supports JavaScript.
+
+use std::fmt::Write;
+
+use dioxus_lib::prelude::*;
+
+/// Manages the scroll position when the router navigates.
+///
+/// If the history provider doesn't restore the scroll position itself, the position of every
+/// page is saved with JavaScript before leaving it, keyed by its depth in the history.
+pub(crate) struct ScrollManager {
+    enabled: bool,
+    save_positions: bool,
+    depth: usize,
+    pending: Option<String>,
+}
+
+impl ScrollManager {
+    pub(crate) fn new(enabled: bool, history_restores_scroll: bool) -> Self {
+        Self {
+            enabled,
+            save_positions: enabled && !history_restores_scroll,
+            depth: 0,
+            pending: None,
+        }
+    }
+
+    /// Save the scroll position of the current page before navigating away from it.
+    pub(crate) fn leave(&self) {
+        if self.save_positions {
+            run_script(&format!(
+                "(window.__dioxusRouterScroll ??= {{}})[{}] = [window.scrollX, window.scrollY];",
+                self.depth
+            ));
+        }
+    }
+
+    /// A new page was pushed. Scroll to the fragment if there is one, otherwise to the top.
+    pub(crate) fn pushed(&mut self, fragment: Option<&str>) {
+        self.depth += 1;
+        if !self.enabled {
+            return;
+        }
+        self.pending = match fragment {
+            Some(fragment) => Some(scroll_to_fragment(fragment)),
+            None if self.save_positions => Some(SCROLL_TO_TOP.to_string()),
+            None => None,
+        };
+    }
+
+    /// The current page was replaced. Only scroll if there is a fragment.
+    pub(crate) fn replaced(&mut self, fragment: Option<&str>) {
+        if self.enabled {
+            self.pending = fragment.map(scroll_to_fragment);
+        }
+    }
+
+    /// The router went back to the previous page. Restore its scroll position.
+    pub(crate) fn went_back(&mut self) {
+        self.depth = self.depth.saturating_sub(1);
+        self.restore();
+    }
+
+    /// The router went forward to the next page. Restore its scroll position.
+    pub(crate) fn went_forward(&mut self) {
+        self.depth += 1;
+        self.restore();
+    }
+
+    /// Scroll to a fragment on the current page without navigating.
+    pub(crate) fn scroll_to_fragment(&self, fragment: &str) {
+        if self.enabled {
+            run_script(&scroll_to_fragment(fragment));
+        }
+    }
+
+    /// Take the scroll that should run once the new page is rendered.
+    pub(crate) fn take_pending(&mut self) -> Option<String> {
+        self.pending.take()
+    }
+
+    fn restore(&mut self) {
+        if self.save_positions {
+            self.pending = Some(scroll_to_saved(self.depth));
+        }
+    }
+}
+
+/// Split the `#fragment` off of a navigation target.
+pub(crate) fn split_fragment(target: &str) -> (&str, Option<String>) {
+    match target.split_once('#') {
+        Some((path, fragment)) if !fragment.is_empty() => {
+            let fragment = urlencoding::decode(fragment)
+                .map(|fragment| fragment.into_owned())
+                .unwrap_or_else(|_| fragment.to_string());
+            (path, Some(fragment))
+        }
+        Some((path, _)) => (path, None),
+        None => (target, None),
+    }
+}
+
+/// Run a script in the root scope, so it still runs if the component that navigated is unmounted.
+pub(crate) fn run_script(script: &str) {
+    ScopeId::ROOT.in_runtime(|| {
+        let _ = eval(script);
+    });
+}
+
+const SCROLL_TO_TOP: &str = "requestAnimationFrame(() => window.scrollTo(0, 0));";
+
+/// Scroll to a position saved with [`ScrollManager::leave`], or to the top if there is none.
+fn scroll_to_saved(depth: usize) -> String {
+    format!(
+        r#"requestAnimationFrame(() => {{
+            const position = (window.__dioxusRouterScroll ?? {{}})[{depth}] ?? [0, 0];
+            window.scrollTo(position[0], position[1]);
+        }});"#
+    )
+}
+
+fn scroll_to_fragment(fragment: &str) -> String {
+    format!(
+        r#"requestAnimationFrame(() => {{
+            const element = document.getElementById({});
+            if (element) element.scrollIntoView();
+        }});"#,
+        js_string(fragment)
+    )
+}
+
+/// Quote a string for JavaScript, escaping everything that could end the string or script.
+fn js_string(value: &str) -> String {
+    let mut quoted = String::from('"');
+    for unit in value.encode_utf16() {
+        match char::from_u32(unit as u32) {
+            Some(c) if c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.' | ':') => {
+                quoted.push(c)
+            }
+            _ => {
+                let _ = write!(quoted, "\\u{unit:04x}");
+            }
+        }
+    }
+    quoted.push('"');
+    quoted
+}
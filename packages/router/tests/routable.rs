@@ -0,0 +1,6 @@
+#[test]
+fn routable() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/routable/duplicate-route.rs");
+    t.compile_fail("tests/routable/duplicate-redirect.rs");
+}
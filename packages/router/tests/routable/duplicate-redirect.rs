@@ -0,0 +1,24 @@
+use dioxus::prelude::*;
+
+#[derive(Routable, Clone, PartialEq)]
+enum Route {
+    #[nest("/blog")]
+        #[route("/")]
+        Home {},
+    #[end_nest]
+    #[redirect("/blog", || Route::Home {})]
+    #[route("/about")]
+    About {},
+}
+
+#[component]
+fn Home() -> Element {
+    None
+}
+
+#[component]
+fn About() -> Element {
+    None
+}
+
+fn main() {}
@@ -0,0 +1,21 @@
+use dioxus::prelude::*;
+
+#[derive(Routable, Clone, PartialEq)]
+enum Route {
+    #[route("/blog/:id")]
+    Post { id: u64 },
+    #[route("/blog/:number")]
+    Article { number: u64 },
+}
+
+#[component]
+fn Post(id: u64) -> Element {
+    None
+}
+
+#[component]
+fn Article(number: u64) -> Element {
+    None
+}
+
+fn main() {}
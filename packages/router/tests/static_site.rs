@@ -0,0 +1,82 @@
+#![cfg(feature = "ssr")]
+#![allow(non_snake_case)]
+
+use dioxus::prelude::*;
+use dioxus_ssr::incremental::{DefaultRenderer, IncrementalRendererConfig};
+
+#[derive(Routable, Clone, PartialEq)]
+enum Route {
+    #[route("/")]
+    Home {},
+    #[route("/post/:id")]
+    Post { id: usize },
+}
+
+#[component]
+fn Home() -> Element {
+    rsx! {
+        Link { to: Route::Post { id: 1 }, "First post" }
+    }
+}
+
+#[component]
+fn Post(id: usize) -> Element {
+    rsx! { "Post {id}" }
+}
+
+#[tokio::test]
+async fn generates_a_page_for_every_route() {
+    let static_dir =
+        std::env::temp_dir().join(format!("dioxus-static-site-{}", std::process::id()));
+    let mut renderer = IncrementalRendererConfig::new()
+        .static_dir(&static_dir)
+        .build();
+
+    let manifest = generate_static_site(
+        &mut renderer,
+        &DefaultRenderer {
+            before_body: String::new(),
+            after_body: String::new(),
+        },
+        StaticSiteConfig::<Route>::new().routes((1..=2).map(|id| Route::Post { id })),
+    )
+    .await
+    .unwrap();
+
+    let pages = manifest
+        .pages()
+        .iter()
+        .map(|page| (page.route.as_str(), page.file.to_str().unwrap()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        pages,
+        [
+            ("/", "index.html"),
+            ("/post/1", "post/1/index.html"),
+            ("/post/2", "post/2/index.html"),
+        ]
+    );
+
+    // Links point to the generated files
+    let home = std::fs::read_to_string(static_dir.join("index.html")).unwrap();
+    assert!(home.contains(r#"href="/post/1/index.html""#), "{home}");
+    let post = std::fs::read_to_string(static_dir.join("post/2/index.html")).unwrap();
+    assert_eq!(post, "Post 2");
+
+    let written = std::fs::read_to_string(static_dir.join("manifest.json")).unwrap();
+    assert_eq!(written, manifest.to_json());
+    assert!(
+        written.contains(r#""file": "post/1/index.html""#),
+        "{written}"
+    );
+
+    std::fs::remove_dir_all(static_dir).unwrap();
+}
+
+#[test]
+fn index_html_hrefs() {
+    assert_eq!(index_html_href("/"), "/index.html");
+    assert_eq!(index_html_href("/blog"), "/blog/index.html");
+    assert_eq!(index_html_href("/blog/?page=2"), "/blog/index.html?page=2");
+    assert_eq!(index_html_href("/blog#top"), "/blog/index.html#top");
+}
@@ -0,0 +1,57 @@
+use dioxus::prelude::*;
+use std::{cell::RefCell, rc::Rc};
+
+thread_local! {
+    static NAVIGATOR: RefCell<Option<Navigator>> = const { RefCell::new(None) };
+}
+
+#[derive(Routable, Clone)]
+enum Route {
+    #[route("/")]
+    Root {},
+    #[route("/settings")]
+    Settings {},
+}
+
+#[component]
+fn Root() -> Element {
+    NAVIGATOR.with(|nav| *nav.borrow_mut() = Some(navigator()));
+    rsx! { "root" }
+}
+
+#[component]
+fn Settings() -> Element {
+    rsx! { "settings" }
+}
+
+#[test]
+fn back_button_goes_back_in_the_router_history() {
+    let mut vdom = VirtualDom::new(|| {
+        rsx! {
+            Router::<Route> {
+                config: || RouterConfig::default().history(MemoryHistory::default())
+            }
+        }
+    });
+    let enabled = Rc::new(RefCell::new(Vec::new()));
+    let back = vdom.in_runtime(|| {
+        let enabled = enabled.clone();
+        BackHandlerContext::provide(move |value| enabled.borrow_mut().push(value))
+    });
+    vdom.rebuild_in_place();
+
+    // There is no page to go back to, so the platform handles the back button
+    assert!(enabled.borrow().is_empty());
+    assert!(!vdom.in_runtime(|| back.back()));
+
+    let navigator = NAVIGATOR.with(|nav| nav.borrow().unwrap());
+    vdom.in_runtime(|| navigator.push(Route::Settings {}));
+    vdom.render_immediate_to_vec();
+    assert_eq!(dioxus_ssr::render(&vdom), "settings");
+    assert_eq!(*enabled.borrow(), [true]);
+
+    assert!(vdom.in_runtime(|| back.back()));
+    vdom.render_immediate_to_vec();
+    assert_eq!(dioxus_ssr::render(&vdom), "root");
+    assert_eq!(*enabled.borrow(), [true, false]);
+}
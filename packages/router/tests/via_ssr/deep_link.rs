@@ -0,0 +1,57 @@
+use dioxus::prelude::*;
+
+#[derive(Routable, Clone)]
+enum Route {
+    #[route("/")]
+    Root {},
+    #[route("/settings/:tab")]
+    Settings { tab: String },
+}
+
+#[component]
+fn Root() -> Element {
+    rsx! { "root" }
+}
+
+#[component]
+fn Settings(tab: String) -> Element {
+    rsx! { "settings {tab}" }
+}
+
+async fn open(deep_links: bool, url: &str) -> String {
+    let mut vdom = VirtualDom::new_with_props(
+        |deep_links: bool| {
+            rsx! {
+                Router::<Route> {
+                    config: move || RouterConfig::default().history(MemoryHistory::default()).deep_links(deep_links)
+                }
+            }
+        },
+        deep_links,
+    );
+    vdom.in_runtime(|| DeepLinkContext::provide(Some(url.to_string())));
+    vdom.rebuild_in_place();
+
+    // The route is pushed in an effect after the router is mounted
+    _ = tokio::time::timeout(std::time::Duration::from_millis(100), vdom.wait_for_work()).await;
+    vdom.render_immediate_to_vec();
+    dioxus_ssr::render(&vdom)
+}
+
+#[tokio::test]
+async fn deep_links_navigate_to_their_route() {
+    assert_eq!(
+        open(true, "myapp://settings/profile").await,
+        "settings profile"
+    );
+    assert_eq!(
+        open(true, "myapp:///settings/profile").await,
+        "settings profile"
+    );
+}
+
+#[tokio::test]
+async fn deep_links_are_ignored_unless_enabled_or_valid() {
+    assert_eq!(open(false, "myapp://settings/profile").await, "root");
+    assert_eq!(open(true, "myapp://missing").await, "root");
+}
@@ -356,3 +356,59 @@ fn with_rel() {
 
     assert_eq!(prepare::<Route>(), expected);
 }
+
+#[test]
+fn with_rewritten_href() {
+    #[derive(Routable, Clone)]
+    enum Route {
+        #[route("/")]
+        Root {},
+        #[route("/test")]
+        Test {},
+    }
+
+    #[component]
+    fn Test() -> Element {
+        unimplemented!()
+    }
+
+    #[component]
+    fn Root() -> Element {
+        rsx! {
+            Link {
+                to: Route::Test {},
+                "Internal"
+            }
+            Link {
+                to: "https://dioxuslabs.com/",
+                "External"
+            }
+        }
+    }
+
+    fn app() -> Element {
+        rsx! {
+            Router::<Route> {
+                config: || {
+                    RouterConfig::default()
+                        .history(MemoryHistory::default())
+                        .rewrite_href(|href| format!("/docs{href}"))
+                }
+            }
+        }
+    }
+
+    let mut vdom = VirtualDom::new(app);
+    vdom.rebuild_in_place();
+
+    // External links are not rewritten
+    let expected = format!(
+        "<a {internal} {default}>Internal</a><a {external} {rel}>External</a>",
+        internal = r#"href="/docs/test""#,
+        default = r#"dioxus-prevent-default="onclick""#,
+        external = r#"href="https://dioxuslabs.com/" dioxus-prevent-default="""#,
+        rel = r#"rel="noopener noreferrer""#,
+    );
+
+    assert_eq!(dioxus_ssr::render(&vdom), expected);
+}
@@ -0,0 +1,87 @@
+#![allow(unused)]
+
+use dioxus::prelude::*;
+use dioxus_router::prelude::*;
+
+fn prepare(path: impl Into<String>) -> VirtualDom {
+    let mut vdom = VirtualDom::new_with_props(App, AppProps { path: path.into() });
+    vdom.rebuild_in_place();
+    return vdom;
+
+    #[derive(Routable, Clone)]
+    #[rustfmt::skip]
+    enum Route {
+        #[layout(Team)]
+            #[route("/")]
+            TeamIndex {},
+            #[nest("/:id")]
+                #[layout(Member)]
+                    #[route("/")]
+                    MemberIndex { id: usize },
+    }
+
+    #[derive(Clone)]
+    struct TeamName(String);
+
+    #[derive(Clone)]
+    struct MemberName(String);
+
+    #[component]
+    fn App(path: String) -> Element {
+        rsx! {
+            Router::<Route> {
+                config: {
+                    let path = path.parse().unwrap();
+                    move || RouterConfig::default().history(MemoryHistory::with_initial_path(path))
+                }
+            }
+        }
+    }
+
+    #[component]
+    fn Team() -> Element {
+        let name = use_loader(|| async { TeamName("Crabs".to_string()) })?;
+        let name = name.cloned().0;
+
+        rsx! {
+            h1 { "{name}" }
+            Outlet::<Route> { }
+        }
+    }
+
+    #[component]
+    fn TeamIndex() -> Element {
+        let team = use_loader_data::<TeamName>().cloned().0;
+        rsx! { h2 { "Index of {team}" } }
+    }
+
+    #[component]
+    fn Member(id: usize) -> Element {
+        use_loader(move || async move { MemberName(format!("Member {id}")) })?;
+
+        rsx! { Outlet::<Route> { } }
+    }
+
+    #[component]
+    fn MemberIndex(id: usize) -> Element {
+        let team = use_loader_data::<TeamName>().cloned().0;
+        let member = use_loader_data::<MemberName>().cloned().0;
+        rsx! { h2 { "{member} of {team}" } }
+    }
+}
+
+#[test]
+fn layout_loader() {
+    let vdom = prepare("/");
+    let html = dioxus_ssr::render(&vdom);
+
+    assert_eq!(html, "<h1>Crabs</h1><h2>Index of Crabs</h2>");
+}
+
+#[test]
+fn nested_layout_loaders() {
+    let vdom = prepare("/3");
+    let html = dioxus_ssr::render(&vdom);
+
+    assert_eq!(html, "<h1>Crabs</h1><h2>Member 3 of Crabs</h2>");
+}
@@ -1,2 +1,5 @@
+mod back_button;
+mod deep_link;
 mod link;
+mod loader;
 mod outlet;
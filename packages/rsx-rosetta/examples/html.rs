@@ -1,4 +1,4 @@
-use html_parser::Dom;
+use rsx_rosetta::{convert_html, ConvertOptions};
 
 fn main() {
     let html = r#"
@@ -14,11 +14,9 @@ fn main() {
     "#
     .trim();
 
-    let dom = Dom::parse(html).unwrap();
+    let conversion = convert_html(html, &ConvertOptions::new());
 
-    let body = rsx_rosetta::rsx_from_html(&dom);
-
-    let out = dioxus_autofmt::write_block_out(body).unwrap();
+    let out = dioxus_autofmt::write_block_out(conversion.body).unwrap();
 
     println!("{out}");
 }
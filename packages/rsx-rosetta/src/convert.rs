@@ -0,0 +1,291 @@
+//! Convert html5 fragments into rsx
+//!
+//! The html is parsed with a spec compliant html5 parser, so anything a browser accepts (unclosed tags, implicit
+//! `<tbody>`s, entities, `<template>`s, inline svg) converts the same way it would render. Full documents starting
+//! with a doctype or `<html>` are split into their head and body.
+
+use crate::{collect_svgs, element_from_html, ifmt_from_text};
+use convert_case::{Case, Casing};
+use dioxus_rsx::{BodyNode, CallBody, Component, ComponentField, ContentField};
+use html5ever::{
+    local_name, namespace_url, ns, parse_document, parse_fragment, tendril::TendrilSink, ParseOpts,
+    QualName,
+};
+use markup5ever_rcdom::{Handle, NodeData, RcDom};
+use proc_macro2::{Ident, Span};
+use std::collections::HashMap;
+use syn::LitStr;
+
+/// Options for [`convert_html`]
+///
+/// ```rust
+/// use rsx_rosetta::ConvertOptions;
+///
+/// let options = ConvertOptions::new()
+///     .with_extracted_svgs(true)
+///     .with_comments(true)
+///     .with_component("my-card", syn::parse_quote!(Card));
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct ConvertOptions {
+    extract_svgs: bool,
+    keep_comments: bool,
+    components: HashMap<String, syn::Path>,
+}
+
+impl ConvertOptions {
+    /// Create the default options: svgs are kept inline, comments are dropped and no elements are mapped to components
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Replace every `<svg>` with an `icons::icon_N {}` component and return the svgs in [`Conversion::svgs`]
+    pub fn with_extracted_svgs(mut self, extract: bool) -> Self {
+        self.extract_svgs = extract;
+        self
+    }
+
+    /// Keep html comments as `raw!("<!-- comment -->")` nodes instead of dropping them
+    pub fn with_comments(mut self, keep: bool) -> Self {
+        self.keep_comments = keep;
+        self
+    }
+
+    /// Convert every element with the tag `tag` into the `component`. Attributes are passed as props and children
+    /// are passed as the children of the component.
+    pub fn with_component(mut self, tag: impl Into<String>, component: syn::Path) -> Self {
+        self.components.insert(tag.into(), component);
+        self
+    }
+}
+
+/// The rsx produced by [`convert_html`]
+#[derive(Debug, Default)]
+pub struct Conversion {
+    /// The converted html
+    pub body: CallBody,
+    /// The svgs that were pulled out of the body if [`ConvertOptions::with_extracted_svgs`] is enabled.
+    /// The svg at index `n` replaced the `icons::icon_n {}` component in the body.
+    pub svgs: Vec<BodyNode>,
+    /// The contents of the `<head>` if the html is a full document, like the `<title>`, `<meta>` and `<link>`s
+    pub head: Vec<BodyNode>,
+}
+
+/// Convert a fragment or a full document of html5 into rsx
+///
+/// Parsing never fails: invalid html is recovered from the same way a browser would.
+pub fn convert_html(html: &str, options: &ConvertOptions) -> Conversion {
+    let (head, roots) = if is_document(html) {
+        let dom = parse_document(RcDom::default(), ParseOpts::default()).one(html);
+
+        // The parser always creates the `<html>`, `<head>` and `<body>` elements of a document
+        let document = dom.document.children.borrow();
+        let root = document.iter().find(|node| element_name(node).is_some());
+        let sections = root
+            .map(|root| root.children.borrow().clone())
+            .unwrap_or_default();
+        let section = |name: &str| {
+            sections
+                .iter()
+                .find(|node| element_name(node).as_deref() == Some(name))
+                .map(|section| convert_children(section, options, false))
+                .unwrap_or_default()
+        };
+
+        (section("head"), section("body"))
+    } else {
+        let context = QualName::new(None, ns!(html), local_name!("body"));
+        let dom =
+            parse_fragment(RcDom::default(), ParseOpts::default(), context, Vec::new()).one(html);
+
+        // The fragment is parsed into a single `<html>` element at the root of the document
+        let roots = match dom.document.children.borrow().first() {
+            Some(root) => convert_children(root, options, false),
+            None => Vec::new(),
+        };
+
+        (Vec::new(), roots)
+    };
+
+    let mut conversion = Conversion {
+        body: CallBody { roots },
+        svgs: Vec::new(),
+        head,
+    };
+
+    if options.extract_svgs {
+        collect_svgs(&mut conversion.body.roots, &mut conversion.svgs);
+    }
+
+    conversion
+}
+
+/// Documents start with a doctype or the `<html>` tag, anything else is a fragment
+fn is_document(html: &str) -> bool {
+    let start = html
+        .trim_start()
+        .chars()
+        .take("<!doctype".len())
+        .collect::<String>()
+        .to_ascii_lowercase();
+    start.starts_with("<!doctype") || start.starts_with("<html")
+}
+
+fn element_name(node: &Handle) -> Option<String> {
+    match &node.data {
+        NodeData::Element { name, .. } => Some(name.local.to_string()),
+        _ => None,
+    }
+}
+
+fn convert_children(
+    parent: &Handle,
+    options: &ConvertOptions,
+    preserve_whitespace: bool,
+) -> Vec<BodyNode> {
+    let children = parent.children.borrow();
+    let last = children.len().saturating_sub(1);
+
+    children
+        .iter()
+        .enumerate()
+        .filter_map(|(idx, child)| {
+            convert_node(child, options, preserve_whitespace, idx == 0, idx == last)
+        })
+        .collect()
+}
+
+fn convert_node(
+    node: &Handle,
+    options: &ConvertOptions,
+    preserve_whitespace: bool,
+    first: bool,
+    last: bool,
+) -> Option<BodyNode> {
+    match &node.data {
+        NodeData::Text { contents } => {
+            let contents = contents.borrow();
+            let text = if preserve_whitespace {
+                contents.to_string()
+            } else {
+                collapse_whitespace(&contents, first, last)?
+            };
+
+            Some(BodyNode::Text(ifmt_from_text(&text)))
+        }
+
+        NodeData::Comment { contents } if options.keep_comments => {
+            let html = format!("<!--{contents}-->")
+                .replace('{', "{{")
+                .replace('}', "}}");
+            let html = LitStr::new(&html, Span::call_site());
+            Some(syn::parse_quote! { raw!(#html) })
+        }
+
+        NodeData::Element {
+            name,
+            attrs,
+            template_contents,
+            ..
+        } => {
+            let tag = &*name.local;
+
+            let attributes = attrs
+                .borrow()
+                .iter()
+                .map(|attr| {
+                    let name = match &attr.name.prefix {
+                        Some(prefix) if !prefix.is_empty() => {
+                            format!("{}:{}", prefix, attr.name.local)
+                        }
+                        _ => attr.name.local.to_string(),
+                    };
+                    (name, attr.value.to_string())
+                })
+                .collect();
+
+            // The children of a template are stored in a separate document fragment
+            let preserve_whitespace = preserve_whitespace || matches!(tag, "pre" | "textarea");
+            let children = match &*template_contents.borrow() {
+                Some(contents) => convert_children(contents, options, preserve_whitespace),
+                None => convert_children(node, options, preserve_whitespace),
+            };
+
+            match options.components.get(tag) {
+                Some(component) => Some(BodyNode::Component(component_from_html(
+                    component, attributes, children,
+                ))),
+                None => Some(BodyNode::Element(element_from_html(
+                    tag, attributes, children,
+                ))),
+            }
+        }
+
+        // Doctypes, processing instructions and dropped comments
+        _ => None,
+    }
+}
+
+fn component_from_html(
+    component: &syn::Path,
+    attributes: Vec<(String, String)>,
+    children: Vec<BodyNode>,
+) -> Component {
+    let fields = attributes
+        .into_iter()
+        .map(|(name, value)| {
+            let name = name.to_case(Case::Snake);
+            // Keywords like `type` need to be raw identifiers
+            let name = match syn::parse_str::<Ident>(&name) {
+                Ok(ident) => ident,
+                Err(_) => Ident::new_raw(&name, Span::call_site()),
+            };
+
+            ComponentField {
+                name,
+                content: ContentField::Formatted(ifmt_from_text(&value)),
+            }
+        })
+        .collect();
+
+    Component {
+        name: component.clone(),
+        prop_gen_args: None,
+        fields,
+        children,
+        manual_props: None,
+        brace: Default::default(),
+    }
+}
+
+/// Collapse runs of whitespace into a single space like the browser does when it renders text.
+///
+/// Returns None if the text is only whitespace.
+fn collapse_whitespace(text: &str, first: bool, last: bool) -> Option<String> {
+    if text.trim().is_empty() {
+        return None;
+    }
+
+    let mut collapsed = String::with_capacity(text.len());
+    let mut last_was_whitespace = false;
+    for c in text.chars() {
+        if c.is_whitespace() {
+            if !last_was_whitespace {
+                collapsed.push(' ');
+            }
+            last_was_whitespace = true;
+        } else {
+            collapsed.push(c);
+            last_was_whitespace = false;
+        }
+    }
+
+    let collapsed = match (first, last) {
+        (true, true) => collapsed.trim(),
+        (true, false) => collapsed.trim_start(),
+        (false, true) => collapsed.trim_end(),
+        (false, false) => &collapsed,
+    };
+
+    Some(collapsed.to_string())
+}
@@ -3,16 +3,21 @@
 #![doc(html_favicon_url = "https://avatars.githubusercontent.com/u/79236386")]
 
 use convert_case::{Case, Casing};
-use dioxus_html::{map_html_attribute_to_rsx, map_html_element_to_rsx};
+use dioxus_html::{map_html_attribute_to_rsx, map_html_element_to_rsx, map_html_style_to_rsx};
 use dioxus_rsx::{
-    AttributeType, BodyNode, CallBody, Component, Element, ElementAttr, ElementAttrNamed,
-    ElementName, IfmtInput,
+    html_names::HTML_ELEMENTS, AttributeType, BodyNode, CallBody, Component, Element, ElementAttr,
+    ElementAttrName, ElementAttrNamed, ElementAttrValue, ElementName, IfmtInput, StyleProperty,
 };
 pub use html_parser::{Dom, Node};
 use proc_macro2::{Ident, Span};
 use syn::{punctuated::Punctuated, LitStr};
 
+mod convert;
+pub use convert::*;
+
 /// Convert an HTML DOM tree into an RSX CallBody
+///
+/// The `html_parser` DOM only supports a subset of html. Use [`convert_html`] to convert any html5 fragment.
 pub fn rsx_from_html(dom: &Dom) -> CallBody {
     CallBody {
         roots: dom.children.iter().filter_map(rsx_node_from_html).collect(),
@@ -26,88 +31,31 @@ pub fn rsx_node_from_html(node: &Node) -> Option<BodyNode> {
     match node {
         Node::Text(text) => Some(BodyNode::Text(ifmt_from_text(text))),
         Node::Element(el) => {
-            let el_name = if let Some(name) = map_html_element_to_rsx(&el.name) {
-                ElementName::Ident(Ident::new(name, Span::call_site()))
-            } else {
-                // if we don't recognize it and it has a dash, we assume it's a web component
-                if el.name.contains('-') {
-                    ElementName::Custom(LitStr::new(&el.name, Span::call_site()))
-                } else {
-                    // otherwise, it might be an element that isn't supported yet
-                    ElementName::Ident(Ident::new(&el.name.to_case(Case::Snake), Span::call_site()))
-                }
-            };
-
             let mut attributes: Vec<_> = el
                 .attributes
                 .iter()
                 .map(|(name, value)| {
-                    let value = ifmt_from_text(value.as_deref().unwrap_or("false"));
-                    let attr = if let Some(name) = map_html_attribute_to_rsx(name) {
-                        let ident = if let Some(name) = name.strip_prefix("r#") {
-                            Ident::new_raw(name, Span::call_site())
-                        } else {
-                            Ident::new(name, Span::call_site())
-                        };
-                        ElementAttr {
-                            value: dioxus_rsx::ElementAttrValue::AttrLiteral(value),
-                            name: dioxus_rsx::ElementAttrName::BuiltIn(ident),
-                        }
-                    } else {
-                        // If we don't recognize the attribute, we assume it's a custom attribute
-                        ElementAttr {
-                            value: dioxus_rsx::ElementAttrValue::AttrLiteral(value),
-                            name: dioxus_rsx::ElementAttrName::Custom(LitStr::new(
-                                name,
-                                Span::call_site(),
-                            )),
-                        }
-                    };
-
-                    AttributeType::Named(ElementAttrNamed {
-                        el_name: el_name.clone(),
-                        attr,
-                    })
+                    (
+                        name.clone(),
+                        value.as_deref().unwrap_or("false").to_string(),
+                    )
                 })
                 .collect();
 
             let class = el.classes.join(" ");
             if !class.is_empty() {
-                attributes.push(AttributeType::Named(ElementAttrNamed {
-                    el_name: el_name.clone(),
-                    attr: ElementAttr {
-                        name: dioxus_rsx::ElementAttrName::BuiltIn(Ident::new(
-                            "class",
-                            Span::call_site(),
-                        )),
-                        value: dioxus_rsx::ElementAttrValue::AttrLiteral(ifmt_from_text(&class)),
-                    },
-                }));
+                attributes.push(("class".to_string(), class));
             }
 
             if let Some(id) = &el.id {
-                attributes.push(AttributeType::Named(ElementAttrNamed {
-                    el_name: el_name.clone(),
-                    attr: ElementAttr {
-                        name: dioxus_rsx::ElementAttrName::BuiltIn(Ident::new(
-                            "id",
-                            Span::call_site(),
-                        )),
-                        value: dioxus_rsx::ElementAttrValue::AttrLiteral(ifmt_from_text(id)),
-                    },
-                }));
+                attributes.push(("id".to_string(), id.clone()));
             }
 
             let children = el.children.iter().filter_map(rsx_node_from_html).collect();
 
-            Some(BodyNode::Element(Element {
-                name: el_name,
-                children,
-                attributes,
-                merged_attributes: Default::default(),
-                key: None,
-                brace: Default::default(),
-            }))
+            Some(BodyNode::Element(element_from_html(
+                &el.name, attributes, children,
+            )))
         }
 
         // We ignore comments
@@ -115,6 +63,74 @@ pub fn rsx_node_from_html(node: &Node) -> Option<BodyNode> {
     }
 }
 
+/// Convert a html element with its attributes and already converted children into an rsx Element
+pub(crate) fn element_from_html(
+    name: &str,
+    attributes: Vec<(String, String)>,
+    children: Vec<BodyNode>,
+) -> Element {
+    let el_name = if let Some(name) = map_html_element_to_rsx(name) {
+        ElementName::Ident(Ident::new(name, Span::call_site()))
+    } else {
+        // if we don't recognize it and it has a dash, we assume it's a web component
+        if name.contains('-') {
+            ElementName::Custom(LitStr::new(name, Span::call_site()))
+        } else {
+            // otherwise, it might be an element that isn't supported yet or a keyword like `use`
+            let name = name.to_case(Case::Snake);
+            match syn::parse_str::<Ident>(&name) {
+                Ok(ident) => ElementName::Ident(ident),
+                Err(_) => ElementName::Ident(Ident::new_raw(&name, Span::call_site())),
+            }
+        }
+    };
+
+    // Only html elements have the typed style attributes that style maps are checked against
+    let typed_styles = matches!(&el_name, ElementName::Ident(ident) if HTML_ELEMENTS.iter().any(|(name, _)| ident == name));
+
+    let attributes = attributes
+        .iter()
+        // The renderer creates elements in the right namespace, so namespace declarations are not needed
+        .filter(|(name, _)| name != "xmlns" && !name.starts_with("xmlns:"))
+        .map(|(name, value)| {
+            let attr = match style_properties(value) {
+                Some(properties) if name == "style" && typed_styles => ElementAttr {
+                    value: ElementAttrValue::Styles(properties),
+                    name: ElementAttrName::BuiltIn(rsx_ident(name)),
+                },
+                _ => {
+                    let value = ElementAttrValue::AttrLiteral(ifmt_from_text(value));
+                    match map_html_attribute_to_rsx(name) {
+                        Some(name) => ElementAttr {
+                            value,
+                            name: ElementAttrName::BuiltIn(rsx_ident(name)),
+                        },
+                        // If we don't recognize the attribute, we assume it's a custom attribute
+                        None => ElementAttr {
+                            value,
+                            name: ElementAttrName::Custom(LitStr::new(name, Span::call_site())),
+                        },
+                    }
+                }
+            };
+
+            AttributeType::Named(ElementAttrNamed {
+                el_name: el_name.clone(),
+                attr,
+            })
+        })
+        .collect();
+
+    Element {
+        name: el_name,
+        children,
+        attributes,
+        merged_attributes: Default::default(),
+        key: None,
+        brace: Default::default(),
+    }
+}
+
 /// Pull out all the svgs from the body and replace them with components of the same name
 pub fn collect_svgs(children: &mut [BodyNode], out: &mut Vec<BodyNode>) {
     for child in children {
@@ -157,9 +173,73 @@ pub fn collect_svgs(children: &mut [BodyNode], out: &mut Vec<BodyNode>) {
     }
 }
 
-fn ifmt_from_text(text: &str) -> IfmtInput {
+/// Convert an inline style like `color: red; font-size: 12px` into the properties of a rsx style map
+///
+/// Returns None if any property is unknown, like custom properties and vendor prefixes, so the style is kept as a string.
+fn style_properties(style: &str) -> Option<Vec<StyleProperty>> {
+    let properties = style_declarations(style)
+        .into_iter()
+        .map(|declaration| {
+            let (name, value) = declaration.split_once(':')?;
+            let name = map_html_style_to_rsx(&name.trim().to_ascii_lowercase())?;
+            let value = value.trim();
+            // The priority can't be set through a style attribute
+            if value.contains("!important") {
+                return None;
+            }
+            Some(StyleProperty {
+                name: rsx_ident(name),
+                value: ElementAttrValue::AttrLiteral(ifmt_from_text(value)),
+            })
+        })
+        .collect::<Option<Vec<_>>>()?;
+
+    (!properties.is_empty()).then_some(properties)
+}
+
+/// Split an inline style into declarations at the semicolons that are not inside of parentheses or quotes
+fn style_declarations(style: &str) -> Vec<&str> {
+    let mut declarations = Vec::new();
+    let mut depth = 0;
+    let mut quote = None;
+    let mut start = 0;
+
+    for (idx, c) in style.char_indices() {
+        match (quote, c) {
+            (Some(open), c) if c == open => quote = None,
+            (Some(_), _) => {}
+            (None, '"' | '\'') => quote = Some(c),
+            (None, '(') => depth += 1,
+            (None, ')') => depth -= 1,
+            (None, ';') if depth == 0 => {
+                declarations.push(&style[start..idx]);
+                start = idx + 1;
+            }
+            _ => {}
+        }
+    }
+    declarations.push(&style[start..]);
+
+    declarations
+        .into_iter()
+        .map(str::trim)
+        .filter(|declaration| !declaration.is_empty())
+        .collect()
+}
+
+/// Create the identifier for a rsx name, which is a raw identifier for keywords like `r#type`
+fn rsx_ident(name: &str) -> Ident {
+    match name.strip_prefix("r#") {
+        Some(name) => Ident::new_raw(name, Span::call_site()),
+        None => Ident::new(name, Span::call_site()),
+    }
+}
+
+pub(crate) fn ifmt_from_text(text: &str) -> IfmtInput {
+    // Braces in html are plain text, but they start a format argument in rsx
+    let text = text.replace('{', "{{").replace('}', "}}");
     IfmtInput {
-        source: Some(LitStr::new(text, Span::call_site())),
+        source: Some(LitStr::new(&text, Span::call_site())),
         segments: vec![],
     }
 }
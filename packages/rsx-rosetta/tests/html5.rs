@@ -0,0 +1,146 @@
+use rsx_rosetta::{convert_html, ConvertOptions};
+
+fn convert(html: &str, options: &ConvertOptions) -> String {
+    dioxus_autofmt::write_block_out(convert_html(html, options).body).unwrap()
+}
+
+#[test]
+fn recovers_like_a_browser() {
+    let html = r#"
+    <table>
+        <tr><td>one<td>two
+    </table>
+    <p>unclosed <b>bold</b> &amp; {braces}
+    "#;
+
+    let expected = r#"
+    table {
+        tbody {
+            tr {
+                td { "one" }
+                td { "two" }
+            }
+        }
+    }
+    p {
+        "unclosed "
+        b { "bold" }
+        " & {{braces}}"
+    }"#;
+    pretty_assertions::assert_eq!(convert(html, &ConvertOptions::new()), expected);
+}
+
+#[test]
+fn comments() {
+    let html = "<div><!-- hello --><span>world</span></div>";
+
+    pretty_assertions::assert_eq!(
+        convert(html, &ConvertOptions::new()),
+        r#"
+    div { span { "world" } }"#
+    );
+
+    pretty_assertions::assert_eq!(
+        convert(html, &ConvertOptions::new().with_comments(true)),
+        r#"
+    div {
+        raw!("<!-- hello -->")
+        span { "world" }
+    }"#
+    );
+}
+
+#[test]
+fn components() {
+    let html = r#"<my-card card-title="Hello" type="info"><p>body</p></my-card>"#;
+    let options = ConvertOptions::new().with_component("my-card", syn::parse_quote!(Card));
+
+    pretty_assertions::assert_eq!(
+        convert(html, &options),
+        r#"
+    Card { card_title: "Hello", r#type: "info", p { "body" } }"#
+    );
+}
+
+#[test]
+fn svgs() {
+    let html =
+        r##"<button><svg viewBox="0 0 10 10"><use xlink:href="#icon"></use></svg>Save</button>"##;
+    let conversion = convert_html(html, &ConvertOptions::new().with_extracted_svgs(true));
+
+    pretty_assertions::assert_eq!(
+        dioxus_autofmt::write_block_out(conversion.body).unwrap(),
+        r#"
+    button {
+        icons::icon_0 {}
+        "Save"
+    }"#
+    );
+    assert_eq!(conversion.svgs.len(), 1);
+}
+
+#[test]
+fn preserves_whitespace_in_pre() {
+    let html = "<pre>  fn main() {\n    hi\n}</pre>";
+
+    pretty_assertions::assert_eq!(
+        convert(html, &ConvertOptions::new()),
+        r#"pre { "  fn main() {{\n    hi\n}}" }"#
+    );
+}
+
+#[test]
+fn full_documents() {
+    let html = r#"<!DOCTYPE html>
+    <html lang="en">
+        <head>
+            <title>Home</title>
+            <link rel="stylesheet" href="style.css">
+        </head>
+        <body>
+            <h1>Hello</h1>
+        </body>
+    </html>"#;
+    let conversion = convert_html(html, &ConvertOptions::new());
+
+    pretty_assertions::assert_eq!(
+        dioxus_autofmt::write_block_out(conversion.body).unwrap(),
+        r#"h1 { "Hello" }"#
+    );
+    pretty_assertions::assert_eq!(
+        dioxus_autofmt::write_block_out(dioxus_rsx::CallBody {
+            roots: conversion.head
+        })
+        .unwrap(),
+        r#"
+    title { "Home" }
+    link { rel: "stylesheet", href: "style.css" }"#
+    );
+}
+
+#[test]
+fn inline_styles() {
+    let html = r#"<div style="color: red; font-size: 12px; background: url('a;b.png')"></div><p style="--accent: blue">hi</p><svg style="fill: red"></svg>"#;
+
+    pretty_assertions::assert_eq!(
+        convert(html, &ConvertOptions::new()),
+        r#"
+    div { style: { color: "red", font_size: "12px", background: "url('a;b.png')" } }
+    p { style: "--accent: blue", "hi" }
+    svg { style: "fill: red" }"#
+    );
+}
+
+#[test]
+fn svg_attributes() {
+    let html = r##"<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 10 10"><path stroke-width="2" d="M0 0"></path><use xlink:href="#icon"></use></svg>"##;
+
+    pretty_assertions::assert_eq!(
+        convert(html, &ConvertOptions::new()),
+        r##"
+    svg { view_box: "0 0 10 10",
+        path { stroke_width: "2", d: "M0 0" }
+        r#use { xlink_href: "#icon" }
+    }"##
+    );
+}
@@ -3,7 +3,7 @@ use std::fmt::{Display, Formatter};
 use super::*;
 
 use proc_macro2::{Span, TokenStream as TokenStream2};
-use quote::{quote, ToTokens, TokenStreamExt};
+use quote::{quote, quote_spanned, ToTokens, TokenStreamExt};
 use syn::{parse_quote, spanned::Spanned, Expr, ExprIf, Ident, LitStr};
 
 #[derive(PartialEq, Eq, Clone, Debug, Hash)]
@@ -22,6 +22,13 @@ impl AttributeType {
 
     pub fn matches_attr_name(&self, other: &Self) -> bool {
         match (self, other) {
+            // Style maps are expanded into one attribute per property, so they never need to be merged
+            (Self::Named(a), Self::Named(b))
+                if matches!(a.attr.value, ElementAttrValue::Styles(_))
+                    || matches!(b.attr.value, ElementAttrValue::Styles(_)) =>
+            {
+                false
+            }
             (Self::Named(a), Self::Named(b)) => a.attr.name == b.attr.name,
             _ => false,
         }
@@ -35,36 +42,33 @@ impl AttributeType {
     }
 
     pub(crate) fn merge_quote(vec: &[&Self]) -> TokenStream2 {
-        // split into spread and single attributes
-        let mut spread = vec![];
-        let mut single = vec![];
-        for attr in vec.iter() {
-            match attr {
-                AttributeType::Named(named) => single.push(named),
-                AttributeType::Spread(expr) => spread.push(expr),
-            }
-        }
-
         // If all of them are single attributes, create a static slice
-        if spread.is_empty() {
-            quote! {
+        let single = vec
+            .iter()
+            .filter_map(|attr| match attr {
+                AttributeType::Named(named) => Some(named),
+                AttributeType::Spread(_) => None,
+            })
+            .collect::<Vec<_>>();
+        if single.len() == vec.len() {
+            return quote! {
                 Box::new([
                     #(#single),*
                 ])
-            }
-        } else {
-            // Otherwise start with the single attributes and append the spread attributes
-            quote! {
-                {
-                    let mut __attributes = vec![
-                        #(#single),*
-                    ];
-                    #(
-                        let mut __spread = #spread;
-                        __attributes.append(&mut __spread);
-                    )*
-                    __attributes.into_boxed_slice()
-                }
+            };
+        }
+
+        // Otherwise collect the single attributes and anything that iterates over attributes in the order they were written
+        let push = vec.iter().map(|attr| match attr {
+            AttributeType::Named(named) => quote! { __attributes.push(#named); },
+            AttributeType::Spread(expr) => quote! { __attributes.extend(#expr); },
+        });
+        quote! {
+            {
+                let mut __attributes: Vec<dioxus_core::Attribute> = Vec::new();
+                #(#push)*
+                // A spread may set an attribute that is already set, so attributes with the same name are merged
+                dioxus_core::merge_attributes(__attributes)
             }
         }
     }
@@ -115,7 +119,10 @@ impl ToTokens for ElementAttrNamed {
             (ElementName::Ident(i), ElementAttrName::BuiltIn(_)) => {
                 quote! { dioxus_elements::#i::#name.1 }
             }
-            _ => quote! { None },
+            _ => match name.custom_namespace() {
+                Some(ns) => quote! { Some(#ns) },
+                None => quote! { None },
+            },
         };
         let volitile = |name: &ElementAttrName| match (el_name, name) {
             (ElementName::Ident(i), ElementAttrName::BuiltIn(_)) => {
@@ -142,10 +149,17 @@ impl ToTokens for ElementAttrNamed {
             };
 
             match &attr.value {
+                ElementAttrValue::Styles(properties) => {
+                    let properties = properties
+                        .iter()
+                        .map(|property| property.to_attribute_tokens(el_name));
+                    quote! { #(#properties),* }
+                }
                 ElementAttrValue::AttrLiteral(_)
                 | ElementAttrValue::AttrExpr(_)
                 | ElementAttrValue::Shorthand(_)
                 | ElementAttrValue::AttrOptionalExpr { .. }
+                | ElementAttrValue::AttrFlag(_)
                     if !is_shorthand_event =>
                 {
                     let name = &self.attr.name;
@@ -163,14 +177,18 @@ impl ToTokens for ElementAttrNamed {
                         )
                     }
                 }
-                ElementAttrValue::EventTokens(tokens) => match &self.attr.name {
-                    ElementAttrName::BuiltIn(name) => {
-                        quote! {
-                            dioxus_elements::events::#name(#tokens)
+                ElementAttrValue::EventTokens(_) | ElementAttrValue::EventWithModifiers { .. } => {
+                    match &self.attr.name {
+                        ElementAttrName::BuiltIn(name) => {
+                            quote! {
+                                dioxus_elements::events::#name(#value)
+                            }
+                        }
+                        ElementAttrName::Custom(_) => {
+                            unreachable!("Handled elsewhere in the macro")
                         }
                     }
-                    ElementAttrName::Custom(_) => unreachable!("Handled elsewhere in the macro"),
-                },
+                }
                 _ => {
                     quote! { dioxus_elements::events::#value(#value) }
                 }
@@ -198,15 +216,27 @@ pub enum ElementAttrValue {
         condition: Expr,
         value: Box<ElementAttrValue>,
     },
+    /// attribute: if bool
+    AttrFlag(Expr),
     /// attribute: true
     AttrExpr(Expr),
     /// onclick: move |_| {}
     EventTokens(Expr),
+    /// onclick |prevent_default, stop_propagation|: move |_| {}
+    EventWithModifiers {
+        modifiers: Vec<EventModifier>,
+        handler: Expr,
+    },
+    /// style: { width: "100px", display: "flex" }
+    Styles(Vec<StyleProperty>),
 }
 
 impl Parse for ElementAttrValue {
     fn parse(input: ParseStream) -> syn::Result<Self> {
-        let element_attr_value = if input.peek(Token![if]) {
+        let element_attr_value = if input.peek(Token![if]) && is_attr_flag(input) {
+            input.parse::<Token![if]>()?;
+            ElementAttrValue::AttrFlag(input.call(Expr::parse_without_eager_brace)?)
+        } else if input.peek(Token![if]) {
             let if_expr = input.parse::<ExprIf>()?;
             if is_if_chain_terminated(&if_expr) {
                 ElementAttrValue::AttrExpr(Expr::If(if_expr))
@@ -233,6 +263,32 @@ impl Parse for ElementAttrValue {
     }
 }
 
+/// Check if the braces at the start of the stream contain a style map like `{ width: "100px" }`
+/// instead of a block expression
+pub(crate) fn is_style_map(input: ParseStream) -> bool {
+    fn peek_style_map(input: ParseStream) -> syn::Result<bool> {
+        let content;
+        syn::braced!(content in input);
+        Ok(content.peek(Ident) && content.peek2(Token![:]) && !content.peek3(Token![:]))
+    }
+    peek_style_map(&input.fork()).unwrap_or(false)
+}
+
+/// Parse the braced properties of a style map
+pub(crate) fn parse_style_map(input: ParseStream) -> syn::Result<Vec<StyleProperty>> {
+    let content;
+    syn::braced!(content in input);
+    let properties = content.parse_terminated(StyleProperty::parse, Token![,])?;
+    Ok(properties.into_iter().collect())
+}
+
+/// Check if the `if` at the start of the stream is a bare condition without a body, like `disabled: if is_busy`
+fn is_attr_flag(input: ParseStream) -> bool {
+    let fork = input.fork();
+    let _ = fork.parse::<Token![if]>();
+    fork.call(Expr::parse_without_eager_brace).is_ok() && (fork.is_empty() || fork.peek(Token![,]))
+}
+
 impl ToTokens for ElementAttrValue {
     fn to_tokens(&self, tokens: &mut TokenStream2) {
         match self {
@@ -241,8 +297,29 @@ impl ToTokens for ElementAttrValue {
             ElementAttrValue::AttrOptionalExpr { condition, value } => {
                 tokens.append_all(quote! { if #condition { Some(#value) } else { None } })
             }
+            ElementAttrValue::AttrFlag(condition) => {
+                tokens.append_all(quote! { if #condition { Some(true) } else { None } })
+            }
             ElementAttrValue::AttrExpr(expr) => tokens.append_all(quote! { #expr }),
             ElementAttrValue::EventTokens(expr) => tokens.append_all(quote! { #expr }),
+            ElementAttrValue::Styles(_) => {
+                unreachable!("Style maps are expanded into one attribute per property")
+            }
+            ElementAttrValue::EventWithModifiers { modifiers, handler } => {
+                if modifiers.contains(&EventModifier::StopPropagation) {
+                    tokens.append_all(quote! {
+                        {
+                            let mut __handler = #handler;
+                            move |evt| {
+                                evt.stop_propagation();
+                                __handler(evt)
+                            }
+                        }
+                    })
+                } else {
+                    tokens.append_all(quote! { #handler })
+                }
+            }
         }
     }
 }
@@ -355,6 +432,7 @@ impl ElementAttrName {
             ElementAttrName::BuiltIn(i) => match i.to_string().as_str() {
                 "class" => Some(" "),
                 "style" => Some(";"),
+                "prevent_default" => Some(" "),
                 _ => None,
             },
             ElementAttrName::Custom(_) => None,
@@ -367,6 +445,21 @@ impl ElementAttrName {
             ElementAttrName::Custom(s) => s.span(),
         }
     }
+
+    /// The namespace of a custom attribute with a well known prefix like `"xlink:href"`
+    pub fn custom_namespace(&self) -> Option<&'static str> {
+        let ElementAttrName::Custom(name) = self else {
+            return None;
+        };
+        let name = name.value();
+        let (prefix, _) = name.split_once(':')?;
+        match prefix {
+            "xlink" => Some("http://www.w3.org/1999/xlink"),
+            "xml" => Some("http://www.w3.org/XML/1998/namespace"),
+            "xmlns" => Some("http://www.w3.org/2000/xmlns/"),
+            _ => None,
+        }
+    }
 }
 
 impl ToTokens for ElementAttrName {
@@ -396,9 +489,139 @@ impl ElementAttr {
         matches!(
             self,
             ElementAttr {
-                value: ElementAttrValue::AttrExpr(_) | ElementAttrValue::EventTokens(_),
+                value: ElementAttrValue::AttrExpr(_)
+                    | ElementAttrValue::EventTokens(_)
+                    | ElementAttrValue::EventWithModifiers { .. },
                 ..
             }
         )
     }
 }
+
+/// A modifier applied to an event listener: `onclick |prevent_default|: move |_| {}`
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Hash)]
+pub enum EventModifier {
+    /// Prevent the browser's default behavior for the event
+    PreventDefault,
+    /// Stop the event from bubbling up to parent elements
+    StopPropagation,
+}
+
+impl EventModifier {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            EventModifier::PreventDefault => "prevent_default",
+            EventModifier::StopPropagation => "stop_propagation",
+        }
+    }
+}
+
+impl Parse for EventModifier {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let ident = input.parse::<Ident>()?;
+        match ident.to_string().as_str() {
+            "prevent_default" => Ok(EventModifier::PreventDefault),
+            "stop_propagation" => Ok(EventModifier::StopPropagation),
+            _ => Err(syn::Error::new(
+                ident.span(),
+                format!(
+                    "`{ident}` is not a valid event modifier\n  = help: the supported modifiers are `prevent_default` and `stop_propagation`"
+                ),
+            )),
+        }
+    }
+}
+
+impl Display for EventModifier {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// A single property in a style map: `style: { width: "100px" }`
+#[derive(PartialEq, Eq, Clone, Debug, Hash)]
+pub struct StyleProperty {
+    pub name: Ident,
+    pub value: ElementAttrValue,
+}
+
+impl Parse for StyleProperty {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let name = input.parse::<Ident>()?;
+        input.parse::<Token![:]>()?;
+        let value = input.parse::<ElementAttrValue>()?;
+
+        if !matches!(
+            value,
+            ElementAttrValue::AttrLiteral(_)
+                | ElementAttrValue::AttrExpr(_)
+                | ElementAttrValue::AttrOptionalExpr { .. }
+        ) {
+            return Err(syn::Error::new(
+                name.span(),
+                "Style properties must be a string, an expression, or `if cond { value }`",
+            ));
+        }
+
+        Ok(Self { name, value })
+    }
+}
+
+impl StyleProperty {
+    /// The CSS name of the property, for elements that don't have typed attributes
+    pub fn css_name(&self) -> String {
+        self.name
+            .to_string()
+            .trim_start_matches("r#")
+            .replace('_', "-")
+    }
+
+    /// Create the style attribute for this property.
+    ///
+    /// For built-in elements, the property is checked against the style attributes of the element at compile time
+    fn to_attribute_tokens(&self, el_name: &ElementName) -> TokenStream2 {
+        let name = &self.name;
+        let value = style_value_tokens(&self.value);
+
+        match el_name {
+            ElementName::Ident(el) => {
+                let error = format!("`{}` is not a CSS property", name);
+                let check = quote_spanned! { name.span() =>
+                    const _: () = assert!(
+                        <dioxus_elements::#el as dioxus_elements::GlobalAttributes>::#name.1.is_some(),
+                        #error
+                    );
+                };
+                quote! {
+                    {
+                        #check
+                        dioxus_core::Attribute::new(
+                            <dioxus_elements::#el as dioxus_elements::GlobalAttributes>::#name.0,
+                            #value,
+                            Some("style"),
+                            false
+                        )
+                    }
+                }
+            }
+            ElementName::Custom(_) => {
+                let css_name = self.css_name();
+                quote! {
+                    dioxus_core::Attribute::new(#css_name, #value, Some("style"), false)
+                }
+            }
+        }
+    }
+}
+
+/// Style values are anything that can be displayed, so users can pass their own typed values
+fn style_value_tokens(value: &ElementAttrValue) -> TokenStream2 {
+    match value {
+        ElementAttrValue::AttrLiteral(lit) => quote! { #lit.to_string() },
+        ElementAttrValue::AttrOptionalExpr { condition, value } => {
+            let value = style_value_tokens(value);
+            quote! { if #condition { Some(#value) } else { None } }
+        }
+        other => quote! { (#other).to_string() },
+    }
+}
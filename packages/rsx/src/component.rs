@@ -14,7 +14,7 @@
 use super::*;
 
 use proc_macro2::TokenStream as TokenStream2;
-use quote::{quote, ToTokens, TokenStreamExt};
+use quote::{format_ident, quote, quote_spanned, ToTokens, TokenStreamExt};
 use syn::{
     ext::IdentExt,
     parse::{Parse, ParseBuffer, ParseStream},
@@ -63,7 +63,14 @@ impl Parse for Component {
                     && !content.peek2(Token![:])
                     && !content.peek2(Token![-]))
             {
-                fields.push(content.parse()?);
+                let field: ComponentField = content.parse()?;
+                if fields.iter().any(|f: &ComponentField| f.name == field.name) {
+                    return Err(Error::new(
+                        field.name.span(),
+                        format!("Duplicate prop `{}`", field.name),
+                    ));
+                }
+                fields.push(field);
             } else {
                 children.push(content.parse()?);
             }
@@ -164,14 +171,17 @@ impl Component {
     fn collect_props(&self) -> TokenStream2 {
         let name = &self.name;
 
+        // Each prop is set in its own statement, so errors about a prop point at the prop instead of the whole macro
         let mut toks = match &self.prop_gen_args {
-            Some(gen_args) => quote! { fc_to_builder(#name #gen_args) },
-            None => quote! { fc_to_builder(#name) },
+            Some(gen_args) => quote! { let __props = fc_to_builder(#name #gen_args); },
+            None => quote! { let __props = fc_to_builder(#name); },
         };
         for field in &self.fields {
             match field.name.to_string().as_str() {
                 "key" => {}
-                _ => toks.append_all(quote! {#field}),
+                _ => toks.append_all(quote_spanned! { field.name.span() =>
+                    let __props = __props #field;
+                }),
             }
         }
         if !self.children.is_empty() {
@@ -181,15 +191,52 @@ impl Component {
             };
 
             toks.append_all(quote! {
-                .children(
+                let __props = __props.children(
                     Some({ #renderer })
-                )
+                );
             });
         }
-        toks.append_all(quote! {
-            .build()
+        // Point errors about missing props at the component instead of the whole macro
+        toks.append_all(quote_spanned! { name.span() =>
+            __props.build()
         });
-        toks
+
+        // Setters of props that exist are inherent or extension methods that take the builder by value, so they are always
+        // picked before the fallbacks that take it by reference. A fallback is only called for a prop that doesn't exist and
+        // fails with an error that names the prop
+        let fn_name = self.fn_name();
+        let (unknown_props, fallbacks): (Vec<_>, Vec<_>) = self
+            .fields
+            .iter()
+            .filter(|field| field.name != "key")
+            .map(|field| {
+                let prop = &field.name;
+                let unknown = format_ident!("{}_has_no_prop_{}", fn_name, prop.unraw());
+                let message = format!("`{fn_name}` has no prop named `{}`", prop.unraw());
+                (
+                    quote_spanned! { prop.span() =>
+                        #[diagnostic::on_unimplemented(message = #message, label = "unknown prop")]
+                        trait #unknown {}
+                    },
+                    quote_spanned! { prop.span() =>
+                        fn #prop<__T>(&self, _: __T) -> Self where Self: #unknown { unreachable!() }
+                    },
+                )
+            })
+            .unzip();
+
+        quote! {{
+            #(
+                #[allow(dead_code, non_camel_case_types)]
+                #unknown_props
+            )*
+            #[allow(dead_code, non_camel_case_types)]
+            trait __UnknownProps: Sized {
+                #(#fallbacks)*
+            }
+            impl<__B> __UnknownProps for __B {}
+            #toks
+        }}
     }
 
     fn fn_name(&self) -> String {
@@ -243,17 +290,12 @@ impl ContentField {
 impl ToTokens for ContentField {
     fn to_tokens(&self, tokens: &mut TokenStream2) {
         match self {
-            ContentField::Shorthand(i) if i.to_string().starts_with("on") => {
-                tokens.append_all(quote! { EventHandler::new(#i) })
-            }
             ContentField::Shorthand(i) => tokens.append_all(quote! { #i }),
-            ContentField::ManExpr(e) => e.to_tokens(tokens),
+            // The Props derive turns the closure of an `EventHandler` field into an `EventHandler`
+            ContentField::ManExpr(e) | ContentField::OnHandlerRaw(e) => e.to_tokens(tokens),
             ContentField::Formatted(s) => tokens.append_all(quote! {
                 #s
             }),
-            ContentField::OnHandlerRaw(e) => tokens.append_all(quote! {
-                EventHandler::new(#e)
-            }),
         }
     }
 }
@@ -283,7 +325,7 @@ impl Parse for ComponentField {
 impl ToTokens for ComponentField {
     fn to_tokens(&self, tokens: &mut TokenStream2) {
         let ComponentField { name, content, .. } = self;
-        tokens.append_all(quote! { .#name(#content) })
+        tokens.append_all(quote_spanned! { name.span() => .#name(#content) })
     }
 }
 
@@ -37,22 +37,22 @@ impl Element {
         // Deduplicate any attributes that can be combined
         // For example, if there are two `class` attributes, combine them into one
         let mut merged_attributes: Vec<AttributeType> = Vec::new();
-        for attr in &attributes {
+        for attr in attributes.iter().flat_map(lower_attribute) {
             let attr_index = merged_attributes
                 .iter()
-                .position(|a| a.matches_attr_name(attr));
+                .position(|a| a.matches_attr_name(&attr));
 
             if let Some(old_attr_index) = attr_index {
                 let old_attr = &mut merged_attributes[old_attr_index];
 
-                if let Some(combined) = old_attr.try_combine(attr) {
+                if let Some(combined) = old_attr.try_combine(&attr) {
                     *old_attr = combined;
                 }
 
                 continue;
             }
 
-            merged_attributes.push(attr.clone());
+            merged_attributes.push(attr);
         }
 
         Self {
@@ -64,11 +64,113 @@ impl Element {
             brace,
         }
     }
+
+    /// Check if any attributes are spread onto the element. Spread attributes are merged with the other attributes of the element when it renders, so none of its attributes are static
+    pub(crate) fn has_spread(&self) -> bool {
+        self.merged_attributes
+            .iter()
+            .any(|attr| matches!(attr, AttributeType::Spread(_)))
+    }
+}
+
+/// Lower attribute sugar into the plain attributes that end up in the template
+///
+/// - `class: ["a", cond.then_some("b")]` is joined into a single class string
+/// - The `prevent_default` event modifier becomes a `prevent_default: "onevent"` attribute
+///   so it can be merged with the other prevent_default attributes on the element
+fn lower_attribute(attr: &AttributeType) -> Vec<AttributeType> {
+    if let AttributeType::Named(ElementAttrNamed {
+        el_name,
+        attr:
+            ElementAttr {
+                name: ElementAttrName::BuiltIn(name),
+                value: ElementAttrValue::AttrExpr(Expr::Array(list)),
+            },
+    }) = attr
+    {
+        if name == "class" {
+            return vec![AttributeType::Named(ElementAttrNamed {
+                el_name: el_name.clone(),
+                attr: ElementAttr {
+                    name: ElementAttrName::BuiltIn(name.clone()),
+                    value: lower_class_list(list),
+                },
+            })];
+        }
+    }
+
+    let mut expanded = vec![attr.clone()];
+
+    if let AttributeType::Named(ElementAttrNamed {
+        el_name,
+        attr:
+            ElementAttr {
+                name: ElementAttrName::BuiltIn(name),
+                value: ElementAttrValue::EventWithModifiers { modifiers, .. },
+            },
+    }) = attr
+    {
+        if modifiers.contains(&EventModifier::PreventDefault) {
+            // Custom elements don't have the prevent_default alias, so use the raw attribute name
+            let attr_name = match el_name {
+                ElementName::Ident(_) => {
+                    ElementAttrName::BuiltIn(Ident::new("prevent_default", name.span()))
+                }
+                ElementName::Custom(_) => {
+                    ElementAttrName::Custom(LitStr::new("dioxus-prevent-default", name.span()))
+                }
+            };
+            expanded.push(AttributeType::Named(ElementAttrNamed {
+                el_name: el_name.clone(),
+                attr: ElementAttr {
+                    name: attr_name,
+                    value: ElementAttrValue::AttrLiteral(IfmtInput::new_static(&name.to_string())),
+                },
+            }));
+        }
+    }
+
+    expanded
+}
+
+/// Join a list of classes. If every class is a literal, the list is joined at compile time
+fn lower_class_list(list: &syn::ExprArray) -> ElementAttrValue {
+    let literals: Option<Vec<String>> = list
+        .elems
+        .iter()
+        .map(|elem| match elem {
+            Expr::Lit(syn::ExprLit {
+                lit: syn::Lit::Str(s),
+                ..
+            }) => Some(s.value()),
+            _ => None,
+        })
+        .collect();
+
+    if let Some(literals) = literals {
+        let joined = literals
+            .iter()
+            .map(|class| class.trim())
+            .filter(|class| !class.is_empty())
+            .collect::<Vec<_>>()
+            .join(" ");
+        return ElementAttrValue::AttrLiteral(IfmtInput::new_static(&joined));
+    }
+
+    let elems = list.elems.iter();
+    ElementAttrValue::AttrExpr(syn::parse_quote! {
+        {
+            let mut __classes = String::new();
+            #(dioxus_elements::ClassListItem::push_class(#elems, &mut __classes);)*
+            __classes
+        }
+    })
 }
 
 impl Parse for Element {
     fn parse(stream: ParseStream) -> Result<Self> {
         let el_name = ElementName::parse(stream)?;
+        validate_element_name(&el_name)?;
 
         // parse the guts
         let content: ParseBuffer;
@@ -127,12 +229,64 @@ impl Parse for Element {
                 continue;
             }
 
+            // Parse event listeners with modifiers
+            // onclick |prevent_default, stop_propagation|: move |_| {},
+            if content.peek(Ident) && content.peek2(Token![|]) {
+                let name = content.parse::<Ident>()?;
+                validate_attribute_name(&el_name, &name)?;
+                if !name.to_string().starts_with("on") {
+                    return Err(syn::Error::new(
+                        name.span(),
+                        format!(
+                            "Only event listeners can have modifiers, but `{name}` is not an event"
+                        ),
+                    ));
+                }
+                check_duplicate_listener(&attributes, &name)?;
+
+                content.parse::<Token![|]>()?;
+                let mut modifiers = Vec::new();
+                while !content.peek(Token![|]) {
+                    let modifier = content.parse::<EventModifier>()?;
+                    if !modifiers.contains(&modifier) {
+                        modifiers.push(modifier);
+                    }
+                    if !content.peek(Token![|]) {
+                        content.parse::<Token![,]>()?;
+                    }
+                }
+                content.parse::<Token![|]>()?;
+                content.parse::<Token![:]>()?;
+
+                let span = content.span();
+                attributes.push(attribute::AttributeType::Named(ElementAttrNamed {
+                    el_name: el_name.clone(),
+                    attr: ElementAttr {
+                        name: ElementAttrName::BuiltIn(name),
+                        value: ElementAttrValue::EventWithModifiers {
+                            modifiers,
+                            handler: content.parse()?,
+                        },
+                    },
+                }));
+
+                if content.is_empty() {
+                    break;
+                }
+
+                if content.parse::<Token![,]>().is_err() {
+                    missing_trailing_comma!(span);
+                }
+                continue;
+            }
+
             // Parse
             // abc: 123,
             if content.peek(Ident) && content.peek2(Token![:]) && !content.peek3(Token![:]) {
                 let name = content.parse::<Ident>()?;
 
                 let name_str = name.to_string();
+                validate_attribute_name(&el_name, &name)?;
                 content.parse::<Token![:]>()?;
 
                 // The span of the content to be parsed,
@@ -140,27 +294,7 @@ impl Parse for Element {
                 let span = content.span();
 
                 if name_str.starts_with("on") {
-                    // check for any duplicate event listeners
-                    if attributes.iter().any(|f| {
-                        if let AttributeType::Named(ElementAttrNamed {
-                            attr:
-                                ElementAttr {
-                                    name: ElementAttrName::BuiltIn(n),
-                                    value: ElementAttrValue::EventTokens(_),
-                                },
-                            ..
-                        }) = f
-                        {
-                            n == &name_str
-                        } else {
-                            false
-                        }
-                    }) {
-                        return Err(syn::Error::new(
-                            name.span(),
-                            format!("Duplicate event listener `{}`", name),
-                        ));
-                    }
+                    check_duplicate_listener(&attributes, &name)?;
                     attributes.push(attribute::AttributeType::Named(ElementAttrNamed {
                         el_name: el_name.clone(),
                         attr: ElementAttr {
@@ -170,6 +304,14 @@ impl Parse for Element {
                     }));
                 } else if name_str == "key" {
                     key = Some(content.parse()?);
+                } else if name_str == "style" && is_style_map(&content) {
+                    attributes.push(attribute::AttributeType::Named(ElementAttrNamed {
+                        el_name: el_name.clone(),
+                        attr: ElementAttr {
+                            name: ElementAttrName::BuiltIn(name),
+                            value: ElementAttrValue::Styles(parse_style_map(&content)?),
+                        },
+                    }));
                 } else {
                     let value = content.parse::<ElementAttrValue>()?;
                     attributes.push(attribute::AttributeType::Named(ElementAttrNamed {
@@ -196,6 +338,7 @@ impl Parse for Element {
                 && !content.peek2(Brace)
                 && !content.peek2(Token![:])
                 && !content.peek2(Token![-])
+                && !content.peek2(Token![!])
             {
                 let name = content.parse::<Ident>()?;
                 let name_ = name.clone();
@@ -256,6 +399,171 @@ Like so:
     }
 }
 
+/// Make sure an element doesn't listen to the same event twice
+fn check_duplicate_listener(attributes: &[AttributeType], name: &Ident) -> Result<()> {
+    let duplicate = attributes.iter().any(|f| {
+        matches!(
+            f,
+            AttributeType::Named(ElementAttrNamed {
+                attr: ElementAttr {
+                    name: ElementAttrName::BuiltIn(n),
+                    value: ElementAttrValue::EventTokens(_) | ElementAttrValue::EventWithModifiers { .. },
+                },
+                ..
+            }) if n == name
+        )
+    });
+
+    if duplicate {
+        return Err(syn::Error::new(
+            name.span(),
+            format!("Duplicate event listener `{}`", name),
+        ));
+    }
+
+    Ok(())
+}
+
+/// Catch attribute names that are common mistakes when coming from JSX or other frameworks
+///
+/// Custom attributes can always be passed with a string literal name to skip this check.
+fn validate_attribute_name(el_name: &ElementName, name: &Ident) -> Result<()> {
+    // Custom elements don't have a fixed set of attributes
+    if let ElementName::Custom(_) = el_name {
+        return Ok(());
+    }
+
+    let name_str = name.to_string();
+
+    // Events are always lowercase without separators: `onclick`, not `on_click` or `onClick`
+    if let Some(event) = name_str.strip_prefix("on") {
+        if event.contains('_') || event.chars().any(|c| c.is_ascii_uppercase()) {
+            let suggestion = format!("on{}", event.replace('_', "").to_ascii_lowercase());
+            invalid_attribute_name!(name.span(), name_str, suggestion);
+        }
+    }
+
+    let suggestion = match name_str.as_str() {
+        "className" | "class_name" => "class",
+        "tabIndex" | "tab_index" => "tabindex",
+        "readOnly" | "read_only" => "readonly",
+        "autoFocus" | "auto_focus" => "autofocus",
+        "contentEditable" | "content_editable" => "contenteditable",
+        "innerHTML" | "innerHtml" | "dangerouslySetInnerHTML" => "dangerous_inner_html",
+        _ => {
+            // Events and keys are checked elsewhere, and elements we don't know may come from a custom `dioxus_elements`
+            if name_str.starts_with("on") || name_str == "key" {
+                return Ok(());
+            }
+            let Some(attributes) = known_attributes(el_name) else {
+                return Ok(());
+            };
+            if attributes.clone().any(|attribute| attribute == name_str) {
+                return Ok(());
+            }
+            match closest_name(&name_str, attributes) {
+                Some(suggestion) => suggestion,
+                None => return Ok(()),
+            }
+        }
+    };
+
+    invalid_attribute_name!(name.span(), name_str, suggestion);
+}
+
+/// Catch misspelled names of the elements in dioxus-html
+///
+/// Names that are not close to any known element are left to `dioxus_elements`, which may be a custom module.
+fn validate_element_name(el_name: &ElementName) -> Result<()> {
+    let ElementName::Ident(name) = el_name else {
+        return Ok(());
+    };
+    let name_str = name.to_string();
+
+    let elements = html_names::HTML_ELEMENTS
+        .iter()
+        .chain(html_names::SVG_ELEMENTS)
+        .map(|(element, _)| *element);
+    if elements.clone().any(|element| element == name_str) {
+        return Ok(());
+    }
+
+    if let Some(suggestion) = closest_name(&name_str, elements) {
+        invalid_element_name!(name.span(), name_str, suggestion);
+    }
+
+    Ok(())
+}
+
+/// The attributes of an element in dioxus-html, including the global attributes
+fn known_attributes(el_name: &ElementName) -> Option<impl Iterator<Item = &'static str> + Clone> {
+    let ElementName::Ident(name) = el_name else {
+        return None;
+    };
+
+    let find = |elements: &'static [(&'static str, &'static [&'static str])]| {
+        elements
+            .iter()
+            .find(|(element, _)| name == element)
+            .map(|(_, attributes)| *attributes)
+    };
+    let (own, global) = match find(html_names::HTML_ELEMENTS) {
+        Some(own) => (own, html_names::GLOBAL_ATTRIBUTES),
+        None => (find(html_names::SVG_ELEMENTS)?, html_names::SVG_ATTRIBUTES),
+    };
+
+    Some(own.iter().chain(global).copied())
+}
+
+/// The candidate that is closest to a misspelled name, if any is close enough to be a typo
+///
+/// Names that only differ in case and underscores always match, so `httpEquiv` finds `http_equiv`.
+fn closest_name(
+    name: &str,
+    candidates: impl Iterator<Item = &'static str>,
+) -> Option<&'static str> {
+    let normalize = |name: &str| {
+        name.trim_start_matches("r#")
+            .chars()
+            .filter(|c| *c != '_')
+            .flat_map(char::to_lowercase)
+            .collect::<Vec<_>>()
+    };
+    let name = normalize(name);
+    let max_distance = (name.len() / 3).max(1);
+
+    candidates
+        .map(|candidate| (edit_distance(&name, &normalize(candidate)), candidate))
+        .filter(|(distance, _)| *distance <= max_distance)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, candidate)| candidate)
+}
+
+/// The number of insertions, deletions, substitutions and swaps of neighbouring characters between two names
+fn edit_distance(a: &[char], b: &[char]) -> usize {
+    // Three rows of the distance matrix are enough to look back for swaps
+    let mut before_last = vec![0; b.len() + 1];
+    let mut last = (0..=b.len()).collect::<Vec<_>>();
+    let mut current = vec![0; b.len() + 1];
+
+    for i in 1..=a.len() {
+        current[0] = i;
+        for j in 1..=b.len() {
+            let substitution = usize::from(a[i - 1] != b[j - 1]);
+            current[j] = (last[j] + 1)
+                .min(current[j - 1] + 1)
+                .min(last[j - 1] + substitution);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                current[j] = current[j].min(before_last[j - 2] + 1);
+            }
+        }
+        std::mem::swap(&mut before_last, &mut last);
+        std::mem::swap(&mut last, &mut current);
+    }
+
+    last[b.len()]
+}
+
 #[derive(PartialEq, Eq, Clone, Debug, Hash)]
 pub enum ElementName {
     Ident(Ident),
@@ -24,3 +24,27 @@ macro_rules! invalid_component_path {
         return Err(Error::new($span, "Invalid component path syntax"));
     };
 }
+
+macro_rules! invalid_element_name {
+    ($span:expr, $name:expr, $suggestion:expr) => {
+        return Err(syn::Error::new(
+            $span,
+            format!(
+                "`{}` is not a valid element name\n  = help: did you mean `{}`?",
+                $name, $suggestion
+            ),
+        ));
+    };
+}
+
+macro_rules! invalid_attribute_name {
+    ($span:expr, $name:expr, $suggestion:expr) => {
+        return Err(syn::Error::new(
+            $span,
+            format!(
+                "`{}` is not a valid attribute name\n  = help: did you mean `{}`?\n  = help: to use a custom attribute, wrap its name in quotes: `\"{}\": value`",
+                $name, $suggestion, $name
+            ),
+        ));
+    };
+}
@@ -101,6 +101,17 @@ impl<Ctx: HotReloadingContext> FileMap<Ctx> {
         })
     }
 
+    /// Add the files of another crate, like a workspace dependency of the main crate, to the map. Returns any errors that occurred while reading the files
+    pub fn add_crate_with_filter(
+        &mut self,
+        path: PathBuf,
+        filter: impl FnMut(&Path) -> bool,
+    ) -> io::Result<Vec<io::Error>> {
+        let FileMapBuildResult { map, errors } = Self::create_with_filter(path, filter)?;
+        self.map.extend(map.map);
+        Ok(errors)
+    }
+
     /// Try to update the rsx in a file
     pub fn update_rsx(&mut self, file_path: &Path, crate_dir: &Path) -> io::Result<UpdateResult> {
         let mut file = File::open(file_path)?;
@@ -171,13 +182,14 @@ impl<Ctx: HotReloadingContext> FileMap<Ctx> {
                     }
                 }
             } else {
-                // if this is a new file, rebuild the project
+                // if this is a new file, rebuild the files of the crate. The files of other crates in the map are kept
                 let FileMapBuildResult { map, mut errors } =
-                    FileMap::create(crate_dir.to_path_buf())?;
+                    FileMap::<Ctx>::create(crate_dir.to_path_buf())?;
                 if let Some(err) = errors.pop() {
                     return Err(err);
                 }
-                *self = map;
+                self.map.retain(|path, _| !path.starts_with(crate_dir));
+                self.map.extend(map.map);
             }
         }
         Ok(UpdateResult::NeedsRebuild)
@@ -203,3 +215,44 @@ impl<Ctx: HotReloadingContext> FileMap<Ctx> {
         }
     }
 }
+
+/// Find the directories of the workspace members the crate depends on, directly or through other workspace members.
+///
+/// The rsx in these crates is compiled into the application, so it can be hot reloaded like the rsx of the crate itself
+pub fn workspace_dependencies(crate_dir: &Path) -> io::Result<Vec<PathBuf>> {
+    let mut cmd = Cmd::new();
+    let manifest_path = crate_dir.join("Cargo.toml");
+    cmd.manifest_path(&manifest_path);
+    let mut cmd: MetadataCommand = cmd.into();
+    cmd.no_deps();
+    let metadata = cmd
+        .exec()
+        .map_err(|err| io::Error::new(io::ErrorKind::Other, err))?;
+
+    let member_dir = |manifest_path: &Path| manifest_path.parent().map(Path::to_path_buf);
+    let members: HashMap<PathBuf, _> = metadata
+        .packages
+        .iter()
+        .filter_map(|package| Some((member_dir(package.manifest_path.as_std_path())?, package)))
+        .collect();
+
+    let mut dependencies = Vec::new();
+    let mut queue = vec![crate_dir.to_path_buf()];
+    while let Some(dir) = queue.pop() {
+        let Some(package) = members.get(&dir) else {
+            continue;
+        };
+        for dependency in &package.dependencies {
+            let Some(path) = &dependency.path else {
+                continue;
+            };
+            let path = path.clone().into_std_path_buf();
+            if path != crate_dir && members.contains_key(&path) && !dependencies.contains(&path) {
+                dependencies.push(path.clone());
+                queue.push(path);
+            }
+        }
+    }
+
+    Ok(dependencies)
+}
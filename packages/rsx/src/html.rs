@@ -0,0 +1,647 @@
+//! Parse the html!{} macro into the same tree as the rsx!{} macro
+//! ==============================================================
+//!
+//! The html! macro accepts a JSX-like syntax and transforms it into a [`CallBody`], so everything the rsx! macro
+//! supports (components, keys, events, formatted text) works the same way in both macros.
+//!
+//! ```rust, ignore
+//! html! {
+//!     <!-- Comments are skipped -->
+//!     <div class="container" key="{id}" onclick={move |_| count += 1} {..attributes}>
+//!         <h1>Hello {name}!</h1>
+//!         <input disabled />
+//!         <Card title="hi">
+//!             {children}
+//!         </Card>
+//!         {for item in items {
+//!             <li key="{item.id}">{item.name}</li>
+//!         }}
+//!         {if show_footer {
+//!             <footer>"Made with Dioxus"</footer>
+//!         }}
+//!     </div>
+//! }
+//! ```
+//!
+//! - Text can be written as it is in html, or as a string literal with the same interpolation as rsx!
+//! - An expression in braces after text is formatted into the text like `Count: {count}`, and on its own it is a node like `{children}`
+//! - Attribute values are string literals, expressions wrapped in braces, or unquoted values like `width=100`
+//! - Attributes without a value are set to `true`
+//! - Attributes with dashes, colons or uppercase letters in their name (like `data-id`, `xlink:href` or `viewBox`) are passed through as custom attributes
+//! - `{..attributes}` in a tag spreads attributes onto an element, or passes the props of a component
+//! - Tags starting with an uppercase letter or with a path (`crate::Card`) are components
+//! - `<>...</>` groups multiple nodes without a wrapper element
+//! - Void elements like `<br>` and `<input>` don't need to be closed
+//! - `{for ...}` and `{if ...}` blocks take markup in their bodies, like `for` and `if` in rsx!
+//! - Comments, the doctype and entities like `&amp;` work like in html
+//!
+//! Stable proc macros can't see the whitespace in the source, so the spaces in unquoted text are guessed from the punctuation.
+//! Use a string literal when the exact text matters, or when the text has apostrophes or quotes that Rust can't tokenize.
+
+use super::*;
+
+use proc_macro2::{Delimiter, Spacing, Span, TokenTree};
+use syn::{
+    braced,
+    ext::IdentExt,
+    parse::{Parse, ParseStream},
+    punctuated::Punctuated,
+    spanned::Spanned,
+    token::Brace,
+    Expr, Ident, Lit, LitStr, Pat, Result,
+};
+
+/// The body of a html! call, which is converted into a [`CallBody`]
+#[derive(Debug)]
+pub struct HtmlCallBody(pub CallBody);
+
+impl Parse for HtmlCallBody {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let roots = parse_html_children(input)?;
+        Ok(Self(CallBody { roots }))
+    }
+}
+
+impl From<HtmlCallBody> for CallBody {
+    fn from(body: HtmlCallBody) -> Self {
+        body.0
+    }
+}
+
+/// Parse html nodes until the end of the stream or a closing tag
+fn parse_html_children(input: ParseStream) -> Result<Vec<BodyNode>> {
+    let mut children = Vec::new();
+
+    while !input.is_empty() && !peek_closing_tag(input) {
+        // Fragments are flattened into their parent
+        if input.peek(Token![<]) && input.peek2(Token![>]) {
+            input.parse::<Token![<]>()?;
+            input.parse::<Token![>]>()?;
+            children.extend(parse_html_children(input)?);
+            input.parse::<Token![<]>()?;
+            input.parse::<Token![/]>()?;
+            input.parse::<Token![>]>()?;
+            continue;
+        }
+
+        if input.peek(Token![<]) && input.peek2(Token![!]) {
+            skip_comment(input)?;
+        } else if input.peek(Token![<]) {
+            children.push(parse_html_tag(input)?);
+        } else if input.peek(LitStr) {
+            children.push(BodyNode::Text(input.parse()?));
+        } else if input.peek(Brace) {
+            let content;
+            braced!(content in input);
+            children.push(parse_html_block(&content)?);
+        } else {
+            let text = parse_text(input, !children.is_empty())?;
+            children.push(BodyNode::Text(text));
+        }
+    }
+
+    Ok(children)
+}
+
+/// Skip a comment like `<!-- comment -->` or a declaration like `<!DOCTYPE html>`
+fn skip_comment(input: ParseStream) -> Result<()> {
+    let start = input.parse::<Token![<]>()?;
+    input.parse::<Token![!]>()?;
+
+    let comment = input.peek(Token![-]) && input.peek2(Token![-]);
+    loop {
+        if input.is_empty() {
+            return Err(syn::Error::new(start.span, "Unclosed comment"));
+        }
+        if comment && input.peek(Token![-]) && input.peek2(Token![-]) && input.peek3(Token![>]) {
+            input.parse::<Token![-]>()?;
+            input.parse::<Token![-]>()?;
+            input.parse::<Token![>]>()?;
+            return Ok(());
+        }
+        if !comment && input.peek(Token![>]) {
+            input.parse::<Token![>]>()?;
+            return Ok(());
+        }
+        input.parse::<TokenTree>()?;
+    }
+}
+
+/// Parse the contents of braces in the children: a `for` loop or `if` chain with markup in the body, or any expression
+fn parse_html_block(input: ParseStream) -> Result<BodyNode> {
+    if input.peek(Token![for]) {
+        let for_token = input.parse()?;
+        let pat = Pat::parse_single(input)?;
+        let in_token = input.parse()?;
+        let expr = input.call(Expr::parse_without_eager_brace)?;
+        let content;
+        let brace_token = braced!(content in input);
+        return Ok(BodyNode::ForLoop(ForLoop {
+            for_token,
+            pat,
+            in_token,
+            expr: Box::new(expr),
+            key: None,
+            body: parse_html_children(&content)?,
+            brace_token,
+        }));
+    }
+
+    if input.peek(Token![if]) {
+        return Ok(BodyNode::IfChain(parse_html_if(input)?));
+    }
+
+    Ok(BodyNode::RawExpr(input.parse()?))
+}
+
+fn parse_html_if(input: ParseStream) -> Result<IfChain> {
+    let if_token = input.parse()?;
+    let cond = input.call(Expr::parse_without_eager_brace)?;
+    let content;
+    braced!(content in input);
+    let then_branch = parse_html_children(&content)?;
+
+    let mut else_if_branch = None;
+    let mut else_branch = None;
+    if input.peek(Token![else]) {
+        input.parse::<Token![else]>()?;
+        if input.peek(Token![if]) {
+            else_if_branch = Some(Box::new(parse_html_if(input)?));
+        } else {
+            let content;
+            braced!(content in input);
+            else_branch = Some(parse_html_children(&content)?);
+        }
+    }
+
+    Ok(IfChain {
+        if_token,
+        cond: Box::new(cond),
+        then_branch,
+        else_if_branch,
+        else_branch,
+    })
+}
+
+/// Parse text that is written without quotes until the next tag, string literal or the end of the parent
+///
+/// Expressions in braces after the first word are formatted into the text.
+fn parse_text(input: ParseStream, after_node: bool) -> Result<IfmtInput> {
+    let mut text = UnquotedText {
+        space: after_node,
+        ..Default::default()
+    };
+
+    while !input.is_empty() && !input.peek(Token![<]) && !input.peek(LitStr) {
+        match input.parse::<TokenTree>()? {
+            TokenTree::Group(group) if group.delimiter() == Delimiter::Brace => {
+                text.push_expr(syn::parse2(group.stream())?);
+            }
+            token => text.push_token(token)?,
+        }
+    }
+
+    // Keep the space before an element that follows the text, like in `Hello <b>world</b>`
+    if input.peek(Token![<]) && !peek_closing_tag(input) && text.space {
+        text.literal.push(' ');
+    }
+
+    Ok(text.finish())
+}
+
+/// Text that is collected token by token
+#[derive(Default)]
+struct UnquotedText {
+    text: IfmtInput,
+    literal: String,
+    /// If the last token can be followed by a space
+    space: bool,
+}
+
+impl UnquotedText {
+    /// Punctuation that sticks to the token before it
+    const NO_SPACE_BEFORE: &'static str = ".,!?;:)]%-'@/";
+    /// Punctuation that sticks to the token after it
+    const NO_SPACE_AFTER: &'static str = "([-'&#$@/";
+
+    fn push_str(&mut self, text: &str, space_before: bool, space_after: bool) {
+        if self.space && space_before {
+            self.literal.push(' ');
+        }
+        self.literal.push_str(text);
+        self.space = space_after;
+    }
+
+    fn push_token(&mut self, token: TokenTree) -> Result<()> {
+        match token {
+            TokenTree::Punct(punct) => {
+                let c = punct.as_char();
+                self.push_str(
+                    &c.to_string(),
+                    !Self::NO_SPACE_BEFORE.contains(c),
+                    punct.spacing() == Spacing::Alone && !Self::NO_SPACE_AFTER.contains(c),
+                );
+            }
+            TokenTree::Group(group) => {
+                let (open, close) = match group.delimiter() {
+                    Delimiter::Parenthesis => ("(", ")"),
+                    Delimiter::Bracket => ("[", "]"),
+                    Delimiter::Brace | Delimiter::None => ("", ""),
+                };
+                self.push_str(open, true, false);
+                for token in group.stream() {
+                    match token {
+                        TokenTree::Group(group) if group.delimiter() == Delimiter::Brace => {
+                            self.push_expr(syn::parse2(group.stream())?);
+                        }
+                        token => self.push_token(token)?,
+                    }
+                }
+                self.push_str(close, false, true);
+            }
+            token => self.push_str(&token.to_string(), true, true),
+        }
+        Ok(())
+    }
+
+    fn push_expr(&mut self, expr: Expr) {
+        if self.space {
+            self.literal.push(' ');
+        }
+        self.flush();
+        self.text.push_expr(expr);
+        self.space = true;
+    }
+
+    fn flush(&mut self) {
+        if !self.literal.is_empty() {
+            self.text.push_str(&decode_entities(&self.literal));
+            self.literal.clear();
+        }
+    }
+
+    fn finish(mut self) -> IfmtInput {
+        self.flush();
+        self.text
+    }
+}
+
+/// Replace html entities like `&amp;` and `&#169;` with the characters they stand for
+fn decode_entities(text: &str) -> String {
+    let mut decoded = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some(start) = rest.find('&') {
+        decoded.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        let entity = rest[1..].find(';').map(|end| &rest[1..end + 1]);
+        let character = entity.and_then(|entity| match entity {
+            "amp" => Some('&'),
+            "lt" => Some('<'),
+            "gt" => Some('>'),
+            "quot" => Some('"'),
+            "apos" => Some('\''),
+            "nbsp" => Some('\u{a0}'),
+            _ => {
+                let code = entity.strip_prefix('#')?;
+                let code = match code.strip_prefix(['x', 'X']) {
+                    Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+                    None => code.parse().ok()?,
+                };
+                char::from_u32(code)
+            }
+        });
+
+        match (entity, character) {
+            (Some(entity), Some(character)) => {
+                decoded.push(character);
+                rest = &rest[entity.len() + 2..];
+            }
+            _ => {
+                decoded.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+
+    decoded.push_str(rest);
+    decoded
+}
+
+/// Check if the next tokens are the start of a closing tag: `</`
+fn peek_closing_tag(input: ParseStream) -> bool {
+    input.peek(Token![<]) && input.peek2(Token![/])
+}
+
+/// Check if the next tokens are the end of a self closing tag: `/>`
+fn peek_self_closing_end(input: ParseStream) -> bool {
+    input.peek(Token![/]) && input.peek2(Token![>])
+}
+
+/// The name of a html tag: either a (possibly dashed) element name or a component path
+enum HtmlTagName {
+    Element(ElementName),
+    Component(syn::Path),
+}
+
+impl HtmlTagName {
+    fn parse(input: ParseStream) -> Result<Self> {
+        // Dashed names are custom elements
+        if input.peek2(Token![-]) {
+            let raw = Punctuated::<Ident, Token![-]>::parse_separated_nonempty_with(
+                input,
+                Ident::parse_any,
+            )?;
+            let span = raw.span();
+            let tag = raw
+                .into_iter()
+                .map(|ident| ident.to_string())
+                .collect::<Vec<_>>()
+                .join("-");
+            return Ok(Self::Element(ElementName::Custom(LitStr::new(&tag, span))));
+        }
+
+        let path = input.parse::<syn::Path>()?;
+        match path.get_ident() {
+            Some(ident)
+                if ident
+                    .to_string()
+                    .starts_with(|c: char| c.is_ascii_lowercase()) =>
+            {
+                Ok(Self::Element(ElementName::Ident(ident.clone())))
+            }
+            _ => Ok(Self::Component(path)),
+        }
+    }
+
+    fn is_void(&self) -> bool {
+        const VOID_ELEMENTS: &[&str] = &[
+            "area", "base", "br", "col", "embed", "hr", "img", "input", "link", "meta", "source",
+            "track", "wbr",
+        ];
+        match self {
+            Self::Element(name) => VOID_ELEMENTS.iter().any(|void| name == void),
+            Self::Component(_) => false,
+        }
+    }
+
+    fn matches(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Element(a), Self::Element(b)) => a == b,
+            (Self::Component(a), Self::Component(b)) => a == b,
+            _ => false,
+        }
+    }
+
+    fn span(&self) -> Span {
+        match self {
+            Self::Element(name) => name.span(),
+            Self::Component(path) => path.span(),
+        }
+    }
+}
+
+/// A single `name=value` pair inside of an opening tag
+struct HtmlAttribute {
+    name: HtmlAttributeName,
+    value: HtmlAttributeValue,
+}
+
+enum HtmlAttributeName {
+    Ident(Ident),
+    Custom(LitStr),
+}
+
+enum HtmlAttributeValue {
+    Literal(IfmtInput),
+    Expr(Box<Expr>),
+    Flag(Span),
+}
+
+impl Parse for HtmlAttribute {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let first = Ident::parse_any(input)?;
+        let span = first.span();
+
+        // Names like `data-id` and `xlink:href` are joined into one custom attribute name
+        let mut name = first.to_string();
+        let mut joined = false;
+        while input.peek(Token![-]) || (input.peek(Token![:]) && !input.peek(Token![::])) {
+            if input.peek(Token![-]) {
+                input.parse::<Token![-]>()?;
+                name.push('-');
+            } else {
+                input.parse::<Token![:]>()?;
+                name.push(':');
+            }
+            name.push_str(&Ident::parse_any(input)?.to_string());
+            joined = true;
+        }
+
+        // Camel case names like `viewBox` are spelled differently in dioxus-elements, so they are passed through as they are
+        let name = if joined || name.chars().any(|c| c.is_ascii_uppercase()) {
+            HtmlAttributeName::Custom(LitStr::new(&name, span))
+        } else {
+            // Keywords like `type` and `for` are raw identifiers in dioxus-elements
+            match syn::parse_str::<Ident>(&name) {
+                Ok(_) => HtmlAttributeName::Ident(first),
+                Err(_) => HtmlAttributeName::Ident(Ident::new_raw(&name, span)),
+            }
+        };
+
+        let value = if input.peek(Token![=]) {
+            input.parse::<Token![=]>()?;
+            if input.peek(LitStr) {
+                HtmlAttributeValue::Literal(input.parse()?)
+            } else if input.peek(Brace) {
+                let content;
+                braced!(content in input);
+                HtmlAttributeValue::Expr(Box::new(content.parse()?))
+            } else if input.peek(Lit) {
+                // Unquoted values like `width=100` are strings in html
+                let value = input.parse::<Lit>()?.to_token_stream().to_string();
+                HtmlAttributeValue::Literal(IfmtInput::new_static(&value))
+            } else if input.peek(Ident::peek_any) {
+                let value = Ident::parse_any(input)?.to_string();
+                HtmlAttributeValue::Literal(IfmtInput::new_static(&value))
+            } else {
+                return Err(syn::Error::new(
+                    input.span(),
+                    "Attribute values must be string literals, unquoted values or expressions wrapped in braces",
+                ));
+            }
+        } else {
+            HtmlAttributeValue::Flag(span)
+        };
+
+        Ok(Self { name, value })
+    }
+}
+
+fn parse_html_tag(input: ParseStream) -> Result<BodyNode> {
+    input.parse::<Token![<]>()?;
+    let name = HtmlTagName::parse(input)?;
+
+    let mut attributes = Vec::new();
+    let mut spreads = Vec::new();
+    while !input.peek(Token![>]) && !peek_self_closing_end(input) {
+        if input.is_empty() {
+            return Err(syn::Error::new(name.span(), "Unclosed opening tag"));
+        }
+        if input.peek(Brace) {
+            let content;
+            braced!(content in input);
+            content.parse::<Token![..]>()?;
+            spreads.push(content.parse::<Expr>()?);
+            continue;
+        }
+        attributes.push(input.parse::<HtmlAttribute>()?);
+    }
+
+    let self_closing = input.peek(Token![/]);
+    if self_closing {
+        input.parse::<Token![/]>()?;
+    }
+    input.parse::<Token![>]>()?;
+
+    // Void elements like <br> never have children or a closing tag
+    let self_closing = self_closing || name.is_void();
+
+    let children = if self_closing {
+        Vec::new()
+    } else {
+        let children = parse_html_children(input)?;
+
+        if input.is_empty() {
+            return Err(syn::Error::new(name.span(), "Missing closing tag"));
+        }
+        input.parse::<Token![<]>()?;
+        input.parse::<Token![/]>()?;
+        let closing = HtmlTagName::parse(input)?;
+        if !closing.matches(&name) {
+            return Err(syn::Error::new(
+                closing.span(),
+                "Closing tag does not match the opening tag",
+            ));
+        }
+        input.parse::<Token![>]>()?;
+        children
+    };
+
+    match name {
+        HtmlTagName::Element(el_name) => Ok(BodyNode::Element(html_element(
+            el_name, attributes, spreads, children,
+        )?)),
+        HtmlTagName::Component(path) => Ok(BodyNode::Component(html_component(
+            path, attributes, spreads, children,
+        )?)),
+    }
+}
+
+fn html_element(
+    el_name: ElementName,
+    attributes: Vec<HtmlAttribute>,
+    spreads: Vec<Expr>,
+    children: Vec<BodyNode>,
+) -> Result<Element> {
+    let mut key = None;
+    let mut element_attributes = Vec::new();
+
+    for HtmlAttribute { name, value } in attributes {
+        let name = match name {
+            HtmlAttributeName::Ident(ident) if ident == "key" => {
+                match value {
+                    HtmlAttributeValue::Literal(lit) => key = Some(lit),
+                    _ => {
+                        return Err(syn::Error::new(
+                            ident.span(),
+                            "Keys must be string literals: key=\"{id}\"",
+                        ))
+                    }
+                }
+                continue;
+            }
+            HtmlAttributeName::Ident(ident) => ElementAttrName::BuiltIn(ident),
+            HtmlAttributeName::Custom(name) => ElementAttrName::Custom(name),
+        };
+
+        let is_event =
+            matches!(&name, ElementAttrName::BuiltIn(ident) if ident.to_string().starts_with("on"));
+        let value = match value {
+            HtmlAttributeValue::Literal(lit) => ElementAttrValue::AttrLiteral(lit),
+            HtmlAttributeValue::Expr(expr) if is_event => ElementAttrValue::EventTokens(*expr),
+            HtmlAttributeValue::Expr(expr) => ElementAttrValue::AttrExpr(*expr),
+            HtmlAttributeValue::Flag(span) => ElementAttrValue::AttrExpr(Expr::Lit(syn::ExprLit {
+                attrs: Vec::new(),
+                lit: syn::LitBool::new(true, span).into(),
+            })),
+        };
+
+        element_attributes.push(AttributeType::Named(ElementAttrNamed {
+            el_name: el_name.clone(),
+            attr: ElementAttr { name, value },
+        }));
+    }
+
+    element_attributes.extend(spreads.into_iter().map(AttributeType::Spread));
+
+    Ok(Element::new(
+        key,
+        el_name,
+        element_attributes,
+        children,
+        Brace::default(),
+    ))
+}
+
+fn html_component(
+    name: syn::Path,
+    attributes: Vec<HtmlAttribute>,
+    spreads: Vec<Expr>,
+    children: Vec<BodyNode>,
+) -> Result<Component> {
+    // Components take all of their props from a single spread, like `Comp { ..props }` in rsx!
+    let mut spreads = spreads.into_iter();
+    let manual_props = spreads.next();
+    if let Some(extra) = spreads.next() {
+        return Err(syn::Error::new(
+            extra.span(),
+            "Components can only spread one set of props",
+        ));
+    }
+
+    let mut fields = Vec::new();
+
+    for HtmlAttribute { name, value } in attributes {
+        let name = match name {
+            HtmlAttributeName::Ident(ident) => ident,
+            HtmlAttributeName::Custom(name) => {
+                return Err(syn::Error::new(
+                    name.span(),
+                    "Component properties cannot contain dashes",
+                ))
+            }
+        };
+
+        let content = match value {
+            HtmlAttributeValue::Literal(lit) => ContentField::Formatted(lit),
+            HtmlAttributeValue::Expr(expr) if name.to_string().starts_with("on") => {
+                ContentField::OnHandlerRaw(*expr)
+            }
+            HtmlAttributeValue::Expr(expr) => ContentField::ManExpr(*expr),
+            HtmlAttributeValue::Flag(_) => ContentField::Shorthand(name.clone()),
+        };
+
+        fields.push(ComponentField { name, content });
+    }
+
+    Ok(Component {
+        name,
+        prop_gen_args: None,
+        fields,
+        children,
+        manual_props,
+        brace: Brace::default(),
+    })
+}
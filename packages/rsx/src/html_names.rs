@@ -0,0 +1,391 @@
+//! The names of the elements and attributes in dioxus-html
+//!
+//! rsx! only knows the names to suggest fixes for typos like `calss` or `dvi`, so names that are missing here are still passed on to `dioxus_elements`.
+//! dioxus-html checks that the names are the same as its own definitions in its tests.
+
+/// The html elements and their own attributes. They also have the [`GLOBAL_ATTRIBUTES`]
+#[rustfmt::skip]
+pub const HTML_ELEMENTS: &[(&str, &[&str])] = &[
+    ("base", &["href", "target"]),
+    ("head", &[]),
+    ("link", &[
+        "crossorigin", "href", "hreflang", "media", "rel", "sizes", "title", "r#type", "integrity",
+    ]),
+    ("meta", &["charset", "content", "http_equiv", "name"]),
+    ("style", &["r#type", "media", "nonce", "title"]),
+    ("title", &[]),
+    ("body", &[]),
+    ("address", &[]),
+    ("article", &[]),
+    ("aside", &[]),
+    ("footer", &[]),
+    ("header", &[]),
+    ("hgroup", &[]),
+    ("h1", &[]),
+    ("h2", &[]),
+    ("h3", &[]),
+    ("h4", &[]),
+    ("h5", &[]),
+    ("h6", &[]),
+    ("main", &[]),
+    ("nav", &[]),
+    ("section", &[]),
+    ("blockquote", &["cite"]),
+    ("dd", &[]),
+    ("div", &[]),
+    ("dl", &[]),
+    ("dt", &[]),
+    ("figcaption", &[]),
+    ("figure", &[]),
+    ("hr", &[]),
+    ("li", &["value"]),
+    ("ol", &["reversed", "start", "r#type"]),
+    ("p", &[]),
+    ("pre", &[]),
+    ("ul", &[]),
+    ("a", &["download", "href", "hreflang", "target", "r#type", "ping", "rel"]),
+    ("abbr", &[]),
+    ("b", &[]),
+    ("bdi", &[]),
+    ("bdo", &[]),
+    ("br", &[]),
+    ("cite", &[]),
+    ("code", &["language"]),
+    ("data", &["value"]),
+    ("dfn", &[]),
+    ("em", &[]),
+    ("i", &[]),
+    ("kbd", &[]),
+    ("mark", &[]),
+    ("menu", &[]),
+    ("q", &["cite"]),
+    ("rp", &[]),
+    ("rt", &[]),
+    ("ruby", &[]),
+    ("s", &[]),
+    ("samp", &[]),
+    ("small", &[]),
+    ("span", &[]),
+    ("strong", &[]),
+    ("sub", &[]),
+    ("sup", &[]),
+    ("time", &["datetime"]),
+    ("u", &[]),
+    ("var", &[]),
+    ("wbr", &[]),
+    ("area", &["alt", "coords", "download", "href", "hreflang", "shape", "target"]),
+    ("audio", &["autoplay", "controls", "crossorigin", "muted", "preload", "src", "r#loop"]),
+    ("img", &[
+        "alt", "crossorigin", "decoding", "height", "ismap", "loading", "src", "srcset", "usemap",
+        "width", "referrerpolicy",
+    ]),
+    ("map", &["name"]),
+    ("track", &["default", "kind", "label", "src", "srclang"]),
+    ("video", &[
+        "autoplay", "controls", "crossorigin", "height", "r#loop", "muted", "preload",
+        "playsinline", "poster", "src", "width",
+    ]),
+    ("embed", &["height", "src", "r#type", "width"]),
+    ("iframe", &[
+        "allow", "allowfullscreen", "allowpaymentrequest", "height", "name", "referrerpolicy",
+        "src", "srcdoc", "width", "margin_width", "align", "longdesc", "scrolling", "margin_height",
+        "frame_border",
+    ]),
+    ("object", &["data", "form", "height", "name", "r#type", "typemustmatch", "usemap", "width"]),
+    ("param", &["name", "value"]),
+    ("picture", &[]),
+    ("source", &["src", "r#type"]),
+    ("canvas", &["height", "width"]),
+    ("noscript", &[]),
+    ("script", &[
+        "crossorigin", "defer", "integrity", "nomodule", "nonce", "src", "text", "r#async",
+        "r#type", "r#script",
+    ]),
+    ("del", &["cite", "datetime"]),
+    ("ins", &["cite", "datetime"]),
+    ("caption", &[]),
+    ("col", &["span"]),
+    ("colgroup", &["span"]),
+    ("table", &[]),
+    ("tbody", &[]),
+    ("td", &["colspan", "rowspan"]),
+    ("tfoot", &[]),
+    ("th", &["abbr", "colspan", "rowspan", "scope"]),
+    ("thead", &[]),
+    ("tr", &[]),
+    ("button", &[
+        "autofocus", "disabled", "form", "formaction", "formenctype", "formmethod",
+        "formnovalidate", "formtarget", "name", "value", "r#type",
+    ]),
+    ("datalist", &[]),
+    ("fieldset", &[]),
+    ("form", &["action", "autocomplete", "enctype", "method", "name", "novalidate", "target"]),
+    ("input", &[
+        "accept", "alt", "autocomplete", "autofocus", "capture", "checked", "directory", "disabled",
+        "form", "formaction", "formenctype", "formmethod", "formnovalidate", "formtarget", "height",
+        "initial_checked", "list", "max", "maxlength", "min", "minlength", "multiple", "name",
+        "pattern", "placeholder", "readonly", "required", "size", "spellcheck", "src", "step",
+        "tabindex", "width", "r#type", "value", "initial_value",
+    ]),
+    ("label", &["form", "r#for"]),
+    ("legend", &[]),
+    ("meter", &["value", "min", "max", "low", "high", "optimum", "form"]),
+    ("optgroup", &["disabled", "label"]),
+    ("option", &["disabled", "label", "value", "selected", "initial_selected"]),
+    ("output", &["form", "name"]),
+    ("progress", &["max", "value"]),
+    ("select", &[
+        "autocomplete", "autofocus", "disabled", "form", "multiple", "name", "required", "size",
+        "value",
+    ]),
+    ("textarea", &[
+        "autocomplete", "autofocus", "cols", "disabled", "form", "maxlength", "minlength", "name",
+        "placeholder", "readonly", "required", "rows", "spellcheck", "wrap", "value",
+        "initial_value",
+    ]),
+    ("details", &["open"]),
+    ("dialog", &["open"]),
+    ("summary", &[]),
+    ("slot", &[]),
+    ("template", &[]),
+];
+
+/// The svg and MathML elements and their own attributes. They also have the [`SVG_ATTRIBUTES`]
+#[rustfmt::skip]
+pub const SVG_ELEMENTS: &[(&str, &[&str])] = &[
+    ("svg", &[]),
+    ("animate", &[]),
+    ("animateMotion", &[]),
+    ("animateTransform", &[]),
+    ("circle", &[]),
+    ("clipPath", &[]),
+    ("defs", &[]),
+    ("desc", &[]),
+    ("discard", &[]),
+    ("ellipse", &[]),
+    ("feBlend", &[]),
+    ("feColorMatrix", &[]),
+    ("feComponentTransfer", &[]),
+    ("feComposite", &[]),
+    ("feConvolveMatrix", &[]),
+    ("feDiffuseLighting", &[]),
+    ("feDisplacementMap", &[]),
+    ("feDistantLight", &[]),
+    ("feDropShadow", &[]),
+    ("feFlood", &[]),
+    ("feFuncA", &[]),
+    ("feFuncB", &[]),
+    ("feFuncG", &[]),
+    ("feFuncR", &[]),
+    ("feGaussianBlur", &[]),
+    ("feImage", &[]),
+    ("feMerge", &[]),
+    ("feMergeNode", &[]),
+    ("feMorphology", &[]),
+    ("feOffset", &[]),
+    ("fePointLight", &[]),
+    ("feSpecularLighting", &[]),
+    ("feSpotLight", &[]),
+    ("feTile", &[]),
+    ("feTurbulence", &[]),
+    ("filter", &[]),
+    ("foreignObject", &[]),
+    ("g", &[]),
+    ("hatch", &[]),
+    ("hatchpath", &[]),
+    ("image", &[]),
+    ("line", &[]),
+    ("linearGradient", &[]),
+    ("marker", &[]),
+    ("mask", &[]),
+    ("metadata", &[]),
+    ("mpath", &[]),
+    ("path", &[]),
+    ("pattern", &[]),
+    ("polygon", &[]),
+    ("polyline", &[]),
+    ("radialGradient", &[]),
+    ("rect", &[]),
+    ("set", &[]),
+    ("stop", &[]),
+    ("switch", &[]),
+    ("symbol", &[]),
+    ("text", &[]),
+    ("textPath", &[]),
+    ("tspan", &[]),
+    ("view", &[]),
+    ("r#use", &["href"]),
+    ("annotation", &["encoding"]),
+    ("annotationXml", &["encoding"]),
+    ("merror", &[]),
+    ("math", &["display"]),
+    ("mfrac", &["linethickness"]),
+    ("mi", &["mathvariant"]),
+    ("mmultiscripts", &[]),
+    ("mn", &[]),
+    ("mo", &[
+        "fence", "largeop", "lspace", "maxsize", "minsize", "movablelimits", "rspace", "separator",
+        "stretchy", "symmetric",
+    ]),
+    ("mover", &["accent"]),
+    ("mpadded", &["depth", "height", "lspace", "voffset", "width"]),
+    ("mphantom", &[]),
+    ("mprescripts", &[]),
+    ("mroot", &[]),
+    ("mrow", &[]),
+    ("ms", &["lquote", "rquote"]),
+    ("mspace", &["depth", "height", "width"]),
+    ("msqrt", &[]),
+    ("mstyle", &[]),
+    ("msub", &[]),
+    ("msubsup", &[]),
+    ("msup", &[]),
+    ("mtable", &[]),
+    ("mtd", &["columnspan", "rowspan"]),
+    ("mtext", &[]),
+    ("mtr", &[]),
+    ("munder", &["accentunder"]),
+    ("munderover", &["accent", "accentunder"]),
+    ("semantics", &["encoding"]),
+];
+
+/// The attributes of every html element
+#[rustfmt::skip]
+pub const GLOBAL_ATTRIBUTES: &[&str] = &[
+    "prevent_default", "accesskey", "autocapitalize", "autofocus", "class", "contenteditable",
+    "data", "dir", "draggable", "enterkeyhint", "exportparts", "hidden", "id", "inputmode", "is",
+    "itemid", "itemprop", "itemref", "itemscope", "itemtype", "lang", "nonce", "part", "role",
+    "slot", "spellcheck", "style", "tabindex", "title", "translate", "dangerous_inner_html",
+    "align_content", "align_items", "align_self", "alignment_adjust", "alignment_baseline", "all",
+    "alt", "animation", "animation_delay", "animation_direction", "animation_duration",
+    "animation_fill_mode", "animation_iteration_count", "animation_name", "animation_play_state",
+    "animation_timing_function", "azimuth", "backdrop_filter", "backface_visibility", "background",
+    "background_attachment", "background_clip", "background_color", "background_image",
+    "background_origin", "background_position", "background_repeat", "background_size",
+    "background_blend_mode", "baseline_shift", "bleed", "bookmark_label", "bookmark_level",
+    "bookmark_state", "border", "border_color", "border_style", "border_width", "border_bottom",
+    "border_bottom_color", "border_bottom_style", "border_bottom_width", "border_left",
+    "border_left_color", "border_left_style", "border_left_width", "border_right",
+    "border_right_color", "border_right_style", "border_right_width", "border_top",
+    "border_top_color", "border_top_style", "border_top_width", "border_collapse", "border_image",
+    "border_image_outset", "border_image_repeat", "border_image_slice", "border_image_source",
+    "border_image_width", "border_radius", "border_bottom_left_radius",
+    "border_bottom_right_radius", "border_top_left_radius", "border_top_right_radius",
+    "border_spacing", "bottom", "box_decoration_break", "box_shadow", "box_sizing", "box_snap",
+    "break_after", "break_before", "break_inside", "buffered_rendering", "caption_side", "clear",
+    "clear_side", "clip", "clip_path", "clip_rule", "color", "color_adjust", "color_correction",
+    "color_interpolation", "color_interpolation_filters", "color_profile", "color_rendering",
+    "column_fill", "column_gap", "column_rule", "column_rule_color", "column_rule_style",
+    "column_rule_width", "column_span", "columns", "column_count", "column_width", "contain",
+    "content", "counter_increment", "counter_reset", "counter_set", "cue", "cue_after",
+    "cue_before", "cursor", "direction", "display", "display_inside", "display_outside",
+    "display_extras", "display_box", "dominant_baseline", "elevation", "empty_cells",
+    "enable_background", "fill", "fill_opacity", "fill_rule", "filter", "float",
+    "float_defer_column", "float_defer_page", "float_offset", "float_wrap", "flow_into",
+    "flow_from", "flex", "flex_basis", "flex_grow", "flex_shrink", "flex_flow", "flex_direction",
+    "flex_wrap", "flood_color", "flood_opacity", "font", "font_family", "font_size", "font_stretch",
+    "font_style", "font_weight", "font_feature_settings", "font_kerning", "font_language_override",
+    "font_size_adjust", "font_synthesis", "font_variant", "font_variant_alternates",
+    "font_variant_caps", "font_variant_east_asian", "font_variant_ligatures",
+    "font_variant_numeric", "font_variant_position", "footnote_policy",
+    "glyph_orientation_horizontal", "glyph_orientation_vertical", "grid", "grid_auto_flow",
+    "grid_auto_columns", "grid_auto_rows", "grid_template", "grid_template_areas",
+    "grid_template_columns", "grid_template_rows", "grid_area", "grid_column", "grid_column_start",
+    "grid_column_end", "grid_row", "grid_row_start", "grid_row_end", "hanging_punctuation",
+    "height", "hyphenate_character", "hyphenate_limit_chars", "hyphenate_limit_last",
+    "hyphenate_limit_lines", "hyphenate_limit_zone", "hyphens", "icon", "image_orientation",
+    "image_resolution", "image_rendering", "ime", "ime_align", "ime_mode", "ime_offset",
+    "ime_width", "initial_letters", "inline_box_align", "isolation", "justify_content",
+    "justify_items", "justify_self", "kerning", "left", "letter_spacing", "lighting_color",
+    "line_box_contain", "line_break", "line_grid", "line_height", "line_slack", "line_snap",
+    "list_style", "list_style_image", "list_style_position", "list_style_type", "margin",
+    "margin_bottom", "margin_left", "margin_right", "margin_top", "marker", "marker_end",
+    "marker_mid", "marker_pattern", "marker_segment", "marker_start", "marker_knockout_left",
+    "marker_knockout_right", "marker_side", "marks", "marquee_direction", "marquee_play_count",
+    "marquee_speed", "marquee_style", "mask", "mask_image", "mask_repeat", "mask_position",
+    "mask_clip", "mask_origin", "mask_size", "mask_box", "mask_box_outset", "mask_box_repeat",
+    "mask_box_slice", "mask_box_source", "mask_box_width", "mask_type", "max_height", "max_lines",
+    "max_width", "min_height", "min_width", "mix_blend_mode", "nav_down", "nav_index", "nav_left",
+    "nav_right", "nav_up", "object_fit", "object_position", "offset_after", "offset_before",
+    "offset_end", "offset_start", "opacity", "order", "orphans", "outline", "outline_color",
+    "outline_style", "outline_width", "outline_offset", "overflow", "overflow_x", "overflow_y",
+    "overflow_style", "overflow_wrap", "padding", "padding_bottom", "padding_left", "padding_right",
+    "padding_top", "page", "page_break_after", "page_break_before", "page_break_inside",
+    "paint_order", "pause", "pause_after", "pause_before", "perspective", "perspective_origin",
+    "pitch", "pitch_range", "play_during", "pointer_events", "position", "quotes",
+    "region_fragment", "resize", "rest", "rest_after", "rest_before", "richness", "right",
+    "ruby_align", "ruby_merge", "ruby_position", "scroll_behavior", "scroll_snap_coordinate",
+    "scroll_snap_destination", "scroll_snap_points_x", "scroll_snap_points_y", "scroll_snap_type",
+    "shape_image_threshold", "shape_inside", "shape_margin", "shape_outside", "shape_padding",
+    "shape_rendering", "size", "speak", "speak_as", "speak_header", "speak_numeral",
+    "speak_punctuation", "speech_rate", "stop_color", "stop_opacity", "stress", "string_set",
+    "stroke", "stroke_dasharray", "stroke_dashoffset", "stroke_linecap", "stroke_linejoin",
+    "stroke_miterlimit", "stroke_opacity", "stroke_width", "tab_size", "table_layout", "text_align",
+    "text_align_all", "text_align_last", "text_anchor", "text_combine_upright", "text_decoration",
+    "text_decoration_color", "text_decoration_line", "text_decoration_style",
+    "text_decoration_skip", "text_emphasis", "text_emphasis_color", "text_emphasis_style",
+    "text_emphasis_position", "text_emphasis_skip", "text_height", "text_indent", "text_justify",
+    "text_orientation", "text_overflow", "text_rendering", "text_shadow", "text_size_adjust",
+    "text_space_collapse", "text_spacing", "text_transform", "text_underline_position", "text_wrap",
+    "top", "touch_action", "transform", "transform_box", "transform_origin", "transform_style",
+    "transition", "transition_delay", "transition_duration", "transition_property", "unicode_bidi",
+    "vector_effect", "vertical_align", "visibility", "voice_balance", "voice_duration",
+    "voice_family", "voice_pitch", "voice_range", "voice_rate", "voice_stress", "voice_volumn",
+    "volume", "white_space", "widows", "width", "will_change", "word_break", "word_spacing",
+    "word_wrap", "wrap_flow", "wrap_through", "writing_mode", "gap", "list_styler_type", "row_gap",
+    "transition_timing_function", "user_select", "webkit_user_select", "z_index", "aria_current",
+    "aria_details", "aria_disabled", "aria_hidden", "aria_invalid", "aria_keyshortcuts",
+    "aria_label", "aria_roledescription", "aria_autocomplete", "aria_checked", "aria_expanded",
+    "aria_haspopup", "aria_level", "aria_modal", "aria_multiline", "aria_multiselectable",
+    "aria_orientation", "aria_placeholder", "aria_pressed", "aria_readonly", "aria_required",
+    "aria_selected", "aria_sort", "aria_valuemax", "aria_valuemin", "aria_valuenow",
+    "aria_valuetext", "aria_atomic", "aria_busy", "aria_live", "aria_relevant", "aria_dropeffect",
+    "aria_grabbed", "aria_activedescendant", "aria_colcount", "aria_colindex", "aria_colspan",
+    "aria_controls", "aria_describedby", "aria_errormessage", "aria_flowto", "aria_labelledby",
+    "aria_owns", "aria_posinset", "aria_rowcount", "aria_rowindex", "aria_rowspan", "aria_setsize",
+];
+
+/// The attributes of every svg and MathML element
+#[rustfmt::skip]
+pub const SVG_ATTRIBUTES: &[&str] = &[
+    "prevent_default", "accent_height", "accumulate", "additive", "alignment_baseline",
+    "alphabetic", "amplitude", "arabic_form", "ascent", "attribute_name", "attribute_type",
+    "azimuth", "base_frequency", "baseline_shift", "base_profile", "bbox", "begin", "bias", "by",
+    "calc_mode", "cap_height", "class", "clip", "clip_path_units", "clip_path", "clip_rule",
+    "color", "color_interpolation", "color_interpolation_filters", "color_profile",
+    "color_rendering", "content_script_type", "content_style_type", "crossorigin", "cursor", "cx",
+    "cy", "d", "decelerate", "descent", "diffuse_constant", "direction", "display", "divisor",
+    "dominant_baseline", "dur", "dx", "dy", "edge_mode", "elevation", "enable_background", "end",
+    "exponent", "fill", "fill_opacity", "fill_rule", "filter", "filter_units", "filterRes",
+    "filterUnits", "flood_color", "flood_opacity", "font_family", "font_size", "font_size_adjust",
+    "font_stretch", "font_style", "font_variant", "font_weight", "format", "from", "fr", "fx", "fy",
+    "g1", "g2", "glyph_name", "glyph_orientation_horizontal", "glyph_orientation_vertical",
+    "glyph_ref", "gradient_transform", "gradient_units", "hanging", "height", "href", "hreflang",
+    "horiz_adv_x", "horiz_origin_x", "id", "ideographic", "image_rendering", "_in", "in2",
+    "intercept", "k", "k1", "k2", "k3", "k4", "kernel_matrix", "kernel_unit_length", "kerning",
+    "key_points", "key_splines", "key_times", "lang", "length_adjust", "letter_spacing",
+    "lighting_color", "limiting_cone_angle", "local", "marker_end", "marker_mid", "marker_start",
+    "marker_height", "marker_units", "marker_width", "mask", "mask_type", "mask_content_units",
+    "mask_units", "mathematical", "max", "media", "method", "min", "mode", "name", "num_octaves",
+    "offset", "opacity", "operator", "order", "orient", "orientation", "origin", "overflow",
+    "overline_position", "overline_thickness", "panose_1", "paint_order", "path", "path_length",
+    "pattern_content_units", "pattern_transform", "pattern_units", "ping", "pointer_events",
+    "points", "points_at_x", "points_at_y", "points_at_z", "preserve_alpha",
+    "preserve_aspect_ratio", "primitive_units", "r", "radius", "referrer_policy", "ref_x", "ref_y",
+    "rel", "rendering_intent", "repeat_count", "repeat_dur", "required_extensions",
+    "required_features", "restart", "result", "role", "rotate", "rx", "ry", "scale", "seed",
+    "shape_rendering", "slope", "spacing", "specular_constant", "specular_exponent", "speed",
+    "spread_method", "start_offset", "std_deviation", "stemh", "stemv", "stitch_tiles",
+    "stop_color", "stop_opacity", "strikethrough_position", "strikethrough_thickness", "string",
+    "stroke", "stroke_dasharray", "stroke_dashoffset", "stroke_linecap", "stroke_linejoin",
+    "stroke_miterlimit", "stroke_opacity", "stroke_width", "style", "surface_scale",
+    "system_language", "tabindex", "table_values", "target", "target_x", "target_y", "text_anchor",
+    "text_decoration", "text_rendering", "text_length", "to", "transform", "transform_origin",
+    "r#type", "u1", "u2", "underline_position", "underline_thickness", "unicode", "unicode_bidi",
+    "unicode_range", "units_per_em", "v_alphabetic", "v_hanging", "v_ideographic", "v_mathematical",
+    "values", "vector_effect", "version", "vert_adv_y", "vert_origin_x", "vert_origin_y",
+    "view_box", "view_target", "visibility", "width", "widths", "word_spacing", "writing_mode", "x",
+    "x_height", "x1", "x2", "xmlns", "xmlns_xlink", "xlink_href", "xlink_title", "xlink_arcrole",
+    "xlink_role", "xlink_show", "xlink_actuate", "xlink_type", "xml_lang", "xml_space",
+    "x_channel_selector", "y", "y1", "y2", "y_channel_selector", "z", "zoom_and_pan",
+];
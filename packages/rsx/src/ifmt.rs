@@ -192,8 +192,11 @@ impl ToTokens for IfmtInput {
                     format_literal += "{";
                     format_literal += &expr_counter.to_string();
                     expr_counter += 1;
-                    format_literal += ":";
-                    format_literal += format_args;
+                    // An empty format spec is left out so a following `<`, `^` or `>` isn't read as an alignment
+                    if !format_args.is_empty() {
+                        format_literal += ":";
+                        format_literal += format_args;
+                    }
                     format_literal += "}";
                 }
             }
@@ -11,6 +11,13 @@
 //! - [x] Components as top-level element (through ambiguous)
 //! - [x] Tags as top-level elements (through ambiguous)
 //! - [x] Good errors if parsing fails
+//! - [x] Suggestions for common attribute name mistakes (quoted names like `"my-attr": value` skip the check)
+//! - [x] Suggestions for misspelled element and attribute names like `dvi` or `calss`
+//! - [x] Event modifiers like `onclick |prevent_default, stop_propagation|: move |_| {}`
+//! - [x] Class lists like `class: ["btn", active.then_some("btn-active")]`
+//! - [x] Style maps like `style: { width: "100px", display: "flex" }` checked against the known CSS properties
+//! - [x] Raw html nodes like `raw!(html)` that render a string without escaping it
+//! - [x] Keyed loops like `for item in items, key: item.id { ... }` with checks for mixed and index based keys
 //!
 //! Any errors in using rsx! will likely occur when people start using it, so the first errors must be really helpful.
 
@@ -21,8 +28,12 @@ mod component;
 mod element;
 #[cfg(feature = "hot_reload")]
 pub mod hot_reload;
+#[cfg(feature = "html")]
+mod html;
+pub mod html_names;
 mod ifmt;
 mod node;
+mod raw_html;
 
 use std::{fmt::Debug, hash::Hash};
 
@@ -34,10 +45,13 @@ use dioxus_core::{Template, TemplateAttribute, TemplateNode};
 pub use element::*;
 #[cfg(feature = "hot_reload")]
 pub use hot_reload::HotReloadingContext;
+#[cfg(feature = "html")]
+pub use html::HtmlCallBody;
 pub use ifmt::*;
 #[cfg(feature = "hot_reload")]
 use internment::Intern;
 pub use node::*;
+pub use raw_html::*;
 
 // imports
 use proc_macro2::TokenStream as TokenStream2;
@@ -176,6 +190,13 @@ impl<'a> TemplateRenderer<'a> {
 
 impl<'a> ToTokens for TemplateRenderer<'a> {
     fn to_tokens(&self, out_tokens: &mut TokenStream2) {
+        self.render(None, out_tokens)
+    }
+}
+
+impl<'a> TemplateRenderer<'a> {
+    /// Render the template. If a key is passed, it is used instead of the key of the root node
+    pub(crate) fn render(&self, key_override: Option<&syn::Expr>, out_tokens: &mut TokenStream2) {
         let mut context = DynamicContext::default();
 
         let key = match self.roots.first() {
@@ -184,9 +205,10 @@ impl<'a> ToTokens for TemplateRenderer<'a> {
             _ => None,
         };
 
-        let key_tokens = match key {
-            Some(tok) => quote! { Some( #tok.to_string() ) },
-            None => quote! { None },
+        let key_tokens = match (key_override, key) {
+            (Some(expr), _) => quote! { Some( (#expr).to_string() ) },
+            (None, Some(tok)) => quote! { Some( #tok.to_string() ) },
+            (None, None) => quote! { None },
         };
 
         let root_col = match self.roots.first() {
@@ -300,6 +322,7 @@ impl DynamicMapping {
     fn add_node(&mut self, node: BodyNode) {
         match node {
             BodyNode::Element(el) => {
+                let has_spread = el.has_spread();
                 for attr in el.merged_attributes {
                     match &attr {
                         AttributeType::Named(ElementAttrNamed {
@@ -309,7 +332,7 @@ impl DynamicMapping {
                                     ..
                                 },
                             ..
-                        }) if input.is_static() => {}
+                        }) if input.is_static() && !has_spread => {}
                         _ => {
                             self.insert_attribute(attr);
                         }
@@ -327,6 +350,8 @@ impl DynamicMapping {
             | BodyNode::Text(_)
             | BodyNode::ForLoop(_)
             | BodyNode::IfChain(_)
+            | BodyNode::Match(_)
+            | BodyNode::RawHtml(_)
             | BodyNode::Component(_) => {
                 self.insert_node(node);
             }
@@ -367,12 +392,15 @@ impl<'a> DynamicContext<'a> {
                                     name,
                                 },
                             ..
-                        }) if value.is_static() => {
+                        }) if value.is_static() && !el.has_spread() => {
                             let value = value.source.as_ref().unwrap();
                             let attribute_name_rust = name.to_string();
                             let (name, namespace) =
                                 Ctx::map_attribute(&element_name_rust, &attribute_name_rust)
-                                    .unwrap_or((intern(attribute_name_rust.as_str()), None));
+                                    .unwrap_or((
+                                        intern(attribute_name_rust.as_str()),
+                                        name.custom_namespace(),
+                                    ));
                             static_attrs.push(TemplateAttribute::Static {
                                 name,
                                 namespace,
@@ -424,6 +452,8 @@ impl<'a> DynamicContext<'a> {
             | BodyNode::Text(_)
             | BodyNode::ForLoop(_)
             | BodyNode::IfChain(_)
+            | BodyNode::Match(_)
+            | BodyNode::RawHtml(_)
             | BodyNode::Component(_) => {
                 let idx = match mapping {
                     Some(mapping) => mapping.get_node_idx(root)?,
@@ -460,12 +490,15 @@ impl<'a> DynamicContext<'a> {
                                 name,
                             },
                         ..
-                    }) if value.is_static() => {
+                    }) if value.is_static() && !el.has_spread() => {
                         let value = value.to_static().unwrap();
                         let ns = {
                             match name {
                                 ElementAttrName::BuiltIn(name) => ns(quote!(#name.1)),
-                                ElementAttrName::Custom(_) => quote!(None),
+                                ElementAttrName::Custom(_) => match name.custom_namespace() {
+                                    Some(ns) => quote!(Some(#ns)),
+                                    None => quote!(None),
+                                },
                             }
                         };
                         let name = match (el_name, name) {
@@ -541,6 +574,8 @@ impl<'a> DynamicContext<'a> {
             | BodyNode::Text(_)
             | BodyNode::ForLoop(_)
             | BodyNode::IfChain(_)
+            | BodyNode::Match(_)
+            | BodyNode::RawHtml(_)
             | BodyNode::Component(_) => {
                 let ct = self.dynamic_nodes.len();
                 self.dynamic_nodes.push(root);
@@ -7,7 +7,7 @@ use syn::{
     parse::{Parse, ParseStream},
     spanned::Spanned,
     token::{self, Brace},
-    Expr, ExprIf, LitStr, Pat, Result,
+    Expr, ExprIf, Ident, LitStr, Pat, Result,
 };
 
 /*
@@ -24,8 +24,10 @@ pub enum BodyNode {
     Component(Component),
     ForLoop(ForLoop),
     IfChain(IfChain),
+    Match(MatchChain),
     Text(IfmtInput),
     RawExpr(Expr),
+    RawHtml(RawHtml),
 }
 
 impl BodyNode {
@@ -41,6 +43,8 @@ impl BodyNode {
             BodyNode::RawExpr(exp) => exp.span(),
             BodyNode::ForLoop(fl) => fl.for_token.span(),
             BodyNode::IfChain(f) => f.if_token.span(),
+            BodyNode::Match(m) => m.match_token.span(),
+            BodyNode::RawHtml(raw) => raw.raw_token.span(),
         }
     }
 }
@@ -51,6 +55,11 @@ impl Parse for BodyNode {
             return Ok(BodyNode::Text(stream.parse()?));
         }
 
+        // raw!(html) renders a string as html without escaping it
+        if RawHtml::peek(stream) {
+            return Ok(BodyNode::RawHtml(stream.parse()?));
+        }
+
         // if this is a dash-separated path, it's a web component (custom element)
         let body_stream = stream.fork();
         if let Ok(ElementName::Custom(name)) = body_stream.parse::<ElementName>() {
@@ -112,17 +121,27 @@ impl Parse for BodyNode {
             return Ok(BodyNode::IfChain(stream.parse()?));
         }
 
-        // Match statements are special but have no special arm syntax
-        // we could allow arm syntax if we wanted
+        // Match statements can either have rsx arms or regular rust expression arms
         //
         // ```
-        // match {
-        //  val => div {}
-        //  other_val => div {}
+        // match val {
+        //  Some(val) => div { "{val}" }
+        //  None => {
+        //      "nothing"
+        //      span {}
+        //  }
         // }
         // ```
+        //
+        // If the arms are not valid rsx, we fall back to a regular expression. Arms that look like rsx
+        // return the error from parsing them as rsx instead, since that is what the user meant to write
         if stream.peek(Token![match]) {
-            return Ok(BodyNode::RawExpr(stream.parse::<Expr>()?));
+            let match_stream = stream.fork();
+            match match_stream.parse::<MatchChain>() {
+                Ok(_) => return Ok(BodyNode::Match(stream.parse()?)),
+                Err(err) if match_arms_look_like_rsx(&stream.fork()) => return Err(err),
+                Err(_) => return Ok(BodyNode::RawExpr(stream.parse::<Expr>()?)),
+            }
         }
 
         if stream.peek(token::Brace) {
@@ -146,6 +165,7 @@ impl ToTokens for BodyNode {
             BodyNode::Text(txt) => tokens.append_all(quote! {
                 dioxus_core::DynamicNode::Text(dioxus_core::VText::new(#txt.to_string()))
             }),
+            BodyNode::RawHtml(raw) => raw.to_tokens(tokens),
             BodyNode::RawExpr(exp) => tokens.append_all(quote! {
                 {
                     let ___nodes = (#exp).into_dyn_node();
@@ -154,20 +174,29 @@ impl ToTokens for BodyNode {
             }),
             BodyNode::ForLoop(exp) => {
                 let ForLoop {
-                    pat, expr, body, ..
+                    pat,
+                    expr,
+                    body,
+                    key,
+                    ..
                 } = exp;
 
                 let renderer: TemplateRenderer = TemplateRenderer {
                     roots: body,
                     location: None,
                 };
+                let mut rendered = TokenStream2::new();
+                renderer.render(key.as_ref(), &mut rendered);
+
+                let index_key_warning = exp.index_key_warning();
 
                 // Signals expose an issue with temporary lifetimes
                 // We need to directly render out the nodes first to collapse their lifetime to <'a>
                 // And then we can return them into the dyn loop
                 tokens.append_all(quote! {
                     {
-                        let ___nodes = (#expr).into_iter().map(|#pat| { #renderer }).into_dyn_node();
+                        #index_key_warning
+                        let ___nodes = (#expr).into_iter().map(|#pat| { #rendered }).into_dyn_node();
                         ___nodes
                     }
                 })
@@ -220,6 +249,46 @@ impl ToTokens for BodyNode {
                     }
                 });
             }
+            BodyNode::Match(chain) => {
+                let MatchChain {
+                    match_token,
+                    expr,
+                    arms,
+                    ..
+                } = chain;
+
+                let arms = arms.iter().map(|arm| {
+                    let MatchArm {
+                        pat,
+                        guard,
+                        fat_arrow_token,
+                        body,
+                    } = arm;
+
+                    let guard = guard
+                        .as_ref()
+                        .map(|(if_token, cond)| quote! { #if_token #cond });
+
+                    // An empty arm renders nothing
+                    if body.is_empty() {
+                        return quote! { #pat #guard #fat_arrow_token { None }, };
+                    }
+
+                    let renderer: TemplateRenderer = TemplateRenderer {
+                        roots: body,
+                        location: None,
+                    };
+
+                    quote! { #pat #guard #fat_arrow_token { Some({#renderer}) }, }
+                });
+
+                tokens.append_all(quote! {
+                    {
+                        let ___nodes = (#match_token #expr { #(#arms)* }).into_dyn_node();
+                        ___nodes
+                    }
+                });
+            }
         }
     }
 }
@@ -230,6 +299,8 @@ pub struct ForLoop {
     pub pat: Pat,
     pub in_token: Token![in],
     pub expr: Box<Expr>,
+    /// The key of each item: `for item in items, key: item.id { ... }`
+    pub key: Option<Expr>,
     pub body: Vec<BodyNode>,
     pub brace_token: token::Brace,
 }
@@ -243,19 +314,138 @@ impl Parse for ForLoop {
         let in_token: Token![in] = input.parse()?;
         let expr: Expr = input.call(Expr::parse_without_eager_brace)?;
 
+        let key = if input.peek(Token![,]) {
+            input.parse::<Token![,]>()?;
+            let key_token = input.parse::<Ident>()?;
+            if key_token != "key" {
+                return Err(syn::Error::new(
+                    key_token.span(),
+                    "Expected a key for the items in the loop: `for item in items, key: item.id { ... }`",
+                ));
+            }
+            input.parse::<Token![:]>()?;
+            Some(input.call(Expr::parse_without_eager_brace)?)
+        } else {
+            None
+        };
+
         let (brace_token, body) = parse_buffer_as_braced_children(input)?;
 
-        Ok(Self {
+        let for_loop = Self {
             for_token,
             pat,
             in_token,
+            key,
             body,
             brace_token,
             expr: Box::new(expr),
+        };
+        for_loop.validate_keys()?;
+
+        Ok(for_loop)
+    }
+}
+
+impl ForLoop {
+    /// Catch keys that would make the diffing panic or silently be ignored
+    fn validate_keys(&self) -> Result<()> {
+        if self.key.is_some() {
+            if let [BodyNode::Element(Element { key: Some(key), .. })] = self.body.as_slice() {
+                return Err(syn::Error::new(
+                    key.source.span(),
+                    "This key is overridden by the key of the for loop. Remove one of them",
+                ));
+            }
+            return Ok(());
+        }
+
+        // Every item in a list must be keyed, or none of them can be
+        let branches: Vec<(Span, bool)> = match self.body.as_slice() {
+            [BodyNode::IfChain(chain)] => {
+                let mut branches = Vec::new();
+                let mut current = Some(chain);
+                while let Some(chain) = current {
+                    branches.push((chain.if_token.span(), is_keyed(&chain.then_branch)));
+                    if let Some(else_branch) = &chain.else_branch {
+                        branches.push((chain.if_token.span(), is_keyed(else_branch)));
+                    }
+                    current = chain.else_if_branch.as_deref();
+                }
+                branches
+            }
+            [BodyNode::Match(chain)] => chain
+                .arms
+                .iter()
+                .map(|arm| (arm.pat.span(), is_keyed(&arm.body)))
+                .collect(),
+            _ => Vec::new(),
+        };
+
+        let any_keyed = branches.iter().any(|(_, keyed)| *keyed);
+        if let Some((span, _)) = branches.iter().find(|(_, keyed)| !keyed) {
+            if any_keyed {
+                return Err(syn::Error::new(
+                    *span,
+                    "Some items in this list are keyed and some are not. Either give every branch a key or use `for item in items, key: item.id { ... }`",
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Keys that come from the loop index change when items are inserted or removed, so they don't help with diffing.
+    ///
+    /// We can't emit warnings from a proc macro, so we use a deprecated item to warn instead.
+    fn index_key_warning(&self) -> Option<TokenStream2> {
+        let key = self.key.as_ref()?;
+
+        let Pat::Tuple(tuple) = &self.pat else {
+            return None;
+        };
+        let Some(Pat::Ident(index)) = tuple.elems.first() else {
+            return None;
+        };
+
+        let mut iter = self.expr.as_ref();
+        let enumerated = loop {
+            match iter {
+                Expr::MethodCall(call) if call.method == "enumerate" => break true,
+                Expr::MethodCall(call) => iter = &call.receiver,
+                _ => break false,
+            }
+        };
+
+        let uses_index = key.to_token_stream().into_iter().any(
+            |token| matches!(token, proc_macro2::TokenTree::Ident(ident) if ident == index.ident),
+        );
+
+        if !enumerated || !uses_index {
+            return None;
+        }
+
+        Some(quote::quote_spanned! { key.span() =>
+            {
+                #[deprecated(
+                    note = "This key is derived from the loop index, so it changes when items are added or removed. Use a stable id from the item instead"
+                )]
+                #[allow(non_upper_case_globals)]
+                const index_key: () = ();
+                index_key
+            }
         })
     }
 }
 
+/// Check if a list of nodes is a single keyed node
+fn is_keyed(nodes: &[BodyNode]) -> bool {
+    match nodes {
+        [BodyNode::Element(el)] => el.key.is_some(),
+        [BodyNode::Component(comp)] => comp.key().is_some(),
+        _ => false,
+    }
+}
+
 #[derive(PartialEq, Eq, Clone, Debug, Hash)]
 pub struct IfChain {
     pub if_token: Token![if],
@@ -298,6 +488,137 @@ impl Parse for IfChain {
     }
 }
 
+#[derive(PartialEq, Eq, Clone, Debug, Hash)]
+pub struct MatchChain {
+    pub match_token: Token![match],
+    pub expr: Box<Expr>,
+    pub brace_token: token::Brace,
+    pub arms: Vec<MatchArm>,
+}
+
+impl Parse for MatchChain {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let match_token: Token![match] = input.parse()?;
+        let expr = Box::new(input.call(Expr::parse_without_eager_brace)?);
+
+        let content;
+        let brace_token = braced!(content in input);
+
+        let mut arms = vec![];
+        while !content.is_empty() {
+            arms.push(content.parse()?);
+        }
+
+        Ok(Self {
+            match_token,
+            expr,
+            brace_token,
+            arms,
+        })
+    }
+}
+
+#[derive(PartialEq, Eq, Clone, Debug, Hash)]
+pub struct MatchArm {
+    pub pat: Pat,
+    pub guard: Option<(Token![if], Box<Expr>)>,
+    pub fat_arrow_token: Token![=>],
+    pub body: Vec<BodyNode>,
+}
+
+impl Parse for MatchArm {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let pat = Pat::parse_multi_with_leading_vert(input)?;
+
+        let guard = if input.peek(Token![if]) {
+            let if_token: Token![if] = input.parse()?;
+            let cond = Box::new(input.parse::<Expr>()?);
+            Some((if_token, cond))
+        } else {
+            None
+        };
+
+        let fat_arrow_token: Token![=>] = input.parse()?;
+
+        // Arms may either be a braced list of nodes or a single node
+        let body = if input.peek(token::Brace) {
+            parse_buffer_as_braced_children(input)?.1
+        } else {
+            vec![input.parse::<BodyNode>()?]
+        };
+
+        // consume comma if it exists
+        if input.peek(Token![,]) {
+            let _ = input.parse::<Token![,]>();
+        }
+
+        Ok(Self {
+            pat,
+            guard,
+            fat_arrow_token,
+            body,
+        })
+    }
+}
+
+/// Check if any arm of a match starts with an element, or with text followed by another node
+fn match_arms_look_like_rsx(input: ParseStream) -> bool {
+    arms_look_like_rsx(input).unwrap_or(false)
+}
+
+fn arms_look_like_rsx(input: ParseStream) -> Result<bool> {
+    input.parse::<Token![match]>()?;
+    input.call(Expr::parse_without_eager_brace)?;
+    let content;
+    braced!(content in input);
+
+    while !content.is_empty() {
+        Pat::parse_multi_with_leading_vert(&content)?;
+        if content.peek(Token![if]) {
+            content.parse::<Token![if]>()?;
+            content.parse::<Expr>()?;
+        }
+        content.parse::<Token![=>]>()?;
+
+        if node_looks_like_rsx(&content.fork()) || block_looks_like_rsx(&content.fork())? {
+            return Ok(true);
+        }
+
+        // Skip to the next arm
+        content.parse::<Expr>()?;
+        if content.peek(Token![,]) {
+            content.parse::<Token![,]>()?;
+        }
+    }
+    Ok(false)
+}
+
+fn block_looks_like_rsx(input: ParseStream) -> Result<bool> {
+    if !input.peek(token::Brace) {
+        return Ok(false);
+    }
+    let content;
+    braced!(content in input);
+    Ok(node_looks_like_rsx(&content))
+}
+
+/// Check if the input starts with a node that is not a rust expression, using the same rules as [`BodyNode::parse`] for elements
+fn node_looks_like_rsx(input: ParseStream) -> bool {
+    if input.peek(LitStr) {
+        let _ = input.parse::<LitStr>();
+        return input.peek(LitStr) || input.peek(Ident) || input.peek(token::Brace);
+    }
+    match input.parse::<Ident>() {
+        Ok(ident) => {
+            let name = ident.to_string();
+            input.peek(token::Brace)
+                && name.starts_with(|c: char| c.is_ascii_lowercase())
+                && !name.contains('_')
+        }
+        Err(_) => false,
+    }
+}
+
 fn parse_buffer_as_braced_children(
     input: &syn::parse::ParseBuffer<'_>,
 ) -> Result<(Brace, Vec<BodyNode>)> {
@@ -0,0 +1,124 @@
+//! Parse the raw!() node
+//! ======================
+//!
+//! `raw!("<b>bold</b>")` renders a string as html without escaping it. It is lowered into a wrapper element
+//! with `display: contents` and the `dangerous_inner_html` attribute so every renderer that supports
+//! `dangerous_inner_html` also supports raw html nodes.
+
+use super::*;
+
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::{quote, quote_spanned};
+use syn::{
+    parenthesized,
+    parse::{Parse, ParseStream},
+    token::{Brace, Paren},
+    Ident, LitStr, Result,
+};
+
+/// The tag of the element raw html is rendered into
+pub const RAW_HTML_TAG: &str = "dioxus-raw-html";
+
+#[derive(PartialEq, Eq, Clone, Debug, Hash)]
+pub struct RawHtml {
+    pub raw_token: Ident,
+    pub paren_token: Paren,
+    /// Either a formatted string literal or an expression that evaluates to the html
+    pub value: ElementAttrValue,
+    /// The wrapper element the html is rendered into
+    pub element: Element,
+}
+
+impl RawHtml {
+    /// Check if the stream starts with `raw!`
+    pub fn peek(input: ParseStream) -> bool {
+        let fork = input.fork();
+        matches!(fork.parse::<Ident>(), Ok(ident) if ident == "raw") && fork.peek(Token![!])
+    }
+
+    /// A reference to `dioxus_elements::RAW_HTML`, which is deprecated when the `raw-html-lint` feature is enabled
+    fn lint(&self) -> TokenStream2 {
+        let span = self.raw_token.span();
+        quote_spanned! { span => { dioxus_elements::RAW_HTML } }
+    }
+}
+
+impl Parse for RawHtml {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let raw_token: Ident = input.parse()?;
+        input.parse::<Token![!]>()?;
+
+        let content;
+        let paren_token = parenthesized!(content in input);
+
+        let value = if content.peek(LitStr) && !content.peek2(Token![.]) {
+            ElementAttrValue::AttrLiteral(content.parse()?)
+        } else {
+            ElementAttrValue::AttrExpr(content.parse()?)
+        };
+
+        if !content.is_empty() {
+            return Err(syn::Error::new(
+                content.span(),
+                "raw! accepts a single string or expression: raw!(\"<b>bold</b>\")",
+            ));
+        }
+
+        let element = wrapper_element(raw_token.span(), value.clone());
+
+        Ok(Self {
+            raw_token,
+            paren_token,
+            value,
+            element,
+        })
+    }
+}
+
+fn wrapper_element(span: Span, value: ElementAttrValue) -> Element {
+    let el_name = ElementName::Custom(LitStr::new(RAW_HTML_TAG, span));
+    let attribute = |name: &str, value: ElementAttrValue| {
+        AttributeType::Named(ElementAttrNamed {
+            el_name: el_name.clone(),
+            attr: ElementAttr {
+                name: ElementAttrName::Custom(LitStr::new(name, span)),
+                value,
+            },
+        })
+    };
+
+    let attributes = vec![
+        attribute(
+            "style",
+            ElementAttrValue::AttrLiteral(IfmtInput::new_static("display: contents")),
+        ),
+        attribute("dangerous_inner_html", value),
+    ];
+
+    Element::new(
+        None,
+        el_name.clone(),
+        attributes,
+        Vec::new(),
+        Brace::default(),
+    )
+}
+
+impl ToTokens for RawHtml {
+    fn to_tokens(&self, tokens: &mut TokenStream2) {
+        let lint = self.lint();
+        let roots = [BodyNode::Element(self.element.clone())];
+        let renderer = TemplateRenderer {
+            roots: &roots,
+            location: None,
+        };
+
+        tokens.append_all(quote! {
+            {
+                #lint
+                let ___nodes = Some({ #renderer }).into_dyn_node();
+                ___nodes
+            }
+        });
+    }
+}
@@ -5,9 +5,16 @@
 //! This crate contains the dioxus implementation of the #[macro@crate::server] macro without additional context from the server.
 //! See the [server_fn_macro] crate for more information.
 
+use convert_case::{Case, Converter};
 use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
 use server_fn_macro::server_macro_impl;
-use syn::__private::ToTokens;
+use std::time::Duration;
+use syn::{
+    __private::ToTokens, punctuated::Punctuated, Expr, ExprLit, FnArg, Ident, ItemFn, Lit, Pat,
+    Token,
+};
 
 /// Declares that a function is a [server function](https://docs.rs/server_fn/).
 /// This means that its body will only run on the server, i.e., when the `ssr`
@@ -68,17 +75,263 @@ use syn::__private::ToTokens;
 ///   // etc.
 /// }
 /// ```
+///
+/// ## Caching
+///
+/// With `cache`, the results of the server function are cached on the server for each set of arguments, for a duration
+/// like `"500ms"`, `"60s"`, `"5m"`, `"1h"` or `"1d"`. Only successful results are cached, and the return value must be
+/// `Clone`. Remove cached results with `ServerFnCache`.
+/// ```rust,ignore
+/// #[server(cache = "60s")]
+/// pub async fn blog_post(id: usize) -> Result<String, ServerFnError> {
+///     todo!()
+/// }
+///
+/// ServerFnCache::global().invalidate(&BlogPost { id: 1 });
+/// ```
+///
+/// ## File Uploads
+///
+/// Server functions with the `MultipartFormData` input encoding from `dioxus_fullstack` take a single `FileUpload` argument.
+/// The files are sent as a multipart body instead of being serialized with the rest of the arguments.
+/// ```rust,ignore
+/// #[server(input = MultipartFormData)]
+/// pub async fn upload(files: FileUpload) -> Result<(), ServerFnError> {
+///     todo!()
+/// }
+/// ```
 #[proc_macro_attribute]
 pub fn server(args: proc_macro::TokenStream, s: TokenStream) -> TokenStream {
+    let (args, cache) = match take_cache_arg(args.into()) {
+        Ok(args) => args,
+        Err(err) => return err.to_compile_error().into(),
+    };
+    let mut body: TokenStream2 = s.into();
+    let multipart = multipart_codec(args.clone(), body.clone());
+    if let Some(ttl) = cache {
+        if multipart.is_some() {
+            return syn::Error::new_spanned(
+                &args,
+                "server functions with the `MultipartFormData` input encoding can't be cached",
+            )
+            .to_compile_error()
+            .into();
+        }
+        body = match cache_body(&args, body, ttl) {
+            Ok(body) => body,
+            Err(err) => return err.to_compile_error().into(),
+        };
+    }
     match server_macro_impl(
-        args.into(),
-        s.into(),
+        args,
+        body,
         Some(syn::parse_quote!(server_fn)),
         "/api",
         None,
         None,
     ) {
         Err(e) => e.to_compile_error().into(),
-        Ok(s) => s.to_token_stream().into(),
+        Ok(s) => {
+            let mut s = s.to_token_stream();
+            s.extend(multipart);
+            s.into()
+        }
+    }
+}
+
+fn parse_args(args: TokenStream2) -> Option<Punctuated<Expr, Token![,]>> {
+    syn::parse::Parser::parse2(Punctuated::<Expr, Token![,]>::parse_terminated, args).ok()
+}
+
+/// Remove the `cache` argument, which `server_fn` doesn't know about, and parse its duration
+fn take_cache_arg(args: TokenStream2) -> syn::Result<(TokenStream2, Option<Duration>)> {
+    let Some(parsed) = parse_args(args.clone()) else {
+        return Ok((args, None));
+    };
+
+    let mut cache = None;
+    let mut rest = Punctuated::<Expr, Token![,]>::new();
+    for arg in parsed {
+        if let Expr::Assign(assign) = &arg {
+            if let Expr::Path(key) = &*assign.left {
+                if key.path.is_ident("cache") {
+                    let Expr::Lit(ExprLit {
+                        lit: Lit::Str(ttl), ..
+                    }) = &*assign.right
+                    else {
+                        return Err(syn::Error::new_spanned(
+                            &assign.right,
+                            "expected a duration like \"60s\"",
+                        ));
+                    };
+                    cache = Some(parse_duration(&ttl.value()).ok_or_else(|| {
+                        syn::Error::new_spanned(
+                            ttl,
+                            "expected a number followed by `ms`, `s`, `m`, `h` or `d`, like \"60s\"",
+                        )
+                    })?);
+                    continue;
+                }
+            }
+        }
+        rest.push(arg);
+    }
+
+    Ok((rest.to_token_stream(), cache))
+}
+
+fn parse_duration(duration: &str) -> Option<Duration> {
+    let duration = duration.trim();
+    let unit_start = duration.find(|c: char| !c.is_ascii_digit())?;
+    let (amount, unit) = duration.split_at(unit_start);
+    let amount: u64 = amount.parse().ok()?;
+    let seconds = match unit.trim() {
+        "ms" => return Some(Duration::from_millis(amount)),
+        "s" => 1,
+        "m" => 60,
+        "h" => 60 * 60,
+        "d" => 24 * 60 * 60,
+        _ => return None,
+    };
+    Some(Duration::from_secs(amount * seconds))
+}
+
+/// Wrap the body of a cached server function so it returns the cached result for its arguments if there is one
+fn cache_body(args: &TokenStream2, body: TokenStream2, ttl: Duration) -> syn::Result<TokenStream2> {
+    let mut body = syn::parse2::<ItemFn>(body)?;
+    let struct_name = struct_name(parse_args(args.clone()).as_ref(), &body);
+
+    let mut names = Vec::new();
+    for input in &body.sig.inputs {
+        match input {
+            FnArg::Typed(arg) => match &*arg.pat {
+                Pat::Ident(ident) => names.push(ident.ident.clone()),
+                pat => {
+                    return Err(syn::Error::new_spanned(
+                        pat,
+                        "the arguments of cached server functions must be identifiers",
+                    ))
+                }
+            },
+            FnArg::Receiver(receiver) => {
+                return Err(syn::Error::new_spanned(
+                    receiver,
+                    "server functions can't take self",
+                ))
+            }
+        }
+    }
+    let keys = names.iter().map(|name| name.to_string());
+    let millis = ttl.as_millis() as u64;
+    let block = &body.block;
+
+    *body.block = syn::parse_quote! {{
+        ServerFnCache::global()
+            .cached::<#struct_name, _, _>(
+                ServerFnCache::key()#(.arg(#keys, &#names))*,
+                std::time::Duration::from_millis(#millis),
+                async move #block,
+            )
+            .await
+    }};
+
+    Ok(body.to_token_stream())
+}
+
+/// The name of the struct the macro generates for the arguments of the server function
+fn struct_name(args: Option<&Punctuated<Expr, Token![,]>>, body: &ItemFn) -> TokenStream2 {
+    for (i, arg) in args.into_iter().flatten().enumerate() {
+        match arg {
+            Expr::Assign(assign) => {
+                let Expr::Path(key) = &*assign.left else {
+                    continue;
+                };
+                if key.path.is_ident("name") {
+                    return assign.right.to_token_stream();
+                }
+            }
+            // The legacy syntax starts with the name of the struct
+            Expr::Path(name) if i == 0 => return name.to_token_stream(),
+            _ => {}
+        }
     }
+
+    let name = Converter::new()
+        .from_case(Case::Snake)
+        .to_case(Case::UpperCamel)
+        .convert(body.sig.ident.to_string());
+    Ident::new(&name, body.sig.ident.span()).to_token_stream()
+}
+
+/// The arguments of a server function are stored in a struct generated by the macro. Multipart encodings
+/// can't be implemented for that struct outside of this crate, so server functions with the `MultipartFormData`
+/// input encoding delegate the encoding of the struct to their only argument.
+fn multipart_codec(args: TokenStream2, body: TokenStream2) -> Option<TokenStream2> {
+    let args = parse_args(args)?;
+    let body = syn::parse2::<ItemFn>(body).ok()?;
+
+    let input = args.iter().find_map(|arg| match arg {
+        Expr::Assign(assign) => match &*assign.left {
+            Expr::Path(key) if key.path.is_ident("input") => Some(assign.right.clone()),
+            _ => None,
+        },
+        _ => None,
+    });
+
+    let Expr::Path(input) = *input? else {
+        return None;
+    };
+    let input_ident = input.path.segments.last()?.ident.to_string();
+    if input_ident != "MultipartFormData" {
+        return None;
+    }
+
+    let struct_name = struct_name(Some(&args), &body);
+
+    let mut inputs = body.sig.inputs.iter();
+    let (Some(FnArg::Typed(arg)), None) = (inputs.next(), inputs.next()) else {
+        return Some(
+            syn::Error::new_spanned(
+                &body.sig.inputs,
+                "server functions with the `MultipartFormData` input encoding take a single argument",
+            )
+            .to_compile_error(),
+        );
+    };
+    let Pat::Ident(field) = &*arg.pat else {
+        return None;
+    };
+    let field = &field.ident;
+    let ty = &arg.ty;
+
+    Some(quote! {
+        impl<__CustErr, __Request> server_fn::codec::IntoReq<#input, __Request, __CustErr> for #struct_name
+        where
+            #ty: server_fn::codec::IntoReq<#input, __Request, __CustErr>,
+        {
+            fn into_req(
+                self,
+                path: &str,
+                accepts: &str,
+            ) -> Result<__Request, server_fn::ServerFnError<__CustErr>> {
+                let #struct_name { #field } = self;
+                <#ty as server_fn::codec::IntoReq<#input, __Request, __CustErr>>::into_req(#field, path, accepts)
+            }
+        }
+
+        impl<__CustErr, __Request> server_fn::codec::FromReq<#input, __Request, __CustErr> for #struct_name
+        where
+            #ty: server_fn::codec::FromReq<#input, __Request, __CustErr>,
+        {
+            fn from_req(
+                req: __Request,
+            ) -> impl std::future::Future<Output = Result<Self, server_fn::ServerFnError<__CustErr>>> + Send {
+                let #field = <#ty as server_fn::codec::FromReq<#input, __Request, __CustErr>>::from_req(req);
+                async move {
+                    let #field = #field.await?;
+                    Ok(#struct_name { #field })
+                }
+            }
+        }
+    })
 }
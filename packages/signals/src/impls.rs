@@ -2,7 +2,7 @@ use crate::copy_value::CopyValue;
 use crate::read::Readable;
 use crate::signal::Signal;
 use crate::write::Writable;
-use crate::{GlobalMemo, GlobalSignal, MappedSignal, ReadOnlySignal, SignalData};
+use crate::{GlobalMemo, GlobalSignal, MappedSignal, ReadOnlySignal, SignalData, Store};
 use generational_box::{AnyStorage, Storage};
 
 use std::{
@@ -159,6 +159,18 @@ impl<T: 'static, S: Storage<SignalData<T>>> Clone for ReadOnlySignal<T, S> {
 
 impl<T: 'static, S: Storage<SignalData<T>>> Copy for ReadOnlySignal<T, S> {}
 
+read_impls!(Store, S: Storage<SignalData<T>>, S: Storage<SignalData<Vec<T>>>);
+default_impl!(Store, S: Storage<SignalData<T>>, S: Storage<SignalData<Vec<T>>>);
+write_impls!(Store, Storage<SignalData<T>>, Storage<SignalData<Vec<T>>>);
+
+impl<T: 'static, S: Storage<SignalData<T>>> Clone for Store<T, S> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: 'static, S: Storage<SignalData<T>>> Copy for Store<T, S> {}
+
 read_impls!(GlobalSignal);
 default_impl!(GlobalSignal);
 
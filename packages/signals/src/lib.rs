@@ -16,6 +16,9 @@ pub use read_only_signal::*;
 mod map;
 pub use map::*;
 
+mod store;
+pub use store::*;
+
 // mod comparer;
 // pub use comparer::*;
 
@@ -51,6 +51,10 @@ pub struct Signal<T: 'static, S: Storage<SignalData<T>> = UnsyncStorage> {
 }
 
 /// A signal that can safely shared between threads.
+///
+/// The value is stored behind a lock instead of a `RefCell`, so the signal can be read and written from background threads and tokio tasks. Writing the signal from another thread schedules the components and effects that read it to rerun on the thread the VirtualDom runs on.
+///
+/// Create one with `use_signal_sync` or [`Signal::new_maybe_sync`].
 pub type SyncSignal<T> = Signal<T, SyncStorage>;
 
 /// The data stored for tracking in a signal.
@@ -0,0 +1,169 @@
+use crate::{read::Readable, write::Writable, ReadOnlySignal, ReadableRef, Signal, SignalData};
+use dioxus_core::{prelude::IntoAttributeValue, ScopeId};
+use generational_box::{Storage, UnsyncStorage};
+use std::ops::Deref;
+
+/// A signal that holds a large piece of state, like the state of a whole app, that components can subscribe to part of.
+///
+/// Reading the store subscribes to the whole value like a [`Signal`]. [`Store::select`] creates a [`Selector`] for one part of the value instead. Components that read a selector only rerun when that part changes, not every time the store is written.
+///
+/// ```rust
+/// use dioxus::prelude::*;
+/// use dioxus_signals::*;
+///
+/// #[derive(Default)]
+/// struct AppState {
+///     user: User,
+///     notifications: Vec<String>,
+/// }
+///
+/// #[derive(Default)]
+/// struct User {
+///     name: String,
+/// }
+///
+/// fn app() -> Element {
+///     let mut store = use_context_provider(|| Store::new(AppState::default()));
+///
+///     rsx! {
+///         button {
+///             onclick: move |_| store.write().notifications.push("Hello".to_string()),
+///             "Notify"
+///         }
+///         UserName {}
+///     }
+/// }
+///
+/// #[component]
+/// fn UserName() -> Element {
+///     let store = use_context::<Store<AppState>>();
+///     // This component doesn't rerun when a notification is added
+///     let name = use_hook(|| store.select(|state| &state.user.name));
+///
+///     rsx! { "{name}" }
+/// }
+/// ```
+pub struct Store<T: 'static, S: Storage<SignalData<T>> = UnsyncStorage> {
+    signal: Signal<T, S>,
+}
+
+/// A read only signal that holds part of a [`Store`]. It is only updated when that part changes.
+pub type Selector<T, S = UnsyncStorage> = ReadOnlySignal<T, S>;
+
+impl<T: 'static> Store<T> {
+    /// Create a new store.
+    #[track_caller]
+    pub fn new(value: T) -> Self {
+        Self::new_maybe_sync(value)
+    }
+
+    /// Create a new store with a custom owner scope. Provide a store owned by [`ScopeId::ROOT`] as context to share it with the whole app.
+    #[track_caller]
+    pub fn new_in_scope(value: T, owner: ScopeId) -> Self {
+        Self::new_maybe_sync_in_scope(value, owner)
+    }
+}
+
+impl<T: 'static, S: Storage<SignalData<T>>> Store<T, S> {
+    /// Create a new store that may be Sync + Send.
+    #[track_caller]
+    pub fn new_maybe_sync(value: T) -> Self {
+        Self {
+            signal: Signal::new_maybe_sync(value),
+        }
+    }
+
+    /// Create a new store that may be Sync + Send with a custom owner scope.
+    #[track_caller]
+    pub fn new_maybe_sync_in_scope(value: T, owner: ScopeId) -> Self {
+        Self {
+            signal: Signal::new_maybe_sync_in_scope(value, owner),
+        }
+    }
+
+    /// Get the signal that holds the whole value of the store.
+    pub fn signal(&self) -> Signal<T, S> {
+        self.signal
+    }
+
+    /// Create a [`Selector`] for part of the store.
+    ///
+    /// The selected value is cloned out of the store every time the store is written, and the selector is only updated if it is different from the last value. Like [`Signal::memo`], the selector runs in a task owned by the current component, so this should only be called once per component, for example inside of `use_hook`.
+    #[track_caller]
+    pub fn select<O>(&self, f: impl Fn(&T) -> &O + 'static) -> Selector<O, S>
+    where
+        O: PartialEq + Clone + 'static,
+        S: Storage<SignalData<O>>,
+    {
+        let signal = self.signal;
+        Signal::use_maybe_sync_memo(move || f(&*signal.read()).clone())
+    }
+}
+
+impl<T, S: Storage<SignalData<T>>> Readable for Store<T, S> {
+    type Target = T;
+    type Storage = S;
+
+    #[track_caller]
+    fn try_read(&self) -> Result<ReadableRef<Self>, generational_box::BorrowError> {
+        self.signal.try_read()
+    }
+
+    #[track_caller]
+    fn peek(&self) -> ReadableRef<Self> {
+        self.signal.peek()
+    }
+}
+
+impl<T: 'static, S: Storage<SignalData<T>>> Writable for Store<T, S> {
+    type Mut<R: ?Sized + 'static> = <Signal<T, S> as Writable>::Mut<R>;
+
+    fn map_mut<I: ?Sized, U: ?Sized + 'static, F: FnOnce(&mut I) -> &mut U>(
+        ref_: Self::Mut<I>,
+        f: F,
+    ) -> Self::Mut<U> {
+        Signal::<T, S>::map_mut(ref_, f)
+    }
+
+    fn try_map_mut<
+        I: ?Sized + 'static,
+        U: ?Sized + 'static,
+        F: FnOnce(&mut I) -> Option<&mut U>,
+    >(
+        ref_: Self::Mut<I>,
+        f: F,
+    ) -> Option<Self::Mut<U>> {
+        Signal::<T, S>::try_map_mut(ref_, f)
+    }
+
+    #[track_caller]
+    fn try_write(&self) -> Result<Self::Mut<T>, generational_box::BorrowMutError> {
+        self.signal.try_write()
+    }
+}
+
+impl<T> IntoAttributeValue for Store<T>
+where
+    T: Clone + IntoAttributeValue,
+{
+    fn into_value(self) -> dioxus_core::AttributeValue {
+        self.signal.into_value()
+    }
+}
+
+impl<T: 'static, S: Storage<SignalData<T>>> PartialEq for Store<T, S> {
+    fn eq(&self, other: &Self) -> bool {
+        self.signal == other.signal
+    }
+}
+
+/// Allow calling a store with store() syntax
+///
+/// Currently only limited to copy types, though could probably specialize for string/arc/rc
+impl<T: Clone, S: Storage<SignalData<T>> + 'static> Deref for Store<T, S> {
+    type Target = dyn Fn() -> T;
+
+    fn deref(&self) -> &Self::Target {
+        Readable::deref_impl(self)
+    }
+}
@@ -0,0 +1,48 @@
+#![allow(unused, non_upper_case_globals, non_snake_case)]
+
+use dioxus::prelude::*;
+use dioxus_core::NoOpMutations;
+use dioxus_signals::*;
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+};
+use std::time::Duration;
+
+fn assert_send_sync<T: Send + Sync>() {}
+
+#[test]
+fn sync_signals_are_send_and_sync() {
+    assert_send_sync::<SyncSignal<String>>();
+    assert_send_sync::<ReadOnlySignal<String, SyncStorage>>();
+}
+
+#[tokio::test]
+async fn writing_from_another_thread_reruns_subscribers() {
+    static RENDERS: AtomicUsize = AtomicUsize::new(0);
+
+    let mut dom = VirtualDom::new(|| {
+        let signal = use_signal_sync(|| 0);
+        RENDERS.fetch_add(1, Ordering::SeqCst);
+
+        use_hook(|| {
+            let mut signal = signal;
+            std::thread::spawn(move || {
+                // wait for the component to finish rendering and subscribe to the signal
+                std::thread::sleep(Duration::from_millis(100));
+                signal += 1;
+            });
+        });
+
+        rsx! { "{signal}" }
+    });
+
+    dom.rebuild_in_place();
+
+    tokio::time::timeout(Duration::from_secs(5), dom.wait_for_work())
+        .await
+        .expect("the write should mark the component dirty");
+    dom.render_immediate(&mut NoOpMutations);
+
+    assert_eq!(RENDERS.load(Ordering::SeqCst), 2);
+}
@@ -0,0 +1,78 @@
+#![allow(unused, non_upper_case_globals, non_snake_case)]
+
+use dioxus::prelude::*;
+use dioxus_core::NoOpMutations;
+use dioxus_signals::*;
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::time::Duration;
+
+#[derive(Default)]
+struct AppState {
+    name: String,
+    count: usize,
+}
+
+#[derive(Default)]
+struct RunCounter {
+    store: Option<Store<AppState>>,
+    child: usize,
+}
+
+#[tokio::test]
+async fn selectors_only_rerun_when_their_slice_changes() {
+    let counter = Rc::new(RefCell::new(RunCounter::default()));
+    let mut dom = VirtualDom::new_with_props(
+        |counter: Rc<RefCell<RunCounter>>| {
+            let store = use_context_provider(|| Store::new(AppState::default()));
+            counter.borrow_mut().store = Some(store);
+
+            rsx! { Child { counter } }
+        },
+        counter.clone(),
+    );
+
+    #[derive(Props, Clone)]
+    struct ChildProps {
+        counter: Rc<RefCell<RunCounter>>,
+    }
+
+    impl PartialEq for ChildProps {
+        fn eq(&self, other: &Self) -> bool {
+            Rc::ptr_eq(&self.counter, &other.counter)
+        }
+    }
+
+    fn Child(ChildProps { counter }: ChildProps) -> Element {
+        let store = use_context::<Store<AppState>>();
+        let name = use_hook(|| store.select(|state| &state.name));
+        counter.borrow_mut().child += 1;
+
+        rsx! { "{name}" }
+    }
+
+    dom.rebuild_in_place();
+    assert_eq!(counter.borrow().child, 1);
+
+    let mut store = counter.borrow().store.unwrap();
+    // Writing a different part of the store doesn't rerun the child
+    dom.in_runtime(|| store.write().count += 1);
+    settle(&mut dom).await;
+    assert_eq!(counter.borrow().child, 1);
+
+    // Writing the selected part does
+    dom.in_runtime(|| store.write().name = "Ferris".to_string());
+    settle(&mut dom).await;
+    assert_eq!(counter.borrow().child, 2);
+    assert_eq!(dom.in_runtime(|| store.peek().name.clone()), "Ferris");
+}
+
+/// Render until there is no more work to do
+async fn settle(dom: &mut VirtualDom) {
+    loop {
+        tokio::select! {
+            _ = dom.wait_for_work() => dom.render_immediate(&mut NoOpMutations),
+            _ = tokio::time::sleep(Duration::from_millis(50)) => break,
+        }
+    }
+}
@@ -1,7 +1,7 @@
 use dioxus_core::prelude::*;
 use std::fmt::Write;
 
-use crate::renderer::{str_truthy, BOOL_ATTRS};
+use crate::renderer::{escape, str_truthy, BOOL_ATTRS};
 
 #[derive(Debug)]
 pub struct StringCache {
@@ -45,13 +45,25 @@ impl std::fmt::Write for StringChain {
 }
 
 impl StringCache {
-    pub fn from_template(template: &VNode, prerender: bool) -> Result<Self, std::fmt::Error> {
+    pub fn from_template(
+        template: &VNode,
+        prerender: bool,
+        strict: bool,
+    ) -> Result<Self, std::fmt::Error> {
         let mut chain = StringChain::default();
 
         let mut cur_path = vec![];
 
         for (root_idx, root) in template.template.get().roots.iter().enumerate() {
-            Self::recurse(root, &mut cur_path, root_idx, true, prerender, &mut chain)?;
+            Self::recurse(
+                root,
+                &mut cur_path,
+                root_idx,
+                true,
+                prerender,
+                strict,
+                &mut chain,
+            )?;
         }
 
         Ok(Self {
@@ -66,6 +78,7 @@ impl StringCache {
         root_idx: usize,
         is_root: bool,
         prerender: bool,
+        strict: bool,
         chain: &mut StringChain,
     ) -> Result<(), std::fmt::Error> {
         match root {
@@ -96,10 +109,13 @@ impl StringCache {
                                 styles.push((name, value));
                             } else if BOOL_ATTRS.contains(name) {
                                 if str_truthy(value) {
-                                    write!(chain, " {name}=\"{value}\"",)?;
+                                    match strict {
+                                        true => write!(chain, " {name}")?,
+                                        false => write!(chain, " {name}=\"{}\"", escape(value))?,
+                                    }
                                 }
                             } else {
-                                write!(chain, " {name}=\"{value}\"")?;
+                                write!(chain, " {name}=\"{}\"", escape(value))?;
                             }
                         }
                         TemplateAttribute::Dynamic { id: index } => {
@@ -114,7 +130,7 @@ impl StringCache {
                 if !styles.is_empty() {
                     write!(chain, " style=\"")?;
                     for (name, value) in styles {
-                        write!(chain, "{name}:{value};")?;
+                        write!(chain, "{name}:{};", escape(value))?;
                     }
                     chain.segments.push(Segment::StyleMarker {
                         inside_style_tag: true,
@@ -138,7 +154,11 @@ impl StringCache {
                 }
 
                 if children.is_empty() && tag_is_self_closing(tag) {
-                    write!(chain, "/>")?;
+                    // The slash is ignored by browsers, and validators warn about it
+                    match strict {
+                        true => write!(chain, ">")?,
+                        false => write!(chain, "/>")?,
+                    }
                 } else {
                     write!(chain, ">")?;
                     // Write the static inner html, or insert a marker if dynamic inner html is possible
@@ -149,18 +169,14 @@ impl StringCache {
                     }
 
                     for child in *children {
-                        Self::recurse(child, cur_path, root_idx, false, prerender, chain)?;
+                        Self::recurse(child, cur_path, root_idx, false, prerender, strict, chain)?;
                     }
                     write!(chain, "</{tag}>")?;
                 }
                 cur_path.pop();
             }
             TemplateNode::Text { text } => {
-                write!(
-                    chain,
-                    "{}",
-                    askama_escape::escape(text, askama_escape::Html)
-                )?;
+                write!(chain, "{}", escape(text))?;
             }
             TemplateNode::Dynamic { id: idx } | TemplateNode::DynamicText { id: idx } => {
                 chain.segments.push(Segment::Node(*idx))
@@ -171,7 +187,8 @@ impl StringCache {
     }
 }
 
-fn tag_is_self_closing(tag: &str) -> bool {
+/// Returns true for void elements, which can't have children or an end tag
+pub(crate) fn tag_is_self_closing(tag: &str) -> bool {
     matches!(
         tag,
         "area"
@@ -0,0 +1,359 @@
+//! Lays out rendered html for [`Renderer::pretty`](crate::Renderer::pretty), [`Renderer::newline`](crate::Renderer::newline) and [`Renderer::minify`](crate::Renderer::minify)
+//!
+//! Templates are cached as strings without knowing where they are inserted, so the html is rendered compactly first and then parsed again to lay it out.
+
+use crate::cache::tag_is_self_closing;
+use std::fmt::{Result, Write};
+
+/// How rendered html is laid out
+pub(crate) enum Layout {
+    /// Every element starts on a new line, indented by this many spaces for each level of nesting
+    Indented(usize),
+    /// Runs of whitespace in text are collapsed into a single space
+    Minified,
+}
+
+/// Write html that was rendered without any layout
+pub(crate) fn write_html(buf: &mut impl Write, html: &str, layout: Layout) -> Result {
+    let nodes = parse(html);
+    match layout {
+        Layout::Indented(indent) => write_indented(buf, &nodes, indent, 0),
+        Layout::Minified => write_minified(buf, &nodes),
+    }
+}
+
+enum Node<'a> {
+    Element {
+        tag: &'a str,
+        start: &'a str,
+        children: Vec<Node<'a>>,
+        /// The end tag, or `None` for void elements and elements that were never closed
+        end: Option<&'a str>,
+    },
+    Text(&'a str),
+    /// Comments, the contents of elements that are written unchanged and anything else that can't be laid out
+    Raw(&'a str),
+}
+
+enum Token<'a> {
+    Start {
+        tag: &'a str,
+        raw: &'a str,
+        self_closing: bool,
+    },
+    End {
+        tag: &'a str,
+        raw: &'a str,
+    },
+    Text(&'a str),
+    Raw(&'a str),
+}
+
+/// Elements with contents that are raw text or where whitespace matters. Their contents are never changed
+fn is_verbatim(tag: &str) -> bool {
+    ["pre", "textarea", "script", "style"]
+        .iter()
+        .any(|verbatim| tag.eq_ignore_ascii_case(verbatim))
+}
+
+struct OpenElement<'a> {
+    tag: &'a str,
+    start: &'a str,
+    children: Vec<Node<'a>>,
+}
+
+fn parse(html: &str) -> Vec<Node<'_>> {
+    let mut roots = Vec::new();
+    let mut open: Vec<OpenElement> = Vec::new();
+    let mut rest = html;
+
+    while !rest.is_empty() {
+        let (token, len) = next_token(rest);
+        rest = &rest[len..];
+        match token {
+            Token::Start {
+                tag,
+                raw,
+                self_closing,
+            } => {
+                if self_closing || tag_is_self_closing(tag) {
+                    let element = Node::Element {
+                        tag,
+                        start: raw,
+                        children: Vec::new(),
+                        end: None,
+                    };
+                    push(&mut open, &mut roots, element);
+                    continue;
+                }
+                let mut children = Vec::new();
+                if is_verbatim(tag) {
+                    let contents = &rest[..find_end_tag(rest, tag)];
+                    if !contents.is_empty() {
+                        children.push(Node::Raw(contents));
+                    }
+                    rest = &rest[contents.len()..];
+                }
+                open.push(OpenElement {
+                    tag,
+                    start: raw,
+                    children,
+                });
+            }
+            Token::End { tag, raw } => {
+                match open
+                    .iter()
+                    .rposition(|element| element.tag.eq_ignore_ascii_case(tag))
+                {
+                    Some(position) => {
+                        // Elements inside of the closed element that were never closed end here
+                        while open.len() > position + 1 {
+                            close(&mut open, &mut roots, None);
+                        }
+                        close(&mut open, &mut roots, Some(raw));
+                    }
+                    None => push(&mut open, &mut roots, Node::Raw(raw)),
+                }
+            }
+            Token::Text(text) => push(&mut open, &mut roots, Node::Text(text)),
+            Token::Raw(raw) => push(&mut open, &mut roots, Node::Raw(raw)),
+        }
+    }
+    while !open.is_empty() {
+        close(&mut open, &mut roots, None);
+    }
+
+    roots
+}
+
+fn push<'a>(open: &mut [OpenElement<'a>], roots: &mut Vec<Node<'a>>, node: Node<'a>) {
+    match open.last_mut() {
+        Some(parent) => parent.children.push(node),
+        None => roots.push(node),
+    }
+}
+
+fn close<'a>(open: &mut Vec<OpenElement<'a>>, roots: &mut Vec<Node<'a>>, end: Option<&'a str>) {
+    if let Some(element) = open.pop() {
+        let element = Node::Element {
+            tag: element.tag,
+            start: element.start,
+            children: element.children,
+            end,
+        };
+        push(open, roots, element);
+    }
+}
+
+/// Find where the end tag of a verbatim element starts
+fn find_end_tag(html: &str, tag: &str) -> usize {
+    let mut offset = 0;
+    while let Some(index) = html[offset..].find("</") {
+        let start = offset + index;
+        let name = &html[start + 2..];
+        if name.len() >= tag.len()
+            && name.is_char_boundary(tag.len())
+            && name[..tag.len()].eq_ignore_ascii_case(tag)
+        {
+            return start;
+        }
+        offset = start + 2;
+    }
+    html.len()
+}
+
+/// Read the next token from the html. Returns the token and the number of bytes it takes up
+fn next_token(html: &str) -> (Token<'_>, usize) {
+    let until = |pattern: &str| {
+        html.find(pattern)
+            .map(|index| index + pattern.len())
+            .unwrap_or(html.len())
+    };
+
+    if html.starts_with("<!--") {
+        let len = until("-->");
+        return (Token::Raw(&html[..len]), len);
+    }
+    if html.starts_with("</") {
+        let len = until(">");
+        let tag = html[2..len].trim_end_matches('>').trim();
+        return (
+            Token::End {
+                tag,
+                raw: &html[..len],
+            },
+            len,
+        );
+    }
+    if html.starts_with("<!") || html.starts_with("<?") {
+        let len = until(">");
+        return (Token::Raw(&html[..len]), len);
+    }
+    if html.starts_with('<') && html[1..].starts_with(|c: char| c.is_ascii_alphabetic()) {
+        // Attribute values may contain a `>` if they are quoted
+        let mut quote = None;
+        let mut len = html.len();
+        for (index, c) in html.char_indices().skip(1) {
+            match (quote, c) {
+                (Some(open), c) if c == open => quote = None,
+                (Some(_), _) => {}
+                (None, '"' | '\'') => quote = Some(c),
+                (None, '>') => {
+                    len = index + 1;
+                    break;
+                }
+                (None, _) => {}
+            }
+        }
+        let raw = &html[..len];
+        let tag_len = raw[1..]
+            .find(|c: char| c.is_whitespace() || c == '/' || c == '>')
+            .unwrap_or(raw.len() - 1);
+        return (
+            Token::Start {
+                tag: &raw[1..1 + tag_len],
+                raw,
+                self_closing: raw.ends_with("/>"),
+            },
+            len,
+        );
+    }
+
+    let len = html
+        .char_indices()
+        .skip(1)
+        .find(|(_, c)| *c == '<')
+        .map(|(index, _)| index)
+        .unwrap_or(html.len());
+    (Token::Text(&html[..len]), len)
+}
+
+/// Add text to a line with every run of whitespace collapsed into a single space
+fn push_collapsed(line: &mut String, text: &str) {
+    for c in text.chars() {
+        if !c.is_whitespace() {
+            line.push(c);
+        } else if !line.is_empty() && !line.ends_with(' ') {
+            line.push(' ');
+        }
+    }
+}
+
+fn write_line(buf: &mut impl Write, line: &str, indent: usize, depth: usize) -> Result {
+    writeln!(buf, "{:width$}{line}", "", width = indent * depth)
+}
+
+fn write_indented(buf: &mut impl Write, nodes: &[Node], indent: usize, depth: usize) -> Result {
+    // Text and comments next to each other are written on the same line
+    let mut line = String::new();
+    for node in nodes {
+        match node {
+            Node::Element {
+                tag,
+                start,
+                children,
+                end,
+            } => {
+                if !line.trim().is_empty() {
+                    write_line(buf, line.trim(), indent, depth)?;
+                }
+                line.clear();
+                write_element(buf, tag, start, children, *end, indent, depth)?;
+            }
+            Node::Text(text) => push_collapsed(&mut line, text),
+            Node::Raw(raw) => line.push_str(raw),
+        }
+    }
+    if !line.trim().is_empty() {
+        write_line(buf, line.trim(), indent, depth)?;
+    }
+    Ok(())
+}
+
+fn write_element(
+    buf: &mut impl Write,
+    tag: &str,
+    start: &str,
+    children: &[Node],
+    end: Option<&str>,
+    indent: usize,
+    depth: usize,
+) -> Result {
+    let end = end.unwrap_or_default();
+    let inline = children
+        .iter()
+        .all(|child| !matches!(child, Node::Element { .. }));
+
+    if is_verbatim(tag) {
+        let mut contents = String::new();
+        for child in children {
+            if let Node::Raw(raw) | Node::Text(raw) = child {
+                contents.push_str(raw);
+            }
+        }
+        return write_line(buf, &format!("{start}{contents}{end}"), indent, depth);
+    }
+    if !inline {
+        write_line(buf, start, indent, depth)?;
+        write_indented(buf, children, indent, depth + 1)?;
+        if !end.is_empty() {
+            write_line(buf, end, indent, depth)?;
+        }
+        return Ok(());
+    }
+
+    // Elements that only contain text are written on one line
+    let mut contents = String::new();
+    for child in children {
+        match child {
+            Node::Text(text) => push_collapsed(&mut contents, text),
+            Node::Raw(raw) => contents.push_str(raw),
+            Node::Element { .. } => {}
+        }
+    }
+    write_line(
+        buf,
+        &format!("{start}{}{end}", contents.trim()),
+        indent,
+        depth,
+    )
+}
+
+fn write_minified(buf: &mut impl Write, nodes: &[Node]) -> Result {
+    for node in nodes {
+        match node {
+            Node::Element {
+                start,
+                children,
+                end,
+                ..
+            } => {
+                buf.write_str(start)?;
+                write_minified(buf, children)?;
+                buf.write_str(end.unwrap_or_default())?;
+            }
+            Node::Text(text) => {
+                let mut collapsed = String::new();
+                let mut whitespace = false;
+                for c in text.chars() {
+                    if c.is_whitespace() {
+                        whitespace = true;
+                        continue;
+                    }
+                    if whitespace {
+                        collapsed.push(' ');
+                        whitespace = false;
+                    }
+                    collapsed.push(c);
+                }
+                // Whitespace at the edges separates the text from the elements next to it
+                if whitespace {
+                    collapsed.push(' ');
+                }
+                buf.write_str(&collapsed)?;
+            }
+            Node::Raw(raw) => buf.write_str(raw)?,
+        }
+    }
+    Ok(())
+}
@@ -1,6 +1,8 @@
 #![allow(non_snake_case)]
 
+use rustc_hash::FxHasher;
 use std::{
+    hash::Hasher,
     ops::{Deref, DerefMut},
     path::PathBuf,
     time::Duration,
@@ -13,6 +15,8 @@ pub struct RenderFreshness {
     age: u64,
     /// The maximum age of the rendered response
     max_age: Option<u64>,
+    /// A hash of the rendered response
+    etag: Option<u64>,
 }
 
 impl RenderFreshness {
@@ -21,12 +25,17 @@ impl RenderFreshness {
         Self {
             age,
             max_age: Some(max_age),
+            etag: None,
         }
     }
 
     /// Create new freshness information with only the age
     pub fn new_age(age: u64) -> Self {
-        Self { age, max_age: None }
+        Self {
+            age,
+            max_age: None,
+            etag: None,
+        }
     }
 
     /// Create new freshness information at the current time
@@ -34,9 +43,18 @@ impl RenderFreshness {
         Self {
             age: 0,
             max_age: max_age.map(|d| d.as_secs()),
+            etag: None,
         }
     }
 
+    /// Set the entity tag of the response to a hash of its body
+    pub fn with_etag(mut self, body: &[u8]) -> Self {
+        let mut hasher = FxHasher::default();
+        hasher.write(body);
+        self.etag = Some(hasher.finish());
+        self
+    }
+
     /// Get the age of the rendered response in seconds
     pub fn age(&self) -> u64 {
         self.age
@@ -47,6 +65,25 @@ impl RenderFreshness {
         self.max_age
     }
 
+    /// Get the entity tag of the rendered response, including the quotes
+    pub fn etag(&self) -> Option<String> {
+        self.etag.map(|etag| format!("\"{etag:016x}\""))
+    }
+
+    /// Check if the `If-None-Match` header of a request matches the entity tag, so the client can use the response it already has
+    pub fn not_modified(&self, request_headers: &http::HeaderMap<http::HeaderValue>) -> bool {
+        let Some(etag) = self.etag() else {
+            return false;
+        };
+        request_headers
+            .get_all(http::header::IF_NONE_MATCH)
+            .iter()
+            .filter_map(|value| value.to_str().ok())
+            .flat_map(|value| value.split(','))
+            .map(|tag| tag.trim())
+            .any(|tag| tag == "*" || tag.trim_start_matches("W/") == etag)
+    }
+
     /// Write the freshness to the response headers.
     pub fn write(&self, headers: &mut http::HeaderMap<http::HeaderValue>) {
         let age = self.age();
@@ -57,6 +94,12 @@ impl RenderFreshness {
                 http::HeaderValue::from_str(&format!("max-age={}", max_age)).unwrap(),
             );
         }
+        if let Some(etag) = self.etag() {
+            headers.insert(
+                http::header::ETAG,
+                http::HeaderValue::from_str(&etag).unwrap(),
+            );
+        }
     }
 }
 
@@ -124,3 +167,33 @@ pub fn timestamp() -> String {
         .as_secs();
     format!("{:x}", timestamp)
 }
+
+#[test]
+fn etag_matches_if_none_match() {
+    let freshness = RenderFreshness::now(None).with_etag(b"<div>hello</div>");
+    let etag = freshness.etag().unwrap();
+    assert_eq!(
+        RenderFreshness::now(None)
+            .with_etag(b"<div>hello</div>")
+            .etag(),
+        Some(etag.clone())
+    );
+    assert_ne!(
+        RenderFreshness::now(None)
+            .with_etag(b"<div>bye</div>")
+            .etag(),
+        Some(etag.clone())
+    );
+
+    let mut response = http::HeaderMap::new();
+    freshness.write(&mut response);
+    assert_eq!(response[http::header::ETAG], etag.as_str());
+
+    let mut request = http::HeaderMap::new();
+    assert!(!freshness.not_modified(&request));
+    request.insert(
+        http::header::IF_NONE_MATCH,
+        http::HeaderValue::from_str(&format!("\"other\", W/{etag}")).unwrap(),
+    );
+    assert!(freshness.not_modified(&request));
+}
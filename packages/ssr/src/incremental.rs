@@ -19,6 +19,7 @@ use tokio::io::{AsyncWrite, AsyncWriteExt};
 
 pub use crate::fs_cache::*;
 pub use crate::incremental_cfg::*;
+pub use crate::manifest::*;
 
 /// An incremental renderer.
 pub struct IncrementalRenderer {
@@ -66,6 +67,39 @@ impl IncrementalRenderer {
         let _ = std::fs::remove_dir_all(&self.static_dir);
     }
 
+    /// Get the HTML file a route was rendered to, if it is cached on the file system.
+    pub fn page_path(&self, route: &str) -> Option<PathBuf> {
+        self.find_file(route).map(|path| path.full_path)
+    }
+
+    /// Add the page that was rendered for a route to a manifest. Returns false if the route is not cached on the file system.
+    pub fn add_to_manifest(&self, route: &str, manifest: &mut StaticSiteManifest) -> bool {
+        let Some(file) = self.page_path(route) else {
+            return false;
+        };
+        let file = file
+            .strip_prefix(&self.static_dir)
+            .map(PathBuf::from)
+            .unwrap_or(file);
+        manifest.add(StaticPage {
+            route: route.to_string(),
+            file,
+        });
+        true
+    }
+
+    /// Write a manifest to `manifest.json` in the static directory. Returns the path of the manifest.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn write_manifest(
+        &self,
+        manifest: &StaticSiteManifest,
+    ) -> Result<PathBuf, IncrementalRendererError> {
+        std::fs::create_dir_all(&self.static_dir)?;
+        let path = self.static_dir.join("manifest.json");
+        std::fs::write(&path, manifest.to_json())?;
+        Ok(path)
+    }
+
     #[cfg(not(target_arch = "wasm32"))]
     fn track_timestamps(&self) -> bool {
         self.invalidate_after.is_some()
@@ -113,8 +147,9 @@ impl IncrementalRenderer {
             let mut file = std::io::BufWriter::new(file);
             file.write_all(&html)?;
         }
+        let freshness = RenderFreshness::now(self.invalidate_after).with_etag(&html);
         self.add_to_memory_cache(route, html);
-        Ok(RenderFreshness::now(self.invalidate_after))
+        Ok(freshness)
     }
 
     fn add_to_memory_cache(&mut self, route: String, html: Vec<u8>) {
@@ -149,24 +184,27 @@ impl IncrementalRenderer {
                     tracing::trace!("memory cache hit {:?}", route);
                     output.write_all(cache_hit).await?;
                     let max_age = invalidate_after.as_secs();
-                    return Ok(Some(RenderFreshness::new(age as u64, max_age)));
+                    return Ok(Some(
+                        RenderFreshness::new(age as u64, max_age).with_etag(cache_hit),
+                    ));
                 }
             } else {
                 tracing::trace!("memory cache hit {:?}", route);
                 output.write_all(cache_hit).await?;
-                return Ok(Some(RenderFreshness::new_age(age as u64)));
+                return Ok(Some(
+                    RenderFreshness::new_age(age as u64).with_etag(cache_hit),
+                ));
             }
         }
         // check the file cache
         #[cfg(not(target_arch = "wasm32"))]
         if let Some(file_path) = self.find_file(&route) {
             if let Some(freshness) = file_path.freshness(self.invalidate_after) {
-                if let Ok(file) = tokio::fs::File::open(file_path.full_path).await {
-                    let mut file = tokio::io::BufReader::new(file);
-                    tokio::io::copy_buf(&mut file, output).await?;
+                if let Ok(html) = tokio::fs::read(file_path.full_path).await {
+                    output.write_all(&html).await?;
                     tracing::trace!("file cache hit {:?}", route);
                     self.promote_memory_cache(&route);
-                    return Ok(Some(freshness));
+                    return Ok(Some(freshness.with_etag(&html)));
                 }
             }
         }
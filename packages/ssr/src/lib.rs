@@ -4,12 +4,15 @@
 
 mod cache;
 pub mod config;
+mod format;
 #[cfg(feature = "incremental")]
 mod fs_cache;
 #[cfg(feature = "incremental")]
 pub mod incremental;
 #[cfg(feature = "incremental")]
 mod incremental_cfg;
+#[cfg(feature = "incremental")]
+mod manifest;
 
 pub mod renderer;
 pub mod template;
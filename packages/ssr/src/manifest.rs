@@ -0,0 +1,75 @@
+use std::path::{Path, PathBuf};
+
+/// A list of the pages generated for a static site.
+///
+/// Deployment tooling can read the manifest to find the file that should be served for each route. [`crate::incremental::IncrementalRenderer::write_manifest`] writes it as JSON:
+///
+/// ```json
+/// {
+///   "pages": [
+///     { "route": "/", "file": "index.html" },
+///     { "route": "/blog", "file": "blog/index.html" }
+///   ]
+/// }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct StaticSiteManifest {
+    pages: Vec<StaticPage>,
+}
+
+/// A page of a static site.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StaticPage {
+    /// The route the page was rendered for.
+    pub route: String,
+    /// The HTML file of the page. This is relative to the static directory unless the path was mapped outside of it.
+    pub file: PathBuf,
+}
+
+impl StaticSiteManifest {
+    /// Create an empty manifest.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a page to the manifest.
+    pub fn add(&mut self, page: StaticPage) {
+        self.pages.push(page);
+    }
+
+    /// Get the pages in the order they were generated.
+    pub fn pages(&self) -> &[StaticPage] {
+        &self.pages
+    }
+
+    /// Get the page generated for a route.
+    pub fn page(&self, route: &str) -> Option<&StaticPage> {
+        self.pages.iter().find(|page| page.route == route)
+    }
+
+    /// Serialize the manifest to JSON. File paths always use `/` as the separator.
+    pub fn to_json(&self) -> String {
+        let pages = self
+            .pages
+            .iter()
+            .map(|page| {
+                serde_json::json!({
+                    "route": page.route,
+                    "file": file_to_string(&page.file),
+                })
+            })
+            .collect::<Vec<_>>();
+        serde_json::to_string_pretty(&serde_json::json!({ "pages": pages }))
+            .expect("a manifest of strings can always be serialized")
+    }
+}
+
+fn file_to_string(file: &Path) -> String {
+    if file.is_absolute() {
+        return file.to_string_lossy().into_owned();
+    }
+    file.components()
+        .map(|component| component.as_os_str().to_string_lossy())
+        .collect::<Vec<_>>()
+        .join("/")
+}
@@ -1,5 +1,6 @@
 use super::cache::Segment;
 use crate::cache::StringCache;
+use crate::format::{self, Layout};
 use dioxus_core::RenderReturn;
 
 use dioxus_core::Attribute;
@@ -9,14 +10,40 @@ use std::fmt::Write;
 use std::sync::Arc;
 
 /// A virtualdom renderer that caches the templates it has seen for faster rendering
-#[derive(Default)]
+///
+/// ```rust
+/// use dioxus::prelude::*;
+///
+/// let mut dom = VirtualDom::new(|| rsx! { ul { li { "one" } li { "two" } } });
+/// dom.rebuild_in_place();
+///
+/// let mut renderer = dioxus_ssr::Renderer::new();
+/// renderer.pretty = true;
+/// assert_eq!(
+///     renderer.render(&dom),
+///     "<ul>\n    <li>one</li>\n    <li>two</li>\n</ul>\n"
+/// );
+/// ```
 pub struct Renderer {
-    /// should we do our best to prettify the output?
+    /// Write every element on its own line, indented by [`Renderer::indent`] spaces for each level of nesting. Elements that only contain text stay on one line.
+    ///
+    /// The whitespace between elements becomes part of the page, so this is meant for reading the html and for snapshot tests. It should not be used with [`Renderer::pre_render`].
     pub pretty: bool,
 
-    /// Control if elements are written onto a new line
+    /// Write every element on its own line without indenting it
     pub newline: bool,
 
+    /// The number of spaces each level of nesting is indented by when [`Renderer::pretty`] is set. Defaults to 4
+    pub indent: usize,
+
+    /// Collapse every run of whitespace in text into a single space. The contents of `pre`, `textarea`, `script` and `style` elements are left unchanged.
+    ///
+    /// This is ignored if [`Renderer::pretty`] or [`Renderer::newline`] is set.
+    pub minify: bool,
+
+    /// Write html that passes validators: boolean attributes like `disabled` are written without a value, every attribute value is quoted and void elements like `br` are written without a closing slash.
+    pub strict: bool,
+
     /// Should we sanitize text nodes? (escape HTML)
     pub sanitize: bool,
 
@@ -32,6 +59,27 @@ pub struct Renderer {
 
     /// The current dynamic node id for hydration
     dynamic_node_id: usize,
+
+    /// The suspense boundaries that showed their fallback in the last render
+    suspended_boundaries: Vec<ScopeId>,
+}
+
+impl Default for Renderer {
+    fn default() -> Self {
+        Self {
+            pretty: false,
+            newline: false,
+            indent: 4,
+            minify: false,
+            strict: false,
+            sanitize: false,
+            pre_render: false,
+            skip_components: false,
+            template_cache: HashMap::new(),
+            dynamic_node_id: 0,
+            suspended_boundaries: Vec::new(),
+        }
+    }
 }
 
 impl Renderer {
@@ -54,6 +102,41 @@ impl Renderer {
         buf: &mut impl Write,
         dom: &VirtualDom,
         scope: ScopeId,
+    ) -> std::fmt::Result {
+        self.suspended_boundaries.clear();
+
+        let layout = if self.pretty {
+            Some(Layout::Indented(self.indent))
+        } else if self.newline {
+            Some(Layout::Indented(0))
+        } else if self.minify {
+            Some(Layout::Minified)
+        } else {
+            None
+        };
+
+        match layout {
+            Some(layout) => {
+                let mut html = String::new();
+                self.render_scope_compact(&mut html, dom, scope)?;
+                format::write_html(buf, &html, layout)
+            }
+            None => self.render_scope_compact(buf, dom, scope),
+        }
+    }
+
+    /// The suspense boundaries that showed their fallback in the last render, in the order they were written
+    ///
+    /// They are only tracked if [`Renderer::pre_render`] is set. Each one is written between a `<!--ds-{id}-->` and a `<!--/ds-{id}-->` comment with the id of its scope, so the html of the boundary can be swapped in once it resolves.
+    pub fn suspended_boundaries(&self) -> &[ScopeId] {
+        &self.suspended_boundaries
+    }
+
+    fn render_scope_compact(
+        &mut self,
+        buf: &mut impl Write,
+        dom: &VirtualDom,
+        scope: ScopeId,
     ) -> std::fmt::Result {
         // We should never ever run into async or errored nodes in SSR
         // Error boundaries and suspense boundaries will convert these to sync
@@ -76,7 +159,8 @@ impl Renderer {
             .entry(template.template.get().name)
             .or_insert_with({
                 let prerender = self.pre_render;
-                move || Arc::new(StringCache::from_template(template, prerender).unwrap())
+                let strict = self.strict;
+                move || Arc::new(StringCache::from_template(template, prerender, strict).unwrap())
             })
             .clone();
 
@@ -96,13 +180,19 @@ impl Renderer {
                         if attr.name == "dangerous_inner_html" {
                             inner_html = Some(attr);
                         } else if attr.namespace == Some("style") {
-                            accumulated_dynamic_styles.push(attr);
+                            // Optional styles that are turned off are left out entirely
+                            if !matches!(attr.value, AttributeValue::None) {
+                                accumulated_dynamic_styles.push(attr);
+                            }
                         } else if BOOL_ATTRS.contains(&attr.name) {
                             if truthy(&attr.value) {
-                                write_attribute(buf, attr)?;
+                                match self.strict {
+                                    true => write!(buf, " {}", attr.name)?,
+                                    false => write_attribute(buf, attr, false)?,
+                                }
                             }
                         } else {
-                            write_attribute(buf, attr)?;
+                            write_attribute(buf, attr, self.strict)?;
                         }
 
                         if self.pre_render {
@@ -121,14 +211,20 @@ impl Renderer {
                             write!(buf, "<{}><{}/>", node.name, node.name)?;
                         } else {
                             let scope = node.mounted_scope(*idx, template, dom).unwrap();
-                            let node = scope.root_node();
-                            match node {
-                                RenderReturn::Ready(node) => {
-                                    self.render_template(buf, dom, node)?
-                                }
-                                _ => todo!(
-                                    "generally, scopes should be sync, only if being traversed"
-                                ),
+                            let suspended = self.pre_render
+                                && scope
+                                    .suspense_boundary()
+                                    .is_some_and(|boundary| boundary.has_suspended());
+                            if suspended {
+                                write!(buf, "<!--ds-{}-->", scope.id().0)?;
+                                self.suspended_boundaries.push(scope.id());
+                            }
+                            // Suspended scopes render the same placeholder the client mounts for them
+                            let (RenderReturn::Ready(node) | RenderReturn::Aborted(node)) =
+                                scope.root_node();
+                            self.render_template(buf, dom, node)?;
+                            if suspended {
+                                write!(buf, "<!--/ds-{}-->", scope.id().0)?;
                             }
                         }
                     }
@@ -139,11 +235,7 @@ impl Renderer {
                             self.dynamic_node_id += 1;
                         }
 
-                        write!(
-                            buf,
-                            "{}",
-                            askama_escape::escape(&text.value, askama_escape::Html)
-                        )?;
+                        write!(buf, "{}", escape(&text.value))?;
 
                         if self.pre_render {
                             write!(buf, "<!--#-->")?;
@@ -393,13 +485,26 @@ pub(crate) fn truthy(value: &AttributeValue) -> bool {
     }
 }
 
-pub(crate) fn write_attribute(buf: &mut impl Write, attr: &Attribute) -> std::fmt::Result {
+/// Escape text so it can be written in html, inside of an element or a quoted attribute value
+pub(crate) fn escape(text: &str) -> askama_escape::Escaped<'_, askama_escape::Html> {
+    askama_escape::escape(text, askama_escape::Html)
+}
+
+/// Write an attribute. Numbers and booleans are only quoted in strict mode
+pub(crate) fn write_attribute(
+    buf: &mut impl Write,
+    attr: &Attribute,
+    strict: bool,
+) -> std::fmt::Result {
     let name = &attr.name;
-    match &attr.value {
-        AttributeValue::Text(value) => write!(buf, " {name}=\"{value}\""),
-        AttributeValue::Bool(value) => write!(buf, " {name}={value}"),
-        AttributeValue::Int(value) => write!(buf, " {name}={value}"),
-        AttributeValue::Float(value) => write!(buf, " {name}={value}"),
+    match (&attr.value, strict) {
+        (AttributeValue::Text(value), _) => write!(buf, " {name}=\"{}\"", escape(value)),
+        (AttributeValue::Bool(value), false) => write!(buf, " {name}={value}"),
+        (AttributeValue::Int(value), false) => write!(buf, " {name}={value}"),
+        (AttributeValue::Float(value), false) => write!(buf, " {name}={value}"),
+        (AttributeValue::Bool(value), true) => write!(buf, " {name}=\"{value}\""),
+        (AttributeValue::Int(value), true) => write!(buf, " {name}=\"{value}\""),
+        (AttributeValue::Float(value), true) => write!(buf, " {name}=\"{value}\""),
         _ => Ok(()),
     }
 }
@@ -409,7 +514,7 @@ pub(crate) fn write_value_unquoted(
     value: &AttributeValue,
 ) -> std::fmt::Result {
     match value {
-        AttributeValue::Text(value) => write!(buf, "{}", value),
+        AttributeValue::Text(value) => write!(buf, "{}", escape(value)),
         AttributeValue::Bool(value) => write!(buf, "{}", value),
         AttributeValue::Int(value) => write!(buf, "{}", value),
         AttributeValue::Float(value) => write!(buf, "{}", value),
@@ -35,3 +35,25 @@ fn dynamic_boolean_attributs() {
         r#"<div></div><div hidden=true></div>"#
     );
 }
+
+#[test]
+fn strict_boolean_attributes() {
+    fn app() -> Element {
+        rsx! {
+            div { hidden: "true" }
+            div { hidden: true }
+            input { disabled: false, name: "agree", tabindex: 1 }
+        }
+    }
+
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild(&mut dioxus_core::NoOpMutations);
+
+    let mut renderer = dioxus_ssr::Renderer::new();
+    renderer.strict = true;
+    // The dynamic attributes of an element share the slot of the first one, so `tabindex` is written where `disabled` was declared
+    assert_eq!(
+        renderer.render(&dom),
+        r#"<div hidden></div><div hidden></div><input tabindex="1" name="agree">"#
+    );
+}
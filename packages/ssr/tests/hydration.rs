@@ -195,3 +195,36 @@ fn hello_world_hydrates() {
         r#"<h1 data-node-hydration="0"><!--node-id1-->High-Five counter: 0<!--#--></h1><button data-node-hydration="2,click:1">Up high!</button><button data-node-hydration="3,click:1">Down low!</button>"#
     );
 }
+
+#[test]
+fn suspended_boundaries_are_marked() {
+    fn app() -> Element {
+        rsx! {
+            div {
+                SuspenseBoundary { fallback: rsx!("Loading..."), suspended_child {} }
+            }
+        }
+    }
+
+    fn suspended_child() -> Element {
+        suspend()?;
+        rsx!("child")
+    }
+
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild(&mut dioxus_core::NoOpMutations);
+    dom.render_immediate(&mut dioxus_core::NoOpMutations);
+
+    let mut renderer = dioxus_ssr::Renderer::new();
+    renderer.pre_render = true;
+    let html = renderer.render(&dom);
+
+    let [boundary] = renderer.suspended_boundaries() else {
+        panic!("the boundary should be suspended");
+    };
+    let start = format!("<!--ds-{}-->", boundary.0);
+    let end = format!("<!--/ds-{}-->", boundary.0);
+    let fallback = &html[html.find(&start).unwrap()..html.find(&end).unwrap()];
+    assert!(fallback.contains("Loading..."));
+    assert!(!html.contains("child"));
+}
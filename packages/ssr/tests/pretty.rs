@@ -0,0 +1,122 @@
+use dioxus::prelude::*;
+
+fn app() -> Element {
+    let name = "world";
+    rsx! {
+        main {
+            h1 { "Hello {name}" }
+            Card { title: "first" }
+            br {}
+            pre { "  keep\n  this" }
+            p { "some " b { "bold" } " text" }
+        }
+    }
+}
+
+#[component]
+fn Card(title: String) -> Element {
+    rsx! {
+        section {
+            h2 { "{title}" }
+            input { value: "{title}" }
+        }
+    }
+}
+
+fn render(configure: impl FnOnce(&mut dioxus_ssr::Renderer)) -> String {
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild(&mut dioxus_core::NoOpMutations);
+
+    let mut renderer = dioxus_ssr::Renderer::new();
+    configure(&mut renderer);
+    renderer.render(&dom)
+}
+
+#[test]
+fn pretty_output_is_indented() {
+    assert_eq!(
+        render(|renderer| renderer.pretty = true),
+        r#"<main>
+    <h1>Hello world</h1>
+    <section>
+        <h2>first</h2>
+        <input value="first"/>
+    </section>
+    <br/>
+    <pre>  keep
+  this</pre>
+    <p>
+        some
+        <b>bold</b>
+        text
+    </p>
+</main>
+"#
+    );
+
+    assert_eq!(
+        render(|renderer| {
+            renderer.pretty = true;
+            renderer.indent = 1;
+        })
+        .lines()
+        .nth(3),
+        Some(r#"  <h2>first</h2>"#)
+    );
+}
+
+#[test]
+fn newline_output_is_not_indented() {
+    assert_eq!(
+        render(|renderer| renderer.newline = true),
+        r#"<main>
+<h1>Hello world</h1>
+<section>
+<h2>first</h2>
+<input value="first"/>
+</section>
+<br/>
+<pre>  keep
+  this</pre>
+<p>
+some
+<b>bold</b>
+text
+</p>
+</main>
+"#
+    );
+}
+
+#[test]
+fn strict_pretty_output_has_no_void_slashes() {
+    let html = render(|renderer| {
+        renderer.pretty = true;
+        renderer.strict = true;
+    });
+    assert!(html.contains("\n        <input value=\"first\">\n"));
+    assert!(html.contains("\n    <br>\n"));
+}
+
+#[test]
+fn minified_output_collapses_whitespace() {
+    fn app() -> Element {
+        rsx! {
+            div {
+                "  lots   of\n  space  "
+                span { "a" }
+                pre { "  keep\n  this" }
+            }
+        }
+    }
+
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild(&mut dioxus_core::NoOpMutations);
+
+    let mut renderer = dioxus_ssr::Renderer::new();
+    renderer.minify = true;
+    assert_eq!(
+        renderer.render(&dom),
+        "<div> lots of space <span>a</span><pre>  keep\n  this</pre></div>"
+    );
+}
@@ -84,3 +84,311 @@ fn fragments() {
         "<div></div>"
     );
 }
+
+#[test]
+fn conditionals() {
+    let value = Some(3);
+    assert_eq!(
+        dioxus_ssr::render_element(rsx! {
+            if value.is_some() {
+                div { "some" }
+            } else {
+                span { "none" }
+            }
+            match value {
+                Some(0) => {}
+                Some(count) if count > 2 => p { "{count}" },
+                _ => {
+                    "fallback"
+                }
+            }
+        }),
+        "<div>some</div><p>3</p>"
+    );
+}
+
+#[test]
+fn slots() {
+    #[component]
+    fn Layout(header: Element, footer: Option<Element>, children: Element) -> Element {
+        rsx! {
+            header { {header} }
+            main { {children} }
+            if let Some(footer) = footer {
+                footer { {footer} }
+            }
+        }
+    }
+
+    assert_eq!(
+        dioxus_ssr::render_element(rsx! {
+            Layout { header: rsx! { h1 { "title" } }, "body" }
+        }),
+        "<header><h1>title</h1></header><main>body</main>"
+    );
+}
+
+#[test]
+fn html_macro() {
+    #[component]
+    fn Card(title: String, children: Element) -> Element {
+        html! {
+            <section>
+                <h2>"{title}"</h2>
+                {children}
+            </section>
+        }
+    }
+
+    let items = ["a", "b"];
+    assert_eq!(
+        dioxus_ssr::render_element(html! {
+            <div class="list" data-count="{items.len()}">
+                <>
+                    <br>
+                    <input type="checkbox" disabled />
+                </>
+                <Card title="items">
+                    {items.iter().map(|item| html! { <span key="{item}">"{item}"</span> })}
+                </Card>
+            </div>
+        }),
+        r#"<div class="list" data-count="2"><br/><input type="checkbox" disabled=true/><section><h2>items</h2><span>a</span><span>b</span></section></div>"#
+    );
+}
+
+#[test]
+fn html_macro_pasted_markup() {
+    let name = "world";
+    let count = 3;
+    let items = ["a", "b"];
+    let attributes = vec![Attribute::new("title", "list", None, false)];
+
+    assert_eq!(
+        dioxus_ssr::render_element(html! {
+            <!-- Pasted from a template -->
+            <p class=intro>Hello {name}, you have {count} new messages &amp; more.</p>
+            <p>Click <b>here</b> (or there).</p>
+            <svg viewBox="0 0 10 10" />
+            <ul {..attributes}>
+                {for item in items {
+                    <li>Item {item}</li>
+                }}
+            </ul>
+            {if count > 5 {
+                <span>many</span>
+            } else {
+                <span>few</span>
+            }}
+        }),
+        r#"<p class="intro">Hello world, you have 3 new messages &amp; more.</p><p>Click <b>here</b> (or there).</p><svg viewBox="0 0 10 10"></svg><ul title="list"><li>Item a</li><li>Item b</li></ul><span>few</span>"#
+    );
+}
+
+#[test]
+fn component_args() {
+    #[component]
+    fn Counter<T: std::fmt::Display + Clone + PartialEq + 'static>(
+        label: T,
+        #[props(default = 1)] step: usize,
+        mut count: usize,
+    ) -> Element {
+        count += step;
+        rsx! { "{label}: {count}" }
+    }
+
+    assert_eq!(
+        dioxus_ssr::render_element(rsx! {
+            Counter { label: "a", count: 1 }
+            Counter { label: 2, step: 5, count: 1 }
+        }),
+        "a: 22: 6"
+    );
+}
+
+#[test]
+fn event_modifiers() {
+    fn app() -> Element {
+        rsx! {
+            form {
+                prevent_default: "onreset",
+                onsubmit |prevent_default|: move |_| {},
+                oninput |stop_propagation|: move |_| {},
+                button { onclick |prevent_default, stop_propagation|: move |_| {} }
+            }
+        }
+    }
+
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild(&mut dioxus_core::NoOpMutations);
+
+    assert_eq!(
+        dioxus_ssr::render(&dom),
+        r#"<form dioxus-prevent-default="onreset onsubmit"><button dioxus-prevent-default="onclick"></button></form>"#
+    );
+}
+
+#[test]
+fn class_list() {
+    let active = true;
+    let disabled = false;
+    let variant = String::from("btn-primary");
+
+    assert_eq!(
+        dioxus_ssr::render_element(rsx! {
+            button {
+                class: ["btn", active.then_some("btn-active"), disabled.then_some("btn-disabled"), &variant],
+                "static"
+            }
+            span { class: ["a", "", "b"] }
+        }),
+        r#"<button class="btn btn-active btn-primary">static</button><span class="a b"></span>"#
+    );
+}
+
+#[test]
+fn style_map() {
+    struct Px(u32);
+
+    impl std::fmt::Display for Px {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "{}px", self.0)
+        }
+    }
+
+    let hidden = true;
+
+    assert_eq!(
+        dioxus_ssr::render_element(rsx! {
+            img { style: { width: Px(100), display: if hidden { "none" } }, width: "50" }
+            div { style: { background_color: "red", opacity: if !hidden { "0.5" } } }
+        }),
+        r#"<img width="50" style="width:100px;display:none;"/><div style="background-color:red;"></div>"#
+    );
+}
+
+#[test]
+fn style_builder() {
+    let wide = true;
+
+    assert_eq!(
+        dioxus_ssr::render_element(rsx! {
+            div {
+                style: Style::new()
+                    .display_flex()
+                    .flex_direction(FlexDirection::Column)
+                    .gap(px(8))
+                    .padding(rem(1.5))
+                    .margin(px(0))
+                    .width(if wide { percent(100) } else { Length::Auto })
+                    .property("transition", "opacity 1s"),
+            }
+            span { style: Style::new() }
+            img { style: { width: px(100), height: vh(50.5) } }
+        }),
+        r#"<div style="display:flex;flex-direction:column;gap:8px;padding:1.5rem;margin:0;width:100%;transition:opacity 1s;"></div><span style=""></span><img style="width:100px;height:50.5vh;"/>"#
+    );
+}
+
+#[test]
+fn custom_elements() {
+    mod dioxus_elements {
+        dioxus::html::custom_elements! {
+            shadercanvas = "shader-canvas" {
+                width: u32,
+                shader_source: &str = "shader-source",
+            }
+            shape in "http://www.w3.org/2000/svg" {
+                sides: u32,
+            }
+        }
+    }
+
+    assert_eq!(
+        dioxus_ssr::render_element(rsx! {
+            shadercanvas { class: "fill", width: 100, shader_source: "main",
+                shape { sides: 3 }
+            }
+        }),
+        r#"<shader-canvas class="fill" width=100 shader-source="main"><shape sides=3></shape></shader-canvas>"#
+    );
+}
+
+#[test]
+fn svg_attributes() {
+    assert_eq!(
+        dioxus_ssr::render_element(rsx! {
+            svg { xmlns_xlink: "http://www.w3.org/1999/xlink",
+                filter { feGaussianBlur { _in: "SourceGraphic", std_deviation: "5" } }
+                mask { mask_type: "alpha" }
+                r#use { xlink_href: "#shape", "xlink:title": "shape", "stroke-width": "2" }
+            }
+        }),
+        r##"<svg xmlns:xlink="http://www.w3.org/1999/xlink"><filter><feGaussianBlur in="SourceGraphic" stdDeviation="5"></feGaussianBlur></filter><mask mask-type="alpha"></mask><use xlink:href="#shape" xlink:title="shape" stroke-width="2"></use></svg>"##
+    );
+}
+
+#[test]
+fn keyed_for_loop() {
+    struct Item {
+        id: usize,
+        name: &'static str,
+    }
+
+    let items = [Item { id: 1, name: "a" }, Item { id: 2, name: "b" }];
+
+    assert_eq!(
+        dioxus_ssr::render_element(rsx! {
+            ul {
+                for item in items.iter(), key: item.id {
+                    li { "{item.name}" }
+                    span { "{item.id}" }
+                }
+            }
+        }),
+        "<ul><li>a</li><span>1</span><li>b</li><span>2</span></ul>"
+    );
+}
+
+#[test]
+fn raw_html() {
+    let markdown = "<h1>Title</h1><p>Body</p>";
+    let name = "<b>bob</b>";
+
+    assert_eq!(
+        dioxus_ssr::render_element(rsx! {
+            article {
+                raw!(markdown)
+                p { "written by {name}" }
+                raw!("<em>{name}</em>")
+            }
+        }),
+        r#"<article><dioxus-raw-html style="display: contents"><h1>Title</h1><p>Body</p></dioxus-raw-html><p>written by &lt;b&gt;bob&lt;/b&gt;</p><dioxus-raw-html style="display: contents"><em><b>bob</b></em></dioxus-raw-html></article>"#
+    );
+}
+
+#[test]
+fn attribute_values_are_escaped() {
+    let title = r#"say "hi" & <leave>"#;
+    let font = r#""Fira Code""#;
+    assert_eq!(
+        dioxus_ssr::render_element(rsx! {
+            div { class: "a&b", title: "{title}", font_family: "{font}" }
+        }),
+        r#"<div class="a&amp;b" title="say &quot;hi&quot; &amp; &lt;leave&gt;" style="font-family:&quot;Fira Code&quot;;"></div>"#
+    );
+}
+
+#[test]
+fn spread_attributes_are_merged_in_written_order() {
+    let attrs = vec![
+        Attribute::new("title", "spread", None, false),
+        Attribute::new("class", "b", None, false),
+    ];
+    assert_eq!(
+        dioxus_ssr::render_element(rsx! {
+            div { class: "a", id: "first", ..attrs, title: "last" }
+        }),
+        r#"<div class="a b" id="first" title="last"></div>"#
+    );
+}
@@ -0,0 +1,93 @@
+use std::{collections::BTreeMap, ops::RangeInclusive};
+
+/// The measured heights of the rows in a list
+#[derive(Debug, Clone, Default, PartialEq)]
+pub(crate) struct RowHeights {
+    measured: BTreeMap<usize, f64>,
+}
+
+impl RowHeights {
+    /// Returns true if the row has not been measured with this height yet. Rows without a height are not laid out, so they keep the estimated height.
+    pub(crate) fn changed(&self, index: usize, height: f64) -> bool {
+        height > 0.0 && self.measured.get(&index) != Some(&height)
+    }
+
+    /// Record the measured height of a row
+    pub(crate) fn measure(&mut self, index: usize, height: f64) {
+        self.measured.insert(index, height);
+    }
+
+    /// The layout of a list of the given length. Rows that have not been measured yet use the estimated height.
+    pub(crate) fn layout(&self, estimate: f64, len: usize) -> RowLayout<'_> {
+        debug_assert!(estimate > 0.0, "The row height must be positive");
+        RowLayout {
+            measured: self,
+            estimate,
+            len,
+        }
+    }
+}
+
+/// The position of the rows in a list
+pub(crate) struct RowLayout<'a> {
+    measured: &'a RowHeights,
+    estimate: f64,
+    len: usize,
+}
+
+impl RowLayout<'_> {
+    /// The distance from the top of the list to the top of a row
+    pub(crate) fn offset(&self, index: usize) -> f64 {
+        let index = index.min(self.len);
+        let measured: f64 = self
+            .measured
+            .measured
+            .range(..index)
+            .map(|(_, height)| height - self.estimate)
+            .sum();
+        index as f64 * self.estimate + measured
+    }
+
+    /// The height of all rows
+    pub(crate) fn total(&self) -> f64 {
+        self.offset(self.len)
+    }
+
+    /// The index of the row at a distance from the top of the list
+    pub(crate) fn index_at(&self, offset: f64) -> usize {
+        let offset = offset.max(0.0);
+
+        // Walk over the measured rows. The rows between them all have the estimated height
+        let mut start_index = 0;
+        let mut start_offset = 0.0;
+        for (&index, &height) in self.measured.measured.range(..self.len) {
+            let end_offset = start_offset + (index - start_index) as f64 * self.estimate;
+            if offset < end_offset {
+                break;
+            }
+            if offset < end_offset + height {
+                return index;
+            }
+            start_index = index + 1;
+            start_offset = end_offset + height;
+        }
+
+        let estimated = ((offset - start_offset) / self.estimate).floor() as usize;
+        (start_index + estimated).min(self.len.saturating_sub(1))
+    }
+
+    /// The rows that are visible in a viewport, extended by the overscan on both sides
+    pub(crate) fn visible(
+        &self,
+        scroll_offset: f64,
+        viewport_height: f64,
+        overscan: usize,
+    ) -> Option<RangeInclusive<usize>> {
+        if self.len == 0 {
+            return None;
+        }
+        let first = self.index_at(scroll_offset);
+        let last = self.index_at(scroll_offset + viewport_height);
+        Some(first.saturating_sub(overscan)..=(last + overscan).min(self.len - 1))
+    }
+}
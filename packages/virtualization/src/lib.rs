@@ -0,0 +1,14 @@
+#![doc = include_str!("../README.md")]
+#![doc(html_logo_url = "https://avatars.githubusercontent.com/u/79236386")]
+#![doc(html_favicon_url = "https://avatars.githubusercontent.com/u/79236386")]
+#![warn(missing_docs)]
+
+mod layout;
+mod list;
+
+pub use list::*;
+
+/// A collection of the most common exports from the crate
+pub mod prelude {
+    pub use crate::list::{use_virtual_list, VirtualList, VirtualListHandle};
+}
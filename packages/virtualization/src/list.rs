@@ -0,0 +1,239 @@
+use std::rc::Rc;
+
+use dioxus_lib::{html::geometry::PixelsVector2D, prelude::*};
+
+use crate::layout::RowHeights;
+
+/// Render the row at an index of a [`VirtualList`]
+#[derive(Clone)]
+pub struct RowRenderer(Rc<dyn Fn(usize) -> Element>);
+
+impl<F: Fn(usize) -> Element + 'static> From<F> for RowRenderer {
+    fn from(value: F) -> Self {
+        Self(Rc::new(value))
+    }
+}
+
+impl PartialEq for RowRenderer {
+    fn eq(&self, _: &Self) -> bool {
+        // The closure is created again every time the parent renders, so it is never equal to the last one
+        false
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+struct ScrollRequest {
+    index: usize,
+    behavior: ScrollBehavior,
+}
+
+/// A handle to scroll a [`VirtualList`] from outside of the list. Create one with [`use_virtual_list`].
+#[derive(Clone, Copy, PartialEq)]
+pub struct VirtualListHandle {
+    request: Signal<Option<ScrollRequest>>,
+}
+
+impl VirtualListHandle {
+    /// Scroll the list so the row at the index is at the top of the list. The row does not need to be rendered.
+    pub fn scroll_to_index(&self, index: usize, behavior: ScrollBehavior) {
+        let mut request = self.request;
+        request.set(Some(ScrollRequest { index, behavior }));
+    }
+}
+
+/// Create a handle to scroll a [`VirtualList`]
+pub fn use_virtual_list() -> VirtualListHandle {
+    VirtualListHandle {
+        request: use_signal(|| None),
+    }
+}
+
+/// The visible area of the list
+#[derive(Clone, Copy, Default, PartialEq)]
+struct Viewport {
+    scroll_offset: f64,
+    height: f64,
+}
+
+/// Read the visible area of the scroll container
+async fn read_viewport(container: Rc<MountedData>, mut viewport: Signal<Viewport>) {
+    let (Ok(offset), Ok(rect)) = (
+        container.get_scroll_offset().await,
+        container.get_client_rect().await,
+    ) else {
+        return;
+    };
+    let new = Viewport {
+        scroll_offset: offset.y,
+        height: rect.height(),
+    };
+    if *viewport.peek() != new {
+        viewport.set(new);
+    }
+}
+
+/// The props of a [`VirtualList`]
+#[derive(Props, Clone, PartialEq)]
+pub struct VirtualListProps {
+    /// The number of rows in the list
+    len: usize,
+
+    /// The estimated height of a row. Rows use this height until they are rendered and measured.
+    ///
+    /// The height is in the units of the renderer: pixels on the web and desktop, and cells in the TUI.
+    row_height: f64,
+
+    /// Render the row at an index
+    #[props(into)]
+    render_row: RowRenderer,
+
+    /// The number of rows rendered above and below the visible rows
+    #[props(default = 3)]
+    overscan: usize,
+
+    /// The indexes of header rows. The last header above the first visible row sticks to the top of the list.
+    #[props(default)]
+    sticky: Vec<usize>,
+
+    /// A handle to scroll the list. The list must always be passed the same handle.
+    #[props(default)]
+    handle: Option<VirtualListHandle>,
+
+    /// The height of the list
+    #[props(default = "100%".to_string(), into)]
+    height: String,
+}
+
+/// A list that only renders the rows that are visible.
+///
+/// ## Details
+///
+/// The list scrolls in a container with the given height. Only the rows in the visible part of the container and a few rows around them are rendered, and the rest of the list is filled with empty space. Rows are measured when they are mounted, so rows can have different heights. Rows that have not been rendered yet use the estimated `row_height`.
+///
+/// The list reads the size and scroll position of the container with [`MountedData`], so it needs a renderer that supports [`MountedData::get_scroll_offset`]. Renderers that cannot measure elements, like SSR, only render the first rows.
+///
+/// ## Example
+///
+/// ```rust, ignore
+/// fn app() -> Element {
+///     let handle = use_virtual_list();
+///     rsx! {
+///         button { onclick: move |_| handle.scroll_to_index(5000, ScrollBehavior::Smooth), "Jump" }
+///         VirtualList {
+///             len: 10_000,
+///             row_height: 20.0,
+///             height: "400px",
+///             sticky: vec![0, 5000],
+///             handle,
+///             render_row: move |index| rsx! { p { "Row {index}" } }
+///         }
+///     }
+/// }
+/// ```
+#[allow(non_snake_case)]
+pub fn VirtualList(props: VirtualListProps) -> Element {
+    let mut container = use_signal(|| None as Option<Rc<MountedData>>);
+    let viewport = use_signal(Viewport::default);
+    let mut heights = use_signal(RowHeights::default);
+
+    // The effect reads the size of the list when it runs instead of when it was created
+    let mut size = use_hook(|| CopyValue::new((props.row_height, props.len)));
+    size.set((props.row_height, props.len));
+
+    let handle = props.handle;
+    use_effect(move || {
+        let Some(handle) = handle else {
+            return;
+        };
+        let Some(request) = (handle.request)() else {
+            return;
+        };
+        let Some(container) = container() else {
+            return;
+        };
+        let mut pending = handle.request;
+        pending.set(None);
+
+        let (row_height, len) = size();
+        let offset = heights.peek().layout(row_height, len).offset(request.index);
+        spawn(async move {
+            let scroll = container.scroll(PixelsVector2D::new(0.0, offset), request.behavior);
+            if scroll.await.is_ok() {
+                read_viewport(container, viewport).await;
+            }
+        });
+    });
+
+    let Viewport {
+        scroll_offset,
+        height,
+    } = viewport();
+    let heights_ref = heights.read();
+    let layout = heights_ref.layout(props.row_height, props.len);
+    let rows = layout.visible(scroll_offset, height, props.overscan);
+    let (top, bottom) = match &rows {
+        Some(rows) => (
+            layout.offset(*rows.start()),
+            layout.total() - layout.offset(rows.end() + 1),
+        ),
+        None => (0.0, 0.0),
+    };
+    let first_visible = layout.index_at(scroll_offset);
+    let header = props
+        .sticky
+        .iter()
+        .copied()
+        .filter(|index| *index <= first_visible && *index < props.len)
+        .max();
+    drop(heights_ref);
+
+    let render_row = props.render_row.0;
+    rsx! {
+        div {
+            position: "relative",
+            height: "{props.height}",
+            div {
+                height: "100%",
+                overflow_y: "auto",
+                display: "flex",
+                flex_direction: "column",
+                onmounted: move |event| {
+                    let data = event.data();
+                    container.set(Some(data.clone()));
+                    read_viewport(data, viewport)
+                },
+                onscroll: move |_| async move {
+                    if let Some(container) = container() {
+                        read_viewport(container, viewport).await;
+                    }
+                },
+                div { height: "{top}px", flex_shrink: "0" }
+                for index in rows.into_iter().flatten() {
+                    div {
+                        key: "{index}",
+                        flex_shrink: "0",
+                        onmounted: move |event| async move {
+                            if let Ok(rect) = event.get_client_rect().await {
+                                let height = rect.height();
+                                if heights.peek().changed(index, height) {
+                                    heights.write().measure(index, height);
+                                }
+                            }
+                        },
+                        {render_row(index)}
+                    }
+                }
+                div { height: "{bottom}px", flex_shrink: "0" }
+            }
+            if let Some(header) = header {
+                div {
+                    position: "absolute",
+                    top: "0",
+                    left: "0",
+                    right: "0",
+                    {render_row(header)}
+                }
+            }
+        }
+    }
+}
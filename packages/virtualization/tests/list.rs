@@ -0,0 +1,70 @@
+//! Virtual lists render the first rows until the renderer measures the list
+
+use dioxus::prelude::*;
+use dioxus_virtualization::prelude::*;
+
+#[test]
+fn renders_the_first_rows() {
+    fn app() -> Element {
+        rsx! {
+            VirtualList {
+                len: 10_000,
+                row_height: 20.0,
+                overscan: 2,
+                render_row: move |index| rsx! { p { "row {index}" } }
+            }
+        }
+    }
+
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild(&mut dioxus_core::NoOpMutations);
+    let html = dioxus_ssr::render(&dom);
+
+    // the viewport is not measured, so only the first row and the overscan below it are rendered
+    for index in 0..3 {
+        assert!(html.contains(&format!("<p>row {index}</p>")));
+    }
+    assert!(!html.contains("<p>row 3</p>"));
+    // the rest of the list is filled with empty space
+    assert!(html.contains(&format!("height:{}px", 9_997 * 20)));
+}
+
+#[test]
+fn sticky_headers_are_rendered_on_top() {
+    fn app() -> Element {
+        rsx! {
+            VirtualList {
+                len: 100,
+                row_height: 1.0,
+                sticky: vec![0, 50],
+                render_row: move |index| rsx! { p { "row {index}" } }
+            }
+        }
+    }
+
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild(&mut dioxus_core::NoOpMutations);
+    let html = dioxus_ssr::render(&dom);
+
+    let header = html.find("position:absolute").unwrap();
+    assert!(html[header..].contains("<p>row 0</p>"));
+    assert!(!html.contains("row 50"));
+}
+
+#[test]
+fn empty_lists_render_no_rows() {
+    fn app() -> Element {
+        rsx! {
+            VirtualList {
+                len: 0,
+                row_height: 1.0,
+                sticky: vec![0],
+                render_row: move |index| rsx! { p { "row {index}" } }
+            }
+        }
+    }
+
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild(&mut dioxus_core::NoOpMutations);
+    assert!(!dioxus_ssr::render(&dom).contains("<p>"));
+}
@@ -11,6 +11,10 @@ pub struct Config {
     pub(crate) hydrate: bool,
     pub(crate) root: ConfigRoot,
     pub(crate) default_panic_hook: bool,
+    #[cfg(feature = "eval")]
+    pub(crate) eval_nonce: Option<String>,
+    #[cfg(feature = "hydrate")]
+    pub(crate) on_hydration_mismatch: Option<crate::rehydrate::OnHydrationMismatch>,
 }
 
 pub(crate) enum ConfigRoot {
@@ -38,6 +42,18 @@ impl Config {
         self
     }
 
+    #[cfg(feature = "hydrate")]
+    /// Call `f` for every node of the HTML from the server that does not match the app
+    ///
+    /// Mismatches are always logged as warnings. The smallest component around each mismatch is rebuilt on the client.
+    pub fn on_hydration_mismatch(
+        mut self,
+        f: impl Fn(&crate::HydrationMismatch) + 'static,
+    ) -> Self {
+        self.on_hydration_mismatch = Some(Box::new(f));
+        self
+    }
+
     /// Set the name of the element that Dioxus will use as the root.
     ///
     /// This is akin to calling React.render() on the element with the specified name.
@@ -56,6 +72,20 @@ impl Config {
         self
     }
 
+    #[cfg(feature = "eval")]
+    /// Run the code of `eval` in a script tag with this Content Security Policy nonce instead of with `new Function`, so
+    /// a policy without `unsafe-eval` allows it. [`crate::page_csp_nonce`] reads the nonce of the page.
+    ///
+    /// **This turns off the protection the policy gives against injected code for `eval`.** Any string passed to
+    /// `eval` runs as a trusted script, so if user input can reach the code of `eval`, it can run anything the nonce
+    /// allows. Only opt in if every call to `eval` runs code written by you.
+    ///
+    /// Without a nonce, `eval` uses `new Function`, which only runs if the policy allows `unsafe-eval`.
+    pub fn eval_with_csp_nonce(mut self, nonce: impl Into<String>) -> Self {
+        self.eval_nonce = Some(nonce.into());
+        self
+    }
+
     /// Set whether or not Dioxus should use the built-in panic hook or defer to your own.
     ///
     /// The panic hook is set to true normally so even the simplest apps have helpful error messages.
@@ -71,6 +101,10 @@ impl Default for Config {
             hydrate: false,
             root: ConfigRoot::RootName("main".to_string()),
             default_panic_hook: true,
+            #[cfg(feature = "eval")]
+            eval_nonce: None,
+            #[cfg(feature = "hydrate")]
+            on_hydration_mismatch: None,
         }
     }
 }
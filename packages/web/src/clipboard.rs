@@ -0,0 +1,138 @@
+//! The clipboard of the browser, used by `use_clipboard`
+
+use std::rc::Rc;
+
+use dioxus_hooks::{ClipboardError, ClipboardFuture, ClipboardImage, ClipboardProvider};
+use wasm_bindgen::{prelude::*, JsCast};
+
+/// Provide the clipboard of the browser in the root scope
+pub fn init_clipboard() {
+    let provider: Rc<dyn ClipboardProvider> = Rc::new(WebClipboard);
+    dioxus_core::ScopeId::ROOT.provide_context(provider);
+}
+
+/// The async Clipboard API of the browser
+struct WebClipboard;
+
+impl ClipboardProvider for WebClipboard {
+    fn read_text(&self) -> ClipboardFuture<String> {
+        Box::pin(async {
+            supported()?;
+            let text = read_text().await.map_err(failed)?;
+            Ok(text.as_string().unwrap_or_default())
+        })
+    }
+
+    fn write_text(&self, text: String) -> ClipboardFuture<()> {
+        Box::pin(async move {
+            supported()?;
+            write_text(&text).await.map_err(failed)?;
+            Ok(())
+        })
+    }
+
+    fn read_image(&self) -> ClipboardFuture<ClipboardImage> {
+        Box::pin(async {
+            supported()?;
+            let image = read_image().await.map_err(failed)?;
+            if image.is_null() {
+                return Err(ClipboardError::Empty);
+            }
+            let get = |key: &str| js_sys::Reflect::get(&image, &key.into()).map_err(failed);
+            Ok(ClipboardImage {
+                width: get("width")?.as_f64().unwrap_or_default() as usize,
+                height: get("height")?.as_f64().unwrap_or_default() as usize,
+                rgba: js_sys::Uint8Array::new(&get("rgba")?).to_vec(),
+            })
+        })
+    }
+
+    fn write_image(&self, image: ClipboardImage) -> ClipboardFuture<()> {
+        Box::pin(async move {
+            supported()?;
+            if image.rgba.len() != image.width * image.height * 4 {
+                return Err(ClipboardError::Failed(
+                    "the image data doesn't match its size".to_string(),
+                ));
+            }
+            write_image(image.width as u32, image.height as u32, &image.rgba)
+                .await
+                .map_err(failed)?;
+            Ok(())
+        })
+    }
+}
+
+/// Browsers only expose the clipboard in a secure context
+fn supported() -> Result<(), ClipboardError> {
+    match has_clipboard() {
+        true => Ok(()),
+        false => Err(ClipboardError::Unsupported),
+    }
+}
+
+fn failed(error: JsValue) -> ClipboardError {
+    let message = match error.dyn_into::<js_sys::Error>() {
+        Ok(error) => error.message().into(),
+        Err(error) => format!("{error:?}"),
+    };
+    ClipboardError::Failed(message)
+}
+
+// The clipboard is still an unstable api in web-sys, and images have to be converted with a canvas
+#[wasm_bindgen(inline_js = r#"
+export function has_clipboard() {
+    return navigator.clipboard != null;
+}
+
+export function read_text() {
+    return navigator.clipboard.readText();
+}
+
+export function write_text(text) {
+    return navigator.clipboard.writeText(text);
+}
+
+export async function read_image() {
+    for (const item of await navigator.clipboard.read()) {
+        const type = item.types.find((type) => type.startsWith("image/"));
+        if (type == null) {
+            continue;
+        }
+        const bitmap = await createImageBitmap(await item.getType(type));
+        const canvas = document.createElement("canvas");
+        canvas.width = bitmap.width;
+        canvas.height = bitmap.height;
+        const context = canvas.getContext("2d");
+        context.drawImage(bitmap, 0, 0);
+        const data = context.getImageData(0, 0, bitmap.width, bitmap.height);
+        return { width: data.width, height: data.height, rgba: new Uint8Array(data.data.buffer) };
+    }
+    return null;
+}
+
+export async function write_image(width, height, rgba) {
+    const canvas = document.createElement("canvas");
+    canvas.width = width;
+    canvas.height = height;
+    canvas.getContext("2d").putImageData(new ImageData(new Uint8ClampedArray(rgba), width, height), 0, 0);
+    // browsers only accept png images on the clipboard
+    const blob = await new Promise((resolve) => canvas.toBlob(resolve, "image/png"));
+    await navigator.clipboard.write([new ClipboardItem({ "image/png": blob })]);
+}
+"#)]
+extern "C" {
+    fn has_clipboard() -> bool;
+
+    #[wasm_bindgen(catch)]
+    async fn read_text() -> Result<JsValue, JsValue>;
+
+    #[wasm_bindgen(catch)]
+    async fn write_text(text: &str) -> Result<JsValue, JsValue>;
+
+    #[wasm_bindgen(catch)]
+    async fn read_image() -> Result<JsValue, JsValue>;
+
+    #[wasm_bindgen(catch)]
+    async fn write_image(width: u32, height: u32, rgba: &[u8]) -> Result<JsValue, JsValue>;
+}
@@ -0,0 +1,52 @@
+//! Exposes the scope tree to the devtools overlay and websocket clients and highlights the elements of the selected component
+
+use dioxus_core::{ElementId, VirtualDom};
+use dioxus_devtools::{devtools_url, Devtools, HIGHLIGHT_ATTRIBUTE};
+use dioxus_interpreter_js::get_node;
+use wasm_bindgen::JsCast;
+
+use crate::dom::WebsysDom;
+
+/// The devtools of the app and the elements that are highlighted on the page
+pub(crate) struct DevtoolsHighlight {
+    devtools: Devtools,
+    elements: Vec<ElementId>,
+}
+
+impl DevtoolsHighlight {
+    /// Install the devtools in the root scope and connect them to the devtools websocket server if one is running
+    pub(crate) fn new(dom: &VirtualDom) -> Self {
+        let devtools = Devtools::install(dom);
+        dioxus_devtools::web::connect(&devtools, &devtools_url());
+        Self {
+            devtools,
+            elements: Vec::new(),
+        }
+    }
+
+    /// Update the devtools after the edits of a render are flushed
+    pub(crate) fn update(&mut self, dom: &mut VirtualDom, websys_dom: &WebsysDom) {
+        let Some(elements) = self.devtools.update(dom) else {
+            return;
+        };
+        for id in &self.elements {
+            set_highlight(websys_dom, *id, false);
+        }
+        for id in &elements {
+            set_highlight(websys_dom, *id, true);
+        }
+        self.elements = elements;
+    }
+}
+
+fn set_highlight(websys_dom: &WebsysDom, id: ElementId, highlighted: bool) {
+    // Text nodes and elements that were removed since the last update can't be highlighted
+    let node = get_node(websys_dom.interpreter.js_channel(), id.0 as u32);
+    let Ok(element) = node.dyn_into::<web_sys::Element>() else {
+        return;
+    };
+    let _ = match highlighted {
+        true => element.set_attribute(HIGHLIGHT_ATTRIBUTE, ""),
+        false => element.remove_attribute(HIGHLIGHT_ATTRIBUTE),
+    };
+}
@@ -6,7 +6,9 @@
 //! - tests to ensure dyn_into works for various event types.
 //! - Partial delegation?
 
-use dioxus_core::ElementId;
+use std::time::Duration;
+
+use dioxus_core::{ElementId, ScopeId};
 use dioxus_html::PlatformEventData;
 use dioxus_interpreter_js::Channel;
 use futures_channel::mpsc;
@@ -22,11 +24,16 @@ pub struct WebsysDom {
     pub(crate) root: Element,
     pub(crate) templates: FxHashMap<String, u16>,
     pub(crate) max_template_id: u16,
+    /// Encodes mutations into a buffer that is applied by the JS interpreter in [`WebsysDom::flush_edits`]
     pub(crate) interpreter: Channel,
     #[cfg(feature = "mounted")]
     pub(crate) event_channel: mpsc::UnboundedSender<UiEvent>,
     #[cfg(feature = "mounted")]
     pub(crate) queued_mounted_events: Vec<ElementId>,
+    /// The exit animations of removed presences that are waited for after the edits are flushed
+    pub(crate) queued_exits: Vec<(ScopeId, ElementId, Duration)>,
+    #[cfg(feature = "hydrate")]
+    pub(crate) on_hydration_mismatch: Option<crate::rehydrate::OnHydrationMismatch>,
 }
 
 pub struct UiEvent {
@@ -126,6 +133,9 @@ impl WebsysDom {
             event_channel,
             #[cfg(feature = "mounted")]
             queued_mounted_events: Default::default(),
+            queued_exits: Default::default(),
+            #[cfg(feature = "hydrate")]
+            on_hydration_mismatch: cfg.on_hydration_mismatch,
         }
     }
 
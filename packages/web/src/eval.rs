@@ -4,41 +4,88 @@ use futures_util::StreamExt;
 use generational_box::{AnyStorage, GenerationalBox, UnsyncStorage};
 use js_sys::Function;
 use serde_json::Value;
-use std::{rc::Rc, str::FromStr};
+use std::{future::Future, pin::Pin, rc::Rc, str::FromStr, task::Poll};
 use wasm_bindgen::prelude::*;
+use wasm_bindgen_futures::JsFuture;
 
 /// Provides the WebEvalProvider through [`cx.provide_context`].
-pub fn init_eval() {
-    let provider: Rc<dyn EvalProvider> = Rc::new(WebEvalProvider);
+pub fn init_eval(nonce: Option<String>) {
+    let provider: Rc<dyn EvalProvider> = Rc::new(WebEvalProvider { nonce });
     dioxus_core::ScopeId::ROOT.provide_context(provider);
 }
 
 /// Represents the web-target's provider of evaluators.
-pub struct WebEvalProvider;
+pub struct WebEvalProvider {
+    /// The Content Security Policy nonce the app opted in to run evaluated code with. With a nonce, evaluated code runs
+    /// in a script tag instead of `new Function`, so policies without `unsafe-eval` allow it.
+    nonce: Option<String>,
+}
+
 impl EvalProvider for WebEvalProvider {
     fn new_evaluator(&self, js: String) -> GenerationalBox<Box<dyn Evaluator>> {
-        WebEvaluator::create(js)
+        WebEvaluator::create(js, self.nonce.as_deref())
     }
 }
 
-/// Required to avoid blocking the Rust WASM thread.
+/// The Content Security Policy nonce of the first script of the page with one, for [`crate::Config::eval_with_csp_nonce`]
+pub fn page_csp_nonce() -> Option<String> {
+    let script = web_sys::window()?
+        .document()?
+        .query_selector("script[nonce]")
+        .ok()??;
+    // Browsers hide the nonce attribute once the page is loaded, but keep the property
+    js_sys::Reflect::get(&script, &"nonce".into())
+        .ok()?
+        .as_string()
+        .filter(|nonce| !nonce.is_empty())
+}
+
+/// Compile the code into a function taking the `dioxus` object
+///
+/// With a nonce, the code runs as a trusted script, so the policy no longer blocks it. This is only done if the app
+/// opted in with [`crate::Config::eval_with_csp_nonce`]
+fn compile(code: &str, nonce: Option<&str>) -> Result<Function, JsValue> {
+    let Some(nonce) = nonce else {
+        return Ok(Function::new_with_args("dioxus", code));
+    };
+
+    // Run a script tag with the nonce that stores the function on the window
+    const EVAL_PROPERTY: &str = "__dioxusEval";
+    let window = web_sys::window().unwrap();
+    let document = window.document().unwrap();
+    let script = document.create_element("script")?;
+    script.set_attribute("nonce", nonce)?;
+    script.set_text_content(Some(&format!(
+        "window.{EVAL_PROPERTY} = function (dioxus) {{ {code} }};"
+    )));
+    let parent = document.document_element().unwrap();
+    parent.append_child(&script)?;
+    parent.remove_child(&script)?;
+
+    let function = js_sys::Reflect::get(&window, &EVAL_PROPERTY.into())?;
+    js_sys::Reflect::delete_property(&window, &EVAL_PROPERTY.into())?;
+    function
+        .dyn_into::<Function>()
+        .map_err(|_| "the code could not be compiled".into())
+}
+
+/// Required to avoid blocking the Rust WASM thread. The promise resolves with the return value of the code, like on desktop.
 const PROMISE_WRAPPER: &str = r#"
-    return new Promise(async (resolve, _reject) => {
+    return (async () => {
         {JS_CODE}
-        resolve(null);
-    });
+    })();
     "#;
 
 /// Represents a web-target's JavaScript evaluator.
 struct WebEvaluator {
     dioxus: Dioxus,
     channel_receiver: futures_channel::mpsc::UnboundedReceiver<serde_json::Value>,
-    result: Option<Result<serde_json::Value, EvalError>>,
+    result: Option<Result<JsFuture, EvalError>>,
 }
 
 impl WebEvaluator {
     /// Creates a new evaluator for web-based targets.
-    fn create(js: String) -> GenerationalBox<Box<dyn Evaluator>> {
+    fn create(js: String, nonce: Option<&str>) -> GenerationalBox<Box<dyn Evaluator>> {
         let (mut channel_sender, channel_receiver) = futures_channel::mpsc::unbounded();
         let owner = UnsyncStorage::owner();
         let invalid = owner.invalid();
@@ -62,25 +109,8 @@ impl WebEvaluator {
         // Wrap the evaluated JS in a promise so that wasm can continue running (send/receive data from js)
         let code = PROMISE_WRAPPER.replace("{JS_CODE}", &js);
 
-        let result = match Function::new_with_args("dioxus", &code).call1(&JsValue::NULL, &dioxus) {
-            Ok(result) => {
-                if let Ok(stringified) = js_sys::JSON::stringify(&result) {
-                    if !stringified.is_undefined() && stringified.is_valid_utf16() {
-                        let string: String = stringified.into();
-                        Value::from_str(&string).map_err(|e| {
-                            EvalError::Communication(format!("Failed to parse result - {}", e))
-                        })
-                    } else {
-                        Err(EvalError::Communication(
-                            "Failed to stringify result".into(),
-                        ))
-                    }
-                } else {
-                    Err(EvalError::Communication(
-                        "Failed to stringify result".into(),
-                    ))
-                }
-            }
+        let result = match compile(&code, nonce).and_then(|f| f.call1(&JsValue::NULL, &dioxus)) {
+            Ok(result) => Ok(JsFuture::from(js_sys::Promise::from(result))),
             Err(err) => Err(EvalError::InvalidJs(
                 err.as_string().unwrap_or("unknown".to_string()),
             )),
@@ -100,13 +130,23 @@ impl Evaluator for WebEvaluator {
     /// Runs the evaluated JavaScript.
     fn poll_join(
         &mut self,
-        _cx: &mut std::task::Context<'_>,
+        cx: &mut std::task::Context<'_>,
     ) -> std::task::Poll<Result<serde_json::Value, EvalError>> {
-        if let Some(result) = self.result.take() {
-            std::task::Poll::Ready(result)
-        } else {
-            std::task::Poll::Ready(Err(EvalError::Finished))
-        }
+        let result = match self.result.take() {
+            Some(Ok(mut returned)) => match Pin::new(&mut returned).poll(cx) {
+                Poll::Ready(Ok(value)) => parse_result(value),
+                Poll::Ready(Err(err)) => Err(EvalError::InvalidJs(
+                    err.as_string().unwrap_or("unknown".to_string()),
+                )),
+                Poll::Pending => {
+                    self.result = Some(Ok(returned));
+                    return Poll::Pending;
+                }
+            },
+            Some(Err(err)) => Err(err),
+            None => Err(EvalError::Finished),
+        };
+        Poll::Ready(result)
     }
 
     /// Sends a message to the evaluated JavaScript.
@@ -133,6 +173,24 @@ impl Evaluator for WebEvaluator {
     }
 }
 
+/// Convert the return value of the evaluated JavaScript to json
+fn parse_result(value: JsValue) -> Result<serde_json::Value, EvalError> {
+    // Code without a return statement returns undefined, which can't be stringified
+    if value.is_undefined() {
+        return Ok(serde_json::Value::Null);
+    }
+    match js_sys::JSON::stringify(&value) {
+        Ok(stringified) if stringified.is_valid_utf16() => {
+            let string: String = stringified.into();
+            Value::from_str(&string)
+                .map_err(|e| EvalError::Communication(format!("Failed to parse result - {}", e)))
+        }
+        _ => Err(EvalError::Communication(
+            "Failed to stringify result".into(),
+        )),
+    }
+}
+
 #[wasm_bindgen(module = "/src/eval.js")]
 extern "C" {
     pub type Dioxus;
@@ -3,14 +3,22 @@
 use futures_channel::mpsc::UnboundedReceiver;
 
 use dioxus_core::Template;
+use serde::Deserialize;
+use wasm_bindgen::JsCast;
 
-pub(crate) fn init() -> UnboundedReceiver<Template> {
+/// A message from the hot reloading server. This mirrors the `HotReloadMsg` from `dioxus-hot-reload`
+#[derive(Deserialize)]
+#[serde(bound(deserialize = "'de: 'static"))]
+pub(crate) enum HotReloadMsg {
+    UpdateTemplate(Template),
+    UpdateAsset(String),
+    Shutdown,
+}
+
+pub(crate) fn init() -> UnboundedReceiver<HotReloadMsg> {
     use wasm_bindgen::closure::Closure;
-    use wasm_bindgen::JsCast;
     use web_sys::{MessageEvent, WebSocket};
 
-    use serde::Deserialize;
-
     let window = web_sys::window().unwrap();
 
     let protocol = match window.location().protocol().unwrap() {
@@ -34,8 +42,12 @@ pub(crate) fn init() -> UnboundedReceiver<Template> {
             let val = serde_json::from_str::<serde_json::Value>(&string).unwrap();
             // leak the value
             let val: &'static serde_json::Value = Box::leak(Box::new(val));
-            let template: Template = Template::deserialize(val).unwrap();
-            tx.unbounded_send(template).unwrap();
+            // Older servers send templates without the message around them
+            let msg = match HotReloadMsg::deserialize(val) {
+                Ok(msg) => msg,
+                Err(_) => HotReloadMsg::UpdateTemplate(Template::deserialize(val).unwrap()),
+            };
+            tx.unbounded_send(msg).unwrap();
         }
     }) as Box<dyn FnMut(MessageEvent)>);
 
@@ -44,3 +56,39 @@ pub(crate) fn init() -> UnboundedReceiver<Template> {
 
     rx
 }
+
+/// Reload every stylesheet and image that uses an asset by adding a query to the url that bypasses the cache.
+/// Scripts can't be replaced while the page is running, so the page is reloaded if a script uses the asset
+pub(crate) fn reload_asset(asset: &str) {
+    let asset = asset.replace('\\', "/");
+    let window = web_sys::window().unwrap();
+    let document = window.document().unwrap();
+    let Ok(elements) = document.query_selector_all("link[href], img[src], script[src]") else {
+        return;
+    };
+    let reloaded = js_sys::Date::now();
+    for i in 0..elements.length() {
+        let Some(element) = elements
+            .get(i)
+            .and_then(|node| node.dyn_into::<web_sys::Element>().ok())
+        else {
+            continue;
+        };
+        let attribute = match element.tag_name().as_str() {
+            "LINK" => "href",
+            _ => "src",
+        };
+        let Some(url) = element.get_attribute(attribute) else {
+            continue;
+        };
+        let url = url.split('?').next().unwrap_or_default();
+        if url != asset && !url.ends_with(&format!("/{asset}")) {
+            continue;
+        }
+        if element.tag_name() == "SCRIPT" {
+            let _ = window.location().reload();
+            return;
+        }
+        let _ = element.set_attribute(attribute, &format!("{url}?dx_hot_reload={reloaded}"));
+    }
+}
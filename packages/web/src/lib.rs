@@ -64,9 +64,15 @@ use dioxus_core::VirtualDom;
 use futures_util::{pin_mut, select, FutureExt, StreamExt};
 
 mod cfg;
+#[cfg(feature = "clipboard")]
+mod clipboard;
+#[cfg(all(feature = "devtools", debug_assertions))]
+mod devtools;
 mod dom;
 #[cfg(feature = "eval")]
 mod eval;
+#[cfg(feature = "eval")]
+pub use eval::page_csp_nonce;
 mod event;
 pub mod launch;
 mod mutations;
@@ -77,6 +83,16 @@ mod file_engine;
 mod hot_reload;
 #[cfg(feature = "hydrate")]
 mod rehydrate;
+#[cfg(feature = "hydrate")]
+pub use rehydrate::HydrationMismatch;
+#[cfg(feature = "shortcut")]
+mod shortcut;
+#[cfg(feature = "storage")]
+mod storage;
+#[cfg(feature = "storage")]
+pub use storage::{use_local_storage, use_session_storage};
+#[cfg(feature = "worker")]
+pub mod worker;
 
 // Currently disabled since it actually slows down immediate rendering
 // todo: only schedule non-immediate renders through ric/raf
@@ -103,11 +119,18 @@ pub async fn run(virtual_dom: VirtualDom, web_config: Config) {
     #[cfg(feature = "eval")]
     {
         // Eval
+        let nonce = web_config.eval_nonce.clone();
         dom.in_runtime(|| {
-            eval::init_eval();
+            eval::init_eval(nonce);
         });
     }
 
+    #[cfg(feature = "clipboard")]
+    dom.in_runtime(clipboard::init_clipboard);
+
+    #[cfg(feature = "shortcut")]
+    dom.in_runtime(shortcut::init_shortcuts);
+
     #[cfg(feature = "panic_hook")]
     if web_config.default_panic_hook {
         console_error_panic_hook::set_once();
@@ -116,6 +139,9 @@ pub async fn run(virtual_dom: VirtualDom, web_config: Config) {
     #[cfg(all(feature = "hot_reload", debug_assertions))]
     let mut hotreload_rx = hot_reload::init();
 
+    #[cfg(all(feature = "devtools", debug_assertions))]
+    let mut devtools = devtools::DevtoolsHighlight::new(&dom);
+
     let (tx, mut rx) = futures_channel::mpsc::unbounded();
 
     let should_hydrate = web_config.hydrate;
@@ -129,7 +155,7 @@ pub async fn run(virtual_dom: VirtualDom, web_config: Config) {
         {
             dom.rebuild(&mut crate::rehydrate::OnlyWriteTemplates(&mut websys_dom));
 
-            if let Err(err) = websys_dom.rehydrate(&dom) {
+            if let Err(err) = websys_dom.rehydrate(&mut dom) {
                 tracing::error!("Rehydration failed. {:?}", err);
                 tracing::error!("Rebuild DOM into element from scratch");
                 websys_dom.root.set_text_content(None);
@@ -148,6 +174,9 @@ pub async fn run(virtual_dom: VirtualDom, web_config: Config) {
     // the mutations come back with nothing - we need to actually mount them
     websys_dom.mount();
 
+    #[cfg(all(feature = "devtools", debug_assertions))]
+    devtools.update(&mut dom, &websys_dom);
+
     loop {
         tracing::trace!("waiting for work");
 
@@ -174,9 +203,18 @@ pub async fn run(virtual_dom: VirtualDom, web_config: Config) {
             }
         };
 
-        if let Some(template) = template {
-            dom.replace_template(template);
+        #[cfg(all(feature = "hot_reload", debug_assertions))]
+        if let Some(msg) = template {
+            match msg {
+                hot_reload::HotReloadMsg::UpdateTemplate(template) => {
+                    dom.replace_template(template);
+                }
+                hot_reload::HotReloadMsg::UpdateAsset(asset) => hot_reload::reload_asset(&asset),
+                hot_reload::HotReloadMsg::Shutdown => {}
+            }
         }
+        #[cfg(not(all(feature = "hot_reload", debug_assertions)))]
+        let _: Option<()> = template;
 
         // Dequeue all of the events from the channel in send order
         // todo: we should re-order these if possible
@@ -208,5 +246,9 @@ pub async fn run(virtual_dom: VirtualDom, web_config: Config) {
         // work_loop.wait_for_raf().await;
 
         websys_dom.flush_edits();
+        websys_dom.wait_for_exits(&dom);
+
+        #[cfg(all(feature = "devtools", debug_assertions))]
+        devtools.update(&mut dom, &websys_dom);
     }
 }
@@ -8,6 +8,8 @@ use dioxus_html::PlatformEventData;
 use dioxus_interpreter_js::get_node;
 use dioxus_interpreter_js::minimal_bindings;
 use dioxus_interpreter_js::save_template;
+use std::time::Duration;
+use wasm_bindgen::closure::Closure;
 use wasm_bindgen::JsCast;
 use wasm_bindgen::JsValue;
 
@@ -56,6 +58,10 @@ impl WebsysDom {
         }
     }
 
+    /// Apply every edit queued since the last flush.
+    ///
+    /// Mutations are not applied as they are written. The interpreter channel encodes them into a shared byte buffer and this applies the whole buffer with a single call into the JS interpreter.
+    #[tracing::instrument(skip(self), level = "trace", name = "WebsysDom::flush_edits")]
     pub fn flush_edits(&mut self) {
         self.interpreter.flush();
         #[cfg(feature = "mounted")]
@@ -79,6 +85,57 @@ impl WebsysDom {
     pub(crate) fn send_mount_event(&mut self, id: ElementId) {
         self.queued_mounted_events.push(id);
     }
+
+    /// Wait for the exit animations of the presences removed since the last flush.
+    pub(crate) fn wait_for_exits(&mut self, dom: &VirtualDom) {
+        for (scope, ended) in self.take_exits() {
+            // The task is dropped with the presence if it is removed some other way first
+            dom.in_runtime(|| {
+                scope.spawn(async move {
+                    _ = wasm_bindgen_futures::JsFuture::from(ended).await;
+                    scope.finish_exit();
+                })
+            });
+        }
+    }
+
+    /// Take the exit animations of the presences removed since the last flush with a promise that resolves when each exit ends.
+    ///
+    /// An exit ends when a transition on the first node of the presence ends, or after the exit duration if no transition ends before then.
+    pub(crate) fn take_exits(&mut self) -> Vec<(ScopeId, js_sys::Promise)> {
+        let exits = std::mem::take(&mut self.queued_exits);
+        exits
+            .into_iter()
+            .map(|(scope, id, duration)| {
+                let node = get_node(self.interpreter.js_channel(), id.0 as u32);
+                let ended = js_sys::Promise::new(&mut |resolve, _| {
+                    if let Some(element) = node.dyn_ref::<web_sys::Element>() {
+                        let target = JsValue::from(element.clone());
+                        let resolve = resolve.clone();
+                        let on_transition_end = Closure::<dyn FnMut(web_sys::Event)>::new(
+                            move |event: web_sys::Event| {
+                                // Ignore the transitions of children that bubble up to the node
+                                if event.target().map(JsValue::from).as_ref() == Some(&target) {
+                                    _ = resolve.call0(&JsValue::NULL);
+                                }
+                            },
+                        );
+                        _ = element.add_event_listener_with_callback(
+                            "transitionend",
+                            on_transition_end.into_js_value().unchecked_ref(),
+                        );
+                    }
+                    if let Some(window) = web_sys::window() {
+                        _ = window.set_timeout_with_callback_and_timeout_and_arguments_0(
+                            &resolve,
+                            duration.as_millis() as i32,
+                        );
+                    }
+                });
+                (scope, ended)
+            })
+            .collect()
+    }
 }
 
 impl WriteMutations for WebsysDom {
@@ -217,4 +274,16 @@ impl WriteMutations for WebsysDom {
     fn push_root(&mut self, id: ElementId) {
         self.interpreter.push_root(id.0 as u32)
     }
+
+    fn create_subtree_root(&mut self, subtree: usize, target: &str) {
+        self.interpreter.create_subtree_root(subtree as u32, target)
+    }
+
+    fn append_to_subtree(&mut self, subtree: usize, m: usize) {
+        self.interpreter.append_to_subtree(subtree as u32, m as u16)
+    }
+
+    fn wait_for_exit(&mut self, scope: ScopeId, id: ElementId, duration: Duration) {
+        self.queued_exits.push((scope, id, duration));
+    }
 }
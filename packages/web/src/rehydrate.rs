@@ -4,30 +4,217 @@ use dioxus_core::AttributeValue;
 use dioxus_core::WriteMutations;
 use dioxus_core::{DynamicNode, ElementId, ScopeState, TemplateNode, VNode, VirtualDom};
 use dioxus_interpreter_js::save_template;
+use rustc_hash::{FxHashMap, FxHashSet};
+use std::fmt::{Display, Formatter};
+use wasm_bindgen::JsCast;
 
 #[derive(Debug)]
 pub enum RehydrationError {
     VNodeNotInitialized,
+    /// The server HTML does not match the VirtualDom and no subtree around the mismatch can be rebuilt on its own
+    Mismatch,
 }
 
 use RehydrationError::*;
 
+/// A node of the HTML from the server that does not match the node the VirtualDom created for it
+#[derive(Debug, Clone, PartialEq)]
+pub struct HydrationMismatch {
+    /// The component that rendered the node
+    pub component: &'static str,
+    /// The scope of the component
+    pub scope: ScopeId,
+    /// The node the VirtualDom created, like `<div>` or `"hello"`
+    pub expected: String,
+    /// The node in the HTML from the server, or `nothing` if it is missing
+    pub found: String,
+}
+
+impl Display for HydrationMismatch {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} expected {} in the HTML from the server, but found {}",
+            self.component, self.expected, self.found
+        )
+    }
+}
+
+pub(crate) type OnHydrationMismatch = Box<dyn Fn(&HydrationMismatch)>;
+
+/// A node the VirtualDom expects to find in the HTML from the server
+enum Expected<'a> {
+    Element(&'a str),
+    Text(&'a str),
+    Placeholder,
+}
+
+impl Display for Expected<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Expected::Element(tag) => write!(f, "<{tag}>"),
+            Expected::Text(text) => write!(f, "{text:?}"),
+            Expected::Placeholder => write!(f, "a placeholder"),
+        }
+    }
+}
+
+/// The state of a rehydration walk through the VirtualDom
+#[derive(Default)]
+struct Hydration {
+    /// The id of every node with a hydration marker, in the order the server numbered them
+    ids: Vec<u32>,
+    to_mount: Vec<(ScopeId, ElementId)>,
+    /// The nodes with a hydration marker in the HTML from the server
+    server_nodes: FxHashMap<usize, web_sys::Node>,
+    /// The nodes that match the node in the HTML from the server
+    matched: FxHashSet<ElementId>,
+    mismatches: Vec<HydrationMismatch>,
+    scope: Option<(ScopeId, &'static str)>,
+}
+
+impl Hydration {
+    /// Add the next node with a hydration marker and check it against the HTML from the server
+    fn push(&mut self, id: ElementId, expected: Expected) {
+        let index = self.ids.len();
+        self.ids.push(id.0 as u32);
+
+        let found = self.server_nodes.get(&index);
+        let matches = match (&expected, found) {
+            (Expected::Element(tag), Some(node)) => node
+                .dyn_ref::<web_sys::Element>()
+                .is_some_and(|element| element.tag_name().eq_ignore_ascii_case(tag)),
+            (Expected::Placeholder, Some(node)) => node
+                .dyn_ref::<web_sys::Element>()
+                .is_some_and(|element| element.tag_name().eq_ignore_ascii_case("pre")),
+            // The browser does not create empty text nodes, so an empty text is followed by the closing comment
+            (Expected::Text(""), Some(node)) => {
+                node.node_type() == web_sys::Node::COMMENT_NODE
+                    || node.node_type() == web_sys::Node::TEXT_NODE
+                        && node.text_content().unwrap_or_default().is_empty()
+            }
+            (Expected::Text(text), Some(node)) => {
+                node.node_type() == web_sys::Node::TEXT_NODE
+                    && node.text_content().as_deref() == Some(*text)
+            }
+            (_, None) => false,
+        };
+
+        if matches {
+            self.matched.insert(id);
+        } else if let Some((scope, component)) = self.scope {
+            self.mismatches.push(HydrationMismatch {
+                component,
+                scope,
+                expected: expected.to_string(),
+                found: describe_node(found),
+            });
+        }
+    }
+}
+
+/// Collect the nodes with a hydration marker the same way the interpreter finds them in `hydrate`
+fn server_nodes(document: &web_sys::Document) -> FxHashMap<usize, web_sys::Node> {
+    let mut nodes = FxHashMap::default();
+
+    if let Ok(elements) = document.query_selector_all("[data-node-hydration]") {
+        for i in 0..elements.length() {
+            let Some(element) = elements
+                .get(i)
+                .and_then(|node| node.dyn_into::<web_sys::Element>().ok())
+            else {
+                continue;
+            };
+            let index = element
+                .get_attribute("data-node-hydration")
+                .and_then(|hydration| hydration.split(',').next()?.parse().ok());
+            if let Some(index) = index {
+                nodes.insert(index, element.unchecked_into());
+            }
+        }
+    }
+
+    // Dynamic text is marked with a comment before the text node
+    let Some(body) = document.body() else {
+        return nodes;
+    };
+    // NodeFilter.SHOW_COMMENT
+    const SHOW_COMMENT: u32 = 0x80;
+    let Ok(walker) = document.create_tree_walker_with_what_to_show(&body, SHOW_COMMENT) else {
+        return nodes;
+    };
+    while let Ok(Some(comment)) = walker.next_node() {
+        let index = comment
+            .text_content()
+            .and_then(|text| text.split("node-id").nth(1)?.parse().ok());
+        if let (Some(index), Some(text)) = (index, comment.next_sibling()) {
+            nodes.insert(index, text);
+        }
+    }
+
+    nodes
+}
+
+fn describe_node(node: Option<&web_sys::Node>) -> String {
+    let Some(node) = node else {
+        return "nothing".to_string();
+    };
+    if let Some(element) = node.dyn_ref::<web_sys::Element>() {
+        return format!("<{}>", element.tag_name().to_lowercase());
+    }
+    match node.node_type() {
+        web_sys::Node::TEXT_NODE => format!("{:?}", node.text_content().unwrap_or_default()),
+        web_sys::Node::COMMENT_NODE => {
+            format!("<!--{}-->", node.text_content().unwrap_or_default())
+        }
+        _ => node.node_name(),
+    }
+}
+
 impl WebsysDom {
     // we're streaming in patches, but the nodes already exist
     // so we're just going to write the correct IDs to the node and load them in
-    pub fn rehydrate(&mut self, dom: &VirtualDom) -> Result<(), RehydrationError> {
-        let root_scope = dom.base_scope();
-        let mut ids = Vec::new();
-        let mut to_mount = Vec::new();
+    //
+    // Nodes that don't match the HTML from the server are reported, and the smallest component around them whose
+    // roots did match is rebuilt on its own
+    pub fn rehydrate(&mut self, dom: &mut VirtualDom) -> Result<(), RehydrationError> {
+        let mut hydration = Hydration {
+            server_nodes: server_nodes(&self.document),
+            ..Default::default()
+        };
 
         // Recursively rehydrate the dom from the VirtualDom
-        self.rehydrate_scope(root_scope, dom, &mut ids, &mut to_mount)?;
+        self.rehydrate_scope(dom.base_scope(), dom, &mut hydration)?;
 
-        dioxus_interpreter_js::hydrate(self.interpreter.js_channel(), ids);
+        for mismatch in &hydration.mismatches {
+            tracing::warn!("Hydration mismatch: {mismatch}");
+            if let Some(on_mismatch) = &self.on_hydration_mismatch {
+                on_mismatch(mismatch);
+            }
+        }
+
+        let rebuilt = recovery_scopes(dom, &hydration)?;
+
+        dioxus_interpreter_js::hydrate(self.interpreter.js_channel(), hydration.ids);
 
         #[cfg(feature = "mounted")]
-        for id in to_mount {
-            self.send_mount_event(id);
+        for (scope, id) in hydration.to_mount {
+            if !rebuilt.iter().any(|root| is_within(dom, scope, *root)) {
+                self.send_mount_event(id);
+            }
+        }
+
+        if !rebuilt.is_empty() {
+            for scope in rebuilt {
+                tracing::warn!(
+                    "Rebuilding {} because it does not match the HTML from the server",
+                    dom.get_scope(scope)
+                        .map(ScopeState::name)
+                        .unwrap_or_default()
+                );
+                dom.remount_scope(scope, self);
+            }
+            self.flush_edits();
         }
 
         Ok(())
@@ -37,27 +224,27 @@ impl WebsysDom {
         &mut self,
         scope: &ScopeState,
         dom: &VirtualDom,
-        ids: &mut Vec<u32>,
-        to_mount: &mut Vec<ElementId>,
+        hydration: &mut Hydration,
     ) -> Result<(), RehydrationError> {
+        let parent = hydration.scope.replace((scope.id(), scope.name()));
         let vnode = scope.root_node();
-        self.rehydrate_vnode(dom, vnode, ids, to_mount)
+        self.rehydrate_vnode(dom, vnode, hydration)?;
+        hydration.scope = parent;
+        Ok(())
     }
 
     fn rehydrate_vnode(
         &mut self,
         dom: &VirtualDom,
         vnode: &VNode,
-        ids: &mut Vec<u32>,
-        to_mount: &mut Vec<ElementId>,
+        hydration: &mut Hydration,
     ) -> Result<(), RehydrationError> {
         for (i, root) in vnode.template.get().roots.iter().enumerate() {
             self.rehydrate_template_node(
                 dom,
                 vnode,
                 root,
-                ids,
-                to_mount,
+                hydration,
                 Some(vnode.mounted_root(i, dom).ok_or(VNodeNotInitialized)?),
             )?;
         }
@@ -69,14 +256,16 @@ impl WebsysDom {
         dom: &VirtualDom,
         vnode: &VNode,
         node: &TemplateNode,
-        ids: &mut Vec<u32>,
-        to_mount: &mut Vec<ElementId>,
+        hydration: &mut Hydration,
         root_id: Option<ElementId>,
     ) -> Result<(), RehydrationError> {
         tracing::trace!("rehydrate template node: {:?}", node);
         match node {
             TemplateNode::Element {
-                children, attrs, ..
+                tag,
+                children,
+                attrs,
+                ..
             } => {
                 let mut mounted_id = root_id;
                 for attr in *attrs {
@@ -90,30 +279,26 @@ impl WebsysDom {
                             mounted_id = Some(id);
                             if let AttributeValue::Listener(_) = value {
                                 if attribute.name == "onmounted" {
-                                    to_mount.push(id);
+                                    if let Some((scope, _)) = hydration.scope {
+                                        hydration.to_mount.push((scope, id));
+                                    }
                                 }
                             }
                         }
                     }
                 }
                 if let Some(id) = mounted_id {
-                    ids.push(id.0 as u32);
+                    hydration.push(id, Expected::Element(tag));
                 }
                 if !children.is_empty() {
                     for child in *children {
-                        self.rehydrate_template_node(dom, vnode, child, ids, to_mount, None)?;
+                        self.rehydrate_template_node(dom, vnode, child, hydration, None)?;
                     }
                 }
             }
-            TemplateNode::Dynamic { id } | TemplateNode::DynamicText { id } => self
-                .rehydrate_dynamic_node(
-                    dom,
-                    &vnode.dynamic_nodes[*id],
-                    *id,
-                    vnode,
-                    ids,
-                    to_mount,
-                )?,
+            TemplateNode::Dynamic { id } | TemplateNode::DynamicText { id } => {
+                self.rehydrate_dynamic_node(dom, &vnode.dynamic_nodes[*id], *id, vnode, hydration)?
+            }
             _ => {}
         }
         Ok(())
@@ -125,28 +310,31 @@ impl WebsysDom {
         dynamic: &DynamicNode,
         dynamic_node_index: usize,
         vnode: &VNode,
-        ids: &mut Vec<u32>,
-        to_mount: &mut Vec<ElementId>,
+        hydration: &mut Hydration,
     ) -> Result<(), RehydrationError> {
         tracing::trace!("rehydrate dynamic node: {:?}", dynamic);
         match dynamic {
-            dioxus_core::DynamicNode::Text(_) | dioxus_core::DynamicNode::Placeholder(_) => {
-                ids.push(
-                    vnode
-                        .mounted_dynamic_node(dynamic_node_index, dom)
-                        .ok_or(VNodeNotInitialized)?
-                        .0 as u32,
-                );
+            dioxus_core::DynamicNode::Text(text) => {
+                let id = vnode
+                    .mounted_dynamic_node(dynamic_node_index, dom)
+                    .ok_or(VNodeNotInitialized)?;
+                hydration.push(id, Expected::Text(&text.value));
+            }
+            dioxus_core::DynamicNode::Placeholder(_) => {
+                let id = vnode
+                    .mounted_dynamic_node(dynamic_node_index, dom)
+                    .ok_or(VNodeNotInitialized)?;
+                hydration.push(id, Expected::Placeholder);
             }
             dioxus_core::DynamicNode::Component(comp) => {
                 let scope = comp
                     .mounted_scope(dynamic_node_index, vnode, dom)
                     .ok_or(VNodeNotInitialized)?;
-                self.rehydrate_scope(scope, dom, ids, to_mount)?;
+                self.rehydrate_scope(scope, dom, hydration)?;
             }
             dioxus_core::DynamicNode::Fragment(fragment) => {
                 for vnode in fragment {
-                    self.rehydrate_vnode(dom, vnode, ids, to_mount)?;
+                    self.rehydrate_vnode(dom, vnode, hydration)?;
                 }
             }
         }
@@ -154,6 +342,69 @@ impl WebsysDom {
     }
 }
 
+/// Find the components to rebuild for the mismatches. The mismatched nodes are replaced through the roots of the
+/// component, so the closest component whose roots all match is rebuilt.
+fn recovery_scopes(
+    dom: &VirtualDom,
+    hydration: &Hydration,
+) -> Result<Vec<ScopeId>, RehydrationError> {
+    if hydration.mismatches.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let tree: FxHashMap<_, _> = dom
+        .scope_tree()
+        .into_iter()
+        .map(|info| (info.id, info))
+        .collect();
+
+    let mut scopes = Vec::new();
+    for mismatch in &hydration.mismatches {
+        let mut scope = Some(mismatch.scope);
+        loop {
+            let Some(info) = scope.and_then(|scope| tree.get(&scope)) else {
+                return Err(Mismatch);
+            };
+            if !info.elements.is_empty()
+                && info
+                    .elements
+                    .iter()
+                    .all(|element| hydration.matched.contains(element))
+            {
+                break;
+            }
+            scope = info.parent;
+        }
+        scopes.extend(scope);
+    }
+
+    // Rebuilding a component rebuilds everything below it
+    let rebuilt: Vec<ScopeId> = scopes
+        .iter()
+        .copied()
+        .filter(|scope| {
+            !scopes
+                .iter()
+                .any(|other| other != scope && is_within(dom, *scope, *other))
+        })
+        .collect::<FxHashSet<_>>()
+        .into_iter()
+        .collect();
+    Ok(rebuilt)
+}
+
+/// Check if a scope is the same as or below another scope
+fn is_within(dom: &VirtualDom, scope: ScopeId, ancestor: ScopeId) -> bool {
+    let mut current = Some(scope);
+    while let Some(scope) = current {
+        if scope == ancestor {
+            return true;
+        }
+        current = dom.in_runtime(|| scope.parent_scope());
+    }
+    false
+}
+
 /// During rehydration, we don't want to actually write anything to the DOM, but we do need to store any templates that were created. This struct is used to only write templates to the DOM.
 pub(crate) struct OnlyWriteTemplates<'a>(pub &'a mut WebsysDom);
 
@@ -214,4 +465,10 @@ impl WriteMutations for OnlyWriteTemplates<'_> {
     fn remove_node(&mut self, _: ElementId) {}
 
     fn push_root(&mut self, _: ElementId) {}
+
+    fn create_subtree_root(&mut self, _: usize, _: &str) {}
+
+    fn append_to_subtree(&mut self, _: usize, _: usize) {}
+
+    fn wait_for_exit(&mut self, _: ScopeId, _: ElementId, _: std::time::Duration) {}
 }
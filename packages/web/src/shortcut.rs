@@ -0,0 +1,93 @@
+//! The keyboard shortcuts of `use_shortcut`, handled with a keydown listener on the document
+
+use dioxus_hooks::{Shortcut, ShortcutContext, ShortcutError, ShortcutModifiers};
+use wasm_bindgen::{prelude::Closure, JsCast};
+use web_sys::KeyboardEvent;
+
+/// Shortcuts that browsers handle themselves without sending them to the page
+const RESERVED: &[&str] = &[
+    "ctrl+n",
+    "ctrl+shift+n",
+    "ctrl+t",
+    "ctrl+shift+t",
+    "ctrl+w",
+    "ctrl+shift+w",
+    "ctrl+tab",
+    "ctrl+shift+tab",
+    "meta+n",
+    "meta+shift+n",
+    "meta+t",
+    "meta+shift+t",
+    "meta+w",
+    "meta+q",
+];
+
+/// Provide the shortcuts in the root scope. The document is only listened to while a shortcut is registered
+pub fn init_shortcuts() {
+    let mut listener = None;
+    let mut registered = 0;
+    ShortcutContext::provide(move |context, shortcut, added| {
+        if !added {
+            registered -= 1;
+            if registered == 0 {
+                listener = None;
+            }
+            return Ok(());
+        }
+
+        let reserved = RESERVED
+            .iter()
+            .any(|reserved| reserved.parse::<Shortcut>().ok().as_ref() == Some(shortcut));
+        if reserved {
+            return Err(ShortcutError::Conflict(shortcut.clone()));
+        }
+        if listener.is_none() {
+            listener = Some(KeyListener::new(context.clone()).ok_or_else(|| {
+                ShortcutError::Failed("failed to listen to the document".to_string())
+            })?);
+        }
+        registered += 1;
+        Ok(())
+    });
+}
+
+/// Calls the handlers of shortcuts that are pressed. The listener is removed when this is dropped.
+struct KeyListener {
+    callback: Closure<dyn FnMut(KeyboardEvent)>,
+}
+
+impl KeyListener {
+    fn new(context: ShortcutContext) -> Option<Self> {
+        let callback = Closure::<dyn FnMut(KeyboardEvent)>::new(move |event: KeyboardEvent| {
+            let modifiers = ShortcutModifiers {
+                ctrl: event.ctrl_key(),
+                alt: event.alt_key(),
+                shift: event.shift_key(),
+                meta: event.meta_key(),
+            };
+            // The physical key is used so shortcuts work the same with every keyboard layout
+            let Some(shortcut) = Shortcut::from_code(modifiers, &event.code()) else {
+                return;
+            };
+            if context.dispatch(&shortcut) {
+                event.prevent_default();
+            }
+        });
+        web_sys::window()?
+            .document()?
+            .add_event_listener_with_callback("keydown", callback.as_ref().unchecked_ref())
+            .ok()?;
+        Some(Self { callback })
+    }
+}
+
+impl Drop for KeyListener {
+    fn drop(&mut self) {
+        if let Some(document) = web_sys::window().and_then(|window| window.document()) {
+            _ = document.remove_event_listener_with_callback(
+                "keydown",
+                self.callback.as_ref().unchecked_ref(),
+            );
+        }
+    }
+}
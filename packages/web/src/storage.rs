@@ -0,0 +1,159 @@
+//! Hooks that persist state in the browser's web storage
+
+use std::rc::Rc;
+
+use dioxus_core::prelude::use_hook;
+use dioxus_hooks::use_effect;
+use dioxus_signals::{Readable, Signal, Writable};
+use serde::{de::DeserializeOwned, Serialize};
+use wasm_bindgen::{prelude::Closure, JsCast};
+use web_sys::{Storage, StorageEvent};
+
+/// Creates a signal that is saved in `window.localStorage` under a key.
+///
+/// The value is loaded from storage when the component is created, or created with `init` if there is no valid value saved. Every time the signal is written, the new value is saved. If another tab changes the value under the same key, the signal is updated with that value.
+///
+/// Outside of the browser, or if storage is unavailable, the signal works like a normal signal and the value is not saved.
+///
+/// ```rust, ignore
+/// use dioxus::prelude::*;
+/// use dioxus_web::use_local_storage;
+///
+/// fn app() -> Element {
+///     let mut count = use_local_storage("count", || 0);
+///
+///     rsx! {
+///         button { onclick: move |_| count += 1, "Clicked {count} times" }
+///     }
+/// }
+/// ```
+#[must_use]
+pub fn use_local_storage<T: Serialize + DeserializeOwned + 'static>(
+    key: impl ToString,
+    init: impl FnOnce() -> T,
+) -> Signal<T> {
+    use_storage(StorageKind::Local, key, init)
+}
+
+/// Creates a signal that is saved in `window.sessionStorage` under a key.
+///
+/// This works like [`use_local_storage`], but the value is only kept until the tab is closed.
+#[must_use]
+pub fn use_session_storage<T: Serialize + DeserializeOwned + 'static>(
+    key: impl ToString,
+    init: impl FnOnce() -> T,
+) -> Signal<T> {
+    use_storage(StorageKind::Session, key, init)
+}
+
+#[derive(Clone, Copy)]
+enum StorageKind {
+    Local,
+    Session,
+}
+
+impl StorageKind {
+    fn storage(self) -> Option<Storage> {
+        // web-sys panics when it is used outside of wasm, so other renderers only keep the value in memory
+        if !cfg!(target_arch = "wasm32") {
+            return None;
+        }
+        let window = web_sys::window()?;
+        match self {
+            StorageKind::Local => window.local_storage(),
+            StorageKind::Session => window.session_storage(),
+        }
+        .ok()
+        .flatten()
+    }
+
+    fn load<T: DeserializeOwned>(self, key: &str) -> Option<T> {
+        let value = self.storage()?.get_item(key).ok()??;
+        match serde_json::from_str(&value) {
+            Ok(value) => Some(value),
+            Err(err) => {
+                tracing::error!("Failed to deserialize the value saved in {key} - {err}");
+                None
+            }
+        }
+    }
+
+    fn save<T: Serialize>(self, key: &str, value: &T) {
+        let Some(storage) = self.storage() else {
+            return;
+        };
+        let value = match serde_json::to_string(value) {
+            Ok(value) => value,
+            Err(err) => {
+                tracing::error!("Failed to serialize the value for {key} - {err}");
+                return;
+            }
+        };
+        if let Err(err) = storage.set_item(key, &value) {
+            tracing::error!("Failed to save {key} - {err:?}");
+        }
+    }
+}
+
+fn use_storage<T: Serialize + DeserializeOwned + 'static>(
+    kind: StorageKind,
+    key: impl ToString,
+    init: impl FnOnce() -> T,
+) -> Signal<T> {
+    let key = use_hook(|| Rc::<str>::from(key.to_string()));
+    let signal = use_hook(|| Signal::new(kind.load(&key).unwrap_or_else(init)));
+
+    use_hook(|| StorageListener::new(kind, key.clone(), signal).map(Rc::new));
+
+    use_effect(move || kind.save(&key, &*signal.read()));
+
+    signal
+}
+
+/// Updates a signal when another tab changes the value it is saved under. The listener is removed when this is dropped.
+struct StorageListener {
+    callback: Closure<dyn FnMut(StorageEvent)>,
+}
+
+impl StorageListener {
+    fn new<T: DeserializeOwned + 'static>(
+        kind: StorageKind,
+        key: Rc<str>,
+        mut signal: Signal<T>,
+    ) -> Option<Self> {
+        let storage = kind.storage()?;
+        let callback = Closure::<dyn FnMut(StorageEvent)>::new(move |event: StorageEvent| {
+            if event.storage_area().as_ref() != Some(&storage) {
+                return;
+            }
+            if event.key().as_deref() != Some(&*key) {
+                return;
+            }
+            // The value was removed if there is no new value. Keep the current value until it is written again
+            let Some(value) = event.new_value() else {
+                return;
+            };
+            match serde_json::from_str(&value) {
+                Ok(value) => signal.set(value),
+                Err(err) => {
+                    tracing::error!("Failed to deserialize the value saved in {key} - {err}")
+                }
+            }
+        });
+        web_sys::window()?
+            .add_event_listener_with_callback("storage", callback.as_ref().unchecked_ref())
+            .ok()?;
+        Some(Self { callback })
+    }
+}
+
+impl Drop for StorageListener {
+    fn drop(&mut self) {
+        if let Some(window) = web_sys::window() {
+            _ = window.remove_event_listener_with_callback(
+                "storage",
+                self.callback.as_ref().unchecked_ref(),
+            );
+        }
+    }
+}
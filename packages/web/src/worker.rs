@@ -0,0 +1,180 @@
+//! Run the VirtualDom in a web worker and apply its mutations on the main thread.
+//!
+//! The VirtualDom runs in a dedicated worker with [`run`], so long running components and tasks don't block the main thread.
+//! The main thread only creates the worker and applies the mutations it sends with [`host`]:
+//! - Mutations are encoded with [`MutationEncoder`] and sent to the main thread in one message per render.
+//! - Events are serialized on the main thread and sent to the worker as JSON.
+//!
+//! The worker has no access to the DOM, so [`dioxus_html::MountedData`] queries are not supported in the worker and eval is not available.
+//! Files in form and drag events are not sent to the worker.
+//!
+//! # Example
+//!
+//! ```rust, ignore
+//! // in the worker script, built as its own wasm binary
+//! wasm_bindgen_futures::spawn_local(dioxus_web::worker::run(VirtualDom::new(app)));
+//!
+//! // on the main thread
+//! let worker = web_sys::Worker::new("./worker.js").unwrap();
+//! wasm_bindgen_futures::spawn_local(dioxus_web::worker::host(worker, Config::new()));
+//! ```
+
+use dioxus_core::{prelude::*, MutationDecoder, MutationEncoder, VirtualDom};
+use dioxus_html::{EventData, HtmlEvent, SerializedHtmlEventConverter};
+use futures_channel::mpsc;
+use futures_util::{pin_mut, select, FutureExt, StreamExt};
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::{closure::Closure, JsCast, JsValue};
+use web_sys::{DedicatedWorkerGlobalScope, MessageEvent, Worker};
+
+use crate::{dom::WebsysDom, Config};
+
+/// A message the main thread sends to the worker
+#[derive(Serialize, Deserialize)]
+enum HostMessage {
+    /// An event from the DOM
+    Event(HtmlEvent),
+    /// The exit animation of a presence ended
+    ExitFinished(usize),
+}
+
+/// Forward the messages a worker or the main thread receives into a channel
+fn listen(
+    set_onmessage: impl FnOnce(Option<&js_sys::Function>),
+) -> mpsc::UnboundedReceiver<JsValue> {
+    let (tx, rx) = mpsc::unbounded();
+    let onmessage = Closure::<dyn FnMut(MessageEvent)>::new(move |event: MessageEvent| {
+        _ = tx.unbounded_send(event.data());
+    });
+    set_onmessage(Some(onmessage.as_ref().unchecked_ref()));
+    onmessage.forget();
+    rx
+}
+
+/// Run the VirtualDom in the current dedicated worker. The mutations are sent to the main thread, which applies them with [`host`].
+///
+/// Polls futures internal to the VirtualDOM, hence the async nature of this function.
+pub async fn run(virtual_dom: VirtualDom) {
+    tracing::info!("Starting up in a worker");
+
+    let mut dom = virtual_dom;
+    let scope: DedicatedWorkerGlobalScope = js_sys::global().unchecked_into();
+    // Events arrive serialized, so they are converted like the events of liveview
+    dioxus_html::set_event_converter(Box::new(SerializedHtmlEventConverter));
+
+    let mut messages = listen(|onmessage| scope.set_onmessage(onmessage));
+    let mut encoder = MutationEncoder::new();
+
+    dom.rebuild(&mut encoder);
+    send_edits(&scope, &mut encoder);
+
+    loop {
+        let mut message = {
+            let work = dom.wait_for_work().fuse();
+            pin_mut!(work);
+            select! {
+                _ = work => None,
+                message = messages.select_next_some() => Some(message),
+            }
+        };
+
+        // Handle every queued message before rendering
+        while let Some(data) = message {
+            let host_message = data
+                .as_string()
+                .and_then(|text| serde_json::from_str::<HostMessage>(&text).ok());
+            match host_message {
+                Some(HostMessage::Event(event)) => {
+                    dom.handle_event(
+                        &event.name,
+                        event.data.into_any(),
+                        event.element,
+                        event.bubbles,
+                    );
+                }
+                Some(HostMessage::ExitFinished(scope)) => {
+                    dom.in_runtime(|| ScopeId(scope).finish_exit());
+                }
+                None => tracing::error!("Received an invalid message from the main thread"),
+            }
+            message = messages.try_next().ok().flatten();
+        }
+
+        dom.render_immediate(&mut encoder);
+        send_edits(&scope, &mut encoder);
+    }
+}
+
+/// Send the mutations written since the last batch to the main thread
+fn send_edits(scope: &DedicatedWorkerGlobalScope, encoder: &mut MutationEncoder) {
+    if encoder.is_empty() {
+        return;
+    }
+    let batch = js_sys::Uint8Array::from(encoder.finish().as_slice());
+    let transfer = js_sys::Array::of1(&batch.buffer());
+    if let Err(err) = scope.post_message_with_transfer(&batch, &transfer) {
+        tracing::error!("Failed to send the mutations to the main thread: {:?}", err);
+    }
+}
+
+/// Apply the mutations of a VirtualDom that runs in a worker with [`run`], and send the events from the DOM back to the worker.
+///
+/// The future runs for as long as the app is mounted.
+pub async fn host(worker: Worker, web_config: Config) {
+    #[cfg(feature = "panic_hook")]
+    if web_config.default_panic_hook {
+        console_error_panic_hook::set_once();
+    }
+
+    let (tx, mut rx) = mpsc::unbounded();
+    let mut websys_dom = WebsysDom::new(web_config, tx);
+    let mut batches = listen(|onmessage| worker.set_onmessage(onmessage));
+    let (exit_tx, mut exits) = mpsc::unbounded::<ScopeId>();
+    let mut decoder = MutationDecoder::new();
+    let mut mounted = false;
+
+    loop {
+        select! {
+            batch = batches.select_next_some() => {
+                let batch = js_sys::Uint8Array::new(&batch).to_vec();
+                if let Err(err) = decoder.decode(&batch, &mut websys_dom) {
+                    tracing::error!("Failed to decode the mutations from the worker: {}", err);
+                }
+                websys_dom.flush_edits();
+                // The first batch is the initial render
+                if !mounted {
+                    websys_dom.mount();
+                    mounted = true;
+                }
+                for (scope, ended) in websys_dom.take_exits() {
+                    let exit_tx = exit_tx.clone();
+                    wasm_bindgen_futures::spawn_local(async move {
+                        _ = wasm_bindgen_futures::JsFuture::from(ended).await;
+                        _ = exit_tx.unbounded_send(scope);
+                    });
+                }
+            },
+            event = rx.select_next_some() => {
+                let Some(data) = EventData::from_platform(&event.name, &event.data) else {
+                    continue;
+                };
+                send_to_worker(&worker, HostMessage::Event(HtmlEvent {
+                    element: event.element,
+                    name: event.name,
+                    bubbles: event.bubbles,
+                    data,
+                }));
+            },
+            scope = exits.select_next_some() => {
+                send_to_worker(&worker, HostMessage::ExitFinished(scope.0));
+            },
+        }
+    }
+}
+
+fn send_to_worker(worker: &Worker, message: HostMessage) {
+    let message = serde_json::to_string(&message).expect("Failed to serialize the message");
+    if let Err(err) = worker.post_message(&JsValue::from_str(&message)) {
+        tracing::error!("Failed to send a message to the worker: {:?}", err);
+    }
+}